@@ -1267,6 +1267,7 @@ pub fn precedence(&self) -> ExprPrecedence {
             ExprKind::Break(..) => ExprPrecedence::Break,
             ExprKind::Continue(..) => ExprPrecedence::Continue,
             ExprKind::Ret(..) => ExprPrecedence::Ret,
+            ExprKind::Become(..) => ExprPrecedence::Become,
             ExprKind::InlineAsm(..) => ExprPrecedence::InlineAsm,
             ExprKind::MacCall(..) => ExprPrecedence::Mac,
             ExprKind::Struct(..) => ExprPrecedence::Struct,
@@ -1434,6 +1435,10 @@ pub enum ExprKind {
     Continue(Option<Label>),
     /// A `return`, with an optional value to be returned.
     Ret(Option<P<Expr>>),
+    /// A `become`, with the call to be performed as a guaranteed tail call.
+    ///
+    /// This is unstable, see the `explicit_tail_calls` feature.
+    Become(P<Expr>),
 
     /// Output of the `asm!()` macro.
     InlineAsm(P<InlineAsm>),
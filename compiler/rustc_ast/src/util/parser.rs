@@ -246,6 +246,7 @@ pub enum ExprPrecedence {
     Break,
     Continue,
     Ret,
+    Become,
     Yield,
 
     Range,
@@ -299,6 +300,7 @@ pub fn order(self) -> i8 {
             ExprPrecedence::Break |
             ExprPrecedence::Continue |
             ExprPrecedence::Ret |
+            ExprPrecedence::Become |
             ExprPrecedence::Yield => PREC_JUMP,
 
             // `Range` claims to have higher precedence than `Assign`, but `x .. x = x` fails to
@@ -1,10 +1,19 @@
+use crate::session_diagnostics::{
+    AssignToBorrowed, AssignToImmutable, BorrowAcrossDestructor, BorrowAcrossGeneratorYield,
+    BorrowAsMutable, BorrowedDataEscapesClosure, CaptureInLongLivedClosure, DoesNotLiveLongEnough,
+    MoveOutOf, MoveOutOfInteriorNonCopy, MoveOutOfInteriorOfDrop, MoveOutWhileBorrowed,
+    MutateInImmutableSection, ReassignImmutable, ReturnReferenceToLocal,
+    TemporaryDroppedWhileBorrowed, ThreadLocalBorrowedPastEndOfFunction,
+    UseOfPossiblyUninitialized, UseWhenMutablyBorrowed,
+};
 use rustc_errors::{struct_span_err, DiagnosticBuilder, DiagnosticId};
 use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_session::SessionDiagnostic;
 use rustc_span::{MultiSpan, Span};
 
 impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
     crate fn cannot_move_when_borrowed(&self, span: Span, desc: &str) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(self, span, E0505, "cannot move out of {} because it is borrowed", desc,)
+        MoveOutWhileBorrowed { span, desc }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_use_when_mutably_borrowed(
@@ -14,17 +23,8 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         borrow_span: Span,
         borrow_desc: &str,
     ) -> DiagnosticBuilder<'cx> {
-        let mut err = struct_span_err!(
-            self,
-            span,
-            E0503,
-            "cannot use {} because it was mutably borrowed",
-            desc,
-        );
-
-        err.span_label(borrow_span, format!("borrow of {} occurs here", borrow_desc));
-        err.span_label(span, format!("use of borrowed {}", borrow_desc));
-        err
+        UseWhenMutablyBorrowed { borrow_span, span, desc, borrow_desc }
+            .into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_act_on_uninitialized_variable(
@@ -33,14 +33,7 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         verb: &str,
         desc: &str,
     ) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(
-            self,
-            span,
-            E0381,
-            "{} of possibly-uninitialized variable: `{}`",
-            verb,
-            desc,
-        )
+        UseOfPossiblyUninitialized { span, verb, desc }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_mutably_borrow_multiply(
@@ -244,17 +237,7 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         borrow_span: Span,
         desc: &str,
     ) -> DiagnosticBuilder<'cx> {
-        let mut err = struct_span_err!(
-            self,
-            span,
-            E0506,
-            "cannot assign to {} because it is borrowed",
-            desc,
-        );
-
-        err.span_label(borrow_span, format!("borrow of {} occurs here", desc));
-        err.span_label(span, format!("assignment to borrowed {} occurs here", desc));
-        err
+        AssignToBorrowed { borrow_span, span, desc }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_reassign_immutable(
@@ -263,12 +246,12 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         desc: &str,
         is_arg: bool,
     ) -> DiagnosticBuilder<'cx> {
-        let msg = if is_arg { "to immutable argument" } else { "twice to immutable variable" };
-        struct_span_err!(self, span, E0384, "cannot assign {} {}", msg, desc)
+        let what = if is_arg { "to immutable argument" } else { "twice to immutable variable" };
+        ReassignImmutable { span, what, desc }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_assign(&self, span: Span, desc: &str) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(self, span, E0594, "cannot assign to {}", desc)
+        AssignToImmutable { span, desc }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_move_out_of(
@@ -276,7 +259,8 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         move_from_span: Span,
         move_from_desc: &str,
     ) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(self, move_from_span, E0507, "cannot move out of {}", move_from_desc,)
+        MoveOutOf { span: move_from_span, desc: move_from_desc }
+            .into_diagnostic(self.infcx.tcx.sess)
     }
 
     /// Signal an error due to an attempt to move out of the interior
@@ -293,16 +277,8 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
             (&ty::Slice(_), _) => "slice",
             _ => span_bug!(move_from_span, "this path should not cause illegal move"),
         };
-        let mut err = struct_span_err!(
-            self,
-            move_from_span,
-            E0508,
-            "cannot move out of type `{}`, a non-copy {}",
-            ty,
-            type_name,
-        );
-        err.span_label(move_from_span, "cannot move out of here");
-        err
+        MoveOutOfInteriorNonCopy { span: move_from_span, ty, type_name }
+            .into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_move_out_of_interior_of_drop(
@@ -310,15 +286,8 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         move_from_span: Span,
         container_ty: Ty<'_>,
     ) -> DiagnosticBuilder<'cx> {
-        let mut err = struct_span_err!(
-            self,
-            move_from_span,
-            E0509,
-            "cannot move out of type `{}`, which implements the `Drop` trait",
-            container_ty,
-        );
-        err.span_label(move_from_span, "cannot move out of here");
-        err
+        MoveOutOfInteriorOfDrop { span: move_from_span, container_ty }
+            .into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_act_on_moved_value(
@@ -347,7 +316,7 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         path: &str,
         reason: &str,
     ) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(self, span, E0596, "cannot borrow {} as mutable{}", path, reason,)
+        BorrowAsMutable { span, path, reason }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_mutate_in_immutable_section(
@@ -358,18 +327,14 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         immutable_section: &str,
         action: &str,
     ) -> DiagnosticBuilder<'cx> {
-        let mut err = struct_span_err!(
-            self,
-            mutate_span,
-            E0510,
-            "cannot {} {} in {}",
+        MutateInImmutableSection {
+            span: mutate_span,
+            immutable_span,
             action,
-            immutable_place,
-            immutable_section,
-        );
-        err.span_label(mutate_span, format!("cannot {}", action));
-        err.span_label(immutable_span, format!("value is immutable in {}", immutable_section));
-        err
+            place: immutable_place,
+            section: immutable_section,
+        }
+        .into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_borrow_across_generator_yield(
@@ -377,23 +342,11 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         span: Span,
         yield_span: Span,
     ) -> DiagnosticBuilder<'cx> {
-        let mut err = struct_span_err!(
-            self,
-            span,
-            E0626,
-            "borrow may still be in use when generator yields",
-        );
-        err.span_label(yield_span, "possible yield occurs here");
-        err
+        BorrowAcrossGeneratorYield { span, yield_span }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_borrow_across_destructor(&self, borrow_span: Span) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(
-            self,
-            borrow_span,
-            E0713,
-            "borrow may still be in use when destructor runs",
-        )
+        BorrowAcrossDestructor { span: borrow_span }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn path_does_not_live_long_enough(
@@ -401,7 +354,7 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         span: Span,
         path: &str,
     ) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(self, span, E0597, "{} does not live long enough", path,)
+        DoesNotLiveLongEnough { span, path }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_return_reference_to_local(
@@ -411,22 +364,8 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         reference_desc: &str,
         path_desc: &str,
     ) -> DiagnosticBuilder<'cx> {
-        let mut err = struct_span_err!(
-            self,
-            span,
-            E0515,
-            "cannot {RETURN} {REFERENCE} {LOCAL}",
-            RETURN = return_kind,
-            REFERENCE = reference_desc,
-            LOCAL = path_desc,
-        );
-
-        err.span_label(
-            span,
-            format!("{}s a {} data owned by the current function", return_kind, reference_desc),
-        );
-
-        err
+        ReturnReferenceToLocal { span, return_kind, reference_desc, path_desc }
+            .into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn cannot_capture_in_long_lived_closure(
@@ -436,30 +375,19 @@ impl<'cx, 'tcx> crate::MirBorrowckCtxt<'cx, 'tcx> {
         borrowed_path: &str,
         capture_span: Span,
     ) -> DiagnosticBuilder<'cx> {
-        let mut err = struct_span_err!(
-            self,
-            closure_span,
-            E0373,
-            "{} may outlive the current function, \
-             but it borrows {}, \
-             which is owned by the current function",
-            closure_kind,
-            borrowed_path,
-        );
-        err.span_label(capture_span, format!("{} is borrowed here", borrowed_path))
-            .span_label(closure_span, format!("may outlive borrowed value {}", borrowed_path));
-        err
+        CaptureInLongLivedClosure { capture_span, span: closure_span, closure_kind, borrowed_path }
+            .into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn thread_local_value_does_not_live_long_enough(
         &self,
         span: Span,
     ) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(self, span, E0712, "thread-local variable borrowed past end of function",)
+        ThreadLocalBorrowedPastEndOfFunction { span }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     crate fn temporary_value_borrowed_for_too_long(&self, span: Span) -> DiagnosticBuilder<'cx> {
-        struct_span_err!(self, span, E0716, "temporary value dropped while borrowed",)
+        TemporaryDroppedWhileBorrowed { span }.into_diagnostic(self.infcx.tcx.sess)
     }
 
     fn struct_span_err_with_code<S: Into<MultiSpan>>(
@@ -477,11 +405,5 @@ fn struct_span_err_with_code<S: Into<MultiSpan>>(
     escape_span: Span,
     escapes_from: &str,
 ) -> DiagnosticBuilder<'tcx> {
-    struct_span_err!(
-        tcx.sess,
-        escape_span,
-        E0521,
-        "borrowed data escapes outside of {}",
-        escapes_from,
-    )
+    BorrowedDataEscapesClosure { span: escape_span, escapes_from }.into_diagnostic(tcx.sess)
 }
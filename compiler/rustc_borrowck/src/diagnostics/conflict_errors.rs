@@ -326,6 +326,20 @@ pub(crate) fn report_use_of_moved_or_uninitialized(
                             "moved",
                         );
                     }
+                    if let UseSpans::OtherUse(_) = move_spans {
+                        let ty = moved_place.ty(self.body, self.infcx.tcx).ty;
+                        if !move_span.from_expansion()
+                            && self.is_plain_move(move_out.source, moved_place.as_ref())
+                            && self.implements_clone(ty)
+                        {
+                            err.span_suggestion_verbose(
+                                move_span.shrink_to_hi(),
+                                "consider cloning the value if the performance cost is acceptable",
+                                ".clone()".to_string(),
+                                Applicability::MachineApplicable,
+                            );
+                        }
+                    }
                 }
 
                 if let (UseSpans::PatUse(span), []) =
@@ -458,6 +472,30 @@ pub(crate) fn report_use_of_moved_or_uninitialized(
         }
     }
 
+    /// Returns whether the move at `location` is a plain `move place` of a user-written
+    /// expression, so that appending `.clone()` to its span clones exactly the moved value.
+    /// Moves into the fields of an aggregate (as with `S { ..s }`) and moves into the
+    /// bindings of a pattern (as with `let (a, _) = t;`) don't have such a span.
+    fn is_plain_move(&self, location: Location, place: PlaceRef<'tcx>) -> bool {
+        let Some(stmt) = self.body[location.block].statements.get(location.statement_index) else {
+            return false;
+        };
+        match &stmt.kind {
+            StatementKind::Assign(box (dest, Rvalue::Use(Operand::Move(moved))))
+                if moved.as_ref() == place =>
+            {
+                match dest.as_local() {
+                    Some(local) => {
+                        let decl = &self.body.local_decls[local];
+                        !(decl.is_user_variable() && decl.source_info.span == stmt.source_info.span)
+                    }
+                    None => true,
+                }
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn report_move_out_while_borrowed(
         &mut self,
         location: Location,
@@ -15,6 +15,7 @@
 use rustc_mir_dataflow::move_paths::{InitLocation, LookupResult};
 use rustc_span::{hygiene::DesugaringKind, symbol::sym, Span};
 use rustc_target::abi::VariantIdx;
+use rustc_trait_selection::infer::InferCtxtExt;
 
 use super::borrow_set::BorrowData;
 use super::MirBorrowckCtxt;
@@ -426,6 +427,20 @@ pub(super) fn note_type_does_not_implement_copy(
         }
     }
 
+    /// Whether a moved value of type `ty` could be cloned instead.
+    pub(super) fn implements_clone(&self, ty: Ty<'tcx>) -> bool {
+        let tcx = self.infcx.tcx;
+        let Some(clone_trait_def) = tcx.lang_items().clone_trait() else { return false };
+        self.infcx
+            .type_implements_trait(
+                clone_trait_def,
+                tcx.erase_regions(ty),
+                ty::List::empty(),
+                self.param_env,
+            )
+            .must_apply_modulo_regions()
+    }
+
     pub(super) fn borrowed_content_source(
         &self,
         deref_base: PlaceRef<'tcx>,
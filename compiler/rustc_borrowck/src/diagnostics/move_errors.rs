@@ -436,10 +436,45 @@ fn report_cannot_move_from_borrowed_content(
                     Applicability::MaybeIncorrect,
                 );
             }
+        } else if matches!(use_spans, Some(UseSpans::OtherUse(_)))
+            && !span.from_expansion()
+            && self.implements_clone(ty)
+        {
+            self.suggest_cloning_borrowed_content(&mut err, deref_target_place, span);
         }
         err
     }
 
+    /// Suggest cloning the moved-from place instead. A move out of `*r`, with
+    /// `r: &T`, is suggested as `r.clone()`; anything else just gets `.clone()`
+    /// appended.
+    fn suggest_cloning_borrowed_content(
+        &self,
+        err: &mut DiagnosticBuilder<'a>,
+        deref_target_place: Place<'tcx>,
+        span: Span,
+    ) {
+        let tcx = self.infcx.tcx;
+        let Ok(snippet) = tcx.sess.source_map().span_to_snippet(span) else { return };
+        let deref_base_ty = match deref_target_place.projection.as_ref() {
+            [proj_base @ .., ProjectionElem::Deref] => {
+                Place::ty_from(deref_target_place.local, proj_base, self.body, tcx).ty
+            }
+            _ => return,
+        };
+        let suggestion = match (snippet.strip_prefix('*'), deref_base_ty.kind()) {
+            (Some(base), ty::Ref(_, inner, _)) if !inner.is_ref() => format!("{}.clone()", base),
+            (_, ty::RawPtr(_)) => return,
+            _ => format!("{}.clone()", snippet),
+        };
+        err.span_suggestion_verbose(
+            span,
+            "consider cloning the value if the performance cost is acceptable",
+            suggestion,
+            Applicability::MachineApplicable,
+        );
+    }
+
     fn add_move_hints(
         &self,
         error: GroupedMoveError<'tcx>,
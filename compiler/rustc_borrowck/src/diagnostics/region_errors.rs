@@ -1,6 +1,6 @@
 //! Error reporting machinery for lifetime errors.
 
-use rustc_errors::{Applicability, DiagnosticBuilder};
+use rustc_errors::{pluralize, Applicability, DiagnosticBuilder};
 use rustc_infer::infer::{
     error_reporting::nice_region_error::NiceRegionError,
     error_reporting::unexpected_hidden_region_diagnostic, NllRegionVariableOrigin,
@@ -375,9 +375,63 @@ pub(crate) fn report_region_error(
             }
         }
 
+        if self.infcx.tcx.sess.opts.debugging_opts.explain_region_errors {
+            self.explain_region_error(&mut diag, fr, outlived_fr);
+        }
+
         diag.buffer(&mut self.errors_buffer);
     }
 
+    /// Adds a note for each step of the path in the constraint graph that requires `fr` to
+    /// outlive `outlived_fr`, pointing at the location each constraint comes from.
+    fn explain_region_error(
+        &self,
+        diag: &mut DiagnosticBuilder<'_>,
+        fr: RegionVid,
+        outlived_fr: RegionVid,
+    ) {
+        let Some((path, _)) = self.regioncx.find_constraint_paths_between_regions(fr, |r| {
+            self.regioncx.provides_universal_region(r, fr, outlived_fr)
+        }) else {
+            return;
+        };
+
+        diag.note(&format!(
+            "`{}` must outlive `{}` because of the following {} constraint{}",
+            self.explained_region_name(fr),
+            self.explained_region_name(outlived_fr),
+            path.len(),
+            pluralize!(path.len()),
+        ));
+        for (i, constraint) in path.iter().enumerate() {
+            let origin = match constraint.category.description().trim_end() {
+                "" => String::new(),
+                description => format!(" due to {}", description),
+            };
+            diag.span_note(
+                constraint.locations.span(&self.body),
+                &format!(
+                    "{}. `{}: {}`{}",
+                    i + 1,
+                    self.explained_region_name(constraint.sup),
+                    self.explained_region_name(constraint.sub),
+                    origin,
+                ),
+            );
+        }
+    }
+
+    /// The name `explain_region_error` uses for `r`: the user-facing name of universal regions,
+    /// or the region variable itself otherwise.
+    fn explained_region_name(&self, r: RegionVid) -> String {
+        if self.regioncx.universal_regions().is_universal_region(r) {
+            if let Some(name) = self.give_region_a_name(r) {
+                return name.to_string();
+            }
+        }
+        format!("{:?}", r)
+    }
+
     /// Report a specialized error when `FnMut` closures return a reference to a captured variable.
     /// This function expects `fr` to be local and `outlived_fr` to not be local.
     ///
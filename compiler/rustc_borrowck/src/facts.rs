@@ -1,11 +1,12 @@
-use crate::location::{LocationIndex, LocationTable};
+use crate::location::{LocationIndex, LocationTable, RichLocation};
 use crate::BorrowIndex;
 use polonius_engine::AllFacts as PoloniusFacts;
 use polonius_engine::Atom;
 use rustc_index::vec::Idx;
-use rustc_middle::mir::Local;
+use rustc_middle::mir::{Local, Location};
 use rustc_middle::ty::{RegionVid, TyCtxt};
 use rustc_mir_dataflow::move_paths::MovePathIndex;
+use rustc_serialize::json::{self, Json};
 use std::error::Error;
 use std::fmt::Debug;
 use std::fs::{self, File};
@@ -25,6 +26,40 @@ impl polonius_engine::FactTypes for RustcFacts {
 
 pub type AllFacts = PoloniusFacts<RustcFacts>;
 
+/// Invokes `$body` once per fact table of `$facts`, with `$name` bound to the name of the table and
+/// `$rows` to its rows.
+macro_rules! for_each_fact_table {
+    ($facts:ident, |$name:ident, $rows:ident| $body:block) => {
+        for_each_fact_table!(@tables $facts, $name, $rows, $body, [
+            loan_issued_at,
+            universal_region,
+            cfg_edge,
+            loan_killed_at,
+            subset_base,
+            loan_invalidated_at,
+            var_used_at,
+            var_defined_at,
+            var_dropped_at,
+            use_of_var_derefs_origin,
+            drop_of_var_derefs_origin,
+            child_path,
+            path_is_var,
+            path_assigned_at_base,
+            path_moved_at_base,
+            path_accessed_at_base,
+            known_placeholder_subset,
+            placeholder,
+        ])
+    };
+    (@tables $facts:ident, $name:ident, $rows:ident, $body:block, [$($field:ident,)*]) => {
+        $({
+            let $name = stringify!($field);
+            let $rows = &$facts.$field;
+            $body
+        })*
+    };
+}
+
 crate trait AllFactsExt {
     /// Returns `true` if there is a need to gather `AllFacts` given the
     /// current `-Z` flags.
@@ -35,12 +70,17 @@ fn write_to_dir(
         dir: impl AsRef<Path>,
         location_table: &LocationTable,
     ) -> Result<(), Box<dyn Error>>;
+
+    /// Returns all facts as a JSON object with one array of rows per fact table.
+    fn to_json(&self, location_table: &LocationTable) -> Json;
 }
 
 impl AllFactsExt for AllFacts {
     /// Return
     fn enabled(tcx: TyCtxt<'_>) -> bool {
-        tcx.sess.opts.debugging_opts.nll_facts || tcx.sess.opts.debugging_opts.polonius
+        tcx.sess.opts.debugging_opts.nll_facts
+            || tcx.sess.opts.debugging_opts.dump_borrowck_facts.is_some()
+            || tcx.sess.opts.debugging_opts.polonius
     }
 
     fn write_to_dir(
@@ -51,42 +91,20 @@ fn write_to_dir(
         let dir: &Path = dir.as_ref();
         fs::create_dir_all(dir)?;
         let wr = FactWriter { location_table, dir };
-        macro_rules! write_facts_to_path {
-            ($wr:ident . write_facts_to_path($this:ident . [
-                $($field:ident,)*
-            ])) => {
-                $(
-                    $wr.write_facts_to_path(
-                        &$this.$field,
-                        &format!("{}.facts", stringify!($field))
-                    )?;
-                )*
-            }
-        }
-        write_facts_to_path! {
-            wr.write_facts_to_path(self.[
-                loan_issued_at,
-                universal_region,
-                cfg_edge,
-                loan_killed_at,
-                subset_base,
-                loan_invalidated_at,
-                var_used_at,
-                var_defined_at,
-                var_dropped_at,
-                use_of_var_derefs_origin,
-                drop_of_var_derefs_origin,
-                child_path,
-                path_is_var,
-                path_assigned_at_base,
-                path_moved_at_base,
-                path_accessed_at_base,
-                known_placeholder_subset,
-                placeholder,
-            ])
-        }
+        for_each_fact_table!(self, |name, rows| {
+            wr.write_facts_to_path(rows, &format!("{}.facts", name))?;
+        });
         Ok(())
     }
+
+    fn to_json(&self, location_table: &LocationTable) -> Json {
+        let mut tables = json::Object::new();
+        for_each_fact_table!(self, |name, rows| {
+            let rows = rows.iter().map(|row| row.to_json(location_table)).collect();
+            tables.insert(name.to_string(), Json::Array(rows));
+        });
+        Json::Object(tables)
+    }
 }
 
 impl Atom for BorrowIndex {
@@ -126,6 +144,8 @@ fn write(
         out: &mut dyn Write,
         location_table: &LocationTable,
     ) -> Result<(), Box<dyn Error>>;
+
+    fn to_json(&self, location_table: &LocationTable) -> Json;
 }
 
 impl FactRow for RegionVid {
@@ -136,6 +156,10 @@ fn write(
     ) -> Result<(), Box<dyn Error>> {
         write_row(out, location_table, &[self])
     }
+
+    fn to_json(&self, location_table: &LocationTable) -> Json {
+        json_row(location_table, &[self])
+    }
 }
 
 impl<A, B> FactRow for (A, B)
@@ -150,6 +174,10 @@ fn write(
     ) -> Result<(), Box<dyn Error>> {
         write_row(out, location_table, &[&self.0, &self.1])
     }
+
+    fn to_json(&self, location_table: &LocationTable) -> Json {
+        json_row(location_table, &[&self.0, &self.1])
+    }
 }
 
 impl<A, B, C> FactRow for (A, B, C)
@@ -165,6 +193,10 @@ fn write(
     ) -> Result<(), Box<dyn Error>> {
         write_row(out, location_table, &[&self.0, &self.1, &self.2])
     }
+
+    fn to_json(&self, location_table: &LocationTable) -> Json {
+        json_row(location_table, &[&self.0, &self.1, &self.2])
+    }
 }
 
 impl<A, B, C, D> FactRow for (A, B, C, D)
@@ -181,6 +213,10 @@ fn write(
     ) -> Result<(), Box<dyn Error>> {
         write_row(out, location_table, &[&self.0, &self.1, &self.2, &self.3])
     }
+
+    fn to_json(&self, location_table: &LocationTable) -> Json {
+        json_row(location_table, &[&self.0, &self.1, &self.2, &self.3])
+    }
 }
 
 fn write_row(
@@ -195,18 +231,49 @@ fn write_row(
     Ok(())
 }
 
+fn json_row(location_table: &LocationTable, columns: &[&dyn FactCell]) -> Json {
+    Json::Array(columns.iter().map(|c| c.to_json(location_table)).collect())
+}
+
+/// Returns the fields of a JSON object describing a MIR location: its `block` and
+/// `statement_index`.
+crate fn location_to_json(location: Location) -> json::Object {
+    let mut json = json::Object::new();
+    json.insert("block".to_string(), Json::U64(location.block.as_u32().into()));
+    json.insert("statement_index".to_string(), Json::U64(location.statement_index as u64));
+    json
+}
+
 trait FactCell {
     fn to_string(&self, location_table: &LocationTable) -> String;
+
+    /// Regions, loans, variables and move paths are written as their index, and points as
+    /// their location along with whether they are its `start` or `mid` point.
+    fn to_json(&self, location_table: &LocationTable) -> Json;
 }
 
-impl<A: Debug> FactCell for A {
+impl<A: Debug + Idx> FactCell for A {
     default fn to_string(&self, _location_table: &LocationTable) -> String {
         format!("{:?}", self)
     }
+
+    default fn to_json(&self, _location_table: &LocationTable) -> Json {
+        Json::U64(self.index() as u64)
+    }
 }
 
 impl FactCell for LocationIndex {
     fn to_string(&self, location_table: &LocationTable) -> String {
         format!("{:?}", location_table.to_location(*self))
     }
+
+    fn to_json(&self, location_table: &LocationTable) -> Json {
+        let (point, location) = match location_table.to_location(*self) {
+            RichLocation::Start(location) => ("start", location),
+            RichLocation::Mid(location) => ("mid", location),
+        };
+        let mut json = location_to_json(location);
+        json.insert("point".to_string(), Json::String(point.to_string()));
+        Json::Object(json)
+    }
 }
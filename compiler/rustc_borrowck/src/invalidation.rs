@@ -136,6 +136,21 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                     self.mutate_place(location, *dest, Deep);
                 }
             }
+            TerminatorKind::TailCall { ref func, ref args, fn_span: _ } => {
+                self.consume_operand(location, func);
+                for arg in args {
+                    self.consume_operand(location, arg);
+                }
+
+                // Invalidate all borrows of local places, as for `return`.
+                let borrow_set = self.borrow_set;
+                let start = self.location_table.start_index(location);
+                for (i, data) in borrow_set.iter_enumerated() {
+                    if borrow_of_local_data(data.borrowed_place) {
+                        self.all_facts.loan_invalidated_at.push((start, i));
+                    }
+                }
+            }
             TerminatorKind::Assert { ref cond, expected: _, ref msg, target: _, cleanup: _ } => {
                 self.consume_operand(location, cond);
                 use rustc_middle::mir::AssertKind;
@@ -291,10 +306,10 @@ fn consume_rvalue(&mut self, location: Location, rvalue: &Rvalue<'tcx>) {
                 self.consume_operand(location, operand)
             }
 
-            Rvalue::Len(place) | Rvalue::Discriminant(place) => {
+            Rvalue::Len(place) | Rvalue::Discriminant(place) | Rvalue::CopyForDeref(place) => {
                 let af = match *rvalue {
                     Rvalue::Len(..) => Some(ArtificialField::ArrayLength),
-                    Rvalue::Discriminant(..) => None,
+                    Rvalue::Discriminant(..) | Rvalue::CopyForDeref(..) => None,
                     _ => unreachable!(),
                 };
                 self.access_place(
@@ -6,14 +6,18 @@
 use rustc_infer::infer::InferCtxt;
 use rustc_middle::mir::{create_dump_file, dump_enabled, dump_mir, PassWhere};
 use rustc_middle::mir::{
-    BasicBlock, Body, ClosureOutlivesSubject, ClosureRegionRequirements, LocalKind, Location,
-    Promoted,
+    BasicBlock, Body, BorrowKind, ClosureOutlivesSubject, ClosureRegionRequirements,
+    ConstraintCategory, LocalKind, Location, Place, ProjectionElem, Promoted, ReturnConstraint,
 };
-use rustc_middle::ty::{self, OpaqueTypeKey, RegionKind, RegionVid, Ty};
+use rustc_middle::ty::{self, OpaqueHiddenType, OpaqueTypeKey, RegionKind, RegionVid, TyCtxt};
+use rustc_serialize::json::{self, Json};
+use rustc_session::config::BorrowckFactsFormat;
 use rustc_span::symbol::sym;
+use rustc_span::Span;
 use std::env;
 use std::fmt::Debug;
-use std::io;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
@@ -25,10 +29,10 @@
 use rustc_mir_dataflow::ResultsCursor;
 
 use crate::{
-    borrow_set::BorrowSet,
+    borrow_set::{BorrowSet, TwoPhaseActivation},
     constraint_generation,
     diagnostics::RegionErrors,
-    facts::{AllFacts, AllFactsExt, RustcFacts},
+    facts::{location_to_json, AllFacts, AllFactsExt, RustcFacts},
     invalidation,
     location::LocationTable,
     region_infer::{values::RegionValueElements, RegionInferenceContext},
@@ -44,7 +48,7 @@
 /// closure requirements to propagate, and any generated errors.
 crate struct NllOutput<'tcx> {
     pub regioncx: RegionInferenceContext<'tcx>,
-    pub opaque_type_values: VecMap<OpaqueTypeKey<'tcx>, Ty<'tcx>>,
+    pub opaque_type_values: VecMap<OpaqueTypeKey<'tcx>, OpaqueHiddenType<'tcx>>,
     pub polonius_input: Option<Box<AllFacts>>,
     pub polonius_output: Option<Rc<PoloniusOutput>>,
     pub opt_closure_req: Option<ClosureRegionRequirements<'tcx>>,
@@ -284,6 +288,24 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
             all_facts.write_to_dir(dir_path, location_table).unwrap();
         }
 
+        if let Some(format) = infcx.tcx.sess.opts.debugging_opts.dump_borrowck_facts {
+            if let Err(err) = dump_borrowck_facts(
+                infcx,
+                body,
+                &regioncx,
+                borrow_set,
+                all_facts,
+                location_table,
+                format,
+            ) {
+                infcx.tcx.sess.err(&format!(
+                    "failed to write borrowck facts for `{}`: {}",
+                    infcx.tcx.def_path_str(body.source.def_id()),
+                    err
+                ));
+            }
+        }
+
         if use_polonius {
             let algorithm =
                 env::var("POLONIUS_ALGORITHM").unwrap_or_else(|_| String::from("Hybrid"));
@@ -317,6 +339,190 @@ pub(crate) fn compute_regions<'cx, 'tcx>(
     }
 }
 
+/// The version of the JSON document written by `-Z dump-borrowck-facts=json`. It is
+/// incremented whenever the schema changes in a way that isn't backwards compatible.
+const BORROWCK_FACTS_FORMAT_VERSION: u64 = 1;
+
+/// Writes the input of region inference for `body` to a file in `-Z nll-facts-dir`, for
+/// consumption by external tools. The schema is:
+///
+/// - `version`: `BORROWCK_FACTS_FORMAT_VERSION`.
+/// - `def_path`: the path of the body's owner.
+/// - `region_constraints`: the edges of the region constraint graph, each with the `sup` and
+///   `sub` region indices, the constraint `category` and the `span` it originated from.
+///   `location` is the MIR location the constraint has to hold at, or `null` if it must hold
+///   everywhere.
+/// - `borrows`: the borrow set, indexed like the loans in `facts`. Each borrow has a `kind`,
+///   its `region`, the `borrowed_place` and `assigned_place`, and the locations where it is
+///   reserved and, for two-phase borrows, activated.
+/// - `facts`: every Polonius input relation (including the `subset_base` and
+///   `known_placeholder_subset` outlives facts), as arrays of rows.
+///
+/// Locations are objects with a `block` and a `statement_index`, and places are objects with a
+/// `local` and a list of `projection` elements, each of which has a `kind`. Spans are objects
+/// with the `file` and the one-based `line` and `column`s of their start (`lo_`) and end (`hi_`).
+fn dump_borrowck_facts<'tcx>(
+    infcx: &InferCtxt<'_, 'tcx>,
+    body: &Body<'tcx>,
+    regioncx: &RegionInferenceContext<'tcx>,
+    borrow_set: &BorrowSet<'tcx>,
+    all_facts: &AllFacts,
+    location_table: &LocationTable,
+    format: BorrowckFactsFormat,
+) -> io::Result<()> {
+    let BorrowckFactsFormat::Json = format;
+    let tcx = infcx.tcx;
+    let def_id = body.source.def_id();
+
+    let opt_location = |location: Option<Location>| match location {
+        Some(location) => Json::Object(location_to_json(location)),
+        None => Json::Null,
+    };
+    let region = |vid: RegionVid| Json::U64(vid.as_u32().into());
+
+    let region_constraints = regioncx
+        .outlives_constraints()
+        .map(|constraint| {
+            let mut json = json::Object::new();
+            json.insert("sup".to_string(), region(constraint.sup));
+            json.insert("sub".to_string(), region(constraint.sub));
+            json.insert(
+                "category".to_string(),
+                Json::String(constraint_category_name(constraint.category).to_string()),
+            );
+            json.insert("location".to_string(), opt_location(constraint.locations.from_location()));
+            json.insert("span".to_string(), span_to_json(tcx, constraint.locations.span(body)));
+            Json::Object(json)
+        })
+        .collect();
+
+    let borrows = borrow_set
+        .iter_enumerated()
+        .map(|(index, borrow)| {
+            let activation_location = match borrow.activation_location {
+                TwoPhaseActivation::ActivatedAt(location) => Some(location),
+                TwoPhaseActivation::NotTwoPhase | TwoPhaseActivation::NotActivated => None,
+            };
+            let kind = match borrow.kind {
+                BorrowKind::Shared => "shared",
+                BorrowKind::Shallow => "shallow",
+                BorrowKind::Unique => "unique",
+                BorrowKind::Mut { allow_two_phase_borrow: false } => "mut",
+                BorrowKind::Mut { allow_two_phase_borrow: true } => "two_phase_mut",
+            };
+            let mut json = json::Object::new();
+            json.insert("index".to_string(), Json::U64(index.as_usize() as u64));
+            json.insert("kind".to_string(), Json::String(kind.to_string()));
+            json.insert("region".to_string(), region(borrow.region));
+            json.insert("borrowed_place".to_string(), place_to_json(borrow.borrowed_place));
+            json.insert("assigned_place".to_string(), place_to_json(borrow.assigned_place));
+            json.insert(
+                "reserve_location".to_string(),
+                opt_location(Some(borrow.reserve_location)),
+            );
+            json.insert("activation_location".to_string(), opt_location(activation_location));
+            Json::Object(json)
+        })
+        .collect();
+
+    let mut json = json::Object::new();
+    json.insert("version".to_string(), Json::U64(BORROWCK_FACTS_FORMAT_VERSION));
+    json.insert("def_path".to_string(), Json::String(tcx.def_path_str(def_id)));
+    json.insert("region_constraints".to_string(), Json::Array(region_constraints));
+    json.insert("borrows".to_string(), Json::Array(borrows));
+    json.insert("facts".to_string(), all_facts.to_json(location_table));
+
+    let dir = PathBuf::from(&tcx.sess.opts.debugging_opts.nll_facts_dir);
+    fs::create_dir_all(&dir)?;
+    let file_name = format!("{}.json", tcx.def_path(def_id).to_filename_friendly_no_crate());
+    let mut file = BufWriter::new(File::create(dir.join(file_name))?);
+    writeln!(file, "{}", Json::Object(json).pretty())?;
+    file.flush()
+}
+
+fn constraint_category_name(category: ConstraintCategory) -> &'static str {
+    match category {
+        ConstraintCategory::Return(ReturnConstraint::Normal) => "return",
+        ConstraintCategory::Return(ReturnConstraint::ClosureUpvar(_)) => "return_closure_upvar",
+        ConstraintCategory::Yield => "yield",
+        ConstraintCategory::UseAsConst => "use_as_const",
+        ConstraintCategory::UseAsStatic => "use_as_static",
+        ConstraintCategory::TypeAnnotation => "type_annotation",
+        ConstraintCategory::Cast => "cast",
+        ConstraintCategory::ClosureBounds => "closure_bounds",
+        ConstraintCategory::CallArgument => "call_argument",
+        ConstraintCategory::CopyBound => "copy_bound",
+        ConstraintCategory::SizedBound => "sized_bound",
+        ConstraintCategory::Assignment => "assignment",
+        ConstraintCategory::Usage => "usage",
+        ConstraintCategory::OpaqueType => "opaque_type",
+        ConstraintCategory::ClosureUpvar(_) => "closure_upvar",
+        ConstraintCategory::Predicate(_) => "predicate",
+        ConstraintCategory::Boring => "boring",
+        ConstraintCategory::BoringNoLocation => "boring_no_location",
+        ConstraintCategory::Internal => "internal",
+    }
+}
+
+fn place_to_json(place: Place<'_>) -> Json {
+    let projection = place
+        .projection
+        .iter()
+        .map(|elem| {
+            let mut json = json::Object::new();
+            let mut insert = |key: &str, value: Json| json.insert(key.to_string(), value);
+            match elem {
+                ProjectionElem::Deref => {
+                    insert("kind", Json::String("deref".to_string()));
+                }
+                ProjectionElem::Field(field, _) => {
+                    insert("kind", Json::String("field".to_string()));
+                    insert("field", Json::U64(field.as_u32().into()));
+                }
+                ProjectionElem::Index(local) => {
+                    insert("kind", Json::String("index".to_string()));
+                    insert("local", Json::U64(local.as_u32().into()));
+                }
+                ProjectionElem::ConstantIndex { offset, min_length, from_end } => {
+                    insert("kind", Json::String("constant_index".to_string()));
+                    insert("offset", Json::U64(offset));
+                    insert("min_length", Json::U64(min_length));
+                    insert("from_end", Json::Boolean(from_end));
+                }
+                ProjectionElem::Subslice { from, to, from_end } => {
+                    insert("kind", Json::String("subslice".to_string()));
+                    insert("from", Json::U64(from));
+                    insert("to", Json::U64(to));
+                    insert("from_end", Json::Boolean(from_end));
+                }
+                ProjectionElem::Downcast(_, variant) => {
+                    insert("kind", Json::String("downcast".to_string()));
+                    insert("variant", Json::U64(variant.as_u32().into()));
+                }
+            }
+            Json::Object(json)
+        })
+        .collect();
+
+    let mut json = json::Object::new();
+    json.insert("local".to_string(), Json::U64(place.local.as_u32().into()));
+    json.insert("projection".to_string(), Json::Array(projection));
+    Json::Object(json)
+}
+
+fn span_to_json(tcx: TyCtxt<'_>, span: Span) -> Json {
+    let source_map = tcx.sess.source_map();
+    let lo = source_map.lookup_char_pos(span.lo());
+    let hi = source_map.lookup_char_pos(span.hi());
+    let mut json = json::Object::new();
+    json.insert("file".to_string(), Json::String(lo.file.name.prefer_remapped().to_string()));
+    json.insert("lo_line".to_string(), Json::U64(lo.line as u64));
+    json.insert("lo_column".to_string(), Json::U64(lo.col.0 as u64 + 1));
+    json.insert("hi_line".to_string(), Json::U64(hi.line as u64));
+    json.insert("hi_column".to_string(), Json::U64(hi.col.0 as u64 + 1));
+    Json::Object(json)
+}
+
 pub(super) fn dump_mir_results<'a, 'tcx>(
     infcx: &InferCtxt<'a, 'tcx>,
     body: &Body<'tcx>,
@@ -372,7 +578,7 @@ pub(super) fn dump_annotation<'a, 'tcx>(
     body: &Body<'tcx>,
     regioncx: &RegionInferenceContext<'tcx>,
     closure_region_requirements: &Option<ClosureRegionRequirements<'_>>,
-    opaque_type_values: &VecMap<OpaqueTypeKey<'tcx>, Ty<'tcx>>,
+    opaque_type_values: &VecMap<OpaqueTypeKey<'tcx>, OpaqueHiddenType<'tcx>>,
     errors_buffer: &mut Vec<Diagnostic>,
 ) {
     let tcx = infcx.tcx;
@@ -1953,6 +1953,11 @@ fn check_member_constraints(
         self.universal_regions.as_ref()
     }
 
+    /// The outlives constraints that region inference started from.
+    crate fn outlives_constraints(&self) -> impl Iterator<Item = &OutlivesConstraint<'tcx>> {
+        self.constraints.outlives().iter()
+    }
+
     /// Tries to find the best constraint to blame for the fact that
     /// `R: from_region`, where `R` is some region that meets
     /// `target_test`. This works by following the constraint graph,
@@ -0,0 +1,199 @@
+//! Errors emitted by borrowck.
+use rustc_macros::SessionDiagnostic;
+use rustc_middle::ty::Ty;
+use rustc_span::Span;
+
+#[derive(SessionDiagnostic)]
+#[error = "E0508"]
+#[slug = "borrowck-move-out-of-interior-non-copy"]
+pub struct MoveOutOfInteriorNonCopy<'tcx> {
+    #[message = "cannot move out of type `{ty}`, a non-copy {type_name}"]
+    #[label = "cannot move out of here"]
+    pub span: Span,
+    pub ty: Ty<'tcx>,
+    pub type_name: &'static str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0509"]
+#[slug = "borrowck-move-out-of-interior-of-drop"]
+pub struct MoveOutOfInteriorOfDrop<'tcx> {
+    #[message = "cannot move out of type `{container_ty}`, which implements the `Drop` trait"]
+    #[label = "cannot move out of here"]
+    pub span: Span,
+    pub container_ty: Ty<'tcx>,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0594"]
+#[slug = "borrowck-assign-to-immutable"]
+pub struct AssignToImmutable<'a> {
+    #[message = "cannot assign to {desc}"]
+    pub span: Span,
+    pub desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0597"]
+#[slug = "borrowck-does-not-live-long-enough"]
+pub struct DoesNotLiveLongEnough<'a> {
+    #[message = "{path} does not live long enough"]
+    pub span: Span,
+    pub path: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0626"]
+#[slug = "borrowck-borrow-across-generator-yield"]
+pub struct BorrowAcrossGeneratorYield {
+    #[message = "borrow may still be in use when generator yields"]
+    pub span: Span,
+    #[label = "possible yield occurs here"]
+    pub yield_span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0712"]
+#[slug = "borrowck-thread-local-borrowed-past-end-of-function"]
+pub struct ThreadLocalBorrowedPastEndOfFunction {
+    #[message = "thread-local variable borrowed past end of function"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0713"]
+#[slug = "borrowck-borrow-across-destructor"]
+pub struct BorrowAcrossDestructor {
+    #[message = "borrow may still be in use when destructor runs"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0716"]
+#[slug = "borrowck-temporary-dropped-while-borrowed"]
+pub struct TemporaryDroppedWhileBorrowed {
+    #[message = "temporary value dropped while borrowed"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0505"]
+#[slug = "borrowck-move-out-while-borrowed"]
+pub struct MoveOutWhileBorrowed<'a> {
+    #[message = "cannot move out of {desc} because it is borrowed"]
+    pub span: Span,
+    pub desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0503"]
+#[slug = "borrowck-use-when-mutably-borrowed"]
+pub struct UseWhenMutablyBorrowed<'a> {
+    #[label = "borrow of {borrow_desc} occurs here"]
+    pub borrow_span: Span,
+    #[message = "cannot use {desc} because it was mutably borrowed"]
+    #[label = "use of borrowed {borrow_desc}"]
+    pub span: Span,
+    pub desc: &'a str,
+    pub borrow_desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0381"]
+#[slug = "borrowck-use-of-possibly-uninitialized"]
+pub struct UseOfPossiblyUninitialized<'a> {
+    #[message = "{verb} of possibly-uninitialized variable: `{desc}`"]
+    pub span: Span,
+    pub verb: &'a str,
+    pub desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0506"]
+#[slug = "borrowck-assign-to-borrowed"]
+pub struct AssignToBorrowed<'a> {
+    #[label = "borrow of {desc} occurs here"]
+    pub borrow_span: Span,
+    #[message = "cannot assign to {desc} because it is borrowed"]
+    #[label = "assignment to borrowed {desc} occurs here"]
+    pub span: Span,
+    pub desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0384"]
+#[slug = "borrowck-reassign-immutable"]
+pub struct ReassignImmutable<'a> {
+    #[message = "cannot assign {what} {desc}"]
+    pub span: Span,
+    pub what: &'static str,
+    pub desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0507"]
+#[slug = "borrowck-move-out-of"]
+pub struct MoveOutOf<'a> {
+    #[message = "cannot move out of {desc}"]
+    pub span: Span,
+    pub desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0596"]
+#[slug = "borrowck-borrow-as-mutable"]
+pub struct BorrowAsMutable<'a> {
+    #[message = "cannot borrow {path} as mutable{reason}"]
+    pub span: Span,
+    pub path: &'a str,
+    pub reason: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0510"]
+#[slug = "borrowck-mutate-in-immutable-section"]
+pub struct MutateInImmutableSection<'a> {
+    #[message = "cannot {action} {place} in {section}"]
+    #[label = "cannot {action}"]
+    pub span: Span,
+    #[label = "value is immutable in {section}"]
+    pub immutable_span: Span,
+    pub action: &'a str,
+    pub place: &'a str,
+    pub section: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0515"]
+#[slug = "borrowck-return-reference-to-local"]
+pub struct ReturnReferenceToLocal<'a> {
+    #[message = "cannot {return_kind} {reference_desc} {path_desc}"]
+    #[label = "{return_kind}s a {reference_desc} data owned by the current function"]
+    pub span: Span,
+    pub return_kind: &'a str,
+    pub reference_desc: &'a str,
+    pub path_desc: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0373"]
+#[slug = "borrowck-capture-in-long-lived-closure"]
+pub struct CaptureInLongLivedClosure<'a> {
+    #[label = "{borrowed_path} is borrowed here"]
+    pub capture_span: Span,
+    #[message = "{closure_kind} may outlive the current function, but it borrows \
+                 {borrowed_path}, which is owned by the current function"]
+    #[label = "may outlive borrowed value {borrowed_path}"]
+    pub span: Span,
+    pub closure_kind: &'a str,
+    pub borrowed_path: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0521"]
+#[slug = "borrowck-borrowed-data-escapes-closure"]
+pub struct BorrowedDataEscapesClosure<'a> {
+    #[message = "borrowed data escapes outside of {escapes_from}"]
+    pub span: Span,
+    pub escapes_from: &'a str,
+}
@@ -1416,54 +1416,15 @@ fn check_terminator(
                 }
                 // FIXME: check the values
             }
-            TerminatorKind::Call { ref func, ref args, ref destination, from_hir_call, .. } => {
-                self.check_operand(func, term_location);
-                for arg in args {
-                    self.check_operand(arg, term_location);
-                }
-
-                let func_ty = func.ty(body, tcx);
-                debug!("check_terminator: call, func_ty={:?}", func_ty);
-                let sig = match func_ty.kind() {
-                    ty::FnDef(..) | ty::FnPtr(_) => func_ty.fn_sig(tcx),
-                    _ => {
-                        span_mirbug!(self, term, "call to non-function {:?}", func_ty);
-                        return;
-                    }
-                };
-                let (sig, map) = self.infcx.replace_bound_vars_with_fresh_vars(
-                    term.source_info.span,
-                    LateBoundRegionConversionTime::FnCall,
-                    sig,
-                );
-                let sig = self.normalize(sig, term_location);
-                self.check_call_dest(body, term, &sig, destination, term_location);
-
-                self.prove_predicates(
-                    sig.inputs_and_output
-                        .iter()
-                        .map(|ty| ty::Binder::dummy(ty::PredicateKind::WellFormed(ty.into()))),
-                    term_location.to_locations(),
-                    ConstraintCategory::Boring,
-                );
-
-                // The ordinary liveness rules will ensure that all
-                // regions in the type of the callee are live here. We
-                // then further constrain the late-bound regions that
-                // were instantiated at the call site to be live as
-                // well. The resulting is that all the input (and
-                // output) types in the signature must be live, since
-                // all the inputs that fed into it were live.
-                for &late_bound_region in map.values() {
-                    let region_vid =
-                        self.borrowck_context.universal_regions.to_region_vid(late_bound_region);
-                    self.borrowck_context
-                        .constraints
-                        .liveness_constraints
-                        .add_element(region_vid, term_location);
-                }
-
-                self.check_call_inputs(body, term, &sig, args, term_location, from_hir_call);
+            TerminatorKind::Call { ref func, ref args, destination, from_hir_call, .. } => {
+                let destination = destination.map(|(place, _)| place);
+                self.check_call(body, term, func, args, destination, from_hir_call, term_location);
+            }
+            TerminatorKind::TailCall { ref func, ref args, .. } => {
+                // The callee returns directly to our caller, so its return value ends up
+                // where ours would.
+                let destination = Some(Place::return_place());
+                self.check_call(body, term, func, args, destination, true, term_location);
             }
             TerminatorKind::Assert { ref cond, ref msg, .. } => {
                 self.check_operand(cond, term_location);
@@ -1510,17 +1471,77 @@ fn check_terminator(
         }
     }
 
+    fn check_call(
+        &mut self,
+        body: &Body<'tcx>,
+        term: &Terminator<'tcx>,
+        func: &Operand<'tcx>,
+        args: &[Operand<'tcx>],
+        destination: Option<Place<'tcx>>,
+        from_hir_call: bool,
+        term_location: Location,
+    ) {
+        let tcx = self.tcx();
+        self.check_operand(func, term_location);
+        for arg in args {
+            self.check_operand(arg, term_location);
+        }
+
+        let func_ty = func.ty(body, tcx);
+        debug!("check_terminator: call, func_ty={:?}", func_ty);
+        let sig = match func_ty.kind() {
+            ty::FnDef(..) | ty::FnPtr(_) => func_ty.fn_sig(tcx),
+            _ => {
+                span_mirbug!(self, term, "call to non-function {:?}", func_ty);
+                return;
+            }
+        };
+        let (sig, map) = self.infcx.replace_bound_vars_with_fresh_vars(
+            term.source_info.span,
+            LateBoundRegionConversionTime::FnCall,
+            sig,
+        );
+        let sig = self.normalize(sig, term_location);
+        self.check_call_dest(body, term, &sig, destination, term_location);
+
+        self.prove_predicates(
+            sig.inputs_and_output
+                .iter()
+                .map(|ty| ty::Binder::dummy(ty::PredicateKind::WellFormed(ty.into()))),
+            term_location.to_locations(),
+            ConstraintCategory::Boring,
+        );
+
+        // The ordinary liveness rules will ensure that all
+        // regions in the type of the callee are live here. We
+        // then further constrain the late-bound regions that
+        // were instantiated at the call site to be live as
+        // well. The resulting is that all the input (and
+        // output) types in the signature must be live, since
+        // all the inputs that fed into it were live.
+        for &late_bound_region in map.values() {
+            let region_vid =
+                self.borrowck_context.universal_regions.to_region_vid(late_bound_region);
+            self.borrowck_context
+                .constraints
+                .liveness_constraints
+                .add_element(region_vid, term_location);
+        }
+
+        self.check_call_inputs(body, term, &sig, args, term_location, from_hir_call);
+    }
+
     fn check_call_dest(
         &mut self,
         body: &Body<'tcx>,
         term: &Terminator<'tcx>,
         sig: &ty::FnSig<'tcx>,
-        destination: &Option<(Place<'tcx>, BasicBlock)>,
+        destination: Option<Place<'tcx>>,
         term_location: Location,
     ) {
         let tcx = self.tcx();
-        match *destination {
-            Some((ref dest, _target_block)) => {
+        match destination {
+            Some(dest) => {
                 let dest_ty = dest.ty(body, tcx).ty;
                 let dest_ty = self.normalize(dest_ty, term_location);
                 let category = match dest.as_local() {
@@ -1646,6 +1667,11 @@ fn check_iscleanup(&mut self, body: &Body<'tcx>, block_data: &BasicBlockData<'tc
                     span_mirbug!(self, block_data, "return on cleanup block")
                 }
             }
+            TerminatorKind::TailCall { .. } => {
+                if is_cleanup {
+                    span_mirbug!(self, block_data, "tail call on cleanup block")
+                }
+            }
             TerminatorKind::GeneratorDrop { .. } => {
                 if is_cleanup {
                     span_mirbug!(self, block_data, "generator_drop in cleanup block")
@@ -1959,16 +1985,20 @@ fn check_rvalue(&mut self, body: &Body<'tcx>, rvalue: &Rvalue<'tcx>, location: L
 
                         let ty_fn_ptr_from = tcx.mk_fn_ptr(fn_sig);
 
-                        if let Err(terr) = self.eq_types(
-                            ty,
+                        // The target type may be a supertype of the function's
+                        // signature if it was computed as the LUB of several
+                        // function items, e.g. `fn(&'static u8)` for the LUB of
+                        // `fn foo(_: &u8)` and `fn bar(_: &'static u8)`.
+                        if let Err(terr) = self.sub_types(
                             ty_fn_ptr_from,
+                            ty,
                             location.to_locations(),
                             ConstraintCategory::Cast,
                         ) {
                             span_mirbug!(
                                 self,
                                 rvalue,
-                                "equating {:?} with {:?} yields {:?}",
+                                "relating {:?} with {:?} yields {:?}",
                                 ty_fn_ptr_from,
                                 ty,
                                 terr
@@ -2258,7 +2288,8 @@ fn check_rvalue(&mut self, body: &Body<'tcx>, rvalue: &Rvalue<'tcx>, location: L
             Rvalue::AddressOf(..)
             | Rvalue::ThreadLocalRef(..)
             | Rvalue::Len(..)
-            | Rvalue::Discriminant(..) => {}
+            | Rvalue::Discriminant(..)
+            | Rvalue::CopyForDeref(..) => {}
         }
     }
 
@@ -2279,7 +2310,8 @@ fn rvalue_user_ty(&self, rvalue: &Rvalue<'tcx>) -> Option<UserTypeAnnotationInde
             | Rvalue::CheckedBinaryOp(..)
             | Rvalue::NullaryOp(..)
             | Rvalue::UnaryOp(..)
-            | Rvalue::Discriminant(..) => None,
+            | Rvalue::Discriminant(..)
+            | Rvalue::CopyForDeref(..) => None,
 
             Rvalue::Aggregate(aggregate, _) => match **aggregate {
                 AggregateKind::Adt(_, _, _, user_ty, _) => user_ty,
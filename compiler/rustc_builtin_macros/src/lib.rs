@@ -38,9 +38,11 @@
 mod format_foreign;
 mod global_allocator;
 mod log_syntax;
+mod multiversion;
 mod source_util;
 mod test;
 mod trace_macros;
+mod type_ascribe;
 mod util;
 
 pub mod asm;
@@ -88,6 +90,7 @@ pub fn register_builtin_macros(resolver: &mut dyn ResolverExpand) {
         unreachable: edition_panic::expand_unreachable,
         stringify: source_util::expand_stringify,
         trace_macros: trace_macros::expand_trace_macros,
+        type_ascribe: type_ascribe::expand_type_ascribe,
     }
 
     register_attr! {
@@ -96,6 +99,7 @@ pub fn register_builtin_macros(resolver: &mut dyn ResolverExpand) {
         cfg_eval: cfg_eval::expand,
         derive: derive::Expander,
         global_allocator: global_allocator::expand,
+        multiversion: multiversion::expand,
         test: test::expand_test,
         test_case: test::expand_test_case,
     }
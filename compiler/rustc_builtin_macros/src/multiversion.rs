@@ -0,0 +1,295 @@
+//! Implementation of the `#[multiversion]` attribute, which turns a function into a dispatcher
+//! calling one of several versions of its body, each compiled with different target features:
+//!
+//! ```text
+//! #[multiversion(enable = "avx2,fma", enable = "sse4.2")]
+//! fn sum(xs: &[f32]) -> f32 { BODY }
+//! ```
+//!
+//! becomes, on x86 targets,
+//!
+//! ```text
+//! fn sum(xs: &[f32]) -> f32 {
+//!     #[inline(always)]
+//!     fn body(xs: &[f32]) -> f32 { BODY }
+//!     #[target_feature(enable = "avx2,fma")]
+//!     unsafe fn version0(xs: &[f32]) -> f32 { unsafe { body(xs) } }
+//!     #[target_feature(enable = "sse4.2")]
+//!     unsafe fn version1(xs: &[f32]) -> f32 { unsafe { body(xs) } }
+//!
+//!     if $crate::arch::is_x86_feature_detected!("avx2")
+//!         && $crate::arch::is_x86_feature_detected!("fma")
+//!     {
+//!         return unsafe { version0(xs) };
+//!     }
+//!     if $crate::arch::is_x86_feature_detected!("sse4.2") {
+//!         return unsafe { version1(xs) };
+//!     }
+//!     body(xs)
+//! }
+//! ```
+//!
+//! The body is only there once, and it is inlined into the versions, which compiles it with their
+//! target features. The attribute is defined in `std`, whose `std::arch` macros detect the features
+//! at runtime, for each target architecture which has them.
+
+use rustc_ast::ptr::P;
+use rustc_ast::token;
+use rustc_ast::tokenstream::{DelimSpan, TokenStream, TokenTree};
+use rustc_ast::visit::{self, Visitor};
+use rustc_ast::{self as ast, attr, BinOpKind, BindingMode, BlockCheckMode, Expr, ExprKind};
+use rustc_ast::{FnHeader, GenericArg, GenericParamKind, ItemKind, MacArgs, MacCall};
+use rustc_ast::{MacDelimiter, NestedMetaItem, PatKind, Stmt, StmtKind, Ty, TyKind};
+use rustc_ast::{Unsafe, UnsafeSource};
+use rustc_expand::base::{Annotatable, ExtCtxt};
+use rustc_feature::AttributeTemplate;
+use rustc_parse::validate_attr;
+use rustc_span::symbol::{sym, Ident, Symbol};
+use rustc_span::Span;
+
+use std::mem;
+
+pub fn expand(
+    ecx: &mut ExtCtxt<'_>,
+    span: Span,
+    meta_item: &ast::MetaItem,
+    item: Annotatable,
+) -> Vec<Annotatable> {
+    let template =
+        AttributeTemplate { list: Some(r#"enable = "feature,...""#), ..Default::default() };
+    let attr = ecx.attribute(meta_item.clone());
+    validate_attr::check_builtin_attribute(
+        &ecx.sess.parse_sess,
+        &attr,
+        sym::multiversion,
+        template,
+    );
+
+    let not_fn = |item: Annotatable| {
+        let msg = "the `#[multiversion]` attribute can only be applied to free functions";
+        ecx.sess.parse_sess.span_diagnostic.span_err(item.span(), msg);
+        vec![item]
+    };
+
+    // Allow using `#[multiversion]` on an item statement
+    let (item, is_stmt) = match item {
+        Annotatable::Item(item) if matches!(item.kind, ItemKind::Fn(..)) => (item, false),
+        Annotatable::Stmt(stmt) => match stmt.into_inner() {
+            Stmt { kind: StmtKind::Item(item), .. } if matches!(item.kind, ItemKind::Fn(..)) => {
+                (item, true)
+            }
+            stmt => return not_fn(Annotatable::Stmt(P(stmt))),
+        },
+        item => return not_fn(item),
+    };
+
+    let item = match parse_versions(ecx, meta_item) {
+        Some(versions) => multiversion_fn(ecx, span, item, &versions),
+        None => item,
+    };
+    if is_stmt {
+        vec![Annotatable::Stmt(P(ecx.stmt_item(item.span, item)))]
+    } else {
+        vec![Annotatable::Item(item)]
+    }
+}
+
+/// Returns the target features of each version, along with the span of the string listing them.
+fn parse_versions(ecx: &ExtCtxt<'_>, meta_item: &ast::MetaItem) -> Option<Vec<(Symbol, Span)>> {
+    let handler = &ecx.sess.parse_sess.span_diagnostic;
+    let mut versions = Vec::new();
+    for nested in meta_item.meta_item_list()? {
+        let features = nested
+            .meta_item()
+            .filter(|item| item.has_name(sym::enable))
+            .and_then(|item| Some((item.value_str()?, item.name_value_literal_span()?)));
+        match features {
+            Some(features) => versions.push(features),
+            None => {
+                handler.span_err(nested.span(), r#"expected `enable = "feature,..."`"#);
+                return None;
+            }
+        }
+    }
+    if versions.is_empty() {
+        handler.span_err(meta_item.span, "`#[multiversion]` needs at least one `enable` list");
+        return None;
+    }
+    Some(versions)
+}
+
+/// Rewrites the function `item` into a dispatcher between its versions, or returns it untouched
+/// after reporting why it can't be.
+fn multiversion_fn(
+    ecx: &ExtCtxt<'_>,
+    attr_span: Span,
+    mut item: P<ast::Item>,
+    versions: &[(Symbol, Span)],
+) -> P<ast::Item> {
+    let handler = &ecx.sess.parse_sess.span_diagnostic;
+    let sp = ecx.with_def_site_ctxt(item.span);
+    let attr_sp = ecx.with_def_site_ctxt(attr_span);
+    let (sig, generics, fn_body) = match &mut item.kind {
+        ItemKind::Fn(box ast::Fn { sig, generics, body: Some(body), .. }) => (sig, generics, body),
+        _ => return item,
+    };
+
+    if let ast::Async::Yes { span, .. } = sig.header.asyncness {
+        handler.span_err(span, "`#[multiversion]` can't be applied to `async` functions");
+        return item;
+    }
+    if let ast::Const::Yes(span) = sig.header.constness {
+        handler.span_err(span, "`#[multiversion]` can't be applied to `const` functions");
+        return item;
+    }
+    // Each version would return its own opaque type.
+    let mut find_impl_trait = FindImplTrait(None);
+    visit::walk_fn_decl(&mut find_impl_trait, &sig.decl);
+    if let Some(span) = find_impl_trait.0 {
+        let msg = "`impl Trait` can't be used in the signature of a `#[multiversion]` function";
+        handler.span_err(span, msg);
+        return item;
+    }
+    let detection_macro = match feature_detection_macro(&ecx.sess.target.arch) {
+        Some(detection_macro) => detection_macro,
+        None => {
+            let msg = format!(
+                "`#[multiversion]` is not supported on `{}`, which has no runtime detection of \
+                target features",
+                ecx.sess.target.arch
+            );
+            handler.span_err(attr_span, &msg);
+            return item;
+        }
+    };
+
+    // The functions are nested in the function, so they copy its generics and parameters. The body
+    // keeps the patterns of the parameters, the others pass them on, so they get a name.
+    let body_decl = sig.decl.clone();
+    let mut args = Vec::new();
+    for (i, param) in sig.decl.inputs.iter_mut().enumerate() {
+        let arg = match param.pat.kind {
+            PatKind::Ident(BindingMode::ByValue(_), ident, None) => ident,
+            _ => Ident::from_str_and_span(&format!("arg{}", i), sp),
+        };
+        param.pat = ecx.pat_ident(param.pat.span, arg);
+        args.push(arg);
+    }
+    let nested_fn = |name: Ident, attrs, unsafety, decl, body| {
+        let header = FnHeader { unsafety, ..FnHeader::default() };
+        let sig = ast::FnSig { header, decl, span: sig.span };
+        let generics = generics.clone();
+        let defaultness = ast::Defaultness::Final;
+        let kind = ItemKind::Fn(Box::new(ast::Fn { defaultness, generics, sig, body: Some(body) }));
+        ecx.stmt_item(sp, ecx.item(sp, name, attrs, kind))
+    };
+    let generic_args: Vec<_> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param.kind {
+            GenericParamKind::Lifetime => None,
+            GenericParamKind::Type { .. } => Some(GenericArg::Type(ecx.ty_ident(sp, param.ident))),
+            GenericParamKind::Const { .. } => {
+                Some(GenericArg::Const(ecx.const_ident(sp, param.ident)))
+            }
+        })
+        .collect();
+    let call = |name: Ident| {
+        let path = ecx.path_all(sp, false, vec![name], generic_args.clone());
+        let args = args.iter().map(|&arg| ecx.expr_ident(sp, arg)).collect();
+        ecx.expr_call(sp, ecx.expr_path(path), args)
+    };
+    let unsafe_block = |expr| {
+        let mut block = ecx.block_expr(expr);
+        block.rules = BlockCheckMode::Unsafe(UnsafeSource::CompilerGenerated);
+        block
+    };
+
+    // There is a single copy of the body, which is inlined into the versions, so that it is
+    // compiled with their target features.
+    let body_fn = Ident::from_str_and_span("body", sp);
+    let inline_always = attr::mk_list_item(
+        Ident::new(sym::inline, attr_sp),
+        vec![NestedMetaItem::MetaItem(attr::mk_word_item(Ident::new(sym::always, attr_sp)))],
+    );
+    let unsafety = sig.header.unsafety;
+    let body_span = fn_body.span;
+    let body = mem::replace(fn_body, ecx.block(fn_body.span, Vec::new()));
+    let mut stmts =
+        vec![nested_fn(body_fn, vec![ecx.attribute(inline_always)], unsafety, body_decl, body)];
+
+    let mut dispatch = Vec::new();
+    for (i, &(features, features_span)) in versions.iter().enumerate() {
+        let name = Ident::from_str_and_span(&format!("version{}", i), sp);
+        let enable =
+            attr::mk_name_value_item_str(Ident::new(sym::enable, attr_sp), features, features_span);
+        let target_feature = attr::mk_list_item(
+            Ident::new(sym::target_feature, attr_sp),
+            vec![NestedMetaItem::MetaItem(enable)],
+        );
+        let attrs = vec![ecx.attribute(target_feature)];
+        let version_body = unsafe_block(call(body_fn));
+        stmts.push(nested_fn(name, attrs, Unsafe::Yes(sp), sig.decl.clone(), version_body));
+
+        let detected = features
+            .as_str()
+            .split(',')
+            .map(|feature| {
+                feature_detected(ecx, sp, detection_macro, Symbol::intern(feature), features_span)
+            })
+            .reduce(|lhs, rhs| ecx.expr_binary(sp, BinOpKind::And, lhs, rhs))
+            .unwrap();
+        let ret = ecx.expr(sp, ExprKind::Ret(Some(ecx.expr_block(unsafe_block(call(name))))));
+        dispatch.push(ecx.stmt_expr(ecx.expr_if(sp, detected, ret, None)));
+    }
+    stmts.extend(dispatch);
+    // None of the versions is supported, so the body is used as is.
+    stmts.push(ecx.stmt_expr(call(body_fn)));
+
+    *fn_body = ecx.block(body_span, stmts);
+    item
+}
+
+/// Returns the `std::arch` macro detecting the target features of `arch` at runtime.
+fn feature_detection_macro(arch: &str) -> Option<Symbol> {
+    Some(match arch {
+        "x86" | "x86_64" => sym::is_x86_feature_detected,
+        "aarch64" => sym::is_aarch64_feature_detected,
+        "arm" => sym::is_arm_feature_detected,
+        "mips" => sym::is_mips_feature_detected,
+        "mips64" => sym::is_mips64_feature_detected,
+        "powerpc" => sym::is_powerpc_feature_detected,
+        "powerpc64" => sym::is_powerpc64_feature_detected,
+        "riscv32" | "riscv64" => sym::is_riscv_feature_detected,
+        _ => return None,
+    })
+}
+
+/// Builds `$crate::arch::is_*_feature_detected!("feature")`. The attribute is defined in `std`, so
+/// `$crate` is `std`.
+fn feature_detected(
+    ecx: &ExtCtxt<'_>,
+    sp: Span,
+    detection_macro: Symbol,
+    feature: Symbol,
+    feature_span: Span,
+) -> P<Expr> {
+    let path = ecx.path_global(sp, ecx.std_path(&[sym::arch, detection_macro]));
+    let lit = token::Lit::new(token::LitKind::Str, feature, None);
+    let tokens = TokenStream::from(TokenTree::token(token::Literal(lit), feature_span));
+    let args = MacArgs::Delimited(DelimSpan::from_single(sp), MacDelimiter::Parenthesis, tokens);
+    let mac = MacCall { path, args: P(args), prior_type_ascription: None };
+    ecx.expr(sp, ExprKind::MacCall(mac))
+}
+
+/// Finds the first `impl Trait` type.
+struct FindImplTrait(Option<Span>);
+
+impl<'ast> Visitor<'ast> for FindImplTrait {
+    fn visit_ty(&mut self, ty: &'ast Ty) {
+        if let TyKind::ImplTrait(..) = ty.kind {
+            self.0.get_or_insert(ty.span);
+        }
+        visit::walk_ty(self, ty);
+    }
+}
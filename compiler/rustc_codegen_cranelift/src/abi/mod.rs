@@ -331,6 +331,7 @@ pub(crate) fn codegen_terminator_call<'tcx>(
                 substs,
                 args,
                 destination,
+                span,
             );
             return;
         }
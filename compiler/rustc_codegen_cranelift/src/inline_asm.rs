@@ -11,7 +11,7 @@
 
 pub(crate) fn codegen_inline_asm<'tcx>(
     fx: &mut FunctionCx<'_, '_, 'tcx>,
-    _span: Span,
+    span: Span,
     template: &[InlineAsmTemplatePiece],
     operands: &[InlineAsmOperand<'tcx>],
     options: InlineAsmOptions,
@@ -100,12 +100,29 @@ pub(crate) fn codegen_inline_asm<'tcx>(
         crate::trap::trap_unimplemented(fx, "Alloca is not supported");
     }
 
+    let arch = fx.tcx.sess.asm_arch.unwrap();
+    if !matches!(arch, InlineAsmArch::X86_64 | InlineAsmArch::RiscV32 | InlineAsmArch::RiscV64) {
+        crate::trap::trap_unsupported(fx, span, &format!("inline assembly on {:?}", arch));
+        return;
+    }
+    if operands.iter().any(|operand| {
+        matches!(
+            operand,
+            InlineAsmOperand::Const { .. }
+                | InlineAsmOperand::SymFn { .. }
+                | InlineAsmOperand::SymStatic { .. }
+        )
+    }) {
+        crate::trap::trap_unsupported(fx, span, "`const` and `sym` operands of inline assembly");
+        return;
+    }
+
     let mut inputs = Vec::new();
     let mut outputs = Vec::new();
 
     let mut asm_gen = InlineAssemblyGenerator {
         tcx: fx.tcx,
-        arch: fx.tcx.sess.asm_arch.unwrap(),
+        arch,
         template,
         operands,
         options,
@@ -157,9 +174,9 @@ pub(crate) fn codegen_inline_asm<'tcx>(
                     ));
                 }
             }
-            InlineAsmOperand::Const { value: _ } => todo!(),
-            InlineAsmOperand::SymFn { value: _ } => todo!(),
-            InlineAsmOperand::SymStatic { def_id: _ } => todo!(),
+            InlineAsmOperand::Const { .. }
+            | InlineAsmOperand::SymFn { .. }
+            | InlineAsmOperand::SymStatic { .. } => unreachable!(),
         }
     }
 
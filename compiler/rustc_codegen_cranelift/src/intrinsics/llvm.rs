@@ -11,14 +11,14 @@ pub(crate) fn codegen_llvm_intrinsic_call<'tcx>(
     substs: SubstsRef<'tcx>,
     args: &[mir::Operand<'tcx>],
     destination: Option<(CPlace<'tcx>, BasicBlock)>,
+    span: Span,
 ) {
     let ret = destination.unwrap().0;
 
     intrinsic_match! {
         fx, intrinsic, substs, args,
         _ => {
-            fx.tcx.sess.warn(&format!("unsupported llvm intrinsic {}; replacing with trap", intrinsic));
-            crate::trap::trap_unimplemented(fx, intrinsic);
+            crate::trap::trap_unsupported(fx, span, &format!("the LLVM intrinsic `{}`", intrinsic));
         };
 
         // Used by `_mm_movemask_epi8` and `_mm256_movemask_epi8`
@@ -57,19 +57,15 @@ pub(crate) fn codegen_llvm_intrinsic_call<'tcx>(
                 0 => FloatCC::Equal,
                 1 => FloatCC::LessThan,
                 2 => FloatCC::LessThanOrEqual,
-                7 => {
-                    unimplemented!("Compares corresponding elements in `a` and `b` to see if neither is `NaN`.");
-                }
-                3 => {
-                    unimplemented!("Compares corresponding elements in `a` and `b` to see if either is `NaN`.");
-                }
+                // Neither is NaN.
+                7 => FloatCC::Ordered,
+                // Either is NaN.
+                3 => FloatCC::Unordered,
                 4 => FloatCC::NotEqual,
-                5 => {
-                    unimplemented!("not less than");
-                }
-                6 => {
-                    unimplemented!("not less than or equal");
-                }
+                // Not less than.
+                5 => FloatCC::UnorderedOrGreaterThanOrEqual,
+                // Not less than or equal.
+                6 => FloatCC::UnorderedOrGreaterThan,
                 kind => unreachable!("kind {:?}", kind),
             };
 
@@ -18,7 +18,7 @@ pub(super) fn codegen_simd_intrinsic_call<'tcx>(
     intrinsic_match! {
         fx, intrinsic, substs, args,
         _ => {
-            fx.tcx.sess.span_fatal(span, &format!("Unknown SIMD intrinsic {}", intrinsic));
+            crate::trap::trap_unsupported(fx, span, &format!("the SIMD intrinsic `{}`", intrinsic));
         };
 
         simd_cast, (c a) {
@@ -450,9 +450,197 @@ pub(super) fn codegen_simd_intrinsic_call<'tcx>(
             }
         };
 
+        // simd_gather<T, U, V>(values: T, pointers: U, mask: V) -> T
+        simd_gather, (c val, c ptr, c mask) {
+            validate_simd_type!(fx, intrinsic, span, val.layout().ty);
+            validate_simd_type!(fx, intrinsic, span, ptr.layout().ty);
+            validate_simd_type!(fx, intrinsic, span, mask.layout().ty);
+
+            let (lane_count, lane_ty) = val.layout().ty.simd_size_and_type(fx.tcx);
+            let (ptr_lane_count, _ptr_lane_ty) = ptr.layout().ty.simd_size_and_type(fx.tcx);
+            let (mask_lane_count, _mask_lane_ty) = mask.layout().ty.simd_size_and_type(fx.tcx);
+            assert_eq!(lane_count, ptr_lane_count);
+            assert_eq!(lane_count, mask_lane_count);
+            assert_eq!(val.layout(), ret.layout());
+
+            let lane_layout = fx.layout_of(lane_ty);
+            let lane_clif_ty = fx.clif_type(lane_ty).unwrap();
+
+            for lane in 0..lane_count {
+                let val_lane = val.value_lane(fx, lane).load_scalar(fx);
+                let ptr_lane = ptr.value_lane(fx, lane).load_scalar(fx);
+                let mask_lane = mask.value_lane(fx, lane).load_scalar(fx);
+
+                // Only access the memory of enabled lanes.
+                let if_enabled = fx.bcx.create_block();
+                let next = fx.bcx.create_block();
+                let res_lane = fx.bcx.append_block_param(next, lane_clif_ty);
+
+                fx.bcx.ins().brnz(mask_lane, if_enabled, &[]);
+                fx.bcx.ins().jump(next, &[val_lane]);
+                fx.bcx.seal_block(if_enabled);
+
+                fx.bcx.switch_to_block(if_enabled);
+                let loaded = fx.bcx.ins().load(lane_clif_ty, MemFlags::trusted(), ptr_lane, 0);
+                fx.bcx.ins().jump(next, &[loaded]);
+                fx.bcx.seal_block(next);
+
+                fx.bcx.switch_to_block(next);
+                ret.place_lane(fx, lane).write_cvalue(fx, CValue::by_val(res_lane, lane_layout));
+            }
+        };
+
+        // simd_scatter<T, U, V>(values: T, pointers: U, mask: V)
+        simd_scatter, (c val, c ptr, c mask) {
+            validate_simd_type!(fx, intrinsic, span, val.layout().ty);
+            validate_simd_type!(fx, intrinsic, span, ptr.layout().ty);
+            validate_simd_type!(fx, intrinsic, span, mask.layout().ty);
+
+            let (lane_count, _lane_ty) = val.layout().ty.simd_size_and_type(fx.tcx);
+            let (ptr_lane_count, _ptr_lane_ty) = ptr.layout().ty.simd_size_and_type(fx.tcx);
+            let (mask_lane_count, _mask_lane_ty) = mask.layout().ty.simd_size_and_type(fx.tcx);
+            assert_eq!(lane_count, ptr_lane_count);
+            assert_eq!(lane_count, mask_lane_count);
+
+            for lane in 0..lane_count {
+                let val_lane = val.value_lane(fx, lane).load_scalar(fx);
+                let ptr_lane = ptr.value_lane(fx, lane).load_scalar(fx);
+                let mask_lane = mask.value_lane(fx, lane).load_scalar(fx);
+
+                // Only access the memory of enabled lanes.
+                let if_enabled = fx.bcx.create_block();
+                let next = fx.bcx.create_block();
+
+                fx.bcx.ins().brnz(mask_lane, if_enabled, &[]);
+                fx.bcx.ins().jump(next, &[]);
+                fx.bcx.seal_block(if_enabled);
+
+                fx.bcx.switch_to_block(if_enabled);
+                fx.bcx.ins().store(MemFlags::trusted(), val_lane, ptr_lane, 0);
+                fx.bcx.ins().jump(next, &[]);
+                fx.bcx.seal_block(next);
+
+                fx.bcx.switch_to_block(next);
+            }
+        };
+
+        // simd_masked_load<T, U, V>(mask: T, pointer: U, values: V) -> V
+        simd_masked_load, (c mask, v ptr, c val) {
+            validate_simd_type!(fx, intrinsic, span, mask.layout().ty);
+            validate_simd_type!(fx, intrinsic, span, val.layout().ty);
+
+            let (lane_count, lane_ty) = val.layout().ty.simd_size_and_type(fx.tcx);
+            let (mask_lane_count, _mask_lane_ty) = mask.layout().ty.simd_size_and_type(fx.tcx);
+            assert_eq!(lane_count, mask_lane_count);
+            assert_eq!(val.layout(), ret.layout());
+
+            let lane_layout = fx.layout_of(lane_ty);
+            let lane_clif_ty = fx.clif_type(lane_ty).unwrap();
+
+            for lane in 0..lane_count {
+                let val_lane = val.value_lane(fx, lane).load_scalar(fx);
+                let mask_lane = mask.value_lane(fx, lane).load_scalar(fx);
+                let offset = i32::try_from(lane * lane_layout.size.bytes()).unwrap();
+
+                // Only access the memory of enabled lanes.
+                let if_enabled = fx.bcx.create_block();
+                let next = fx.bcx.create_block();
+                let res_lane = fx.bcx.append_block_param(next, lane_clif_ty);
+
+                fx.bcx.ins().brnz(mask_lane, if_enabled, &[]);
+                fx.bcx.ins().jump(next, &[val_lane]);
+                fx.bcx.seal_block(if_enabled);
+
+                fx.bcx.switch_to_block(if_enabled);
+                let loaded = fx.bcx.ins().load(lane_clif_ty, MemFlags::trusted(), ptr, offset);
+                fx.bcx.ins().jump(next, &[loaded]);
+                fx.bcx.seal_block(next);
+
+                fx.bcx.switch_to_block(next);
+                ret.place_lane(fx, lane).write_cvalue(fx, CValue::by_val(res_lane, lane_layout));
+            }
+        };
+
+        // simd_masked_store<T, U, V>(mask: T, pointer: U, values: V)
+        simd_masked_store, (c mask, v ptr, c val) {
+            validate_simd_type!(fx, intrinsic, span, mask.layout().ty);
+            validate_simd_type!(fx, intrinsic, span, val.layout().ty);
+
+            let (lane_count, lane_ty) = val.layout().ty.simd_size_and_type(fx.tcx);
+            let (mask_lane_count, _mask_lane_ty) = mask.layout().ty.simd_size_and_type(fx.tcx);
+            assert_eq!(lane_count, mask_lane_count);
+
+            let lane_layout = fx.layout_of(lane_ty);
+
+            for lane in 0..lane_count {
+                let val_lane = val.value_lane(fx, lane).load_scalar(fx);
+                let mask_lane = mask.value_lane(fx, lane).load_scalar(fx);
+                let offset = i32::try_from(lane * lane_layout.size.bytes()).unwrap();
+
+                // Only access the memory of enabled lanes.
+                let if_enabled = fx.bcx.create_block();
+                let next = fx.bcx.create_block();
+
+                fx.bcx.ins().brnz(mask_lane, if_enabled, &[]);
+                fx.bcx.ins().jump(next, &[]);
+                fx.bcx.seal_block(if_enabled);
+
+                fx.bcx.switch_to_block(if_enabled);
+                fx.bcx.ins().store(MemFlags::trusted(), val_lane, ptr, offset);
+                fx.bcx.ins().jump(next, &[]);
+                fx.bcx.seal_block(next);
+
+                fx.bcx.switch_to_block(next);
+            }
+        };
+
+        // simd_swizzle_dyn<T, U>(values: T, indices: U) -> T
+        simd_swizzle_dyn, (c val, c idx) {
+            validate_simd_type!(fx, intrinsic, span, val.layout().ty);
+            validate_simd_type!(fx, intrinsic, span, idx.layout().ty);
+
+            let (lane_count, lane_ty) = val.layout().ty.simd_size_and_type(fx.tcx);
+            let (idx_lane_count, _idx_lane_ty) = idx.layout().ty.simd_size_and_type(fx.tcx);
+            assert_eq!(lane_count, idx_lane_count);
+            assert_eq!(val.layout(), ret.layout());
+
+            let lane_layout = fx.layout_of(lane_ty);
+            let lane_clif_ty = fx.clif_type(lane_ty).unwrap();
+            let zero = CValue::const_val(fx, lane_layout, ty::ScalarInt::null(lane_layout.size))
+                .load_scalar(fx);
+            let (val_ptr, _) = val.force_stack(fx);
+
+            for lane in 0..lane_count {
+                let idx_lane = idx.value_lane(fx, lane).load_scalar(fx);
+                let idx_lane = clif_intcast(fx, idx_lane, fx.pointer_type, false);
+                let in_range = fx.bcx.ins().icmp_imm(
+                    IntCC::UnsignedLessThan,
+                    idx_lane,
+                    i64::try_from(lane_count).unwrap(),
+                );
+
+                // Only read the lanes of `val` for the indices that are in range.
+                let if_in_range = fx.bcx.create_block();
+                let next = fx.bcx.create_block();
+                let res_lane = fx.bcx.append_block_param(next, lane_clif_ty);
+
+                fx.bcx.ins().brnz(in_range, if_in_range, &[]);
+                fx.bcx.ins().jump(next, &[zero]);
+                fx.bcx.seal_block(if_in_range);
+
+                fx.bcx.switch_to_block(if_in_range);
+                let offset = fx.bcx.ins().imul_imm(idx_lane, lane_layout.size.bytes() as i64);
+                let loaded =
+                    val_ptr.offset_value(fx, offset).load(fx, lane_clif_ty, MemFlags::trusted());
+                fx.bcx.ins().jump(next, &[loaded]);
+                fx.bcx.seal_block(next);
+
+                fx.bcx.switch_to_block(next);
+                ret.place_lane(fx, lane).write_cvalue(fx, CValue::by_val(res_lane, lane_layout));
+            }
+        };
+
         // simd_saturating_*
         // simd_bitmask
-        // simd_scatter
-        // simd_gather
     }
 }
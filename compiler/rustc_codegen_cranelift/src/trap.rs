@@ -65,6 +65,23 @@ pub(crate) fn trap_unimplemented(fx: &mut FunctionCx<'_, '_, '_>, msg: impl AsRe
     fx.bcx.ins().trapnz(true_, TrapCode::User(!0));
 }
 
+/// Use this when the code uses something this backend doesn't support yet, like inline assembly on
+/// some targets or some SIMD intrinsics. This warns about it at compile time, and the code traps
+/// when it is reached at runtime, so that crates which only use it in code paths that are not taken
+/// with this backend still work. Like `trap_unimplemented`, this does not fill the current block.
+///
+/// Trap code: user65535
+pub(crate) fn trap_unsupported(fx: &mut FunctionCx<'_, '_, '_>, span: Span, what: &str) {
+    fx.tcx.sess.span_warn(
+        span,
+        &format!(
+            "{} is not yet supported by the Cranelift codegen backend; replacing it with a trap",
+            what
+        ),
+    );
+    trap_unimplemented(fx, &format!("{} is not supported", what));
+}
+
 /// Like `trap_unimplemented` but returns a fake value of the specified type.
 ///
 /// Trap code: user65535
@@ -15,6 +15,7 @@
 use rustc_codegen_ssa::traits::DebugInfoMethods;
 use rustc_session::config::DebugInfo;
 use rustc_span::Symbol;
+use rustc_target::spec::StackProtector;
 
 use crate::GccContext;
 use crate::builder::Builder;
@@ -85,6 +86,12 @@ fn module_codegen(tcx: TyCtxt<'_>, cgu_name: Symbol) -> ModuleCodegen<GccContext
         context.add_command_line_option("-fno-semantic-interposition");
         // NOTE: Rust relies on LLVM not doing TBAA (https://github.com/rust-lang/unsafe-code-guidelines/issues/292).
         context.add_command_line_option("-fno-strict-aliasing");
+        match tcx.sess.stack_protector() {
+            StackProtector::None => (),
+            StackProtector::Basic => context.add_command_line_option("-fstack-protector"),
+            StackProtector::Strong => context.add_command_line_option("-fstack-protector-strong"),
+            StackProtector::All => context.add_command_line_option("-fstack-protector-all"),
+        }
         if env::var("CG_GCCJIT_DUMP_CODE").as_deref() == Ok("1") {
             context.set_dump_code_on_compile(true);
         }
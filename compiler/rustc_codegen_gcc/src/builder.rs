@@ -444,7 +444,7 @@ fn switch(&mut self, value: RValue<'gcc>, default_block: Block<'gcc>, cases: imp
         self.block.expect("block").end_with_switch(None, value, default_block, &gcc_cases);
     }
 
-    fn invoke(&mut self, _typ: Type<'gcc>, _func: RValue<'gcc>, _args: &[RValue<'gcc>], then: Block<'gcc>, catch: Block<'gcc>, _funclet: Option<&Funclet>) -> RValue<'gcc> {
+    fn invoke(&mut self, _typ: Type<'gcc>, _func: RValue<'gcc>, _args: &[RValue<'gcc>], then: Block<'gcc>, catch: Block<'gcc>, _funclet: Option<&Funclet>, _kcfi_typeid: Option<u32>) -> RValue<'gcc> {
         let condition = self.context.new_rvalue_from_int(self.bool_type, 0);
         self.llbb().end_with_conditional(None, condition, then, catch);
         self.context.new_rvalue_from_int(self.int_type, 0)
@@ -916,6 +916,10 @@ fn type_metadata(&mut self, _function: RValue<'gcc>, _typeid: String) {
         // Unsupported.
     }
 
+    fn kcfi_type_metadata(&mut self, _function: RValue<'gcc>, _typeid: u32) {
+        // Unsupported.
+    }
+
     fn typeid_metadata(&mut self, _typeid: String) -> RValue<'gcc> {
         // Unsupported.
         self.context.new_rvalue_from_int(self.int_type, 0)
@@ -1374,7 +1378,7 @@ fn lifetime_end(&mut self, _ptr: RValue<'gcc>, _size: Size) {
         // TODO(antoyo)
     }
 
-    fn call(&mut self, _typ: Type<'gcc>, func: RValue<'gcc>, args: &[RValue<'gcc>], funclet: Option<&Funclet>) -> RValue<'gcc> {
+    fn call(&mut self, _typ: Type<'gcc>, func: RValue<'gcc>, args: &[RValue<'gcc>], funclet: Option<&Funclet>, _kcfi_typeid: Option<u32>) -> RValue<'gcc> {
         // FIXME(antoyo): remove when having a proper API.
         let gcc_func = unsafe { std::mem::transmute(func) };
         if self.functions.borrow().values().find(|value| **value == gcc_func).is_some() {
@@ -1404,6 +1408,10 @@ fn apply_attrs_to_cleanup_callsite(&mut self, _llret: RValue<'gcc>) {
         unimplemented!();
     }
 
+    fn set_must_tail_call(&mut self, _llret: RValue<'gcc>) {
+        self.cx.tcx.sess.fatal("guaranteed tail calls are not supported by the GCC backend");
+    }
+
     fn set_span(&mut self, _span: Span) {}
 
     fn from_immediate(&mut self, val: Self::Value) -> Self::Value {
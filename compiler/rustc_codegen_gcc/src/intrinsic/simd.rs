@@ -1,4 +1,4 @@
-use gccjit::{RValue, Type};
+use gccjit::{ComparisonOp, Function, RValue, ToRValue, Type};
 use rustc_codegen_ssa::base::compare_simd_types;
 use rustc_codegen_ssa::common::{TypeKind, span_invalid_monomorphization_error};
 use rustc_codegen_ssa::mir::operand::OperandRef;
@@ -133,6 +133,149 @@ macro_rules! require_simd {
         ));
     }
 
+    // Spill a vector to a local, and return a pointer to its first lane.
+    fn lanes<'gcc>(bx: &Builder<'_, 'gcc, '_>, func: Function<'gcc>, vector: RValue<'gcc>, name: &str) -> RValue<'gcc> {
+        let local = func.new_local(None, vector.get_type(), name);
+        bx.llbb().add_assignment(None, local, vector);
+        let lane_ptr_type = bx.element_type(vector.get_type()).make_pointer();
+        bx.context.new_cast(None, local.get_address(None), lane_ptr_type)
+    }
+
+    if let sym::simd_gather | sym::simd_scatter | sym::simd_masked_load | sym::simd_masked_store = name {
+        // NOTE: libgccjit has no masked memory accesses, so these are lowered lane by lane, only
+        // accessing the memory of the lanes that are enabled in the mask.
+        let is_gather_scatter = matches!(name, sym::simd_gather | sym::simd_scatter);
+        let is_store = matches!(name, sym::simd_scatter | sym::simd_masked_store);
+        let (values_index, mask_index) = if is_gather_scatter { (0, 2) } else { (2, 0) };
+
+        require_simd!(arg_tys[values_index], "values");
+        require_simd!(arg_tys[mask_index], "mask");
+        let (len, _) = arg_tys[values_index].simd_size_and_type(bx.tcx());
+        let (mask_len, _) = arg_tys[mask_index].simd_size_and_type(bx.tcx());
+        require!(
+            mask_len == len,
+            "expected mask `{}` with length {} (same as the values `{}`), found length {}",
+            arg_tys[mask_index],
+            len,
+            arg_tys[values_index],
+            mask_len
+        );
+        if is_gather_scatter {
+            require_simd!(arg_tys[1], "pointers");
+            let (pointers_len, _) = arg_tys[1].simd_size_and_type(bx.tcx());
+            require!(
+                pointers_len == len,
+                "expected pointers `{}` with length {} (same as the values `{}`), found length {}",
+                arg_tys[1],
+                len,
+                arg_tys[values_index],
+                pointers_len
+            );
+        }
+
+        let func = bx.current_func();
+        let values = args[values_index].immediate();
+        let values_lanes = lanes(bx, func, values, "masked_values");
+        let mask_lanes = lanes(bx, func, args[mask_index].immediate(), "masked_mask");
+        let pointers_lanes =
+            if is_gather_scatter {
+                Some(lanes(bx, func, args[1].immediate(), "masked_pointers"))
+            }
+            else {
+                None
+            };
+        let result = func.new_local(None, values.get_type(), "masked_result");
+        bx.llbb().add_assignment(None, result, values);
+        let result_lanes = bx.context.new_cast(None, result.get_address(None), values_lanes.get_type());
+
+        for lane in 0..len {
+            let index = bx.context.new_rvalue_from_long(bx.usize_type, lane as i64);
+            let mask_lane = bx.context.new_array_access(None, mask_lanes, index).to_rvalue();
+            let zero = bx.context.new_rvalue_zero(mask_lane.get_type());
+            let enabled = bx.context.new_comparison(None, ComparisonOp::NotEquals, mask_lane, zero);
+
+            let then_block = func.new_block("lane_enabled");
+            let after_block = func.new_block("lane_after");
+            bx.llbb().end_with_conditional(None, enabled, then_block, after_block);
+
+            let memory =
+                match pointers_lanes {
+                    Some(pointers_lanes) => bx.context.new_array_access(None, pointers_lanes, index).to_rvalue().dereference(None),
+                    None => bx.context.new_array_access(None, args[1].immediate(), index),
+                };
+            if is_store {
+                let value = bx.context.new_array_access(None, values_lanes, index).to_rvalue();
+                then_block.add_assignment(None, memory, value);
+            }
+            else {
+                let result_lane = bx.context.new_array_access(None, result_lanes, index);
+                then_block.add_assignment(None, result_lane, memory.to_rvalue());
+            }
+            then_block.end_with_jump(None, after_block);
+
+            // NOTE: since jumps were added in a place rustc does not
+            // expect, the current blocks in the state need to be updated.
+            *bx.current_block.borrow_mut() = Some(after_block);
+            bx.block = Some(after_block);
+        }
+
+        // NOTE: the stores return `()`, so their value is never used.
+        return Ok(result.to_rvalue());
+    }
+
+    if name == sym::simd_swizzle_dyn {
+        // NOTE: libgccjit has no dynamic shuffle, so this is lowered lane by lane, only reading the
+        // lanes of the values that are selected by an index in range.
+        require_simd!(arg_tys[0], "values");
+        require_simd!(arg_tys[1], "indices");
+        let (len, _) = arg_tys[0].simd_size_and_type(bx.tcx());
+        let (indices_len, _) = arg_tys[1].simd_size_and_type(bx.tcx());
+        require!(
+            indices_len == len,
+            "expected indices `{}` with length {} (same as the values `{}`), found length {}",
+            arg_tys[1],
+            len,
+            arg_tys[0],
+            indices_len
+        );
+
+        let func = bx.current_func();
+        let values = args[0].immediate();
+        let values_lanes = lanes(bx, func, values, "swizzle_values");
+        let indices_lanes = lanes(bx, func, args[1].immediate(), "swizzle_indices");
+        let result = func.new_local(None, values.get_type(), "swizzle_result");
+        let result_lanes = bx.context.new_cast(None, result.get_address(None), values_lanes.get_type());
+        let zero = bx.context.new_rvalue_zero(bx.element_type(values.get_type()));
+        let len_value = bx.context.new_rvalue_from_long(bx.usize_type, len as i64);
+
+        for lane in 0..len {
+            let lane_index = bx.context.new_rvalue_from_long(bx.usize_type, lane as i64);
+            let index = bx.context.new_array_access(None, indices_lanes, lane_index).to_rvalue();
+            let index = bx.context.new_cast(None, index, bx.usize_type);
+            let in_range = bx.context.new_comparison(None, ComparisonOp::LessThan, index, len_value);
+
+            let in_range_block = func.new_block("swizzle_in_range");
+            let out_of_range_block = func.new_block("swizzle_out_of_range");
+            let after_block = func.new_block("swizzle_after");
+            bx.llbb().end_with_conditional(None, in_range, in_range_block, out_of_range_block);
+
+            let value = bx.context.new_array_access(None, values_lanes, index).to_rvalue();
+            let result_lane = bx.context.new_array_access(None, result_lanes, lane_index);
+            in_range_block.add_assignment(None, result_lane, value);
+            in_range_block.end_with_jump(None, after_block);
+            let result_lane = bx.context.new_array_access(None, result_lanes, lane_index);
+            out_of_range_block.add_assignment(None, result_lane, zero);
+            out_of_range_block.end_with_jump(None, after_block);
+
+            // NOTE: since jumps were added in a place rustc does not
+            // expect, the current blocks in the state need to be updated.
+            *bx.current_block.borrow_mut() = Some(after_block);
+            bx.block = Some(after_block);
+        }
+
+        return Ok(result.to_rvalue());
+    }
+
     macro_rules! arith_binary {
         ($($name: ident: $($($p: ident),* => $call: ident),*;)*) => {
             $(if name == sym::$name {
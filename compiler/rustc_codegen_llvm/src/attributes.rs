@@ -9,7 +9,7 @@
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc_middle::ty::layout::HasTyCtxt;
 use rustc_middle::ty::{self, TyCtxt};
-use rustc_session::config::OptLevel;
+use rustc_session::config::{BranchProtection, OptLevel, PAuthKey, PacRet};
 use rustc_session::Session;
 use rustc_target::spec::abi::Abi;
 use rustc_target::spec::{FramePointer, SanitizerSet, StackProbeType, StackProtector};
@@ -108,6 +108,46 @@ fn set_instrument_function<'ll>(cx: &CodegenCx<'ll, '_>, llfn: &'ll Value) {
             &mcount_name,
         );
     }
+    if let Some(options) = &cx.sess().opts.debugging_opts.instrument_xray {
+        // XRay instrumentation is similar to __cyg_profile_func_{enter,exit}.
+        // Function prologue and epilogue are instrumented with NOP sleds,
+        // a runtime library later replaces them with detours into tracing code.
+        if options.always {
+            llvm::AddFunctionAttrStringValue(
+                llfn,
+                llvm::AttributePlace::Function,
+                cstr!("function-instrument"),
+                cstr!("xray-always"),
+            );
+        }
+        if options.never {
+            llvm::AddFunctionAttrStringValue(
+                llfn,
+                llvm::AttributePlace::Function,
+                cstr!("function-instrument"),
+                cstr!("xray-never"),
+            );
+        }
+        if options.ignore_loops {
+            llvm::AddFunctionAttrString(llfn, Function, cstr!("xray-ignore-loops"));
+        }
+        // LLVM will not choose the default for us, but rather requires specific
+        // threshold in absence of "xray-always". Use the same default as Clang.
+        let threshold = options.instruction_threshold.unwrap_or(200);
+        let threshold = CString::new(threshold.to_string()).unwrap();
+        llvm::AddFunctionAttrStringValue(
+            llfn,
+            llvm::AttributePlace::Function,
+            cstr!("xray-instruction-threshold"),
+            &threshold,
+        );
+        if options.skip_entry {
+            llvm::AddFunctionAttrString(llfn, Function, cstr!("xray-skip-entry"));
+        }
+        if options.skip_exit {
+            llvm::AddFunctionAttrString(llfn, Function, cstr!("xray-skip-exit"));
+        }
+    }
 }
 
 fn set_probestack<'ll>(cx: &CodegenCx<'ll, '_>, llfn: &'ll Value) {
@@ -172,6 +212,38 @@ fn set_stackprotector<'ll>(cx: &CodegenCx<'ll, '_>, llfn: &'ll Value) {
     sspattr.apply_llfn(Function, llfn)
 }
 
+/// Tells LLVM to emit BTI landing pads and to sign return addresses in `llfn`. The module flags
+/// set in `create_module` do the same, but they are lost when the function is inlined into a
+/// module without them during cross-language LTO.
+fn set_branch_protection<'ll>(cx: &CodegenCx<'ll, '_>, llfn: &'ll Value) {
+    if cx.sess().target.arch != "aarch64" {
+        return;
+    }
+    let BranchProtection { bti, pac_ret } = cx.sess().opts.debugging_opts.branch_protection;
+    if bti {
+        llvm::AddFunctionAttrStringValue(
+            llfn,
+            llvm::AttributePlace::Function,
+            cstr!("branch-target-enforcement"),
+            cstr!("true"),
+        );
+    }
+    if let Some(PacRet { leaf, key }) = pac_ret {
+        llvm::AddFunctionAttrStringValue(
+            llfn,
+            llvm::AttributePlace::Function,
+            cstr!("sign-return-address"),
+            if leaf { cstr!("all") } else { cstr!("non-leaf") },
+        );
+        llvm::AddFunctionAttrStringValue(
+            llfn,
+            llvm::AttributePlace::Function,
+            cstr!("sign-return-address-key"),
+            if key == PAuthKey::A { cstr!("a_key") } else { cstr!("b_key") },
+        );
+    }
+}
+
 pub fn apply_target_cpu_attr<'ll>(cx: &CodegenCx<'ll, '_>, llfn: &'ll Value) {
     let target_cpu = SmallCStr::new(llvm_util::target_cpu(cx.tcx.sess));
     llvm::AddFunctionAttrStringValue(
@@ -282,11 +354,12 @@ pub fn from_fn_attrs<'ll, 'tcx>(
         llvm::AddFunctionAttrString(llfn, Function, cstr!("use-sample-profile"));
     }
 
-    // FIXME: none of these three functions interact with source level attributes.
+    // FIXME: none of these functions interact with source level attributes.
     set_frame_pointer_type(cx, llfn);
     set_instrument_function(cx, llfn);
     set_probestack(cx, llfn);
     set_stackprotector(cx, llfn);
+    set_branch_protection(cx, llfn);
 
     if codegen_fn_attrs.flags.contains(CodegenFnAttrFlags::COLD) {
         Attribute::Cold.apply_llfn(Function, llfn);
@@ -8,11 +8,12 @@
 use std::ptr;
 use std::str;
 
+use crate::common;
 use crate::llvm::archive_ro::{ArchiveRO, Child};
 use crate::llvm::{self, ArchiveKind, LLVMMachineType, LLVMRustCOFFShortExport};
 use rustc_codegen_ssa::back::archive::ArchiveBuilder;
 use rustc_data_structures::temp_dir::MaybeTempDir;
-use rustc_session::cstore::{DllCallingConvention, DllImport};
+use rustc_session::cstore::DllImport;
 use rustc_session::Session;
 
 struct ArchiveConfig<'a> {
@@ -159,14 +160,14 @@ fn inject_dll_import_lib(
             output_path.with_extension("lib")
         };
 
-        let mingw_gnu_toolchain = self.config.sess.target.llvm_target.ends_with("pc-windows-gnu");
+        let mingw_gnu_toolchain = common::is_mingw_gnu_toolchain(&self.config.sess.target);
 
         let import_name_and_ordinal_vector: Vec<(String, Option<u16>)> = dll_imports
             .iter()
             .map(|import: &DllImport| {
                 if self.config.sess.target.arch == "x86" {
                     (
-                        LlvmArchiveBuilder::i686_decorated_name(import, mingw_gnu_toolchain),
+                        common::i686_decorated_name(import, mingw_gnu_toolchain, false),
                         import.ordinal,
                     )
                 } else {
@@ -214,6 +215,8 @@ fn inject_dll_import_lib(
                     lib_name,
                     "-l",
                     output_path.to_str().unwrap(),
+                    // The names are already decorated exactly as the symbols we reference.
+                    "--no-leading-underscore",
                 ])
                 .output();
 
@@ -405,22 +408,6 @@ fn build_with_llvm(&mut self, kind: ArchiveKind) -> io::Result<()> {
             ret
         }
     }
-
-    fn i686_decorated_name(import: &DllImport, mingw: bool) -> String {
-        let name = import.name;
-        let prefix = if mingw { "" } else { "_" };
-
-        match import.calling_convention {
-            DllCallingConvention::C => format!("{}{}", prefix, name),
-            DllCallingConvention::Stdcall(arg_list_size) => {
-                format!("{}{}@{}", prefix, name, arg_list_size)
-            }
-            DllCallingConvention::Fastcall(arg_list_size) => format!("@{}@{}", name, arg_list_size),
-            DllCallingConvention::Vectorcall(arg_list_size) => {
-                format!("{}@@{}", name, arg_list_size)
-            }
-        }
-    }
 }
 
 fn string_to_io_error(s: String) -> io::Error {
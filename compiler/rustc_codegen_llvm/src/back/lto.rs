@@ -22,7 +22,7 @@
 use tracing::{debug, info};
 
 use std::ffi::{CStr, CString};
-use std::fs::File;
+use std::fs::{self, File};
 use std::io;
 use std::iter;
 use std::path::Path;
@@ -497,6 +497,16 @@ fn thin_lto(
 
         info!("thin LTO data created");
 
+        if cgcx.opts.debugging_opts.dump_thinlto_summary {
+            let summary = build_string(|s| llvm::LLVMRustThinLTODumpSummary(data.0, s))
+                .expect("non-UTF-8 ThinLTO summary");
+            let path = cgcx.output_filenames.with_extension("thinlto-summary.txt");
+            if let Err(err) = fs::write(&path, summary) {
+                let msg = format!("failed to write ThinLTO summary to {}: {}", path.display(), err);
+                diag_handler.err(&msg);
+            }
+        }
+
         let (key_map_path, prev_key_map, curr_key_map) = if let Some(ref incr_comp_session_dir) =
             cgcx.incr_comp_session_dir
         {
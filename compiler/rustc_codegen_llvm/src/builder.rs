@@ -222,12 +222,15 @@ fn invoke(
         then: &'ll BasicBlock,
         catch: &'ll BasicBlock,
         funclet: Option<&Funclet<'ll>>,
+        kcfi_typeid: Option<u32>,
     ) -> &'ll Value {
         debug!("invoke {:?} with args ({:?})", llfn, args);
 
         let args = self.check_call("invoke", llty, llfn, args);
-        let bundle = funclet.map(|funclet| funclet.bundle());
-        let bundle = bundle.as_ref().map(|b| &*b.raw);
+        let funclet_bundle = funclet.map(|funclet| funclet.bundle());
+        let kcfi_bundle = kcfi_typeid.map(|typeid| self.kcfi_operand_bundle(typeid));
+        let bundles: Vec<_> =
+            funclet_bundle.into_iter().chain(kcfi_bundle.as_ref()).map(|b| &*b.raw).collect();
 
         unsafe {
             llvm::LLVMRustBuildInvoke(
@@ -238,7 +241,8 @@ fn invoke(
                 args.len() as c_uint,
                 then,
                 catch,
-                bundle,
+                bundles.as_ptr(),
+                bundles.len() as c_uint,
                 UNNAMED,
             )
         }
@@ -608,7 +612,7 @@ fn type_metadata(&mut self, function: &'ll Value, typeid: String) {
         let typeid_metadata = self.typeid_metadata(typeid);
         let v = [self.const_usize(0), typeid_metadata];
         unsafe {
-            llvm::LLVMGlobalSetMetadata(
+            llvm::LLVMRustGlobalAddMetadata(
                 function,
                 llvm::MD_type as c_uint,
                 llvm::LLVMValueAsMetadata(llvm::LLVMMDNodeInContext(
@@ -620,6 +624,21 @@ fn type_metadata(&mut self, function: &'ll Value, typeid: String) {
         }
     }
 
+    fn kcfi_type_metadata(&mut self, function: &'ll Value, typeid: u32) {
+        let v = [self.const_u32(typeid)];
+        unsafe {
+            llvm::LLVMRustGlobalAddMetadata(
+                function,
+                llvm::MD_kcfi_type as c_uint,
+                llvm::LLVMValueAsMetadata(llvm::LLVMMDNodeInContext(
+                    self.cx.llcx,
+                    v.as_ptr(),
+                    v.len() as c_uint,
+                )),
+            )
+        }
+    }
+
     fn typeid_metadata(&mut self, typeid: String) -> Self::Value {
         unsafe {
             llvm::LLVMMDStringInContext(
@@ -1137,7 +1156,8 @@ fn instrprof_increment(
                 llfn,
                 args.as_ptr() as *const &llvm::Value,
                 args.len() as c_uint,
-                None,
+                [].as_ptr(),
+                0 as c_uint,
             );
         }
     }
@@ -1148,12 +1168,15 @@ fn call(
         llfn: &'ll Value,
         args: &[&'ll Value],
         funclet: Option<&Funclet<'ll>>,
+        kcfi_typeid: Option<u32>,
     ) -> &'ll Value {
         debug!("call {:?} with args ({:?})", llfn, args);
 
         let args = self.check_call("call", llty, llfn, args);
-        let bundle = funclet.map(|funclet| funclet.bundle());
-        let bundle = bundle.as_ref().map(|b| &*b.raw);
+        let funclet_bundle = funclet.map(|funclet| funclet.bundle());
+        let kcfi_bundle = kcfi_typeid.map(|typeid| self.kcfi_operand_bundle(typeid));
+        let bundles: Vec<_> =
+            funclet_bundle.into_iter().chain(kcfi_bundle.as_ref()).map(|b| &*b.raw).collect();
 
         unsafe {
             llvm::LLVMRustBuildCall(
@@ -1162,7 +1185,8 @@ fn call(
                 llfn,
                 args.as_ptr() as *const &llvm::Value,
                 args.len() as c_uint,
-                bundle,
+                bundles.as_ptr(),
+                bundles.len() as c_uint,
             )
         }
     }
@@ -1181,6 +1205,10 @@ fn apply_attrs_to_cleanup_callsite(&mut self, llret: &'ll Value) {
             llvm::Attribute::NoInline.apply_callsite(llvm::AttributePlace::Function, llret);
         }
     }
+
+    fn set_must_tail_call(&mut self, llret: &'ll Value) {
+        unsafe { llvm::LLVMRustSetMustTailCall(llret) }
+    }
 }
 
 impl<'ll> StaticBuilderMethods for Builder<'_, 'll, '_> {
@@ -1201,6 +1229,12 @@ pub fn llfn(&self) -> &'ll Value {
         unsafe { llvm::LLVMGetBasicBlockParent(self.llbb()) }
     }
 
+    /// Creates the operand bundle through which KCFI checks that the callee of an indirect call
+    /// has the type id `typeid`.
+    fn kcfi_operand_bundle(&self, typeid: u32) -> llvm::OperandBundleDef<'ll> {
+        llvm::OperandBundleDef::new("kcfi", &[self.const_u32(typeid)])
+    }
+
     fn position_at_start(&mut self, llbb: &'ll BasicBlock) {
         unsafe {
             llvm::LLVMRustPositionBuilderAtStart(self.llbuilder, llbb);
@@ -1381,7 +1415,7 @@ pub fn va_arg(&mut self, list: &'ll Value, ty: &'ll Type) -> &'ll Value {
 
     crate fn call_intrinsic(&mut self, intrinsic: &str, args: &[&'ll Value]) -> &'ll Value {
         let (ty, f) = self.cx.get_intrinsic(intrinsic);
-        self.call(ty, f, args, None)
+        self.call(ty, f, args, None, None)
     }
 
     fn call_lifetime_intrinsic(&mut self, intrinsic: &str, ptr: &'ll Value, size: Size) {
@@ -1459,7 +1493,7 @@ fn fptoint_sat(
             };
             let f =
                 self.declare_cfn(&name, llvm::UnnamedAddr::No, self.type_func(&[src_ty], dest_ty));
-            Some(self.call(self.type_func(&[src_ty], dest_ty), f, &[val], None))
+            Some(self.call(self.type_func(&[src_ty], dest_ty), f, &[val], None, None))
         } else {
             None
         }
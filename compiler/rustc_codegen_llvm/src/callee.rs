@@ -6,6 +6,7 @@
 
 use crate::abi::FnAbiLlvmExt;
 use crate::attributes;
+use crate::common;
 use crate::context::CodegenCx;
 use crate::llvm;
 use crate::value::Value;
@@ -79,13 +80,33 @@ pub fn get_fn<'ll, 'tcx>(cx: &CodegenCx<'ll, 'tcx>, instance: Instance<'tcx>) ->
             llfn
         }
     } else {
-        let llfn = cx.declare_fn(sym, fn_abi);
+        let instance_def_id = instance.def_id();
+        let dllimport = if tcx.sess.target.arch == "x86" {
+            common::get_dllimport(tcx, instance_def_id, sym)
+        } else {
+            None
+        };
+        let llfn = match dllimport {
+            // The import libraries we generate for `#[link(kind = "raw-dylib")]` on i686 name
+            // their symbols after the decorated names of the imports, which may differ from
+            // how LLVM would decorate `sym` (e.g. with `import_name_type = "undecorated"`), so
+            // declare the import by its exact name.
+            Some(dllimport) => {
+                let mingw = common::is_mingw_gnu_toolchain(&tcx.sess.target);
+                let name = common::i686_decorated_name(dllimport, mingw, true);
+                let llfn = cx.declare_fn(&name, fn_abi);
+                // Reference the import through its `__imp_` pointer even on MinGW, where we'd
+                // otherwise leave it to the linker: the names we generate may start with `L`,
+                // which LLVM takes for a private symbol of its own if it isn't dllimport.
+                unsafe { llvm::LLVMSetDLLStorageClass(llfn, llvm::DLLStorageClass::DllImport) };
+                llfn
+            }
+            None => cx.declare_fn(sym, fn_abi),
+        };
         debug!("get_fn: not casting pointer!");
 
         attributes::from_fn_attrs(cx, llfn, instance);
 
-        let instance_def_id = instance.def_id();
-
         // Apply an appropriate linkage/visibility value to our item that we
         // just declared.
         //
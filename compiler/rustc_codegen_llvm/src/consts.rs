@@ -264,7 +264,9 @@ impl<'ll> CodegenCx<'ll, '_> {
         let g = if def_id.is_local() && !self.tcx.is_foreign_item(def_id) {
             let llty = self.layout_of(ty).llvm_type(self);
             if let Some(g) = self.get_declared_value(sym) {
-                if self.val_ty(g) != self.type_ptr_to(llty) {
+                // Compare the types of the values, not of the pointers to them: with opaque
+                // pointers, all pointers have the same type.
+                if unsafe { llvm::LLVMGlobalGetValueType(g) } != llty {
                     span_bug!(self.tcx.def_span(def_id), "Conflicting types for static");
                 }
             }
@@ -111,6 +111,7 @@ fn codegen_intrinsic_call(
                     simple_fn,
                     &args.iter().map(|arg| arg.immediate()).collect::<Vec<_>>(),
                     None,
+                    None,
                 )
             }
             sym::likely => {
@@ -422,7 +423,7 @@ fn try_intrinsic<'ll>(
 ) {
     if bx.sess().panic_strategy() == PanicStrategy::Abort {
         let try_func_ty = bx.type_func(&[bx.type_i8p()], bx.type_void());
-        bx.call(try_func_ty, try_func, &[data], None);
+        bx.call(try_func_ty, try_func, &[data], None, None);
         // Return 0 unconditionally from the intrinsic call;
         // we can never unwind.
         let ret_align = bx.tcx().data_layout.i32_align.abi;
@@ -521,7 +522,7 @@ fn codegen_msvc_try<'ll>(
         let ptr_align = bx.tcx().data_layout.pointer_align.abi;
         let slot = bx.alloca(bx.type_i8p(), ptr_align);
         let try_func_ty = bx.type_func(&[bx.type_i8p()], bx.type_void());
-        bx.invoke(try_func_ty, try_func, &[data], normal.llbb(), catchswitch.llbb(), None);
+        bx.invoke(try_func_ty, try_func, &[data], normal.llbb(), catchswitch.llbb(), None, None);
 
         normal.ret(bx.const_i32(0));
 
@@ -563,14 +564,14 @@ fn codegen_msvc_try<'ll>(
         let funclet = catchpad_rust.catch_pad(cs, &[tydesc, flags, slot]);
         let ptr = catchpad_rust.load(bx.type_i8p(), slot, ptr_align);
         let catch_ty = bx.type_func(&[bx.type_i8p(), bx.type_i8p()], bx.type_void());
-        catchpad_rust.call(catch_ty, catch_func, &[data, ptr], Some(&funclet));
+        catchpad_rust.call(catch_ty, catch_func, &[data, ptr], Some(&funclet), None);
         catchpad_rust.catch_ret(&funclet, caught.llbb());
 
         // The flag value of 64 indicates a "catch-all".
         let flags = bx.const_i32(64);
         let null = bx.const_null(bx.type_i8p());
         let funclet = catchpad_foreign.catch_pad(cs, &[null, flags, null]);
-        catchpad_foreign.call(catch_ty, catch_func, &[data, null], Some(&funclet));
+        catchpad_foreign.call(catch_ty, catch_func, &[data, null], Some(&funclet), None);
         catchpad_foreign.catch_ret(&funclet, caught.llbb());
 
         caught.ret(bx.const_i32(1));
@@ -578,7 +579,7 @@ fn codegen_msvc_try<'ll>(
 
     // Note that no invoke is used here because by definition this function
     // can't panic (that's what it's catching).
-    let ret = bx.call(llty, llfn, &[try_func, data, catch_func], None);
+    let ret = bx.call(llty, llfn, &[try_func, data, catch_func], None, None);
     let i32_align = bx.tcx().data_layout.i32_align.abi;
     bx.store(ret, dest, i32_align);
 }
@@ -621,7 +622,7 @@ fn codegen_gnu_try<'ll>(
         let data = llvm::get_param(bx.llfn(), 1);
         let catch_func = llvm::get_param(bx.llfn(), 2);
         let try_func_ty = bx.type_func(&[bx.type_i8p()], bx.type_void());
-        bx.invoke(try_func_ty, try_func, &[data], then.llbb(), catch.llbb(), None);
+        bx.invoke(try_func_ty, try_func, &[data], then.llbb(), catch.llbb(), None, None);
         then.ret(bx.const_i32(0));
 
         // Type indicator for the exception being thrown.
@@ -636,13 +637,13 @@ fn codegen_gnu_try<'ll>(
         catch.add_clause(vals, tydesc);
         let ptr = catch.extract_value(vals, 0);
         let catch_ty = bx.type_func(&[bx.type_i8p(), bx.type_i8p()], bx.type_void());
-        catch.call(catch_ty, catch_func, &[data, ptr], None);
+        catch.call(catch_ty, catch_func, &[data, ptr], None, None);
         catch.ret(bx.const_i32(1));
     });
 
     // Note that no invoke is used here because by definition this function
     // can't panic (that's what it's catching).
-    let ret = bx.call(llty, llfn, &[try_func, data, catch_func], None);
+    let ret = bx.call(llty, llfn, &[try_func, data, catch_func], None, None);
     let i32_align = bx.tcx().data_layout.i32_align.abi;
     bx.store(ret, dest, i32_align);
 }
@@ -682,7 +683,7 @@ fn codegen_emcc_try<'ll>(
         let data = llvm::get_param(bx.llfn(), 1);
         let catch_func = llvm::get_param(bx.llfn(), 2);
         let try_func_ty = bx.type_func(&[bx.type_i8p()], bx.type_void());
-        bx.invoke(try_func_ty, try_func, &[data], then.llbb(), catch.llbb(), None);
+        bx.invoke(try_func_ty, try_func, &[data], then.llbb(), catch.llbb(), None, None);
         then.ret(bx.const_i32(0));
 
         // Type indicator for the exception being thrown.
@@ -724,13 +725,13 @@ fn codegen_emcc_try<'ll>(
         let catch_data = catch.bitcast(catch_data, bx.type_i8p());
 
         let catch_ty = bx.type_func(&[bx.type_i8p(), bx.type_i8p()], bx.type_void());
-        catch.call(catch_ty, catch_func, &[data, catch_data], None);
+        catch.call(catch_ty, catch_func, &[data, catch_data], None, None);
         catch.ret(bx.const_i32(1));
     });
 
     // Note that no invoke is used here because by definition this function
     // can't panic (that's what it's catching).
-    let ret = bx.call(llty, llfn, &[try_func, data, catch_func], None);
+    let ret = bx.call(llty, llfn, &[try_func, data, catch_func], None, None);
     let i32_align = bx.tcx().data_layout.i32_align.abi;
     bx.store(ret, dest, i32_align);
 }
@@ -1199,8 +1200,13 @@ macro_rules! return_error {
         };
         let llvm_name = &format!("llvm.{0}.v{1}{2}", intr_name, in_len, elem_ty_str);
         let f = bx.declare_cfn(llvm_name, llvm::UnnamedAddr::No, fn_ty);
-        let c =
-            bx.call(fn_ty, f, &args.iter().map(|arg| arg.immediate()).collect::<Vec<_>>(), None);
+        let c = bx.call(
+            fn_ty,
+            f,
+            &args.iter().map(|arg| arg.immediate()).collect::<Vec<_>>(),
+            None,
+            None,
+        );
         Ok(c)
     }
 
@@ -1276,6 +1282,22 @@ fn llvm_vector_ty<'ll>(
         cx.type_vector(elem_ty, vec_len)
     }
 
+    // This counts how many pointers
+    fn ptr_count(t: Ty<'_>) -> usize {
+        match t.kind() {
+            ty::RawPtr(p) => 1 + ptr_count(p.ty),
+            _ => 0,
+        }
+    }
+
+    // Non-ptr type
+    fn non_ptr(t: Ty<'_>) -> Ty<'_> {
+        match t.kind() {
+            ty::RawPtr(p) => non_ptr(p.ty),
+            _ => t,
+        }
+    }
+
     if name == sym::simd_gather {
         // simd_gather(values: <N x T>, pointers: <N x *_ T>,
         //             mask: <N x i{M}>) -> <N x T>
@@ -1316,22 +1338,6 @@ fn llvm_vector_ty<'ll>(
         // The return type must match the first argument type
         require!(ret_ty == in_ty, "expected return type `{}`, found `{}`", in_ty, ret_ty);
 
-        // This counts how many pointers
-        fn ptr_count(t: Ty<'_>) -> usize {
-            match t.kind() {
-                ty::RawPtr(p) => 1 + ptr_count(p.ty),
-                _ => 0,
-            }
-        }
-
-        // Non-ptr type
-        fn non_ptr(t: Ty<'_>) -> Ty<'_> {
-            match t.kind() {
-                ty::RawPtr(p) => non_ptr(p.ty),
-                _ => t,
-            }
-        }
-
         // The second argument must be a simd vector with an element type that's a pointer
         // to the element type of the first argument
         let (_, element_ty0) = arg_tys[0].simd_size_and_type(bx.tcx());
@@ -1399,8 +1405,13 @@ fn non_ptr(t: Ty<'_>) -> Ty<'_> {
             llvm_elem_vec_ty,
         );
         let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
-        let v =
-            bx.call(fn_ty, f, &[args[1].immediate(), alignment, mask, args[0].immediate()], None);
+        let v = bx.call(
+            fn_ty,
+            f,
+            &[args[1].immediate(), alignment, mask, args[0].immediate()],
+            None,
+            None,
+        );
         return Ok(v);
     }
 
@@ -1440,22 +1451,6 @@ fn non_ptr(t: Ty<'_>) -> Ty<'_> {
             element_len2
         );
 
-        // This counts how many pointers
-        fn ptr_count(t: Ty<'_>) -> usize {
-            match t.kind() {
-                ty::RawPtr(p) => 1 + ptr_count(p.ty),
-                _ => 0,
-            }
-        }
-
-        // Non-ptr type
-        fn non_ptr(t: Ty<'_>) -> Ty<'_> {
-            match t.kind() {
-                ty::RawPtr(p) => non_ptr(p.ty),
-                _ => t,
-            }
-        }
-
         // The second argument must be a simd vector with an element type that's a pointer
         // to the element type of the first argument
         let (_, element_ty0) = arg_tys[0].simd_size_and_type(bx.tcx());
@@ -1525,11 +1520,188 @@ fn non_ptr(t: Ty<'_>) -> Ty<'_> {
         let fn_ty =
             bx.type_func(&[llvm_elem_vec_ty, llvm_pointer_vec_ty, alignment_ty, mask_ty], ret_t);
         let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
-        let v =
-            bx.call(fn_ty, f, &[args[0].immediate(), args[1].immediate(), alignment, mask], None);
+        let v = bx.call(
+            fn_ty,
+            f,
+            &[args[0].immediate(), args[1].immediate(), alignment, mask],
+            None,
+            None,
+        );
         return Ok(v);
     }
 
+    if name == sym::simd_masked_load || name == sym::simd_masked_store {
+        // simd_masked_load(mask: <N x i{M}>, pointer: *const T, values: <N x T>) -> <N x T>
+        // simd_masked_store(mask: <N x i{M}>, pointer: *mut T, values: <N x T>) -> ()
+        // * N: number of elements in the input vectors
+        // * T: type of the element to load or store
+        // * M: any integer width is supported, will be truncated to i1
+        // Only the elements of lanes that are enabled in `mask` are loaded from, or stored to,
+        // the contiguous memory behind `pointer`; the memory of the other lanes isn't accessed.
+        // A masked load takes the elements of the disabled lanes from `values`.
+        let is_store = name == sym::simd_masked_store;
+
+        // The mask and the values must be simd vector types
+        require_simd!(in_ty, "first");
+        require_simd!(arg_tys[2], "third");
+
+        // Of the same length:
+        let (values_len, values_elem) = arg_tys[2].simd_size_and_type(bx.tcx());
+        require!(
+            in_len == values_len,
+            "expected {} argument with length {} (same as input type `{}`), \
+             found `{}` with length {}",
+            "third",
+            in_len,
+            in_ty,
+            arg_tys[2],
+            values_len
+        );
+
+        // A load returns a vector of the type of the values
+        if !is_store {
+            require!(
+                ret_ty == arg_tys[2],
+                "expected return type `{}`, found `{}`",
+                arg_tys[2],
+                ret_ty
+            );
+        }
+
+        // The second argument must be a pointer to the element type of the values, and must be
+        // mutable for a store
+        let pointer_matches = match arg_tys[1].kind() {
+            ty::RawPtr(p) => p.ty == values_elem && (!is_store || p.mutbl == hir::Mutability::Mut),
+            _ => false,
+        };
+        require!(
+            pointer_matches,
+            "expected second argument `{}` to be a pointer to the element type `{}` \
+                of the third argument `{}`, found `{}` != `{} {}`",
+            arg_tys[1],
+            values_elem,
+            arg_tys[2],
+            arg_tys[1],
+            if is_store { "*mut" } else { "*_" },
+            values_elem
+        );
+
+        // The element type of the first argument must be a signed integer type of any width:
+        match in_elem.kind() {
+            ty::Int(_) => (),
+            _ => {
+                require!(
+                    false,
+                    "expected element type `{}` of first argument `{}` \
+                        to be a signed integer type",
+                    in_elem,
+                    in_ty
+                );
+            }
+        }
+
+        // Alignment of T, must be a constant integer value:
+        let alignment_ty = bx.type_i32();
+        let alignment = bx.const_i32(bx.align_of(values_elem).bytes() as i32);
+
+        // Truncate the mask vector to a vector of i1s:
+        let (mask, mask_ty) = {
+            let i1 = bx.type_i1();
+            let i1xn = bx.type_vector(i1, in_len);
+            (bx.trunc(args[0].immediate(), i1xn), i1xn)
+        };
+
+        // Type of the vector of elements:
+        let pointer_count = ptr_count(values_elem);
+        let underlying_ty = non_ptr(values_elem);
+        let llvm_elem_vec_ty = llvm_vector_ty(bx, underlying_ty, in_len, pointer_count);
+        let llvm_elem_vec_str = llvm_vector_str(underlying_ty, in_len, pointer_count, bx);
+
+        // The intrinsics take a pointer to the whole vector:
+        let llvm_pointer_ty = bx.type_ptr_to(llvm_elem_vec_ty);
+        let pointer = bx.pointercast(args[1].immediate(), llvm_pointer_ty);
+
+        let v = if is_store {
+            let llvm_intrinsic =
+                format!("llvm.masked.store.{}.p0{}", llvm_elem_vec_str, llvm_elem_vec_str);
+            let fn_ty = bx.type_func(
+                &[llvm_elem_vec_ty, llvm_pointer_ty, alignment_ty, mask_ty],
+                bx.type_void(),
+            );
+            let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, f, &[args[2].immediate(), pointer, alignment, mask], None, None)
+        } else {
+            let llvm_intrinsic =
+                format!("llvm.masked.load.{}.p0{}", llvm_elem_vec_str, llvm_elem_vec_str);
+            let fn_ty = bx.type_func(
+                &[llvm_pointer_ty, alignment_ty, mask_ty, llvm_elem_vec_ty],
+                llvm_elem_vec_ty,
+            );
+            let f = bx.declare_cfn(&llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
+            bx.call(fn_ty, f, &[pointer, alignment, mask, args[2].immediate()], None, None)
+        };
+        return Ok(v);
+    }
+
+    if name == sym::simd_swizzle_dyn {
+        // simd_swizzle_dyn(values: <N x T>, indices: <N x u{M}>) -> <N x T>
+        // * N: number of elements in the input vectors
+        // * T: type of the elements
+        // * M: any unsigned integer width is supported
+        // Lane `i` of the result is the lane `indices[i]` of `values`, or zero if `indices[i]` is
+        // out of range.
+        require_simd!(in_ty, "first");
+        require_simd!(arg_tys[1], "second");
+
+        // Of the same length:
+        let (indices_len, indices_elem) = arg_tys[1].simd_size_and_type(bx.tcx());
+        require!(
+            in_len == indices_len,
+            "expected {} argument with length {} (same as input type `{}`), \
+             found `{}` with length {}",
+            "second",
+            in_len,
+            in_ty,
+            arg_tys[1],
+            indices_len
+        );
+
+        // The return type must match the first argument type
+        require!(ret_ty == in_ty, "expected return type `{}`, found `{}`", in_ty, ret_ty);
+
+        // The element type of the second argument must be an unsigned integer type of any width:
+        match indices_elem.kind() {
+            ty::Uint(_) => (),
+            _ => {
+                require!(
+                    false,
+                    "expected element type `{}` of second argument `{}` \
+                        to be an unsigned integer type",
+                    indices_elem,
+                    arg_tys[1]
+                );
+            }
+        }
+
+        // LLVM has no dynamic shuffle, so select each lane on its own. `extractelement` with an
+        // out of range index is poison, which the `select` then replaces with zero.
+        let values = args[0].immediate();
+        let indices = args[1].immediate();
+        let zero = bx.const_null(bx.element_type(bx.val_ty(values)));
+        let index_ty = bx.element_type(bx.val_ty(indices));
+        let len = bx.const_uint(index_ty, in_len);
+        let mut result = bx.const_undef(bx.val_ty(values));
+        for lane in 0..in_len {
+            let lane = bx.const_i32(lane as i32);
+            let index = bx.extract_element(indices, lane);
+            let in_range = bx.icmp(IntPredicate::IntULT, index, len);
+            let elem = bx.extract_element(values, index);
+            let elem = bx.select(in_range, elem, zero);
+            result = bx.insert_element(result, elem, lane);
+        }
+        return Ok(result);
+    }
+
     macro_rules! arith_red {
         ($name:ident : $integer_reduce:ident, $float_reduce:ident, $ordered:expr, $op:ident,
          $identity:expr) => {
@@ -1862,7 +2034,7 @@ macro_rules! arith_unary {
 
         let fn_ty = bx.type_func(&[vec_ty, vec_ty], vec_ty);
         let f = bx.declare_cfn(llvm_intrinsic, llvm::UnnamedAddr::No, fn_ty);
-        let v = bx.call(fn_ty, f, &[lhs, rhs], None);
+        let v = bx.call(fn_ty, f, &[lhs, rhs], None, None);
         return Ok(v);
     }
 
@@ -245,6 +245,11 @@ pub fn new() -> Box<dyn CodegenBackend> {
 impl CodegenBackend for LlvmCodegenBackend {
     fn init(&self, sess: &Session) {
         llvm_util::init(sess); // Make sure llvm is inited
+
+        // KCFI operand bundles and type ids are only understood by LLVM 16 and later.
+        if sess.is_sanitizer_kcfi_enabled() && llvm_util::get_version() < (16, 0, 0) {
+            sess.err("`-Zsanitizer=kcfi` requires LLVM 16 or later");
+        }
     }
 
     fn print(&self, req: PrintRequest, sess: &Session) {
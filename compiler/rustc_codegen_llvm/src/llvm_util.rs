@@ -116,6 +116,10 @@ fn llvm_arg_to_arg_name(full_arg: &str) -> &str {
         // Use non-zero `import-instr-limit` multiplier for cold callsites.
         add("-import-cold-multiplier=0.1", false);
 
+        if let Some(limit) = sess.opts.debugging_opts.thinlto_import_instr_limit {
+            add(&format!("-import-instr-limit={}", limit), false);
+        }
+
         for arg in sess_args {
             add(&(*arg), true);
         }
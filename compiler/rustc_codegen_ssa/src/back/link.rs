@@ -940,7 +940,7 @@ fn is_illegal_instruction(_status: &ExitStatus) -> bool {
         }
     }
 
-    match sess.split_debuginfo() {
+    match sess.split_debuginfo_for(crate_type) {
         // If split debug information is disabled or located in individual files
         // there's nothing to do here.
         SplitDebuginfo::Off | SplitDebuginfo::Unpacked => {}
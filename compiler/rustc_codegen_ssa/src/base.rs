@@ -456,7 +456,7 @@ fn create_entry_fn<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
             (rust_main, start_ty, vec![arg_argc, arg_argv])
         };
 
-        let result = bx.call(start_ty, start_fn, &args, None);
+        let result = bx.call(start_ty, start_fn, &args, None, None);
         let cast = bx.intcast(result, cx.type_int(), true);
         bx.ret(cast);
 
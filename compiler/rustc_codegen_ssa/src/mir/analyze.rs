@@ -270,6 +270,7 @@ fn discover_masters<'tcx>(
                 | TerminatorKind::Resume
                 | TerminatorKind::Abort
                 | TerminatorKind::Return
+                | TerminatorKind::TailCall { .. }
                 | TerminatorKind::GeneratorDrop
                 | TerminatorKind::Unreachable
                 | TerminatorKind::SwitchInt { .. }
@@ -20,10 +20,13 @@
 use rustc_middle::ty::{self, Instance, Ty, TypeFoldable};
 use rustc_span::source_map::Span;
 use rustc_span::{sym, Symbol};
-use rustc_symbol_mangling::typeid_for_fnabi;
+use rustc_symbol_mangling::{kcfi_typeid_for_fnabi, typeid_for_fnabi, typeid_for_vtable_fnabi};
 use rustc_target::abi::call::{ArgAbi, FnAbi, PassMode};
 use rustc_target::abi::{self, HasDataLayout, WrappingRange};
 use rustc_target::spec::abi::Abi;
+use rustc_target::spec::SanitizerSet;
+
+use std::iter;
 
 /// Used by `FunctionCx::codegen_terminator` for emitting common patterns
 /// e.g., creating a basic block, calling a function, etc.
@@ -122,6 +125,7 @@ fn funclet_br<Bx: BuilderMethods<'a, 'tcx>>(
 
     /// Call `fn_ptr` of `fn_abi` with the arguments `llargs`, the optional
     /// return destination `destination` and the cleanup function `cleanup`.
+    /// With KCFI, the callee is checked to have the type id `kcfi_typeid`.
     fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
         &self,
         fx: &mut FunctionCx<'a, 'tcx, Bx>,
@@ -131,6 +135,7 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
         llargs: &[Bx::Value],
         destination: Option<(ReturnDest<'tcx, Bx::Value>, mir::BasicBlock)>,
         cleanup: Option<mir::BasicBlock>,
+        kcfi_typeid: Option<u32>,
     ) {
         // If there is a cleanup block and the function we're calling can unwind, then
         // do an invoke, otherwise do a call.
@@ -148,6 +153,7 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
                 ret_llbb,
                 self.llblock(fx, cleanup),
                 self.funclet(fx),
+                kcfi_typeid,
             );
             bx.apply_attrs_callsite(&fn_abi, invokeret);
 
@@ -157,7 +163,7 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
                 fx.store_return(&mut ret_bx, ret_dest, &fn_abi.ret, invokeret);
             }
         } else {
-            let llret = bx.call(fn_ty, fn_ptr, &llargs, self.funclet(fx));
+            let llret = bx.call(fn_ty, fn_ptr, &llargs, self.funclet(fx), kcfi_typeid);
             bx.apply_attrs_callsite(&fn_abi, llret);
             if fx.mir[self.bb].is_cleanup {
                 bx.apply_attrs_to_cleanup_callsite(llret);
@@ -172,6 +178,33 @@ fn do_call<Bx: BuilderMethods<'a, 'tcx>>(
         }
     }
 
+    /// Call `fn_ptr` of `fn_abi` with the arguments `llargs`, as a guaranteed tail call if
+    /// `must_tail` is set, and return its result from the current function.
+    fn do_tail_call<Bx: BuilderMethods<'a, 'tcx>>(
+        &self,
+        fx: &mut FunctionCx<'a, 'tcx, Bx>,
+        bx: &mut Bx,
+        fn_abi: &'tcx FnAbi<'tcx, Ty<'tcx>>,
+        fn_ptr: Bx::Value,
+        llargs: &[Bx::Value],
+        kcfi_typeid: Option<u32>,
+        must_tail: bool,
+    ) {
+        let fn_ty = bx.fn_decl_backend_type(&fn_abi);
+        let llret = bx.call(fn_ty, fn_ptr, &llargs, self.funclet(fx), kcfi_typeid);
+        bx.apply_attrs_callsite(&fn_abi, llret);
+        if must_tail {
+            bx.set_must_tail_call(llret);
+        }
+
+        // The callee has the same signature as the current function, so its result is returned
+        // as is. An indirect result has been written to our own return place by the callee.
+        match fn_abi.ret.mode {
+            PassMode::Ignore | PassMode::Indirect { .. } => bx.ret_void(),
+            _ => bx.ret(llret),
+        }
+    }
+
     /// Generates inline assembly with optional `destination` and `cleanup`.
     fn do_inlineasm<Bx: BuilderMethods<'a, 'tcx>>(
         &self,
@@ -393,6 +426,7 @@ fn codegen_drop_terminator(
             args,
             Some((ReturnDest::Nothing, target)),
             unwind,
+            None,
         );
     }
 
@@ -474,7 +508,7 @@ fn codegen_assert_terminator(
         let llfn = bx.get_fn_addr(instance);
 
         // Codegen the actual panic invoke/call.
-        helper.do_call(self, &mut bx, fn_abi, llfn, &args, None, cleanup);
+        helper.do_call(self, &mut bx, fn_abi, llfn, &args, None, cleanup, None);
     }
 
     fn codegen_abort_terminator(
@@ -496,7 +530,7 @@ fn codegen_abort_terminator(
         let llfn = bx.get_fn_addr(instance);
 
         // Codegen the actual panic invoke/call.
-        helper.do_call(self, &mut bx, fn_abi, llfn, &[location], None, None);
+        helper.do_call(self, &mut bx, fn_abi, llfn, &[location], None, None, None);
     }
 
     /// Returns `true` if this is indeed a panic intrinsic and codegen is done.
@@ -570,6 +604,7 @@ enum AssertIntrinsic {
                     &[msg.0, msg.1, location],
                     destination.as_ref().map(|(_, bb)| (ReturnDest::Nothing, *bb)),
                     cleanup,
+                    None,
                 );
             } else {
                 // a NOP
@@ -592,6 +627,7 @@ fn codegen_call_terminator(
         destination: &Option<(mir::Place<'tcx>, mir::BasicBlock)>,
         cleanup: Option<mir::BasicBlock>,
         fn_span: Span,
+        tail_call: bool,
     ) {
         let source_info = terminator.source_info;
         let span = source_info.span;
@@ -616,8 +652,12 @@ fn codegen_call_terminator(
 
         if let Some(ty::InstanceDef::DropGlue(_, None)) = def {
             // Empty drop glue; a no-op.
-            let &(_, target) = destination.as_ref().unwrap();
-            helper.funclet_br(self, &mut bx, target);
+            if tail_call {
+                self.codegen_return_terminator(bx);
+            } else {
+                let &(_, target) = destination.as_ref().unwrap();
+                helper.funclet_br(self, &mut bx, target);
+            }
             return;
         }
 
@@ -632,6 +672,10 @@ fn codegen_call_terminator(
             Some(ty::InstanceDef::Intrinsic(def_id)) => Some(bx.tcx().item_name(def_id)),
             _ => None,
         };
+        if tail_call && intrinsic.is_some() {
+            // Intrinsics have their own ABI, which no caller of a tail call can have.
+            span_bug!(span, "tail call to intrinsic {:?}", intrinsic);
+        }
 
         let extra_args = &args[sig.inputs().skip_binder().len()..];
         let extra_args = bx.tcx().mk_type_list(extra_args.iter().map(|op_arg| {
@@ -683,6 +727,13 @@ fn codegen_call_terminator(
             let is_intrinsic = intrinsic.is_some();
             self.make_return_dest(&mut bx, dest, &fn_abi.ret, &mut llargs, is_intrinsic)
         } else {
+            if tail_call && fn_abi.ret.is_indirect() {
+                // The callee writes its result directly to our own return place.
+                match self.locals[mir::RETURN_PLACE] {
+                    LocalRef::Place(ret_place) => llargs.push(ret_place.llval),
+                    _ => bug!("indirect return place is not a place"),
+                }
+            }
             ReturnDest::Nothing
         };
 
@@ -877,19 +928,41 @@ fn codegen_call_terminator(
             self.codegen_argument(&mut bx, location, &mut llargs, last_arg);
         }
 
+        if tail_call {
+            self.move_tail_call_indirect_args(&mut bx, fn_abi, &mut llargs);
+        }
+
         let (is_indirect_call, fn_ptr) = match (llfn, instance) {
             (Some(llfn), _) => (true, llfn),
             (None, Some(instance)) => (false, bx.get_fn_addr(instance)),
             _ => span_bug!(span, "no llfn for call"),
         };
 
+        // For backends that support KCFI, indirect calls carry the type id the callee is checked
+        // against. Virtual calls aren't checked: they don't know the type of the receiver, and a
+        // function only has a single KCFI type id, the one for calls through function pointers.
+        let caller_attrs = bx.tcx().codegen_fn_attrs(self.instance.def_id());
+        let kcfi_typeid = if bx.tcx().sess.is_sanitizer_kcfi_enabled()
+            && !caller_attrs.no_sanitize.contains(SanitizerSet::KCFI)
+            && is_indirect_call
+            && !matches!(def, Some(ty::InstanceDef::Virtual(..)))
+        {
+            Some(kcfi_typeid_for_fnabi(bx.tcx(), fn_abi))
+        } else {
+            None
+        };
+
         // For backends that support CFI using type membership (i.e., testing whether a given
         // pointer is associated with a type identifier).
         if bx.tcx().sess.is_sanitizer_cfi_enabled() && is_indirect_call {
             // Emit type metadata and checks.
             // FIXME(rcvalle): Add support for generalized identifiers.
             // FIXME(rcvalle): Create distinct unnamed MDNodes for internal identifiers.
-            let typeid = typeid_for_fnabi(bx.tcx(), fn_abi);
+            let typeid = if let Some(ty::InstanceDef::Virtual(..)) = def {
+                typeid_for_vtable_fnabi(bx.tcx(), fn_abi)
+            } else {
+                typeid_for_fnabi(bx.tcx(), fn_abi)
+            };
             let typeid_metadata = bx.typeid_metadata(typeid);
 
             // Test whether the function pointer is associated with the type identifier.
@@ -898,19 +971,45 @@ fn codegen_call_terminator(
             let mut bx_fail = bx.build_sibling_block("type_test.fail");
             bx.cond_br(cond, bx_pass.llbb(), bx_fail.llbb());
 
-            helper.do_call(
+            if tail_call {
+                helper.do_tail_call(
+                    self,
+                    &mut bx_pass,
+                    fn_abi,
+                    fn_ptr,
+                    &llargs,
+                    kcfi_typeid,
+                    !needs_location,
+                );
+            } else {
+                helper.do_call(
+                    self,
+                    &mut bx_pass,
+                    fn_abi,
+                    fn_ptr,
+                    &llargs,
+                    destination.as_ref().map(|&(_, target)| (ret_dest, target)),
+                    cleanup,
+                    kcfi_typeid,
+                );
+            }
+
+            bx_fail.abort();
+            bx_fail.unreachable();
+
+            return;
+        }
+
+        if tail_call {
+            helper.do_tail_call(
                 self,
-                &mut bx_pass,
+                &mut bx,
                 fn_abi,
                 fn_ptr,
                 &llargs,
-                destination.as_ref().map(|&(_, target)| (ret_dest, target)),
-                cleanup,
+                kcfi_typeid,
+                !needs_location,
             );
-
-            bx_fail.abort();
-            bx_fail.unreachable();
-
             return;
         }
 
@@ -922,6 +1021,7 @@ fn codegen_call_terminator(
             &llargs,
             destination.as_ref().map(|&(_, target)| (ret_dest, target)),
             cleanup,
+            kcfi_typeid,
         );
     }
 
@@ -1086,6 +1186,12 @@ fn codegen_terminator(
                     destination,
                     cleanup,
                     fn_span,
+                    false,
+                );
+            }
+            mir::TerminatorKind::TailCall { ref func, ref args, fn_span } => {
+                self.codegen_call_terminator(
+                    helper, bx, terminator, func, args, &None, None, fn_span, true,
                 );
             }
             mir::TerminatorKind::GeneratorDrop | mir::TerminatorKind::Yield { .. } => {
@@ -1214,6 +1320,56 @@ fn codegen_argument(
         llargs.push(llval);
     }
 
+    /// Moves the arguments of a tail call which are passed indirectly to the memory of our own
+    /// indirect arguments. The callee takes over our stack frame, but the memory of our arguments
+    /// belongs to our caller. The signatures of a tail call and its caller are the same, so their
+    /// arguments are passed in the same way.
+    fn move_tail_call_indirect_args(
+        &mut self,
+        bx: &mut Bx,
+        fn_abi: &FnAbi<'tcx, Ty<'tcx>>,
+        llargs: &mut [Bx::Value],
+    ) {
+        let mut llarg_idx = fn_abi.ret.is_indirect() as usize;
+        let mut indirect_args = Vec::new();
+        for arg in fn_abi.args.iter() {
+            if arg.pad.is_some() {
+                llarg_idx += 1;
+            }
+            match arg.mode {
+                PassMode::Ignore => {}
+                PassMode::Direct(_) | PassMode::Cast(_) => llarg_idx += 1,
+                PassMode::Pair(..) => llarg_idx += 2,
+                PassMode::Indirect { extra_attrs: None, .. } => {
+                    indirect_args.push((llarg_idx, arg.layout));
+                    llarg_idx += 1;
+                }
+                PassMode::Indirect { extra_attrs: Some(_), .. } => {
+                    bug!("tail call with an unsized argument")
+                }
+            }
+        }
+
+        // The arguments may be read from our own arguments, e.g. when they are swapped, so all of
+        // them are copied out before any of them is overwritten.
+        let temps: Vec<_> = indirect_args
+            .iter()
+            .map(|&(llarg_idx, layout)| {
+                let tmp = PlaceRef::alloca(bx, layout);
+                let align = layout.align.abi;
+                let llarg = llargs[llarg_idx];
+                base::memcpy_ty(bx, tmp.llval, align, llarg, align, layout, MemFlags::empty());
+                tmp
+            })
+            .collect();
+        for (&(llarg_idx, layout), tmp) in iter::zip(&indirect_args, temps) {
+            let llarg = bx.get_param(llarg_idx);
+            let align = layout.align.abi;
+            base::memcpy_ty(bx, llarg, align, tmp.llval, align, layout, MemFlags::empty());
+            llargs[llarg_idx] = llarg;
+        }
+    }
+
     fn codegen_arguments_untupled(
         &mut self,
         bx: &mut Bx,
@@ -10,6 +10,8 @@
 use rustc_target::abi::Abi;
 use rustc_target::abi::Size;
 
+use std::ops::Range;
+
 use super::operand::{OperandRef, OperandValue};
 use super::place::PlaceRef;
 use super::{FunctionCx, LocalRef};
@@ -25,7 +27,7 @@ pub enum VariableKind {
 }
 
 /// Like `mir::VarDebugInfo`, but within a `mir::Local`.
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct PerLocalVarDebugInfo<'tcx, D> {
     pub name: Symbol,
     pub source_info: mir::SourceInfo,
@@ -35,6 +37,10 @@ pub struct PerLocalVarDebugInfo<'tcx, D> {
 
     /// `.place.projection` from `mir::VarDebugInfo`.
     pub projection: &'tcx ty::List<mir::PlaceElem<'tcx>>,
+
+    /// `projection`-like path from the start of the variable to a fragment,
+    /// see `mir::VarDebugInfoContents::Composite`, as a byte range.
+    pub fragment: Option<Range<Size>>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -151,7 +157,8 @@ pub fn debug_introduce_local(&self, bx: &mut Bx, local: mir::Local) {
             Some(per_local) => &per_local[local],
             None => return,
         };
-        let whole_local_var = vars.iter().find(|var| var.projection.is_empty()).copied();
+        let whole_local_var =
+            vars.iter().find(|var| var.projection.is_empty() && var.fragment.is_none()).cloned();
         let has_proj = || vars.iter().any(|var| !var.projection.is_empty());
 
         let fallback_var = if self.mir.local_kind(local) == mir::LocalKind::Arg {
@@ -194,6 +201,7 @@ pub fn debug_introduce_local(&self, bx: &mut Bx, local: mir::Local) {
                     source_info: decl.source_info,
                     dbg_var,
                     projection: ty::List::empty(),
+                    fragment: None,
                 })
             }
         } else {
@@ -255,7 +263,7 @@ pub fn debug_introduce_local(&self, bx: &mut Bx, local: mir::Local) {
             LocalRef::UnsizedPlace(_) => return,
         };
 
-        let vars = vars.iter().copied().chain(fallback_var);
+        let vars = vars.iter().cloned().chain(fallback_var);
 
         for var in vars {
             let dbg_var = match var.dbg_var {
@@ -324,9 +332,23 @@ pub fn debug_introduce_local(&self, bx: &mut Bx, local: mir::Local) {
                 bx.store(place.llval, alloca.llval, alloca.align);
 
                 // Point the debug info to `*alloca` for the current variable
-                bx.dbg_var_addr(dbg_var, dbg_loc, alloca.llval, Size::ZERO, &[Size::ZERO]);
+                bx.dbg_var_addr(
+                    dbg_var,
+                    dbg_loc,
+                    alloca.llval,
+                    Size::ZERO,
+                    &[Size::ZERO],
+                    var.fragment,
+                );
             } else {
-                bx.dbg_var_addr(dbg_var, dbg_loc, base.llval, direct_offset, &indirect_offsets);
+                bx.dbg_var_addr(
+                    dbg_var,
+                    dbg_loc,
+                    base.llval,
+                    direct_offset,
+                    &indirect_offsets,
+                    var.fragment,
+                );
             }
         }
     }
@@ -394,6 +416,10 @@ pub fn compute_per_local_var_debug_info(
                         let ty = self.monomorphize(c.ty());
                         (ty, VariableKind::LocalVariable)
                     }
+                    mir::VarDebugInfoContents::Composite { ty, fragments: _ } => {
+                        let ty = self.monomorphize(ty);
+                        (ty, VariableKind::LocalVariable)
+                    }
                 };
 
                 self.cx.create_dbg_var(var.name, var_ty, dbg_scope, var_kind, span)
@@ -406,6 +432,7 @@ pub fn compute_per_local_var_debug_info(
                         source_info: var.source_info,
                         dbg_var,
                         projection: place.projection,
+                        fragment: None,
                     });
                 }
                 mir::VarDebugInfoContents::Const(c) => {
@@ -422,10 +449,47 @@ pub fn compute_per_local_var_debug_info(
                                 bx,
                             );
 
-                            bx.dbg_var_addr(dbg_var, dbg_loc, base.llval, Size::ZERO, &[]);
+                            bx.dbg_var_addr(dbg_var, dbg_loc, base.llval, Size::ZERO, &[], None);
                         }
                     }
                 }
+                mir::VarDebugInfoContents::Composite { ty, ref fragments } => {
+                    let var_ty = self.monomorphize(ty);
+                    let var_layout = self.cx.layout_of(var_ty);
+                    for fragment in fragments {
+                        let mut fragment_start = Size::ZERO;
+                        let mut fragment_layout = var_layout;
+
+                        for elem in &fragment.projection {
+                            match *elem {
+                                mir::ProjectionElem::Field(field, _) => {
+                                    let i = field.index();
+                                    fragment_start += fragment_layout.fields.offset(i);
+                                    fragment_layout = fragment_layout.field(self.cx, i);
+                                }
+                                _ => span_bug!(
+                                    var.source_info.span,
+                                    "unsupported fragment projection `{:?}`",
+                                    elem,
+                                ),
+                            }
+                        }
+
+                        // Zero-sized fragments have nothing to describe.
+                        if fragment_layout.size == Size::ZERO {
+                            continue;
+                        }
+
+                        let place = fragment.contents;
+                        per_local[place.local].push(PerLocalVarDebugInfo {
+                            name: var.name,
+                            source_info: var.source_info,
+                            dbg_var,
+                            projection: place.projection,
+                            fragment: Some(fragment_start..fragment_start + fragment_layout.size),
+                        });
+                    }
+                }
             }
         }
         Some(per_local)
@@ -379,6 +379,11 @@ pub fn codegen_intrinsic_call(
                 return;
             }
 
+            sym::const_make_global => {
+                // returns the pointer unchanged at runtime.
+                args[0].immediate()
+            }
+
             // This requires that atomic intrinsics follow a specific naming pattern:
             // "atomic_<operation>[_<ordering>]", and no ordering means SeqCst
             name if name_str.starts_with("atomic_") => {
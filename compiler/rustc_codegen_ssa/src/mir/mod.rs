@@ -1,10 +1,11 @@
 use crate::traits::*;
 use rustc_errors::ErrorReported;
+use rustc_hir::def::DefKind;
 use rustc_middle::mir;
 use rustc_middle::mir::interpret::ErrorHandled;
 use rustc_middle::ty::layout::{FnAbiOf, HasTyCtxt, TyAndLayout};
-use rustc_middle::ty::{self, Instance, Ty, TypeFoldable};
-use rustc_symbol_mangling::typeid_for_fnabi;
+use rustc_middle::ty::{self, Instance, Ty, TyCtxt, TypeFoldable};
+use rustc_symbol_mangling::{kcfi_typeid_for_fnabi, typeid_for_fnabi, typeid_for_vtable_fnabi};
 use rustc_target::abi::call::{FnAbi, PassMode};
 
 use std::iter;
@@ -251,6 +252,45 @@ pub fn codegen_mir<'a, 'tcx, Bx: BuilderMethods<'a, 'tcx>>(
     if cx.tcx().sess.is_sanitizer_cfi_enabled() {
         let typeid = typeid_for_fnabi(cx.tcx(), fn_abi);
         bx.type_metadata(llfn, typeid);
+        // Virtual calls don't know the type of the receiver, so functions that can end up in a
+        // vtable also need to be associated with the typeid those calls test for.
+        if may_be_called_through_vtable(cx.tcx(), instance) {
+            let typeid = typeid_for_vtable_fnabi(cx.tcx(), fn_abi);
+            bx.type_metadata(llfn, typeid);
+        }
+    }
+
+    // For backends that support KCFI, where the type id is stored before the code of the function
+    // and checked by indirect calls.
+    if cx.tcx().sess.is_sanitizer_kcfi_enabled() {
+        let typeid = kcfi_typeid_for_fnabi(cx.tcx(), fn_abi);
+        bx.kcfi_type_metadata(llfn, typeid);
+    }
+}
+
+/// Returns whether `instance` may be an entry of a vtable: the methods of object safe traits,
+/// closures (through the `Fn*` traits) and the shims that adapt functions to vtable entries.
+fn may_be_called_through_vtable<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) -> bool {
+    match instance.def {
+        ty::InstanceDef::Item(def) => match tcx.def_kind(def.did) {
+            DefKind::Closure => true,
+            DefKind::AssocFn => {
+                // Inherent methods are never called through a vtable.
+                let trait_def_id = tcx.trait_of_item(def.did).or_else(|| {
+                    tcx.impl_of_method(def.did)
+                        .and_then(|impl_def_id| tcx.trait_id_of_impl(impl_def_id))
+                });
+                trait_def_id.map_or(false, |trait_def_id| {
+                    tcx.associated_item(def.did).fn_has_self_parameter
+                        && tcx.is_object_safe(trait_def_id)
+                })
+            }
+            _ => false,
+        },
+        ty::InstanceDef::VtableShim(..)
+        | ty::InstanceDef::ClosureOnceShim { .. }
+        | ty::InstanceDef::FnPtrShim(..) => true,
+        _ => false,
     }
 }
 
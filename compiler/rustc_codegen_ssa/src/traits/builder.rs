@@ -79,6 +79,7 @@ fn invoke(
         then: Self::BasicBlock,
         catch: Self::BasicBlock,
         funclet: Option<&Self::Funclet>,
+        kcfi_typeid: Option<u32>,
     ) -> Self::Value;
     fn unreachable(&mut self);
 
@@ -162,6 +163,7 @@ fn write_operand_repeatedly(
     fn range_metadata(&mut self, load: Self::Value, range: WrappingRange);
     fn nonnull_metadata(&mut self, load: Self::Value);
     fn type_metadata(&mut self, function: Self::Function, typeid: String);
+    fn kcfi_type_metadata(&mut self, function: Self::Function, typeid: u32);
     fn typeid_metadata(&mut self, typeid: String) -> Self::Value;
 
     fn store(&mut self, val: Self::Value, ptr: Self::Value, align: Align) -> Self::Value;
@@ -477,8 +479,13 @@ fn call(
         llfn: Self::Value,
         args: &[Self::Value],
         funclet: Option<&Self::Funclet>,
+        kcfi_typeid: Option<u32>,
     ) -> Self::Value;
     fn zext(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value;
 
     fn apply_attrs_to_cleanup_callsite(&mut self, llret: Self::Value);
+
+    /// Marks `llret`, the result of `call`, as a call which must be performed as a tail call.
+    /// It has to be immediately followed by a return of its result.
+    fn set_must_tail_call(&mut self, llret: Self::Value);
 }
@@ -67,6 +67,8 @@ pub struct ConstEvalErr<'tcx> {
     pub span: Span,
     pub error: InterpError<'tcx>,
     pub stacktrace: Vec<FrameInfo<'tcx>>,
+    /// Extra notes to attach to the diagnostic.
+    pub notes: Vec<String>,
 }
 
 impl<'tcx> ConstEvalErr<'tcx> {
@@ -86,6 +88,7 @@ pub fn new<'mir, M: Machine<'mir, 'tcx>>(
         ConstEvalErr {
             error: error.into_kind(),
             stacktrace,
+            notes: Vec::new(),
             span: span.unwrap_or_else(|| ecx.cur_span()),
         }
     }
@@ -154,39 +157,64 @@ fn struct_generic(
             if let Some(span_msg) = span_msg {
                 err.span_label(self.span, span_msg);
             }
-            // Add spans for the stacktrace. Don't print a single-line backtrace though.
+            // Add a note for every frame of the stacktrace, the innermost one first. Don't print
+            // a single-line backtrace though.
             if self.stacktrace.len() > 1 {
-                // Helper closure to print duplicated lines.
-                let mut flush_last_line = |last_frame, times| {
-                    if let Some((line, span)) = last_frame {
-                        err.span_label(span, &line);
-                        // Don't print [... additional calls ...] if the number of lines is small
-                        if times < 3 {
-                            for _ in 0..times {
-                                err.span_label(span, &line);
+                // Deep backtraces only show their innermost and outermost frames.
+                let max_frames = tcx.sess.opts.debugging_opts.max_const_backtrace_frames;
+                let (innermost, outermost) =
+                    if max_frames == 0 || self.stacktrace.len() <= max_frames {
+                        (&self.stacktrace[..], &[][..])
+                    } else {
+                        let outermost = self.stacktrace.len() - (max_frames - max_frames / 2);
+                        (&self.stacktrace[..max_frames / 2], &self.stacktrace[outermost..])
+                    };
+                let elided = self.stacktrace.len() - innermost.len() - outermost.len();
+
+                // Helper closure to print duplicated frames.
+                let flush_last_frame =
+                    |err: &mut DiagnosticBuilder<'_>, last_frame: Option<(String, Span)>, times| {
+                        if let Some((frame, span)) = last_frame {
+                            err.span_note(span, &frame);
+                            // Don't print [... additional calls ...] for a few frames.
+                            if times < 3 {
+                                for _ in 0..times {
+                                    err.span_note(span, &frame);
+                                }
+                            } else {
+                                err.span_note(
+                                    span,
+                                    &format!("[... {} additional calls {} ...]", times, &frame),
+                                );
                             }
-                        } else {
-                            err.span_label(
-                                span,
-                                format!("[... {} additional calls {} ...]", times, &line),
-                            );
                         }
+                    };
+
+                for (i, frames) in [innermost, outermost].into_iter().enumerate() {
+                    if i == 1 && elided > 0 {
+                        err.note(&format!(
+                            "{} frames of the backtrace were elided, use \
+                             `-Zmax-const-backtrace-frames=0` to show them all",
+                            elided
+                        ));
                     }
-                };
-
-                let mut last_frame = None;
-                let mut times = 0;
-                for frame_info in &self.stacktrace {
-                    let frame = (frame_info.to_string(), frame_info.span);
-                    if last_frame.as_ref() == Some(&frame) {
-                        times += 1;
-                    } else {
-                        flush_last_line(last_frame, times);
-                        last_frame = Some(frame);
-                        times = 0;
+                    let mut last_frame = None;
+                    let mut times = 0;
+                    for frame_info in frames {
+                        let frame = (frame_info.to_string(), frame_info.span);
+                        if last_frame.as_ref() == Some(&frame) {
+                            times += 1;
+                        } else {
+                            flush_last_frame(&mut err, last_frame, times);
+                            last_frame = Some(frame);
+                            times = 0;
+                        }
                     }
+                    flush_last_frame(&mut err, last_frame, times);
                 }
-                flush_last_line(last_frame, times);
+            }
+            for note in &self.notes {
+                err.note(note);
             }
             // Let the caller finish the job.
             emit(err)
@@ -6,7 +6,7 @@
     ScalarMaybeUninit, StackPopCleanup,
 };
 
-use rustc_errors::ErrorReported;
+use rustc_errors::{pluralize, ErrorReported};
 use rustc_hir::def::DefKind;
 use rustc_middle::mir;
 use rustc_middle::mir::interpret::ErrorHandled;
@@ -15,6 +15,7 @@
 use rustc_middle::ty::layout::LayoutOf;
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::{self, subst::Subst, TyCtxt};
+use rustc_session::Limit;
 use rustc_span::source_map::Span;
 use rustc_target::abi::Abi;
 use std::borrow::Cow;
@@ -102,7 +103,9 @@ pub(super) fn mk_eval_cx<'mir, 'tcx>(
         root_span,
         param_env,
         CompileTimeInterpreter::new(tcx.const_eval_limit()),
-        MemoryExtra { can_access_statics },
+        // The memory limit is only meant for the user's code, not for the constants the compiler
+        // itself builds in here.
+        MemoryExtra { can_access_statics, memory_limit: Limit::new(0), memory_in_use: 0 },
     )
 }
 
@@ -238,7 +241,8 @@ pub fn eval_to_const_value_raw_provider<'tcx>(
         };
         return eval_nullary_intrinsic(tcx, key.param_env, def_id, substs).map_err(|error| {
             let span = tcx.def_span(def_id);
-            let error = ConstEvalErr { error: error.into_kind(), stacktrace: vec![], span };
+            let error =
+                ConstEvalErr { error: error.into_kind(), stacktrace: vec![], notes: vec![], span };
             error.report_as_error(tcx.at(span), "could not evaluate nullary intrinsic")
         });
     }
@@ -309,13 +313,29 @@ pub fn eval_to_allocation_raw_provider<'tcx>(
         CompileTimeInterpreter::new(tcx.const_eval_limit()),
         // Statics (and promoteds inside statics) may access other statics, because unlike consts
         // they do not have to behave "as if" they were evaluated at runtime.
-        MemoryExtra { can_access_statics: is_static },
+        MemoryExtra {
+            can_access_statics: is_static,
+            memory_limit: tcx.const_eval_memory_limit(),
+            memory_in_use: 0,
+        },
     );
 
     let res = ecx.load_mir(cid.instance.def, cid.promoted);
     match res.and_then(|body| eval_body_using_ecx(&mut ecx, cid, &body)) {
         Err(error) => {
-            let err = ConstEvalErr::new(&ecx, error, None);
+            let mut err = ConstEvalErr::new(&ecx, error, None);
+            // Point at the functions the evaluation spent its time in.
+            if let err_exhaust!(StepLimitReached) | err_exhaust!(MemoryLimitReached) = err.error {
+                err.notes = ecx
+                    .machine
+                    .hottest_fns(3)
+                    .into_iter()
+                    .map(|(def_id, steps)| {
+                        let path = tcx.def_path_str(def_id);
+                        format!("`{}` took {} step{}", path, steps, pluralize!(steps))
+                    })
+                    .collect();
+            }
             // Some CTFE errors raise just a lint, not a hard error; see
             // <https://github.com/rust-lang/rust/issues/71800>.
             let is_hard_err = if let Some(def) = def.as_local() {
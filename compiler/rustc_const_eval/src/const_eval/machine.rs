@@ -16,8 +16,8 @@
 use rustc_target::spec::abi::Abi;
 
 use crate::interpret::{
-    self, compile_time_machine, AllocId, Allocation, Frame, ImmTy, InterpCx, InterpResult, OpTy,
-    PlaceTy, Scalar, StackPopUnwind,
+    self, compile_time_machine, AllocId, AllocRange, Allocation, Frame, ImmTy, InterpCx,
+    InterpResult, OpTy, PlaceTy, Scalar, StackPopUnwind,
 };
 
 use super::error::*;
@@ -90,6 +90,10 @@ pub struct CompileTimeInterpreter<'mir, 'tcx> {
     /// Setting this to `0` disables the limit and allows the interpreter to run forever.
     pub steps_remaining: usize,
 
+    /// The number of terminators evaluated in each function, to point at the hottest ones when
+    /// a limit is reached.
+    fn_steps: FxHashMap<DefId, usize>,
+
     /// The virtual call stack.
     pub(crate) stack: Vec<Frame<'mir, 'tcx, AllocId, ()>>,
 }
@@ -103,11 +107,32 @@ pub struct MemoryExtra {
     /// * Pointers to allocations inside of statics can never leak outside, to a non-static global.
     /// This boolean here controls the second part.
     pub(super) can_access_statics: bool,
+    /// The number of bytes of stack and heap memory that may be allocated at once before we throw
+    /// a resource exhaustion error.
+    ///
+    /// Setting this to `0` disables the limit.
+    pub(super) memory_limit: Limit,
+    /// The number of bytes of stack and heap memory currently allocated.
+    pub(super) memory_in_use: usize,
 }
 
 impl<'mir, 'tcx> CompileTimeInterpreter<'mir, 'tcx> {
     pub(super) fn new(const_eval_limit: Limit) -> Self {
-        CompileTimeInterpreter { steps_remaining: const_eval_limit.0, stack: Vec::new() }
+        CompileTimeInterpreter {
+            steps_remaining: const_eval_limit.0,
+            fn_steps: FxHashMap::default(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns the `count` functions that the most terminators were evaluated in, hottest first,
+    /// along with their number of terminators.
+    pub(super) fn hottest_fns(&self, count: usize) -> Vec<(DefId, usize)> {
+        let mut fns: Vec<_> =
+            self.fn_steps.iter().map(|(&def_id, &steps)| (def_id, steps)).collect();
+        fns.sort_by(|(a, a_steps), (b, b_steps)| b_steps.cmp(a_steps).then(a.cmp(b)));
+        fns.truncate(count);
+        fns
     }
 }
 
@@ -166,13 +191,19 @@ fn get_mut_or<E>(&mut self, k: K, vacant: impl FnOnce() -> Result<V, E>) -> Resu
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum MemoryKind {
-    Heap,
+    /// Memory allocated with `const_allocate`.
+    Heap {
+        /// Whether `const_make_global` was called on this allocation: it is then immutable, and
+        /// can be part of the final value of the constant.
+        was_made_global: bool,
+    },
 }
 
 impl fmt::Display for MemoryKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            MemoryKind::Heap => write!(f, "heap allocation"),
+            MemoryKind::Heap { was_made_global: false } => write!(f, "heap allocation"),
+            MemoryKind::Heap { was_made_global: true } => write!(f, "global heap allocation"),
         }
     }
 }
@@ -181,7 +212,7 @@ impl interpret::MayLeak for MemoryKind {
     #[inline(always)]
     fn may_leak(self) -> bool {
         match self {
-            MemoryKind::Heap => false,
+            MemoryKind::Heap { was_made_global } => was_made_global,
         }
     }
 }
@@ -343,7 +374,7 @@ fn call_intrinsic(
                 let ptr = ecx.memory.allocate(
                     Size::from_bytes(size as u64),
                     align,
-                    interpret::MemoryKind::Machine(MemoryKind::Heap),
+                    interpret::MemoryKind::Machine(MemoryKind::Heap { was_made_global: false }),
                 )?;
                 ecx.write_pointer(ptr, dest)?;
             }
@@ -370,10 +401,40 @@ fn call_intrinsic(
                     ecx.memory.deallocate(
                         ptr,
                         Some((size, align)),
-                        interpret::MemoryKind::Machine(MemoryKind::Heap),
+                        interpret::MemoryKind::Machine(MemoryKind::Heap { was_made_global: false }),
                     )?;
                 }
             }
+            sym::const_make_global => {
+                let ptr = ecx.read_pointer(&args[0])?;
+                let (alloc_id, offset, _) = ecx.memory.ptr_get_alloc(ptr)?;
+                if offset.bytes() != 0 {
+                    throw_ub_format!(
+                        "pointer passed to `const_make_global` does not point to the beginning \
+                         of an allocation"
+                    );
+                }
+                match ecx.memory.get_local_alloc_mut(alloc_id) {
+                    Some((
+                        interpret::MemoryKind::Machine(MemoryKind::Heap { was_made_global }),
+                        alloc,
+                    )) => {
+                        if *was_made_global {
+                            throw_ub_format!("`const_make_global` called twice on {}", alloc_id);
+                        }
+                        // From now on, the allocation is shared with the final value, so it may
+                        // neither be written to nor deallocated anymore.
+                        *was_made_global = true;
+                        alloc.mutability = Mutability::Not;
+                    }
+                    _ => throw_ub_format!(
+                        "pointer passed to `const_make_global` does not point to a heap \
+                         allocation: {}",
+                        alloc_id
+                    ),
+                }
+                ecx.write_pointer(ptr, dest)?;
+            }
             _ => {
                 return Err(ConstEvalErrKind::NeedsRfc(format!(
                     "calling intrinsic `{}`",
@@ -426,6 +487,10 @@ fn binary_ptr_op(
     }
 
     fn before_terminator(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx> {
+        if let Some(frame) = ecx.machine.stack.last() {
+            *ecx.machine.fn_steps.entry(frame.instance.def_id()).or_default() += 1;
+        }
+
         // The step limit has already been hit in a previous call to `before_terminator`.
         if ecx.machine.steps_remaining == 0 {
             return Ok(());
@@ -439,6 +504,36 @@ fn before_terminator(ecx: &mut InterpCx<'mir, 'tcx, Self>) -> InterpResult<'tcx>
         Ok(())
     }
 
+    fn before_memory_allocation(
+        memory_extra: &mut MemoryExtra,
+        size: Size,
+        kind: interpret::MemoryKind<MemoryKind>,
+    ) -> InterpResult<'static> {
+        // The caller locations are made by the interpreter itself, which expects them not to
+        // fail. They are immutable, so they are never deallocated either.
+        if kind == interpret::MemoryKind::CallerLocation {
+            return Ok(());
+        }
+        let memory_in_use = memory_extra.memory_in_use.saturating_add(size.bytes_usize());
+        if memory_extra.memory_limit.0 != 0
+            && !memory_extra.memory_limit.value_within_limit(memory_in_use)
+        {
+            throw_exhaust!(MemoryLimitReached)
+        }
+        memory_extra.memory_in_use = memory_in_use;
+        Ok(())
+    }
+
+    fn memory_deallocated(
+        memory_extra: &mut MemoryExtra,
+        _alloc_extra: &mut (),
+        _tag: AllocId,
+        range: AllocRange,
+    ) -> InterpResult<'tcx> {
+        memory_extra.memory_in_use -= range.size.bytes_usize();
+        Ok(())
+    }
+
     #[inline(always)]
     fn init_frame_extra(
         ecx: &mut InterpCx<'mir, 'tcx, Self>,
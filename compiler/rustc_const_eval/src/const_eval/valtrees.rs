@@ -0,0 +1,261 @@
+use super::eval_queries::{mk_eval_cx, op_to_const};
+use super::machine::CompileTimeEvalContext;
+use crate::interpret::{
+    intern_const_alloc_recursive, Allocation, ConstValue, InternKind, MPlaceTy, MemPlaceMeta,
+    MemoryKind, Scalar,
+};
+use rustc_middle::mir::interpret::{ConstAlloc, EvalToValTreeResult, GlobalId};
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, ScalarInt, Ty, TyCtxt};
+use rustc_span::source_map::DUMMY_SP;
+use rustc_target::abi::VariantIdx;
+
+use std::convert::TryFrom;
+
+/// Evaluates a constant and turns it into a valtree, so it can be used in the type system.
+/// Returns `Ok(None)` if the constant's value can't be represented as a valtree.
+pub(crate) fn eval_to_valtree<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    cid: GlobalId<'tcx>,
+) -> EvalToValTreeResult<'tcx> {
+    let const_alloc = tcx.eval_to_allocation_raw(param_env.and(cid))?;
+    Ok(const_to_valtree(tcx, param_env, const_alloc))
+}
+
+/// Convert an evaluated constant to a type level constant
+fn const_to_valtree<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    raw: ConstAlloc<'tcx>,
+) -> Option<ty::ValTree<'tcx>> {
+    let ecx = mk_eval_cx(
+        tcx, DUMMY_SP, param_env,
+        // It is absolutely crucial for soundness that
+        // we do not read from static items or other mutable memory.
+        false,
+    );
+    let place = ecx.raw_const_to_mplace(raw).unwrap();
+    const_to_valtree_inner(&ecx, &place)
+}
+
+fn const_to_valtree_inner<'tcx>(
+    ecx: &CompileTimeEvalContext<'tcx, 'tcx>,
+    place: &MPlaceTy<'tcx>,
+) -> Option<ty::ValTree<'tcx>> {
+    let branches = |n, variant| {
+        let place = match variant {
+            Some(variant) => ecx.mplace_downcast(&place, variant).unwrap(),
+            None => *place,
+        };
+        let variant =
+            variant.map(|variant| Some(ty::ValTree::Leaf(ScalarInt::from(variant.as_u32()))));
+        let fields = (0..n).map(|i| {
+            let field = ecx.mplace_field(&place, i).unwrap();
+            const_to_valtree_inner(ecx, &field)
+        });
+        // For enums, we preped their variant index before the variant's fields so we can figure out
+        // the variant again when just seeing a valtree.
+        let branches = variant.into_iter().chain(fields);
+        Some(ty::ValTree::Branch(
+            ecx.tcx.arena.alloc_from_iter(branches.collect::<Option<Vec<_>>>()?),
+        ))
+    };
+    // Arrays, slices and `str` list their elements, which are indexed rather than being fields.
+    let elements = |n: u64| {
+        let elements = (0..n).map(|i| {
+            let element = ecx.mplace_index(&place, i).unwrap();
+            const_to_valtree_inner(ecx, &element)
+        });
+        Some(ty::ValTree::Branch(
+            ecx.tcx.arena.alloc_from_iter(elements.collect::<Option<Vec<_>>>()?),
+        ))
+    };
+    match place.layout.ty.kind() {
+        ty::FnDef(..) => Some(ty::ValTree::zst()),
+        ty::Bool | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Char => {
+            let val = ecx.read_immediate(&place.into()).unwrap();
+            let val = val.to_scalar().unwrap();
+            Some(ty::ValTree::Leaf(val.assert_int()))
+        }
+
+        // Raw pointers are not allowed in type level constants, as we cannot properly test them for
+        // equality at compile-time (see `ptr_guaranteed_eq`/`_ne`).
+        // Technically we could allow function pointers (represented as `ty::Instance`), but this is not guaranteed to
+        // agree with runtime equality tests.
+        ty::FnPtr(_) | ty::RawPtr(_) => None,
+
+        // References are transparent: only the value they point to is part of the valtree, so
+        // equal pointees in different allocations give equal valtrees.
+        ty::Ref(..) => {
+            let derefd_place = ecx.deref_operand(&place.into()).unwrap();
+            const_to_valtree_inner(ecx, &derefd_place)
+        }
+
+        // Trait objects are not allowed in type level constants, as we have no concept for
+        // resolving their backing type, even if we can do that at const eval time. We may
+        // hypothetically be able to allow `dyn StructuralEq` trait objects in the future,
+        // but it is unclear if this is useful.
+        ty::Dynamic(..) => None,
+
+        ty::Slice(_) | ty::Str => {
+            elements(place.meta.unwrap_meta().to_machine_usize(ecx).unwrap())
+        }
+        ty::Tuple(substs) => branches(substs.len(), None),
+        ty::Array(_, len) => elements(len.eval_usize(ecx.tcx.tcx, ecx.param_env)),
+
+        // Reading a union's fields could observe uninitialized bytes, and there is no way to tell
+        // which of its fields is the active one.
+        ty::Adt(def, _) if def.is_union() => None,
+        ty::Adt(def, _) => {
+            if def.variants.is_empty() {
+                bug!("uninhabited types should have errored and never gotten converted to valtree")
+            }
+
+            let variant = ecx.read_discriminant(&place.into()).unwrap().1;
+
+            branches(def.variants[variant].fields.len(), def.is_enum().then_some(variant))
+        }
+
+        ty::Never
+        | ty::Error(_)
+        | ty::Foreign(..)
+        | ty::Infer(ty::FreshIntTy(_))
+        | ty::Infer(ty::FreshFloatTy(_))
+        | ty::Projection(..)
+        | ty::Param(_)
+        | ty::Bound(..)
+        | ty::Placeholder(..)
+        // FIXME(oli-obk): we could look behind opaque types
+        | ty::Opaque(..)
+        | ty::Infer(_)
+        // FIXME(oli-obk): we can probably encode closures just like structs
+        | ty::Closure(..)
+        | ty::Generator(..)
+        | ty::GeneratorWitness(..) => None,
+    }
+}
+
+/// Converts a valtree back to a `ConstValue`, which is what MIR, the interpreter and codegen use.
+///
+/// Scalars and byte/string slices are turned into their `ConstValue` directly. Everything else is
+/// written into a fresh allocation, walking the valtree and the type together, and references
+/// get a new allocation for their pointee.
+pub(crate) fn valtree_to_const_value<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    (ty, valtree): (Ty<'tcx>, ty::ValTree<'tcx>),
+) -> ConstValue<'tcx> {
+    match ty.kind() {
+        ty::FnDef(..) => {
+            assert!(valtree.unwrap_branch().is_empty());
+            ConstValue::Scalar(Scalar::ZST)
+        }
+        ty::Bool | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Char => {
+            ConstValue::Scalar(Scalar::Int(valtree.unwrap_leaf()))
+        }
+        ty::Ref(_, inner_ty, _) if matches!(inner_ty.kind(), ty::Str | ty::Slice(_)) => {
+            match valtree.try_to_raw_bytes(tcx, ty) {
+                Some(bytes) => {
+                    let alloc = Allocation::from_bytes_byte_aligned_immutable(bytes);
+                    let data = tcx.intern_const_alloc(alloc);
+                    ConstValue::Slice { data, start: 0, end: bytes.len() }
+                }
+                None => via_allocation(tcx, ty, valtree),
+            }
+        }
+        ty::Ref(..) | ty::Tuple(_) | ty::Array(..) | ty::Adt(..) => {
+            via_allocation(tcx, ty, valtree)
+        }
+        _ => bug!("cannot turn valtree {:?} of type {} into a constant", valtree, ty),
+    }
+}
+
+/// Writes `valtree` into an interned allocation of type `ty`, and returns it as a `ConstValue`.
+fn via_allocation<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ty: Ty<'tcx>,
+    valtree: ty::ValTree<'tcx>,
+) -> ConstValue<'tcx> {
+    let mut ecx = mk_eval_cx(tcx, DUMMY_SP, ty::ParamEnv::reveal_all(), false);
+    let place = create_place(&mut ecx, ty, valtree);
+    intern_const_alloc_recursive(&mut ecx, InternKind::Constant, &place).unwrap();
+    op_to_const(&ecx, &place.into())
+}
+
+/// Allocates a place of type `ty` and writes `valtree` into it. `str` and slices get an
+/// allocation of the array of their elements.
+fn create_place<'tcx>(
+    ecx: &mut CompileTimeEvalContext<'tcx, 'tcx>,
+    ty: Ty<'tcx>,
+    valtree: ty::ValTree<'tcx>,
+) -> MPlaceTy<'tcx> {
+    let tcx = ecx.tcx.tcx;
+    let place = match ty.kind() {
+        ty::Str | ty::Slice(_) => {
+            let elem_ty = match ty.kind() {
+                ty::Slice(elem_ty) => *elem_ty,
+                _ => tcx.types.u8,
+            };
+            let len = u64::try_from(valtree.unwrap_branch().len()).unwrap();
+            let array_layout = ecx.layout_of(tcx.mk_array(elem_ty, len)).unwrap();
+            let array_place = ecx.allocate(array_layout, MemoryKind::Stack).unwrap();
+            let meta = MemPlaceMeta::Meta(Scalar::from_machine_usize(len, ecx));
+            MPlaceTy::from_aligned_ptr_with_meta(array_place.ptr, ecx.layout_of(ty).unwrap(), meta)
+        }
+        _ => {
+            let layout = ecx.layout_of(ty).unwrap();
+            ecx.allocate(layout, MemoryKind::Stack).unwrap()
+        }
+    };
+    fill_place(ecx, &place, valtree);
+    place
+}
+
+fn fill_place<'tcx>(
+    ecx: &mut CompileTimeEvalContext<'tcx, 'tcx>,
+    place: &MPlaceTy<'tcx>,
+    valtree: ty::ValTree<'tcx>,
+) {
+    let ty = place.layout.ty;
+    match ty.kind() {
+        ty::FnDef(..) => {}
+        ty::Bool | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Char => {
+            ecx.write_scalar(Scalar::Int(valtree.unwrap_leaf()), &(*place).into()).unwrap();
+        }
+        ty::Ref(_, inner_ty, _) => {
+            let pointee = create_place(ecx, *inner_ty, valtree);
+            let imm = pointee.to_ref(ecx);
+            ecx.write_immediate(imm, &(*place).into()).unwrap();
+        }
+        ty::Str | ty::Slice(_) | ty::Array(..) => {
+            for (i, &element) in valtree.unwrap_branch().iter().enumerate() {
+                let element_place = ecx.mplace_index(place, u64::try_from(i).unwrap()).unwrap();
+                fill_place(ecx, &element_place, element);
+            }
+        }
+        ty::Tuple(_) | ty::Adt(..) => {
+            let branches = valtree.unwrap_branch();
+            // Enums start with their variant index, followed by the fields of that variant.
+            let (variant, fields) = match ty.kind() {
+                ty::Adt(def, _) if def.is_enum() => {
+                    let (variant, fields) = branches.split_first().unwrap();
+                    let variant = u32::try_from(variant.unwrap_leaf()).unwrap();
+                    (Some(VariantIdx::from_u32(variant)), fields)
+                }
+                _ => (None, branches),
+            };
+            let variant_place = match variant {
+                Some(variant) => ecx.mplace_downcast(place, variant).unwrap(),
+                None => *place,
+            };
+            for (i, &field) in fields.iter().enumerate() {
+                let field_place = ecx.mplace_field(&variant_place, i).unwrap();
+                fill_place(ecx, &field_place, field);
+            }
+            if let Some(variant) = variant {
+                ecx.write_discriminant(variant, &(*place).into()).unwrap();
+            }
+        }
+        _ => bug!("cannot write valtree {:?} of type {} into memory", valtree, ty),
+    }
+}
@@ -241,9 +241,9 @@ fn cast_from_float<F>(&self, f: F, dest_ty: Ty<'tcx>) -> Scalar<M::PointerTag>
                 Scalar::from_int(v, size)
             }
             // float -> f32
-            Float(FloatTy::F32) => Scalar::from_f32(f.convert(&mut false).value),
+            Float(FloatTy::F32) => Scalar::from_f32(self.adjust_nan(f.convert(&mut false).value)),
             // float -> f64
-            Float(FloatTy::F64) => Scalar::from_f64(f.convert(&mut false).value),
+            Float(FloatTy::F64) => Scalar::from_f64(self.adjust_nan(f.convert(&mut false).value)),
             // That's it.
             _ => span_bug!(self.cur_span(), "invalid float to {:?} cast", dest_ty),
         }
@@ -3,7 +3,7 @@
 use std::mem;
 
 use rustc_data_structures::stable_hasher::{HashStable, StableHasher};
-use rustc_hir::{self as hir, def_id::DefId, definitions::DefPathData};
+use rustc_hir::{self as hir, def::Namespace, def_id::DefId, definitions::DefPathData};
 use rustc_index::vec::IndexVec;
 use rustc_macros::HashStable;
 use rustc_middle::mir;
@@ -12,13 +12,14 @@
     self, FnAbiError, FnAbiOfHelpers, FnAbiRequest, LayoutError, LayoutOf, LayoutOfHelpers,
     TyAndLayout,
 };
+use rustc_middle::ty::print::{FmtPrinter, Printer};
 use rustc_middle::ty::{
     self, query::TyCtxtAt, subst::SubstsRef, ParamEnv, Ty, TyCtxt, TypeFoldable,
 };
 use rustc_mir_dataflow::storage::AlwaysLiveLocals;
 use rustc_query_system::ich::StableHashingContext;
 use rustc_session::Limit;
-use rustc_span::{Pos, Span};
+use rustc_span::Span;
 use rustc_target::abi::{call::FnAbi, Align, HasDataLayout, Size, TargetDataLayout};
 
 use super::{
@@ -268,25 +269,20 @@ pub fn current_span(&self) -> Span {
 impl<'tcx> fmt::Display for FrameInfo<'tcx> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         ty::tls::with(|tcx| {
-            if tcx.def_key(self.instance.def_id()).disambiguated_data.data
-                == DefPathData::ClosureExpr
-            {
-                write!(f, "inside closure")?;
+            let def_id = self.instance.def_id();
+            if tcx.def_key(def_id).disambiguated_data.data == DefPathData::ClosureExpr {
+                // Closures are printed with the generic args of the item they are defined in, the
+                // ones of the closure itself are not helpful.
+                let root_def_id = tcx.typeck_root_def_id(def_id);
+                let substs = tcx.lift(self.instance.substs).expect("could not lift for printing");
+                let substs = substs.truncate_to(tcx, tcx.generics_of(root_def_id));
+                write!(f, "inside closure in `")?;
+                FmtPrinter::new(tcx, &mut *f, Namespace::ValueNS)
+                    .print_def_path(root_def_id, substs)?;
+                write!(f, "`")
             } else {
-                write!(f, "inside `{}`", self.instance)?;
+                write!(f, "inside `{}`", self.instance)
             }
-            if !self.span.is_dummy() {
-                let sm = tcx.sess.source_map();
-                let lo = sm.lookup_char_pos(self.span.lo());
-                write!(
-                    f,
-                    " at {}:{}:{}",
-                    sm.filename_for_diagnostics(&lo.file.name),
-                    lo.line,
-                    lo.col.to_usize() + 1
-                )?;
-            }
-            Ok(())
         })
     }
 }
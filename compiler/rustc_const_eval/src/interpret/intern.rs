@@ -14,16 +14,19 @@
 //! always immutable. At least for `const` however we use this opportunity to reject any `const`
 //! that contains allocations whose mutability we cannot identify.)
 
-use super::validity::RefTracking;
+use super::validity::{aggregate_field_path_elem, write_path, PathElem, RefTracking};
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
-use rustc_errors::ErrorReported;
+use rustc_errors::{struct_span_err, ErrorReported};
 use rustc_hir as hir;
-use rustc_middle::mir::interpret::InterpResult;
+use rustc_middle::mir::interpret::{alloc_range, InterpResult};
 use rustc_middle::ty::{self, layout::TyAndLayout, Ty};
+use rustc_target::abi::{Size, VariantIdx};
 
 use rustc_ast::Mutability;
 
-use super::{AllocId, Allocation, InterpCx, MPlaceTy, Machine, MemoryKind, PlaceTy, ValueVisitor};
+use super::{
+    AllocId, Allocation, Immediate, InterpCx, MPlaceTy, Machine, MemoryKind, PlaceTy, ValueVisitor,
+};
 use crate::const_eval;
 
 pub trait CompileTimeMachine<'mir, 'tcx, T> = Machine<
@@ -40,8 +43,8 @@ pub trait CompileTimeMachine<'mir, 'tcx, T> = Machine<
 struct InternVisitor<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx, const_eval::MemoryKind>> {
     /// The ectx from which we intern.
     ecx: &'rt mut InterpCx<'mir, 'tcx, M>,
-    /// Previously encountered safe references.
-    ref_tracking: &'rt mut RefTracking<(MPlaceTy<'tcx>, InternMode)>,
+    /// Previously encountered safe references, with the path at which we found them.
+    ref_tracking: &'rt mut RefTracking<(MPlaceTy<'tcx>, InternMode), Vec<PathElem>>,
     /// A list of all encountered allocations. After type-based interning, we traverse this list to
     /// also intern allocations that are only referenced by a raw pointer or inside a union.
     leftover_allocations: &'rt mut FxHashSet<AllocId>,
@@ -52,6 +55,10 @@ struct InternVisitor<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx, const_ev
     /// This field stores whether we are *currently* inside an `UnsafeCell`. This can affect
     /// the intern mode of references we encounter.
     inside_unsafe_cell: bool,
+    /// The path to the value being visited, to point at dangling pointers and uninitialized bytes.
+    path: Vec<PathElem>,
+    /// Set once a dangling pointer or uninitialized bytes were reported.
+    found_invalid: &'rt mut bool,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Hash, Eq)]
@@ -82,8 +89,16 @@ fn intern_shallow<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx, const_eval:
     ty: Option<Ty<'tcx>>,
 ) -> Option<IsStaticOrFn> {
     trace!("intern_shallow {:?} with {:?}", alloc_id, mode);
-    // remove allocation
     let tcx = ecx.tcx;
+    if let Some((MemoryKind::Machine(const_eval::MemoryKind::Heap { was_made_global: false }), _)) =
+        ecx.memory.alloc_map.get(alloc_id)
+    {
+        // The heap allocation is still owned by the evaluation. Leave it to the interning of the
+        // leftover allocations, which reports it no matter how it is referenced.
+        leftover_allocations.insert(alloc_id);
+        return Some(IsStaticOrFn);
+    }
+    // remove allocation
     let (kind, mut alloc) = match ecx.memory.alloc_map.remove(&alloc_id) {
         Some(entry) => entry,
         None => {
@@ -103,11 +118,10 @@ fn intern_shallow<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx, const_eval:
     };
     // This match is just a canary for future changes to `MemoryKind`, which most likely need
     // changes in this function.
-    match kind {
-        MemoryKind::Stack
-        | MemoryKind::Machine(const_eval::MemoryKind::Heap)
-        | MemoryKind::CallerLocation => {}
-    }
+    let was_made_global = match kind {
+        MemoryKind::Machine(const_eval::MemoryKind::Heap { was_made_global }) => was_made_global,
+        MemoryKind::Stack | MemoryKind::CallerLocation => false,
+    };
     // Set allocation mutability as appropriate. This is used by LLVM to put things into
     // read-only memory, and also by Miri when evaluating other globals that
     // access this one.
@@ -121,6 +135,14 @@ fn intern_shallow<'rt, 'mir, 'tcx, M: CompileTimeMachine<'mir, 'tcx, const_eval:
         let immutable = mutability == Mutability::Not && frozen;
         if immutable {
             alloc.mutability = Mutability::Not;
+        } else if was_made_global {
+            // `const_make_global` made the allocation immutable for good, but the static could
+            // mutate it through this `&mut` or `UnsafeCell`.
+            tcx.sess.span_err(
+                tcx.span,
+                "encountered mutable reference or `UnsafeCell` to memory made immutable by \
+                 `const_make_global`",
+            );
         } else {
             // Just making sure we are not "upgrading" an immutable allocation to mutable.
             assert_eq!(alloc.mutability, Mutability::Mut);
@@ -151,6 +173,94 @@ fn intern_shallow(
     ) -> Option<IsStaticOrFn> {
         intern_shallow(self.ecx, self.leftover_allocations, alloc_id, mode, ty)
     }
+
+    /// Reports the pointer at the current path if it points to an allocation that was freed
+    /// during the evaluation, like a local of the evaluated body, or heap memory that was
+    /// deallocated again. Returns whether it did.
+    ///
+    /// The rest of the compiler, codegen in particular, assumes that all allocations referenced by
+    /// a constant exist, so this is always a hard error.
+    fn check_dangling(&mut self, alloc_id: AllocId) -> bool {
+        if !self.ecx.memory.dead_alloc_map.contains_key(&alloc_id) {
+            return false;
+        }
+        let mut path = String::new();
+        write_path(&mut path, &self.path);
+        let note = if path.is_empty() {
+            "the value points to a freed allocation".to_string()
+        } else {
+            format!("field `{}` points to a freed allocation", path)
+        };
+        let tcx = self.ecx.tcx;
+        struct_span_err!(
+            tcx.sess,
+            tcx.span,
+            E0790,
+            "encountered dangling pointer in final constant"
+        )
+        .note(&note)
+        .emit();
+        *self.found_invalid = true;
+        true
+    }
+
+    /// Reports uninitialized bytes in `mplace`, which holds plain data behind a reference: an
+    /// integer, float, `bool` or `char`, or a `str` or an array or slice of those.
+    ///
+    /// Other types are either walked down to these, or cannot be uninitialized anyway. Values that
+    /// are not behind a reference are left to validation.
+    fn check_init(&mut self, mplace: &MPlaceTy<'tcx>) {
+        // Errors, like out-of-bounds references, are left to validation as well.
+        let size = match self.ecx.size_and_align_of_mplace(mplace) {
+            Ok(Some((size, _))) => size,
+            _ => return,
+        };
+        let alloc = match self.ecx.memory.get(mplace.ptr, size, mplace.align) {
+            Ok(Some(alloc)) => alloc,
+            _ => return,
+        };
+        let err = match alloc.check_bytes(alloc_range(Size::ZERO, size), false) {
+            Err(err) => err,
+            Ok(()) => return,
+        };
+        let access = match err.kind() {
+            err_ub!(InvalidUninitBytes(Some((_, access)))) => access,
+            // Pointers in plain data are left to validation.
+            _ => return,
+        };
+        let (_, offset) = mplace.ptr.into_parts();
+        let mut path = self.path.clone();
+        if let ty::Str | ty::Array(..) | ty::Slice(_) = mplace.layout.ty.kind() {
+            // Point at the element with the first uninitialized byte.
+            let elem_size = mplace.layout.field(self.ecx, 0).size;
+            let index = (access.uninit_offset - offset).bytes() / elem_size.bytes();
+            path.push(PathElem::ArrayElem(usize::try_from(index).unwrap()));
+        }
+        let mut path_str = String::new();
+        write_path(&mut path_str, &path);
+        let tcx = self.ecx.tcx;
+        struct_span_err!(
+            tcx.sess,
+            tcx.span,
+            E0790,
+            "encountered uninitialized bytes behind a reference in final constant"
+        )
+        .note(&format!("field `{}` is uninitialized", path_str))
+        .emit();
+        *self.found_invalid = true;
+    }
+
+    fn with_elem(
+        &mut self,
+        elem: PathElem,
+        f: impl FnOnce(&mut Self) -> InterpResult<'tcx>,
+    ) -> InterpResult<'tcx> {
+        let path_len = self.path.len();
+        self.path.push(elem);
+        let r = f(self);
+        self.path.truncate(path_len);
+        r
+    }
 }
 
 impl<'rt, 'mir, 'tcx: 'mir, M: CompileTimeMachine<'mir, 'tcx, const_eval::MemoryKind>>
@@ -189,6 +299,30 @@ fn visit_aggregate(
         self.walk_aggregate(mplace, fields)
     }
 
+    fn visit_field(
+        &mut self,
+        old_mplace: &MPlaceTy<'tcx>,
+        field: usize,
+        new_mplace: &MPlaceTy<'tcx>,
+    ) -> InterpResult<'tcx> {
+        let elem = aggregate_field_path_elem(*self.ecx.tcx, old_mplace.layout, field);
+        self.with_elem(elem, |this| this.visit_value(new_mplace))
+    }
+
+    fn visit_variant(
+        &mut self,
+        old_mplace: &MPlaceTy<'tcx>,
+        variant_id: VariantIdx,
+        new_mplace: &MPlaceTy<'tcx>,
+    ) -> InterpResult<'tcx> {
+        let elem = match old_mplace.layout.ty.kind() {
+            ty::Adt(adt, _) => PathElem::Variant(adt.variants[variant_id].name),
+            ty::Generator(..) => PathElem::GeneratorState(variant_id),
+            _ => bug!("Unexpected type with variant: {:?}", old_mplace.layout.ty),
+        };
+        self.with_elem(elem, |this| this.visit_value(new_mplace))
+    }
+
     fn visit_value(&mut self, mplace: &MPlaceTy<'tcx>) -> InterpResult<'tcx> {
         // Handle Reference types, as these are the only relocations supported by const eval.
         // Raw pointers (and boxes) are handled by the `leftover_relocations` logic.
@@ -198,6 +332,11 @@ fn visit_value(&mut self, mplace: &MPlaceTy<'tcx>) -> InterpResult<'tcx> {
             let value = self.ecx.read_immediate(&(*mplace).into())?;
             let mplace = self.ecx.ref_to_mplace(&value)?;
             assert_eq!(mplace.layout.ty, referenced_ty);
+            if let Some(alloc_id) = mplace.ptr.provenance {
+                if self.check_dangling(alloc_id) {
+                    return Ok(());
+                }
+            }
             // Handle trait object vtables.
             if let ty::Dynamic(..) =
                 tcx.struct_tail_erasing_lifetimes(referenced_ty, self.ecx.param_env).kind()
@@ -266,10 +405,33 @@ fn visit_value(&mut self, mplace: &MPlaceTy<'tcx>) -> InterpResult<'tcx> {
                     // intern everything referenced by this value. The mutability is taken from the
                     // reference. It is checked above that mutable references only happen in
                     // `static mut`
-                    None => self.ref_tracking.track((mplace, ref_mode), || ()),
+                    None => {
+                        let path = &self.path;
+                        self.ref_tracking.track((mplace, ref_mode), || {
+                            let mut path = path.clone();
+                            path.push(PathElem::Deref);
+                            path
+                        })
+                    }
+                }
+            }
+            Ok(())
+        } else if let ty::RawPtr(..) = *ty.kind() {
+            // The memory behind raw pointers is interned with the leftover allocations, but it
+            // must not have been freed either. Invalid raw pointers are left to validation.
+            if let Ok(value) = self.ecx.read_immediate(&(*mplace).into()) {
+                let (Immediate::Scalar(ptr) | Immediate::ScalarPair(ptr, _)) = *value;
+                if let Ok(ptr) = ptr.check_init() {
+                    if let Some(alloc_id) = self.ecx.scalar_to_ptr(ptr).provenance {
+                        self.check_dangling(alloc_id);
+                    }
                 }
             }
             Ok(())
+        } else if self.path.iter().any(|elem| matches!(elem, PathElem::Deref)) && is_plain_data(ty)
+        {
+            self.check_init(mplace);
+            Ok(())
         } else {
             // Not a reference -- proceed recursively.
             self.walk_value(mplace)
@@ -277,6 +439,18 @@ fn visit_value(&mut self, mplace: &MPlaceTy<'tcx>) -> InterpResult<'tcx> {
     }
 }
 
+/// Whether values of type `ty` consist of initialized bytes only, without any padding, pointers or
+/// fields that need to be walked.
+fn is_plain_data(ty: Ty<'_>) -> bool {
+    match *ty.kind() {
+        ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Str => true,
+        ty::Array(elem_ty, _) | ty::Slice(elem_ty) => {
+            matches!(elem_ty.kind(), ty::Bool | ty::Char | ty::Int(_) | ty::Uint(_) | ty::Float(_))
+        }
+        _ => false,
+    }
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Hash, Eq)]
 pub enum InternKind {
     /// The `mutability` of the static, ignoring the type which may have interior mutability.
@@ -290,7 +464,9 @@ pub enum InternKind {
 /// This *cannot raise an interpreter error*.  Doing so is left to validation, which
 /// tracks where in the value we are and thus can show much better error messages.
 /// Any errors here would anyway be turned into `const_err` lints, whereas validation failures
-/// are hard errors.
+/// are hard errors. Dangling pointers are the exception: they are reported here, along with the
+/// path to them in the value, since the memory they point to cannot be validated anymore. So are
+/// uninitialized bytes behind references, which codegen cannot cope with.
 #[tracing::instrument(level = "debug", skip(ecx))]
 pub fn intern_const_alloc_recursive<
     'mir,
@@ -327,15 +503,18 @@ pub fn intern_const_alloc_recursive<
         Some(ret.layout.ty),
     );
 
-    ref_tracking.track((*ret, base_intern_mode), || ());
+    ref_tracking.track((*ret, base_intern_mode), Vec::new);
 
-    while let Some(((mplace, mode), _)) = ref_tracking.todo.pop() {
+    let mut found_invalid = false;
+    while let Some(((mplace, mode), path)) = ref_tracking.todo.pop() {
         let res = InternVisitor {
             ref_tracking: &mut ref_tracking,
             ecx,
             mode,
             leftover_allocations,
             inside_unsafe_cell: false,
+            path,
+            found_invalid: &mut found_invalid,
         }
         .visit_value(&mplace);
         // We deliberately *ignore* interpreter errors here.  When there is a problem, the remaining
@@ -355,16 +534,44 @@ pub fn intern_const_alloc_recursive<
         }
     }
 
+    if found_invalid {
+        return Err(ErrorReported);
+    }
+
     // Intern the rest of the allocations as mutable. These might be inside unions, padding, raw
     // pointers, ... So we can't intern them according to their type rules
 
     let mut todo: Vec<_> = leftover_allocations.iter().cloned().collect();
     while let Some(alloc_id) = todo.pop() {
-        if let Some((_, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
+        if let Some((kind, mut alloc)) = ecx.memory.alloc_map.remove(&alloc_id) {
             // We can't call the `intern_shallow` method here, as its logic is tailored to safe
             // references and a `leftover_allocations` set (where we only have a todo-list here).
             // So we hand-roll the interning logic here again.
+            let was_made_global = match kind {
+                MemoryKind::Machine(const_eval::MemoryKind::Heap { was_made_global: true }) => true,
+                MemoryKind::Machine(const_eval::MemoryKind::Heap { was_made_global: false }) => {
+                    // The heap allocation is still owned by the evaluation, so it could have been
+                    // changed or freed through the pointers of the final value.
+                    ecx.tcx
+                        .sess
+                        .struct_span_err(
+                            ecx.tcx.span,
+                            "encountered `const_allocate` pointer in final value that was not \
+                             made global",
+                        )
+                        .note(
+                            "use `const_make_global` to make allocated pointers immutable before \
+                             returning",
+                        )
+                        .emit();
+                    return Err(ErrorReported);
+                }
+                MemoryKind::Stack | MemoryKind::CallerLocation => false,
+            };
             match intern_kind {
+                // The heap allocations made global are immutable, so they can be part of any
+                // value, even behind raw pointers, like the buffers of collections.
+                _ if was_made_global => {}
                 // Statics may contain mutable allocations even behind relocations.
                 // Even for immutable statics it would be ok to have mutable allocations behind
                 // raw pointers, e.g. for `static FOO: *const AtomicUsize = &AtomicUsize::new(42)`.
@@ -314,6 +314,18 @@ fn init_allocation_extra<'b>(
         kind: Option<MemoryKind<Self::MemoryKind>>,
     ) -> Cow<'b, Allocation<Self::PointerTag, Self::AllocExtra>>;
 
+    /// Hook for performing extra checks before `size` bytes of memory of the given `kind` are
+    /// allocated. All the allocations of the local memory go through this hook, no matter how
+    /// they are created.
+    #[inline(always)]
+    fn before_memory_allocation(
+        _memory_extra: &mut Self::MemoryExtra,
+        _size: Size,
+        _kind: MemoryKind<Self::MemoryKind>,
+    ) -> InterpResult<'static> {
+        Ok(())
+    }
+
     /// Hook for performing extra checks on a memory read access.
     ///
     /// Takes read-only access to the allocation so we can keep all the memory read
@@ -208,7 +208,7 @@ pub fn allocate(
         kind: MemoryKind<M::MemoryKind>,
     ) -> InterpResult<'static, Pointer<M::PointerTag>> {
         let alloc = Allocation::uninit(size, align, M::PANIC_ON_ALLOC_FAIL)?;
-        Ok(self.allocate_with(alloc, kind))
+        self.allocate_with(alloc, kind)
     }
 
     pub fn allocate_bytes(
@@ -217,7 +217,7 @@ pub fn allocate_bytes(
         align: Align,
         kind: MemoryKind<M::MemoryKind>,
         mutability: Mutability,
-    ) -> Pointer<M::PointerTag> {
+    ) -> InterpResult<'static, Pointer<M::PointerTag>> {
         let alloc = Allocation::from_bytes(bytes, align, mutability);
         self.allocate_with(alloc, kind)
     }
@@ -226,7 +226,8 @@ pub fn allocate_with(
         &mut self,
         alloc: Allocation,
         kind: MemoryKind<M::MemoryKind>,
-    ) -> Pointer<M::PointerTag> {
+    ) -> InterpResult<'static, Pointer<M::PointerTag>> {
+        M::before_memory_allocation(&mut self.extra, alloc.size(), kind)?;
         let id = self.tcx.reserve_alloc_id();
         debug_assert_ne!(
             Some(kind),
@@ -235,7 +236,7 @@ pub fn allocate_with(
         );
         let alloc = M::init_allocation_extra(self, id, Cow::Owned(alloc), Some(kind));
         self.alloc_map.insert(id, (kind, alloc.into_owned()));
-        M::tag_alloc_base_pointer(self, Pointer::from(id))
+        Ok(M::tag_alloc_base_pointer(self, Pointer::from(id)))
     }
 
     pub fn reallocate(
@@ -830,6 +831,15 @@ pub fn leak_report(&self, static_roots: &[AllocId]) -> usize {
     pub fn alloc_map(&self) -> &M::MemoryMap {
         &self.alloc_map
     }
+
+    /// Gives mutable access to a local allocation and its kind, so that machines can change the
+    /// kind of their own allocations, e.g. to hand them over to the final value of a constant.
+    pub fn get_local_alloc_mut(
+        &mut self,
+        id: AllocId,
+    ) -> Option<&mut (MemoryKind<M::MemoryKind>, Allocation<M::PointerTag, M::AllocExtra>)> {
+        self.alloc_map.get_mut(id)
+    }
 }
 
 #[doc(hidden)]
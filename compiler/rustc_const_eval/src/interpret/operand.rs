@@ -574,7 +574,10 @@ pub fn const_to_op(
             ty::ConstKind::Infer(..) | ty::ConstKind::Placeholder(..) => {
                 span_bug!(self.cur_span(), "const_to_op: Unexpected ConstKind {:?}", val)
             }
-            ty::ConstKind::Value(val_val) => self.const_val_to_op(val_val, val.ty, layout),
+            ty::ConstKind::Value(valtree) => {
+                let const_val = self.tcx.valtree_to_const_val((val.ty, valtree));
+                self.const_val_to_op(const_val, val.ty, layout)
+            }
         }
     }
 
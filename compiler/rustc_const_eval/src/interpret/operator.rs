@@ -88,6 +88,19 @@ fn binary_bool_op(
         (Scalar::from_bool(res), false, self.tcx.types.bool)
     }
 
+    /// Replaces a NaN result of a float operation with the preferred NaN, that is, the quiet NaN
+    /// with a positive sign and no payload.
+    ///
+    /// The hardware is free to pick the sign and payload of the NaN it produces, so they differ
+    /// between targets. Using the preferred NaN makes evaluating float code deterministic.
+    pub(super) fn adjust_nan<F: Float>(&self, f: F) -> F {
+        if f.is_nan() {
+            F::NAN
+        } else {
+            f
+        }
+    }
+
     fn binary_float_op<F: Float + Into<Scalar<M::PointerTag>>>(
         &self,
         bin_op: mir::BinOp,
@@ -104,11 +117,11 @@ fn binary_float_op<F: Float + Into<Scalar<M::PointerTag>>>(
             Le => (Scalar::from_bool(l <= r), self.tcx.types.bool),
             Gt => (Scalar::from_bool(l > r), self.tcx.types.bool),
             Ge => (Scalar::from_bool(l >= r), self.tcx.types.bool),
-            Add => ((l + r).value.into(), ty),
-            Sub => ((l - r).value.into(), ty),
-            Mul => ((l * r).value.into(), ty),
-            Div => ((l / r).value.into(), ty),
-            Rem => ((l % r).value.into(), ty),
+            Add => (self.adjust_nan((l + r).value).into(), ty),
+            Sub => (self.adjust_nan((l - r).value).into(), ty),
+            Mul => (self.adjust_nan((l * r).value).into(), ty),
+            Div => (self.adjust_nan((l / r).value).into(), ty),
+            Rem => (self.adjust_nan((l % r).value).into(), ty),
             _ => span_bug!(self.cur_span(), "invalid float op: `{:?}`", bin_op),
         };
         (val, false, ty)
@@ -167,6 +167,8 @@ pub(super) fn eval_terminator(
 
             // Inline assembly can't be interpreted.
             InlineAsm { .. } => throw_unsup_format!("inline assembly is not supported"),
+
+            TailCall { .. } => throw_unsup_format!("tail calls are not supported"),
         }
 
         Ok(())
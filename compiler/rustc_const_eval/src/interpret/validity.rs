@@ -11,8 +11,8 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
 use rustc_middle::mir::interpret::InterpError;
-use rustc_middle::ty;
 use rustc_middle::ty::layout::{LayoutOf, TyAndLayout};
+use rustc_middle::ty::{self, TyCtxt};
 use rustc_span::symbol::{sym, Symbol};
 use rustc_span::DUMMY_SP;
 use rustc_target::abi::{Abi, Scalar as ScalarAbi, Size, VariantIdx, Variants, WrappingRange};
@@ -157,7 +157,7 @@ pub fn track(&mut self, op: T, path: impl FnOnce() -> PATH) {
 }
 
 /// Format a path
-fn write_path(out: &mut String, path: &[PathElem]) {
+pub(super) fn write_path(out: &mut String, path: &[PathElem]) {
     use self::PathElem::*;
 
     for elem in path.iter() {
@@ -201,92 +201,97 @@ fn wrapping_range_format(r: WrappingRange, max_hi: u128) -> String {
     }
 }
 
-struct ValidityVisitor<'rt, 'mir, 'tcx, M: Machine<'mir, 'tcx>> {
-    /// The `path` may be pushed to, but the part that is present when a function
-    /// starts must not be changed!  `visit_fields` and `visit_array` rely on
-    /// this stack discipline.
-    path: Vec<PathElem>,
-    ref_tracking: Option<&'rt mut RefTracking<MPlaceTy<'tcx, M::PointerTag>, Vec<PathElem>>>,
-    /// `None` indicates this is not validating for CTFE (but for runtime).
-    ctfe_mode: Option<CtfeValidationMode>,
-    ecx: &'rt InterpCx<'mir, 'tcx, M>,
-}
-
-impl<'rt, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> ValidityVisitor<'rt, 'mir, 'tcx, M> {
-    fn aggregate_field_path_elem(&mut self, layout: TyAndLayout<'tcx>, field: usize) -> PathElem {
-        // First, check if we are projecting to a variant.
-        match layout.variants {
-            Variants::Multiple { tag_field, .. } => {
-                if tag_field == field {
-                    return match layout.ty.kind() {
-                        ty::Adt(def, ..) if def.is_enum() => PathElem::EnumTag,
-                        ty::Generator(..) => PathElem::GeneratorTag,
-                        _ => bug!("non-variant type {:?}", layout.ty),
-                    };
-                }
+/// Returns the path element for projecting to field `field` of a value with the given `layout`.
+pub(super) fn aggregate_field_path_elem<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    layout: TyAndLayout<'tcx>,
+    field: usize,
+) -> PathElem {
+    // First, check if we are projecting to a variant.
+    match layout.variants {
+        Variants::Multiple { tag_field, .. } => {
+            if tag_field == field {
+                return match layout.ty.kind() {
+                    ty::Adt(def, ..) if def.is_enum() => PathElem::EnumTag,
+                    ty::Generator(..) => PathElem::GeneratorTag,
+                    _ => bug!("non-variant type {:?}", layout.ty),
+                };
             }
-            Variants::Single { .. } => {}
         }
+        Variants::Single { .. } => {}
+    }
 
-        // Now we know we are projecting to a field, so figure out which one.
-        match layout.ty.kind() {
-            // generators and closures.
-            ty::Closure(def_id, _) | ty::Generator(def_id, _, _) => {
-                let mut name = None;
-                // FIXME this should be more descriptive i.e. CapturePlace instead of CapturedVar
-                // https://github.com/rust-lang/project-rfc-2229/issues/46
-                if let Some(local_def_id) = def_id.as_local() {
-                    let tables = self.ecx.tcx.typeck(local_def_id);
-                    if let Some(captured_place) =
-                        tables.closure_min_captures_flattened(*def_id).nth(field)
-                    {
-                        // Sometimes the index is beyond the number of upvars (seen
-                        // for a generator).
-                        let var_hir_id = captured_place.get_root_variable();
-                        let node = self.ecx.tcx.hir().get(var_hir_id);
-                        if let hir::Node::Binding(pat) = node {
-                            if let hir::PatKind::Binding(_, _, ident, _) = pat.kind {
-                                name = Some(ident.name);
-                            }
+    // Now we know we are projecting to a field, so figure out which one.
+    match layout.ty.kind() {
+        // generators and closures.
+        ty::Closure(def_id, _) | ty::Generator(def_id, _, _) => {
+            let mut name = None;
+            // FIXME this should be more descriptive i.e. CapturePlace instead of CapturedVar
+            // https://github.com/rust-lang/project-rfc-2229/issues/46
+            if let Some(local_def_id) = def_id.as_local() {
+                let tables = tcx.typeck(local_def_id);
+                if let Some(captured_place) =
+                    tables.closure_min_captures_flattened(*def_id).nth(field)
+                {
+                    // Sometimes the index is beyond the number of upvars (seen
+                    // for a generator).
+                    let var_hir_id = captured_place.get_root_variable();
+                    let node = tcx.hir().get(var_hir_id);
+                    if let hir::Node::Binding(pat) = node {
+                        if let hir::PatKind::Binding(_, _, ident, _) = pat.kind {
+                            name = Some(ident.name);
                         }
                     }
                 }
-
-                PathElem::CapturedVar(name.unwrap_or_else(|| {
-                    // Fall back to showing the field index.
-                    sym::integer(field)
-                }))
             }
 
-            // tuples
-            ty::Tuple(_) => PathElem::TupleElem(field),
+            PathElem::CapturedVar(name.unwrap_or_else(|| {
+                // Fall back to showing the field index.
+                sym::integer(field)
+            }))
+        }
 
-            // enums
-            ty::Adt(def, ..) if def.is_enum() => {
-                // we might be projecting *to* a variant, or to a field *in* a variant.
-                match layout.variants {
-                    Variants::Single { index } => {
-                        // Inside a variant
-                        PathElem::Field(def.variants[index].fields[field].name)
-                    }
-                    Variants::Multiple { .. } => bug!("we handled variants above"),
+        // tuples
+        ty::Tuple(_) => PathElem::TupleElem(field),
+
+        // enums
+        ty::Adt(def, ..) if def.is_enum() => {
+            // we might be projecting *to* a variant, or to a field *in* a variant.
+            match layout.variants {
+                Variants::Single { index } => {
+                    // Inside a variant
+                    PathElem::Field(def.variants[index].fields[field].name)
                 }
+                Variants::Multiple { .. } => bug!("we handled variants above"),
             }
+        }
 
-            // other ADTs
-            ty::Adt(def, _) => PathElem::Field(def.non_enum_variant().fields[field].name),
+        // other ADTs
+        ty::Adt(def, _) => PathElem::Field(def.non_enum_variant().fields[field].name),
 
-            // arrays/slices
-            ty::Array(..) | ty::Slice(..) => PathElem::ArrayElem(field),
+        // arrays/slices
+        ty::Array(..) | ty::Slice(..) => PathElem::ArrayElem(field),
 
-            // dyn traits
-            ty::Dynamic(..) => PathElem::DynDowncast,
+        // dyn traits
+        ty::Dynamic(..) => PathElem::DynDowncast,
 
-            // nothing else has an aggregate layout
-            _ => bug!("aggregate_field_path_elem: got non-aggregate type {:?}", layout.ty),
-        }
+        // nothing else has an aggregate layout
+        _ => bug!("aggregate_field_path_elem: got non-aggregate type {:?}", layout.ty),
     }
+}
+
+struct ValidityVisitor<'rt, 'mir, 'tcx, M: Machine<'mir, 'tcx>> {
+    /// The `path` may be pushed to, but the part that is present when a function
+    /// starts must not be changed!  `visit_fields` and `visit_array` rely on
+    /// this stack discipline.
+    path: Vec<PathElem>,
+    ref_tracking: Option<&'rt mut RefTracking<MPlaceTy<'tcx, M::PointerTag>, Vec<PathElem>>>,
+    /// `None` indicates this is not validating for CTFE (but for runtime).
+    ctfe_mode: Option<CtfeValidationMode>,
+    ecx: &'rt InterpCx<'mir, 'tcx, M>,
+}
 
+impl<'rt, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> ValidityVisitor<'rt, 'mir, 'tcx, M> {
     fn with_elem<R>(
         &mut self,
         elem: PathElem,
@@ -714,7 +719,7 @@ fn visit_field(
         field: usize,
         new_op: &OpTy<'tcx, M::PointerTag>,
     ) -> InterpResult<'tcx> {
-        let elem = self.aggregate_field_path_elem(old_op.layout, field);
+        let elem = aggregate_field_path_elem(*self.ecx.tcx, old_op.layout, field);
         self.with_elem(elem, move |this| this.visit_value(new_op))
     }
 
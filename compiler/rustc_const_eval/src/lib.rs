@@ -41,16 +41,20 @@ pub fn provide(providers: &mut Providers) {
     providers.eval_to_const_value_raw = const_eval::eval_to_const_value_raw_provider;
     providers.eval_to_allocation_raw = const_eval::eval_to_allocation_raw_provider;
     providers.const_caller_location = const_eval::const_caller_location;
+    providers.eval_to_valtree = |tcx, param_env_and_value| {
+        let (param_env, cid) = param_env_and_value.into_parts();
+        const_eval::eval_to_valtree(tcx, param_env, cid)
+    };
+    providers.valtree_to_const_val = const_eval::valtree_to_const_value;
     providers.destructure_const = |tcx, param_env_and_value| {
-        let (param_env, value) = param_env_and_value.into_parts();
-        const_eval::destructure_const(tcx, param_env, value)
+        let (_, value) = param_env_and_value.into_parts();
+        const_eval::destructure_const(tcx, value)
     };
-    providers.const_to_valtree = |tcx, param_env_and_value| {
-        let (param_env, raw) = param_env_and_value.into_parts();
-        const_eval::const_to_valtree(tcx, param_env, raw)
+    providers.destructure_mir_constant = |tcx, param_env_and_value| {
+        let (param_env, value) = param_env_and_value.into_parts();
+        const_eval::destructure_mir_constant(tcx, param_env, value)
     };
-    providers.deref_const = |tcx, param_env_and_value| {
+    providers.deref_mir_constant = |tcx, param_env_and_value| {
         let (param_env, value) = param_env_and_value.into_parts();
-        const_eval::deref_const(tcx, param_env, value)
+        const_eval::deref_mir_constant(tcx, param_env, value)
     };
-}
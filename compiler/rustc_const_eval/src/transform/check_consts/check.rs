@@ -453,9 +453,9 @@ fn check_mut_borrow(&mut self, local: Local, kind: hir::BorrowKind) {
             // references in the arguments (so we already checked them with
             // TransientMutBorrow/MutBorrow as appropriate).
             // The borrow checker guarantees that no new non-transient borrows are created.
-            // NOTE: Once we have heap allocations during CTFE we need to figure out
-            // how to prevent `const fn` to create long-lived allocations that point
-            // to mutable memory.
+            // Mutable references into the heap allocations of `const_allocate` can be long-lived,
+            // but they are caught dynamically: interning makes these allocations immutable, and
+            // validation rejects the mutable references in the final value of constants.
             hir::ConstContext::ConstFn => self.check_op(ops::TransientMutBorrow(kind)),
             _ => {
                 // Locals with StorageDead do not live beyond the evaluation and can
@@ -537,6 +537,7 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
             Rvalue::ThreadLocalRef(_) => self.check_op(ops::ThreadLocalAccess),
 
             Rvalue::Use(_)
+            | Rvalue::CopyForDeref(..)
             | Rvalue::Repeat(..)
             | Rvalue::Discriminant(..)
             | Rvalue::Len(_)
@@ -628,8 +629,8 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
             ) => self.check_op(ops::FnPtrCast),
 
             Rvalue::Cast(CastKind::Pointer(PointerCast::Unsize), _, _) => {
-                // Nothing to check here (`check_local_or_return_ty` ensures no trait objects occur
-                // in the type of any local, which also excludes casts).
+                // Nothing to check here: unsizing to a slice or trait object is fine, and
+                // `check_local_or_return_ty` ensures that no trait objects occur in the signature.
             }
 
             Rvalue::Cast(CastKind::Misc, ref operand, cast_ty) => {
@@ -647,10 +648,8 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
 
             Rvalue::UnaryOp(_, ref operand) => {
                 let ty = operand.ty(self.body, self.tcx);
-                if is_int_bool_or_char(ty) {
-                    // Int, bool, and char operations are fine.
-                } else if ty.is_floating_point() {
-                    self.check_op(ops::FloatingPointOp);
+                if is_int_bool_or_char(ty) || ty.is_floating_point() {
+                    // Int, bool, char and float operations are fine.
                 } else {
                     span_bug!(self.span, "non-primitive type in `Rvalue::UnaryOp`: {:?}", ty);
                 }
@@ -663,6 +662,9 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
 
                 if is_int_bool_or_char(lhs_ty) && is_int_bool_or_char(rhs_ty) {
                     // Int, bool, and char operations are fine.
+                } else if lhs_ty.is_floating_point() || rhs_ty.is_floating_point() {
+                    // Float operations are computed with a software implementation of IEEE 754,
+                    // so they give the same results on every target.
                 } else if lhs_ty.is_fn_ptr() || lhs_ty.is_unsafe_ptr() {
                     assert_eq!(lhs_ty, rhs_ty);
                     assert!(
@@ -676,8 +678,6 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
                     );
 
                     self.check_op(ops::RawPtrComparison);
-                } else if lhs_ty.is_floating_point() || rhs_ty.is_floating_point() {
-                    self.check_op(ops::FloatingPointOp);
                 } else {
                     span_bug!(
                         self.span,
@@ -1031,6 +1031,8 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
 
             TerminatorKind::InlineAsm { .. } => self.check_op(ops::InlineAsm),
 
+            TerminatorKind::TailCall { .. } => self.check_op(ops::TailCall),
+
             TerminatorKind::GeneratorDrop | TerminatorKind::Yield { .. } => {
                 self.check_op(ops::Generator(hir::GeneratorKind::Gen))
             }
@@ -42,36 +42,24 @@ fn importance(&self) -> DiagnosticImportance {
     fn build_error<'tcx>(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<'tcx>;
 }
 
+/// A function call where the callee is a pointer.
 #[derive(Debug)]
-pub struct FloatingPointOp;
-impl NonConstOp for FloatingPointOp {
-    fn status_in_item(&self, ccx: &ConstCx<'_, '_>) -> Status {
-        if ccx.const_kind() == hir::ConstContext::ConstFn {
-            Status::Unstable(sym::const_fn_floating_point_arithmetic)
-        } else {
-            Status::Allowed
-        }
+pub struct FnCallIndirect;
+impl NonConstOp for FnCallIndirect {
+    fn status_in_item(&self, _: &ConstCx<'_, '_>) -> Status {
+        Status::Unstable(sym::const_fn_ptr_calls)
     }
 
     fn build_error<'tcx>(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<'tcx> {
         feature_err(
             &ccx.tcx.sess.parse_sess,
-            sym::const_fn_floating_point_arithmetic,
+            sym::const_fn_ptr_calls,
             span,
-            &format!("floating point arithmetic is not allowed in {}s", ccx.const_kind()),
+            &format!("function pointer calls are not allowed in {}s", ccx.const_kind()),
         )
     }
 }
 
-/// A function call where the callee is a pointer.
-#[derive(Debug)]
-pub struct FnCallIndirect;
-impl NonConstOp for FnCallIndirect {
-    fn build_error<'tcx>(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<'tcx> {
-        ccx.tcx.sess.struct_span_err(span, "function pointers are not allowed in const fn")
-    }
-}
-
 /// A function call where the callee is not marked as `const`.
 #[derive(Debug)]
 pub struct FnCallNonConst<'tcx>(pub Option<(DefId, SubstsRef<'tcx>)>);
@@ -252,6 +240,16 @@ fn build_error<'tcx>(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBu
     }
 }
 
+#[derive(Debug)]
+pub struct TailCall;
+impl NonConstOp for TailCall {
+    fn build_error<'tcx>(&self, ccx: &ConstCx<'_, 'tcx>, span: Span) -> DiagnosticBuilder<'tcx> {
+        ccx.tcx
+            .sess
+            .struct_span_err(span, &format!("tail calls are not allowed in {}s", ccx.const_kind()))
+    }
+}
+
 #[derive(Debug)]
 pub struct LiveDrop {
     pub dropped_at: Option<Span>,
@@ -672,23 +670,23 @@ fn build_error<'tcx>(
         }
     }
 
+    /// A trait object in the signature of a `const fn`.
+    ///
+    /// Trait objects in the locals and temporaries of the body, like the ones created by unsizing
+    /// coercions in `Wrapper { field: &VALUE }`, are allowed: they can only be built from concrete
+    /// types, whose vtables the interpreter creates just like for the final value of a constant.
     #[derive(Debug)]
     pub struct DynTrait(pub mir::LocalKind);
     impl NonConstOp for DynTrait {
-        fn importance(&self) -> DiagnosticImportance {
+        fn status_in_item(&self, ccx: &ConstCx<'_, '_>) -> Status {
             match self.0 {
-                mir::LocalKind::Var | mir::LocalKind::Temp => DiagnosticImportance::Secondary,
-                mir::LocalKind::ReturnPointer | mir::LocalKind::Arg => {
-                    DiagnosticImportance::Primary
+                mir::LocalKind::Var | mir::LocalKind::Temp => Status::Allowed,
+                mir::LocalKind::ReturnPointer | mir::LocalKind::Arg
+                    if ccx.const_kind() == hir::ConstContext::ConstFn =>
+                {
+                    Status::Unstable(sym::const_fn_trait_bound)
                 }
-            }
-        }
-
-        fn status_in_item(&self, ccx: &ConstCx<'_, '_>) -> Status {
-            if ccx.const_kind() != hir::ConstContext::ConstFn {
-                Status::Allowed
-            } else {
-                Status::Unstable(sym::const_fn_trait_bound)
+                mir::LocalKind::ReturnPointer | mir::LocalKind::Arg => Status::Allowed,
             }
         }
 
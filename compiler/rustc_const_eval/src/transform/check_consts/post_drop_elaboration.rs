@@ -115,6 +115,7 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
             | mir::TerminatorKind::InlineAsm { .. }
             | mir::TerminatorKind::Resume
             | mir::TerminatorKind::Return
+            | mir::TerminatorKind::TailCall { .. }
             | mir::TerminatorKind::SwitchInt { .. }
             | mir::TerminatorKind::Unreachable
             | mir::TerminatorKind::Yield { .. } => {}
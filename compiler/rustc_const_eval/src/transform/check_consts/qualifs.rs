@@ -258,7 +258,7 @@ pub fn in_rvalue<'tcx, Q, F>(
             Q::in_any_value_of_ty(cx, rvalue.ty(cx.body, cx.tcx))
         }
 
-        Rvalue::Discriminant(place) | Rvalue::Len(place) => {
+        Rvalue::Discriminant(place) | Rvalue::Len(place) | Rvalue::CopyForDeref(place) => {
             in_place::<Q, _>(cx, in_local, place.as_ref())
         }
 
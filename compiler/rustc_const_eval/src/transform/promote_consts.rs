@@ -13,12 +13,16 @@
 //! move analysis runs after promotion on broken MIR.
 
 use rustc_hir as hir;
+use rustc_index::bit_set::BitSet;
 use rustc_middle::mir::traversal::ReversePostorder;
-use rustc_middle::mir::visit::{MutVisitor, MutatingUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::visit::{
+    MutVisitor, MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor,
+};
 use rustc_middle::mir::*;
 use rustc_middle::ty::cast::CastTy;
 use rustc_middle::ty::subst::InternalSubsts;
 use rustc_middle::ty::{self, List, TyCtxt, TypeFoldable};
+use rustc_session::lint::builtin::CONST_FN_CALL_PROMOTION;
 use rustc_span::Span;
 
 use rustc_index::vec::{Idx, IndexVec};
@@ -98,6 +102,9 @@ pub fn is_promotable(&self) -> bool {
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub struct Candidate {
     location: Location,
+    /// Whether this borrow only gets the `'static` lifetime it needs because a `const fn` call is
+    /// promoted in a `const` or `static` initializer, which `CONST_FN_CALL_PROMOTION` warns about.
+    relies_on_const_fn_promotion: bool,
 }
 
 struct Collector<'a, 'tcx> {
@@ -161,7 +168,7 @@ fn visit_rvalue(&mut self, rvalue: &Rvalue<'tcx>, location: Location) {
 
         match *rvalue {
             Rvalue::Ref(..) => {
-                self.candidates.push(Candidate { location });
+                self.candidates.push(Candidate { location, relies_on_const_fn_promotion: false });
             }
             _ => {}
         }
@@ -189,6 +196,9 @@ pub fn collect_temps_and_candidates<'tcx>(
 struct Validator<'a, 'tcx> {
     ccx: &'a ConstCx<'a, 'tcx>,
     temps: &'a IndexVec<Local, TempState>,
+    /// Whether the candidate being validated calls a `const fn` without `#[rustc_promotable]`,
+    /// which is only promoted inside const/static items.
+    calls_non_promotable_const_fn: Cell<bool>,
 }
 
 impl<'a, 'tcx> std::ops::Deref for Validator<'a, 'tcx> {
@@ -486,6 +496,10 @@ fn validate_rvalue(&self, rvalue: &Rvalue<'tcx>) -> Result<(), Unpromotable> {
                 self.validate_operand(operand)?;
             }
 
+            Rvalue::CopyForDeref(place) => {
+                self.validate_operand(&Operand::Copy(*place))?;
+            }
+
             Rvalue::Discriminant(place) | Rvalue::Len(place) => {
                 self.validate_place(place.as_ref())?
             }
@@ -629,19 +643,21 @@ fn validate_call(
     ) -> Result<(), Unpromotable> {
         let fn_ty = callee.ty(self.body, self.tcx);
 
-        // Inside const/static items, we promote all (eligible) function calls.
+        // Inside const/static items, we still promote the (eligible) function calls of the borrows
+        // that need it, but `promote_candidates` warns about them.
         // Everywhere else, we require `#[rustc_promotable]` on the callee.
         let promote_all_const_fn = matches!(
             self.const_kind,
             Some(hir::ConstContext::Static(_) | hir::ConstContext::Const)
         );
-        if !promote_all_const_fn {
-            if let ty::FnDef(def_id, _) = *fn_ty.kind() {
+        if let ty::FnDef(def_id, _) = *fn_ty.kind() {
+            if !self.tcx.is_promotable_const_fn(def_id) {
                 // Never promote runtime `const fn` calls of
                 // functions without `#[rustc_promotable]`.
-                if !self.tcx.is_promotable_const_fn(def_id) {
+                if !promote_all_const_fn {
                     return Err(Unpromotable);
                 }
+                self.calls_non_promotable_const_fn.set(true);
             }
         }
 
@@ -668,15 +684,127 @@ pub fn validate_candidates(
     temps: &IndexVec<Local, TempState>,
     candidates: &[Candidate],
 ) -> Vec<Candidate> {
-    let validator = Validator { ccx, temps };
+    let validator = Validator { ccx, temps, calls_non_promotable_const_fn: Cell::new(false) };
+    let mut local_has_storage_dead = BitSet::new_empty(ccx.body.local_decls.len());
+    for data in ccx.body.basic_blocks() {
+        for statement in &data.statements {
+            if let StatementKind::StorageDead(local) = statement.kind {
+                local_has_storage_dead.insert(local);
+            }
+        }
+    }
 
     candidates
         .iter()
-        .copied()
-        .filter(|&candidate| validator.validate_candidate(candidate).is_ok())
+        .filter_map(|&candidate| {
+            validator.calls_non_promotable_const_fn.set(false);
+            if validator.validate_candidate(candidate).is_err() {
+                return None;
+            }
+            if !validator.calls_non_promotable_const_fn.get() {
+                return Some(candidate);
+            }
+
+            let statement =
+                &ccx.body[candidate.location.block].statements[candidate.location.statement_index];
+            let StatementKind::Assign(box (dest, Rvalue::Ref(_, _, place))) = &statement.kind else {
+                bug!()
+            };
+            // Temps without `StorageDead` live until the end of the initializer, and become part
+            // of its final value. Their borrows do not need to be promoted to be `'static`.
+            if !local_has_storage_dead.contains(place.local) {
+                return None;
+            }
+            // Neither do the borrows that are only read through: the temp outlives them.
+            if !ref_escapes(ccx.body, *dest) {
+                return None;
+            }
+            Some(Candidate { relies_on_const_fn_promotion: true, ..candidate })
+        })
         .collect()
 }
 
+/// Returns whether the reference assigned to `dest` may have to outlive the value it borrows,
+/// because it, or a reborrow of it, is used for something else than reading through it, or its
+/// type is annotated.
+///
+/// This is conservative: that a reference escapes doesn't mean that it needs to be `'static`.
+fn ref_escapes<'tcx>(body: &Body<'tcx>, dest: Place<'tcx>) -> bool {
+    let dest = match dest.as_local() {
+        Some(dest) => dest,
+        None => return true,
+    };
+    let mut visitor =
+        RefUses { holders: BitSet::new_empty(body.local_decls.len()), escapes: false };
+    visitor.holders.insert(dest);
+    // Reborrows and copies make more locals hold the reference, so iterate until no new ones are
+    // found.
+    loop {
+        let holders = visitor.holders.count();
+        visitor.visit_body(body);
+        if visitor.escapes || visitor.holders.count() == holders {
+            break;
+        }
+    }
+    visitor.escapes
+        || visitor.holders.contains(RETURN_PLACE)
+        || visitor.holders.iter().any(|local| body.local_decls[local].user_ty.is_some())
+}
+
+struct RefUses {
+    /// The locals that hold the reference, or a reborrow of it.
+    holders: BitSet<Local>,
+    escapes: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for RefUses {
+    fn visit_assign(&mut self, dest: &Place<'tcx>, rvalue: &Rvalue<'tcx>, location: Location) {
+        let source = match rvalue {
+            Rvalue::Use(Operand::Copy(place) | Operand::Move(place)) => place.as_local(),
+            Rvalue::Ref(_, _, place) | Rvalue::AddressOf(_, place)
+                if place.projection.first() == Some(&ProjectionElem::Deref) =>
+            {
+                Some(place.local)
+            }
+            _ => None,
+        };
+        match source {
+            Some(source) if self.holders.contains(source) => match dest.as_local() {
+                Some(dest) => {
+                    self.holders.insert(dest);
+                }
+                None => self.escapes = true,
+            },
+            _ => self.super_assign(dest, rvalue, location),
+        }
+    }
+
+    fn visit_ascribe_user_ty(
+        &mut self,
+        place: &Place<'tcx>,
+        _variance: &ty::Variance,
+        _user_ty: &UserTypeProjection,
+        _location: Location,
+    ) {
+        if self.holders.contains(place.local) {
+            self.escapes = true;
+        }
+    }
+
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+        // Uses of the reference itself, rather than of what it points to.
+        if self.holders.contains(place.local) && place.projection.is_empty() {
+            match context {
+                PlaceContext::NonUse(_)
+                | PlaceContext::NonMutatingUse(NonMutatingUseContext::Inspect)
+                | PlaceContext::MutatingUse(MutatingUseContext::Store) => {}
+                _ => self.escapes = true,
+            }
+        }
+        self.super_place(place, context, location);
+    }
+}
+
 struct Promoter<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     source: &'a mut Body<'tcx>,
@@ -765,7 +893,7 @@ fn promote_temp(&mut self, temp: Local) -> Local {
                         let unit = Rvalue::Use(Operand::Constant(Box::new(Constant {
                             span: statement.source_info.span,
                             user_ty: None,
-                            literal: ty::Const::zero_sized(self.tcx, self.tcx.types.unit).into(),
+                            literal: ConstantKind::zero_sized(self.tcx.types.unit),
                         })));
                         mem::replace(rhs, unit)
                     },
@@ -958,6 +1086,25 @@ pub fn promote_candidates<'tcx>(
             }
         }
 
+        if candidate.relies_on_const_fn_promotion {
+            let source_info = body.source_info(candidate.location);
+            let lint_root = body.source_scopes[source_info.scope]
+                .local_data
+                .as_ref()
+                .assert_crate_local()
+                .lint_root;
+            tcx.struct_span_lint_hir(
+                CONST_FN_CALL_PROMOTION,
+                lint_root,
+                source_info.span,
+                |lint| {
+                    lint.build("this borrow relies on the promotion of a `const fn` call")
+                        .help("move the borrowed value into a named `const` item")
+                        .emit()
+                },
+            );
+        }
+
         // Declare return place local so that `mir::Body::new` doesn't complain.
         let initial_locals = iter::once(LocalDecl::new(tcx.types.never, body.span)).collect();
 
@@ -4,18 +4,19 @@
 use rustc_infer::infer::TyCtxtInferExt;
 use rustc_middle::mir::interpret::Scalar;
 use rustc_middle::mir::traversal;
-use rustc_middle::mir::visit::{PlaceContext, Visitor};
+use rustc_middle::mir::visit::{NonUseContext, PlaceContext, Visitor};
 use rustc_middle::mir::{
     AggregateKind, BasicBlock, Body, BorrowKind, Local, Location, MirPass, MirPhase, Operand,
-    PlaceElem, PlaceRef, ProjectionElem, Rvalue, SourceScope, Statement, StatementKind, Terminator,
-    TerminatorKind, START_BLOCK,
+    Place, PlaceElem, PlaceRef, ProjectionElem, Rvalue, SourceScope, Statement, StatementKind,
+    SwitchTargets, Terminator, TerminatorKind, START_BLOCK,
 };
 use rustc_middle::ty::fold::BottomUpFolder;
+use rustc_middle::ty::layout::{LayoutCx, LayoutOf};
 use rustc_middle::ty::{self, ParamEnv, Ty, TyCtxt, TypeFoldable};
 use rustc_mir_dataflow::impls::MaybeStorageLive;
 use rustc_mir_dataflow::storage::AlwaysLiveLocals;
 use rustc_mir_dataflow::{Analysis, ResultsCursor};
-use rustc_target::abi::Size;
+use rustc_target::abi::{Size, TagEncoding, VariantIdx, Variants};
 
 #[derive(Copy, Clone, Debug)]
 enum EdgeKind {
@@ -36,6 +37,11 @@ pub struct Validator {
 
 impl<'tcx> MirPass<'tcx> for Validator {
     fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        // Bodies written with `#[custom_mir]` are only in the dialect of their phase once it is
+        // reached, so they can't be validated against the phases before it.
+        if body.should_skip() {
+            return;
+        }
         let def_id = body.source.def_id();
         let param_env = tcx.param_env(def_id);
         let mir_phase = self.mir_phase;
@@ -180,6 +186,114 @@ fn mir_assign_valid_types(&self, src: Ty<'tcx>, dest: Ty<'tcx>) -> bool {
         // differences. So we compare ignoring lifetimes.
         equal_up_to_regions(self.tcx, param_env, src, dest)
     }
+
+    /// Checks that `SetDiscriminant` writes a variant that the type of `place` has, and that the
+    /// layout of the type can represent.
+    fn check_set_discriminant(
+        &self,
+        location: Location,
+        place: Place<'tcx>,
+        variant_index: VariantIdx,
+    ) {
+        let ty = place.ty(&self.body.local_decls, self.tcx).ty;
+        match *ty.kind() {
+            ty::Adt(adt_def, _) if adt_def.is_enum() => {
+                if !adt_def.variant_range().contains(&variant_index) {
+                    self.fail(
+                        location,
+                        format!(
+                            "`SetDiscriminant` with variant {:?} of {}, which only has {} variants",
+                            variant_index,
+                            ty,
+                            adt_def.variants.len(),
+                        ),
+                    );
+                    return;
+                }
+            }
+            // The variants of a generator are only known from its layout, which requires its
+            // optimized MIR, so they can't be checked while that MIR is being built.
+            ty::Generator(..) => return,
+            _ => {
+                self.fail(location, format!("`SetDiscriminant` on {}, which is not an enum", ty));
+                return;
+            }
+        }
+
+        // Computing the layout is somewhat expensive, so only do it when -Zvalidate-mir is
+        // passed. Unreachable code may construct variants which can't exist, so only check
+        // reachable blocks.
+        if !self.tcx.sess.opts.debugging_opts.validate_mir
+            || self.mir_phase < MirPhase::DropLowering
+            || !self.reachable_blocks.contains(location.block)
+        {
+            return;
+        }
+        let cx = LayoutCx { tcx: self.tcx, param_env: self.param_env };
+        let Ok(layout) = cx.layout_of(ty) else { return };
+        // A variant without a tag value is left out of the layout, because it is uninhabited.
+        let has_encoding = match layout.variants {
+            Variants::Single { index } => index == variant_index,
+            Variants::Multiple { tag_encoding: TagEncoding::Direct, .. } => true,
+            Variants::Multiple {
+                tag_encoding: TagEncoding::Niche { dataful_variant, ref niche_variants, .. },
+                ..
+            } => variant_index == dataful_variant || niche_variants.contains(&variant_index),
+        };
+        if !has_encoding {
+            self.fail(
+                location,
+                format!(
+                    "`SetDiscriminant` with variant {:?} of {}, which is uninhabited and has no \
+                    discriminant in the layout",
+                    variant_index, ty,
+                ),
+            );
+        }
+    }
+
+    /// Checks that a `SwitchInt` on the discriminant of an enum, read in the same block, only
+    /// switches on values which are discriminants of its variants.
+    fn check_switch_on_discriminant(
+        &self,
+        location: Location,
+        discr: &Operand<'tcx>,
+        targets: &SwitchTargets,
+    ) {
+        if !self.tcx.sess.opts.debugging_opts.validate_mir {
+            return;
+        }
+        let Some(discr_local) = discr.place().and_then(|place| place.as_local()) else { return };
+        let statements = &self.body[location.block].statements;
+        let discr_place = statements.iter().rev().find_map(|statement| match &statement.kind {
+            StatementKind::Assign(box (dest, rvalue)) if dest.as_local() == Some(discr_local) => {
+                Some(match rvalue {
+                    Rvalue::Discriminant(place) => Some(*place),
+                    _ => None,
+                })
+            }
+            _ => None,
+        });
+        let Some(Some(place)) = discr_place else { return };
+
+        let ty = place.ty(&self.body.local_decls, self.tcx).ty;
+        let ty::Adt(adt_def, _) = *ty.kind() else { return };
+        if !adt_def.is_enum() {
+            return;
+        }
+        for (value, _) in targets.iter() {
+            if !adt_def.discriminants(self.tcx).any(|(_, discr)| discr.val == value) {
+                self.fail(
+                    location,
+                    format!(
+                        "`SwitchInt` on the discriminant of {} with value {:#x}, which is not the \
+                        discriminant of any of its variants",
+                        ty, value,
+                    ),
+                );
+            }
+        }
+    }
 }
 
 impl<'a, 'tcx> Visitor<'tcx> for TypeChecker<'a, 'tcx> {
@@ -232,6 +346,15 @@ fn visit_projection_elem(
                 self.fail(location, format!("bad index ({:?} != usize)", index_ty))
             }
         }
+        // Once the `Derefer` ran, places only dereference their base local. The places of the
+        // debuginfo are left alone.
+        if elem == ProjectionElem::Deref
+            && !proj_base.is_empty()
+            && self.mir_phase >= MirPhase::Derefered
+            && context != PlaceContext::NonUse(NonUseContext::VarDebugInfo)
+        {
+            self.fail(location, format!("place based on {:?} has a non-leading `Deref`", local));
+        }
         self.super_projection_elem(local, proj_base, elem, context, location);
     }
 
@@ -255,7 +378,8 @@ fn visit_statement(&mut self, statement: &Statement<'tcx>, location: Location) {
                 match rvalue {
                     // The sides of an assignment must not alias. Currently this just checks whether the places
                     // are identical.
-                    Rvalue::Use(Operand::Copy(src) | Operand::Move(src)) => {
+                    Rvalue::Use(Operand::Copy(src) | Operand::Move(src))
+                    | Rvalue::CopyForDeref(src) => {
                         if dest == src {
                             self.fail(
                                 location,
@@ -348,8 +472,10 @@ fn visit_statement(&mut self, statement: &Statement<'tcx>, location: Location) {
                     self.fail(location, format!("bad arg ({:?} != usize)", op_cnt_ty))
                 }
             }
-            StatementKind::SetDiscriminant { .. }
-            | StatementKind::StorageLive(..)
+            StatementKind::SetDiscriminant { place, variant_index } => {
+                self.check_set_discriminant(location, **place, *variant_index);
+            }
+            StatementKind::StorageLive(..)
             | StatementKind::StorageDead(..)
             | StatementKind::Retag(_, _)
             | StatementKind::Coverage(_)
@@ -413,6 +539,8 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                         ),
                     );
                 }
+
+                self.check_switch_on_discriminant(location, discr, targets);
             }
             TerminatorKind::Drop { target, unwind, .. } => {
                 self.check_edge(location, *target, EdgeKind::Normal);
@@ -432,6 +560,19 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                     self.check_edge(location, *unwind, EdgeKind::Unwind);
                 }
             }
+            TerminatorKind::TailCall { func, .. } => {
+                let func_ty = func.ty(&self.body.local_decls, self.tcx);
+                match func_ty.kind() {
+                    ty::FnPtr(..) | ty::FnDef(..) => {}
+                    _ => self.fail(
+                        location,
+                        format!(
+                            "encountered non-callable type {} in `TailCall` terminator",
+                            func_ty
+                        ),
+                    ),
+                }
+            }
             TerminatorKind::Call { func, args, destination, cleanup, .. } => {
                 let func_ty = func.ty(&self.body.local_decls, self.tcx);
                 match func_ty.kind() {
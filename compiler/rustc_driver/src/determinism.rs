@@ -0,0 +1,189 @@
+//! The `-Z verify-determinism` self-check: the crate is compiled a second time, into a scratch
+//! directory, and every output of the second compilation is compared with the same output of the
+//! first one.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_interface::interface::Compiler;
+use rustc_session::config::Input;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The directory, inside the output directory, that the second compilation writes to. It is kept
+/// when the compilations differ, so that the outputs can be inspected.
+const SCRATCH_DIR: &str = "verify-determinism";
+
+pub(crate) fn verify(compiler: &Compiler, args: &[String]) {
+    let sess = compiler.session();
+    if let Input::Str { .. } = compiler.input() {
+        sess.warn("`-Z verify-determinism` has no effect when the input is not a file");
+        return;
+    }
+    if sess.opts.incremental.is_some() {
+        // The second compilation would reuse the results of the first one.
+        sess.warn("`-Z verify-determinism` has no effect with incremental compilation");
+        return;
+    }
+
+    let out_dir = match (compiler.output_file(), compiler.output_dir()) {
+        (Some(ofile), _) => ofile.parent().map(Path::to_path_buf).unwrap_or_default(),
+        (None, Some(odir)) => odir.clone(),
+        (None, None) => PathBuf::new(),
+    };
+    let out_dir = if out_dir.as_os_str().is_empty() { PathBuf::from(".") } else { out_dir };
+    let scratch_dir = out_dir.join(SCRATCH_DIR);
+
+    // Don't compare with the outputs of an earlier run.
+    let _ = fs::remove_dir_all(&scratch_dir);
+    if let Err(err) = fs::create_dir_all(&scratch_dir) {
+        sess.err(&format!("failed to create `{}`: {}", scratch_dir.display(), err));
+        return;
+    }
+
+    let rustc = match env::current_exe() {
+        Ok(rustc) => rustc,
+        Err(err) => {
+            sess.err(&format!("failed to find the current executable: {}", err));
+            return;
+        }
+    };
+    let (second_args, emitted) =
+        second_compilation_args(&args[1..], &scratch_dir, compiler.output_file());
+    let output = match Command::new(&rustc).args(&second_args).output() {
+        Ok(output) => output,
+        Err(err) => {
+            sess.err(&format!("failed to run `{}`: {}", rustc.display(), err));
+            return;
+        }
+    };
+    if !output.status.success() {
+        // Only show the diagnostics of the second compilation when it failed, as they are the
+        // same as the ones of the first compilation otherwise.
+        sess.struct_err("the second compilation of `-Z verify-determinism` failed")
+            .note(&String::from_utf8_lossy(&output.stderr))
+            .emit();
+        return;
+    }
+
+    let entries = match fs::read_dir(&scratch_dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            sess.err(&format!("failed to read `{}`: {}", scratch_dir.display(), err));
+            return;
+        }
+    };
+    let mut deterministic = true;
+    for entry in entries {
+        let second = match entry {
+            Ok(entry) => entry.path(),
+            Err(err) => {
+                sess.err(&format!("failed to read `{}`: {}", scratch_dir.display(), err));
+                return;
+            }
+        };
+        // Dep-info files contain the paths of the outputs, which differ between both compilations.
+        if !second.is_file() || second.extension().map_or(false, |ext| ext == "d") {
+            continue;
+        }
+        let first = match emitted.get(&second) {
+            Some(Some(first)) => first.clone(),
+            Some(None) => continue,
+            None => out_dir.join(second.file_name().unwrap()),
+        };
+        let identical = match (fs::read(&first), fs::read(&second)) {
+            (Ok(first), Ok(second)) => first == second,
+            _ => false,
+        };
+        if !identical {
+            deterministic = false;
+            sess.err(&format!(
+                "`{}` is not deterministic: it differs from `{}`, written by a second compilation",
+                first.display(),
+                second.display(),
+            ));
+        }
+    }
+
+    if deterministic {
+        let _ = fs::remove_dir_all(&scratch_dir);
+    }
+}
+
+/// Returns the arguments of the second compilation: the arguments of the current compilation,
+/// with its outputs redirected to `scratch_dir` and without `-Z verify-determinism`.
+///
+/// The outputs that `--emit` gives a path to are redirected to `scratch_dir` as well. They are
+/// returned by their path in the second compilation, mapped to their path in the current
+/// compilation, or to `None` for the dep-info files, which aren't compared.
+fn second_compilation_args(
+    args: &[String],
+    scratch_dir: &Path,
+    ofile: &Option<PathBuf>,
+) -> (Vec<String>, FxHashMap<PathBuf, Option<PathBuf>>) {
+    fn is_verify_determinism(opt: &str) -> bool {
+        let opt = opt.replace('_', "-");
+        opt == "verify-determinism" || opt.starts_with("verify-determinism=")
+    }
+
+    fn redirect_emit(
+        emit: &str,
+        scratch_dir: &Path,
+        emitted: &mut FxHashMap<PathBuf, Option<PathBuf>>,
+    ) -> String {
+        let kinds: Vec<String> = emit
+            .split(',')
+            .map(|kind| match kind.split_once('=') {
+                Some((kind, path)) => {
+                    let first = PathBuf::from(path);
+                    let second = scratch_dir.join(first.file_name().unwrap_or_default());
+                    let arg = format!("{}={}", kind, second.display());
+                    emitted.insert(second, if kind == "dep-info" { None } else { Some(first) });
+                    arg
+                }
+                None => kind.to_string(),
+            })
+            .collect();
+        kinds.join(",")
+    }
+
+    let mut second_args = Vec::with_capacity(args.len() + 2);
+    let mut emitted = FxHashMap::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-o" | "--out-dir" => {
+                args.next();
+            }
+            "--emit" => {
+                second_args.push(arg.clone());
+                if let Some(emit) = args.next() {
+                    second_args.push(redirect_emit(emit, scratch_dir, &mut emitted));
+                }
+            }
+            "-Z" if args.as_slice().first().map_or(false, |opt| is_verify_determinism(opt)) => {
+                args.next();
+            }
+            _ if arg.starts_with("-o") || arg.starts_with("--out-dir=") => {}
+            _ if arg.strip_prefix("-Z").map_or(false, is_verify_determinism) => {}
+            _ if arg.starts_with("--emit=") => {
+                let emit = redirect_emit(&arg["--emit=".len()..], scratch_dir, &mut emitted);
+                second_args.push(format!("--emit={}", emit));
+            }
+            _ => second_args.push(arg.clone()),
+        }
+    }
+
+    match ofile.as_ref().and_then(|ofile| ofile.file_name()) {
+        Some(file_name) => {
+            second_args.push("-o".to_string());
+            second_args.push(scratch_dir.join(file_name).to_string_lossy().into_owned());
+        }
+        None => {
+            second_args.push("--out-dir".to_string());
+            second_args.push(scratch_dir.to_string_lossy().into_owned());
+        }
+    }
+    (second_args, emitted)
+}
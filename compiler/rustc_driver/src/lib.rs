@@ -55,6 +55,7 @@
 use std::time::Instant;
 
 pub mod args;
+mod determinism;
 pub mod pretty;
 
 /// Exit status code used for successful compilation and help output.
@@ -420,7 +421,13 @@ fn run_compiler(
 
         if let Some(linker) = linker {
             let _timer = sess.timer("link");
-            linker.link()?
+            linker.link()?;
+
+            if sess.opts.debugging_opts.verify_determinism {
+                let _timer = sess.timer("verify_determinism");
+                determinism::verify(compiler, &args);
+                sess.compile_status()?;
+            }
         }
 
         if sess.opts.debugging_opts.perf_stats {
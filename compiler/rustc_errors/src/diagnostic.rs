@@ -10,6 +10,7 @@
 use rustc_span::{MultiSpan, Span, DUMMY_SP};
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::path::Path;
 
 /// Error type for `Diagnostic`'s `suggestions` field, indicating that
 /// `.disable_suggestions()` was called on the `Diagnostic`.
@@ -83,6 +84,15 @@ pub fn highlighted<S: Into<String>>(t: S) -> DiagnosticStyledString {
     pub fn content(&self) -> String {
         self.0.iter().map(|x| x.content()).collect::<String>()
     }
+
+    /// Returns the parts of the string, with the style that `style` gives them depending on whether
+    /// they are highlighted.
+    fn styled(&self, style: fn(bool) -> Style) -> impl Iterator<Item = (String, Style)> + '_ {
+        self.0.iter().map(move |x| match *x {
+            StringPart::Normal(ref s) => (s.to_owned(), style(false)),
+            StringPart::Highlighted(ref s) => (s.to_owned(), style(true)),
+        })
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -191,15 +201,9 @@ pub fn replace_span_with(&mut self, after: Span) -> &mut Self {
     ) -> &mut Self {
         let mut msg: Vec<_> =
             vec![("required when trying to coerce from type `".to_string(), Style::NoStyle)];
-        msg.extend(expected.0.iter().map(|x| match *x {
-            StringPart::Normal(ref s) => (s.to_owned(), Style::NoStyle),
-            StringPart::Highlighted(ref s) => (s.to_owned(), Style::Highlight),
-        }));
+        msg.extend(found.styled(|emphasized| Style::Found { emphasized }));
         msg.push(("` to type '".to_string(), Style::NoStyle));
-        msg.extend(found.0.iter().map(|x| match *x {
-            StringPart::Normal(ref s) => (s.to_owned(), Style::NoStyle),
-            StringPart::Highlighted(ref s) => (s.to_owned(), Style::Highlight),
-        }));
+        msg.extend(expected.styled(|emphasized| Style::Expected { emphasized }));
         msg.push(("`".to_string(), Style::NoStyle));
 
         // For now, just attach these as notes
@@ -233,18 +237,17 @@ pub fn note_expected_found_extra(
         } else {
             (0, found_label.len() - expected_label.len())
         };
-        let mut msg: Vec<_> =
-            vec![(format!("{}{} `", " ".repeat(expected_padding), expected_label), Style::NoStyle)];
-        msg.extend(expected.0.iter().map(|x| match *x {
-            StringPart::Normal(ref s) => (s.to_owned(), Style::NoStyle),
-            StringPart::Highlighted(ref s) => (s.to_owned(), Style::Highlight),
-        }));
+        let mut msg: Vec<_> = vec![
+            (" ".repeat(expected_padding), Style::NoStyle),
+            (expected_label, Style::Expected { emphasized: false }),
+            (" `".to_string(), Style::NoStyle),
+        ];
+        msg.extend(expected.styled(|emphasized| Style::Expected { emphasized }));
         msg.push((format!("`{}\n", expected_extra), Style::NoStyle));
-        msg.push((format!("{}{} `", " ".repeat(found_padding), found_label), Style::NoStyle));
-        msg.extend(found.0.iter().map(|x| match *x {
-            StringPart::Normal(ref s) => (s.to_owned(), Style::NoStyle),
-            StringPart::Highlighted(ref s) => (s.to_owned(), Style::Highlight),
-        }));
+        msg.push((" ".repeat(found_padding), Style::NoStyle));
+        msg.push((found_label, Style::Found { emphasized: false }));
+        msg.push((" `".to_string(), Style::NoStyle));
+        msg.extend(found.styled(|emphasized| Style::Found { emphasized }));
         msg.push((format!("`{}", found_extra), Style::NoStyle));
 
         // For now, just attach these as notes.
@@ -267,6 +270,15 @@ pub fn note(&mut self, msg: &str) -> &mut Self {
         self
     }
 
+    /// Add a note pointing to the file that a type shortened by `shorten_long_type` was written
+    /// to, if it was shortened.
+    pub fn note_long_type(&mut self, path: Option<&Path>) -> &mut Self {
+        if let Some(path) = path {
+            self.note(&format!("the full type name has been written to '{}'", path.display()));
+        }
+        self
+    }
+
     pub fn highlighted_note(&mut self, msg: Vec<(String, Style)>) -> &mut Self {
         self.sub_with_highlights(Level::Note, msg, MultiSpan::new(), None);
         self
@@ -5,6 +5,7 @@
 use rustc_span::{MultiSpan, Span};
 use std::fmt::{self, Debug};
 use std::ops::{Deref, DerefMut};
+use std::path::Path;
 use std::thread::panicking;
 use tracing::debug;
 
@@ -224,6 +225,7 @@ pub fn span_labels(
     ) -> &mut Self);
 
     forward!(pub fn note(&mut self, msg: &str) -> &mut Self);
+    forward!(pub fn note_long_type(&mut self, path: Option<&Path>) -> &mut Self);
     forward!(pub fn span_note<S: Into<MultiSpan>>(
         &mut self,
         sp: S,
@@ -12,6 +12,7 @@
 use rustc_span::source_map::SourceMap;
 use rustc_span::{MultiSpan, SourceFile, Span};
 
+use crate::grouping::DiagnosticGroups;
 use crate::snippet::{Annotation, AnnotationType, Line, MultilineAnnotation, Style, StyledString};
 use crate::styled_buffer::StyledBuffer;
 use crate::{
@@ -26,6 +27,7 @@
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use std::borrow::Cow;
 use std::cmp::{max, min, Reverse};
+use std::env;
 use std::io;
 use std::io::prelude::*;
 use std::iter;
@@ -34,9 +36,16 @@
 use termcolor::{Buffer, Color, WriteColor};
 use tracing::*;
 
+#[cfg(test)]
+mod tests;
+
 /// Default column width, used in tests and when terminal dimensions cannot be determined.
 const DEFAULT_COLUMN_WIDTH: usize = 140;
 
+/// The narrowest column that labels and notes are wrapped to. When less room than that is left,
+/// they are written on a single line, which is easier to read than a column of a word or two.
+const MIN_WRAP_WIDTH: usize = 20;
+
 /// Describes the way the content of the `rendered` field of the json output is generated
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HumanReadableErrorType {
@@ -200,6 +209,10 @@ fn emit_future_breakage_report(&mut self, _diags: Vec<Diagnostic>) {}
     /// Emit list of unused externs
     fn emit_unused_externs(&mut self, _lint_level: &str, _unused_externs: &[&str]) {}
 
+    /// Emit the diagnostics held back to be grouped, and a summary of all the errors and
+    /// warnings. This is called once, before the number of errors is reported.
+    fn emit_summary(&mut self) {}
+
     /// Checks if should show explanations about "rustc --explain"
     fn should_show_explain(&self) -> bool {
         true
@@ -493,26 +506,37 @@ fn source_map(&self) -> Option<&Lrc<SourceMap>> {
     }
 
     fn emit_diagnostic(&mut self, diag: &Diagnostic) {
-        let mut children = diag.children.clone();
-        let (mut primary_span, suggestions) = self.primary_span_formatted(&diag);
-        debug!("emit_diagnostic: suggestions={:?}", suggestions);
+        if let Some(groups) = &mut self.groups {
+            if groups.record(diag) {
+                return;
+            }
+        }
+        self.emit_pending_groups();
+        self.emit_occurrences(diag, 1, &[]);
+    }
 
-        self.fix_multispans_in_extern_macros_and_render_macro_backtrace(
-            &self.sm,
-            &mut primary_span,
-            &mut children,
-            &diag.level,
-            self.macro_backtrace,
-        );
+    fn emit_summary(&mut self) {
+        self.emit_pending_groups();
+        let Some(groups) = self.groups.take() else { return };
+        let summary = groups.summary();
+        if summary.is_empty() {
+            return;
+        }
 
-        self.emit_messages_default(
-            &diag.level,
-            &diag.styled_message(),
-            &diag.code,
-            &primary_span,
-            &children,
-            &suggestions,
-        );
+        let count_width = summary.iter().map(|row| row.count.to_string().len()).max().unwrap_or(0);
+        let mut buffer = StyledBuffer::new();
+        buffer.append(0, "summary:", Style::MainHeaderMsg);
+        for (i, row) in summary.iter().enumerate() {
+            let line = i + 1;
+            buffer.append(line, &format!("  {:>1$} x ", row.count, count_width), Style::NoStyle);
+            buffer.append(line, row.level.to_str(), Style::Level(row.level));
+            if let Some(code) = &row.code {
+                buffer.append(line, &format!("[{}]", code), Style::Level(row.level));
+            }
+        }
+        if let Err(e) = emit_to_destination(&buffer.render(), &Level::Note, &mut self.dst, false) {
+            panic!("failed to emit error: {}", e);
+        }
     }
 
     fn should_show_explain(&self) -> bool {
@@ -566,16 +590,25 @@ pub enum ColorConfig {
 
 impl ColorConfig {
     fn to_color_choice(self) -> ColorChoice {
+        let no_color = env::var_os("NO_COLOR").map_or(false, |v| !v.is_empty());
+        self.color_choice(atty::is(atty::Stream::Stderr), no_color)
+    }
+
+    /// Returns the colors to use when stderr is a terminal or not, and when the `NO_COLOR`
+    /// environment variable is set to a non-empty value or not.
+    fn color_choice(self, stderr_is_tty: bool, no_color: bool) -> ColorChoice {
         match self {
             ColorConfig::Always => {
-                if atty::is(atty::Stream::Stderr) {
+                if stderr_is_tty {
                     ColorChoice::Always
                 } else {
                     ColorChoice::AlwaysAnsi
                 }
             }
             ColorConfig::Never => ColorChoice::Never,
-            ColorConfig::Auto if atty::is(atty::Stream::Stderr) => ColorChoice::Auto,
+            // See https://no-color.org/: only an explicit `--color=always` overrides `NO_COLOR`.
+            ColorConfig::Auto if no_color => ColorChoice::Never,
+            ColorConfig::Auto if stderr_is_tty => ColorChoice::Auto,
             ColorConfig::Auto => ColorChoice::Never,
         }
     }
@@ -595,6 +628,9 @@ pub struct EmitterWriter {
     teach: bool,
     ui_testing: bool,
     terminal_width: Option<usize>,
+    /// The identical diagnostics held back to be emitted once, with their number of occurrences,
+    /// if they are grouped.
+    groups: Option<DiagnosticGroups>,
 
     macro_backtrace: bool,
 }
@@ -623,6 +659,7 @@ pub fn stderr(
             teach,
             ui_testing: false,
             terminal_width,
+            groups: None,
             macro_backtrace,
         }
     }
@@ -643,6 +680,7 @@ pub fn new(
             teach,
             ui_testing: false,
             terminal_width,
+            groups: None,
             macro_backtrace,
         }
     }
@@ -652,6 +690,101 @@ pub fn ui_testing(mut self, ui_testing: bool) -> Self {
         self
     }
 
+    /// Emits the identical errors and warnings once, with the number of times they were emitted and
+    /// all their locations, and a summary of them before the number of errors, as
+    /// `--error-format=short` does with `-Z group-diagnostics`.
+    pub fn group_identical(mut self, group_identical: bool) -> Self {
+        self.groups = if group_identical { Some(DiagnosticGroups::default()) } else { None };
+        self
+    }
+
+    fn emit_pending_groups(&mut self) {
+        let groups = match &mut self.groups {
+            Some(groups) => groups.take_groups(),
+            None => return,
+        };
+        for group in &groups {
+            let other_spans = group.spans.get(1..).unwrap_or_default();
+            self.emit_occurrences(&group.diagnostic, group.count(), other_spans);
+        }
+    }
+
+    /// Emits `diag`, which stands for `occurrences` identical diagnostics, followed by the
+    /// locations of the ones other than `diag` itself.
+    fn emit_occurrences(&mut self, diag: &Diagnostic, occurrences: usize, other_spans: &[Span]) {
+        let mut children = diag.children.clone();
+        let (mut primary_span, suggestions) = self.primary_span_formatted(&diag);
+        debug!("emit_diagnostic: suggestions={:?}", suggestions);
+
+        self.fix_multispans_in_extern_macros_and_render_macro_backtrace(
+            &self.sm,
+            &mut primary_span,
+            &mut children,
+            &diag.level,
+            self.macro_backtrace,
+        );
+
+        self.emit_messages_default(
+            &diag.level,
+            &diag.styled_message(),
+            &diag.code,
+            occurrences,
+            &primary_span,
+            &children,
+            &suggestions,
+        );
+
+        let sm = match &self.sm {
+            Some(sm) if other_spans.iter().any(|span| !span.is_dummy()) => sm,
+            _ => return,
+        };
+        let mut buffer = StyledBuffer::new();
+        for (line, span) in other_spans.iter().filter(|span| !span.is_dummy()).enumerate() {
+            let loc = sm.lookup_char_pos(span.lo());
+            buffer.append(line, "  ", Style::NoStyle);
+            buffer.append(
+                line,
+                &format!(
+                    "{}:{}:{}",
+                    sm.filename_for_diagnostics(&loc.file.name),
+                    sm.doctest_offset_line(&loc.file.name, loc.line),
+                    loc.col.0 + 1,
+                ),
+                Style::LineAndColumn,
+            );
+        }
+        if let Err(e) = emit_to_destination(&buffer.render(), &diag.level, &mut self.dst, false) {
+            panic!("failed to emit error: {}", e);
+        }
+    }
+
+    /// Returns the width that labels and notes are wrapped to: the one given with
+    /// `--diagnostic-width`, or the width of the terminal that the diagnostics are written to.
+    /// Nothing is wrapped when the width isn't known, which is the case in UI tests unless one is
+    /// given, so that their output doesn't depend on the terminal running them.
+    fn wrap_width(&self) -> Option<usize> {
+        if self.terminal_width.is_some() {
+            return self.terminal_width;
+        }
+        match self.dst {
+            Terminal(_) | Buffered(_) if !self.ui_testing => termize::dimensions().map(|(w, _)| w),
+            _ => None,
+        }
+    }
+
+    /// Wraps the label of an annotation, which starts at column `col` of the output, returning its
+    /// lines.
+    fn wrap_label(&self, label: &str, col: usize) -> Vec<String> {
+        match self.wrap_width().map(|width| width.saturating_sub(col)) {
+            Some(width) if width >= MIN_WRAP_WIDTH => {
+                let mut label = [label.to_string()];
+                soft_wrap(&mut label, width);
+                label[0].split('\n').map(|line| line.to_string()).collect()
+            }
+            _ => vec![label.to_string()],
+        }
+    }
+
     fn maybe_anonymized(&self, line_num: usize) -> String {
         if self.ui_testing { ANONYMIZED_LINE_NUM.to_string() } else { line_num.to_string() }
     }
@@ -885,6 +1018,9 @@ fn render_source_line(
         //      <EMPTY LINE>
         //
         let mut annotations_position = vec![];
+        // The lines of the label of each annotation in `annotations_position`, which is wrapped
+        // to the width of the output.
+        let mut labels = vec![];
         let mut line_len = 0;
         let mut p = 0;
         for (i, annotation) in annotations.iter().enumerate() {
@@ -910,6 +1046,13 @@ fn render_source_line(
                 }
             }
             annotations_position.push((p, annotation));
+            let label = annotation.label.as_ref().map(|label| {
+                let col = if p == 0 { annotation.end_col + 1 } else { annotation.start_col };
+                self.wrap_label(label, code_offset + col.saturating_sub(left))
+            });
+            // The next annotations are positioned below all the lines of this label.
+            let label_lines = label.as_ref().map_or(1, |label| label.len());
+            labels.push(label);
             for (j, next) in annotations.iter().enumerate() {
                 if j > i {
                     let l = next.label.as_ref().map_or(0, |label| label.len() + 2);
@@ -938,7 +1081,7 @@ fn render_source_line(
                     // Avoid #42595.
                     {
                         // This annotation needs a new line in the output.
-                        p += 1;
+                        p += label_lines;
                         break;
                     }
                 }
@@ -949,6 +1092,13 @@ fn render_source_line(
         if line_len != 0 {
             line_len += 1;
         }
+        // Make room for the last lines of the wrapped labels.
+        for (&(pos, _), label) in annotations_position.iter().zip(&labels) {
+            if let Some(label) = label {
+                let last_line = if pos == 0 { label.len() } else { pos + label.len() + 1 };
+                line_len = max(line_len, last_line - 1);
+            }
+        }
 
         // If there are no annotations or the only annotations on this line are
         // MultilineLine, then there's only code being shown, stop processing.
@@ -1069,7 +1219,7 @@ fn render_source_line(
         // 3 |
         // 4 |   }
         //   |  _  test
-        for &(pos, annotation) in &annotations_position {
+        for (&(pos, annotation), label) in annotations_position.iter().zip(&labels) {
             let style =
                 if annotation.is_primary { Style::LabelPrimary } else { Style::LabelSecondary };
             let (pos, col) = if pos == 0 {
@@ -1077,8 +1227,8 @@ fn render_source_line(
             } else {
                 (pos + 2, annotation.start_col.saturating_sub(left))
             };
-            if let Some(ref label) = annotation.label {
-                buffer.puts(line_offset + pos, code_offset + col, &label, style);
+            for (i, line) in label.iter().flatten().enumerate() {
+                buffer.puts(line_offset + pos + i, code_offset + col, line, style);
             }
         }
 
@@ -1198,7 +1348,20 @@ fn msg_to_buffer(
         //    |  |   length of label
         //    |  magic `3`
         //    `max_line_num_len`
-        let padding = " ".repeat(padding + label.len() + 5);
+        let indent = padding + label.len() + 5;
+        let padding = " ".repeat(indent);
+
+        // Wrap the message to the width of the output, keeping the highlighting of its parts.
+        let wrapped: Vec<(String, Style)>;
+        let msg = match self.wrap_width().map(|width| width.saturating_sub(indent)) {
+            Some(width) if width >= MIN_WRAP_WIDTH => {
+                let mut text: Vec<_> = msg.iter().map(|(text, _)| text.clone()).collect();
+                soft_wrap(&mut text, width);
+                wrapped = text.into_iter().zip(msg.iter().map(|&(_, style)| style)).collect();
+                &wrapped[..]
+            }
+            _ => msg,
+        };
 
         /// Returns `override` if it is present and `style` is `NoStyle` or `style` otherwise
         fn style_or_override(style: Style, override_: Option<Style>) -> Style {
@@ -1250,6 +1413,7 @@ fn emit_message_default(
         msp: &MultiSpan,
         msg: &[(String, Style)],
         code: &Option<DiagnosticId>,
+        occurrences: usize,
         level: &Level,
         max_line_num_len: usize,
         is_secondary: bool,
@@ -1282,6 +1446,11 @@ fn emit_message_default(
                 buffer.append(0, "]", Style::Level(*level));
                 label_width += 2 + code.len();
             }
+            if occurrences > 1 {
+                let occurrences = format!(" x{}", occurrences);
+                buffer.append(0, &occurrences, Style::Level(*level));
+                label_width += occurrences.chars().count();
+            }
             let header_style = if is_secondary { Style::HeaderMsg } else { Style::MainHeaderMsg };
             if *level != Level::FailureNote {
                 buffer.append(0, ": ", header_style);
@@ -1831,6 +2000,7 @@ fn emit_messages_default(
         level: &Level,
         message: &[(String, Style)],
         code: &Option<DiagnosticId>,
+        occurrences: usize,
         span: &MultiSpan,
         children: &[SubDiagnostic],
         suggestions: &[CodeSuggestion],
@@ -1842,7 +2012,15 @@ fn emit_messages_default(
             num_decimal_digits(n)
         };
 
-        match self.emit_message_default(span, message, code, level, max_line_num_len, false) {
+        match self.emit_message_default(
+            span,
+            message,
+            code,
+            occurrences,
+            level,
+            max_line_num_len,
+            false,
+        ) {
             Ok(()) => {
                 if !children.is_empty()
                     || suggestions.iter().any(|s| s.style != SuggestionStyle::CompletelyHidden)
@@ -1867,6 +2045,7 @@ fn emit_messages_default(
                             &span,
                             &child.styled_message(),
                             &None,
+                            1,
                             &child.level,
                             max_line_num_len,
                             true,
@@ -1882,6 +2061,7 @@ fn emit_messages_default(
                                 &MultiSpan::new(),
                                 &[(sugg.msg.to_owned(), Style::HeaderMsg)],
                                 &None,
+                                1,
                                 &Level::Help,
                                 max_line_num_len,
                                 true,
@@ -1911,6 +2091,14 @@ fn emit_messages_default(
     }
 }
 
+impl Drop for EmitterWriter {
+    fn drop(&mut self) {
+        // The summary isn't emitted when the compilation stops before the number of errors is
+        // reported, but the diagnostics themselves still are.
+        self.emit_pending_groups();
+    }
+}
+
 impl FileWithAnnotatedLines {
     /// Preprocess all the annotations so that they are grouped by file and by line number
     /// This helps us quickly iterate over the whole message (including secondary file spans)
@@ -2113,6 +2301,35 @@ fn normalize_whitespace(str: &str) -> String {
     s
 }
 
+/// Soft-wraps a message, given as the text of its differently styled parts, to lines of at most
+/// `width` columns, by replacing the spaces that the lines are broken at with newlines. A word
+/// that is wider than `width` is left whole, on a line of its own.
+fn soft_wrap(parts: &mut [String], width: usize) {
+    // The width of the current line so far.
+    let mut col = 0;
+    // The last space of the current line, as its part and byte offset, and the width of the line
+    // up to and including it.
+    let mut last_space = None;
+    for i in 0..parts.len() {
+        for (j, ch) in parts[i].clone().char_indices() {
+            if ch == '\n' {
+                col = 0;
+                last_space = None;
+                continue;
+            }
+            col += unicode_width::UnicodeWidthChar::width(ch).unwrap_or(1);
+            if ch == ' ' {
+                last_space = Some((i, j, col));
+            } else if col > width {
+                if let Some((i, j, space_col)) = last_space.take() {
+                    parts[i].replace_range(j..j + 1, "\n");
+                    col -= space_col;
+                }
+            }
+        }
+    }
+}
+
 fn draw_col_separator(buffer: &mut StyledBuffer, line: usize, col: usize) {
     buffer.puts(line, col, "| ", Style::LineNumber);
 }
@@ -2306,6 +2523,12 @@ fn apply_style(&mut self, lvl: Level, style: Style) -> io::Result<()> {
             Style::Highlight => {
                 spec.set_bold(true);
             }
+            Style::Expected { emphasized } => {
+                spec.set_fg(Some(Color::Green)).set_bold(emphasized).set_underline(emphasized);
+            }
+            Style::Found { emphasized } => {
+                spec.set_fg(Some(Color::Red)).set_bold(emphasized).set_underline(emphasized);
+            }
         }
         self.set_color(&spec)
     }
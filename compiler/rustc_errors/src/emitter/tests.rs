@@ -0,0 +1,64 @@
+use super::{soft_wrap, ColorConfig};
+
+use termcolor::ColorChoice;
+
+fn wrap(parts: &[&str], width: usize) -> Vec<String> {
+    let mut parts: Vec<_> = parts.iter().map(|part| part.to_string()).collect();
+    soft_wrap(&mut parts, width);
+    parts
+}
+
+#[test]
+fn short_message_is_not_wrapped() {
+    assert_eq!(wrap(&["expected `()`, found integer"], 40), ["expected `()`, found integer"]);
+}
+
+#[test]
+fn wrap_at_last_space_that_fits() {
+    assert_eq!(
+        wrap(&["the trait bound `Foo: Bar` is not satisfied by the type"], 20),
+        ["the trait bound\n`Foo: Bar` is not\nsatisfied by the\ntype"]
+    );
+}
+
+#[test]
+fn wrap_across_styled_parts() {
+    assert_eq!(
+        wrap(&["expected type ", "`Vec<u8>`", " because of this argument"], 24),
+        ["expected type ", "`Vec<u8>`", "\nbecause of this argument"]
+    );
+    assert_eq!(wrap(&["one two ", "three"], 10), ["one two\n", "three"]);
+}
+
+#[test]
+fn keep_long_words_whole() {
+    assert_eq!(
+        wrap(&["see std::collections::hash_map::HashMap::with_capacity_and_hasher"], 20),
+        ["see\nstd::collections::hash_map::HashMap::with_capacity_and_hasher"]
+    );
+}
+
+#[test]
+fn existing_newlines_start_new_lines() {
+    assert_eq!(
+        wrap(&["first line\nthen a longer second line"], 16),
+        ["first line\nthen a longer\nsecond line"]
+    );
+}
+
+#[test]
+fn unicode_width() {
+    // Each of these characters takes two columns.
+    assert_eq!(wrap(&["日本語 日本語"], 10), ["日本語\n日本語"]);
+    assert_eq!(wrap(&["日本語 日本語"], 13), ["日本語 日本語"]);
+}
+
+#[test]
+fn no_color_disables_auto_colors() {
+    assert_eq!(ColorConfig::Auto.color_choice(true, false), ColorChoice::Auto);
+    assert_eq!(ColorConfig::Auto.color_choice(true, true), ColorChoice::Never);
+    assert_eq!(ColorConfig::Auto.color_choice(false, false), ColorChoice::Never);
+    // An explicit `--color=always` takes precedence over `NO_COLOR`.
+    assert_eq!(ColorConfig::Always.color_choice(true, true), ColorChoice::Always);
+    assert_eq!(ColorConfig::Always.color_choice(false, true), ColorChoice::AlwaysAnsi);
+}
@@ -0,0 +1,106 @@
+//! Grouping of identical diagnostics, for `--error-format=short-json` and for
+//! `--error-format=short` with `-Z group-diagnostics`.
+//!
+//! A refactoring can make the same error appear in thousands of places, which is more than anyone
+//! can read when each of them is reported on its own line. The grouped formats instead report the
+//! errors and warnings with the same level, code and message once, with the number of times they
+//! were emitted and all their locations, and end with a summary of the number of errors and
+//! warnings of each code or lint.
+//!
+//! Groups are held back until another kind of diagnostic is emitted, or until the summary is, so
+//! that a fatal error is still reported after the errors that led to it. This means that grouped
+//! diagnostics aren't streamed as they are emitted, which is why the short format only groups
+//! them when asked to.
+
+use crate::{Diagnostic, DiagnosticId, Level};
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_span::{Span, DUMMY_SP};
+
+use std::cmp::Reverse;
+
+#[cfg(test)]
+mod tests;
+
+/// The occurrences of a diagnostic with the same level, code and message.
+pub struct DiagnosticGroup {
+    /// The first occurrence, which stands for all of them.
+    pub diagnostic: Diagnostic,
+    /// The primary span of each occurrence, in the order in which they were emitted.
+    pub spans: Vec<Span>,
+}
+
+impl DiagnosticGroup {
+    pub fn count(&self) -> usize {
+        self.spans.len()
+    }
+}
+
+/// The number of errors or warnings of a level and code.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SummaryRow {
+    pub level: Level,
+    /// The error code or the name of the lint, if any.
+    pub code: Option<String>,
+    pub count: usize,
+}
+
+#[derive(Default)]
+pub struct DiagnosticGroups {
+    groups: Vec<DiagnosticGroup>,
+    /// The index in `groups` of the group of each level, code and message.
+    index: FxHashMap<(&'static str, Option<String>, String), usize>,
+    /// The rows of the summary, in the order in which their level and code were first emitted.
+    summary: Vec<SummaryRow>,
+}
+
+fn code(diag: &Diagnostic) -> Option<String> {
+    match &diag.code {
+        Some(DiagnosticId::Error(code)) => Some(code.clone()),
+        Some(DiagnosticId::Lint { name, .. }) => Some(name.clone()),
+        None => None,
+    }
+}
+
+impl DiagnosticGroups {
+    /// Adds `diag` to the group of the identical diagnostics and counts it in the summary, if it
+    /// is an error or a warning. Returns whether it was held back; other diagnostics should be
+    /// emitted right away, after the groups held back so far.
+    pub fn record(&mut self, diag: &Diagnostic) -> bool {
+        if !matches!(diag.level, Level::Error { .. } | Level::Warning) {
+            return false;
+        }
+
+        let code = code(diag);
+        let level = diag.level.to_str();
+        match self.summary.iter_mut().find(|row| row.level.to_str() == level && row.code == code) {
+            Some(row) => row.count += 1,
+            None => {
+                self.summary.push(SummaryRow { level: diag.level, code: code.clone(), count: 1 })
+            }
+        }
+
+        let span = diag.span.primary_span().unwrap_or(DUMMY_SP);
+        let groups = &mut self.groups;
+        let index = *self.index.entry((level, code, diag.message())).or_insert_with(|| {
+            groups.push(DiagnosticGroup { diagnostic: diag.clone(), spans: Vec::new() });
+            groups.len() - 1
+        });
+        self.groups[index].spans.push(span);
+        true
+    }
+
+    /// Returns the groups held back so far, in the order in which their first diagnostic was
+    /// emitted. Identical diagnostics recorded after this start a new group.
+    pub fn take_groups(&mut self) -> Vec<DiagnosticGroup> {
+        self.index.clear();
+        std::mem::take(&mut self.groups)
+    }
+
+    /// Returns the rows of the summary, from the most to the least frequent code.
+    pub fn summary(&self) -> Vec<SummaryRow> {
+        let mut summary = self.summary.clone();
+        summary.sort_by_key(|row| Reverse(row.count));
+        summary
+    }
+}
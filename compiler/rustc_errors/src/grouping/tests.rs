@@ -0,0 +1,106 @@
+use super::*;
+
+use rustc_span::BytePos;
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::with_root_ctxt(BytePos(lo), BytePos(hi))
+}
+
+fn error(code: &str, message: &str, span: Span) -> Diagnostic {
+    let code = DiagnosticId::Error(code.to_string());
+    let mut diag = Diagnostic::new_with_code(Level::Error { lint: false }, Some(code), message);
+    diag.set_span(span);
+    diag
+}
+
+fn warning(lint: &str, message: &str) -> Diagnostic {
+    let code = DiagnosticId::Lint {
+        name: lint.to_string(),
+        has_future_breakage: false,
+        is_force_warn: false,
+    };
+    Diagnostic::new_with_code(Level::Warning, Some(code), message)
+}
+
+fn row(level: Level, code: Option<&str>, count: usize) -> SummaryRow {
+    SummaryRow { level, code: code.map(str::to_string), count }
+}
+
+#[test]
+fn identical_diagnostics_are_grouped() {
+    rustc_span::create_default_session_globals_then(|| {
+        let mut groups = DiagnosticGroups::default();
+        assert!(groups.record(&error("E0308", "mismatched types", span(0, 1))));
+        assert!(groups.record(&error("E0599", "no method named `foo`", span(2, 3))));
+        assert!(groups.record(&error("E0308", "mismatched types", span(4, 5))));
+        assert!(groups.record(&error("E0308", "mismatched types", span(6, 7))));
+
+        let groups = groups.take_groups();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].diagnostic.message(), "mismatched types");
+        assert_eq!(groups[0].spans, [span(0, 1), span(4, 5), span(6, 7)]);
+        assert_eq!(groups[1].diagnostic.message(), "no method named `foo`");
+        assert_eq!(groups[1].count(), 1);
+    })
+}
+
+#[test]
+fn different_messages_or_codes_are_not_grouped() {
+    rustc_span::create_default_session_globals_then(|| {
+        let mut groups = DiagnosticGroups::default();
+        groups.record(&error("E0308", "mismatched types", span(0, 1)));
+        groups.record(&error("E0308", "mismatched types: expected `u32`", span(2, 3)));
+        groups.record(&error("E0309", "mismatched types", span(4, 5)));
+        groups.record(&warning("unused_mut", "mismatched types"));
+        assert_eq!(groups.take_groups().len(), 4);
+    })
+}
+
+#[test]
+fn other_levels_are_not_held_back() {
+    rustc_span::create_default_session_globals_then(|| {
+        let mut groups = DiagnosticGroups::default();
+        assert!(!groups.record(&Diagnostic::new(Level::Fatal, "aborting due to previous error")));
+        assert!(!groups.record(&Diagnostic::new(Level::Note, "a note")));
+        assert!(!groups.record(&Diagnostic::new(Level::FailureNote, "a failure note")));
+        assert!(groups.take_groups().is_empty());
+        assert!(groups.summary().is_empty());
+    })
+}
+
+#[test]
+fn taking_the_groups_starts_new_ones() {
+    rustc_span::create_default_session_globals_then(|| {
+        let mut groups = DiagnosticGroups::default();
+        groups.record(&error("E0308", "mismatched types", span(0, 1)));
+        assert_eq!(groups.take_groups().len(), 1);
+        groups.record(&error("E0308", "mismatched types", span(2, 3)));
+        let taken = groups.take_groups();
+        assert_eq!(taken.len(), 1);
+        assert_eq!(taken[0].spans, [span(2, 3)]);
+        // The summary still counts both.
+        assert_eq!(groups.summary(), [row(Level::Error { lint: false }, Some("E0308"), 2)]);
+    })
+}
+
+#[test]
+fn summary_is_sorted_by_count() {
+    rustc_span::create_default_session_globals_then(|| {
+        let mut groups = DiagnosticGroups::default();
+        groups.record(&warning("unused_variables", "unused variable: `x`"));
+        groups.record(&error("E0599", "no method named `foo`", span(0, 1)));
+        groups.record(&error("E0308", "mismatched types", span(2, 3)));
+        groups.record(&error("E0308", "mismatched types: expected `u32`", span(4, 5)));
+        groups.record(&warning("unused_variables", "unused variable: `y`"));
+        groups.record(&Diagnostic::new(Level::Error { lint: false }, "an error without code"));
+        assert_eq!(
+            groups.summary(),
+            [
+                row(Level::Warning, Some("unused_variables"), 2),
+                row(Level::Error { lint: false }, Some("E0308"), 2),
+                row(Level::Error { lint: false }, Some("E0599"), 1),
+                row(Level::Error { lint: false }, None, 1),
+            ]
+        );
+    })
+}
@@ -193,10 +193,16 @@ struct Diagnostic {
     children: Vec<Diagnostic>,
     /// The message as rustc would render it.
     rendered: Option<String>,
+    /// The version of the schema the diagnostic follows, only set on top-level diagnostics.
+    schema_version: Option<u32>,
     /// Extra tool metadata
     tool_metadata: ToolMetadata,
 }
 
+/// The version of `src/doc/rustc/src/json/diagnostic-v1.schema.json` that the emitted
+/// diagnostics follow.
+const SCHEMA_VERSION: u32 = 1;
+
 macro_rules! encode_fields {
     (
         $enc:expr,                  // encoder
@@ -223,7 +229,7 @@ macro_rules! encode_fields {
     };
 }
 
-// Special-case encoder to skip tool_metadata if not set
+// Special-case encoder to skip schema_version and tool_metadata if not set
 impl<E: Encoder> Encodable<E> for Diagnostic {
     fn encode(&self, s: &mut E) -> Result<(), E::Error> {
         s.emit_struct(false, |s| {
@@ -235,8 +241,18 @@ fn encode(&self, s: &mut E) -> Result<(), E::Error> {
                 self,
                 Self,
                 [message, code, level, spans, children, rendered],
-                [tool_metadata]
+                [schema_version, tool_metadata]
             );
+            if self.schema_version.is_some() {
+                idx = encode_fields!(
+                    s,
+                    idx,
+                    self,
+                    Self,
+                    [schema_version],
+                    [message, code, level, spans, children, rendered, tool_metadata]
+                );
+            }
             if self.tool_metadata.is_set() {
                 idx = encode_fields!(
                     s,
@@ -244,7 +260,7 @@ fn encode(&self, s: &mut E) -> Result<(), E::Error> {
                     self,
                     Self,
                     [tool_metadata],
-                    [message, code, level, spans, children, rendered]
+                    [message, code, level, spans, children, rendered, schema_version]
                 );
             }
 
@@ -352,6 +368,7 @@ fn from_errors_diagnostic(diag: &crate::Diagnostic, je: &JsonEmitter) -> Diagnos
             spans: DiagnosticSpan::from_suggestion(sugg, je),
             children: vec![],
             rendered: None,
+            schema_version: None,
             tool_metadata: sugg.tool_metadata.clone(),
         });
 
@@ -396,6 +413,7 @@ fn flush(&mut self) -> io::Result<()> {
                 .chain(sugg)
                 .collect(),
             rendered: Some(output),
+            schema_version: Some(SCHEMA_VERSION),
             tool_metadata: ToolMetadata::default(),
         }
     }
@@ -412,6 +430,7 @@ fn from_sub_diagnostic(diag: &SubDiagnostic, je: &JsonEmitter) -> Diagnostic {
                 .unwrap_or_else(|| DiagnosticSpan::from_multispan(&diag.span, je)),
             children: vec![],
             rendered: None,
+            schema_version: None,
             tool_metadata: ToolMetadata::default(),
         }
     }
@@ -5,7 +5,7 @@
 
 use crate::emitter::{ColorConfig, HumanReadableErrorType};
 use crate::Handler;
-use rustc_serialize::json::decode;
+use rustc_serialize::json::{decode, Json};
 use rustc_span::{BytePos, Span};
 
 use std::str;
@@ -197,3 +197,74 @@ fn span_after_crlf() {
         },
     )
 }
+
+/// Checks that `value` has all fields that the schema definition `definition` requires, and does
+/// the same for the diagnostics, spans and lines it contains.
+fn check_required_fields(definitions: &Json, definition: &str, value: &Json) {
+    let object = value.as_object().unwrap();
+    let required = definitions.find(definition).and_then(|def| def.find("required")).unwrap();
+    for field in required.as_array().unwrap() {
+        let field = field.as_string().unwrap();
+        assert!(object.contains_key(field), "`{}` is missing from `{}`", field, value);
+    }
+
+    let nested =
+        [("code", "code"), ("spans", "span"), ("children", "diagnostic"), ("text", "span_line")];
+    for &(field, definition) in &nested {
+        match object.get(field) {
+            Some(Json::Array(values)) => {
+                for value in values {
+                    check_required_fields(definitions, definition, value);
+                }
+            }
+            Some(value @ Json::Object(_)) => check_required_fields(definitions, definition, value),
+            _ => {}
+        }
+    }
+}
+
+/// Test that the JSON output has the fields documented in the published schema.
+#[test]
+fn matches_schema() {
+    let schema = include_str!("../../../../src/doc/rustc/src/json/diagnostic-v1.schema.json");
+    let schema = Json::from_str(schema).unwrap();
+    let definitions = schema.find("definitions").unwrap();
+
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("test.rs").to_owned().into(), "let x = 1;".to_owned());
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let je = JsonEmitter::new(
+            Box::new(Shared { data: output.clone() }),
+            None,
+            sm,
+            true,
+            HumanReadableErrorType::Short(ColorConfig::Never),
+            None,
+            false,
+        );
+
+        let span = Span::with_root_ctxt(BytePos(4), BytePos(5));
+        let handler = Handler::with_emitter(true, None, Box::new(je));
+        handler
+            .struct_span_err_with_code(span, "foo", DiagnosticId::Error("E0000".to_owned()))
+            .span_label(span, "bar")
+            .span_suggestion(span, "baz", "y".to_owned(), Applicability::MachineApplicable)
+            .note("qux")
+            .emit();
+
+        let bytes = output.lock().unwrap();
+        let actual_output = Json::from_str(str::from_utf8(&bytes).unwrap()).unwrap();
+        check_required_fields(definitions, "diagnostic", &actual_output);
+
+        // Only the top-level diagnostic names the version of the schema it follows.
+        let version = definitions
+            .find_path(&["diagnostic", "properties", "schema_version", "const"])
+            .unwrap();
+        assert_eq!(actual_output.find("schema_version"), Some(version));
+        for child in actual_output.find("children").unwrap().as_array().unwrap() {
+            assert_eq!(child.find("schema_version"), None);
+        }
+    })
+}
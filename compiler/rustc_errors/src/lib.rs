@@ -45,12 +45,19 @@
 mod diagnostic;
 mod diagnostic_builder;
 pub mod emitter;
+mod grouping;
 pub mod json;
 mod lock;
+mod long_type;
 pub mod registry;
+pub mod sarif;
+pub mod short_json;
 mod snippet;
 mod styled_buffer;
+mod translation;
+pub use long_type::shorten_long_type;
 pub use snippet::Style;
+pub use translation::{FluentBundle, TranslationError};
 
 pub type PResult<'a, T> = Result<T, DiagnosticBuilder<'a>>;
 
@@ -1009,6 +1016,7 @@ fn treat_err_as_bug(&self) -> bool {
 
     fn print_error_count(&mut self, registry: &Registry) {
         self.emit_stashed_diagnostics();
+        self.emitter.emit_summary();
 
         let warnings = match self.deduplicated_warn_count {
             0 => String::new(),
@@ -0,0 +1,87 @@
+//! Types that are too long to be printed in full in a diagnostic, like the types of long chains of
+//! iterator or future combinators, are printed in a shortened form, and written in full to a file.
+
+use rustc_data_structures::stable_hasher::StableHasher;
+
+use std::fs;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+#[cfg(test)]
+mod tests;
+
+/// Returns `ty`, the printed form of a type, shortened if it is longer than `max_len` characters.
+/// The full type is then written to a `long-type-<hash>.txt` file in `out_dir`, whose path is
+/// returned too, so that the diagnostic can point to it. If the file can't be written, `ty` is
+/// returned as is.
+pub fn shorten_long_type(ty: String, max_len: usize, out_dir: &Path) -> (String, Option<PathBuf>) {
+    if ty.chars().count() <= max_len {
+        return (ty, None);
+    }
+
+    let mut hasher = StableHasher::new();
+    ty.hash(&mut hasher);
+    let hash: u64 = hasher.finish();
+    let path = out_dir.join(format!("long-type-{:016x}.txt", hash));
+    match fs::write(&path, &ty) {
+        Ok(()) => (shorten(&ty, max_len), Some(path)),
+        Err(err) => {
+            debug!("shorten_long_type: failed to write `{}`: {}", path.display(), err);
+            (ty, None)
+        }
+    }
+}
+
+/// Replaces the innermost generic arguments of `ty` by `...`, one level of nesting at a time,
+/// until it is at most `max_len` characters long, or only the outermost generic arguments are
+/// elided.
+fn shorten(ty: &str, max_len: usize) -> String {
+    let max_depth = generic_depths(ty).map(|(_, depth)| depth).max().unwrap_or(0);
+    let mut shortened = ty.to_string();
+    for depth in (0..max_depth).rev() {
+        shortened = elide_deeper_than(ty, depth);
+        if shortened.chars().count() <= max_len {
+            break;
+        }
+    }
+    shortened
+}
+
+/// Returns the characters of `ty`, with the depth of generic arguments that each of them is at.
+/// The `<` and `>` of generic arguments are at the depth of the path they follow. The `>` of `->`
+/// isn't a bracket.
+fn generic_depths(ty: &str) -> impl Iterator<Item = (char, usize)> + '_ {
+    let mut depth = 0usize;
+    let mut prev = None;
+    ty.chars().map(move |c| {
+        let char_depth = match c {
+            '<' => {
+                depth += 1;
+                depth - 1
+            }
+            '>' if prev != Some('-') => {
+                depth = depth.saturating_sub(1);
+                depth
+            }
+            _ => depth,
+        };
+        prev = Some(c);
+        (c, char_depth)
+    })
+}
+
+/// Replaces the generic arguments of `ty` that are nested more than `depth` levels deep by `...`.
+fn elide_deeper_than(ty: &str, depth: usize) -> String {
+    let mut shortened = String::with_capacity(ty.len());
+    let mut elided = false;
+    for (c, char_depth) in generic_depths(ty) {
+        if char_depth <= depth {
+            shortened.push(c);
+            elided = false;
+        } else if !elided {
+            shortened.push_str("...");
+            elided = true;
+        }
+    }
+    shortened
+}
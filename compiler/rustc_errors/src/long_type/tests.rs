@@ -0,0 +1,33 @@
+use super::*;
+
+const TY: &str =
+    "Map<Filter<std::vec::IntoIter<Option<u8>>, [closure@a.rs:2:20: 2:31]>, fn(u8) -> u16>";
+
+#[test]
+fn short_types_are_kept() {
+    let dir = PathBuf::from("non-existent");
+    assert_eq!(shorten_long_type("Vec<u8>".to_string(), 7, &dir), ("Vec<u8>".to_string(), None));
+}
+
+#[test]
+fn innermost_arguments_are_elided_first() {
+    assert_eq!(
+        shorten(TY, 80),
+        "Map<Filter<std::vec::IntoIter<...>, [closure@a.rs:2:20: 2:31]>, fn(u8) -> u16>"
+    );
+    assert_eq!(shorten(TY, 40), "Map<Filter<...>, fn(u8) -> u16>");
+}
+
+#[test]
+fn outermost_arguments_are_elided_last() {
+    assert_eq!(shorten(TY, 5), "Map<...>");
+    assert_eq!(shorten("[closure@a.rs:2:20: 2:31]", 5), "[closure@a.rs:2:20: 2:31]");
+}
+
+#[test]
+fn arrows_are_not_brackets() {
+    assert_eq!(
+        elide_deeper_than("Box<dyn Fn(Vec<u8>) -> Vec<u16>>", 1),
+        "Box<dyn Fn(Vec<...>) -> Vec<...>>"
+    );
+}
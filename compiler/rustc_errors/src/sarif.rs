@@ -0,0 +1,333 @@
+//! A SARIF emitter for errors.
+//!
+//! [SARIF] is the format that static analysis tools report their results in, and that code
+//! scanning services ingest. Each diagnostic is converted to a result:
+//!
+//! - its rule is its error code or lint name, and points to the explanation of error codes,
+//! - its primary spans are its locations, with their labels as messages,
+//! - its secondary spans and the spans of its children are its related locations,
+//! - its children without a span are appended to its message,
+//! - its suggestions are its fixes, whose applicability is a tag of their property bag.
+//!
+//! A compilation is a single SARIF log, so the results are buffered, and the log is written once
+//! the emitter is dropped.
+//!
+//! [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+
+use crate::emitter::Emitter;
+use crate::registry::Registry;
+use crate::{Applicability, CodeSuggestion, DiagnosticId, Level, Substitution};
+
+use rustc_data_structures::sync::Lrc;
+use rustc_serialize::json::{Json, Object, ToJson};
+use rustc_span::Span;
+use std::io::{self, Write};
+use std::mem;
+use std::path::Path;
+
+#[cfg(test)]
+mod tests;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+pub struct SarifEmitter {
+    dst: Box<dyn Write + Send>,
+    registry: Option<Registry>,
+    sm: Lrc<SourceMap>,
+    ui_testing: bool,
+    /// The rules of the results, i.e., their error codes and lint names, in the order in which
+    /// they were first used.
+    rules: Vec<DiagnosticId>,
+    results: Vec<Json>,
+}
+
+impl SarifEmitter {
+    pub fn stderr(registry: Option<Registry>, source_map: Lrc<SourceMap>) -> SarifEmitter {
+        SarifEmitter::new(Box::new(io::BufWriter::new(io::stderr())), registry, source_map)
+    }
+
+    pub fn basic() -> SarifEmitter {
+        let file_path_mapping = FilePathMapping::empty();
+        SarifEmitter::stderr(None, Lrc::new(SourceMap::new(file_path_mapping)))
+    }
+
+    pub fn new(
+        dst: Box<dyn Write + Send>,
+        registry: Option<Registry>,
+        source_map: Lrc<SourceMap>,
+    ) -> SarifEmitter {
+        SarifEmitter {
+            dst,
+            registry,
+            sm: source_map,
+            ui_testing: false,
+            rules: Vec::new(),
+            results: Vec::new(),
+        }
+    }
+
+    pub fn ui_testing(mut self, ui_testing: bool) -> Self {
+        self.ui_testing = ui_testing;
+        self
+    }
+
+    fn rule_index(&mut self, code: &DiagnosticId) -> usize {
+        match self.rules.iter().position(|rule| rule == code) {
+            Some(index) => index,
+            None => {
+                self.rules.push(code.clone());
+                self.rules.len() - 1
+            }
+        }
+    }
+
+    fn rule(&self, code: &DiagnosticId) -> Json {
+        let mut rule = Object::new();
+        match code {
+            DiagnosticId::Error(code) => {
+                rule.insert("id".to_string(), code.to_json());
+                let explained = self.registry.as_ref().map_or(false, |registry| {
+                    matches!(registry.try_find_description(code), Ok(Some(_)))
+                });
+                if explained {
+                    let help_uri = format!("https://doc.rust-lang.org/error-index.html#{}", code);
+                    rule.insert("helpUri".to_string(), help_uri.to_json());
+                }
+            }
+            DiagnosticId::Lint { name, .. } => {
+                rule.insert("id".to_string(), name.to_json());
+            }
+        }
+        Json::Object(rule)
+    }
+
+    fn uri(&self, span: Span) -> String {
+        let file = self.sm.lookup_source_file(span.lo());
+        file_uri(&self.sm.filename_for_diagnostics(&file.name).to_string())
+    }
+
+    fn region(&self, span: Span) -> Json {
+        let start = self.sm.lookup_char_pos(span.lo());
+        let end = self.sm.lookup_char_pos(span.hi());
+        object([
+            ("startLine", start.line.to_json()),
+            ("startColumn", (start.col.0 + 1).to_json()),
+            ("endLine", end.line.to_json()),
+            ("endColumn", (end.col.0 + 1).to_json()),
+        ])
+    }
+
+    fn location(&self, span: Span, message: Option<String>) -> Object {
+        let physical_location = object([
+            ("artifactLocation", object([("uri", self.uri(span).to_json())])),
+            ("region", self.region(span)),
+        ]);
+        let mut location = Object::new();
+        location.insert("physicalLocation".to_string(), physical_location);
+        if let Some(message) = message {
+            location.insert("message".to_string(), text(message));
+        }
+        location
+    }
+
+    /// Converts an alternative of a suggestion to a fix, with one change per file it edits.
+    fn fix(&self, suggestion: &CodeSuggestion, substitution: &Substitution) -> Json {
+        let mut changes: Vec<(String, Vec<Json>)> = Vec::new();
+        for part in &substitution.parts {
+            if part.span.is_dummy() {
+                continue;
+            }
+            let uri = self.uri(part.span);
+            let replacement = object([
+                ("deletedRegion", self.region(part.span)),
+                ("insertedContent", text(part.snippet.clone())),
+            ]);
+            match changes.iter_mut().find(|(file, _)| *file == uri) {
+                Some((_, replacements)) => replacements.push(replacement),
+                None => changes.push((uri, vec![replacement])),
+            }
+        }
+        let changes = changes
+            .into_iter()
+            .map(|(uri, replacements)| {
+                object([
+                    ("artifactLocation", object([("uri", uri.to_json())])),
+                    ("replacements", Json::Array(replacements)),
+                ])
+            })
+            .collect();
+        // SARIF has no notion of the applicability of a fix: it is a tag of the fix instead.
+        let applicability = match suggestion.applicability {
+            Applicability::MachineApplicable => "machine-applicable",
+            Applicability::MaybeIncorrect => "maybe-incorrect",
+            Applicability::HasPlaceholders => "has-placeholders",
+            Applicability::Unspecified => "unspecified",
+        };
+        object([
+            ("description", text(suggestion.msg.clone())),
+            ("artifactChanges", Json::Array(changes)),
+            ("properties", object([("tags", Json::Array(vec![applicability.to_json()]))])),
+        ])
+    }
+}
+
+impl Emitter for SarifEmitter {
+    fn emit_diagnostic(&mut self, diag: &crate::Diagnostic) {
+        // The summary of the errors at the end of the compilation isn't a result.
+        if diag.level.is_failure_note() {
+            return;
+        }
+
+        let mut result = Object::new();
+        if let Some(code) = &diag.code {
+            let (DiagnosticId::Error(id) | DiagnosticId::Lint { name: id, .. }) = code;
+            result.insert("ruleId".to_string(), id.to_json());
+            result.insert("ruleIndex".to_string(), self.rule_index(code).to_json());
+        }
+        result.insert("level".to_string(), result_level(diag.level).to_json());
+
+        let mut message = diag.message();
+        let mut locations = Vec::new();
+        let mut related_locations = Vec::new();
+        for span_label in diag.span.span_labels() {
+            if span_label.span.is_dummy() {
+                continue;
+            }
+            let location = self.location(span_label.span, span_label.label);
+            if span_label.is_primary {
+                locations.push(Json::Object(location));
+            } else {
+                related_locations.push(location);
+            }
+        }
+        for child in &diag.children {
+            let span = child.render_span.as_ref().unwrap_or(&child.span);
+            let child_message = format!("{}: {}", child.level, child.message());
+            let spans: Vec<_> =
+                span.primary_spans().iter().copied().filter(|span| !span.is_dummy()).collect();
+            if spans.is_empty() {
+                message.push('\n');
+                message.push_str(&child_message);
+            }
+            for span in spans {
+                related_locations.push(self.location(span, Some(child_message.clone())));
+            }
+        }
+        result.insert("message".to_string(), text(message));
+        if !locations.is_empty() {
+            result.insert("locations".to_string(), Json::Array(locations));
+        }
+        if !related_locations.is_empty() {
+            let related_locations = related_locations
+                .into_iter()
+                .enumerate()
+                .map(|(id, mut location)| {
+                    location.insert("id".to_string(), id.to_json());
+                    Json::Object(location)
+                })
+                .collect();
+            result.insert("relatedLocations".to_string(), Json::Array(related_locations));
+        }
+
+        let this = &*self;
+        let fixes: Vec<_> = diag
+            .suggestions
+            .iter()
+            .flatten()
+            .flat_map(|suggestion| {
+                suggestion
+                    .substitutions
+                    .iter()
+                    .map(move |substitution| this.fix(suggestion, substitution))
+            })
+            .collect();
+        if !fixes.is_empty() {
+            result.insert("fixes".to_string(), Json::Array(fixes));
+        }
+
+        self.results.push(Json::Object(result));
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        // The rules of the log point to the explanations of the error codes instead.
+        false
+    }
+}
+
+impl Drop for SarifEmitter {
+    fn drop(&mut self) {
+        let mut driver = Object::new();
+        driver.insert("name".to_string(), "rustc".to_json());
+        driver.insert("informationUri".to_string(), "https://www.rust-lang.org/".to_json());
+        // Keep the output of UI tests independent of the version of the compiler.
+        if let (false, Some(version)) = (self.ui_testing, option_env!("CFG_RELEASE")) {
+            driver.insert("version".to_string(), version.to_json());
+        }
+        let rules = self.rules.iter().map(|code| self.rule(code)).collect();
+        driver.insert("rules".to_string(), Json::Array(rules));
+
+        let run = object([
+            ("tool", object([("driver", Json::Object(driver))])),
+            ("columnKind", "unicodeCodePoints".to_json()),
+            ("results", Json::Array(mem::take(&mut self.results))),
+        ]);
+        let log = object([
+            ("$schema", SARIF_SCHEMA.to_json()),
+            ("version", SARIF_VERSION.to_json()),
+            ("runs", Json::Array(vec![run])),
+        ]);
+        // There is nowhere left to report a failure to write the log to.
+        let _ = writeln!(&mut self.dst, "{}", log.pretty()).and_then(|_| self.dst.flush());
+    }
+}
+
+fn object<const N: usize>(fields: [(&str, Json); N]) -> Json {
+    Json::Object(fields.into_iter().map(|(name, value)| (name.to_string(), value)).collect())
+}
+
+/// A SARIF message, or the inserted content of a replacement.
+fn text(text: String) -> Json {
+    object([("text", Json::String(text))])
+}
+
+fn result_level(level: Level) -> &'static str {
+    match level {
+        Level::Bug | Level::Fatal | Level::Error { .. } => "error",
+        Level::Warning => "warning",
+        _ => "note",
+    }
+}
+
+/// Converts a file name to a URI reference: relative paths stay relative to the directory that
+/// rustc was run from, and absolute paths become `file` URIs. All the bytes of the name but the
+/// unreserved characters of RFC 3986 and the path separators are percent-encoded.
+fn file_uri(name: &str) -> String {
+    let is_absolute = Path::new(name).is_absolute();
+    let mut uri = String::with_capacity(name.len());
+    for (i, byte) in name.bytes().enumerate() {
+        match byte {
+            b'/' | b'\\' => uri.push('/'),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                uri.push(byte as char)
+            }
+            // The drive of a Windows path, like `C:\src\main.rs`.
+            b':' if i == 1 && is_absolute => uri.push(':'),
+            _ => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    if uri.starts_with('/') {
+        format!("file://{}", uri)
+    } else if is_absolute {
+        // A Windows path.
+        format!("file:///{}", uri)
+    } else {
+        uri
+    }
+}
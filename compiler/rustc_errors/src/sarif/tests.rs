@@ -0,0 +1,192 @@
+use super::*;
+
+use crate::Handler;
+use rustc_lint_defs::Applicability;
+use rustc_serialize::json::from_str;
+use rustc_span::BytePos;
+
+use std::str;
+use std::sync::{Arc, Mutex};
+
+const CODE: &str = "fn main() {\n    let x: i32 = \"a\";\n}\n";
+
+struct Shared<T> {
+    data: Arc<Mutex<T>>,
+}
+
+impl<T: Write> Write for Shared<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.data.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.data.lock().unwrap().flush()
+    }
+}
+
+fn span(lo: u32, hi: u32) -> Span {
+    Span::with_root_ctxt(BytePos(lo), BytePos(hi))
+}
+
+/// Emits the diagnostics of `emit` on `CODE`, and returns the SARIF log.
+fn sarif_log(emit: impl FnOnce(&Handler)) -> Json {
+    rustc_span::create_default_session_globals_then(|| {
+        let sm = Lrc::new(SourceMap::new(FilePathMapping::empty()));
+        sm.new_source_file(Path::new("src/main.rs").to_owned().into(), CODE.to_owned());
+
+        let output = Arc::new(Mutex::new(Vec::new()));
+        let emitter =
+            SarifEmitter::new(Box::new(Shared { data: output.clone() }), None, sm).ui_testing(true);
+        let handler = Handler::with_emitter(true, None, Box::new(emitter));
+        emit(&handler);
+        // The log is written when the emitter is dropped.
+        drop(handler);
+
+        let bytes = output.lock().unwrap();
+        from_str(str::from_utf8(&bytes).unwrap()).unwrap()
+    })
+}
+
+fn json(s: &str) -> Json {
+    from_str(s).unwrap()
+}
+
+#[test]
+fn empty_log() {
+    let log = sarif_log(|_| {});
+    assert_eq!(log["version"], json(r#""2.1.0""#));
+    assert_eq!(log["runs"][0]["results"], json("[]"));
+    assert_eq!(log["runs"][0]["tool"]["driver"]["name"], json(r#""rustc""#));
+    assert_eq!(log["runs"][0]["tool"]["driver"].find("version"), None);
+}
+
+#[test]
+fn locations_and_related_locations() {
+    let log = sarif_log(|handler| {
+        let code = DiagnosticId::Error("E0308".to_string());
+        handler
+            .struct_span_err_with_code(span(29, 32), "mismatched types", code)
+            .span_label(span(29, 32), "expected `i32`, found `&str`")
+            .span_label(span(23, 26), "expected due to this")
+            .span_note(span(16, 17), "`x` is declared here")
+            .note("an unspanned note")
+            .emit();
+    });
+    let result = &log["runs"][0]["results"][0];
+    assert_eq!(result["ruleId"], json(r#""E0308""#));
+    assert_eq!(result["ruleIndex"], json("0"));
+    assert_eq!(result["level"], json(r#""error""#));
+    assert_eq!(
+        result["message"],
+        json(r#"{ "text": "mismatched types\nnote: an unspanned note" }"#)
+    );
+    assert_eq!(
+        result["locations"],
+        json(
+            r#"[{
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "src/main.rs" },
+                    "region": { "startLine": 2, "startColumn": 18, "endLine": 2, "endColumn": 21 }
+                },
+                "message": { "text": "expected `i32`, found `&str`" }
+            }]"#
+        )
+    );
+    assert_eq!(
+        result["relatedLocations"],
+        json(
+            r#"[{
+                "id": 0,
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "src/main.rs" },
+                    "region": { "startLine": 2, "startColumn": 12, "endLine": 2, "endColumn": 15 }
+                },
+                "message": { "text": "expected due to this" }
+            }, {
+                "id": 1,
+                "physicalLocation": {
+                    "artifactLocation": { "uri": "src/main.rs" },
+                    "region": { "startLine": 2, "startColumn": 9, "endLine": 2, "endColumn": 10 }
+                },
+                "message": { "text": "note: `x` is declared here" }
+            }]"#
+        )
+    );
+    assert_eq!(log["runs"][0]["tool"]["driver"]["rules"], json(r#"[{ "id": "E0308" }]"#));
+}
+
+#[test]
+fn fixes_keep_their_applicability() {
+    let log = sarif_log(|handler| {
+        handler
+            .struct_span_err(span(29, 32), "mismatched types")
+            .span_suggestion(
+                span(29, 32),
+                "use an integer",
+                "1".to_string(),
+                Applicability::MaybeIncorrect,
+            )
+            .emit();
+    });
+    let result = &log["runs"][0]["results"][0];
+    assert_eq!(result.find("ruleId"), None);
+    assert_eq!(
+        result["fixes"],
+        json(
+            r#"[{
+                "description": { "text": "use an integer" },
+                "artifactChanges": [{
+                    "artifactLocation": { "uri": "src/main.rs" },
+                    "replacements": [{
+                        "deletedRegion": {
+                            "startLine": 2, "startColumn": 18, "endLine": 2, "endColumn": 21
+                        },
+                        "insertedContent": { "text": "1" }
+                    }]
+                }],
+                "properties": { "tags": ["maybe-incorrect"] }
+            }]"#
+        )
+    );
+}
+
+#[test]
+fn rules_are_shared_by_results() {
+    let lint = || DiagnosticId::Lint {
+        name: "unused_variables".to_string(),
+        has_future_breakage: false,
+        is_force_warn: false,
+    };
+    let log = sarif_log(|handler| {
+        handler.struct_span_warn_with_code(span(16, 17), "unused variable: `x`", lint()).emit();
+        handler
+            .struct_span_err_with_code(
+                span(29, 32),
+                "mismatched types",
+                DiagnosticId::Error("E0308".to_string()),
+            )
+            .emit();
+        handler.struct_span_warn_with_code(span(16, 17), "unused variable: `x`", lint()).emit();
+        // The summary of the errors isn't a result.
+        handler.emit_diagnostic(&crate::Diagnostic::new(Level::FailureNote, "aborting"));
+    });
+    let results = log["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0]["level"], json(r#""warning""#));
+    assert_eq!(results[0]["ruleIndex"], json("0"));
+    assert_eq!(results[1]["ruleIndex"], json("1"));
+    assert_eq!(results[2]["ruleIndex"], json("0"));
+    assert_eq!(
+        log["runs"][0]["tool"]["driver"]["rules"],
+        json(r#"[{ "id": "unused_variables" }, { "id": "E0308" }]"#)
+    );
+}
+
+#[test]
+fn uris() {
+    assert_eq!(file_uri("src/main.rs"), "src/main.rs");
+    assert_eq!(file_uri("src\\my lib.rs"), "src/my%20lib.rs");
+    assert_eq!(file_uri("src/[a]#%?:.rs"), "src/%5Ba%5D%23%25%3F%3A.rs");
+    assert_eq!(file_uri("src/caf\u{e9}.rs"), "src/caf%C3%A9.rs");
+    assert_eq!(file_uri("/home/user/src/main.rs"), "file:///home/user/src/main.rs");
+}
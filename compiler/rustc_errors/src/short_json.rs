@@ -0,0 +1,149 @@
+//! An emitter of the grouped diagnostics of `--error-format=short-json`.
+//!
+//! Each group of identical errors or warnings is written as a JSON object on its own line, with
+//! the locations of all its occurrences (wrapped here):
+//!
+//! ```text
+//! {"code":"E0308","count":2,"level":"error","locations":[{"column":9,"file":"src/a.rs","line":6},
+//! {"column":5,"file":"src/b.rs","line":12}],"message":"mismatched types"}
+//! ```
+//!
+//! Before the number of errors is reported, the groups are followed by a summary of the number
+//! of errors and warnings of each code or lint:
+//!
+//! ```text
+//! {"summary":[{"code":"E0308","count":2,"level":"error"},...]}
+//! ```
+//!
+//! Other diagnostics, like fatal errors and the number of errors, are written in the same way as
+//! the groups, with a count of 1.
+
+use rustc_span::source_map::{FilePathMapping, SourceMap};
+
+use crate::emitter::Emitter;
+use crate::grouping::{DiagnosticGroup, DiagnosticGroups};
+use crate::DiagnosticId;
+
+use rustc_data_structures::sync::Lrc;
+use rustc_serialize::json::{Json, ToJson};
+use rustc_span::{Span, DUMMY_SP};
+use std::io::{self, Write};
+
+pub struct ShortJsonEmitter {
+    dst: Box<dyn Write + Send>,
+    sm: Lrc<SourceMap>,
+    /// The groups held back until the summary, which is `None` once it has been written.
+    groups: Option<DiagnosticGroups>,
+}
+
+impl ShortJsonEmitter {
+    pub fn stderr(source_map: Lrc<SourceMap>) -> ShortJsonEmitter {
+        ShortJsonEmitter::new(Box::new(io::BufWriter::new(io::stderr())), source_map)
+    }
+
+    pub fn basic() -> ShortJsonEmitter {
+        let file_path_mapping = FilePathMapping::empty();
+        ShortJsonEmitter::stderr(Lrc::new(SourceMap::new(file_path_mapping)))
+    }
+
+    pub fn new(dst: Box<dyn Write + Send>, source_map: Lrc<SourceMap>) -> ShortJsonEmitter {
+        ShortJsonEmitter { dst, sm: source_map, groups: Some(DiagnosticGroups::default()) }
+    }
+
+    fn location(&self, span: Span) -> Json {
+        let loc = self.sm.lookup_char_pos(span.lo());
+        object([
+            ("file", self.sm.filename_for_diagnostics(&loc.file.name).to_string().to_json()),
+            ("line", loc.line.to_json()),
+            ("column", (loc.col.0 + 1).to_json()),
+        ])
+    }
+
+    fn write_group(&mut self, group: &DiagnosticGroup) {
+        let diag = &group.diagnostic;
+        let code = match &diag.code {
+            Some(DiagnosticId::Error(code) | DiagnosticId::Lint { name: code, .. }) => {
+                code.to_json()
+            }
+            None => Json::Null,
+        };
+        let locations = group
+            .spans
+            .iter()
+            .filter(|span| !span.is_dummy())
+            .map(|&span| self.location(span))
+            .collect();
+        let line = object([
+            ("level", diag.level.to_str().to_json()),
+            ("code", code),
+            ("message", diag.message().to_json()),
+            ("count", group.count().to_json()),
+            ("locations", Json::Array(locations)),
+        ]);
+        self.write_line(&line);
+    }
+
+    fn write_pending_groups(&mut self) {
+        let groups = match &mut self.groups {
+            Some(groups) => groups.take_groups(),
+            None => return,
+        };
+        for group in &groups {
+            self.write_group(group);
+        }
+    }
+
+    fn write_line(&mut self, line: &Json) {
+        if let Err(e) = writeln!(&mut self.dst, "{}", line).and_then(|_| self.dst.flush()) {
+            panic!("failed to print diagnostics: {:?}", e);
+        }
+    }
+}
+
+impl Emitter for ShortJsonEmitter {
+    fn emit_diagnostic(&mut self, diag: &crate::Diagnostic) {
+        if let Some(groups) = &mut self.groups {
+            if groups.record(diag) {
+                return;
+            }
+        }
+        self.write_pending_groups();
+        let span = diag.span.primary_span().unwrap_or(DUMMY_SP);
+        self.write_group(&DiagnosticGroup { diagnostic: diag.clone(), spans: vec![span] });
+    }
+
+    fn emit_summary(&mut self) {
+        self.write_pending_groups();
+        let Some(groups) = self.groups.take() else { return };
+        let summary = groups
+            .summary()
+            .into_iter()
+            .map(|row| {
+                object([
+                    ("level", row.level.to_str().to_json()),
+                    ("code", row.code.to_json()),
+                    ("count", row.count.to_json()),
+                ])
+            })
+            .collect();
+        self.write_line(&object([("summary", Json::Array(summary))]));
+    }
+
+    fn source_map(&self) -> Option<&Lrc<SourceMap>> {
+        Some(&self.sm)
+    }
+
+    fn should_show_explain(&self) -> bool {
+        false
+    }
+}
+
+impl Drop for ShortJsonEmitter {
+    fn drop(&mut self) {
+        self.write_pending_groups();
+    }
+}
+
+fn object<const N: usize>(fields: [(&str, Json); N]) -> Json {
+    Json::Object(fields.into_iter().map(|(name, value)| (name.to_string(), value)).collect())
+}
@@ -174,6 +174,16 @@ pub enum Style {
     NoStyle,
     Level(Level),
     Highlight,
+    /// The expected type of a mismatch, where the parts that differ from the found type are
+    /// `emphasized`.
+    Expected {
+        emphasized: bool,
+    },
+    /// The found type of a mismatch, where the parts that differ from the expected type are
+    /// `emphasized`.
+    Found {
+        emphasized: bool,
+    },
     Addition,
     Removal,
 }
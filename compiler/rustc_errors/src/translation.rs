@@ -0,0 +1,168 @@
+//! Translation of diagnostics into other languages than English.
+//!
+//! Translations are written in [Fluent] resources, `.ftl` files, as messages named by the slug of
+//! a diagnostic that derives `SessionDiagnostic`, with one attribute per note, help, label or
+//! suggestion message of the diagnostic:
+//!
+//! ```text
+//! # The primary message, which refers to the `ident` field of the diagnostic.
+//! typeck-field-multiply-specified-in-initializer = champ `{ $ident }` spécifié plus d'une fois
+//!     # The label on the `span` field.
+//!     .span = utilisé plus d'une fois
+//! ```
+//!
+//! The resources are parsed and the messages are formatted with the `fluent-bundle` crate. A
+//! message or an attribute that is missing from the resources falls back to English.
+//!
+//! [Fluent]: https://projectfluent.org/fluent/guide/
+
+use fluent_bundle::{FluentArgs, FluentResource};
+use intl_memoizer::concurrent::IntlLangMemoizer;
+use unic_langid::LanguageIdentifier;
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[cfg(test)]
+mod tests;
+
+/// An error in a Fluent resource, or in the translations to load.
+#[derive(Debug, PartialEq)]
+pub struct TranslationError {
+    /// The path of the resource, if it was read from a file.
+    pub path: Option<String>,
+    /// The line of the error, starting at 1, or 0 if the error isn't in the resource itself.
+    pub line: usize,
+    pub msg: String,
+}
+
+impl fmt::Display for TranslationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.path, self.line) {
+            (Some(path), 0) => write!(f, "{}: {}", path, self.msg),
+            (Some(path), line) => write!(f, "{}:{}: {}", path, line, self.msg),
+            (None, 0) => write!(f, "{}", self.msg),
+            (None, line) => write!(f, "line {}: {}", line, self.msg),
+        }
+    }
+}
+
+/// The translated messages of one language.
+pub struct FluentBundle {
+    pub locale: LanguageIdentifier,
+    bundle: fluent_bundle::bundle::FluentBundle<FluentResource, IntlLangMemoizer>,
+}
+
+impl FluentBundle {
+    pub fn new(locale: LanguageIdentifier) -> Self {
+        let mut bundle = fluent_bundle::bundle::FluentBundle::new_concurrent(vec![locale.clone()]);
+        // The Unicode isolation marks around placeables would be printed as is by the emitters.
+        bundle.set_use_isolating(false);
+        FluentBundle { locale, bundle }
+    }
+
+    /// Loads the translations for `locale`, from all the resources in the
+    /// `share/locale/<locale>` directory of `sysroot`, and then from `additional_ftl`, whose
+    /// messages override the ones of the sysroot.
+    ///
+    /// Returns `None` if the sysroot has no translations for `locale` and there is no
+    /// `additional_ftl`, in which case diagnostics are emitted in English.
+    pub fn load(
+        sysroot: &Path,
+        locale: &str,
+        additional_ftl: Option<&Path>,
+    ) -> Result<Option<Self>, Vec<TranslationError>> {
+        let io_error = |path: &Path, err: io::Error| {
+            vec![TranslationError {
+                path: Some(path.display().to_string()),
+                line: 0,
+                msg: format!("failed to read translations: {}", err),
+            }]
+        };
+
+        let langid = locale.parse().map_err(|_| {
+            vec![TranslationError {
+                path: None,
+                line: 0,
+                msg: format!("`{}` isn't a valid language identifier", locale),
+            }]
+        })?;
+        let mut bundle = FluentBundle::new(langid);
+        let locale_dir = sysroot.join("share").join("locale").join(locale);
+        let mut paths = Vec::new();
+        match fs::read_dir(&locale_dir) {
+            Ok(entries) => {
+                for entry in entries {
+                    let path = entry.map_err(|err| io_error(&locale_dir, err))?.path();
+                    if path.extension().map_or(false, |ext| ext == "ftl") {
+                        paths.push(path);
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                // Only the additional resource is used when the sysroot has no translations.
+                if additional_ftl.is_none() {
+                    return Ok(None);
+                }
+            }
+            Err(err) => return Err(io_error(&locale_dir, err)),
+        }
+        // Keep the order in which the messages override each other deterministic.
+        paths.sort();
+        paths.extend(additional_ftl.map(Path::to_path_buf));
+
+        for path in paths {
+            let source = fs::read_to_string(&path).map_err(|err| io_error(&path, err))?;
+            bundle.add_resource(source).map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|err| TranslationError { path: Some(path.display().to_string()), ..err })
+                    .collect::<Vec<_>>()
+            })?;
+        }
+        Ok(Some(bundle))
+    }
+
+    /// Adds the messages of the Fluent resource `source` to the bundle, replacing the existing
+    /// messages with the same slug.
+    pub fn add_resource(&mut self, source: String) -> Result<(), Vec<TranslationError>> {
+        let resource = FluentResource::try_new(source).map_err(|(resource, errors)| {
+            errors
+                .into_iter()
+                .map(|err| {
+                    let line = resource.source()[..err.pos.start].matches('\n').count() + 1;
+                    TranslationError { path: None, line, msg: err.to_string() }
+                })
+                .collect::<Vec<_>>()
+        })?;
+        self.bundle.add_resource_overriding(resource);
+        Ok(())
+    }
+
+    /// Returns the translation of the message `slug`, or of its attribute `attr` if given, with
+    /// its variables replaced by the values in `args`. A variable that isn't in `args` is kept as
+    /// `{$name}`, so that an incorrect translation can still be understood.
+    pub fn format(
+        &self,
+        slug: &str,
+        attr: Option<&str>,
+        args: &[(&str, String)],
+    ) -> Option<String> {
+        let message = self.bundle.get_message(slug)?;
+        let pattern = match attr {
+            Some(attr) => message.get_attribute(attr)?.value(),
+            None => message.value()?,
+        };
+
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.as_str());
+        }
+        // The errors, like a missing variable, are also written in the formatted message.
+        let mut errors = Vec::new();
+        let formatted = self.bundle.format_pattern(pattern, Some(&fluent_args), &mut errors);
+        Some(formatted.into_owned())
+    }
+}
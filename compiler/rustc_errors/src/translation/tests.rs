@@ -0,0 +1,99 @@
+use super::*;
+
+fn bundle(source: &str) -> FluentBundle {
+    let mut bundle = FluentBundle::new("fr".parse().unwrap());
+    bundle.add_resource(source.to_string()).unwrap();
+    bundle
+}
+
+fn error_lines(source: &str) -> Vec<usize> {
+    let errors = FluentBundle::new("fr".parse().unwrap()).add_resource(source.to_string());
+    errors.unwrap_err().into_iter().map(|err| err.line).collect()
+}
+
+#[test]
+fn messages_and_attributes() {
+    let bundle = bundle(
+        "# A comment.
+typeck-field-multiply-specified = champ `{ $ident }` spécifié plus d'une fois
+    .span = utilisé plus d'une fois
+    .prev-span = `{ $ident }` utilisé ici en premier
+
+resolve-only-attributes =
+    .note = une note
+",
+    );
+    let args = [("ident", "x".to_string())];
+    let format = |slug, attr| bundle.format(slug, attr, &args);
+    assert_eq!(
+        format("typeck-field-multiply-specified", None).as_deref(),
+        Some("champ `x` spécifié plus d'une fois")
+    );
+    assert_eq!(
+        format("typeck-field-multiply-specified", Some("span")).as_deref(),
+        Some("utilisé plus d'une fois")
+    );
+    assert_eq!(
+        format("typeck-field-multiply-specified", Some("prev-span")).as_deref(),
+        Some("`x` utilisé ici en premier")
+    );
+    assert_eq!(format("resolve-only-attributes", Some("note")).as_deref(), Some("une note"));
+}
+
+#[test]
+fn missing_translations_fall_back() {
+    let bundle = bundle("resolve-only-attributes =\n    .note = une note\n");
+    assert_eq!(bundle.format("resolve-only-attributes", None, &[]), None);
+    assert_eq!(bundle.format("resolve-only-attributes", Some("help"), &[]), None);
+    assert_eq!(bundle.format("typeck-unknown", None, &[]), None);
+}
+
+#[test]
+fn multiline_patterns() {
+    let bundle = bundle(
+        "borrowck-multiline = première ligne\n    seconde ligne\n    .help =\n        aide\n",
+    );
+    assert_eq!(
+        bundle.format("borrowck-multiline", None, &[]).as_deref(),
+        Some("première ligne\nseconde ligne")
+    );
+    assert_eq!(bundle.format("borrowck-multiline", Some("help"), &[]).as_deref(), Some("aide"));
+}
+
+#[test]
+fn string_literals_and_unknown_variables() {
+    let bundle = bundle(r#"typeck-braces = { "{" }{ $name }{ "}" } et { $other }"#);
+    assert_eq!(
+        bundle.format("typeck-braces", None, &[("name", "T".to_string())]).as_deref(),
+        Some("{T} et {$other}")
+    );
+}
+
+#[test]
+fn later_resources_override_earlier_ones() {
+    let mut bundle = bundle("typeck-a = un\ntypeck-b = deux\n");
+    bundle.add_resource("typeck-a = uno\n".to_string()).unwrap();
+    assert_eq!(bundle.format("typeck-a", None, &[]).as_deref(), Some("uno"));
+    assert_eq!(bundle.format("typeck-b", None, &[]).as_deref(), Some("deux"));
+}
+
+#[test]
+fn invalid_resources_are_rejected() {
+    assert_eq!(error_lines("typeck-a = un\n1abc = x\n"), vec![2]);
+    assert!(!error_lines("typeck-empty =\n").is_empty());
+    assert!(!error_lines("typeck-a = { $ident\n").is_empty());
+}
+
+#[test]
+fn missing_locales_fall_back_to_english() {
+    let sysroot = Path::new("/nonexistent-sysroot");
+    assert!(FluentBundle::load(sysroot, "fr", None).unwrap().is_none());
+    assert_eq!(
+        FluentBundle::load(sysroot, "not a locale", None).err(),
+        Some(vec![TranslationError {
+            path: None,
+            line: 0,
+            msg: "`not a locale` isn't a valid language identifier".to_string(),
+        }])
+    );
+}
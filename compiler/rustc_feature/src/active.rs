@@ -142,6 +142,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, allow_internal_unstable, "1.0.0", None, None),
     /// Allows identifying the `compiler_builtins` crate.
     (active, compiler_builtins, "1.13.0", None, None),
+    /// Allows writing custom MIR
+    (active, custom_mir, "1.60.0", None, None),
     /// Allows using the `rust-intrinsic`'s "ABI".
     (active, intrinsics, "1.0.0", None, None),
     /// Allows using `#[lang = ".."]` attribute for linking items to special compiler logic.
@@ -325,10 +327,10 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, const_eval_limit, "1.43.0", Some(67217), None),
     /// Allows the definition of `const extern fn` and `const unsafe extern fn`.
     (active, const_extern_fn, "1.40.0", Some(64926), None),
-    /// Allows basic arithmetic on floating point types in a `const fn`.
-    (active, const_fn_floating_point_arithmetic, "1.48.0", Some(57241), None),
     /// Allows using and casting function pointers in a `const fn`.
     (active, const_fn_fn_ptr_basics, "1.48.0", Some(57563), None),
+    /// Allows calls through function pointers in `const fn`s and constants.
+    (active, const_fn_ptr_calls, "1.60.0", None, None),
     /// Allows trait bounds in `const fn`.
     (active, const_fn_trait_bound, "1.53.0", Some(93706), None),
     /// Allows `for _ in _` loops in const contexts.
@@ -373,6 +375,8 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, exhaustive_patterns, "1.13.0", Some(51085), None),
     /// Allows explicit generic arguments specification with `impl Trait` present.
     (active, explicit_generic_args_with_impl_trait, "1.56.0", Some(83701), None),
+    /// Allows guaranteed tail calls with `become`.
+    (incomplete, explicit_tail_calls, "1.60.0", None, None),
     /// Allows defining `extern type`s.
     (active, extern_types, "1.23.0", Some(43467), None),
     /// Allows the use of `#[ffi_const]` on foreign functions.
@@ -496,6 +500,9 @@ pub fn set(&self, features: &mut Features, span: Span) {
     (active, static_nobundle, "1.16.0", Some(37403), None),
     /// Allows attributes on expressions and non-item statements.
     (active, stmt_expr_attributes, "1.6.0", Some(15701), None),
+    /// Allows the use of the strict provenance lints, `fuzzy_provenance_casts` and
+    /// `lossy_provenance_casts`.
+    (active, strict_provenance, "1.60.0", Some(95228), None),
     /// Allows the use of `#[target_feature]` on safe functions.
     (active, target_feature_11, "1.45.0", Some(69098), None),
     /// Allows using `#[thread_local]` on `static` items.
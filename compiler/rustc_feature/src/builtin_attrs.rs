@@ -335,6 +335,10 @@ pub struct BuiltinAttribute {
         move_size_limit, CrateLevel, template!(NameValueStr: "N"), ErrorFollowing,
         large_assignments, experimental!(move_size_limit)
     ),
+    gated!(
+        future_size_limit, CrateLevel, template!(NameValueStr: "N"), ErrorFollowing,
+        large_assignments, experimental!(future_size_limit)
+    ),
 
     // Entry point:
     ungated!(start, Normal, template!(Word), WarnFollowing),
@@ -741,6 +745,10 @@ pub struct BuiltinAttribute {
         omit_gdb_pretty_printer_section, Normal, template!(Word), WarnFollowing,
         "the `#[omit_gdb_pretty_printer_section]` attribute is just used for the Rust test suite",
     ),
+    gated!(
+        custom_mir, Normal, template!(List: r#"dialect = "...", /*opt*/ phase = "...""#),
+        ErrorFollowing, "the `#[custom_mir]` attribute is just used for the Rust test suite",
+    ),
 ];
 
 pub fn deprecated_attributes() -> Vec<&'static BuiltinAttribute> {
@@ -1576,6 +1576,11 @@ pub fn print_expr(&mut self, expr: &hir::Expr<'_>) {
                     self.print_expr_maybe_paren(&expr, parser::PREC_JUMP);
                 }
             }
+            hir::ExprKind::Become(ref result) => {
+                self.word("become");
+                self.word(" ");
+                self.print_expr_maybe_paren(&result, parser::PREC_JUMP);
+            }
             hir::ExprKind::InlineAsm(ref asm) => {
                 self.word("asm!");
                 self.print_inline_asm(asm);
@@ -27,6 +27,7 @@
 use super::lub::Lub;
 use super::sub::Sub;
 use super::type_variable::TypeVariableValue;
+use super::type_variable::UnificationOriginKind;
 use super::unify_key::replace_if_possible;
 use super::unify_key::{ConstVarValue, ConstVariableValue};
 use super::unify_key::{ConstVariableOrigin, ConstVariableOriginKind};
@@ -654,6 +655,12 @@ fn tys(&mut self, t: Ty<'tcx>, t2: Ty<'tcx>) -> RelateResult<'tcx, Ty<'tcx>> {
                             // operation. This is needed to detect cyclic types. To see why, see the
                             // docs in the `type_variables` module.
                             self.infcx.inner.borrow_mut().type_variables().sub(vid, new_var_id);
+                            self.infcx.record_unification_origin(
+                                vid,
+                                new_var_id,
+                                UnificationOriginKind::Generalization,
+                                self.cause,
+                            );
                             debug!("generalize: replacing original vid={:?} with new={:?}", vid, u);
                             Ok(u)
                         }
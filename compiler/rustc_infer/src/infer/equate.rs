@@ -1,4 +1,5 @@
 use super::combine::{CombineFields, ConstEquateRelation, RelationDir};
+use super::type_variable::UnificationOriginKind;
 use super::Subtype;
 
 use rustc_middle::ty::relate::{self, Relate, RelateResult, TypeRelation};
@@ -82,6 +83,8 @@ fn tys(&mut self, a: Ty<'tcx>, b: Ty<'tcx>) -> RelateResult<'tcx, Ty<'tcx>> {
         match (a.kind(), b.kind()) {
             (&ty::Infer(TyVar(a_id)), &ty::Infer(TyVar(b_id))) => {
                 infcx.inner.borrow_mut().type_variables().equate(a_id, b_id);
+                let cause = &self.fields.trace.cause;
+                infcx.record_unification_origin(a_id, b_id, UnificationOriginKind::Equate, cause);
             }
 
             (&ty::Infer(TyVar(a_id)), _) => {
@@ -74,6 +74,7 @@
 use rustc_span::{sym, BytePos, DesugaringKind, MultiSpan, Pos, Span};
 use rustc_target::spec::abi;
 use std::ops::ControlFlow;
+use std::path::PathBuf;
 use std::{cmp, fmt, iter};
 
 mod note;
@@ -271,12 +272,18 @@ pub fn unexpected_hidden_region_diagnostic<'tcx>(
                 nice_region_error::suggest_new_region_bound(
                     tcx,
                     &mut err,
-                    fn_returns,
+                    fn_returns.clone(),
                     hidden_region.to_string(),
                     None,
                     format!("captures `{}`", hidden_region),
                     None,
-                )
+                );
+                nice_region_error::suggest_captures_bound(
+                    tcx,
+                    &mut err,
+                    fn_returns,
+                    hidden_region.to_string(),
+                );
             }
         }
         _ => {
@@ -1090,10 +1097,61 @@ fn cmp_fn_sig(
         values
     }
 
+    /// Pushes the compared generic arguments `args` of two types, separated by commas. The flag of
+    /// each argument tells whether it was elided because it is the same in both types. If
+    /// `collapse` is set, a run of more than one elided argument at the start or at the end of the
+    /// list is shown as a single `…`.
+    fn push_generic_args(
+        &self,
+        values: &mut (DiagnosticStyledString, DiagnosticStyledString),
+        args: Vec<(DiagnosticStyledString, DiagnosticStyledString, bool)>,
+        collapse: bool,
+    ) {
+        let (prefix, suffix) = if collapse {
+            let prefix = args.iter().take_while(|(.., elided)| *elided).count();
+            let suffix = args[prefix..].iter().rev().take_while(|(.., elided)| *elided).count();
+            (if prefix > 1 { prefix } else { 0 }, if suffix > 1 { suffix } else { 0 })
+        } else {
+            (0, 0)
+        };
+        let ellipsis =
+            || (DiagnosticStyledString::normal("…"), DiagnosticStyledString::normal("…"));
+
+        let shown = args.len() - prefix - suffix;
+        let mut parts = Vec::with_capacity(shown + 2);
+        if prefix > 0 {
+            parts.push(ellipsis());
+        }
+        parts.extend(args.into_iter().skip(prefix).take(shown).map(|(x1, x2, _)| (x1, x2)));
+        if suffix > 0 {
+            parts.push(ellipsis());
+        }
+
+        let len = parts.len();
+        for (i, (x1, x2)) in parts.into_iter().enumerate() {
+            (values.0).0.extend(x1.0);
+            (values.1).0.extend(x2.0);
+            self.push_comma(&mut values.0, &mut values.1, len, i);
+        }
+    }
+
     /// Compares two given types, eliding parts that are the same between them and highlighting
     /// relevant differences, and return two representation of those types for highlighted printing.
     fn cmp(&self, t1: Ty<'tcx>, t2: Ty<'tcx>) -> (DiagnosticStyledString, DiagnosticStyledString) {
+        self.cmp_inner(t1, t2, false)
+    }
+
+    /// Does the work of `cmp`. `is_generic_arg` is set when the types are the generic arguments of
+    /// a type that is being compared, where the elided arguments they have in common can be
+    /// collapsed, as the outer type shows enough context.
+    fn cmp_inner(
+        &self,
+        t1: Ty<'tcx>,
+        t2: Ty<'tcx>,
+        is_generic_arg: bool,
+    ) -> (DiagnosticStyledString, DiagnosticStyledString) {
         debug!("cmp(t1={}, t1.kind={:?}, t2={}, t2.kind={:?})", t1, t1.kind(), t2, t2.kind());
+        let elide = !self.tcx.sess.opts.debugging_opts.verbose_type_diffs;
 
         // helper functions
         fn equals<'tcx>(a: Ty<'tcx>, b: Ty<'tcx>) -> bool {
@@ -1189,21 +1247,30 @@ fn lifetime_display(lifetime: Region<'_>) -> String {
                     //
                     // for<'r, 's> fn(Inv<'r>, Inv<'s>)
                     // for<'r> fn(Inv<'r>, Inv<'r>)
+                    let mut args = Vec::with_capacity(len);
                     let lifetimes = sub1.regions().zip(sub2.regions());
-                    for (i, lifetimes) in lifetimes.enumerate() {
+                    for lifetimes in lifetimes {
                         let l1 = lifetime_display(lifetimes.0);
                         let l2 = lifetime_display(lifetimes.1);
                         if lifetimes.0 != lifetimes.1 {
-                            values.0.push_highlighted(l1);
-                            values.1.push_highlighted(l2);
-                        } else if lifetimes.0.is_late_bound() {
-                            values.0.push_normal(l1);
-                            values.1.push_normal(l2);
+                            args.push((
+                                DiagnosticStyledString::highlighted(l1),
+                                DiagnosticStyledString::highlighted(l2),
+                                false,
+                            ));
+                        } else if lifetimes.0.is_late_bound() || !elide {
+                            args.push((
+                                DiagnosticStyledString::normal(l1),
+                                DiagnosticStyledString::normal(l2),
+                                false,
+                            ));
                         } else {
-                            values.0.push_normal("'_");
-                            values.1.push_normal("'_");
+                            args.push((
+                                DiagnosticStyledString::normal("'_"),
+                                DiagnosticStyledString::normal("'_"),
+                                true,
+                            ));
                         }
-                        self.push_comma(&mut values.0, &mut values.1, len, i);
                     }
 
                     // We're comparing two types with the same path, so we compare the type
@@ -1215,34 +1282,58 @@ fn lifetime_display(lifetime: Region<'_>) -> String {
                     let type_arguments = sub1.types().zip(sub2.types());
                     let regions_len = sub1.regions().count();
                     let num_display_types = consts_offset - regions_len;
-                    for (i, (ta1, ta2)) in type_arguments.take(num_display_types).enumerate() {
-                        let i = i + regions_len;
-                        if ta1 == ta2 {
-                            values.0.push_normal("_");
-                            values.1.push_normal("_");
+                    for (ta1, ta2) in type_arguments.take(num_display_types) {
+                        if ta1 != ta2 {
+                            let (x1, x2) = self.cmp_inner(ta1, ta2, true);
+                            args.push((x1, x2, false));
+                        } else if elide {
+                            args.push((
+                                DiagnosticStyledString::normal("_"),
+                                DiagnosticStyledString::normal("_"),
+                                true,
+                            ));
                         } else {
-                            let (x1, x2) = self.cmp(ta1, ta2);
-                            (values.0).0.extend(x1.0);
-                            (values.1).0.extend(x2.0);
+                            args.push((
+                                DiagnosticStyledString::normal(ta1.to_string()),
+                                DiagnosticStyledString::normal(ta2.to_string()),
+                                false,
+                            ));
                         }
-                        self.push_comma(&mut values.0, &mut values.1, len, i);
                     }
 
                     // Do the same for const arguments, if they are equal, do not highlight and
                     // elide them from the output.
                     let const_arguments = sub1.consts().zip(sub2.consts());
-                    for (i, (ca1, ca2)) in const_arguments.enumerate() {
-                        let i = i + consts_offset;
-                        if ca1 == ca2 {
-                            values.0.push_normal("_");
-                            values.1.push_normal("_");
+                    for (ca1, ca2) in const_arguments {
+                        if ca1 != ca2 {
+                            args.push((
+                                DiagnosticStyledString::highlighted(ca1.to_string()),
+                                DiagnosticStyledString::highlighted(ca2.to_string()),
+                                false,
+                            ));
+                        } else if elide {
+                            args.push((
+                                DiagnosticStyledString::normal("_"),
+                                DiagnosticStyledString::normal("_"),
+                                true,
+                            ));
                         } else {
-                            values.0.push_highlighted(ca1.to_string());
-                            values.1.push_highlighted(ca2.to_string());
+                            args.push((
+                                DiagnosticStyledString::normal(ca1.to_string()),
+                                DiagnosticStyledString::normal(ca2.to_string()),
+                                false,
+                            ));
                         }
-                        self.push_comma(&mut values.0, &mut values.1, len, i);
                     }
 
+                    // When this type is itself a generic argument, the arguments it has in common
+                    // at the start or at the end are collapsed, which keeps the differing ones
+                    // of deeply nested types readable.
+                    //     Foo<Bar<…, u32>>
+                    //     Foo<Bar<…, u64>>
+                    //             ^ the first arguments of `Bar`, which are the same on both sides
+                    self.push_generic_args(&mut values, args, elide && is_generic_arg);
+
                     // Close the type argument bracket.
                     // Only draw `<...>` if there're lifetime/type arguments.
                     if len > 0 {
@@ -1412,7 +1503,12 @@ fn lifetime_display(lifetime: Region<'_>) -> String {
             (ty::FnPtr(sig1), ty::FnPtr(sig2)) => self.cmp_fn_sig(sig1, sig2),
 
             _ => {
-                if t1 == t2 {
+                if t1 == t2 && !elide {
+                    (
+                        DiagnosticStyledString::normal(t1.to_string()),
+                        DiagnosticStyledString::normal(t2.to_string()),
+                    )
+                } else if t1 == t2 {
                     // The two types are the same, elide and don't highlight.
                     (DiagnosticStyledString::normal("_"), DiagnosticStyledString::normal("_"))
                 } else {
@@ -1426,6 +1522,18 @@ fn lifetime_display(lifetime: Region<'_>) -> String {
         }
     }
 
+    /// Shortens the printed type in `s` with `TyCtxt::short_string`, returning the file the full
+    /// type was written to. A shortened type loses its highlighting.
+    fn shorten_styled_string(
+        &self,
+        s: DiagnosticStyledString,
+    ) -> (DiagnosticStyledString, Option<PathBuf>) {
+        match self.tcx.short_string(s.content()) {
+            (short, Some(file)) => (DiagnosticStyledString::normal(short), Some(file)),
+            (_, None) => (s, None),
+        }
+    }
+
     /// Extend a type error with extra labels pointing at "non-trivial" types, like closures and
     /// the return type of `async fn`s.
     ///
@@ -1653,7 +1761,12 @@ enum Mismatch<'a> {
                 ),
                 Mismatch::Fixed(s) => (s.into(), s.into(), None),
             };
-            match (&terr, expected == found) {
+            // Compare before shortening: two long types can shorten to the same text.
+            let same_text = expected == found;
+            let (expected, expected_file) = self.shorten_styled_string(expected);
+            let (found, found_file) = self.shorten_styled_string(found);
+            let children = diag.children.len();
+            match (&terr, same_text) {
                 (TypeError::Sorts(values), extra) => {
                     let sort_string = |ty: Ty<'tcx>| match (extra, ty.kind()) {
                         (true, ty::Opaque(def_id, _)) => {
@@ -1695,7 +1808,7 @@ enum Mismatch<'a> {
                     }
                 }
                 (TypeError::ObjectUnsafeCoercion(_), _) => {
-                    diag.note_unsuccessful_coercion(found, expected);
+                    diag.note_unsuccessful_coercion(expected, found);
                 }
                 (_, _) => {
                     debug!(
@@ -1707,6 +1820,13 @@ enum Mismatch<'a> {
                     }
                 }
             }
+            // Only point to the files if the shortened types were shown.
+            if diag.children.len() > children {
+                diag.note_long_type(expected_file.as_deref());
+                if found_file != expected_file {
+                    diag.note_long_type(found_file.as_deref());
+                }
+            }
         }
         let exp_found = match exp_found {
             Mismatch::Variable(exp_found) => Some(exp_found),
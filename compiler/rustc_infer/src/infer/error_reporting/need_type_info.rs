@@ -1,5 +1,6 @@
 use crate::infer::type_variable::TypeVariableOriginKind;
 use crate::infer::{InferCtxt, Symbol};
+use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{pluralize, struct_span_err, Applicability, DiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_hir::def::{DefKind, Namespace};
@@ -834,9 +835,37 @@ pub fn emit_inference_failure_err(
             );
         }
 
+        if self.tcx.sess.opts.debugging_opts.track_unification_origins {
+            self.note_unification_origins(&mut err, arg);
+        }
+
         err
     }
 
+    /// Explains where the type variables in `arg` were unified with other
+    /// type variables, using the history recorded under
+    /// `-Z track-unification-origins`.
+    fn note_unification_origins(&self, err: &mut DiagnosticBuilder<'_>, arg: GenericArg<'tcx>) {
+        let mut seen = FxHashSet::default();
+        for arg in arg.walk() {
+            let vid = match arg.unpack() {
+                GenericArgKind::Type(ty) => match *ty.kind() {
+                    ty::Infer(ty::TyVar(vid)) => vid,
+                    _ => continue,
+                },
+                _ => continue,
+            };
+            let unifications = self.inner.borrow_mut().type_variables().unification_origins(vid);
+            for unification in unifications {
+                let origin = unification.origin;
+                if origin.span.is_dummy() || !seen.insert((origin.span, origin.kind)) {
+                    continue;
+                }
+                err.span_note(origin.span, &format!("type {}", origin.kind.descr()));
+            }
+        }
+    }
+
     fn trait_def_from_hir_fn(&self, hir_id: hir::HirId) -> Option<DefId> {
         // The DefId will be the method's trait item ID unless this is an inherent impl
         if let Some((DefKind::AssocFn, def_id)) =
@@ -2,15 +2,19 @@
 //! where both the regions are anonymous.
 
 use crate::infer::error_reporting::nice_region_error::find_anon_type::find_anon_type;
-use crate::infer::error_reporting::nice_region_error::util::AnonymousParamInfo;
+use crate::infer::error_reporting::nice_region_error::util::{
+    name_elided_lifetime, AnonymousParamInfo,
+};
 use crate::infer::error_reporting::nice_region_error::NiceRegionError;
 use crate::infer::lexical_region_resolve::RegionResolutionError;
 use crate::infer::SubregionOrigin;
 
 use rustc_errors::{struct_span_err, Applicability, DiagnosticBuilder, ErrorReported};
 use rustc_hir as hir;
+use rustc_hir::intravisit::{self, Visitor};
 use rustc_hir::{GenericParamKind, Ty};
-use rustc_middle::ty::Region;
+use rustc_middle::middle::resolve_lifetime as rl;
+use rustc_middle::ty::{Region, TyCtxt};
 
 impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
     /// Print the error message for lifetime errors when both the concerned regions are anonymous.
@@ -158,69 +162,99 @@ fn suggest_adding_lifetime_params(
         ty_sub: &Ty<'_>,
         err: &mut DiagnosticBuilder<'_>,
     ) {
-        if let (
-            hir::Ty { kind: hir::TyKind::Rptr(lifetime_sub, _), .. },
-            hir::Ty { kind: hir::TyKind::Rptr(lifetime_sup, _), .. },
-        ) = (ty_sub, ty_sup)
-        {
-            if lifetime_sub.name.is_elided() && lifetime_sup.name.is_elided() {
-                if let Some(anon_reg) = self.tcx().is_suitable_region(sub) {
-                    let hir_id = self.tcx().hir().local_def_id_to_hir_id(anon_reg.def_id);
-                    if let hir::Node::Item(&hir::Item {
-                        kind: hir::ItemKind::Fn(_, ref generics, ..),
-                        ..
-                    }) = self.tcx().hir().get(hir_id)
-                    {
-                        let (suggestion_param_name, introduce_new) = generics
-                            .params
-                            .iter()
-                            .find(|p| matches!(p.kind, GenericParamKind::Lifetime { .. }))
-                            .and_then(|p| self.tcx().sess.source_map().span_to_snippet(p.span).ok())
-                            .map(|name| (name, false))
-                            .unwrap_or_else(|| ("'a".to_string(), true));
-
-                        let mut suggestions = vec![
-                            if let hir::LifetimeName::Underscore = lifetime_sub.name {
-                                (lifetime_sub.span, suggestion_param_name.clone())
-                            } else {
-                                (
-                                    lifetime_sub.span.shrink_to_hi(),
-                                    suggestion_param_name.clone() + " ",
-                                )
-                            },
-                            if let hir::LifetimeName::Underscore = lifetime_sup.name {
-                                (lifetime_sup.span, suggestion_param_name.clone())
-                            } else {
-                                (
-                                    lifetime_sup.span.shrink_to_hi(),
-                                    suggestion_param_name.clone() + " ",
-                                )
-                            },
-                        ];
-
-                        if introduce_new {
-                            let new_param_suggestion = match &generics.params {
-                                [] => (generics.span, format!("<{}>", suggestion_param_name)),
-                                [first, ..] => (
-                                    first.span.shrink_to_lo(),
-                                    format!("{}, ", suggestion_param_name),
-                                ),
-                            };
-
-                            suggestions.push(new_param_suggestion);
-                        }
-
-                        err.multipart_suggestion(
-                            "consider introducing a named lifetime parameter",
-                            suggestions,
-                            Applicability::MaybeIncorrect,
-                        );
-                        err.note(
-                            "each elided lifetime in input position becomes a distinct lifetime",
-                        );
-                    }
-                }
+        let (lifetime_sub, lifetime_sup) = match (ty_sub, ty_sup) {
+            (
+                hir::Ty { kind: hir::TyKind::Rptr(lifetime_sub, _), .. },
+                hir::Ty { kind: hir::TyKind::Rptr(lifetime_sup, _), .. },
+            ) => (lifetime_sub, lifetime_sup),
+            _ => return,
+        };
+        if !lifetime_sub.name.is_elided() || !lifetime_sup.name.is_elided() {
+            return;
+        }
+        let Some(anon_reg) = self.tcx().is_suitable_region(sub) else {
+            return;
+        };
+
+        let hir_id = self.tcx().hir().local_def_id_to_hir_id(anon_reg.def_id);
+        let (fn_sig, generics) = match self.tcx().hir().get(hir_id) {
+            hir::Node::Item(&hir::Item {
+                kind: hir::ItemKind::Fn(ref fn_sig, ref generics, ..),
+                ..
+            }) => (fn_sig, generics),
+            // The signature of a method implementing a trait method has to match the trait, and
+            // the elided lifetimes in the return type of an `async` method are those of its future.
+            hir::Node::ImplItem(&hir::ImplItem {
+                kind: hir::ImplItemKind::Fn(ref fn_sig, _),
+                ref generics,
+                ..
+            }) if !anon_reg.is_impl_item && !fn_sig.header.is_async() => (fn_sig, generics),
+            _ => return,
+        };
+
+        let (suggestion_param_name, introduce_new) = generics
+            .params
+            .iter()
+            .find(|p| matches!(p.kind, GenericParamKind::Lifetime { .. }))
+            .and_then(|p| self.tcx().sess.source_map().span_to_snippet(p.span).ok())
+            .map(|name| (name, false))
+            .unwrap_or_else(|| (self.fresh_lifetime_name(anon_reg.def_id.to_def_id()), true));
+
+        // The references of the return type that have the lifetime of one of both parameters
+        // are given the new name as well, so that the signature stays consistent.
+        let mut lifetimes = vec![lifetime_sub, lifetime_sup];
+        if let hir::FnRetTy::Return(output) = fn_sig.decl.output {
+            let regions = [lifetime_sub, lifetime_sup].map(|l| self.tcx().named_region(l.hir_id));
+            let mut visitor = ElidedReferenceVisitor { tcx: self.tcx(), regions, found: vec![] };
+            visitor.visit_ty(output);
+            lifetimes.extend(visitor.found);
+        }
+
+        let mut suggestions: Vec<_> = lifetimes
+            .into_iter()
+            .map(|lifetime| name_elided_lifetime(lifetime, &suggestion_param_name))
+            .collect();
+
+        if introduce_new {
+            let new_param_suggestion = match &generics.params {
+                [] => (generics.span, format!("<{}>", suggestion_param_name)),
+                [first, ..] => (first.span.shrink_to_lo(), format!("{}, ", suggestion_param_name)),
+            };
+
+            suggestions.push(new_param_suggestion);
+        }
+
+        // Reusing a lifetime that is already declared may be wrong, as it can have other
+        // constraints, but introducing a new one only relates both parameters.
+        let applicability = if introduce_new {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        err.multipart_suggestion(
+            "consider introducing a named lifetime parameter",
+            suggestions,
+            applicability,
+        );
+        err.note("each elided lifetime in input position becomes a distinct lifetime");
+    }
+}
+
+/// Collects the elided lifetimes of the references of a type which resolve to one of `regions`.
+struct ElidedReferenceVisitor<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    regions: [Option<rl::Region>; 2],
+    found: Vec<&'tcx hir::Lifetime>,
+}
+
+impl<'tcx> Visitor<'tcx> for ElidedReferenceVisitor<'tcx> {
+    fn visit_ty(&mut self, ty: &'tcx hir::Ty<'tcx>) {
+        if let hir::TyKind::Rptr(ref lifetime, _) = ty.kind {
+            let region = self.tcx.named_region(lifetime.hir_id);
+            if lifetime.name.is_elided() && region.is_some() && self.regions.contains(&region) {
+                self.found.push(lifetime);
             }
         }
+        intravisit::walk_ty(self, ty);
     }
 }
@@ -1,9 +1,12 @@
 //! Error Reporting for Anonymous Region Lifetime Errors
 //! where one region is named and the other is anonymous.
 use crate::infer::error_reporting::nice_region_error::find_anon_type::find_anon_type;
+use crate::infer::error_reporting::nice_region_error::util::name_elided_lifetime;
 use crate::infer::error_reporting::nice_region_error::NiceRegionError;
 use rustc_errors::{struct_span_err, Applicability, DiagnosticBuilder};
+use rustc_hir as hir;
 use rustc_middle::ty;
+use rustc_span::Pos;
 
 impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
     /// When given a `ConcreteFailure` for a function with parameters containing a named region and
@@ -78,9 +81,8 @@ pub(super) fn try_report_named_anon_conflict(&self) -> Option<DiagnosticBuilder<
             return None;
         }
 
-        if find_anon_type(self.tcx(), anon, &br).is_some()
-            && self.is_self_anon(is_first, scope_def_id)
-        {
+        let anon_ty = find_anon_type(self.tcx(), anon, &br);
+        if anon_ty.is_some() && self.is_self_anon(is_first, scope_def_id) {
             return None;
         }
 
@@ -100,12 +102,35 @@ pub(super) fn try_report_named_anon_conflict(&self) -> Option<DiagnosticBuilder<
             error_var
         );
 
+        // When the anonymous region is the elided lifetime of a reference, only that lifetime is
+        // rewritten, and the rest of the type is kept as written.
+        let sm = self.tcx().sess.source_map();
+        let written_ty = match anon_ty {
+            Some((&hir::Ty { kind: hir::TyKind::Rptr(ref lifetime, _), .. }, _))
+                if lifetime.is_elided()
+                    && !new_ty_span.from_expansion()
+                    && new_ty_span.contains(lifetime.span) =>
+            {
+                sm.span_to_snippet(new_ty_span).ok().map(|snippet| {
+                    let (span, name) = name_elided_lifetime(lifetime, &named.to_string());
+                    let start = (span.lo() - new_ty_span.lo()).to_usize();
+                    let end = (span.hi() - new_ty_span.lo()).to_usize();
+                    format!("{}{}{}", &snippet[..start], name, &snippet[end..])
+                })
+            }
+            _ => None,
+        };
+        let (suggestion, applicability) = match written_ty {
+            Some(ty) => (ty, Applicability::MachineApplicable),
+            None => (new_ty.to_string(), Applicability::Unspecified),
+        };
+
         diag.span_label(span, format!("lifetime `{}` required", named));
         diag.span_suggestion(
             new_ty_span,
             &format!("add explicit lifetime `{}` to {}", named, span_label_var),
-            new_ty.to_string(),
-            Applicability::Unspecified,
+            suggestion,
+            applicability,
         );
 
         Some(diag)
@@ -13,6 +13,7 @@
     self, AssocItemContainer, RegionKind, StaticLifetimeVisitor, Ty, TyCtxt, TypeFoldable,
     TypeVisitor,
 };
+use rustc_session::DiagnosticMessageId;
 use rustc_span::symbol::Ident;
 use rustc_span::{MultiSpan, Span};
 
@@ -417,6 +418,87 @@ pub fn suggest_new_region_bound(
     }
 }
 
+/// When an `impl Trait` that already has a lifetime bound captures another lifetime, adding
+/// that lifetime as a second bound requires the hidden type to outlive both of them, which it
+/// often doesn't. Suggest mentioning the lifetime through a `Captures` helper trait instead,
+/// along with a definition of that trait, unless the module already has a `Captures` item or
+/// the definition was already suggested in this crate: defining it twice would conflict.
+pub fn suggest_captures_bound(
+    tcx: TyCtxt<'_>,
+    err: &mut DiagnosticBuilder<'_>,
+    fn_returns: Vec<&rustc_hir::Ty<'_>>,
+    lifetime_name: String,
+) {
+    for fn_return in fn_returns {
+        if fn_return.span.desugaring_kind().is_some() {
+            continue;
+        }
+        let TyKind::OpaqueDef(item_id, _) = fn_return.kind else { continue };
+        let ItemKind::OpaqueTy(opaque) = &tcx.hir().item(item_id).kind else { continue };
+        let has_other_lifetime_bound = opaque.bounds.iter().any(|arg| match arg {
+            GenericBound::Outlives(Lifetime { name, .. }) => {
+                *name != LifetimeName::Static && name.ident().to_string() != lifetime_name
+            }
+            _ => false,
+        });
+        if !has_other_lifetime_bound {
+            continue;
+        }
+        let msg = format!(
+            "alternatively, if the hidden type does not outlive `{}`, you can capture it through a \
+             helper trait",
+            lifetime_name,
+        );
+        let bound = (fn_return.span.shrink_to_hi(), format!(" + Captures<{}>", lifetime_name));
+
+        let (module, _, _) = tcx.hir().get_module(tcx.parent_module(fn_return.hir_id));
+        let captures_in_scope = module.item_ids.iter().any(|&id| {
+            let item = tcx.hir().item(id);
+            item.ident.name.as_str() == "Captures"
+                && matches!(
+                    item.kind,
+                    ItemKind::Trait(..) | ItemKind::TraitAlias(..) | ItemKind::Use(..)
+                )
+        });
+        // Traits can't be defined in `impl` blocks, so the helper trait is defined right
+        // before the outermost item containing the return type.
+        let mut item_span = None;
+        for (_, node) in tcx.hir().parent_iter(fn_return.hir_id) {
+            match node {
+                Node::Item(Item { kind: ItemKind::Mod(_), .. }) | Node::Crate(_) => break,
+                Node::Item(item) => item_span = Some(item.span),
+                _ => {}
+            }
+        }
+        let define = match item_span {
+            Some(item_span) if !captures_in_scope => {
+                let id = (DiagnosticMessageId::ErrorId(700), None, "trait Captures".to_string());
+                tcx.sess.one_time_diagnostics.borrow_mut().insert(id).then_some(item_span)
+            }
+            _ => None,
+        };
+        match define {
+            Some(item_span) => {
+                let indentation =
+                    tcx.sess.source_map().indentation_before(item_span).unwrap_or_default();
+                let definition = format!(
+                    "trait Captures<'a> {{}}\n{0}\
+                     impl<'a, T: ?Sized> Captures<'a> for T {{}}\n\n{0}",
+                    indentation,
+                );
+                err.multipart_suggestion_verbose(
+                    &msg,
+                    vec![(item_span.shrink_to_lo(), definition), bound],
+                    Applicability::MaybeIncorrect,
+                );
+            }
+            None => {
+                err.span_suggestion_verbose(bound.0, &msg, bound.1, Applicability::MaybeIncorrect);
+            }
+        }
+    }
+}
+
 impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
     fn get_impl_ident_and_self_ty_from_trait(
         &self,
@@ -2,9 +2,11 @@
 //! anonymous regions.
 
 use crate::infer::error_reporting::nice_region_error::NiceRegionError;
+use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
-use rustc_hir::def_id::LocalDefId;
+use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_middle::ty::{self, Binder, DefIdTree, Region, Ty, TypeFoldable};
+use rustc_span::symbol::Symbol;
 use rustc_span::Span;
 
 /// Information about the anonymous region we are searching for.
@@ -22,6 +24,15 @@ pub(super) struct AnonymousParamInfo<'tcx> {
     pub is_first: bool,
 }
 
+/// Returns the edit that gives the elided lifetime of a reference the name `name`: `'_` is
+/// replaced with it, and it is inserted after a bare `&`.
+pub(super) fn name_elided_lifetime(lifetime: &hir::Lifetime, name: &str) -> (Span, String) {
+    match lifetime.name {
+        hir::LifetimeName::Underscore => (lifetime.span, name.to_string()),
+        _ => (lifetime.span.shrink_to_hi(), format!("{} ", name)),
+    }
+}
+
 impl<'a, 'tcx> NiceRegionError<'a, 'tcx> {
     // This method walks the Type of the function body parameters using
     // `fold_regions()` function and returns the
@@ -142,4 +153,19 @@ pub(super) fn is_self_anon(&self, is_first: bool, scope_def_id: LocalDefId) -> b
                 .map(|i| i.fn_has_self_parameter)
                 == Some(true)
     }
+
+    /// Returns a lifetime name that isn't used by the generics of `def_id`, nor by the generics
+    /// of its parents, so that it can be introduced without shadowing another lifetime.
+    pub(super) fn fresh_lifetime_name(&self, def_id: DefId) -> String {
+        let mut used_names = FxHashSet::default();
+        let mut generics = Some(self.tcx().generics_of(def_id));
+        while let Some(g) = generics {
+            used_names.extend(g.params.iter().map(|param| param.name));
+            generics = g.parent.map(|parent| self.tcx().generics_of(parent));
+        }
+        ('a'..='z')
+            .map(|c| format!("'{}", c))
+            .find(|name| !used_names.contains(&Symbol::intern(name)))
+            .unwrap_or_else(|| "'a".to_string())
+    }
 }
@@ -55,6 +55,62 @@ pub fn higher_ranked_sub<T>(
             Ok(ty::Binder::dummy(result))
         })
     }
+
+    /// Computes the LUB (if `is_lub`) or GLB of two higher-ranked types in
+    /// the case where one of them is a subtype of the other. For example,
+    /// the LUB of `for<'a> fn(&'a u8)` and `fn(&'static u8)` is
+    /// `fn(&'static u8)`, and their GLB is `for<'a> fn(&'a u8)`.
+    ///
+    /// Returns `None` if neither type is a subtype of the other, in which
+    /// case no constraints are registered and the caller has to fall back
+    /// to relating the two types in some other way.
+    #[instrument(skip(self), level = "debug")]
+    pub fn higher_ranked_lattice_bound<T>(
+        &mut self,
+        a: Binder<'tcx, T>,
+        b: Binder<'tcx, T>,
+        a_is_expected: bool,
+        is_lub: bool,
+    ) -> Option<Binder<'tcx, T>>
+    where
+        T: Relate<'tcx>,
+    {
+        if self.higher_ranked_sub_if_ok(a, b, a_is_expected) {
+            Some(if is_lub { b } else { a })
+        } else if self.higher_ranked_sub_if_ok(b, a, !a_is_expected) {
+            Some(if is_lub { a } else { b })
+        } else {
+            None
+        }
+    }
+
+    /// Checks whether `a <: b` holds, keeping the resulting constraints
+    /// and obligations only if it does.
+    ///
+    /// Unlike `higher_ranked_sub`, this runs the leak check, as otherwise
+    /// an error like `'!a: 'static` would only be detected during region
+    /// checking, after we've already committed to `a <: b`.
+    fn higher_ranked_sub_if_ok<T>(
+        &mut self,
+        a: Binder<'tcx, T>,
+        b: Binder<'tcx, T>,
+        a_is_expected: bool,
+    ) -> bool
+    where
+        T: Relate<'tcx>,
+    {
+        let obligations_len = self.obligations.len();
+        let result = self.infcx.commit_if_ok(|snapshot| {
+            self.higher_ranked_sub(a, b, a_is_expected)?;
+            self.infcx.leak_check(false, snapshot)
+        });
+        debug!("higher_ranked_sub_if_ok: result={:?}", result);
+
+        if result.is_err() {
+            self.obligations.truncate(obligations_len);
+        }
+        result.is_ok()
+    }
 }
 
 impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
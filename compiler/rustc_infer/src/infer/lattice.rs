@@ -19,13 +19,15 @@
 //! over a `LatticeValue`, which is a value defined with respect to
 //! a lattice.
 
-use super::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
+use super::type_variable::{TypeVariableOrigin, TypeVariableOriginKind, UnificationOriginKind};
 use super::InferCtxt;
 
+use crate::traits::util::supertraits;
 use crate::traits::{ObligationCause, PredicateObligation};
+use rustc_hir as hir;
 use rustc_middle::ty::relate::{RelateResult, TypeRelation};
 use rustc_middle::ty::TyVar;
-use rustc_middle::ty::{self, Ty};
+use rustc_middle::ty::{self, Ty, TypeFoldable};
 
 pub trait LatticeDir<'f, 'tcx>: TypeRelation<'tcx> {
     fn infcx(&self) -> &'f InferCtxt<'f, 'tcx>;
@@ -43,6 +45,18 @@ pub trait LatticeDir<'f, 'tcx>: TypeRelation<'tcx> {
     // relates `v` to `a` first, which may help us to avoid unnecessary
     // type variable obligations. See caller for details.
     fn relate_bound(&mut self, v: Ty<'tcx>, a: Ty<'tcx>, b: Ty<'tcx>) -> RelateResult<'tcx, ()>;
+
+    // Relates the type `v` to `a` such that `v` is an upper bound (for
+    // LUB) or a lower bound (for GLB) of `a`. Used to constrain the
+    // hidden type of an opaque type, see `super_lattice_tys`.
+    fn relate_single_bound(&mut self, v: Ty<'tcx>, a: Ty<'tcx>) -> RelateResult<'tcx, ()>;
+
+    // Given two values where `sub` can be coerced to (but is not a subtype of)
+    // `sup`, e.g. a trait object that can be upcast to a supertrait object,
+    // returns the one that represents their LUB (`sup`). There is no such
+    // GLB: it would have to be a subtype of both sides, so for a GLB this
+    // returns `None` and the two sides are related structurally instead.
+    fn coercion_bound<T>(&self, sub: T, sup: T) -> Option<T>;
 }
 
 #[instrument(skip(this), level = "debug")]
@@ -84,39 +98,202 @@ pub fn super_lattice_tys<'a, 'tcx: 'a, L>(
         // is (e.g.) `Box<i32>`. A more obvious solution might be to
         // iterate on the subtype obligations that are returned, but I
         // think this suffices. -nmatsakis
-        (&ty::Infer(TyVar(..)), _) => {
-            let v = infcx.next_ty_var(TypeVariableOrigin {
+        (&ty::Infer(TyVar(a_vid)), _) => {
+            let v_vid = infcx.next_ty_var_id(TypeVariableOrigin {
                 kind: TypeVariableOriginKind::LatticeVariable,
                 span: this.cause().span,
             });
+            infcx.record_unification_origin(
+                a_vid,
+                v_vid,
+                UnificationOriginKind::LatticeVariable,
+                this.cause(),
+            );
+            let v = infcx.tcx.mk_ty_var(v_vid);
             this.relate_bound(v, b, a)?;
             Ok(v)
         }
-        (_, &ty::Infer(TyVar(..))) => {
-            let v = infcx.next_ty_var(TypeVariableOrigin {
+        (_, &ty::Infer(TyVar(b_vid))) => {
+            let v_vid = infcx.next_ty_var_id(TypeVariableOrigin {
                 kind: TypeVariableOriginKind::LatticeVariable,
                 span: this.cause().span,
             });
+            infcx.record_unification_origin(
+                b_vid,
+                v_vid,
+                UnificationOriginKind::LatticeVariable,
+                this.cause(),
+            );
+            let v = infcx.tcx.mk_ty_var(v_vid);
             this.relate_bound(v, a, b)?;
             Ok(v)
         }
 
+        // If one side is a projection which has not been normalized, e.g.
+        // `<T as Trait>::Assoc`, relating it structurally to the other
+        // side would fail even though it may normalize to the same type.
+        // Instead, lazily normalize it: replace the projection with a fresh
+        // variable which is constrained by a projection obligation, and
+        // compute the LUB/GLB using that variable. The obligation is then
+        // handled by the usual (query-based) projection machinery once it is
+        // processed by the fulfillment context.
+        (&ty::Projection(projection_ty), _) if !a.has_escaping_bound_vars() => {
+            let a = lazily_normalize_projection(this, projection_ty);
+            this.relate(a, b)
+        }
+        (_, &ty::Projection(projection_ty)) if !b.has_escaping_bound_vars() => {
+            let b = lazily_normalize_projection(this, projection_ty);
+            this.relate(a, b)
+        }
+
         (&ty::Opaque(a_def_id, _), &ty::Opaque(b_def_id, _)) if a_def_id == b_def_id => {
             infcx.super_combine_tys(this, a, b)
         }
+        // If one side is an opaque type we may define, the LUB/GLB is that
+        // opaque type, as long as its hidden type is an upper bound (for LUB)
+        // or lower bound (for GLB) of the other side. We generalize the other
+        // side into a fresh variable which we relate in the appropriate
+        // direction, and use that variable as the hidden type.
         (&ty::Opaque(did, ..), _) | (_, &ty::Opaque(did, ..))
             if this.define_opaque_types() && did.is_local() =>
         {
+            let (opaque, other) = if let ty::Opaque(..) = a.kind() { (a, b) } else { (b, a) };
+            let hidden = infcx.next_ty_var_in_universe(
+                TypeVariableOrigin {
+                    kind: TypeVariableOriginKind::LatticeVariable,
+                    span: this.cause().span,
+                },
+                ty::UniverseIndex::ROOT,
+            );
+            this.relate_single_bound(hidden, other)?;
             this.add_obligations(vec![infcx.opaque_ty_obligation(
-                a,
-                b,
-                this.a_is_expected(),
+                opaque,
+                hidden,
+                true,
                 this.param_env(),
                 this.cause().clone(),
             )]);
-            Ok(a)
+            Ok(opaque)
+        }
+
+        // With `#![feature(trait_upcasting)]`, a trait object can be coerced to
+        // a trait object of one of its supertraits. If the principal trait of
+        // one side is a supertrait of the other's, the LUB is the supertrait
+        // object. Note that this is *not*
+        // a subtyping relationship: callers must apply an upcasting coercion
+        // to get from one side to the other, see `try_find_coercion_lub`.
+        (&ty::Dynamic(a_data, _), &ty::Dynamic(b_data, _))
+            if infcx.tcx.features().trait_upcasting
+                && a_data.principal_def_id() != b_data.principal_def_id() =>
+        {
+            if let Some(ty) = lattice_trait_objects(this, a, b)? {
+                Ok(ty)
+            } else {
+                infcx.super_combine_tys(this, a, b)
+            }
+        }
+
+        // A safe function pointer can be coerced to an unsafe one, so the LUB
+        // of `fn()` and `unsafe fn()` is `unsafe fn()`. As with trait objects
+        // above, this is not a subtyping relationship, and callers must apply
+        // the coercion, see `try_find_coercion_lub`.
+        (&ty::FnPtr(a_sig), &ty::FnPtr(b_sig)) if a_sig.unsafety() != b_sig.unsafety() => {
+            match this.coercion_bound(hir::Unsafety::Normal, hir::Unsafety::Unsafe) {
+                Some(unsafety) => {
+                    let with_unsafety = |sig: ty::PolyFnSig<'tcx>| {
+                        infcx.tcx.mk_fn_ptr(sig.map_bound(|sig| ty::FnSig { unsafety, ..sig }))
+                    };
+                    this.relate(with_unsafety(a_sig), with_unsafety(b_sig))
+                }
+                None => infcx.super_combine_tys(this, a, b),
+            }
         }
 
         _ => infcx.super_combine_tys(this, a, b),
     }
 }
+
+/// Computes the LUB of two trait objects with different principal traits,
+/// if one of them can be upcast to the other. Returns `None` if neither can
+/// be upcast to the other, or when computing a GLB (see `coercion_bound`).
+fn lattice_trait_objects<'a, 'tcx: 'a, L>(
+    this: &mut L,
+    a: Ty<'tcx>,
+    b: Ty<'tcx>,
+) -> RelateResult<'tcx, Option<Ty<'tcx>>>
+where
+    L: LatticeDir<'a, 'tcx>,
+{
+    let tcx = this.tcx();
+    let (a_data, a_region, b_data, b_region) = match (a.kind(), b.kind()) {
+        (&ty::Dynamic(a_data, a_region), &ty::Dynamic(b_data, b_region)) => {
+            (a_data, a_region, b_data, b_region)
+        }
+        _ => bug!("lattice_trait_objects: expected two trait objects, found {:?} and {:?}", a, b),
+    };
+
+    // Finds the supertrait of `sub`'s principal trait that corresponds to the
+    // principal trait of `sup`, provided that `sup` does not require anything
+    // else that `sub` cannot provide.
+    let upcast =
+        |sub: &'tcx ty::List<ty::Binder<'tcx, ty::ExistentialPredicate<'tcx>>>,
+         sup: &'tcx ty::List<ty::Binder<'tcx, ty::ExistentialPredicate<'tcx>>>| {
+            let sub_principal = sub.principal()?;
+            let sup_principal = sup.principal()?;
+            if sup.projection_bounds().next().is_some()
+                || !sup.auto_traits().all(|did| sub.auto_traits().any(|sub_did| sub_did == did))
+            {
+                return None;
+            }
+            let self_ty = tcx.types.trait_object_dummy_self;
+            supertraits(tcx, sub_principal.with_self_ty(tcx, self_ty))
+                .find(|supertrait| supertrait.def_id() == sup_principal.def_id())
+                .map(|supertrait| {
+                    let upcast = supertrait.map_bound(|trait_ref| {
+                        ty::ExistentialTraitRef::erase_self_ty(tcx, trait_ref)
+                    });
+                    (upcast, sup_principal)
+                })
+        };
+
+    let (sub, sup, upcast, sup_principal) =
+        if let Some((upcast, sup_principal)) = upcast(a_data, b_data) {
+            (a_data, b_data, upcast, sup_principal)
+        } else if let Some((upcast, sup_principal)) = upcast(b_data, a_data) {
+            (b_data, a_data, upcast, sup_principal)
+        } else {
+            return Ok(None);
+        };
+    debug!("lattice_trait_objects: {:?} can be upcast to {:?}", sub, sup);
+    let Some(bound) = this.coercion_bound(sub, sup) else {
+        return Ok(None);
+    };
+
+    // The generic arguments of the supertrait must agree.
+    this.relate(upcast, sup_principal)?;
+
+    let region = this.regions(a_region, b_region)?;
+    Ok(Some(tcx.mk_dynamic(bound, region)))
+}
+
+/// Replaces `projection_ty` with a fresh type variable, registering an
+/// obligation that the variable is equal to the normalized projection.
+fn lazily_normalize_projection<'a, 'tcx: 'a, L>(
+    this: &mut L,
+    projection_ty: ty::ProjectionTy<'tcx>,
+) -> Ty<'tcx>
+where
+    L: LatticeDir<'a, 'tcx>,
+{
+    let mut obligations = vec![];
+    let ty = this.infcx().infer_projection(
+        this.param_env(),
+        projection_ty,
+        this.cause().clone(),
+        0,
+        &mut obligations,
+    );
+    debug!("lazily_normalize_projection: {:?} -> {:?}", projection_ty, ty);
+    this.add_obligations(obligations);
+    ty
+}
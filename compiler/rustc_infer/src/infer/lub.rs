@@ -6,7 +6,7 @@
 use crate::infer::combine::ConstEquateRelation;
 use crate::traits::{ObligationCause, PredicateObligation};
 use rustc_middle::ty::relate::{Relate, RelateResult, TypeRelation};
-use rustc_middle::ty::{self, Ty, TyCtxt};
+use rustc_middle::ty::{self, Ty, TyCtxt, TypeFoldable};
 
 /// "Least upper bound" (common supertype)
 pub struct Lub<'combine, 'infcx, 'tcx> {
@@ -95,8 +95,18 @@ fn binders<T>(
         debug!("binders(a={:?}, b={:?})", a, b);
 
         // When higher-ranked types are involved, computing the LUB is
-        // very challenging, switch to invariance. This is obviously
-        // overly conservative but works ok in practice.
+        // very challenging. We handle the common case where one side is
+        // a subtype of the other (e.g. `for<'a> fn(&'a u8)` and
+        // `fn(&'static u8)`); otherwise, switch to invariance. This is
+        // obviously overly conservative but works ok in practice.
+        if a.skip_binder().has_escaping_bound_vars() || b.skip_binder().has_escaping_bound_vars() {
+            if let Some(bound) =
+                self.fields.higher_ranked_lattice_bound(a, b, self.a_is_expected, true)
+            {
+                return Ok(bound);
+            }
+        }
+
         self.relate_with_variance(ty::Variance::Invariant, ty::VarianceDiagInfo::default(), a, b)?;
         Ok(a)
     }
@@ -128,6 +138,15 @@ fn relate_bound(&mut self, v: Ty<'tcx>, a: Ty<'tcx>, b: Ty<'tcx>) -> RelateResul
         Ok(())
     }
 
+    fn relate_single_bound(&mut self, v: Ty<'tcx>, a: Ty<'tcx>) -> RelateResult<'tcx, ()> {
+        self.fields.sub(self.a_is_expected).relate(a, v)?;
+        Ok(())
+    }
+
+    fn coercion_bound<T>(&self, _sub: T, sup: T) -> Option<T> {
+        Some(sup)
+    }
+
     fn define_opaque_types(&self) -> bool {
         self.fields.define_opaque_types
     }
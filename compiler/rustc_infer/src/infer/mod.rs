@@ -21,7 +21,7 @@
 use rustc_middle::infer::unify_key::{ConstVarValue, ConstVariableValue};
 use rustc_middle::infer::unify_key::{ConstVariableOrigin, ConstVariableOriginKind, ToType};
 use rustc_middle::mir::interpret::ErrorHandled;
-use rustc_middle::mir::interpret::EvalToConstValueResult;
+use rustc_middle::mir::interpret::EvalToValTreeResult;
 use rustc_middle::traits::select;
 use rustc_middle::ty::error::{ExpectedFound, TypeError};
 use rustc_middle::ty::fold::{TypeFoldable, TypeFolder};
@@ -47,6 +47,7 @@
     RegionConstraintCollector, RegionConstraintStorage, RegionSnapshot,
 };
 use self::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
+use self::type_variable::{UnificationOrigin, UnificationOriginKind};
 
 pub mod at;
 pub mod canonical;
@@ -1017,7 +1018,12 @@ pub fn coerce_predicate(
             a: p.a,
             b: p.b,
         });
-        self.subtype_predicate(cause, param_env, subtype_predicate)
+        self.relate_subtype_predicate(
+            cause,
+            param_env,
+            subtype_predicate,
+            UnificationOriginKind::Coercion,
+        )
     }
 
     pub fn subtype_predicate(
@@ -1025,6 +1031,16 @@ pub fn subtype_predicate(
         cause: &ObligationCause<'tcx>,
         param_env: ty::ParamEnv<'tcx>,
         predicate: ty::PolySubtypePredicate<'tcx>,
+    ) -> Option<InferResult<'tcx, ()>> {
+        self.relate_subtype_predicate(cause, param_env, predicate, UnificationOriginKind::Subtype)
+    }
+
+    fn relate_subtype_predicate(
+        &self,
+        cause: &ObligationCause<'tcx>,
+        param_env: ty::ParamEnv<'tcx>,
+        predicate: ty::PolySubtypePredicate<'tcx>,
+        origin_kind: UnificationOriginKind,
     ) -> Option<InferResult<'tcx, ()>> {
         // Check for two unresolved inference variables, in which case we can
         // make no progress. This is partly a micro-optimization, but it's
@@ -1044,6 +1060,7 @@ pub fn subtype_predicate(
         match (r_a.kind(), r_b.kind()) {
             (&ty::Infer(ty::TyVar(a_vid)), &ty::Infer(ty::TyVar(b_vid))) => {
                 self.inner.borrow_mut().type_variables().sub(a_vid, b_vid);
+                self.record_unification_origin(a_vid, b_vid, origin_kind, cause);
                 return None;
             }
             _ => {}
@@ -1080,6 +1097,27 @@ pub fn num_ty_vars(&self) -> usize {
         self.inner.borrow_mut().type_variables().num_vars()
     }
 
+    /// Records why `a` and `b` were unified, if `-Z track-unification-origins`
+    /// is enabled. Unifications performed while confirming a method call are
+    /// attributed to that method call, regardless of `kind`.
+    pub(crate) fn record_unification_origin(
+        &self,
+        a: TyVid,
+        b: TyVid,
+        kind: UnificationOriginKind,
+        cause: &ObligationCause<'tcx>,
+    ) {
+        if !self.tcx.sess.opts.debugging_opts.track_unification_origins {
+            return;
+        }
+        let kind = match cause.code() {
+            traits::ObligationCauseCode::UnifyReceiver(..) => UnificationOriginKind::MethodCall,
+            _ => kind,
+        };
+        let origin = UnificationOrigin { kind, span: cause.span };
+        self.inner.borrow_mut().type_variables().record_unification_origin(a, b, origin);
+    }
+
     pub fn next_ty_var_id(&self, origin: TypeVariableOrigin) -> TyVid {
         self.inner.borrow_mut().type_variables().new_var(self.universe(), origin)
     }
@@ -1617,6 +1655,8 @@ pub fn create_next_universe(&self) -> ty::UniverseIndex {
     /// too generic for the constant to be evaluated then `Err(ErrorHandled::TooGeneric)` is
     /// returned.
     ///
+    /// The value is returned as a valtree, or `Ok(None)` if it can't be represented as one.
+    ///
     /// This handles inferences variables within both `param_env` and `substs` by
     /// performing the operation on their respective canonical forms.
     pub fn const_eval_resolve(
@@ -1624,7 +1664,7 @@ pub fn const_eval_resolve(
         param_env: ty::ParamEnv<'tcx>,
         unevaluated: ty::Unevaluated<'tcx>,
         span: Option<Span>,
-    ) -> EvalToConstValueResult<'tcx> {
+    ) -> EvalToValTreeResult<'tcx> {
         let substs = self.resolve_vars_if_possible(unevaluated.substs);
 
         // Postpone the evaluation of constants whose substs depend on inference
@@ -1644,7 +1684,7 @@ pub fn const_eval_resolve(
 
         // The return value is the evaluated value which doesn't contain any reference to inference
         // variables, thus we don't need to substitute back the original values.
-        self.tcx.const_eval_resolve(param_env_erased, unevaluated, span)
+        self.tcx.const_eval_resolve_for_typeck(param_env_erased, unevaluated, span)
     }
 
     /// If `typ` is a type variable of some kind, resolve it one level
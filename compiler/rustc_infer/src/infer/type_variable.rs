@@ -18,6 +18,7 @@ pub(crate) enum UndoLog<'tcx> {
     EqRelation(sv::UndoLog<ut::Delegate<TyVidEqKey<'tcx>>>),
     SubRelation(sv::UndoLog<ut::Delegate<ty::TyVid>>),
     Values(sv::UndoLog<Delegate>),
+    PushUnificationOrigin,
 }
 
 /// Convert from a specific kind of undo to the more general UndoLog
@@ -54,6 +55,9 @@ fn reverse(&mut self, undo: UndoLog<'tcx>) {
             UndoLog::EqRelation(undo) => self.eq_relations.reverse(undo),
             UndoLog::SubRelation(undo) => self.sub_relations.reverse(undo),
             UndoLog::Values(undo) => self.values.reverse(undo),
+            UndoLog::PushUnificationOrigin => {
+                self.unification_origins.pop();
+            }
         }
     }
 }
@@ -100,6 +104,12 @@ pub struct TypeVariableStorage<'tcx> {
     /// done to aid diagnostics, as it allows us to be more effective when
     /// we guide the user towards where they should insert type hints.
     sub_relations: ut::UnificationTableStorage<ty::TyVid>,
+
+    /// Every variable-variable unification performed so far, along with
+    /// where and why it happened. Only populated when
+    /// `-Z track-unification-origins` is enabled; used to explain to the
+    /// user how an unresolved variable ended up tied to other expressions.
+    unification_origins: Vec<TypeVariableUnification>,
 }
 
 pub struct TypeVariableTable<'a, 'tcx> {
@@ -137,6 +147,56 @@ pub enum TypeVariableOriginKind {
     LatticeVariable,
 }
 
+/// A record of two type variables being unified with one another.
+#[derive(Copy, Clone, Debug)]
+pub struct TypeVariableUnification {
+    pub a: TyVid,
+    pub b: TyVid,
+    pub origin: UnificationOrigin,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct UnificationOrigin {
+    pub kind: UnificationOriginKind,
+    pub span: Span,
+}
+
+/// Reasons for two type inference variables to be unified
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnificationOriginKind {
+    /// The variables were required to be equal.
+    Equate,
+    /// One variable was required to be a subtype of (or coercible to) the other.
+    Subtype,
+    /// A fresh variable was created while generalizing a type that
+    /// mentions the other variable.
+    Generalization,
+    /// One variable was coerced to the other.
+    Coercion,
+    /// The variables were unified while matching a method's `self` type
+    /// against the type of its receiver.
+    MethodCall,
+    /// A fresh variable was created to represent the least upper bound or
+    /// greatest lower bound of the other variable and some type, e.g. for
+    /// the arms of a `match`.
+    LatticeVariable,
+}
+
+impl UnificationOriginKind {
+    pub fn descr(self) -> &'static str {
+        match self {
+            UnificationOriginKind::Equate => "required to be the same as another type here",
+            UnificationOriginKind::Subtype => "related to another type through subtyping here",
+            UnificationOriginKind::Generalization => "used in another inferred type here",
+            UnificationOriginKind::Coercion => "coerced to another type here",
+            UnificationOriginKind::MethodCall => "used as the receiver of a method call here",
+            UnificationOriginKind::LatticeVariable => {
+                "combined with another type to find a common type here"
+            }
+        }
+    }
+}
+
 pub(crate) struct TypeVariableData {
     origin: TypeVariableOrigin,
 }
@@ -175,6 +235,7 @@ pub fn new() -> TypeVariableStorage<'tcx> {
             values: sv::SnapshotVecStorage::new(),
             eq_relations: ut::UnificationTableStorage::new(),
             sub_relations: ut::UnificationTableStorage::new(),
+            unification_origins: Vec::new(),
         }
     }
 
@@ -215,6 +276,31 @@ pub fn sub(&mut self, a: ty::TyVid, b: ty::TyVid) {
         self.sub_relations().union(a, b);
     }
 
+    /// Records why `a` and `b` were unified, for use in diagnostics.
+    ///
+    /// Callers are expected to check `-Z track-unification-origins`
+    /// before calling this.
+    pub fn record_unification_origin(
+        &mut self,
+        a: ty::TyVid,
+        b: ty::TyVid,
+        origin: UnificationOrigin,
+    ) {
+        self.storage.unification_origins.push(TypeVariableUnification { a, b, origin });
+        self.undo_log.push(UndoLog::PushUnificationOrigin);
+    }
+
+    /// Returns the recorded unifications involving any variable that is
+    /// sub-unified with `vid`, in the order in which they happened.
+    pub fn unification_origins(&mut self, vid: ty::TyVid) -> Vec<TypeVariableUnification> {
+        let root = self.sub_root_var(vid);
+        let recorded = self.storage.unification_origins.clone();
+        recorded
+            .into_iter()
+            .filter(|u| self.sub_root_var(u.a) == root || self.sub_root_var(u.b) == root)
+            .collect()
+    }
+
     /// Instantiates `vid` with the type `ty`.
     ///
     /// Precondition: `vid` must not have been previously instantiated.
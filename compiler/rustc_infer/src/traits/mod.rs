@@ -4,6 +4,7 @@
 
 mod engine;
 pub mod error_reporting;
+mod pending;
 mod project;
 mod structural_impls;
 pub mod util;
@@ -19,6 +20,7 @@
 pub use self::SelectionError::*;
 
 pub use self::engine::{TraitEngine, TraitEngineExt};
+pub use self::pending::{PendingObligation, PendingObligationsSnapshot, PendingRegionObligation};
 pub use self::project::MismatchedProjectionTypes;
 pub(crate) use self::project::UndoLog;
 pub use self::project::{
@@ -0,0 +1,82 @@
+//! A snapshot of the obligations an inference context is currently holding.
+//!
+//! The obligations themselves are tied to the `'tcx` lifetime and to the
+//! inference context that created them, which makes them awkward to hand out
+//! to tools built on top of `rustc_interface`. The types in this module are
+//! plain data: each obligation is resolved as far as inference allows and then
+//! rendered, so a snapshot can outlive the inference context and be encoded
+//! with any `rustc_serialize` encoder (e.g. the JSON one).
+
+use crate::infer::InferCtxt;
+use crate::traits::TraitEngine;
+use rustc_span::Span;
+
+/// A pending trait, projection, well-formedness, etc. obligation.
+#[derive(Clone, Debug, Encodable, Decodable)]
+pub struct PendingObligation {
+    /// The predicate that has to be proven, e.g. `Vec<u8>: Clone`.
+    pub predicate: String,
+    /// Why the predicate has to be proven, i.e. the `ObligationCauseCode`.
+    pub cause: String,
+    /// The span which caused the obligation to be registered.
+    pub span: Span,
+    /// How many obligations deep this obligation is nested
+    /// below the one that was originally registered.
+    pub recursion_depth: usize,
+}
+
+/// A pending `T: 'a` obligation, which is only checked by regionck.
+#[derive(Clone, Debug, Encodable, Decodable)]
+pub struct PendingRegionObligation {
+    /// The type which has to outlive `region`.
+    pub ty: String,
+    pub region: String,
+    /// The span which caused the obligation to be registered.
+    pub span: Span,
+}
+
+/// All obligations pending at the time `InferCtxt::pending_obligations_snapshot`
+/// was called.
+#[derive(Clone, Debug, Default, Encodable, Decodable)]
+pub struct PendingObligationsSnapshot {
+    pub obligations: Vec<PendingObligation>,
+    pub region_obligations: Vec<PendingRegionObligation>,
+}
+
+impl<'a, 'tcx> InferCtxt<'a, 'tcx> {
+    /// Returns a snapshot of the obligations which are still pending in
+    /// `fulfill_cx`, together with the region obligations registered with
+    /// this inference context.
+    ///
+    /// This does not process any obligations, so taking a snapshot never
+    /// affects the outcome of type inference.
+    pub fn pending_obligations_snapshot(
+        &self,
+        fulfill_cx: &dyn TraitEngine<'tcx>,
+    ) -> PendingObligationsSnapshot {
+        let obligations = fulfill_cx
+            .pending_obligations()
+            .into_iter()
+            .map(|obligation| PendingObligation {
+                predicate: self.resolve_vars_if_possible(obligation.predicate).to_string(),
+                cause: format!("{:?}", obligation.cause.code()),
+                span: obligation.cause.span,
+                recursion_depth: obligation.recursion_depth,
+            })
+            .collect();
+
+        // Resolving inference variables borrows `inner` mutably, so the region
+        // obligations are copied out of it before they get resolved.
+        let region_obligations = self.inner.borrow().region_obligations().to_vec();
+        let region_obligations = region_obligations
+            .into_iter()
+            .map(|(_, obligation)| PendingRegionObligation {
+                ty: self.resolve_vars_if_possible(obligation.sup_type).to_string(),
+                region: obligation.sub_region.to_string(),
+                span: obligation.origin.span(),
+            })
+            .collect();
+
+        PendingObligationsSnapshot { obligations, region_obligations }
+    }
+}
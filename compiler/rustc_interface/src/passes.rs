@@ -960,6 +960,8 @@ fn analysis(tcx: TyCtxt<'_>, (): ()) -> Result<()> {
         );
     });
 
+    rustc_mir_transform::check_enable_passes(tcx);
+
     sess.time("MIR_borrow_checking", || {
         tcx.hir().par_body_owners(|def_id| tcx.ensure().mir_borrowck(def_id));
     });
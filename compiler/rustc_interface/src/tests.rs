@@ -1,16 +1,20 @@
 use crate::interface::parse_cfgspecs;
 
+use rustc_ast::{FloatTy, UintTy};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::{emitter::HumanReadableErrorType, registry, ColorConfig};
 use rustc_session::config::InstrumentCoverage;
+use rustc_session::config::InstrumentXRay;
+use rustc_session::config::IntFallback;
 use rustc_session::config::Strip;
 use rustc_session::config::{build_configuration, build_session_options, to_crate_config};
 use rustc_session::config::{
-    rustc_optgroups, ErrorOutputType, ExternLocation, LocationDetail, Options, Passes,
+    rustc_optgroups, CrateType, ErrorOutputType, ExternLocation, LocationDetail, MirDumpFormat,
+    Options, Passes,
 };
 use rustc_session::config::{
-    BranchProtection, Externs, OutputType, OutputTypes, PAuthKey, PacRet, SymbolManglingVersion,
-    WasiExecModel,
+    BorrowckFactsFormat, BranchProtection, Externs, NeverTypeFallback, OutputType, OutputTypes,
+    PAuthKey, PacRet, SplitDebuginfoConfig, SymbolManglingVersion, WasiExecModel,
 };
 use rustc_session::config::{CFGuard, ExternEntry, LinkerPluginLto, LtoCli, SwitchWithOptPath};
 use rustc_session::lint::Level;
@@ -594,7 +598,10 @@ macro_rules! tracked {
     tracked!(profile_use, Some(PathBuf::from("abc")));
     tracked!(relocation_model, Some(RelocModel::Pic));
     tracked!(soft_float, true);
-    tracked!(split_debuginfo, Some(SplitDebuginfo::Packed));
+    tracked!(
+        split_debuginfo,
+        SplitDebuginfoConfig { default: Some(SplitDebuginfo::Packed), per_crate_type: vec![] }
+    );
     tracked!(symbol_mangling_version, Some(SymbolManglingVersion::V0));
     tracked!(target_cpu, Some(String::from("abc")));
     tracked!(target_feature, String::from("all the features, all of them"));
@@ -649,14 +656,18 @@ macro_rules! untracked {
     untracked!(dep_tasks, true);
     untracked!(dlltool, Some(PathBuf::from("custom_dlltool.exe")));
     untracked!(dont_buffer_diagnostics, true);
+    untracked!(dump_borrowck_facts, Some(BorrowckFactsFormat::Json));
     untracked!(dump_dep_graph, true);
     untracked!(dump_mir, Some(String::from("abc")));
     untracked!(dump_mir_dataflow, true);
     untracked!(dump_mir_dir, String::from("abc"));
     untracked!(dump_mir_exclude_pass_number, true);
+    untracked!(dump_mir_format, MirDumpFormat::Json);
     untracked!(dump_mir_graphviz, true);
+    untracked!(dump_thinlto_summary, true);
     untracked!(emit_stack_sizes, true);
     untracked!(future_incompat_test, true);
+    untracked!(group_diagnostics, true);
     untracked!(hir_stats, true);
     untracked!(identify_regions, true);
     untracked!(incremental_ignore_spans, true);
@@ -666,14 +677,17 @@ macro_rules! untracked {
     untracked!(keep_hygiene_data, true);
     untracked!(link_native_libraries, false);
     untracked!(llvm_time_trace, true);
+    untracked!(long_type_length, Some(100));
     untracked!(ls, true);
     untracked!(macro_backtrace, true);
+    untracked!(max_const_backtrace_frames, 8);
     untracked!(meta_stats, true);
     untracked!(nll_facts, true);
     untracked!(no_analysis, true);
     untracked!(no_interleave_lints, true);
     untracked!(no_leak_check, true);
     untracked!(no_parallel_llvm, true);
+    untracked!(obligation_chain, Some(3));
     untracked!(parse_only, true);
     untracked!(perf_stats, true);
     // `pre_link_arg` is omitted because it just forwards to `pre_link_args`.
@@ -696,12 +710,16 @@ macro_rules! untracked {
     untracked!(time_llvm_passes, true);
     untracked!(time_passes, true);
     untracked!(trace_macros, true);
+    untracked!(translate_additional_ftl, Some(PathBuf::from("fr.ftl")));
+    untracked!(translate_lang, Some(String::from("fr")));
     untracked!(trim_diagnostic_paths, false);
     untracked!(ui_testing, true);
     untracked!(unpretty, Some("expanded".to_string()));
     untracked!(unstable_options, true);
     untracked!(validate_mir, true);
     untracked!(verbose, true);
+    untracked!(verbose_type_diffs, true);
+    untracked!(verify_determinism, true);
 
     macro_rules! tracked {
         ($name: ident, $non_default_value: expr) => {
@@ -725,31 +743,42 @@ macro_rules! tracked {
     );
     tracked!(chalk, true);
     tracked!(codegen_backend, Some("abc".to_string()));
+    tracked!(coinductive_traits, true);
+    tracked!(const_eval_limit, Some(4096));
+    tracked!(const_eval_memory_limit, Some(4096));
     tracked!(crate_attr, vec!["abc".to_string()]);
     tracked!(debug_info_for_profiling, true);
     tracked!(debug_macros, true);
     tracked!(dep_info_omit_d_target, true);
     tracked!(drop_tracking, true);
     tracked!(dual_proc_macros, true);
+    tracked!(explain_region_errors, true);
     tracked!(fewer_names, Some(true));
+    tracked!(float_fallback, Some(FloatTy::F32));
     tracked!(force_unstable_if_unmarked, true);
     tracked!(fuel, Some(("abc".to_string(), 99)));
     tracked!(function_sections, Some(false));
+    tracked!(future_size_limit, Some(65536));
     tracked!(human_readable_cgu_names, true);
     tracked!(inline_in_all_cgus, Some(true));
     tracked!(inline_mir, Some(true));
     tracked!(inline_mir_hint_threshold, Some(123));
+    tracked!(inline_mir_report, true);
     tracked!(inline_mir_threshold, Some(123));
     tracked!(instrument_coverage, Some(InstrumentCoverage::All));
     tracked!(instrument_mcount, true);
+    tracked!(instrument_xray, Some(InstrumentXRay::default()));
+    tracked!(int_fallback, Some(IntFallback::Unsigned(UintTy::U64)));
     tracked!(link_only, true);
     tracked!(llvm_plugins, vec![String::from("plugin_name")]);
     tracked!(location_detail, LocationDetail { file: true, line: false, column: false });
     tracked!(merge_functions, Some(MergeFunctions::Disabled));
     tracked!(mir_emit_retag, true);
+    tracked!(mir_enable_passes, vec![("DestinationPropagation".to_string(), false)]);
     tracked!(mir_opt_level, Some(4));
     tracked!(move_size_limit, Some(4096));
     tracked!(mutable_noalias, Some(true));
+    tracked!(never_type_fallback, Some(NeverTypeFallback::Never));
     tracked!(new_llvm_pass_manager, Some(true));
     tracked!(no_generate_arange_section, true);
     tracked!(no_link, true);
@@ -762,6 +791,7 @@ macro_rules! tracked {
     tracked!(pick_stable_methods_before_any_unstable, false);
     tracked!(plt, Some(true));
     tracked!(polonius, true);
+    tracked!(polymorphize, true);
     tracked!(precise_enum_drop_elaboration, false);
     tracked!(print_fuel, Some("abc".to_string()));
     tracked!(profile, true);
@@ -784,8 +814,10 @@ macro_rules! tracked {
     tracked!(symbol_mangling_version, Some(SymbolManglingVersion::V0));
     tracked!(teach, true);
     tracked!(thinlto, Some(true));
+    tracked!(thinlto_import_instr_limit, Some(200));
     tracked!(thir_unsafeck, true);
     tracked!(tls_model, Some(TlsModel::GeneralDynamic));
+    tracked!(track_unification_origins, true);
     tracked!(trap_unreachable, Some(false));
     tracked!(treat_err_as_bug, NonZeroUsize::new(1));
     tracked!(tune_cpu, Some(String::from("abc")));
@@ -815,3 +847,40 @@ fn test_edition_parsing() {
     let (sessopts, _) = build_session_options_and_crate_config(matches);
     assert!(sessopts.edition == Edition::Edition2018)
 }
+
+#[test]
+fn test_split_debuginfo_parsing() {
+    let matches = optgroups()
+        .parse(&[
+            "-Zunstable-options".to_string(),
+            "-Csplit-debuginfo=packed,bin=unpacked".to_string(),
+            "-Csplit-debuginfo=off".to_string(),
+        ])
+        .unwrap();
+    let (sessopts, _) = build_session_options_and_crate_config(matches);
+    let split_debuginfo = &sessopts.cg.split_debuginfo;
+    assert_eq!(split_debuginfo.default, Some(SplitDebuginfo::Off));
+    assert_eq!(split_debuginfo.for_crate_type(CrateType::Rlib), Some(SplitDebuginfo::Off));
+    assert_eq!(
+        split_debuginfo.for_crate_type(CrateType::Executable),
+        Some(SplitDebuginfo::Unpacked)
+    );
+}
+
+#[test]
+fn test_diagnostic_width_parsing() {
+    let matches = optgroups().parse(&["-Zterminal-width=100".to_string()]).unwrap();
+    let (sessopts, _) = build_session_options_and_crate_config(matches);
+    assert_eq!(sessopts.diagnostic_width, Some(100));
+
+    // `--diagnostic-width` takes precedence over `-Z terminal-width`.
+    let matches = optgroups()
+        .parse(&[
+            "-Zunstable-options".to_string(),
+            "-Zterminal-width=100".to_string(),
+            "--diagnostic-width=60".to_string(),
+        ])
+        .unwrap();
+    let (sessopts, _) = build_session_options_and_crate_config(matches);
+    assert_eq!(sessopts.diagnostic_width, Some(60));
+}
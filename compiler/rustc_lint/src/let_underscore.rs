@@ -0,0 +1,164 @@
+use crate::{LateContext, LateLintPass, LintContext};
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_middle::ty::{self, subst::GenericArgKind};
+use rustc_span::{sym, Symbol};
+
+declare_lint! {
+    /// The `let_underscore_drop` lint checks for statements which don't bind
+    /// an expression which has a non-trivial Drop implementation to anything,
+    /// causing the expression to be dropped immediately instead of at end of
+    /// scope.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(let_underscore_drop)]
+    /// struct SomeStruct;
+    ///
+    /// impl Drop for SomeStruct {
+    ///     fn drop(&mut self) {
+    ///         println!("Dropping SomeStruct");
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     // SomeStruct is dropped immediately instead of at end of scope,
+    ///     // so "Dropping SomeStruct" is printed before "end of main".
+    ///     // The order of prints would be reversed if SomeStruct was bound to
+    ///     // a name (such as "_foo").
+    ///     let _ = SomeStruct;
+    ///     println!("end of main");
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Statements which assign an expression to an underscore cause the
+    /// expression to immediately drop, instead of extending the expression's
+    /// lifetime to the end of the scope. This is usually unintended,
+    /// especially for types like `Box`.
+    ///
+    /// If you want to extend the expression's lifetime to the end of the scope,
+    /// assign to an underscore-prefixed name (such as `_foo`) instead. If you
+    /// do actually want to drop the expression immediately, then calling
+    /// `std::mem::drop` on the expression is clearer and helps convey intent.
+    pub LET_UNDERSCORE_DROP,
+    Allow,
+    "non-binding let on a type that implements `Drop`"
+}
+
+declare_lint! {
+    /// The `let_underscore_lock` lint checks for statements which don't bind
+    /// a mutex guard to anything, causing the lock to be released immediately
+    /// instead of at end of scope, which is typically incorrect.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// use std::sync::{Arc, Mutex};
+    /// use std::thread;
+    /// let data = Arc::new(Mutex::new(0));
+    ///
+    /// thread::spawn(move || {
+    ///     // The lock is immediately released instead of at the end of the
+    ///     // scope, which is probably not intended.
+    ///     let _ = data.lock().unwrap();
+    ///     println!("doing some work");
+    ///     let mut lock = data.lock().unwrap();
+    ///     *lock += 1;
+    /// });
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Statements which assign an expression to an underscore cause the
+    /// expression to immediately drop instead of extending the expression's
+    /// lifetime to the end of the scope. This is usually unintended,
+    /// especially for types like `MutexGuard`, which are typically used to
+    /// lock a mutex for the duration of an entire scope.
+    ///
+    /// If you want to extend the expression's lifetime to the end of the scope,
+    /// assign to an underscore-prefixed name (such as `_foo`) instead. If you
+    /// do actually want to drop the expression immediately, then calling
+    /// `std::mem::drop` on the expression is clearer and helps convey intent.
+    pub LET_UNDERSCORE_LOCK,
+    Deny,
+    "non-binding let on a synchronization lock"
+}
+
+declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK]);
+
+const SYNC_GUARD_SYMBOLS: [Symbol; 3] =
+    [sym::MutexGuard, sym::RwLockReadGuard, sym::RwLockWriteGuard];
+
+impl<'tcx> LateLintPass<'tcx> for LetUnderscore {
+    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx hir::Local<'tcx>) {
+        if !matches!(local.pat.kind, hir::PatKind::Wild)
+            || local.source != hir::LocalSource::Normal
+            || local.span.from_expansion()
+        {
+            return;
+        }
+        let Some(init) = local.init else { return };
+        // `let _ = place;` doesn't move out of the place, so nothing is dropped.
+        if init.is_place_expr(|_| false) {
+            return;
+        }
+        let init_ty = cx.typeck_results().expr_ty(init);
+        // If the type has a trivial destructor, it doesn't matter that the value is dropped
+        // right away.
+        if !init_ty.has_significant_drop(cx.tcx, cx.param_env) {
+            return;
+        }
+        let is_sync_lock = init_ty.walk().any(|inner| match inner.unpack() {
+            GenericArgKind::Type(inner_ty) => match inner_ty.kind() {
+                ty::Adt(adt, _) => SYNC_GUARD_SYMBOLS
+                    .iter()
+                    .any(|&guard| cx.tcx.is_diagnostic_item(guard, adt.did)),
+                _ => false,
+            },
+            GenericArgKind::Lifetime(_) | GenericArgKind::Const(_) => false,
+        });
+
+        let (lint, msg, pat_label) = if is_sync_lock {
+            (
+                LET_UNDERSCORE_LOCK,
+                "non-binding let on a synchronization lock",
+                "this lock is not assigned to a binding and is immediately dropped",
+            )
+        } else {
+            (
+                LET_UNDERSCORE_DROP,
+                "non-binding let on a type that implements `Drop`",
+                "this value is not assigned to a binding and is immediately dropped",
+            )
+        };
+        cx.struct_span_lint(lint, local.pat.span, |lint| {
+            let mut err = lint.build(msg);
+            err.span_label(local.pat.span, pat_label);
+            err.span_suggestion_verbose(
+                local.pat.span,
+                "consider binding to an unused variable to avoid immediately dropping the value",
+                "_unused".to_string(),
+                Applicability::MachineApplicable,
+            );
+            // Without the type annotation, `drop(..)` could infer a different type.
+            if local.ty.is_none() {
+                err.multipart_suggestion(
+                    "consider immediately dropping the value",
+                    vec![
+                        (local.span.until(init.span), "drop(".to_string()),
+                        (init.span.shrink_to_hi(), ")".to_string()),
+                    ],
+                    Applicability::MachineApplicable,
+                );
+            }
+            err.emit();
+        });
+    }
+}
@@ -52,6 +52,7 @@
 pub mod hidden_unicode_codepoints;
 mod internal;
 mod late;
+mod let_underscore;
 mod levels;
 mod methods;
 mod non_ascii_idents;
@@ -61,6 +62,7 @@
 mod pass_by_value;
 mod passes;
 mod redundant_semicolon;
+mod tail_expr_drop_order;
 mod traits;
 mod types;
 mod unused;
@@ -83,6 +85,7 @@
 use enum_intrinsics_non_enums::EnumIntrinsicsNonEnums;
 use hidden_unicode_codepoints::*;
 use internal::*;
+use let_underscore::*;
 use methods::*;
 use non_ascii_idents::*;
 use non_fmt_panic::NonPanicFmt;
@@ -90,6 +93,7 @@
 use noop_method_call::*;
 use pass_by_value::*;
 use redundant_semicolon::*;
+use tail_expr_drop_order::*;
 use traits::*;
 use types::*;
 use unused::*;
@@ -180,6 +184,8 @@ macro_rules! late_lint_passes {
                 EnumIntrinsicsNonEnums: EnumIntrinsicsNonEnums,
                 InvalidAtomicOrdering: InvalidAtomicOrdering,
                 NamedAsmLabels: NamedAsmLabels,
+                TailExprDropOrder: TailExprDropOrder,
+                LetUnderscore: LetUnderscore,
             ]
         );
     };
@@ -310,6 +316,8 @@ macro_rules! register_passes {
         REDUNDANT_SEMICOLONS
     );
 
+    add_lint_group!("let_underscore", LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK);
+
     add_lint_group!(
         "rust_2018_idioms",
         BARE_TRAIT_OBJECTS,
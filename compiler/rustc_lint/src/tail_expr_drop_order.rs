@@ -0,0 +1,159 @@
+use crate::{LateContext, LateLintPass, LintContext};
+use rustc_hir as hir;
+use rustc_hir::intravisit::{self, Visitor};
+use rustc_middle::middle::region::{Scope, ScopeData, ScopeTree};
+use rustc_middle::ty::adjustment::Adjust;
+
+declare_lint! {
+    /// The `tail_expr_drop_order` lint detects temporaries with a significant
+    /// destructor that are created in the tail expression of a block and that
+    /// outlive the block's local variables.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// #![warn(tail_expr_drop_order)]
+    /// use std::sync::Mutex;
+    ///
+    /// struct Logger;
+    ///
+    /// impl Drop for Logger {
+    ///     fn drop(&mut self) {
+    ///         println!("done");
+    ///     }
+    /// }
+    ///
+    /// fn len(m: &Mutex<Vec<u8>>) -> usize {
+    ///     let _logger = Logger;
+    ///     m.lock().unwrap().len()
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Temporaries created in the tail expression of a block are dropped at
+    /// the end of the statement enclosing the block, *after* the block's own
+    /// local variables. This is a common source of surprising deadlocks and
+    /// borrow errors, and proposed changes to temporary scoping would drop
+    /// such temporaries earlier. Binding the value to a local variable makes
+    /// the drop order explicit.
+    pub TAIL_EXPR_DROP_ORDER,
+    Allow,
+    "detects temporaries with significant drops that outlive the enclosing block's locals"
+}
+
+declare_lint_pass!(TailExprDropOrder => [TAIL_EXPR_DROP_ORDER]);
+
+impl<'tcx> LateLintPass<'tcx> for TailExprDropOrder {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx hir::Block<'tcx>) {
+        let Some(tail) = block.expr else { return };
+        if block.span.from_expansion() {
+            return;
+        }
+        // The order only matters if there are locals whose destructors the
+        // temporaries can be observed to run after.
+        let typeck_results = cx.typeck_results();
+        let significant_locals: Vec<_> = block
+            .stmts
+            .iter()
+            .filter_map(|stmt| match stmt.kind {
+                hir::StmtKind::Local(local) => Some(local),
+                _ => None,
+            })
+            .filter(|local| {
+                // `let _ = ..;` drops its initializer right away.
+                let mut has_binding = false;
+                local.pat.each_binding(|..| has_binding = true);
+                has_binding
+                    && typeck_results.pat_ty(local.pat).has_significant_drop(cx.tcx, cx.param_env)
+            })
+            .collect();
+        if significant_locals.is_empty() {
+            return;
+        }
+
+        let boundary = Scope { id: block.hir_id.local_id, data: ScopeData::Node };
+        for temporary in outliving_temporaries(cx, tail, boundary) {
+            cx.struct_span_lint(TAIL_EXPR_DROP_ORDER, temporary.span, |lint| {
+                let mut err = lint.build(
+                    "temporary with significant `Drop` in tail expression is dropped after the \
+                     block's local variables",
+                );
+                err.span_label(temporary.span, "this temporary is dropped last");
+                for local in &significant_locals {
+                    err.span_label(local.pat.span, "dropped before the temporary");
+                }
+                err.help("consider binding the value to a local variable");
+                err.emit();
+            });
+        }
+    }
+}
+
+/// Returns the temporaries created while evaluating `root` that have a significant destructor and
+/// are only dropped once `boundary` has been exited.
+fn outliving_temporaries<'tcx>(
+    cx: &LateContext<'tcx>,
+    root: &'tcx hir::Expr<'tcx>,
+    boundary: Scope,
+) -> Vec<&'tcx hir::Expr<'tcx>> {
+    let Some(body_id) = cx.enclosing_body else { return Vec::new() };
+    let scope_tree = cx.tcx.region_scope_tree(cx.tcx.hir().body_owner_def_id(body_id).to_def_id());
+    let mut finder = TemporaryFinder { cx, scope_tree, boundary, temporaries: Vec::new() };
+    finder.visit_expr(root);
+    finder.temporaries
+}
+
+struct TemporaryFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    scope_tree: &'tcx ScopeTree,
+    boundary: Scope,
+    temporaries: Vec<&'tcx hir::Expr<'tcx>>,
+}
+
+impl<'a, 'tcx> TemporaryFinder<'a, 'tcx> {
+    /// `expr` is used where a place is expected, so a temporary is created for it unless it is a
+    /// place expression already.
+    fn check_place_operand(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        if expr.span.from_expansion() || expr.is_place_expr(|_| false) {
+            return;
+        }
+        let cx = self.cx;
+        let Some(scope) = self.scope_tree.temporary_scope(expr.hir_id.local_id) else { return };
+        if self.scope_tree.is_subscope_of(self.boundary, scope)
+            && cx.typeck_results().expr_ty(expr).has_significant_drop(cx.tcx, cx.param_env)
+        {
+            self.temporaries.push(expr);
+        }
+    }
+}
+
+impl<'a, 'tcx> Visitor<'tcx> for TemporaryFinder<'a, 'tcx> {
+    fn visit_expr(&mut self, expr: &'tcx hir::Expr<'tcx>) {
+        let typeck_results = self.cx.typeck_results();
+        // Autoref and overloaded derefs borrow their operand.
+        if let Some(adjustment) = typeck_results.expr_adjustments(expr).first() {
+            if let Adjust::Borrow(_) | Adjust::Deref(Some(_)) = adjustment.kind {
+                self.check_place_operand(expr);
+            }
+        }
+        match expr.kind {
+            hir::ExprKind::AddrOf(_, _, operand)
+            | hir::ExprKind::Field(operand, _)
+            | hir::ExprKind::Index(operand, _)
+            | hir::ExprKind::Match(operand, ..) => self.check_place_operand(operand),
+            hir::ExprKind::Unary(hir::UnOp::Deref, operand)
+                if typeck_results.is_method_call(expr) =>
+            {
+                self.check_place_operand(operand)
+            }
+            hir::ExprKind::Let(let_expr) => self.check_place_operand(let_expr.init),
+            // Nested blocks are checked on their own.
+            hir::ExprKind::Block(..) => return,
+            _ => {}
+        }
+        intravisit::walk_expr(self, expr);
+    }
+}
@@ -91,7 +91,7 @@ fn check_stmt(&mut self, cx: &LateContext<'_>, s: &hir::Stmt<'_>) {
             _ => return,
         };
 
-        if let hir::ExprKind::Ret(..) = expr.kind {
+        if let hir::ExprKind::Ret(..) | hir::ExprKind::Become(..) = expr.kind {
             return;
         }
 
@@ -479,7 +479,10 @@ fn is_expr_delims_necessary(
         lhs_needs_parens
             || (followed_by_block
                 && match &inner.kind {
-                    ExprKind::Ret(_) | ExprKind::Break(..) | ExprKind::Yield(..) => true,
+                    ExprKind::Ret(_)
+                    | ExprKind::Become(_)
+                    | ExprKind::Break(..)
+                    | ExprKind::Yield(..) => true,
                     ExprKind::Range(_lhs, Some(rhs), _limits) => {
                         matches!(rhs.kind, ExprKind::Block(..))
                     }
@@ -2922,6 +2922,57 @@
     "detects large moves or copies",
 }
 
+declare_lint! {
+    /// The `large_futures` lint detects generators, and futures of `async` functions and blocks,
+    /// whose size exceeds the `future_size_limit`.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,ignore (needs a build and the future_size_limit)
+    /// #![feature(large_assignments)]
+    /// #![future_size_limit = "1024"]
+    ///
+    /// async fn process() {
+    ///     let buf = [0u8; 4096];
+    ///     tick().await;
+    ///     drop(buf);
+    /// }
+    /// ```
+    ///
+    /// produces:
+    ///
+    /// ```text
+    /// warning: the future returned by `process` is 4098 bytes
+    ///  --> src/lib.rs:4:1
+    ///   |
+    /// 4 | async fn process() {
+    ///   | ^^^^^^^^^^^^^^^^^^
+    ///   |
+    /// note: `buf` (4096 bytes) and a value of type `impl Future<Output = ()>` (1 byte) are held
+    ///       across this `await`
+    ///  --> src/lib.rs:6:11
+    ///   |
+    /// 6 |     tick().await;
+    ///   |           ^^^^^^
+    ///   = note: the `future_size_limit` is 1024 bytes
+    /// ```
+    ///
+    /// ### Explanation
+    ///
+    /// A future holds all the values that are live across one of its `await`s, so a large
+    /// buffer or a large awaited future can make it much larger than expected. Storing such a
+    /// future or moving it around is expensive, and can overflow the stack of small targets. The
+    /// notes show the largest values that are held across each `await` or `yield`, which can be
+    /// boxed or dropped before it.
+    ///
+    /// Like `large_assignments`, the lint is only emitted for generators that participate in code
+    /// generation, and only when the limit is set, with the `#![future_size_limit = "N"]` crate
+    /// attribute or the `-Zfuture-size-limit=N` flag.
+    pub LARGE_FUTURES,
+    Warn,
+    "detects generators and futures whose size exceeds the `future_size_limit`",
+}
+
 declare_lint! {
     /// The `deprecated_cfg_attr_crate_type_name` lint detects uses of the
     /// `#![cfg_attr(..., crate_type = "...")]` and
@@ -2957,6 +3008,114 @@
     };
 }
 
+declare_lint! {
+    /// The `never_type_fallback_change` lint detects expressions whose type
+    /// falls back to `()` today, but will fall back to `!` once never type
+    /// fallback is enabled.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(never_type_fallback_change)]
+    /// fn main() {
+    ///     let x = panic!();
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// When the type of a diverging expression (such as `panic!()` or
+    /// `return`) is not otherwise constrained, it "falls back" to `()`. The
+    /// [never type RFC] changes this fallback to `!`, except for type
+    /// variables which flow into live code. This lint points out the
+    /// expressions whose type would change, so that code relying on the type
+    /// being `()` can be annotated ahead of the change.
+    ///
+    /// This lint is "allow" by default, since most code is not affected by
+    /// the change.
+    ///
+    /// [never type RFC]: https://github.com/rust-lang/rfcs/blob/master/text/1216-bang-type.md
+    pub NEVER_TYPE_FALLBACK_CHANGE,
+    Allow,
+    "detects expressions whose type will fall back to `!` instead of `()`",
+}
+
+declare_lint! {
+    /// The `numeric_fallback` lint detects numeric literals whose type is
+    /// not constrained by the surrounding code, and which therefore fall back
+    /// to the default integer or floating-point type.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(numeric_fallback)]
+    /// fn main() {
+    ///     let x = 1;
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// An unsuffixed integer literal whose type is not otherwise inferred
+    /// has type `i32`, and an unsuffixed floating-point literal has type
+    /// `f64`. These defaults can be changed with `-Z int-fallback` and
+    /// `-Z float-fallback`. This lint points out where the fallback is
+    /// applied, which is useful when auditing code for the effect of a
+    /// different default.
+    ///
+    /// This lint is "allow" by default, since relying on the default type
+    /// of a literal is common and rarely a problem.
+    pub NUMERIC_FALLBACK,
+    Allow,
+    "detects numeric literals whose type falls back to the default",
+}
+
+declare_lint! {
+    /// The `const_fn_call_promotion` lint detects borrows in the initializer
+    /// of a `const` or `static` that only get the `'static` lifetime because
+    /// a call to a `const fn` is implicitly promoted.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// const fn make() -> (i32, i32) { (1, 2) }
+    ///
+    /// const X: i32 = {
+    ///     let r: &'static (i32, i32) = &make();
+    ///     r.0
+    /// };
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Outside of `const` and `static` initializers, a borrow of the result
+    /// of a function call is only promoted to a `'static` constant if the
+    /// function is one of a few promotable functions of the standard library.
+    /// Promoting calls to arbitrary `const fn` means evaluating them even if
+    /// the borrow is never reached, so a panic in such a call can not be
+    /// reported only when the call is actually evaluated. The initializers of
+    /// `const` and `static` items will stop promoting these calls, which can
+    /// then be moved into a named `const` item to keep the `'static`
+    /// lifetime. This is a [future-incompatible] lint to transition this to
+    /// a hard error in the future. See [issue #80619] for more details.
+    ///
+    /// [issue #80619]: https://github.com/rust-lang/rust/issues/80619
+    /// [future-incompatible]: ../index.md#future-incompatible-lints
+    pub CONST_FN_CALL_PROMOTION,
+    Warn,
+    "detects borrows that rely on the promotion of `const fn` calls",
+    @future_incompatible = FutureIncompatibleInfo {
+        reference: "issue #80619 <https://github.com/rust-lang/rust/issues/80619>",
+        reason: FutureIncompatibilityReason::FutureReleaseError,
+    };
+}
+
 declare_lint_pass! {
     /// Does nothing as a lint pass, but registers some `Lint`s
     /// that are used by other parts of the compiler.
@@ -3044,6 +3203,7 @@
         PROC_MACRO_BACK_COMPAT,
         RUST_2021_INCOMPATIBLE_OR_PATTERNS,
         LARGE_ASSIGNMENTS,
+        LARGE_FUTURES,
         RUST_2021_PRELUDE_COLLISIONS,
         RUST_2021_PREFIXES_INCOMPATIBLE_SYNTAX,
         UNSUPPORTED_CALLING_CONVENTIONS,
@@ -3055,6 +3215,11 @@
         DEPRECATED_CFG_ATTR_CRATE_TYPE_NAME,
         DUPLICATE_MACRO_ATTRIBUTES,
         SUSPICIOUS_AUTO_TRAIT_IMPLS,
+        NEVER_TYPE_FALLBACK_CHANGE,
+        NUMERIC_FALLBACK,
+        CONST_FN_CALL_PROMOTION,
+        FUZZY_PROVENANCE_CASTS,
+        LOSSY_PROVENANCE_CASTS,
     ]
 }
 
@@ -3665,3 +3830,72 @@
         reference: "issue #93367 <https://github.com/rust-lang/rust/issues/93367>",
     };
 }
+
+declare_lint! {
+    /// The `fuzzy_provenance_casts` lint detects an `as` cast between an integer and a pointer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![feature(strict_provenance)]
+    /// #![deny(fuzzy_provenance_casts)]
+    ///
+    /// fn main() {
+    ///     let _dangling = 16_usize as *const u8;
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// This lint is part of the Strict Provenance experiment, see [issue #95228]. Casting an
+    /// integer to a pointer has to guess the provenance of the pointer, i.e., which allocated
+    /// object it may access, and which one it picks is unclear. Code that keeps a pointer to the
+    /// same allocated object should use [`with_addr`] on it to create a pointer with its
+    /// provenance, and code that can't should use [`from_exposed_addr`], which gives the
+    /// pointer a provenance that was exposed with [`expose_addr`].
+    ///
+    /// [issue #95228]: https://github.com/rust-lang/rust/issues/95228
+    /// [`with_addr`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.with_addr
+    /// [`from_exposed_addr`]: https://doc.rust-lang.org/core/ptr/fn.from_exposed_addr.html
+    /// [`expose_addr`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.expose_addr
+    pub FUZZY_PROVENANCE_CASTS,
+    Allow,
+    "an `as` cast from an integer to a pointer",
+    @feature_gate = sym::strict_provenance;
+}
+
+declare_lint! {
+    /// The `lossy_provenance_casts` lint detects an `as` cast between a pointer and an integer.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![feature(strict_provenance)]
+    /// #![deny(lossy_provenance_casts)]
+    ///
+    /// fn main() {
+    ///     let x: u8 = 37;
+    ///     let _addr: usize = &x as *const u8 as usize;
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// This lint is part of the Strict Provenance experiment, see [issue #95228]. Casting a
+    /// pointer to an integer exposes the provenance of the pointer, which makes every later cast
+    /// of an integer to a pointer harder to reason about. Code that only needs the address of
+    /// the pointer should use [`addr`], which discards its provenance instead, and code that
+    /// converts the address back to a pointer should use [`expose_addr`] to make it explicit.
+    ///
+    /// [issue #95228]: https://github.com/rust-lang/rust/issues/95228
+    /// [`addr`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.addr
+    /// [`expose_addr`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.expose_addr
+    pub LOSSY_PROVENANCE_CASTS,
+    Allow,
+    "an `as` cast from a pointer to an integer",
+    @feature_gate = sym::strict_provenance;
+}
@@ -1521,6 +1521,64 @@ LLVMRustGetThinLTOModules(const LLVMRustThinLTOData *data,
   }
 }
 
+static std::string
+getThinLTOValueName(const LLVMRustThinLTOData *Data, GlobalValue::GUID GUID) {
+  ValueInfo VI = Data->Index.getValueInfo(GUID);
+  if (VI && !VI.name().empty())
+    return VI.name().str();
+  return "<guid " + std::to_string(GUID) + ">";
+}
+
+// Writes a textual dump of the combined ThinLTO summary to `Str`: for each
+// module, the functions it defines with their size and calls, followed by the
+// functions ThinLTO decided to import into it. This is meant to help figure out
+// why a function didn't get imported (and therefore inlined) across codegen
+// units, by comparing the sizes here with `-import-instr-limit`.
+extern "C" void
+LLVMRustThinLTODumpSummary(const LLVMRustThinLTOData *Data, RustStringRef Str) {
+  RawRustStringOstream OS(Str);
+
+  std::vector<StringRef> Modules;
+  for (const auto &Module : Data->ModuleToDefinedGVSummaries)
+    Modules.push_back(Module.getKey());
+  llvm::sort(Modules);
+
+  for (StringRef ModuleId : Modules) {
+    OS << "module " << ModuleId << "\n";
+
+    std::vector<std::pair<std::string, const FunctionSummary *>> Functions;
+    for (const auto &Defined : Data->ModuleToDefinedGVSummaries.lookup(ModuleId))
+      if (const auto *FS = dyn_cast<FunctionSummary>(Defined.second))
+        Functions.emplace_back(getThinLTOValueName(Data, Defined.first), FS);
+    llvm::sort(Functions, [](const auto &A, const auto &B) { return A.first < B.first; });
+
+    for (const auto &Function : Functions) {
+      const FunctionSummary *FS = Function.second;
+      OS << "  function " << Function.first << " insts=" << FS->instCount();
+      if (!FS->flags().Live)
+        OS << " dead";
+      if (FS->notEligibleToImport())
+        OS << " not-eligible-to-import";
+      OS << "\n";
+      for (const auto &Call : FS->calls()) {
+        OS << "    call " << getThinLTOValueName(Data, Call.first.getGUID())
+           << " hotness=" << getHotnessName(Call.second.getHotness()) << "\n";
+      }
+    }
+
+    const auto &Imports = Data->ImportLists.find(ModuleId);
+    if (Imports == Data->ImportLists.end())
+      continue;
+    std::vector<std::pair<std::string, StringRef>> Imported;
+    for (const auto &FromModule : Imports->getValue())
+      for (GlobalValue::GUID GUID : FromModule.getValue())
+        Imported.emplace_back(getThinLTOValueName(Data, GUID), FromModule.getKey());
+    llvm::sort(Imported);
+    for (const auto &Import : Imported)
+      OS << "  import " << Import.first << " from " << Import.second << "\n";
+  }
+}
+
 // This struct and various functions are sort of a hack right now, but the
 // problem is that we've got in-memory LLVM modules after we generate and
 // optimize all codegen-units for one compilation in rustc. To be compatible
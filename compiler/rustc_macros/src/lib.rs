@@ -43,6 +43,9 @@ pub fn symbols(input: TokenStream) -> TokenStream {
         message,
         lint,
         error,
+        note,
+        help,
+        slug,
         label,
         suggestion,
         suggestion_short,
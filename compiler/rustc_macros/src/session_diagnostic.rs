@@ -16,6 +16,7 @@
 /// # use rustc_middle::ty::Ty;
 /// #[derive(SessionDiagnostic)]
 /// #[code = "E0505"]
+/// #[slug = "borrowck-move-out-of-borrow"]
 /// #[error = "cannot move out of {name} because it is borrowed"]
 /// pub struct MoveOutOfBorrowError<'tcx> {
 ///     pub name: Ident,
@@ -28,6 +29,21 @@
 ///     pub opt_sugg: Option<(Span, Applicability)>
 /// }
 /// ```
+/// Notes and help messages without a span are added with `#[note = "..."]` and
+/// `#[help = "..."]` on the struct.
+///
+/// A diagnostic with a `#[slug = "..."]` attribute on the struct can be translated with
+/// `-Z translate-lang`: its primary message is the Fluent message named by the slug, and its notes,
+/// help messages, labels and suggestion messages are the attributes of that message named `note`
+/// and `help`, or after their field, with its underscores replaced by dashes. The fields that the
+/// English message refers to are available to the translation as variables:
+/// ```text
+/// borrowck-move-out-of-borrow = impossible de déplacer { $name } car il est emprunté
+///     .span = impossible de déplacer l'emprunt
+///     .other-span = `{ $ty }` emprunté ici en premier
+/// ```
+/// Messages without a translation are emitted in English.
+///
 /// Then, later, to emit the error:
 ///
 /// ```ignore (pseudo-rust)
@@ -148,8 +164,33 @@ fn new(diag: syn::Ident, sess: syn::Ident, structure: synstructure::Structure<'a
             }
         }
 
+        // The slug is needed to build the messages of all the other attributes, so it is read
+        // before any of them.
+        let mut slug = None;
+        for attr in &ast.attrs {
+            if let Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                lit: syn::Lit::Str(s),
+                ..
+            })) = attr.parse_meta()
+            {
+                if path.is_ident("slug") {
+                    if slug.is_some() {
+                        span_err(s.span().unwrap(), "`slug` specified multiple times").emit();
+                    }
+                    slug = Some(s.value());
+                }
+            }
+        }
+
         Self {
-            builder: SessionDiagnosticDeriveBuilder { diag, sess, fields: fields_map, kind: None },
+            builder: SessionDiagnosticDeriveBuilder {
+                diag,
+                sess,
+                fields: fields_map,
+                kind: None,
+                slug,
+            },
             structure,
         }
     }
@@ -268,6 +309,19 @@ struct SessionDiagnosticDeriveBuilder<'a> {
     /// stores at what Span the kind was first set at (for error reporting purposes, if the kind
     /// was multiply specified).
     kind: Option<(DiagnosticId, proc_macro2::Span)>,
+
+    /// The slug of the diagnostic, which names its Fluent message when it is translated.
+    slug: Option<String>,
+}
+
+/// The part of the Fluent message of a diagnostic that translates a format string of it.
+enum MessageKey {
+    /// The format string isn't a message, e.g. it is the code of a suggestion.
+    Untranslated,
+    /// The value of the Fluent message, for the primary message of the diagnostic.
+    Primary,
+    /// An attribute of the Fluent message.
+    Attribute(String),
 }
 
 impl<'a> SessionDiagnosticDeriveBuilder<'a> {
@@ -277,9 +331,14 @@ fn generate_structure_code(
     ) -> Result<proc_macro2::TokenStream, SessionDiagnosticDeriveError> {
         Ok(match attr.parse_meta()? {
             syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(s), .. }) => {
-                let formatted_str = self.build_format(&s.value(), attr.span());
                 let name = attr.path.segments.last().unwrap().ident.to_string();
                 let name = name.as_str();
+                let key = match name {
+                    "message" => MessageKey::Primary,
+                    "note" | "help" => MessageKey::Attribute(name.to_string()),
+                    _ => MessageKey::Untranslated,
+                };
+                let formatted_str = self.build_format(&s.value(), attr.span(), key);
                 match name {
                     "message" => {
                         let diag = &self.diag;
@@ -287,6 +346,20 @@ fn generate_structure_code(
                             #diag.set_primary_message(#formatted_str);
                         }
                     }
+                    "note" => {
+                        let diag = &self.diag;
+                        quote! {
+                            #diag.note(&#formatted_str);
+                        }
+                    }
+                    "help" => {
+                        let diag = &self.diag;
+                        quote! {
+                            #diag.help(&#formatted_str);
+                        }
+                    }
+                    // Read when the builder is created.
+                    "slug" => quote! {},
                     attr @ "error" | attr @ "lint" => {
                         self.set_kind_once(
                             if attr == "error" {
@@ -380,12 +453,18 @@ fn generate_non_option_field_code(
         let field_binding = &info.binding.binding;
         let name = attr.path.segments.last().unwrap().ident.to_string();
         let name = name.as_str();
+        // Labels and suggestion messages are translated by the attribute named after their field.
+        let field_key = || match &info.binding.ast().ident {
+            Some(ident) => MessageKey::Attribute(ident.to_string().replace('_', "-")),
+            None => MessageKey::Untranslated,
+        };
         // At this point, we need to dispatch based on the attribute key + the
         // type.
         let meta = attr.parse_meta()?;
         Ok(match meta {
             syn::Meta::NameValue(syn::MetaNameValue { lit: syn::Lit::Str(s), .. }) => {
-                let formatted_str = self.build_format(&s.value(), attr.span());
+                let key = if name == "message" { MessageKey::Primary } else { field_key() };
+                let formatted_str = self.build_format(&s.value(), attr.span(), key);
                 match name {
                     "message" => {
                         if type_matches_path(&info.ty, &["rustc_span", "Span"]) {
@@ -512,7 +591,13 @@ fn generate_non_option_field_code(
                                         .ident
                                         .to_string();
                                     let name = name.as_str();
-                                    let formatted_str = self.build_format(&s.value(), arg.span());
+                                    let key = if name == "message" {
+                                        field_key()
+                                    } else {
+                                        MessageKey::Untranslated
+                                    };
+                                    let formatted_str =
+                                        self.build_format(&s.value(), arg.span(), key);
                                     match name {
                                         "message" => {
                                             msg = Some(formatted_str);
@@ -573,8 +658,19 @@ fn generate_non_option_field_code(
     /// ```ignore (not-usage-example)
     /// format!("Expected a point greater than ({x}, {y})", x = self.x, y = self.y)
     /// ```
-    /// This function builds the entire call to format!.
-    fn build_format(&self, input: &str, span: proc_macro2::Span) -> proc_macro2::TokenStream {
+    /// This function builds the entire call to format!. If the diagnostic has a slug and `key` is
+    /// a message, the call to format! is only the fallback for when the message isn't translated:
+    /// ```ignore (not-usage-example)
+    /// let args = &[("x", format!("{}", &self.x)), ("y", format!("{}", &self.y))];
+    /// sess.translate("slug", None, args)
+    ///     .unwrap_or_else(|| format!("Expected a point ({x}, {y})", x = self.x, y = self.y))
+    /// ```
+    fn build_format(
+        &self,
+        input: &str,
+        span: proc_macro2::Span,
+        key: MessageKey,
+    ) -> proc_macro2::TokenStream {
         // This set is used later to generate the final format string. To keep builds reproducible,
         // the iteration order needs to be deterministic, hence why we use a BTreeSet here instead
         // of a HashSet.
@@ -619,6 +715,16 @@ fn build_format(&self, input: &str, span: proc_macro2::Span) -> proc_macro2::Tok
         // At this point, `referenced_fields` contains a set of the unique fields that were
         // referenced in the format string. Generate the corresponding "x = self.x" format
         // string parameters:
+        let translation_args = referenced_fields
+            .iter()
+            .filter(|field| self.fields.contains_key(*field))
+            .map(|field| {
+                let field_ident = format_ident!("{}", field);
+                quote! {
+                    (#field, format!("{}", &self.#field_ident))
+                }
+            })
+            .collect::<Vec<_>>();
         let args = referenced_fields.into_iter().map(|field: String| {
             let field_ident = format_ident!("{}", field);
             let value = if self.fields.contains_key(&field) {
@@ -641,8 +747,17 @@ fn build_format(&self, input: &str, span: proc_macro2::Span) -> proc_macro2::Tok
                 #field_ident = #value
             }
         });
-        quote! {
+        let english = quote! {
             format!(#input #(,#args)*)
+        };
+        let attr = match (&self.slug, key) {
+            (None, _) | (_, MessageKey::Untranslated) => return english,
+            (Some(_), MessageKey::Primary) => quote! { None },
+            (Some(_), MessageKey::Attribute(attr)) => quote! { Some(#attr) },
+        };
+        let (sess, slug) = (&self.sess, self.slug.as_ref().unwrap());
+        quote! {
+            #sess.translate(#slug, #attr, &[#(#translation_args),*]).unwrap_or_else(|| #english)
         }
     }
 }
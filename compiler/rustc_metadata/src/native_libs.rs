@@ -2,9 +2,10 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_errors::struct_span_err;
 use rustc_hir as hir;
+use rustc_hir::def::DefKind;
 use rustc_hir::itemlikevisit::ItemLikeVisitor;
 use rustc_middle::ty::{List, ParamEnv, ParamEnvAnd, Ty, TyCtxt};
-use rustc_session::cstore::{DllCallingConvention, DllImport, NativeLib};
+use rustc_session::cstore::{DllCallingConvention, DllImport, NativeLib, PeImportNameType};
 use rustc_session::parse::feature_err;
 use rustc_session::utils::NativeLibKind;
 use rustc_session::Session;
@@ -59,6 +60,7 @@ fn visit_item(&mut self, it: &'tcx hir::Item<'tcx>) {
                 dll_imports: Vec::new(),
             };
             let mut kind_specified = false;
+            let mut import_name_type = None;
 
             for item in items.iter() {
                 if item.has_name(sym::kind) {
@@ -120,6 +122,35 @@ fn visit_item(&mut self, it: &'tcx hir::Item<'tcx>) {
                             sess.span_err(item.span(), msg);
                         }
                     }
+                } else if item.has_name(sym::import_name_type) {
+                    if import_name_type.is_some() {
+                        sess.span_err(
+                            item.span(),
+                            "multiple `import_name_type` arguments in a single `#[link]` attribute",
+                        );
+                        continue;
+                    }
+                    import_name_type = match item.value_str().map(|s| s.as_str()) {
+                        Some("decorated") => Some((PeImportNameType::Decorated, item.span())),
+                        Some("noprefix") => Some((PeImportNameType::NoPrefix, item.span())),
+                        Some("undecorated") => Some((PeImportNameType::Undecorated, item.span())),
+                        Some(value) => {
+                            sess.span_err(
+                                item.span(),
+                                &format!(
+                                    "unknown import name type `{}`, expected one of: \
+                                    decorated, noprefix, undecorated",
+                                    value
+                                ),
+                            );
+                            continue;
+                        }
+                        None => {
+                            let msg = "must be of the form `#[link(import_name_type = \"...\")]`";
+                            sess.span_err(item.span(), msg);
+                            continue;
+                        }
+                    };
                 } else {
                     // currently, like past compilers, ignore unknown
                     // directives here.
@@ -206,11 +237,23 @@ fn visit_item(&mut self, it: &'tcx hir::Item<'tcx>) {
                 .emit();
             }
 
+            if let Some((_, span)) = import_name_type {
+                if lib.kind != NativeLibKind::RawDylib {
+                    sess.span_err(
+                        span,
+                        "import name type can only be used with link kind `raw-dylib`",
+                    );
+                } else if sess.target.arch != "x86" {
+                    sess.span_err(span, "import name type is only supported on x86");
+                }
+            }
+            let import_name_type = import_name_type.map(|(import_name_type, _)| import_name_type);
+
             if lib.kind == NativeLibKind::RawDylib {
                 lib.dll_imports.extend(
                     foreign_mod_items
                         .iter()
-                        .map(|child_item| self.build_dll_import(abi, child_item)),
+                        .map(|child_item| self.build_dll_import(abi, import_name_type, child_item)),
                 );
             }
 
@@ -401,8 +444,17 @@ fn i686_arg_list_size(&self, item: &hir::ForeignItemRef) -> usize {
             .sum()
     }
 
-    fn build_dll_import(&self, abi: Abi, item: &hir::ForeignItemRef) -> DllImport {
-        let calling_convention = if self.tcx.sess.target.arch == "x86" {
+    fn build_dll_import(
+        &self,
+        abi: Abi,
+        import_name_type: Option<PeImportNameType>,
+        item: &hir::ForeignItemRef,
+    ) -> DllImport {
+        let is_fn = self.tcx.def_kind(item.id.def_id) == DefKind::Fn;
+        let calling_convention = if !is_fn {
+            // Statics don't have a calling convention, and are never decorated with one.
+            DllCallingConvention::C
+        } else if self.tcx.sess.target.arch == "x86" {
             match abi {
                 Abi::C { .. } | Abi::Cdecl { .. } => DllCallingConvention::C,
                 Abi::Stdcall { .. } | Abi::System { .. } => {
@@ -431,11 +483,14 @@ fn build_dll_import(&self, abi: Abi, item: &hir::ForeignItemRef) -> DllImport {
             }
         };
 
+        let codegen_fn_attrs = self.tcx.codegen_fn_attrs(item.id.def_id);
         DllImport {
-            name: item.ident.name,
-            ordinal: self.tcx.codegen_fn_attrs(item.id.def_id).link_ordinal,
+            name: codegen_fn_attrs.link_name.unwrap_or(item.ident.name),
+            ordinal: codegen_fn_attrs.link_ordinal,
             calling_convention,
             span: item.span,
+            is_fn,
+            import_name_type,
         }
     }
 }
@@ -1,6 +1,7 @@
 //! Registering limits:
 //! * recursion_limit,
 //! * move_size_limit,
+//! * future_size_limit,
 //! * type_length_limit, and
 //! * const_eval_limit
 //!
@@ -27,17 +28,26 @@ pub fn provide(providers: &mut ty::query::Providers) {
             sym::move_size_limit,
             tcx.sess.opts.debugging_opts.move_size_limit.unwrap_or(0),
         ),
+        future_size_limit: get_limit(
+            tcx.hir().krate_attrs(),
+            tcx.sess,
+            sym::future_size_limit,
+            tcx.sess.opts.debugging_opts.future_size_limit.unwrap_or(0),
+        ),
         type_length_limit: get_limit(
             tcx.hir().krate_attrs(),
             tcx.sess,
             sym::type_length_limit,
             1048576,
         ),
-        const_eval_limit: get_limit(
-            tcx.hir().krate_attrs(),
-            tcx.sess,
-            sym::const_eval_limit,
-            1_000_000,
+        // The command line takes precedence over the crate attribute, so that the limit of a
+        // dependency can be raised without editing it.
+        const_eval_limit: match tcx.sess.opts.debugging_opts.const_eval_limit {
+            Some(limit) => Limit::new(limit),
+            None => get_limit(tcx.hir().krate_attrs(), tcx.sess, sym::const_eval_limit, 1_000_000),
+        },
+        const_eval_memory_limit: Limit::new(
+            tcx.sess.opts.debugging_opts.const_eval_memory_limit.unwrap_or(0),
         ),
     }
 }
@@ -1,7 +1,7 @@
 use super::{AllocId, ConstAlloc, Pointer, Scalar};
 
 use crate::mir::interpret::ConstValue;
-use crate::ty::{layout, query::TyCtxtAt, tls, FnSig, Ty};
+use crate::ty::{layout, query::TyCtxtAt, tls, FnSig, Ty, ValTree};
 
 use rustc_data_structures::sync::Lock;
 use rustc_errors::{pluralize, struct_span_err, DiagnosticBuilder, ErrorReported};
@@ -35,6 +35,9 @@ fn from(err: ErrorReported) -> ErrorHandled {
 
 pub type EvalToAllocationRawResult<'tcx> = Result<ConstAlloc<'tcx>, ErrorHandled>;
 pub type EvalToConstValueResult<'tcx> = Result<ConstValue<'tcx>, ErrorHandled>;
+/// `Ok(None)` means that the constant was evaluated successfully, but that its value can't be
+/// represented as a valtree, e.g. because it contains raw pointers.
+pub type EvalToValTreeResult<'tcx> = Result<Option<ValTree<'tcx>>, ErrorHandled>;
 
 pub fn struct_error<'tcx>(tcx: TyCtxtAt<'tcx>, msg: &str) -> DiagnosticBuilder<'tcx> {
     struct_span_err!(tcx.sess, tcx.span, E0080, "{}", msg)
@@ -448,8 +451,12 @@ pub enum ResourceExhaustionInfo {
     StackFrameLimitReached,
     /// The program ran for too long.
     ///
-    /// The exact limit is set by the `const_eval_limit` attribute.
+    /// The exact limit is set by the `const_eval_limit` attribute or `-Zconst-eval-limit`.
     StepLimitReached,
+    /// The program allocated more memory than it is allowed to have at once.
+    ///
+    /// The exact limit is set by `-Zconst-eval-memory-limit`.
+    MemoryLimitReached,
     /// There is not enough memory to perform an allocation.
     MemoryExhausted,
 }
@@ -464,6 +471,9 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             StepLimitReached => {
                 write!(f, "exceeded interpreter step limit (see `#[const_eval_limit]`)")
             }
+            MemoryLimitReached => {
+                write!(f, "exceeded interpreter memory limit (see `-Zconst-eval-memory-limit`)")
+            }
             MemoryExhausted => {
                 write!(f, "tried to allocate more memory than available to compiler")
             }
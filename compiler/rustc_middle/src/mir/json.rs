@@ -0,0 +1,430 @@
+//! Dumps MIR as JSON, for `-Z dump-mir-format=json`.
+//!
+//! The schema is documented in the unstable book, in the chapter on `dump-mir-format`. Statements
+//! are described down to their places, rvalues and operands, and the structure of the body
+//! (blocks, successors, scopes) is explicit so that tools don't need to parse it out of the text.
+//! Types, regions and constants are strings in the syntax of the textual MIR.
+
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, TyCtxt};
+use rustc_serialize::json::{Json, Object, ToJson};
+use rustc_span::Span;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+/// The version of the schema, increased on every incompatible change.
+const SCHEMA_VERSION: u64 = 1;
+
+/// Writes the JSON representation of `body`, as dumped by `pass_name`.
+pub fn write_mir_fn_json<'tcx, W>(
+    tcx: TyCtxt<'tcx>,
+    pass_num: Option<&dyn Display>,
+    pass_name: &str,
+    disambiguator: &dyn Display,
+    body: &Body<'tcx>,
+    w: &mut W,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let def_path = ty::print::with_forced_impl_filename_line(|| {
+        // see notes on #41697 in `pretty.rs`
+        tcx.def_path_str(body.source.def_id())
+    });
+
+    let mut root = Object::new();
+    root.insert("version".into(), SCHEMA_VERSION.to_json());
+    root.insert("def_path".into(), def_path.to_json());
+    root.insert("promoted".into(), body.source.promoted.map(|p| p.index()).to_json());
+    root.insert("pass".into(), pass_name.to_json());
+    root.insert("pass_num".into(), pass_num.map(|n| n.to_string()).to_json());
+    root.insert("disambiguator".into(), disambiguator.to_string().to_json());
+    root.insert("phase".into(), format!("{:?}", body.phase).to_json());
+    root.insert("arg_count".into(), body.arg_count.to_json());
+    root.insert("locals".into(), locals_json(tcx, body));
+    root.insert("source_scopes".into(), source_scopes_json(tcx, body));
+    root.insert("var_debug_info".into(), var_debug_info_json(tcx, body));
+    root.insert("basic_blocks".into(), basic_blocks_json(tcx, body));
+
+    writeln!(w, "{}", Json::Object(root).pretty())
+}
+
+fn locals_json<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> Json {
+    let locals = body.local_decls.iter_enumerated().map(|(local, decl)| {
+        let mut object = Object::new();
+        object.insert("local".into(), local.index().to_json());
+        object.insert("kind".into(), format!("{:?}", body.local_kind(local)).to_json());
+        object.insert("ty".into(), decl.ty.to_string().to_json());
+        object.insert("mutable".into(), (decl.mutability == Mutability::Mut).to_json());
+        object.insert("source_info".into(), source_info_json(tcx, decl.source_info));
+        Json::Object(object)
+    });
+    Json::Array(locals.collect())
+}
+
+fn source_scopes_json<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> Json {
+    let scopes = body.source_scopes.iter_enumerated().map(|(scope, data)| {
+        let mut object = Object::new();
+        object.insert("scope".into(), scope.index().to_json());
+        object.insert("parent".into(), data.parent_scope.map(|p| p.index()).to_json());
+        object.insert("span".into(), span_json(tcx, data.span));
+        let inlined = data.inlined.map(|(callee, call_site)| {
+            let mut inlined = Object::new();
+            inlined.insert("callee".into(), callee.to_string().to_json());
+            inlined.insert("call_site".into(), span_json(tcx, call_site));
+            Json::Object(inlined)
+        });
+        object.insert("inlined".into(), inlined.to_json());
+        Json::Object(object)
+    });
+    Json::Array(scopes.collect())
+}
+
+fn var_debug_info_json<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> Json {
+    let var_debug_info = body.var_debug_info.iter().map(|var_debug_info| {
+        let mut object = Object::new();
+        object.insert("name".into(), var_debug_info.name.to_string().to_json());
+        object.insert("value".into(), var_debug_info_contents_json(&var_debug_info.value));
+        object.insert("source_info".into(), source_info_json(tcx, var_debug_info.source_info));
+        Json::Object(object)
+    });
+    Json::Array(var_debug_info.collect())
+}
+
+fn basic_blocks_json<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> Json {
+    let blocks = body.basic_blocks().iter_enumerated().map(|(block, data)| {
+        let statements = data.statements.iter().map(|statement| {
+            let mut object = Object::new();
+            object.insert("kind".into(), statement_kind_name(&statement.kind).to_json());
+            statement_kind_json(tcx, &statement.kind, &mut object);
+            object.insert("source_info".into(), source_info_json(tcx, statement.source_info));
+            Json::Object(object)
+        });
+
+        let mut object = Object::new();
+        object.insert("block".into(), block.index().to_json());
+        object.insert("is_cleanup".into(), data.is_cleanup.to_json());
+        object.insert("statements".into(), Json::Array(statements.collect()));
+        object.insert("terminator".into(), terminator_json(tcx, data.terminator()));
+        Json::Object(object)
+    });
+    Json::Array(blocks.collect())
+}
+
+fn var_debug_info_contents_json(contents: &VarDebugInfoContents<'_>) -> Json {
+    let mut object = Object::new();
+    match contents {
+        VarDebugInfoContents::Place(place) => {
+            object.insert("kind".into(), "Place".to_json());
+            object.insert("place".into(), place_json(*place));
+        }
+        VarDebugInfoContents::Const(constant) => {
+            object.insert("kind".into(), "Const".to_json());
+            object.insert("constant".into(), constant_json(constant));
+        }
+        VarDebugInfoContents::Composite { ty, fragments } => {
+            object.insert("kind".into(), "Composite".to_json());
+            object.insert("ty".into(), ty.to_string().to_json());
+            let fragments = fragments.iter().map(|fragment| {
+                let mut object = Object::new();
+                object.insert("projection".into(), projection_json(&fragment.projection));
+                object.insert("place".into(), place_json(fragment.contents));
+                Json::Object(object)
+            });
+            object.insert("fragments".into(), Json::Array(fragments.collect()));
+        }
+    }
+    Json::Object(object)
+}
+
+/// Adds the fields of `kind` to the statement `object`.
+fn statement_kind_json<'tcx>(tcx: TyCtxt<'tcx>, kind: &StatementKind<'tcx>, object: &mut Object) {
+    match kind {
+        StatementKind::Assign(box (place, rvalue)) => {
+            object.insert("place".into(), place_json(*place));
+            object.insert("rvalue".into(), rvalue_json(tcx, rvalue));
+        }
+        StatementKind::FakeRead(box (cause, place)) => {
+            let cause = match cause {
+                FakeReadCause::ForMatchGuard => "ForMatchGuard",
+                FakeReadCause::ForMatchedPlace(_) => "ForMatchedPlace",
+                FakeReadCause::ForGuardBinding => "ForGuardBinding",
+                FakeReadCause::ForLet(_) => "ForLet",
+                FakeReadCause::ForIndex => "ForIndex",
+            };
+            object.insert("cause".into(), cause.to_json());
+            object.insert("place".into(), place_json(*place));
+        }
+        StatementKind::SetDiscriminant { place, variant_index } => {
+            object.insert("place".into(), place_json(**place));
+            object.insert("variant".into(), variant_index.index().to_json());
+        }
+        StatementKind::StorageLive(local) | StatementKind::StorageDead(local) => {
+            object.insert("local".into(), local.index().to_json());
+        }
+        StatementKind::Retag(retag_kind, place) => {
+            object.insert("retag_kind".into(), format!("{:?}", retag_kind).to_json());
+            object.insert("place".into(), place_json(**place));
+        }
+        StatementKind::AscribeUserType(box (place, user_ty), variance) => {
+            object.insert("place".into(), place_json(*place));
+            object.insert("user_ty".into(), user_ty.base.index().to_json());
+            object.insert("variance".into(), format!("{:?}", variance).to_json());
+        }
+        StatementKind::Coverage(coverage) => {
+            object.insert("coverage".into(), format!("{:?}", coverage.kind).to_json());
+        }
+        StatementKind::CopyNonOverlapping(box CopyNonOverlapping { src, dst, count }) => {
+            object.insert("src".into(), operand_json(src));
+            object.insert("dst".into(), operand_json(dst));
+            object.insert("count".into(), operand_json(count));
+        }
+        StatementKind::Nop => {}
+    }
+}
+
+fn rvalue_json<'tcx>(tcx: TyCtxt<'tcx>, rvalue: &Rvalue<'tcx>) -> Json {
+    let mut object = Object::new();
+    let kind = match rvalue {
+        Rvalue::Use(operand) => {
+            object.insert("operand".into(), operand_json(operand));
+            "Use"
+        }
+        Rvalue::Repeat(operand, count) => {
+            object.insert("operand".into(), operand_json(operand));
+            object.insert("count".into(), count.to_string().to_json());
+            "Repeat"
+        }
+        Rvalue::Ref(region, borrow_kind, place) => {
+            let borrow_kind = match borrow_kind {
+                BorrowKind::Shared => "Shared",
+                BorrowKind::Shallow => "Shallow",
+                BorrowKind::Unique => "Unique",
+                BorrowKind::Mut { .. } => "Mut",
+            };
+            object.insert("region".into(), region.to_string().to_json());
+            object.insert("borrow_kind".into(), borrow_kind.to_json());
+            object.insert("place".into(), place_json(*place));
+            "Ref"
+        }
+        Rvalue::ThreadLocalRef(def_id) => {
+            object.insert("def_path".into(), tcx.def_path_str(*def_id).to_json());
+            "ThreadLocalRef"
+        }
+        Rvalue::AddressOf(mutability, place) => {
+            object.insert("mutable".into(), (*mutability == Mutability::Mut).to_json());
+            object.insert("place".into(), place_json(*place));
+            "AddressOf"
+        }
+        Rvalue::Len(place) => {
+            object.insert("place".into(), place_json(*place));
+            "Len"
+        }
+        Rvalue::Cast(cast_kind, operand, ty) => {
+            object.insert("cast_kind".into(), format!("{:?}", cast_kind).to_json());
+            object.insert("operand".into(), operand_json(operand));
+            object.insert("ty".into(), ty.to_string().to_json());
+            "Cast"
+        }
+        Rvalue::BinaryOp(op, box (lhs, rhs)) | Rvalue::CheckedBinaryOp(op, box (lhs, rhs)) => {
+            object.insert("op".into(), format!("{:?}", op).to_json());
+            object.insert("lhs".into(), operand_json(lhs));
+            object.insert("rhs".into(), operand_json(rhs));
+            if let Rvalue::BinaryOp(..) = rvalue { "BinaryOp" } else { "CheckedBinaryOp" }
+        }
+        Rvalue::NullaryOp(op, ty) => {
+            object.insert("op".into(), format!("{:?}", op).to_json());
+            object.insert("ty".into(), ty.to_string().to_json());
+            "NullaryOp"
+        }
+        Rvalue::UnaryOp(op, operand) => {
+            object.insert("op".into(), format!("{:?}", op).to_json());
+            object.insert("operand".into(), operand_json(operand));
+            "UnaryOp"
+        }
+        Rvalue::Discriminant(place) => {
+            object.insert("place".into(), place_json(*place));
+            "Discriminant"
+        }
+        Rvalue::Aggregate(aggregate_kind, operands) => {
+            object.insert("aggregate".into(), aggregate_kind_json(tcx, aggregate_kind));
+            let operands = operands.iter().map(operand_json);
+            object.insert("operands".into(), Json::Array(operands.collect()));
+            "Aggregate"
+        }
+        Rvalue::ShallowInitBox(operand, ty) => {
+            object.insert("operand".into(), operand_json(operand));
+            object.insert("ty".into(), ty.to_string().to_json());
+            "ShallowInitBox"
+        }
+        Rvalue::CopyForDeref(place) => {
+            object.insert("place".into(), place_json(*place));
+            "CopyForDeref"
+        }
+    };
+    object.insert("kind".into(), kind.to_json());
+    Json::Object(object)
+}
+
+fn aggregate_kind_json<'tcx>(tcx: TyCtxt<'tcx>, aggregate_kind: &AggregateKind<'tcx>) -> Json {
+    let mut object = Object::new();
+    let kind = match *aggregate_kind {
+        AggregateKind::Array(ty) => {
+            object.insert("ty".into(), ty.to_string().to_json());
+            "Array"
+        }
+        AggregateKind::Tuple => "Tuple",
+        AggregateKind::Adt(def_id, variant, _, _, active_field) => {
+            object.insert("def_path".into(), tcx.def_path_str(def_id).to_json());
+            object.insert("variant".into(), variant.index().to_json());
+            object.insert("active_field".into(), active_field.to_json());
+            "Adt"
+        }
+        AggregateKind::Closure(def_id, _) => {
+            object.insert("def_path".into(), tcx.def_path_str(def_id).to_json());
+            "Closure"
+        }
+        AggregateKind::Generator(def_id, _, _) => {
+            object.insert("def_path".into(), tcx.def_path_str(def_id).to_json());
+            "Generator"
+        }
+    };
+    object.insert("kind".into(), kind.to_json());
+    Json::Object(object)
+}
+
+fn operand_json(operand: &Operand<'_>) -> Json {
+    let mut object = Object::new();
+    match operand {
+        Operand::Copy(place) | Operand::Move(place) => {
+            let kind = if let Operand::Copy(_) = operand { "Copy" } else { "Move" };
+            object.insert("kind".into(), kind.to_json());
+            object.insert("place".into(), place_json(*place));
+        }
+        Operand::Constant(constant) => {
+            object.insert("kind".into(), "Constant".to_json());
+            object.insert("constant".into(), constant_json(constant));
+        }
+    }
+    Json::Object(object)
+}
+
+fn constant_json(constant: &Constant<'_>) -> Json {
+    let mut object = Object::new();
+    object.insert("ty".into(), constant.ty().to_string().to_json());
+    object.insert("text".into(), format!("{:?}", constant).to_json());
+    Json::Object(object)
+}
+
+fn place_json(place: Place<'_>) -> Json {
+    let mut object = Object::new();
+    object.insert("local".into(), place.local.index().to_json());
+    object.insert("projection".into(), projection_json(&place.projection));
+    Json::Object(object)
+}
+
+fn projection_json(projection: &[PlaceElem<'_>]) -> Json {
+    let elems = projection.iter().map(|elem| {
+        let mut object = Object::new();
+        let kind = match *elem {
+            ProjectionElem::Deref => "Deref",
+            ProjectionElem::Field(field, ty) => {
+                object.insert("field".into(), field.index().to_json());
+                object.insert("ty".into(), ty.to_string().to_json());
+                "Field"
+            }
+            ProjectionElem::Index(local) => {
+                object.insert("local".into(), local.index().to_json());
+                "Index"
+            }
+            ProjectionElem::ConstantIndex { offset, min_length, from_end } => {
+                object.insert("offset".into(), offset.to_json());
+                object.insert("min_length".into(), min_length.to_json());
+                object.insert("from_end".into(), from_end.to_json());
+                "ConstantIndex"
+            }
+            ProjectionElem::Subslice { from, to, from_end } => {
+                object.insert("from".into(), from.to_json());
+                object.insert("to".into(), to.to_json());
+                object.insert("from_end".into(), from_end.to_json());
+                "Subslice"
+            }
+            ProjectionElem::Downcast(name, variant) => {
+                object.insert("variant".into(), variant.index().to_json());
+                object.insert("name".into(), name.map(|name| name.to_string()).to_json());
+                "Downcast"
+            }
+        };
+        object.insert("kind".into(), kind.to_json());
+        Json::Object(object)
+    });
+    Json::Array(elems.collect())
+}
+
+fn terminator_json<'tcx>(tcx: TyCtxt<'tcx>, terminator: &Terminator<'tcx>) -> Json {
+    let mut head = String::new();
+    // Writing to a `String` can't fail.
+    terminator.kind.fmt_head(&mut head).unwrap();
+
+    let labels = terminator.kind.fmt_successor_labels();
+    let successors = terminator.successors().zip(labels).map(|(&target, label)| {
+        let mut successor = Object::new();
+        successor.insert("target".into(), target.index().to_json());
+        successor.insert("label".into(), label.as_ref().to_json());
+        Json::Object(successor)
+    });
+
+    let mut object = Object::new();
+    object.insert("kind".into(), terminator_kind_name(&terminator.kind).to_json());
+    object.insert("text".into(), head.to_json());
+    object.insert("successors".into(), Json::Array(successors.collect()));
+    object.insert("source_info".into(), source_info_json(tcx, terminator.source_info));
+    Json::Object(object)
+}
+
+fn source_info_json(tcx: TyCtxt<'_>, SourceInfo { span, scope }: SourceInfo) -> Json {
+    let mut object = Object::new();
+    object.insert("scope".into(), scope.index().to_json());
+    object.insert("span".into(), span_json(tcx, span));
+    Json::Object(object)
+}
+
+fn span_json(tcx: TyCtxt<'_>, span: Span) -> Json {
+    tcx.sess.source_map().span_to_embeddable_string(span).to_json()
+}
+
+fn statement_kind_name(kind: &StatementKind<'_>) -> &'static str {
+    match kind {
+        StatementKind::Assign(..) => "Assign",
+        StatementKind::FakeRead(..) => "FakeRead",
+        StatementKind::SetDiscriminant { .. } => "SetDiscriminant",
+        StatementKind::StorageLive(..) => "StorageLive",
+        StatementKind::StorageDead(..) => "StorageDead",
+        StatementKind::Retag(..) => "Retag",
+        StatementKind::AscribeUserType(..) => "AscribeUserType",
+        StatementKind::Coverage(..) => "Coverage",
+        StatementKind::CopyNonOverlapping(..) => "CopyNonOverlapping",
+        StatementKind::Nop => "Nop",
+    }
+}
+
+fn terminator_kind_name(kind: &TerminatorKind<'_>) -> &'static str {
+    match kind {
+        TerminatorKind::Goto { .. } => "Goto",
+        TerminatorKind::SwitchInt { .. } => "SwitchInt",
+        TerminatorKind::Resume => "Resume",
+        TerminatorKind::Abort => "Abort",
+        TerminatorKind::Return => "Return",
+        TerminatorKind::Unreachable => "Unreachable",
+        TerminatorKind::Drop { .. } => "Drop",
+        TerminatorKind::DropAndReplace { .. } => "DropAndReplace",
+        TerminatorKind::Call { .. } => "Call",
+        TerminatorKind::TailCall { .. } => "TailCall",
+        TerminatorKind::Assert { .. } => "Assert",
+        TerminatorKind::Yield { .. } => "Yield",
+        TerminatorKind::GeneratorDrop => "GeneratorDrop",
+        TerminatorKind::FalseEdge { .. } => "FalseEdge",
+        TerminatorKind::FalseUnwind { .. } => "FalseUnwind",
+        TerminatorKind::InlineAsm { .. } => "InlineAsm",
+    }
+}
@@ -51,6 +51,7 @@
 mod graph_cyclic_cache;
 pub mod graphviz;
 pub mod interpret;
+pub mod json;
 pub mod mono;
 pub mod patch;
 mod predecessors;
@@ -148,10 +149,14 @@ pub enum MirPhase {
     /// * `Drop` now uses explicit drop flags visible in the MIR and reaching a `Drop` terminator
     ///   means that the auto-generated drop glue will be invoked.
     DropLowering = 3,
+    /// After this phase, places only dereference their base local, e.g. `(*_1).0` is fine but
+    /// `(*(_1.0)).1` has been split into a temporary holding `_1.0`. Only the places of the
+    /// debuginfo may still contain a non-leading `Deref`.
+    Derefered = 4,
     /// After this phase, generators are explicit state machines (no more `Yield`).
     /// `AggregateKind::Generator` is gone for good.
-    GeneratorLowering = 4,
-    Optimization = 5,
+    GeneratorLowering = 5,
+    Optimization = 6,
 }
 
 impl MirPhase {
@@ -223,6 +228,10 @@ pub struct Body<'tcx> {
     /// us to see the difference and forego optimization on the inlined promoted items.
     pub phase: MirPhase,
 
+    /// The phase in whose dialect this MIR was written, if it was written by hand with
+    /// `#[custom_mir]`. The passes of the phases before it are skipped, see `Body::should_skip`.
+    pub injection_phase: Option<MirPhase>,
+
     pub source: MirSource<'tcx>,
 
     /// A list of source scopes; these are referenced by statements
@@ -308,6 +317,7 @@ pub fn new(
 
         let mut body = Body {
             phase: MirPhase::Build,
+            injection_phase: None,
             source,
             basic_blocks,
             source_scopes,
@@ -342,6 +352,7 @@ pub fn new(
     pub fn new_cfg_only(basic_blocks: IndexVec<BasicBlock, BasicBlockData<'tcx>>) -> Self {
         let mut body = Body {
             phase: MirPhase::Build,
+            injection_phase: None,
             source: MirSource::item(DefId::local(CRATE_DEF_INDEX)),
             basic_blocks,
             source_scopes: IndexVec::new(),
@@ -361,6 +372,16 @@ pub fn new_cfg_only(basic_blocks: IndexVec<BasicBlock, BasicBlockData<'tcx>>) ->
         body
     }
 
+    /// Returns `true` if this MIR was written with `#[custom_mir]` in the dialect of a later phase
+    /// than the current one, in which case the passes transforming it to that phase, and the
+    /// analyses of the current phase, must not run on it.
+    pub fn should_skip(&self) -> bool {
+        match self.injection_phase {
+            Some(injection_phase) => injection_phase > self.phase,
+            None => false,
+        }
+    }
+
     #[inline]
     pub fn basic_blocks(&self) -> &IndexVec<BasicBlock, BasicBlockData<'tcx>> {
         &self.basic_blocks
@@ -1153,6 +1174,9 @@ pub enum VarDebugInfoContents<'tcx> {
     /// based on a `Local`, not a `Static`, and contains no indexing.
     Place(Place<'tcx>),
     Const(Constant<'tcx>),
+    /// The user variable's data is split across several fragments,
+    /// each of which is found in a different place.
+    Composite { ty: Ty<'tcx>, fragments: Vec<VarDebugInfoFragment<'tcx>> },
 }
 
 impl<'tcx> Debug for VarDebugInfoContents<'tcx> {
@@ -1160,7 +1184,44 @@ fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
         match self {
             VarDebugInfoContents::Const(c) => write!(fmt, "{}", c),
             VarDebugInfoContents::Place(p) => write!(fmt, "{:?}", p),
+            VarDebugInfoContents::Composite { ty, fragments } => {
+                write!(fmt, "{:?}{{ ", ty)?;
+                for f in fragments.iter() {
+                    write!(fmt, "{:?}, ", f)?;
+                }
+                write!(fmt, "}}")
+            }
+        }
+    }
+}
+
+/// A part of a user variable, see `VarDebugInfoContents::Composite`.
+#[derive(Clone, TyEncodable, TyDecodable, HashStable, TypeFoldable)]
+pub struct VarDebugInfoFragment<'tcx> {
+    /// Where in the composite user variable this fragment is,
+    /// represented as a "projection" into the composite variable.
+    /// At lower levels, this corresponds to a byte/bit range.
+    ///
+    /// This can only contain `PlaceElem::Field`.
+    pub projection: Vec<PlaceElem<'tcx>>,
+
+    /// Where the data for this fragment can be found.
+    /// This `Place` must not be a `Composite`.
+    pub contents: Place<'tcx>,
+}
+
+impl Debug for VarDebugInfoFragment<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for elem in self.projection.iter() {
+            match elem {
+                ProjectionElem::Field(field, _) => {
+                    write!(fmt, ".{:?}", field.index())?;
+                }
+                _ => bug!("unsupported fragment projection `{:?}`", elem),
+            }
         }
+
+        write!(fmt, " => {:?}", self.contents)
     }
 }
 
@@ -2109,7 +2170,7 @@ pub fn function_handle(
         Operand::Constant(Box::new(Constant {
             span,
             user_ty: None,
-            literal: ConstantKind::Ty(ty::Const::zero_sized(tcx, ty)),
+            literal: ConstantKind::zero_sized(ty),
         }))
     }
 
@@ -2223,6 +2284,13 @@ pub enum Rvalue<'tcx> {
     /// This is different a normal transmute because dataflow analysis will treat the box
     /// as initialized but its content as uninitialized.
     ShallowInitBox(Operand<'tcx>, Ty<'tcx>),
+
+    /// A copy of a pointer or `Box` which is only made to be dereferenced.
+    ///
+    /// This behaves the same as `Use(Operand::Copy(place))`, except that the copied value need not
+    /// be `Copy`, since the copy is only used as the base of a place. The `Derefer` pass introduces
+    /// these, so that the places of the MIR only dereference a local directly.
+    CopyForDeref(Place<'tcx>),
 }
 
 #[cfg(all(target_arch = "x86_64", target_pointer_width = "64"))]
@@ -2305,6 +2373,13 @@ pub fn is_checkable(self) -> bool {
         use self::BinOp::*;
         matches!(self, Add | Sub | Mul | Shl | Shr)
     }
+
+    /// Whether `a op b` always evaluates to the same value as `b op a`,
+    /// including whether the evaluation overflows.
+    pub fn is_commutative(self) -> bool {
+        use self::BinOp::*;
+        matches!(self, Add | Mul | BitXor | BitAnd | BitOr | Eq | Ne)
+    }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, TyEncodable, TyDecodable, Hash, HashStable)]
@@ -2479,6 +2554,8 @@ fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
             ShallowInitBox(ref place, ref ty) => {
                 write!(fmt, "ShallowInitBox({:?}, {:?})", place, ty)
             }
+
+            CopyForDeref(ref place) => write!(fmt, "deref_copy {:?}", place),
         }
     }
 }
@@ -2516,7 +2593,7 @@ pub enum ConstantKind<'tcx> {
 
 impl<'tcx> Constant<'tcx> {
     pub fn check_static_ptr(&self, tcx: TyCtxt<'_>) -> Option<DefId> {
-        match self.literal.const_for_ty()?.val.try_to_scalar() {
+        match self.literal.try_to_scalar() {
             Some(Scalar::Ptr(ptr, _size)) => match tcx.global_alloc(ptr.provenance) {
                 GlobalAlloc::Static(def_id) => {
                     assert!(!tcx.is_thread_local_static(def_id));
@@ -2557,21 +2634,27 @@ pub fn ty(&self) -> Ty<'tcx> {
     }
 
     #[inline]
-    pub fn try_to_value(self) -> Option<interpret::ConstValue<'tcx>> {
+    pub fn try_to_value(self, tcx: TyCtxt<'tcx>) -> Option<interpret::ConstValue<'tcx>> {
         match self {
-            ConstantKind::Ty(c) => c.val.try_to_value(),
+            ConstantKind::Ty(c) => match c.val {
+                ty::ConstKind::Value(valtree) => Some(tcx.valtree_to_const_val((c.ty, valtree))),
+                _ => None,
+            },
             ConstantKind::Val(val, _) => Some(val),
         }
     }
 
     #[inline]
     pub fn try_to_scalar(self) -> Option<Scalar> {
-        self.try_to_value()?.try_to_scalar()
+        match self {
+            ConstantKind::Ty(c) => c.val.try_to_scalar(),
+            ConstantKind::Val(val, _) => val.try_to_scalar(),
+        }
     }
 
     #[inline]
     pub fn try_to_scalar_int(self) -> Option<ScalarInt> {
-        Some(self.try_to_value()?.try_to_scalar()?.assert_int())
+        Some(self.try_to_scalar()?.assert_int())
     }
 
     #[inline]
@@ -2584,6 +2667,26 @@ pub fn try_to_bool(self) -> Option<bool> {
         self.try_to_scalar_int()?.try_into().ok()
     }
 
+    #[inline]
+    /// Tries to evaluate the constant if it is an unevaluated type-level constant. Unlike
+    /// `ty::Const::eval`, this also evaluates constants whose value can't be represented as a
+    /// valtree, since MIR constants don't have to be valtrees.
+    pub fn eval(self, tcx: TyCtxt<'tcx>, param_env: ty::ParamEnv<'tcx>) -> Self {
+        match self {
+            Self::Ty(c) => {
+                if let Some(val) = c.val.try_eval_for_mir(tcx, param_env) {
+                    match val {
+                        Ok(val) => Self::Val(val, c.ty),
+                        Err(_) => Self::Ty(tcx.const_error(self.ty())),
+                    }
+                } else {
+                    self
+                }
+            }
+            Self::Val(_, _) => self,
+        }
+    }
+
     #[inline]
     pub fn try_eval_bits(
         &self,
@@ -2617,6 +2720,63 @@ pub fn try_eval_usize(&self, tcx: TyCtxt<'tcx>, param_env: ty::ParamEnv<'tcx>) -
             Self::Val(val, _) => val.try_to_machine_usize(tcx),
         }
     }
+
+    #[inline]
+    /// Panics if the value cannot be evaluated or doesn't contain a valid integer of the given type.
+    pub fn eval_bits(self, tcx: TyCtxt<'tcx>, param_env: ty::ParamEnv<'tcx>, ty: Ty<'tcx>) -> u128 {
+        self.try_eval_bits(tcx, param_env, ty)
+            .unwrap_or_else(|| bug!("expected bits of {:#?}, got {:#?}", ty, self))
+    }
+
+    #[inline]
+    /// Panics if the value cannot be evaluated or doesn't contain a valid `usize`.
+    pub fn eval_usize(self, tcx: TyCtxt<'tcx>, param_env: ty::ParamEnv<'tcx>) -> u64 {
+        self.try_eval_usize(tcx, param_env)
+            .unwrap_or_else(|| bug!("expected usize, got {:#?}", self))
+    }
+
+    pub fn from_value(val: interpret::ConstValue<'tcx>, ty: Ty<'tcx>) -> Self {
+        Self::Val(val, ty)
+    }
+
+    pub fn from_bits(
+        tcx: TyCtxt<'tcx>,
+        bits: u128,
+        param_env_ty: ty::ParamEnvAnd<'tcx, Ty<'tcx>>,
+    ) -> Self {
+        let size = tcx
+            .layout_of(param_env_ty)
+            .unwrap_or_else(|e| {
+                bug!("could not compute layout for {:?}: {:?}", param_env_ty.value, e)
+            })
+            .size;
+        let cv = interpret::ConstValue::Scalar(Scalar::from_uint(bits, size));
+
+        Self::Val(cv, param_env_ty.value)
+    }
+
+    #[inline]
+    pub fn from_bool(tcx: TyCtxt<'tcx>, v: bool) -> Self {
+        let cv = interpret::ConstValue::from_bool(v);
+        Self::Val(cv, tcx.types.bool)
+    }
+
+    #[inline]
+    pub fn zero_sized(ty: Ty<'tcx>) -> Self {
+        let cv = interpret::ConstValue::Scalar(Scalar::ZST);
+        Self::Val(cv, ty)
+    }
+
+    pub fn from_usize(tcx: TyCtxt<'tcx>, n: u64) -> Self {
+        let ty = tcx.types.usize;
+        Self::from_bits(tcx, n as u128, ty::ParamEnv::empty().and(ty))
+    }
+
+    #[inline]
+    pub fn from_scalar(s: Scalar, ty: Ty<'tcx>) -> Self {
+        let val = interpret::ConstValue::Scalar(s);
+        Self::Val(val, ty)
+    }
 }
 
 /// A collection of projections into user types.
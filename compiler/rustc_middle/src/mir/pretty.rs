@@ -6,6 +6,7 @@
 use std::path::{Path, PathBuf};
 
 use super::graphviz::write_mir_fn_graphviz;
+use super::json::write_mir_fn_json;
 use super::spanview::write_mir_fn_spanview;
 use either::Either;
 use rustc_data_structures::fx::FxHashMap;
@@ -17,9 +18,9 @@
 use rustc_middle::mir::visit::Visitor;
 use rustc_middle::mir::MirSource;
 use rustc_middle::mir::*;
-use rustc_middle::ty::{self, TyCtxt, TyS, TypeFoldable, TypeVisitor};
+use rustc_middle::ty::{self, TyCtxt, TyS};
+use rustc_session::config::MirDumpFormat;
 use rustc_target::abi::Size;
-use std::ops::ControlFlow;
 
 const INDENT: &str = "    ";
 /// Alignment for lining up comments following MIR statements
@@ -122,28 +123,36 @@ fn dump_matched_mir_node<'tcx, F>(
 ) where
     F: FnMut(PassWhere, &mut dyn Write) -> io::Result<()>,
 {
-    let _: io::Result<()> = try {
-        let mut file =
-            create_dump_file(tcx, "mir", pass_num, pass_name, disambiguator, body.source)?;
-        let def_path = ty::print::with_forced_impl_filename_line(|| {
-            // see notes on #41697 above
-            tcx.def_path_str(body.source.def_id())
-        });
-        write!(file, "// MIR for `{}", def_path)?;
-        match body.source.promoted {
-            None => write!(file, "`")?,
-            Some(promoted) => write!(file, "::{:?}`", promoted)?,
-        }
-        writeln!(file, " {} {}", disambiguator, pass_name)?;
-        if let Some(ref layout) = body.generator_layout() {
-            writeln!(file, "/* generator_layout = {:#?} */", layout)?;
-        }
-        writeln!(file)?;
-        extra_data(PassWhere::BeforeCFG, &mut file)?;
-        write_user_type_annotations(tcx, body, &mut file)?;
-        write_mir_fn(tcx, body, &mut extra_data, &mut file)?;
-        extra_data(PassWhere::AfterCFG, &mut file)?;
-    };
+    if tcx.sess.opts.debugging_opts.dump_mir_format == MirDumpFormat::Json {
+        let _: io::Result<()> = try {
+            let mut file =
+                create_dump_file(tcx, "json", pass_num, pass_name, disambiguator, body.source)?;
+            write_mir_fn_json(tcx, pass_num, pass_name, disambiguator, body, &mut file)?;
+        };
+    } else {
+        let _: io::Result<()> = try {
+            let mut file =
+                create_dump_file(tcx, "mir", pass_num, pass_name, disambiguator, body.source)?;
+            let def_path = ty::print::with_forced_impl_filename_line(|| {
+                // see notes on #41697 above
+                tcx.def_path_str(body.source.def_id())
+            });
+            write!(file, "// MIR for `{}", def_path)?;
+            match body.source.promoted {
+                None => write!(file, "`")?,
+                Some(promoted) => write!(file, "::{:?}`", promoted)?,
+            }
+            writeln!(file, " {} {}", disambiguator, pass_name)?;
+            if let Some(ref layout) = body.generator_layout() {
+                writeln!(file, "/* generator_layout = {:#?} */", layout)?;
+            }
+            writeln!(file)?;
+            extra_data(PassWhere::BeforeCFG, &mut file)?;
+            write_user_type_annotations(tcx, body, &mut file)?;
+            write_mir_fn(tcx, body, &mut extra_data, &mut file)?;
+            extra_data(PassWhere::AfterCFG, &mut file)?;
+        };
+    }
 
     if tcx.sess.opts.debugging_opts.dump_mir_graphviz {
         let _: io::Result<()> = try {
@@ -682,16 +691,16 @@ fn alloc_ids_from_const(val: ConstValue<'_>) -> impl Iterator<Item = AllocId> +
         }
     }
     struct CollectAllocIds(BTreeSet<AllocId>);
-    impl<'tcx> TypeVisitor<'tcx> for CollectAllocIds {
-        fn visit_const(&mut self, c: &'tcx ty::Const<'tcx>) -> ControlFlow<Self::BreakTy> {
-            if let ty::ConstKind::Value(val) = c.val {
+    impl<'tcx> Visitor<'tcx> for CollectAllocIds {
+        fn visit_constant(&mut self, c: &Constant<'tcx>, _: Location) {
+            // Type-level constants are valtrees, which don't refer to any allocations.
+            if let ConstantKind::Val(val, _) = c.literal {
                 self.0.extend(alloc_ids_from_const(val));
             }
-            c.super_visit_with(self)
         }
     }
     let mut visitor = CollectAllocIds(Default::default());
-    body.visit_with(&mut visitor);
+    visitor.visit_body(body);
     // `seen` contains all seen allocations, including the ones we have *not* printed yet.
     // The protocol is to first `insert` into `seen`, and only if that returns `true`
     // then push to `todo`.
@@ -265,6 +265,7 @@ pub fn terminator_kind_name(term: &Terminator<'_>) -> &'static str {
         Drop { .. } => "Drop",
         DropAndReplace { .. } => "DropAndReplace",
         Call { .. } => "Call",
+        TailCall { .. } => "TailCall",
         Assert { .. } => "Assert",
         Yield { .. } => "Yield",
         GeneratorDrop => "GeneratorDrop",
@@ -4,8 +4,8 @@
 use smallvec::{smallvec, SmallVec};
 
 use super::{
-    AssertMessage, BasicBlock, InlineAsmOperand, Operand, Place, SourceInfo, Successors,
-    SuccessorsMut,
+    AssertMessage, BasicBlock, ConstantKind, InlineAsmOperand, Operand, Place, SourceInfo,
+    Successors, SuccessorsMut,
 };
 pub use rustc_ast::Mutability;
 use rustc_macros::HashStable;
@@ -199,6 +199,24 @@ pub enum TerminatorKind<'tcx> {
         fn_span: Span,
     },
 
+    /// Tail calls a function: all the locals of the current function, except the ones used
+    /// as arguments, are dropped and deallocated, and control is transferred to the callee,
+    /// whose return value is directly returned to the caller of the current function.
+    ///
+    /// The callee must have the same signature and calling convention as the current
+    /// function. This only comes from `become` expressions, see the `explicit_tail_calls`
+    /// feature.
+    TailCall {
+        /// The function that’s being called.
+        func: Operand<'tcx>,
+        /// Arguments the function is called with.
+        /// These are owned by the callee, which is free to modify them.
+        args: Vec<Operand<'tcx>>,
+        /// This `Span` is the span of the function, without the dot and receiver
+        /// (e.g. `foo(a, b)` in `x.foo(a, b)`
+        fn_span: Span,
+    },
+
     /// Jump to the target if the condition has the expected value,
     /// otherwise panic with a message and a cleanup target.
     Assert {
@@ -318,6 +336,7 @@ pub fn successors(&self) -> Successors<'_> {
             | Abort
             | GeneratorDrop
             | Return
+            | TailCall { .. }
             | Unreachable
             | Call { destination: None, cleanup: None, .. }
             | InlineAsm { destination: None, cleanup: None, .. } => None.into_iter().chain(&[]),
@@ -356,6 +375,7 @@ pub fn successors_mut(&mut self) -> SuccessorsMut<'_> {
             | Abort
             | GeneratorDrop
             | Return
+            | TailCall { .. }
             | Unreachable
             | Call { destination: None, cleanup: None, .. }
             | InlineAsm { destination: None, cleanup: None, .. } => None.into_iter().chain(&mut []),
@@ -393,6 +413,7 @@ pub fn unwind(&self) -> Option<&Option<BasicBlock>> {
             | TerminatorKind::Resume
             | TerminatorKind::Abort
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::GeneratorDrop
             | TerminatorKind::Yield { .. }
@@ -413,6 +434,7 @@ pub fn unwind_mut(&mut self) -> Option<&mut Option<BasicBlock>> {
             | TerminatorKind::Resume
             | TerminatorKind::Abort
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::GeneratorDrop
             | TerminatorKind::Yield { .. }
@@ -502,6 +524,16 @@ pub fn fmt_head<W: Write>(&self, fmt: &mut W) -> fmt::Result {
                 }
                 write!(fmt, ")")
             }
+            TailCall { func, args, .. } => {
+                write!(fmt, "tailcall {:?}(", func)?;
+                for (index, arg) in args.iter().enumerate() {
+                    if index > 0 {
+                        write!(fmt, ", ")?;
+                    }
+                    write!(fmt, "{:?}", arg)?;
+                }
+                write!(fmt, ")")
+            }
             Assert { cond, expected, msg, .. } => {
                 write!(fmt, "assert(")?;
                 if !expected {
@@ -566,7 +598,7 @@ pub fn fmt_head<W: Write>(&self, fmt: &mut W) -> fmt::Result {
     pub fn fmt_successor_labels(&self) -> Vec<Cow<'static, str>> {
         use self::TerminatorKind::*;
         match *self {
-            Return | Resume | Abort | Unreachable | GeneratorDrop => vec![],
+            Return | TailCall { .. } | Resume | Abort | Unreachable | GeneratorDrop => vec![],
             Goto { .. } => vec!["".into()],
             SwitchInt { ref targets, switch_ty, .. } => ty::tls::with(|tcx| {
                 let param_env = ty::ParamEnv::empty();
@@ -576,7 +608,7 @@ pub fn fmt_successor_labels(&self) -> Vec<Cow<'static, str>> {
                     .values
                     .iter()
                     .map(|&u| {
-                        ty::Const::from_scalar(tcx, Scalar::from_uint(u, size), switch_ty)
+                        ConstantKind::from_scalar(Scalar::from_uint(u, size), switch_ty)
                             .to_string()
                             .into()
                     })
@@ -58,6 +58,11 @@ fn try_super_fold_with<F: FallibleTypeFolder<'tcx>>(
                     fn_span,
                 }
             }
+            TailCall { func, args, fn_span } => TailCall {
+                func: func.try_fold_with(folder)?,
+                args: args.try_fold_with(folder)?,
+                fn_span,
+            },
             Assert { cond, expected, msg, target, cleanup } => {
                 use AssertKind::*;
                 let msg = match msg {
@@ -119,6 +124,10 @@ fn super_visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> ControlFlow
                 func.visit_with(visitor)?;
                 args.visit_with(visitor)
             }
+            TailCall { ref func, ref args, .. } => {
+                func.visit_with(visitor)?;
+                args.visit_with(visitor)
+            }
             Assert { ref cond, ref msg, .. } => {
                 cond.visit_with(visitor)?;
                 use AssertKind::*;
@@ -241,6 +250,7 @@ fn try_super_fold_with<F: FallibleTypeFolder<'tcx>>(
             ShallowInitBox(op, ty) => {
                 ShallowInitBox(op.try_fold_with(folder)?, ty.try_fold_with(folder)?)
             }
+            CopyForDeref(place) => CopyForDeref(place.try_fold_with(folder)?),
         })
     }
 
@@ -290,6 +300,7 @@ fn super_visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> ControlFlow
                 op.visit_with(visitor)?;
                 ty.visit_with(visitor)
             }
+            CopyForDeref(ref place) => place.visit_with(visitor),
         }
     }
 }
@@ -914,29 +914,46 @@
         cache_on_disk_if { true }
     }
 
-    /// Convert an evaluated constant to a type level constant or
-    /// return `None` if that is not possible.
-    query const_to_valtree(
-        key: ty::ParamEnvAnd<'tcx, ConstAlloc<'tcx>>
-    ) -> Option<ty::ValTree<'tcx>> {
-        desc { "destructure constant" }
-        remap_env_constness
+    /// Evaluates const items or anonymous constants into a representation suitable for the type
+    /// system and const generics: a valtree, or `None` if the value can't be represented as one.
+    ///
+    /// **Do not use this** directly, use `tcx.const_eval_resolve_for_typeck` or
+    /// `tcx.const_eval_global_id_for_typeck`.
+    query eval_to_valtree(
+        key: ty::ParamEnvAnd<'tcx, GlobalId<'tcx>>
+    ) -> EvalToValTreeResult<'tcx> {
+        desc { "evaluating type-level constant" }
+        cache_on_disk_if { true }
     }
 
-    /// Destructure a constant ADT or array into its variant index and its
+    /// Converts a type level constant value into `ConstValue`
+    query valtree_to_const_val(key: (Ty<'tcx>, ty::ValTree<'tcx>)) -> ConstValue<'tcx> {
+        desc { "convert type-level constant value to mir constant value" }
+    }
+
+    /// Destructure a type-level constant ADT or array into its variant index and its
     /// field values.
     query destructure_const(
         key: ty::ParamEnvAnd<'tcx, &'tcx ty::Const<'tcx>>
     ) -> mir::DestructuredConst<'tcx> {
-        desc { "destructure constant" }
+        desc { "destructure type level constant" }
+        remap_env_constness
+    }
+
+    /// Destructure a mir constant ADT or array into its variant index and its
+    /// field values.
+    query destructure_mir_constant(
+        key: ty::ParamEnvAnd<'tcx, mir::ConstantKind<'tcx>>
+    ) -> mir::DestructuredMirConstant<'tcx> {
+        desc { "destructure mir constant" }
         remap_env_constness
     }
 
     /// Dereference a constant reference or raw pointer and turn the result into a constant
     /// again.
-    query deref_const(
-        key: ty::ParamEnvAnd<'tcx, &'tcx ty::Const<'tcx>>
-    ) -> &'tcx ty::Const<'tcx> {
+    query deref_mir_constant(
+        key: ty::ParamEnvAnd<'tcx, mir::ConstantKind<'tcx>>
+    ) -> mir::ConstantKind<'tcx> {
         desc { "deref constant" }
         remap_env_constness
     }
@@ -951,6 +968,12 @@
         desc { "converting literal to const" }
     }
 
+    query lit_to_mir_constant(
+        key: LitToConstInput<'tcx>
+    ) -> Result<mir::ConstantKind<'tcx>, LitToConstError> {
+        desc { "converting literal to mir constant" }
+    }
+
     query check_match(key: DefId) {
         desc { |tcx| "match-checking `{}`", tcx.def_path_str(key) }
         cache_on_disk_if { key.is_local() }
@@ -1455,9 +1478,8 @@
     query is_statically_included_foreign_item(def_id: DefId) -> bool {
         desc { |tcx| "is_statically_included_foreign_item({})", tcx.def_path_str(def_id) }
     }
-    query native_library_kind(def_id: DefId)
-        -> Option<NativeLibKind> {
-        desc { |tcx| "native_library_kind({})", tcx.def_path_str(def_id) }
+    query native_library(def_id: DefId) -> Option<&'tcx NativeLib> {
+        desc { |tcx| "getting the native library for `{}`", tcx.def_path_str(def_id) }
     }
 
     /// Does lifetime resolution, but does not descend into trait items. This
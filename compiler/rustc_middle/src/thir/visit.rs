@@ -1,6 +1,7 @@
 use super::{
     Arm, Block, Expr, ExprKind, Guard, InlineAsmOperand, Pat, PatKind, Stmt, StmtKind, Thir,
 };
+use rustc_middle::mir::ConstantKind;
 use rustc_middle::ty::Const;
 
 pub trait Visitor<'a, 'tcx: 'a>: Sized {
@@ -27,6 +28,8 @@ fn visit_pat(&mut self, pat: &Pat<'tcx>) {
     }
 
     fn visit_const(&mut self, _cnst: &'tcx Const<'tcx>) {}
+
+    fn visit_constant(&mut self, _constant: ConstantKind<'tcx>) {}
 }
 
 pub fn walk_expr<'a, 'tcx: 'a, V: Visitor<'a, 'tcx>>(visitor: &mut V, expr: &Expr<'tcx>) {
@@ -93,6 +96,7 @@ pub fn walk_expr<'a, 'tcx: 'a, V: Visitor<'a, 'tcx>>(visitor: &mut V, expr: &Exp
                 visitor.visit_expr(&visitor.thir()[value])
             }
         }
+        Become { value } => visitor.visit_expr(&visitor.thir()[value]),
         ConstBlock { value } => visitor.visit_const(value),
         Repeat { value, count } => {
             visitor.visit_expr(&visitor.thir()[value]);
@@ -122,8 +126,8 @@ pub fn walk_expr<'a, 'tcx: 'a, V: Visitor<'a, 'tcx>>(visitor: &mut V, expr: &Exp
             visitor.visit_expr(&visitor.thir()[source])
         }
         Closure { closure_id: _, substs: _, upvars: _, movability: _, fake_reads: _ } => {}
-        Literal { literal, user_ty: _, const_id: _ } => visitor.visit_const(literal),
-        StaticRef { literal, def_id: _ } => visitor.visit_const(literal),
+        Literal { ref literal, user_ty: _, const_id: _ } => visitor.visit_constant(**literal),
+        StaticRef { literal, def_id: _ } => visitor.visit_constant(literal),
         InlineAsm { ref operands, template: _, options: _, line_spans: _ } => {
             for op in &**operands {
                 use InlineAsmOperand::*;
@@ -209,10 +213,10 @@ pub fn walk_pat<'a, 'tcx: 'a, V: Visitor<'a, 'tcx>>(visitor: &mut V, pat: &Pat<'
                 visitor.visit_pat(&subpattern.pattern);
             }
         }
-        Constant { value } => visitor.visit_const(value),
+        Constant { value } => visitor.visit_constant(*value),
         Range(range) => {
-            visitor.visit_const(range.lo);
-            visitor.visit_const(range.hi);
+            visitor.visit_constant(range.lo);
+            visitor.visit_constant(range.hi);
         }
         Slice { prefix, slice, suffix } | Array { prefix, slice, suffix } => {
             for subpattern in prefix {
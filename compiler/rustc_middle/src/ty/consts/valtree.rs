@@ -1,4 +1,8 @@
+use std::convert::TryFrom;
+
 use super::ScalarInt;
+use crate::mir::interpret::{AllocId, Scalar};
+use crate::ty::{self, Ty, TyCtxt};
 use rustc_macros::HashStable;
 
 #[derive(Copy, Clone, Debug, Hash, TyEncodable, TyDecodable, Eq, PartialEq, Ord, PartialOrd)]
@@ -31,4 +35,61 @@ impl<'tcx> ValTree<'tcx> {
     pub fn zst() -> Self {
         Self::Branch(&[])
     }
+
+    #[inline]
+    pub fn unwrap_leaf(self) -> ScalarInt {
+        match self {
+            Self::Leaf(s) => s,
+            _ => bug!("expected leaf, got {:?}", self),
+        }
+    }
+
+    #[inline]
+    pub fn unwrap_branch(self) -> &'tcx [Self] {
+        match self {
+            Self::Branch(branch) => branch,
+            _ => bug!("expected branch, got {:?}", self),
+        }
+    }
+
+    /// Creates the valtree of a `&str` or `&[u8]` from its bytes: the reference itself is not part
+    /// of the valtree, only the value it points to.
+    pub fn from_raw_bytes(tcx: TyCtxt<'tcx>, bytes: &[u8]) -> Self {
+        let branches = bytes.iter().map(|&b| Self::Leaf(b.into()));
+        Self::Branch(tcx.arena.alloc_from_iter(branches))
+    }
+
+    pub fn from_scalar_int(i: ScalarInt) -> Self {
+        Self::Leaf(i)
+    }
+
+    pub fn try_to_scalar(self) -> Option<Scalar<AllocId>> {
+        self.try_to_scalar_int().map(Scalar::Int)
+    }
+
+    pub fn try_to_scalar_int(self) -> Option<ScalarInt> {
+        match self {
+            Self::Leaf(s) => Some(s),
+            Self::Branch(_) => None,
+        }
+    }
+
+    pub fn try_to_machine_usize(self, tcx: TyCtxt<'tcx>) -> Option<u64> {
+        self.try_to_scalar_int()?.try_to_machine_usize(tcx).ok()
+    }
+
+    /// Returns the bytes of a valtree of type `&str`, `&[u8]` or `&[u8; N]`, or `None` for any
+    /// other type.
+    pub fn try_to_raw_bytes(self, tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> Option<&'tcx [u8]> {
+        match ty.kind() {
+            ty::Ref(_, inner_ty, _) => match inner_ty.kind() {
+                ty::Str => {}
+                ty::Slice(elem_ty) | ty::Array(elem_ty, _) if *elem_ty == tcx.types.u8 => {}
+                _ => return None,
+            },
+            _ => return None,
+        }
+        let bytes = self.unwrap_branch().iter().map(|leaf| u8::try_from(leaf.unwrap_leaf()).unwrap());
+        Some(tcx.arena.alloc_from_iter(bytes))
+    }
 }
@@ -3,7 +3,7 @@
 use crate::ty::print::{FmtPrinter, Printer};
 use crate::ty::{self, BoundRegionKind, Region, Ty, TyCtxt};
 use rustc_errors::Applicability::{MachineApplicable, MaybeIncorrect};
-use rustc_errors::{pluralize, DiagnosticBuilder};
+use rustc_errors::{pluralize, shorten_long_type, DiagnosticBuilder};
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
 use rustc_span::symbol::{sym, Symbol};
@@ -12,6 +12,7 @@
 
 use std::borrow::Cow;
 use std::fmt;
+use std::path::PathBuf;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, TypeFoldable)]
 pub struct ExpectedFound<T> {
@@ -345,6 +346,19 @@ pub fn prefix_string(&self, tcx: TyCtxt<'_>) -> Cow<'static, str> {
 }
 
 impl<'tcx> TyCtxt<'tcx> {
+    /// Prints `value`, usually a type, for a diagnostic. With `-Z long-type-length`, a print that
+    /// is too long is shortened, and written in full to the returned file, which the diagnostic
+    /// points to with `note_long_type`.
+    pub fn short_string(self, value: impl fmt::Display) -> (String, Option<PathBuf>) {
+        let full = value.to_string();
+        match self.sess.opts.debugging_opts.long_type_length {
+            Some(max_len) => {
+                shorten_long_type(full, max_len, &self.output_filenames(()).out_directory)
+            }
+            None => (full, None),
+        }
+    }
+
     pub fn note_and_explain_type_err(
         self,
         db: &mut DiagnosticBuilder<'_>,
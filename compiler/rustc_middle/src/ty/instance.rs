@@ -629,7 +629,11 @@ fn polymorphize<'tcx>(
         None
     };
     let has_upvars = upvars_ty.map_or(false, |ty| ty.tuple_fields().count() > 0);
-    debug!("polymorphize: upvars_ty={:?} has_upvars={:?}", upvars_ty, has_upvars);
+    // The tupled upvars are always the last of the substs of a closure or generator. Find them by
+    // their index rather than their type, as a parameter of the parent can be substituted with
+    // the same type as the upvars.
+    let upvars_index = has_upvars.then(|| substs.len() - 1);
+    debug!("polymorphize: upvars_ty={:?} upvars_index={:?}", upvars_ty, upvars_index);
 
     struct PolymorphizationFolder<'tcx> {
         tcx: TyCtxt<'tcx>,
@@ -678,10 +682,8 @@ fn fold_ty(&mut self, ty: Ty<'tcx>) -> Ty<'tcx> {
         match param.kind {
             // Upvar case: If parameter is a type parameter..
             ty::GenericParamDefKind::Type { .. } if
-                // ..and has upvars..
-                has_upvars &&
-                // ..and this param has the same type as the tupled upvars..
-                upvars_ty == Some(substs[param.index as usize].expect_ty()) => {
+                // ..and is the tupled upvars..
+                upvars_index == Some(param.index as usize) => {
                     // ..then double-check that polymorphization marked it used..
                     debug_assert!(!is_unused);
                     // ..and polymorphize any closures/generators captured as upvars.
@@ -1,3 +1,4 @@
+use crate::mir;
 use crate::mir::interpret::{AllocRange, ConstValue, GlobalAlloc, Pointer, Provenance, Scalar};
 use crate::ty::subst::{GenericArg, GenericArgKind, Subst};
 use crate::ty::{self, ConstInt, DefIdTree, ParamConst, ScalarInt, Term, Ty, TyCtxt, TypeFoldable};
@@ -12,7 +13,7 @@
 use rustc_session::config::TrimmedDefPaths;
 use rustc_session::cstore::{ExternCrate, ExternCrateSource};
 use rustc_span::symbol::{kw, Ident, Symbol};
-use rustc_target::abi::Size;
+use rustc_target::abi::{Size, VariantIdx};
 use rustc_target::spec::abi::Abi;
 
 use std::cell::Cell;
@@ -1198,7 +1199,7 @@ macro_rules! print_underscore {
             ty::ConstKind::Infer(..) => print_underscore!(),
             ty::ConstKind::Param(ParamConst { name, .. }) => p!(write("{}", name)),
             ty::ConstKind::Value(value) => {
-                return self.pretty_print_const_value(value, ct.ty, print_ty);
+                return self.pretty_print_const_valtree(value, ct.ty, print_ty);
             }
 
             ty::ConstKind::Bound(debruijn, bound_var) => {
@@ -1239,9 +1240,7 @@ fn pretty_print_const_scalar_ptr(
                     kind:
                         ty::Array(
                             ty::TyS { kind: ty::Uint(ty::UintTy::U8), .. },
-                            ty::Const {
-                                val: ty::ConstKind::Value(ConstValue::Scalar(int)), ..
-                            },
+                            ty::Const { val: ty::ConstKind::Value(ty::ValTree::Leaf(int)), .. },
                         ),
                     ..
                 },
@@ -1383,6 +1382,128 @@ fn pretty_print_byte_str(mut self, byte_str: &'tcx [u8]) -> Result<Self::Const,
         Ok(self)
     }
 
+    /// Prints the fields of an array, tuple or ADT constant in construction syntax.
+    fn pretty_print_const_aggregate<T>(
+        mut self,
+        ty: Ty<'tcx>,
+        variant: Option<VariantIdx>,
+        fields: &[T],
+    ) -> Result<Self::Const, Self::Error>
+    where
+        T: Copy + Print<'tcx, Self, Output = Self, Error = Self::Error>,
+    {
+        define_scoped_cx!(self);
+
+        match *ty.kind() {
+            ty::Array(..) => {
+                p!("[", comma_sep(fields.iter().copied()), "]");
+            }
+            ty::Tuple(..) => {
+                p!("(", comma_sep(fields.iter().copied()));
+                if fields.len() == 1 {
+                    p!(",");
+                }
+                p!(")");
+            }
+            ty::Adt(def, _) if def.variants.is_empty() => {
+                self = self.typed_value(
+                    |mut this| {
+                        write!(this, "unreachable()")?;
+                        Ok(this)
+                    },
+                    |this| this.print_type(ty),
+                    ": ",
+                )?;
+            }
+            ty::Adt(def, substs) => {
+                let variant_idx = variant.expect("destructed const of adt without variant idx");
+                let variant_def = &def.variants[variant_idx];
+                p!(print_value_path(variant_def.def_id, substs));
+
+                match variant_def.ctor_kind {
+                    CtorKind::Const => {}
+                    CtorKind::Fn => {
+                        p!("(", comma_sep(fields.iter().copied()), ")");
+                    }
+                    CtorKind::Fictive => {
+                        p!(" {{ ");
+                        let mut first = true;
+                        for (field_def, field) in iter::zip(&variant_def.fields, fields) {
+                            if !first {
+                                p!(", ");
+                            }
+                            p!(write("{}: ", field_def.name), print(field));
+                            first = false;
+                        }
+                        p!(" }}");
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        Ok(self)
+    }
+
+    fn pretty_print_const_valtree(
+        mut self,
+        valtree: ty::ValTree<'tcx>,
+        ty: Ty<'tcx>,
+        print_ty: bool,
+    ) -> Result<Self::Const, Self::Error> {
+        define_scoped_cx!(self);
+
+        if self.tcx().sess.verbose() {
+            p!(write("ValTree({:?}: ", valtree), print(ty), ")");
+            return Ok(self);
+        }
+
+        match (valtree, ty.kind()) {
+            // Byte/string slices and byte string arrays, printed as (byte) string literals.
+            (ty::ValTree::Branch(_), ty::Ref(_, inner_ty, _))
+                if let Some(bytes) = valtree.try_to_raw_bytes(self.tcx(), ty) =>
+            {
+                if inner_ty.is_str() {
+                    let s = std::str::from_utf8(bytes).expect("non utf8 str in valtree");
+                    p!(write("{:?}", s));
+                } else {
+                    p!(pretty_print_byte_str(bytes));
+                }
+            }
+            // Other references are printed as a reference to their pointee, since a valtree
+            // has no addresses.
+            (ty::ValTree::Branch(_), ty::Ref(_, inner_ty, _)) => {
+                p!("&");
+                self = self.pretty_print_const_valtree(valtree, inner_ty, false)?;
+            }
+            // Aggregates, printed as array/tuple/struct/variant construction syntax.
+            //
+            // NB: the `has_param_types_or_consts` check ensures that we can use
+            // the `destructure_const` query with an empty `ty::ParamEnv` without
+            // introducing ICEs (e.g. via `layout_of`) from missing bounds.
+            (ty::ValTree::Branch(_), ty::Array(..) | ty::Tuple(..) | ty::Adt(..))
+                if !ty.has_param_types_or_consts() =>
+            {
+                let ct = self.tcx().mk_const(ty::Const { val: ty::ConstKind::Value(valtree), ty });
+                let contents = self.tcx().destructure_const(ty::ParamEnv::reveal_all().and(ct));
+                return self.pretty_print_const_aggregate(ty, contents.variant, contents.fields);
+            }
+            // For function type zsts just printing the path is enough
+            (ty::ValTree::Branch(_), ty::FnDef(d, s)) => p!(print_value_path(*d, s)),
+            (ty::ValTree::Leaf(leaf), _) => {
+                return self.pretty_print_const_scalar_int(leaf, ty, print_ty);
+            }
+            _ => {
+                // fallback
+                p!(write("{:?}", valtree));
+                if print_ty {
+                    p!(": ", print(ty));
+                }
+            }
+        }
+        Ok(self)
+    }
+
     fn pretty_print_const_value(
         mut self,
         ct: ConstValue<'tcx>,
@@ -1436,7 +1557,7 @@ fn pretty_print_const_value(
             // Aggregates, printed as array/tuple/struct/variant construction syntax.
             //
             // NB: the `has_param_types_or_consts` check ensures that we can use
-            // the `destructure_const` query with an empty `ty::ParamEnv` without
+            // the `destructure_mir_constant` query with an empty `ty::ParamEnv` without
             // introducing ICEs (e.g. via `layout_of`) from missing bounds.
             // E.g. `transmute([0usize; 2]): (u8, *mut T)` needs to know `T: Sized`
             // to be able to destructure the tuple into `(0u8, *mut T)
@@ -1444,62 +1565,10 @@ fn pretty_print_const_value(
             // FIXME(eddyb) for `--emit=mir`/`-Z dump-mir`, we should provide the
             // correct `ty::ParamEnv` to allow printing *all* constant values.
             (_, ty::Array(..) | ty::Tuple(..) | ty::Adt(..)) if !ty.has_param_types_or_consts() => {
-                let contents = self.tcx().destructure_const(
-                    ty::ParamEnv::reveal_all()
-                        .and(self.tcx().mk_const(ty::Const { val: ty::ConstKind::Value(ct), ty })),
+                let contents = self.tcx().destructure_mir_constant(
+                    ty::ParamEnv::reveal_all().and(mir::ConstantKind::Val(ct, ty)),
                 );
-                let fields = contents.fields.iter().copied();
-
-                match *ty.kind() {
-                    ty::Array(..) => {
-                        p!("[", comma_sep(fields), "]");
-                    }
-                    ty::Tuple(..) => {
-                        p!("(", comma_sep(fields));
-                        if contents.fields.len() == 1 {
-                            p!(",");
-                        }
-                        p!(")");
-                    }
-                    ty::Adt(def, _) if def.variants.is_empty() => {
-                        self = self.typed_value(
-                            |mut this| {
-                                write!(this, "unreachable()")?;
-                                Ok(this)
-                            },
-                            |this| this.print_type(ty),
-                            ": ",
-                        )?;
-                    }
-                    ty::Adt(def, substs) => {
-                        let variant_idx =
-                            contents.variant.expect("destructed const of adt without variant idx");
-                        let variant_def = &def.variants[variant_idx];
-                        p!(print_value_path(variant_def.def_id, substs));
-
-                        match variant_def.ctor_kind {
-                            CtorKind::Const => {}
-                            CtorKind::Fn => {
-                                p!("(", comma_sep(fields), ")");
-                            }
-                            CtorKind::Fictive => {
-                                p!(" {{ ");
-                                let mut first = true;
-                                for (field_def, field) in iter::zip(&variant_def.fields, fields) {
-                                    if !first {
-                                        p!(", ");
-                                    }
-                                    p!(write("{}: ", field_def.name), print(field));
-                                    first = false;
-                                }
-                                p!(" }}");
-                            }
-                        }
-                    }
-                    _ => unreachable!(),
-                }
-
-                Ok(self)
+                self.pretty_print_const_aggregate(ty, contents.variant, contents.fields)
             }
 
             (ConstValue::Scalar(scalar), _) => self.pretty_print_const_scalar(scalar, ty, print_ty),
@@ -2366,6 +2435,17 @@ fn print(&$self, $cx: P) -> Result<Self::Output, Self::Error> {
     };
 }
 
+impl<'tcx, P: PrettyPrinter<'tcx>> Print<'tcx, P> for mir::ConstantKind<'tcx> {
+    type Output = P;
+    type Error = fmt::Error;
+    fn print(&self, cx: P) -> Result<Self::Output, Self::Error> {
+        match *self {
+            mir::ConstantKind::Ty(c) => cx.print_const(c),
+            mir::ConstantKind::Val(val, ty) => cx.pretty_print_const_value(val, ty, true),
+        }
+    }
+}
+
 // HACK(eddyb) this is separate because `ty::RegionKind` doesn't need lifting.
 impl fmt::Display for ty::RegionKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
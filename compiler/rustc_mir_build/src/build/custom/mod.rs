@@ -0,0 +1,180 @@
+//! Provides the implementation of the `custom_mir` attribute.
+//!
+//! Up until MIR building, this attribute has absolutely no effect. The `mir!` macro is a normal
+//! decl macro that expands like any other, and the code goes through parsing, name resolution and
+//! type checking like all other code. In MIR building we finally detect whether this attribute is
+//! present, and if so we branch off into this module, which implements the attribute by
+//! implementing a custom lowering from THIR to MIR.
+//!
+//! The result of this lowering is returned "normally" from the `mir_built` query, with the only
+//! notable difference being that the `injection_phase` field is set on the body. The passes of the
+//! phases before it are skipped, as is the borrow checker if the MIR was written in a dialect that
+//! comes after it, see `Body::should_skip`.
+//!
+//! This file defines the general framework for the custom parsing. The parsing for all the
+//! "top-level" constructs can be found in the `parse` submodule, while the parsing for statements,
+//! terminators, and everything below can be found in the `parse::instruction` submodule.
+
+use rustc_ast::Attribute;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
+use rustc_index::vec::IndexVec;
+use rustc_middle::mir::*;
+use rustc_middle::thir::*;
+use rustc_middle::ty::{ParamEnv, Ty, TyCtxt};
+use rustc_span::symbol::sym;
+use rustc_span::Span;
+
+use super::ArgInfo;
+
+mod parse;
+
+pub(super) fn build_custom_mir<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    did: DefId,
+    hir_id: hir::HirId,
+    thir: &Thir<'tcx>,
+    expr: ExprId,
+    arguments: &[ArgInfo<'tcx>],
+    safety: Safety,
+    return_ty: Ty<'tcx>,
+    return_ty_span: Span,
+    span: Span,
+    attr: &Attribute,
+) -> Body<'tcx> {
+    let injection_phase = parse_attribute(tcx, attr);
+
+    let source_scopes = IndexVec::from_elem_n(
+        SourceScopeData {
+            span,
+            parent_scope: None,
+            inlined: None,
+            inlined_parent_scope: None,
+            local_data: ClearCrossCrate::Set(SourceScopeLocalData { lint_root: hir_id, safety }),
+        },
+        1,
+    );
+
+    let mut pctxt = ParseCtxt {
+        tcx,
+        param_env: tcx.param_env(did),
+        thir,
+        source_info: SourceInfo::outermost(span),
+        local_decls: IndexVec::from_elem_n(LocalDecl::new(return_ty, return_ty_span), 1),
+        basic_blocks: IndexVec::new(),
+        local_map: FxHashMap::default(),
+        block_map: FxHashMap::default(),
+    };
+
+    // The arguments are the locals after the return place. Map the variables they bind, so that
+    // the body can refer to them.
+    for &ArgInfo(ty, ty_span, param, _) in arguments {
+        let local = pctxt.local_decls.push(LocalDecl::new(ty, ty_span.unwrap_or(span)));
+        if let Some(param) = param {
+            if let hir::PatKind::Binding(_, var, ..) = param.pat.kind {
+                pctxt.local_map.insert(var, local);
+            }
+        }
+    }
+
+    if let Err(err) = pctxt.parse_body(expr) {
+        tcx.sess
+            .struct_span_fatal(err.span, &format!("could not parse {}", err.expected))
+            .note(&format!("found {}", err.item_description))
+            .emit();
+        rustc_errors::FatalError.raise();
+    }
+
+    let ParseCtxt { local_decls, basic_blocks, .. } = pctxt;
+    let mut body = Body::new(
+        MirSource::item(did),
+        basic_blocks,
+        source_scopes,
+        local_decls,
+        IndexVec::new(),
+        arguments.len(),
+        vec![],
+        span,
+        None,
+    );
+    body.injection_phase = Some(injection_phase);
+    body
+}
+
+/// Parses the `dialect` and `phase` of a `#[custom_mir]` attribute into the phase the MIR is
+/// written in.
+fn parse_attribute(tcx: TyCtxt<'_>, attr: &Attribute) -> MirPhase {
+    let mut dialect = None;
+    let mut phase = None;
+
+    for nested in attr.meta_item_list().unwrap_or_default() {
+        let value = nested.value_str();
+        match (nested.name_or_empty(), value) {
+            (sym::dialect, Some(value)) => dialect = Some(value),
+            (sym::phase, Some(value)) => phase = Some(value),
+            _ => tcx.sess.span_fatal(
+                nested.span(),
+                "expected `dialect = \"...\"` or `phase = \"...\"` in `#[custom_mir]`",
+            ),
+        }
+    }
+
+    let Some(dialect) = dialect else {
+        tcx.sess.span_fatal(attr.span, "`#[custom_mir]` requires a `dialect`")
+    };
+    let phase = phase.map(|phase| phase.as_str());
+    match (&*dialect.as_str(), phase.as_deref()) {
+        ("built", None) => MirPhase::Build,
+        ("analysis", None | Some("initial")) => MirPhase::ConstPromotion,
+        ("runtime", None | Some("initial")) => MirPhase::DropLowering,
+        ("runtime", Some("optimized")) => MirPhase::Optimization,
+        _ => tcx.sess.span_fatal(attr.span, "unknown `dialect` or `phase` in `#[custom_mir]`"),
+    }
+}
+
+struct ParseCtxt<'tcx, 'body> {
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    thir: &'body Thir<'tcx>,
+    source_info: SourceInfo,
+
+    local_decls: LocalDecls<'tcx>,
+    basic_blocks: IndexVec<BasicBlock, BasicBlockData<'tcx>>,
+
+    /// The locals and basic blocks that the variables of the `mir!` macro stand for.
+    local_map: FxHashMap<hir::HirId, Local>,
+    block_map: FxHashMap<hir::HirId, BasicBlock>,
+}
+
+struct ParseError {
+    span: Span,
+    item_description: String,
+    expected: String,
+}
+
+impl<'tcx, 'body> ParseCtxt<'tcx, 'body> {
+    fn expr_error(&self, expr: ExprId, expected: &'static str) -> ParseError {
+        let expr = &self.thir[expr];
+        ParseError {
+            span: expr.span,
+            item_description: format!("{:?}", expr.kind),
+            expected: expected.to_string(),
+        }
+    }
+
+    fn stmt_error(&self, stmt: StmtId, expected: &'static str) -> ParseError {
+        let stmt = &self.thir[stmt];
+        let span = match stmt.kind {
+            StmtKind::Expr { expr, .. } => self.thir[expr].span,
+            StmtKind::Let { ref pattern, .. } => pattern.span,
+        };
+        ParseError {
+            span,
+            item_description: format!("{:?}", stmt.kind),
+            expected: expected.to_string(),
+        }
+    }
+}
+
+type PResult<T> = Result<T, ParseError>;
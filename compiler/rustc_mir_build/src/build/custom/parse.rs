@@ -0,0 +1,175 @@
+//! Parsing of the top-level constructs of the `mir!` macro: the basic block and local
+//! declarations, and the basic blocks themselves.
+//!
+//! The macro expands to
+//!
+//! ```ignore (illustrative)
+//! {
+//!     let bb1: BasicBlock;
+//!     let bb2: BasicBlock;
+//!     {
+//!         let RET;
+//!         let local1;
+//!         let local2;
+//!         {
+//!             { /* entry block */ };
+//!             bb1 = { /* bb1 */ };
+//!             bb2 = { /* bb2 */ };
+//!             RET
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! where the entry block is not actually surrounded by braces, its statements and terminator are
+//! directly part of the innermost block.
+
+use rustc_hir::HirId;
+use rustc_middle::mir::*;
+use rustc_middle::thir::*;
+use rustc_middle::ty::Ty;
+use rustc_span::Span;
+
+use super::{PResult, ParseCtxt, ParseError};
+
+mod instruction;
+
+impl<'tcx, 'body> ParseCtxt<'tcx, 'body> {
+    /// Parses the body of the function, which must consist of just a `mir!` invocation.
+    pub(super) fn parse_body(&mut self, expr: ExprId) -> PResult<()> {
+        let body = self.parse_block_expr(expr, "the function body")?;
+        let Some(mir) = body.expr.filter(|_| body.stmts.is_empty()) else {
+            return Err(self.expr_error(expr, "a single `mir!` invocation"));
+        };
+
+        // The block declarations.
+        let blocks = self.parse_block_expr(mir, "basic block declarations")?;
+        self.basic_blocks.push(BasicBlockData::new(None));
+        for stmt in blocks.stmts.iter() {
+            let var = self.parse_let(*stmt)?.0;
+            let block = self.basic_blocks.push(BasicBlockData::new(None));
+            self.block_map.insert(var, block);
+        }
+        let Some(locals) = blocks.expr else {
+            return Err(self.expr_error(mir, "local declarations"));
+        };
+
+        // The local declarations, starting with the return place.
+        let locals = self.parse_block_expr(locals, "local declarations")?;
+        let Some((ret, locals_stmts)) = locals.stmts.split_first() else {
+            return Err(self.expr_error(mir, "the declaration of `RET`"));
+        };
+        let ret = self.parse_let(*ret)?.0;
+        self.local_map.insert(ret, RETURN_PLACE);
+        for stmt in locals_stmts.iter() {
+            let (var, ty, span) = self.parse_let(*stmt)?;
+            let local = self.local_decls.push(LocalDecl::new(ty, span));
+            self.local_map.insert(var, local);
+        }
+        let Some(contents) = locals.expr else {
+            return Err(self.expr_error(mir, "basic blocks"));
+        };
+
+        // The basic blocks. The entry block is everything up to the first assignment to a basic
+        // block variable.
+        let contents = self.parse_block_expr(contents, "basic blocks")?;
+        let entry_len = contents
+            .stmts
+            .iter()
+            .position(|stmt| self.block_def(*stmt).is_some())
+            .unwrap_or(contents.stmts.len());
+        let (entry, defs) = contents.stmts.split_at(entry_len);
+        self.basic_blocks[START_BLOCK] = self.parse_block_def(entry, None, contents.span)?;
+        for stmt in defs.iter() {
+            let Some((block, rhs)) = self.block_def(*stmt) else {
+                return Err(self.stmt_error(*stmt, "a basic block definition"));
+            };
+            let def = self.parse_block_expr(rhs, "basic block")?;
+            self.basic_blocks[block] = self.parse_block_def(&def.stmts, def.expr, def.span)?;
+        }
+
+        Ok(())
+    }
+
+    /// Parses a basic block, made of `stmts` followed by the terminator, which is `terminator` or
+    /// else the last statement.
+    fn parse_block_def(
+        &self,
+        stmts: &[StmtId],
+        terminator: Option<ExprId>,
+        span: Span,
+    ) -> PResult<BasicBlockData<'tcx>> {
+        let (stmts, terminator) = match terminator {
+            Some(terminator) => (stmts, terminator),
+            None => match stmts.split_last() {
+                Some((&last, stmts)) => match self.thir[last].kind {
+                    StmtKind::Expr { expr, .. } => (stmts, expr),
+                    StmtKind::Let { .. } => return Err(self.stmt_error(last, "terminator")),
+                },
+                None => {
+                    return Err(ParseError {
+                        span,
+                        item_description: "an empty block".to_string(),
+                        expected: "terminator".to_string(),
+                    });
+                }
+            },
+        };
+
+        let mut data = BasicBlockData::new(None);
+        for stmt in stmts.iter() {
+            let (kind, span) = self.parse_statement(*stmt)?;
+            data.statements.push(Statement { source_info: self.source_info(span), kind });
+        }
+        let kind = self.parse_terminator(terminator)?;
+        let source_info = self.source_info(self.thir[terminator].span);
+        data.terminator = Some(Terminator { source_info, kind });
+        Ok(data)
+    }
+
+    /// Returns the basic block and the definition of a `bb = { ... };` statement.
+    fn block_def(&self, stmt: StmtId) -> Option<(BasicBlock, ExprId)> {
+        let StmtKind::Expr { expr, .. } = self.thir[stmt].kind else { return None };
+        let ExprKind::Assign { lhs, rhs } = self.thir[self.preparse(expr)].kind else {
+            return None;
+        };
+        let ExprKind::VarRef { id } = self.thir[self.preparse(lhs)].kind else { return None };
+        self.block_map.get(&id).map(|&block| (block, rhs))
+    }
+
+    /// Parses a `let` statement without initializer, returning the variable, its type and its
+    /// span.
+    fn parse_let(&self, stmt: StmtId) -> PResult<(HirId, Ty<'tcx>, Span)> {
+        let StmtKind::Let { ref pattern, initializer: None, .. } = self.thir[stmt].kind else {
+            return Err(self.stmt_error(stmt, "declaration"));
+        };
+        let mut pat = pattern;
+        while let PatKind::AscribeUserType { ref subpattern, .. } = *pat.kind {
+            pat = subpattern;
+        }
+        let PatKind::Binding { var, subpattern: None, .. } = *pat.kind else {
+            return Err(self.stmt_error(stmt, "declaration of a single variable"));
+        };
+        Ok((var, pattern.ty, pattern.span))
+    }
+
+    /// Parses a block expression, possibly surrounded by scopes.
+    fn parse_block_expr(&self, expr: ExprId, expected: &'static str) -> PResult<&'body Block> {
+        match self.thir[self.preparse(expr)].kind {
+            ExprKind::Block { ref body } => Ok(body),
+            _ => Err(self.expr_error(expr, expected)),
+        }
+    }
+
+    /// Skips the scopes around an expression, which have no meaning in custom MIR.
+    fn preparse(&self, mut expr: ExprId) -> ExprId {
+        while let ExprKind::Scope { value, .. } = self.thir[expr].kind {
+            expr = value;
+        }
+        expr
+    }
+
+    fn source_info(&self, span: Span) -> SourceInfo {
+        SourceInfo { span, ..self.source_info }
+    }
+}
@@ -0,0 +1,235 @@
+//! Parsing of statements, terminators, rvalues, operands and places.
+//!
+//! Most of these are written as calls to the functions in `core::intrinsics::mir`, which are
+//! recognized by their diagnostic items.
+
+use rustc_middle::mir::*;
+use rustc_middle::thir::*;
+use rustc_middle::ty;
+use rustc_span::symbol::{sym, Symbol};
+use rustc_span::Span;
+
+use super::{PResult, ParseCtxt, ParseError};
+
+impl<'tcx, 'body> ParseCtxt<'tcx, 'body> {
+    /// Parses a statement, returning its kind and span.
+    pub(super) fn parse_statement(&self, stmt: StmtId) -> PResult<(StatementKind<'tcx>, Span)> {
+        let StmtKind::Expr { expr, .. } = self.thir[stmt].kind else {
+            return Err(self.stmt_error(stmt, "statement"));
+        };
+        let expr = self.preparse(expr);
+        let span = self.thir[expr].span;
+        let kind = match self.thir[expr].kind {
+            ExprKind::Assign { lhs, rhs } => {
+                let place = self.parse_place(lhs)?;
+                let rvalue = self.parse_rvalue(rhs)?;
+                StatementKind::Assign(Box::new((place, rvalue)))
+            }
+            _ => match self.mir_call(expr) {
+                Some((sym::mir_retag, &[place])) => {
+                    StatementKind::Retag(RetagKind::Default, Box::new(self.parse_place(place)?))
+                }
+                Some((sym::mir_storage_live, &[local])) => {
+                    StatementKind::StorageLive(self.parse_local(local)?)
+                }
+                Some((sym::mir_storage_dead, &[local])) => {
+                    StatementKind::StorageDead(self.parse_local(local)?)
+                }
+                _ => return Err(self.expr_error(expr, "statement")),
+            },
+        };
+        Ok((kind, span))
+    }
+
+    pub(super) fn parse_terminator(&self, expr: ExprId) -> PResult<TerminatorKind<'tcx>> {
+        let expr = self.preparse(expr);
+        if let ExprKind::Match { scrutinee, ref arms } = self.thir[expr].kind {
+            return self.parse_match(expr, scrutinee, arms);
+        }
+        match self.mir_call(expr) {
+            Some((sym::mir_return, &[])) => Ok(TerminatorKind::Return),
+            Some((sym::mir_goto, &[target])) => {
+                Ok(TerminatorKind::Goto { target: self.parse_block(target)? })
+            }
+            Some((sym::mir_unreachable, &[])) => Ok(TerminatorKind::Unreachable),
+            Some((sym::mir_drop, &[place, target])) => Ok(TerminatorKind::Drop {
+                place: self.parse_place(place)?,
+                target: self.parse_block(target)?,
+                unwind: None,
+            }),
+            Some((sym::mir_call, &[destination, target, call])) => {
+                let destination = self.parse_place(destination)?;
+                let target = self.parse_block(target)?;
+                let call = self.preparse(call);
+                let call_kind = &self.thir[call].kind;
+                let ExprKind::Call { fun, ref args, from_hir_call, fn_span, .. } = *call_kind else {
+                    return Err(self.expr_error(call, "function call"));
+                };
+                Ok(TerminatorKind::Call {
+                    func: self.parse_operand(fun)?,
+                    args: self.parse_operands(args)?,
+                    destination: Some((destination, target)),
+                    cleanup: None,
+                    from_hir_call,
+                    fn_span,
+                })
+            }
+            _ => Err(self.expr_error(expr, "terminator")),
+        }
+    }
+
+    /// Parses a `match` on an integer into a `SwitchInt`. The arms must all have constant
+    /// patterns, except for the last one, which must be `_` and becomes the `otherwise` target.
+    fn parse_match(
+        &self,
+        expr: ExprId,
+        scrutinee: ExprId,
+        arms: &[ArmId],
+    ) -> PResult<TerminatorKind<'tcx>> {
+        let Some((otherwise, arms)) = arms.split_last() else {
+            return Err(self.expr_error(expr, "`match` with a `_` arm"));
+        };
+        let otherwise = &self.thir[*otherwise];
+        let PatKind::Wild = *otherwise.pattern.kind else {
+            return Err(ParseError {
+                span: otherwise.pattern.span,
+                item_description: format!("{:?}", otherwise.pattern.kind),
+                expected: "`_` as the last pattern".to_string(),
+            });
+        };
+
+        let switch_ty = self.thir[scrutinee].ty;
+        let mut values = Vec::with_capacity(arms.len());
+        for arm in arms.iter() {
+            let arm = &self.thir[*arm];
+            let PatKind::Constant { value } = *arm.pattern.kind else {
+                return Err(ParseError {
+                    span: arm.pattern.span,
+                    item_description: format!("{:?}", arm.pattern.kind),
+                    expected: "constant pattern".to_string(),
+                });
+            };
+            let value = value.eval_bits(self.tcx, self.param_env, switch_ty);
+            values.push((value, self.parse_block(arm.body)?));
+        }
+
+        Ok(TerminatorKind::SwitchInt {
+            discr: self.parse_operand(scrutinee)?,
+            switch_ty,
+            targets: SwitchTargets::new(values.into_iter(), self.parse_block(otherwise.body)?),
+        })
+    }
+
+    fn parse_rvalue(&self, expr: ExprId) -> PResult<Rvalue<'tcx>> {
+        let expr = self.preparse(expr);
+        let rvalue = match self.thir[expr].kind {
+            ExprKind::Borrow { borrow_kind, arg } => {
+                Rvalue::Ref(self.tcx.lifetimes.re_erased, borrow_kind, self.parse_place(arg)?)
+            }
+            ExprKind::AddressOf { mutability, arg } => {
+                Rvalue::AddressOf(mutability, self.parse_place(arg)?)
+            }
+            ExprKind::Binary { op, lhs, rhs } => Rvalue::BinaryOp(
+                op,
+                Box::new((self.parse_operand(lhs)?, self.parse_operand(rhs)?)),
+            ),
+            ExprKind::Unary { op, arg } => Rvalue::UnaryOp(op, self.parse_operand(arg)?),
+            ExprKind::Cast { source } => {
+                Rvalue::Cast(CastKind::Misc, self.parse_operand(source)?, self.thir[expr].ty)
+            }
+            ExprKind::Tuple { ref fields } => {
+                Rvalue::Aggregate(Box::new(AggregateKind::Tuple), self.parse_operands(fields)?)
+            }
+            ExprKind::Array { ref fields } => {
+                let ty::Array(elem_ty, _) = *self.thir[expr].ty.kind() else {
+                    return Err(self.expr_error(expr, "array"));
+                };
+                let operands = self.parse_operands(fields)?;
+                Rvalue::Aggregate(Box::new(AggregateKind::Array(elem_ty)), operands)
+            }
+            _ => match self.mir_call(expr) {
+                Some((sym::mir_checked, &[binop])) => {
+                    let binop = self.preparse(binop);
+                    let ExprKind::Binary { op, lhs, rhs } = self.thir[binop].kind else {
+                        return Err(self.expr_error(binop, "binary operation"));
+                    };
+                    Rvalue::CheckedBinaryOp(
+                        op,
+                        Box::new((self.parse_operand(lhs)?, self.parse_operand(rhs)?)),
+                    )
+                }
+                Some((sym::mir_discriminant, &[place])) => {
+                    Rvalue::Discriminant(self.parse_place(place)?)
+                }
+                _ => Rvalue::Use(self.parse_operand(expr)?),
+            },
+        };
+        Ok(rvalue)
+    }
+
+    fn parse_operands(&self, exprs: &[ExprId]) -> PResult<Vec<Operand<'tcx>>> {
+        exprs.iter().map(|expr| self.parse_operand(*expr)).collect()
+    }
+
+    fn parse_operand(&self, expr: ExprId) -> PResult<Operand<'tcx>> {
+        let expr = self.preparse(expr);
+        let Expr { span, ref kind, .. } = self.thir[expr];
+        match *kind {
+            ExprKind::Literal { ref literal, .. } => Ok(Operand::Constant(Box::new(Constant {
+                span,
+                user_ty: None,
+                literal: **literal,
+            }))),
+            ExprKind::StaticRef { literal, .. } => {
+                Ok(Operand::Constant(Box::new(Constant { span, user_ty: None, literal })))
+            }
+            _ => match self.mir_call(expr) {
+                Some((sym::mir_move, &[place])) => Ok(Operand::Move(self.parse_place(place)?)),
+                _ => Ok(Operand::Copy(self.parse_place(expr)?)),
+            },
+        }
+    }
+
+    fn parse_place(&self, expr: ExprId) -> PResult<Place<'tcx>> {
+        let expr = self.preparse(expr);
+        match self.thir[expr].kind {
+            ExprKind::VarRef { id } => match self.local_map.get(&id) {
+                Some(&local) => Ok(Place::from(local)),
+                None => Err(self.expr_error(expr, "local")),
+            },
+            ExprKind::Field { lhs, name } => {
+                Ok(self.tcx.mk_place_field(self.parse_place(lhs)?, name, self.thir[expr].ty))
+            }
+            ExprKind::Deref { arg } => Ok(self.tcx.mk_place_deref(self.parse_place(arg)?)),
+            ExprKind::Index { lhs, index } => {
+                Ok(self.tcx.mk_place_index(self.parse_place(lhs)?, self.parse_local(index)?))
+            }
+            _ => Err(self.expr_error(expr, "place")),
+        }
+    }
+
+    fn parse_local(&self, expr: ExprId) -> PResult<Local> {
+        match self.parse_place(expr)?.as_local() {
+            Some(local) => Ok(local),
+            None => Err(self.expr_error(expr, "local")),
+        }
+    }
+
+    fn parse_block(&self, expr: ExprId) -> PResult<BasicBlock> {
+        let expr = self.preparse(expr);
+        if let ExprKind::VarRef { id } = self.thir[expr].kind {
+            if let Some(&block) = self.block_map.get(&id) {
+                return Ok(block);
+            }
+        }
+        Err(self.expr_error(expr, "basic block"))
+    }
+
+    /// If `expr` is a call to one of the functions in `core::intrinsics::mir`, returns the
+    /// diagnostic item of the function and the arguments of the call.
+    fn mir_call(&self, expr: ExprId) -> Option<(Symbol, &'body [ExprId])> {
+        let ExprKind::Call { ty, ref args, .. } = self.thir[expr].kind else { return None };
+        let ty::FnDef(def_id, _) = *ty.kind() else { return None };
+        Some((self.tcx.get_diagnostic_name(def_id)?, &args[..]))
+    }
+}
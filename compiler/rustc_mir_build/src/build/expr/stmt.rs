@@ -101,6 +101,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                 BreakableTarget::Return,
                 source_info,
             ),
+            ExprKind::Become { value } => this.tail_call(block, &this.thir[value], source_info),
             _ => {
                 assert!(
                     statement_scope.is_some(),
@@ -148,4 +149,42 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             }
         }
     }
+
+    /// Builds the tail call to `call`, the value of a `become` expression. The callee and the
+    /// arguments are evaluated as for a normal call, then everything in scope is dropped before
+    /// the `TailCall` terminator.
+    fn tail_call(
+        &mut self,
+        mut block: BasicBlock,
+        call: &Expr<'tcx>,
+        source_info: SourceInfo,
+    ) -> BlockAnd<()> {
+        let this = self;
+        match call.kind {
+            ExprKind::Scope { region_scope, lint_level, value } => {
+                let region_scope = (region_scope, this.source_info(call.span));
+                this.in_scope(region_scope, lint_level, |this| {
+                    this.tail_call(block, &this.thir[value], source_info)
+                })
+            }
+            ExprKind::Call { ty: _, fun, ref args, from_hir_call: _, fn_span } => {
+                let fun = unpack!(block = this.as_local_operand(block, &this.thir[fun]));
+                let args: Vec<_> = args
+                    .into_iter()
+                    .copied()
+                    .map(|arg| unpack!(block = this.as_local_call_operand(block, &this.thir[arg])))
+                    .collect();
+
+                unpack!(block = this.break_for_tail_call(block, &fun, &args));
+                this.cfg.terminate(
+                    block,
+                    source_info,
+                    TerminatorKind::TailCall { func: fun, args, fn_span },
+                );
+
+                this.cfg.start_new_block().unit()
+            }
+            _ => span_bug!(call.span, "`become` of a non-call expression: {:?}", call.kind),
+        }
+    }
 }
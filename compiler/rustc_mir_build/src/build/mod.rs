@@ -196,20 +196,39 @@ fn mir_build(tcx: TyCtxt<'_>, def: ty::WithOptConstParam<LocalDefId>) -> Body<'_
                 (None, fn_sig.output())
             };
 
-            let mut mir = build::construct_fn(
-                &thir,
-                &infcx,
-                def,
-                id,
-                arguments,
-                safety,
-                abi,
-                return_ty,
-                return_ty_span,
-                body,
-                expr,
-                span_with_body,
-            );
+            let custom_mir_attr =
+                tcx.hir().attrs(id).iter().find(|attr| attr.has_name(sym::custom_mir));
+            let mut mir = if let Some(custom_mir_attr) = custom_mir_attr {
+                let arguments: Vec<_> = arguments.collect();
+                custom::build_custom_mir(
+                    tcx,
+                    fn_def_id.to_def_id(),
+                    id,
+                    &thir,
+                    expr,
+                    &arguments,
+                    safety,
+                    return_ty,
+                    return_ty_span,
+                    span_with_body,
+                    custom_mir_attr,
+                )
+            } else {
+                build::construct_fn(
+                    &thir,
+                    &infcx,
+                    def,
+                    id,
+                    arguments,
+                    safety,
+                    abi,
+                    return_ty,
+                    return_ty_span,
+                    body,
+                    expr,
+                    span_with_body,
+                )
+            };
             if yield_ty.is_some() {
                 mir.generator.as_mut().unwrap().yield_ty = yield_ty;
             }
@@ -1069,6 +1088,7 @@ fn get_unit_temp(&mut self) -> Place<'tcx> {
 
 mod block;
 mod cfg;
+mod custom;
 mod expr;
 mod matches;
 mod misc;
@@ -81,7 +81,7 @@
 
 */
 
-use std::mem;
+use std::{iter, mem};
 
 use crate::build::{BlockAnd, BlockAndExtension, BlockFrame, Builder, CFG};
 use rustc_data_structures::fx::FxHashMap;
@@ -698,6 +698,83 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         self.cfg.start_new_block().unit()
     }
 
+    /// Sets up the drops for a tail call from `block` to `func` with `args`. This drops everything
+    /// in scope, like a `return` does, except for the temporaries holding the arguments, which are
+    /// moved to the callee. If one of the drops panics, the arguments are dropped on the unwind
+    /// path, as they would have been without the tail call.
+    crate fn break_for_tail_call(
+        &mut self,
+        mut block: BasicBlock,
+        func: &Operand<'tcx>,
+        args: &[Operand<'tcx>],
+    ) -> BlockAnd<()> {
+        let arg_locals: Vec<_> = iter::once(func)
+            .chain(args)
+            .filter_map(|operand| match operand {
+                Operand::Copy(_) | Operand::Constant(_) => None,
+                Operand::Move(place) => place.as_local(),
+            })
+            .collect();
+
+        let mut unwind_to = self.diverge_cleanup();
+        let mut arg_drops = Vec::new();
+        for scope in self.scopes.scopes.iter().rev() {
+            for drop_data in scope.drops.iter().rev() {
+                let source_info = drop_data.source_info;
+                let local = drop_data.local;
+
+                match drop_data.kind {
+                    DropKind::Value => {
+                        // Same as in `build_scope_drops`, with the drops of the arguments added
+                        // in front of the unwind path.
+                        let unwind_drops = &mut self.scopes.unwind_drops;
+                        debug_assert_eq!(unwind_drops.drops[unwind_to].0.local, local);
+                        debug_assert_eq!(unwind_drops.drops[unwind_to].0.kind, drop_data.kind);
+                        unwind_to = unwind_drops.drops[unwind_to].1;
+
+                        if arg_locals.contains(&local) {
+                            arg_drops.push(*drop_data);
+                            continue;
+                        }
+                        if scope.moved_locals.contains(&local) {
+                            continue;
+                        }
+
+                        let mut unwind_entry = unwind_to;
+                        for &arg_drop in &arg_drops {
+                            unwind_entry = unwind_drops.add_drop(arg_drop, unwind_entry);
+                        }
+                        unwind_drops.add_entry(block, unwind_entry);
+
+                        let next = self.cfg.start_new_block();
+                        self.cfg.terminate(
+                            block,
+                            source_info,
+                            TerminatorKind::Drop {
+                                place: local.into(),
+                                target: next,
+                                unwind: None,
+                            },
+                        );
+                        block = next;
+                    }
+                    DropKind::Storage => {
+                        if arg_locals.contains(&local) {
+                            continue;
+                        }
+                        // Only temps and vars need their storage dead.
+                        assert!(local.index() > self.arg_count);
+                        self.cfg.push(
+                            block,
+                            Statement { source_info, kind: StatementKind::StorageDead(local) },
+                        );
+                    }
+                }
+            }
+        }
+        block.unit()
+    }
+
     crate fn break_for_else(
         &mut self,
         block: BasicBlock,
@@ -1383,6 +1460,7 @@ fn add_entry(cfg: &mut CFG<'tcx>, from: BasicBlock, to: BasicBlock) {
             | TerminatorKind::Resume
             | TerminatorKind::Abort
             | TerminatorKind::Return
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Unreachable
             | TerminatorKind::Yield { .. }
             | TerminatorKind::GeneratorDrop
@@ -123,6 +123,15 @@ fn node_examined(
             | TerminatorKind::Unreachable
             | TerminatorKind::Yield { .. } => ControlFlow::Break(NonRecursive),
 
+            // A tail call returns control flow to the caller, unless it recurses.
+            TerminatorKind::TailCall { ref func, ref args, .. } => {
+                if self.is_recursive_call(func, args) {
+                    ControlFlow::CONTINUE
+                } else {
+                    ControlFlow::Break(NonRecursive)
+                }
+            }
+
             // A diverging InlineAsm is treated as non-recursing
             TerminatorKind::InlineAsm { destination, .. } => {
                 if destination.is_some() {
@@ -147,7 +156,9 @@ fn node_examined(
     fn node_settled(&mut self, bb: BasicBlock) -> ControlFlow<Self::BreakVal> {
         // When we examine a node for the last time, remember it if it is a recursive call.
         let terminator = self.body[bb].terminator();
-        if let TerminatorKind::Call { func, args, .. } = &terminator.kind {
+        if let TerminatorKind::Call { func, args, .. }
+        | TerminatorKind::TailCall { func, args, .. } = &terminator.kind
+        {
             if self.is_recursive_call(func, args) {
                 self.reachable_recursive_calls.push(terminator.source_info.span);
             }
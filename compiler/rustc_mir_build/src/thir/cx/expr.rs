@@ -9,7 +9,7 @@
 use rustc_middle::hir::place::ProjectionKind as HirProjectionKind;
 use rustc_middle::middle::region;
 use rustc_middle::mir::interpret::Scalar;
-use rustc_middle::mir::{BinOp, BorrowKind, Field, UnOp};
+use rustc_middle::mir::{BinOp, BorrowKind, ConstantKind, Field, UnOp};
 use rustc_middle::thir::*;
 use rustc_middle::ty::adjustment::{
     Adjust, Adjustment, AutoBorrow, AutoBorrowMutability, PointerCast,
@@ -292,7 +292,7 @@ fn make_mirror_unadjusted(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Expr<'tcx>
             }
 
             hir::ExprKind::Lit(ref lit) => ExprKind::Literal {
-                literal: self.const_eval_literal(&lit.node, expr_ty, lit.span, false),
+                literal: Box::new(self.const_eval_literal(&lit.node, expr_ty, lit.span, false)),
                 user_ty: None,
                 const_id: None,
             },
@@ -361,7 +361,9 @@ fn make_mirror_unadjusted(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Expr<'tcx>
                     self.overloaded_operator(expr, Box::new([arg]))
                 } else if let hir::ExprKind::Lit(ref lit) = arg.kind {
                     ExprKind::Literal {
-                        literal: self.const_eval_literal(&lit.node, expr_ty, lit.span, true),
+                        literal: Box::new(
+                            self.const_eval_literal(&lit.node, expr_ty, lit.span, true),
+                        ),
                         user_ty: None,
                         const_id: None,
                     }
@@ -527,7 +529,7 @@ fn make_mirror_unadjusted(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Expr<'tcx>
                                                 temp_lifetime,
                                                 span: expr.span,
                                                 kind: ExprKind::Literal {
-                                                    literal: ty::Const::zero_sized(self.tcx, ty),
+                                                    literal: Box::new(ConstantKind::zero_sized(ty)),
                                                     user_ty,
                                                     const_id: None,
                                                 },
@@ -553,7 +555,7 @@ fn make_mirror_unadjusted(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Expr<'tcx>
                                                 temp_lifetime,
                                                 span: expr.span,
                                                 kind: ExprKind::Literal {
-                                                    literal: ty::Const::zero_sized(self.tcx, ty),
+                                                    literal: Box::new(ConstantKind::zero_sized(ty)),
                                                     user_ty: None,
                                                     const_id: None,
                                                 },
@@ -588,6 +590,7 @@ fn make_mirror_unadjusted(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Expr<'tcx>
             hir::ExprKind::Ret(ref v) => {
                 ExprKind::Return { value: v.as_ref().map(|v| self.mirror_expr(v)) }
             }
+            hir::ExprKind::Become(call) => ExprKind::Become { value: self.mirror_expr(call) },
             hir::ExprKind::Break(dest, ref value) => match dest.target_id {
                 Ok(target_id) => ExprKind::Break {
                     label: region::Scope { id: target_id.local_id, data: region::ScopeData::Node },
@@ -696,9 +699,13 @@ fn make_mirror_unadjusted(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Expr<'tcx>
                             temp_lifetime,
                             ty: var_ty,
                             span: expr.span,
-                            kind: ExprKind::Literal { literal, user_ty: None, const_id: None },
+                            kind: ExprKind::Literal {
+                                literal: Box::new(literal),
+                                user_ty: None,
+                                const_id: None,
+                            },
                         };
-                        let offset = self.thir.exprs.push(mk_const(ty::Const::from_bits(
+                        let offset = self.thir.exprs.push(mk_const(ConstantKind::from_bits(
                             self.tcx,
                             offset as u128,
                             self.param_env.and(var_ty),
@@ -715,7 +722,8 @@ fn make_mirror_unadjusted(&mut self, expr: &'tcx hir::Expr<'tcx>) -> Expr<'tcx>
                                     )),
                                     ty: var_ty,
                                 };
-                                let lhs = self.thir.exprs.push(mk_const(self.tcx().mk_const(lhs)));
+                                let lhs = ConstantKind::Ty(self.tcx().mk_const(lhs));
+                                let lhs = self.thir.exprs.push(mk_const(lhs));
                                 let bin =
                                     ExprKind::Binary { op: BinOp::Add, lhs: lhs, rhs: offset };
                                 self.thir.exprs.push(Expr {
@@ -837,7 +845,7 @@ fn method_callee(
             ty,
             span,
             kind: ExprKind::Literal {
-                literal: ty::Const::zero_sized(self.tcx(), ty),
+                literal: Box::new(ConstantKind::zero_sized(ty)),
                 user_ty,
                 const_id: None,
             },
@@ -872,10 +880,9 @@ fn convert_path_expr(&mut self, expr: &'tcx hir::Expr<'tcx>, res: Res) -> ExprKi
                 let user_ty = self.user_substs_applied_to_res(expr.hir_id, res);
                 debug!("convert_path_expr: user_ty={:?}", user_ty);
                 ExprKind::Literal {
-                    literal: ty::Const::zero_sized(
-                        self.tcx,
+                    literal: Box::new(ConstantKind::zero_sized(
                         self.typeck_results().node_type(expr.hir_id),
-                    ),
+                    )),
                     user_ty,
                     const_id: None,
                 }
@@ -890,10 +897,10 @@ fn convert_path_expr(&mut self, expr: &'tcx hir::Expr<'tcx>, res: Res) -> ExprKi
                 let name = self.tcx.hir().name(hir_id);
                 let val = ty::ConstKind::Param(ty::ParamConst::new(index, name));
                 ExprKind::Literal {
-                    literal: self.tcx.mk_const(ty::Const {
+                    literal: Box::new(ConstantKind::Ty(self.tcx.mk_const(ty::Const {
                         val,
                         ty: self.typeck_results().node_type(expr.hir_id),
-                    }),
+                    }))),
                     user_ty: None,
                     const_id: Some(def_id),
                 }
@@ -903,13 +910,13 @@ fn convert_path_expr(&mut self, expr: &'tcx hir::Expr<'tcx>, res: Res) -> ExprKi
                 let user_ty = self.user_substs_applied_to_res(expr.hir_id, res);
                 debug!("convert_path_expr: (const) user_ty={:?}", user_ty);
                 ExprKind::Literal {
-                    literal: self.tcx.mk_const(ty::Const {
+                    literal: Box::new(ConstantKind::Ty(self.tcx.mk_const(ty::Const {
                         val: ty::ConstKind::Unevaluated(ty::Unevaluated::new(
                             ty::WithOptConstParam::unknown(def_id),
                             substs,
                         )),
                         ty: self.typeck_results().node_type(expr.hir_id),
-                    }),
+                    }))),
                     user_ty,
                     const_id: Some(def_id),
                 }
@@ -945,8 +952,7 @@ fn convert_path_expr(&mut self, expr: &'tcx hir::Expr<'tcx>, res: Res) -> ExprKi
                 } else {
                     let ptr = self.tcx.create_static_alloc(id);
                     ExprKind::StaticRef {
-                        literal: ty::Const::from_scalar(
-                            self.tcx,
+                        literal: ConstantKind::from_scalar(
                             Scalar::from_pointer(ptr.into(), &self.tcx),
                             ty,
                         ),
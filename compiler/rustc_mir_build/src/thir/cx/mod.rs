@@ -13,6 +13,7 @@
 use rustc_hir::Node;
 use rustc_middle::middle::region;
 use rustc_middle::mir::interpret::{LitToConstError, LitToConstInput};
+use rustc_middle::mir::ConstantKind;
 use rustc_middle::thir::*;
 use rustc_middle::ty::{self, Ty, TyCtxt};
 use rustc_span::Span;
@@ -79,14 +80,14 @@ fn new(tcx: TyCtxt<'tcx>, def: ty::WithOptConstParam<LocalDefId>) -> Cx<'tcx> {
         ty: Ty<'tcx>,
         sp: Span,
         neg: bool,
-    ) -> &'tcx ty::Const<'tcx> {
+    ) -> ConstantKind<'tcx> {
         trace!("const_eval_literal: {:#?}, {:?}, {:?}, {:?}", lit, ty, sp, neg);
 
-        match self.tcx.at(sp).lit_to_const(LitToConstInput { lit, ty, neg }) {
+        match self.tcx.at(sp).lit_to_mir_constant(LitToConstInput { lit, ty, neg }) {
             Ok(c) => c,
             Err(LitToConstError::Reported) => {
                 // create a dummy value and continue compiling
-                self.tcx.const_error(ty)
+                self.tcx.const_error(ty).into()
             }
             Err(LitToConstError::TypeError) => bug!("const_eval_literal: had type error"),
         }
@@ -451,7 +451,7 @@ fn join_state_into_successors_of<'tcx, A>(
     {
         use mir::TerminatorKind::*;
         match bb_data.terminator().kind {
-            Return | Resume | Abort | GeneratorDrop | Unreachable => {}
+            Return | TailCall { .. } | Resume | Abort | GeneratorDrop | Unreachable => {}
 
             Goto { target } => propagate(target, exit_state),
 
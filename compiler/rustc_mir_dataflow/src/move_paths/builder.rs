@@ -332,6 +332,7 @@ fn gather_rvalue(&mut self, rvalue: &Rvalue<'tcx>) {
             | Rvalue::AddressOf(..)
             | Rvalue::Discriminant(..)
             | Rvalue::Len(..)
+            | Rvalue::CopyForDeref(..)
             | Rvalue::NullaryOp(NullOp::SizeOf | NullOp::AlignOf, _) => {}
         }
     }
@@ -390,6 +391,12 @@ fn gather_terminator(&mut self, term: &Terminator<'tcx>) {
                     self.gather_init(destination.as_ref(), InitKind::NonPanicPathOnly);
                 }
             }
+            TerminatorKind::TailCall { ref func, ref args, fn_span: _ } => {
+                self.gather_operand(func);
+                for arg in args {
+                    self.gather_operand(arg);
+                }
+            }
             TerminatorKind::InlineAsm {
                 template: _,
                 ref operands,
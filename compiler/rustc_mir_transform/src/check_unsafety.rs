@@ -66,7 +66,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
                 // safe (at least as emitted during MIR construction)
             }
 
-            TerminatorKind::Call { ref func, .. } => {
+            TerminatorKind::Call { ref func, .. } | TerminatorKind::TailCall { ref func, .. } => {
                 let func_ty = func.ty(self.body, self.tcx);
                 let sig = func_ty.fn_sig(self.tcx);
                 if let hir::Unsafety::Unsafe = sig.unsafety() {
@@ -434,6 +434,14 @@ fn unsafety_check_result<'tcx>(
     // `mir_built` force this.
     let body = &tcx.mir_built(def).borrow();
 
+    // Bodies written with `#[custom_mir]` in the dialect of a later phase aren't checked.
+    if body.should_skip() {
+        return tcx.arena.alloc(UnsafetyCheckResult {
+            violations: Vec::new().into(),
+            unsafe_blocks: Vec::new().into(),
+        });
+    }
+
     let param_env = tcx.param_env(def.did);
 
     let mut checker = UnsafetyChecker::new(body, def.did, tcx, param_env);
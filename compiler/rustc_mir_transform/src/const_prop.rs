@@ -28,6 +28,8 @@
 use rustc_target::spec::abi::Abi;
 use rustc_trait_selection::traits;
 
+use crate::dataflow_const_prop::DataflowConstProp;
+use crate::pass_manager as pm;
 use crate::MirPass;
 use rustc_const_eval::const_eval::ConstEvalErr;
 use rustc_const_eval::interpret::{
@@ -151,7 +153,11 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
         // constants, instead of just checking for const-folding succeeding.
         // That would require a uniform one-def no-mutation analysis
         // and RPO (or recursing when needing the value of a local).
-        let mut optimization_finder = ConstPropagator::new(body, dummy_body, tcx);
+        // `DataflowConstProp` propagates the constants when it runs, this pass then only evaluates
+        // the body for its lints.
+        let propagate =
+            tcx.sess.mir_opt_level() > 0 && !pm::is_enabled(tcx, body, &DataflowConstProp);
+        let mut optimization_finder = ConstPropagator::new(body, dummy_body, tcx, propagate);
         optimization_finder.visit_body(body);
 
         trace!("ConstProp done for {:?}", def_id);
@@ -324,6 +330,8 @@ struct ConstPropagator<'mir, 'tcx> {
     // Because we have `MutVisitor` we can't obtain the `SourceInfo` from a `Location`. So we store
     // the last known `SourceInfo` here and just keep revisiting it.
     source_info: Option<SourceInfo>,
+    /// Whether the evaluated constants are propagated into the body, or only used for the lints.
+    propagate: bool,
 }
 
 impl<'tcx> LayoutOfHelpers<'tcx> for ConstPropagator<'_, 'tcx> {
@@ -361,6 +369,7 @@ fn new(
         body: &Body<'tcx>,
         dummy_body: &'mir Body<'tcx>,
         tcx: TyCtxt<'tcx>,
+        propagate: bool,
     ) -> ConstPropagator<'mir, 'tcx> {
         let def_id = body.source.def_id();
         let substs = &InternalSubsts::identity_for_item(tcx, def_id);
@@ -417,6 +426,7 @@ fn new(
             //FIXME(wesleywiser) we can't steal this because `Visitor::super_visit_body()` needs it
             local_decls: body.local_decls.clone(),
             source_info: None,
+            propagate,
         }
     }
 
@@ -716,6 +726,7 @@ fn const_prop(
             // There's no other checking to do at this time.
             Rvalue::Aggregate(..)
             | Rvalue::Use(..)
+            | Rvalue::CopyForDeref(..)
             | Rvalue::Repeat(..)
             | Rvalue::Len(..)
             | Rvalue::Cast(..)
@@ -788,7 +799,7 @@ fn operand_from_scalar(&self, scalar: Scalar, ty: Ty<'tcx>, span: Span) -> Opera
         Operand::Constant(Box::new(Constant {
             span,
             user_ty: None,
-            literal: ty::Const::from_scalar(self.tcx, scalar, ty).into(),
+            literal: ConstantKind::from_scalar(scalar, ty),
         }))
     }
 
@@ -871,17 +882,10 @@ fn replace_with_const(
                                 *rval = Rvalue::Use(Operand::Constant(Box::new(Constant {
                                     span: source_info.span,
                                     user_ty: None,
-                                    literal: self
-                                        .ecx
-                                        .tcx
-                                        .mk_const(ty::Const {
-                                            ty,
-                                            val: ty::ConstKind::Value(ConstValue::ByRef {
-                                                alloc,
-                                                offset: Size::ZERO,
-                                            }),
-                                        })
-                                        .into(),
+                                    literal: ConstantKind::Val(
+                                        ConstValue::ByRef { alloc, offset: Size::ZERO },
+                                        ty,
+                                    ),
                                 })));
                             }
                         }
@@ -896,9 +900,7 @@ fn replace_with_const(
 
     /// Returns `true` if and only if this `op` should be const-propagated into.
     fn should_const_prop(&mut self, op: &OpTy<'tcx>) -> bool {
-        let mir_opt_level = self.tcx.sess.mir_opt_level();
-
-        if mir_opt_level == 0 {
+        if !self.propagate {
             return false;
         }
 
@@ -1263,7 +1265,7 @@ fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             //
             // NOTE: because LLVM codegen gives slight performance regressions with it, so this is
             // gated on `mir_opt_level=3`.
-            TerminatorKind::Call { .. } => {}
+            TerminatorKind::Call { .. } | TerminatorKind::TailCall { .. } => {}
         }
 
         // We remove all Locals which are restricted in propagation to their containing blocks and
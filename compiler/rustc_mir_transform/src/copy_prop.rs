@@ -0,0 +1,134 @@
+//! Copy propagation.
+//!
+//! This pass unifies the SSA locals connected by copies. The copies `_b = copy _a; _c = move _b;`
+//! starting at an SSA local `_a`, possibly in several branches, form a copy class whose head is
+//! `_a`. Every other local in the class holds the same value as `_a` wherever it is used, so all
+//! its uses are replaced with `_a`, and the copies between them become self-assignments, which are
+//! removed. This is what cleans up the chains of moves
+//! introduced by the desugaring of `?` and `.await`, and by inlining.
+//!
+//! The head now lives longer than the MIR builder intended, so its storage markers are removed.
+//! If any local in a class is copied, rather than moved, the moves out of the class become copies,
+//! since a callee could mutate an argument it receives by move. Otherwise every local in the class
+//! is only moved out of once, and the moves are left alone.
+//!
+//! The address of a borrowed local may be observed, so it keeps its own name. Since it is in SSA
+//! form, it is only borrowed immutably, and can still be the head of a class.
+
+use crate::ssa::SsaLocals;
+use crate::MirPass;
+use rustc_index::bit_set::BitSet;
+use rustc_index::vec::IndexVec;
+use rustc_middle::mir::visit::{MutVisitor, NonUseContext, PlaceContext};
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+
+pub struct CopyProp;
+
+impl<'tcx> MirPass<'tcx> for CopyProp {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let def_id = body.source.def_id();
+        let param_env = tcx.param_env_reveal_all_normalized(def_id);
+        let ssa = SsaLocals::new(tcx, param_env, body);
+
+        let copy_classes = ssa.copy_classes();
+        let mut storage_to_remove = BitSet::new_empty(body.local_decls.len());
+        for (local, &head) in copy_classes.iter_enumerated() {
+            if local != head && !ssa.borrowed_locals().contains(local) {
+                storage_to_remove.insert(head);
+            }
+        }
+        if storage_to_remove.is_empty() {
+            return;
+        }
+        if !tcx.consider_optimizing(|| format!("CopyProp {:?}", def_id)) {
+            return;
+        }
+
+        let fully_moved = fully_moved_locals(&ssa, body);
+        debug!(?fully_moved);
+
+        Replacer {
+            tcx,
+            copy_classes,
+            fully_moved,
+            storage_to_remove,
+            borrowed_locals: ssa.borrowed_locals(),
+        }
+        .visit_body(body);
+    }
+}
+
+/// Returns the locals whose copy class is only ever moved from, and never copied from: then each
+/// local of the class is only used once, and its moves can be kept.
+fn fully_moved_locals(ssa: &SsaLocals, body: &Body<'_>) -> BitSet<Local> {
+    let mut fully_moved = BitSet::new_filled(body.local_decls.len());
+    for (_, rvalue) in ssa.assignments(body) {
+        let Rvalue::Use(Operand::Copy(place)) = rvalue else { continue };
+        if let Some(rhs) = place.as_local().filter(|&rhs| ssa.is_ssa(rhs)) {
+            fully_moved.remove(rhs);
+        }
+    }
+    ssa.meet_copy_equivalence(&mut fully_moved);
+    fully_moved
+}
+
+/// Replaces the locals of each copy class with its head.
+struct Replacer<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    copy_classes: &'a IndexVec<Local, Local>,
+    fully_moved: BitSet<Local>,
+    storage_to_remove: BitSet<Local>,
+    borrowed_locals: &'a BitSet<Local>,
+}
+
+impl<'tcx> MutVisitor<'tcx> for Replacer<'_, 'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_local(&mut self, local: &mut Local, context: PlaceContext, _: Location) {
+        // A borrowed local keeps its address, and so its name, even when it is a copy.
+        if self.borrowed_locals.contains(*local) {
+            return;
+        }
+        match context {
+            PlaceContext::NonUse(NonUseContext::StorageLive | NonUseContext::StorageDead) => {}
+            _ => *local = self.copy_classes[*local],
+        }
+    }
+
+    fn visit_operand(&mut self, operand: &mut Operand<'tcx>, location: Location) {
+        if let Operand::Move(place) = *operand {
+            if !place.is_indirect() && !self.fully_moved.contains(place.local) {
+                *operand = Operand::Copy(place);
+            }
+        }
+        self.super_operand(operand, location);
+    }
+
+    fn visit_statement(&mut self, statement: &mut Statement<'tcx>, location: Location) {
+        match statement.kind {
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local)
+                if self.storage_to_remove.contains(local) =>
+            {
+                statement.make_nop();
+                return;
+            }
+            _ => {}
+        }
+
+        self.super_statement(statement, location);
+
+        // The copies within a class are now self-assignments.
+        if let StatementKind::Assign(box (lhs, Rvalue::Use(ref operand))) = statement.kind {
+            if operand.place() == Some(lhs) {
+                statement.make_nop();
+            }
+        }
+    }
+}
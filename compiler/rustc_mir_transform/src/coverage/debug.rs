@@ -825,6 +825,7 @@ pub(super) fn term_type(kind: &TerminatorKind<'_>) -> &'static str {
         TerminatorKind::Drop { .. } => "Drop",
         TerminatorKind::DropAndReplace { .. } => "DropAndReplace",
         TerminatorKind::Call { .. } => "Call",
+        TerminatorKind::TailCall { .. } => "TailCall",
         TerminatorKind::Assert { .. } => "Assert",
         TerminatorKind::Yield { .. } => "Yield",
         TerminatorKind::GeneratorDrop => "GeneratorDrop",
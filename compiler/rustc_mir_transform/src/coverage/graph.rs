@@ -123,6 +123,7 @@ fn compute_basic_coverage_blocks(
 
             match term.kind {
                 TerminatorKind::Return { .. }
+                | TerminatorKind::TailCall { .. }
                 | TerminatorKind::Abort
                 | TerminatorKind::Yield { .. }
                 | TerminatorKind::SwitchInt { .. } => {
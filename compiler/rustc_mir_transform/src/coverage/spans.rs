@@ -872,6 +872,7 @@ pub(super) fn filtered_terminator_span(terminator: &Terminator<'_>) -> Option<Sp
         TerminatorKind::Resume
         | TerminatorKind::Abort
         | TerminatorKind::Return
+        | TerminatorKind::TailCall { .. }
         | TerminatorKind::Yield { .. }
         | TerminatorKind::GeneratorDrop
         | TerminatorKind::FalseUnwind { .. }
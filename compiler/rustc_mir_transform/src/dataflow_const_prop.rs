@@ -0,0 +1,862 @@
+//! A constant propagation optimization pass based on dataflow analysis.
+//!
+//! This pass replaces `ConstProp` as the constant propagation optimization, `ConstProp` is only
+//! kept for its lints. Instead of evaluating the body in a virtual interpreter frame, this pass
+//! tracks a flat lattice of values for every scalar place whose address is never taken, and
+//! computes them with the dataflow framework, so that values are also known after control flow
+//! joins and in loops. Tracked places are locals, and the fields of tuples, structs and enum
+//! variants stored in them, as well as enum discriminants. Pairs of scalars, like the results of
+//! checked arithmetic, are tracked field by field.
+//!
+//! Operands whose value is known are then replaced by constants, as are the right-hand sides of
+//! assignments to places whose value is known. Folding the resulting constant `SwitchInt` and
+//! `Assert` terminators is left to `SimplifyConstCondition`.
+
+use rustc_data_structures::fx::FxHashMap;
+use rustc_index::bit_set::BitSet;
+use rustc_index::vec::IndexVec;
+use rustc_middle::mir::visit::{
+    MutVisitor, MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor,
+};
+use rustc_middle::mir::*;
+use rustc_middle::ty::layout::LayoutOf;
+use rustc_middle::ty::{self, ParamEnv, ScalarInt, Ty, TyCtxt};
+use rustc_mir_dataflow::fmt::DebugWithContext;
+use rustc_mir_dataflow::lattice::{FlatSet, JoinSemiLattice};
+use rustc_mir_dataflow::{Analysis, AnalysisDomain, CallReturnPlaces, ResultsVisitor};
+use rustc_span::DUMMY_SP;
+use rustc_target::abi::VariantIdx;
+use rustc_target::spec::abi::Abi;
+
+use crate::MirPass;
+use rustc_const_eval::interpret::{
+    self, compile_time_machine, ConstValue, Frame, ImmTy, InterpCx, InterpResult, OpTy, PlaceTy,
+    Scalar, StackPopUnwind,
+};
+
+/// Bodies with more basic blocks than this are not optimized, to bound the analysis' cost.
+const BLOCK_LIMIT: usize = 100;
+/// The maximum number of places tracked per body.
+const PLACE_LIMIT: usize = 100;
+/// The maximum depth of nested fields tracked below a local.
+const DEPTH_LIMIT: usize = 4;
+
+pub struct DataflowConstProp;
+
+impl<'tcx> MirPass<'tcx> for DataflowConstProp {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() > 0
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        if body.basic_blocks().len() > BLOCK_LIMIT {
+            debug!("aborted dataflow const prop due to too many basic blocks");
+            return;
+        }
+
+        let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+
+        // Decide which places to track during the analysis.
+        let map = Map::new(tcx, param_env, body);
+
+        // Perform the actual dataflow analysis, and collect the values it found.
+        let mut collector = CollectAndPatch::new(tcx, &map);
+        {
+            let analysis = ConstAnalysis::new(tcx, param_env, body, &map);
+            let results = analysis
+                .into_engine(tcx, body)
+                .pass_name("dataflow_const_prop")
+                .iterate_to_fixpoint();
+            results.visit_reachable_with(body, &mut collector);
+        }
+
+        // Patch the body afterwards.
+        collector.visit_body(body);
+    }
+}
+
+rustc_index::newtype_index! {
+    /// A place tracked by the analysis.
+    struct PlaceIndex {
+        DEBUG_FORMAT = "place{}"
+    }
+}
+
+/// A projection from a tracked place to one of the tracked places it contains.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum TrackElem {
+    Field(Field),
+    Variant(VariantIdx),
+    Discriminant,
+}
+
+impl TrackElem {
+    fn from_projection<V, T>(elem: ProjectionElem<V, T>) -> Option<Self> {
+        match elem {
+            ProjectionElem::Field(field, _) => Some(TrackElem::Field(field)),
+            ProjectionElem::Downcast(_, variant) => Some(TrackElem::Variant(variant)),
+            _ => None,
+        }
+    }
+}
+
+struct PlaceInfo<'tcx> {
+    /// The type of the place. For variants, this is the type of the enum.
+    ty: Ty<'tcx>,
+    /// The tracked places directly contained in this one. Places without children are scalars.
+    children: Vec<(TrackElem, PlaceIndex)>,
+}
+
+/// The places tracked by the analysis, and how they are nested in each other.
+struct Map<'tcx> {
+    locals: IndexVec<Local, Option<PlaceIndex>>,
+    projections: FxHashMap<(PlaceIndex, TrackElem), PlaceIndex>,
+    places: IndexVec<PlaceIndex, PlaceInfo<'tcx>>,
+}
+
+impl<'tcx> Map<'tcx> {
+    fn new(tcx: TyCtxt<'tcx>, param_env: ParamEnv<'tcx>, body: &Body<'tcx>) -> Self {
+        let mut map = Map {
+            locals: IndexVec::from_elem(None, &body.local_decls),
+            projections: FxHashMap::default(),
+            places: IndexVec::new(),
+        };
+        // Locals whose address is taken may be modified through pointers, so we can't track them.
+        let escaped = escaped_locals(body);
+        for (local, decl) in body.local_decls.iter_enumerated() {
+            if !escaped.contains(local) {
+                map.locals[local] = map.register(tcx, param_env, decl.ty, DEPTH_LIMIT);
+            }
+        }
+        map
+    }
+
+    /// Registers a place of type `ty` and the parts of it we can track. Returns `None` if there
+    /// is nothing to track in it.
+    fn register(
+        &mut self,
+        tcx: TyCtxt<'tcx>,
+        param_env: ParamEnv<'tcx>,
+        ty: Ty<'tcx>,
+        depth: usize,
+    ) -> Option<PlaceIndex> {
+        if self.places.len() >= PLACE_LIMIT {
+            return None;
+        }
+        if is_tracked_scalar(ty) {
+            return Some(self.push(ty, Vec::new()));
+        }
+        if depth == 0 {
+            return None;
+        }
+
+        let mut children = Vec::new();
+        match *ty.kind() {
+            ty::Tuple(_) => {
+                for (i, field_ty) in ty.tuple_fields().enumerate() {
+                    if let Some(child) = self.register(tcx, param_env, field_ty, depth - 1) {
+                        children.push((TrackElem::Field(Field::new(i)), child));
+                    }
+                }
+            }
+            ty::Adt(def, substs) if def.is_struct() => {
+                for (i, field) in def.non_enum_variant().fields.iter().enumerate() {
+                    let field_ty = tcx.normalize_erasing_regions(param_env, field.ty(tcx, substs));
+                    if let Some(child) = self.register(tcx, param_env, field_ty, depth - 1) {
+                        children.push((TrackElem::Field(Field::new(i)), child));
+                    }
+                }
+            }
+            ty::Adt(def, substs) if def.is_enum() => {
+                let discr_ty = ty.discriminant_ty(tcx);
+                if let Some(discr) = self.register(tcx, param_env, discr_ty, depth - 1) {
+                    children.push((TrackElem::Discriminant, discr));
+                }
+                for (variant_index, variant) in def.variants.iter_enumerated() {
+                    let mut fields = Vec::new();
+                    for (i, field) in variant.fields.iter().enumerate() {
+                        let field_ty =
+                            tcx.normalize_erasing_regions(param_env, field.ty(tcx, substs));
+                        if let Some(child) = self.register(tcx, param_env, field_ty, depth - 1) {
+                            fields.push((TrackElem::Field(Field::new(i)), child));
+                        }
+                    }
+                    if !fields.is_empty() {
+                        let variant_place = self.push(ty, fields);
+                        children.push((TrackElem::Variant(variant_index), variant_place));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        if children.is_empty() { None } else { Some(self.push(ty, children)) }
+    }
+
+    fn push(&mut self, ty: Ty<'tcx>, children: Vec<(TrackElem, PlaceIndex)>) -> PlaceIndex {
+        let place = self.places.push(PlaceInfo { ty, children });
+        for &(elem, child) in &self.places[place].children {
+            self.projections.insert((place, elem), child);
+        }
+        place
+    }
+
+    fn apply(&self, place: PlaceIndex, elem: TrackElem) -> Option<PlaceIndex> {
+        self.projections.get(&(place, elem)).copied()
+    }
+
+    /// Returns the tracked place `place` refers to, if any.
+    fn find(&self, place: PlaceRef<'_>) -> Option<PlaceIndex> {
+        let mut index = self.locals[place.local]?;
+        for &elem in place.projection {
+            index = self.apply(index, TrackElem::from_projection(elem)?)?;
+        }
+        Some(index)
+    }
+
+    fn is_scalar(&self, place: PlaceIndex) -> bool {
+        self.places[place].children.is_empty()
+    }
+
+    /// Calls `f` on `place` and all the tracked places it contains.
+    fn preorder(&self, place: PlaceIndex, f: &mut impl FnMut(PlaceIndex)) {
+        f(place);
+        for &(_, child) in &self.places[place].children {
+            self.preorder(child, f);
+        }
+    }
+}
+
+fn is_tracked_scalar(ty: Ty<'_>) -> bool {
+    ty.is_bool() || ty.is_char() || ty.is_integral() || ty.is_floating_point()
+}
+
+/// Returns the locals which are borrowed or whose address is taken somewhere in `body`.
+fn escaped_locals(body: &Body<'_>) -> BitSet<Local> {
+    struct Collector {
+        result: BitSet<Local>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for Collector {
+        fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, _location: Location) {
+            if context.is_borrow()
+                || matches!(
+                    context,
+                    PlaceContext::NonMutatingUse(NonMutatingUseContext::AddressOf)
+                        | PlaceContext::MutatingUse(MutatingUseContext::AddressOf)
+                )
+            {
+                self.result.insert(place.local);
+            }
+        }
+    }
+
+    let mut collector = Collector { result: BitSet::new_empty(body.local_decls.len()) };
+    collector.visit_body(body);
+    collector.result
+}
+
+/// The value of every tracked place. Only the values of scalar places are meaningful.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum State {
+    Unreachable,
+    Reachable(IndexVec<PlaceIndex, FlatSet<ScalarInt>>),
+}
+
+impl JoinSemiLattice for State {
+    fn join(&mut self, other: &Self) -> bool {
+        match (&mut *self, other) {
+            (_, State::Unreachable) => false,
+            (State::Unreachable, _) => {
+                *self = other.clone();
+                true
+            }
+            (State::Reachable(this), State::Reachable(other)) => this.join(other),
+        }
+    }
+}
+
+impl<C> DebugWithContext<C> for State {}
+
+/// The result of evaluating an rvalue: either a (single) value, or a tracked place whose values
+/// are copied.
+enum ValueOrPlace {
+    Value(FlatSet<ScalarInt>),
+    Place(PlaceIndex),
+}
+
+impl State {
+    fn get(&self, place: PlaceIndex) -> FlatSet<ScalarInt> {
+        match self {
+            State::Unreachable => FlatSet::Bottom,
+            State::Reachable(values) => values[place],
+        }
+    }
+
+    /// Forgets the values of `place` and of all the places it contains.
+    fn flood(&mut self, map: &Map<'_>, place: PlaceIndex) {
+        if let State::Reachable(values) = self {
+            map.preorder(place, &mut |place| values[place] = FlatSet::Top);
+        }
+    }
+
+    fn assign(&mut self, map: &Map<'_>, target: PlaceIndex, value: ValueOrPlace) {
+        match value {
+            ValueOrPlace::Value(value) => self.assign_value(map, target, value),
+            ValueOrPlace::Place(source) => {
+                if let State::Reachable(values) = self {
+                    copy_values(map, values, target, source);
+                }
+            }
+        }
+    }
+
+    fn assign_value(&mut self, map: &Map<'_>, target: PlaceIndex, value: FlatSet<ScalarInt>) {
+        self.flood(map, target);
+        if let State::Reachable(values) = self {
+            if map.is_scalar(target) {
+                values[target] = value;
+            }
+        }
+    }
+}
+
+/// Copies the values of `source` and the places it contains to the corresponding places of
+/// `target`, which has the same type.
+fn copy_values(
+    map: &Map<'_>,
+    values: &mut IndexVec<PlaceIndex, FlatSet<ScalarInt>>,
+    target: PlaceIndex,
+    source: PlaceIndex,
+) {
+    if target == source {
+        return;
+    }
+    values[target] = values[source];
+    for &(elem, target_child) in &map.places[target].children {
+        match map.apply(source, elem) {
+            Some(source_child) => copy_values(map, values, target_child, source_child),
+            None => map.preorder(target_child, &mut |place| values[place] = FlatSet::Top),
+        }
+    }
+}
+
+struct ConstAnalysis<'a, 'tcx> {
+    map: &'a Map<'tcx>,
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    local_decls: &'a LocalDecls<'tcx>,
+    ecx: InterpCx<'a, 'tcx, DummyMachine<'a, 'tcx>>,
+}
+
+impl<'a, 'tcx> AnalysisDomain<'tcx> for ConstAnalysis<'a, 'tcx> {
+    type Domain = State;
+
+    const NAME: &'static str = "ConstAnalysis";
+
+    fn bottom_value(&self, _body: &Body<'tcx>) -> State {
+        State::Unreachable
+    }
+
+    fn initialize_start_block(&self, _body: &Body<'tcx>, state: &mut State) {
+        // Nothing is known about the arguments.
+        *state = State::Reachable(IndexVec::from_elem_n(FlatSet::Top, self.map.places.len()));
+    }
+}
+
+impl<'a, 'tcx> Analysis<'tcx> for ConstAnalysis<'a, 'tcx> {
+    fn apply_statement_effect(
+        &self,
+        state: &mut State,
+        statement: &Statement<'tcx>,
+        _location: Location,
+    ) {
+        match &statement.kind {
+            StatementKind::Assign(box (place, rvalue)) => self.handle_assign(state, *place, rvalue),
+            StatementKind::SetDiscriminant { place, variant_index } => {
+                self.handle_set_discriminant(state, **place, *variant_index)
+            }
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local) => {
+                if let Some(place) = self.map.locals[*local] {
+                    state.flood(self.map, place);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_terminator_effect(
+        &self,
+        state: &mut State,
+        terminator: &Terminator<'tcx>,
+        _location: Location,
+    ) {
+        match terminator.kind {
+            TerminatorKind::Drop { place, .. } | TerminatorKind::DropAndReplace { place, .. } => {
+                self.flood_written(state, place);
+            }
+            _ => {}
+        }
+    }
+
+    fn apply_call_return_effect(
+        &self,
+        state: &mut State,
+        _block: BasicBlock,
+        return_places: CallReturnPlaces<'_, 'tcx>,
+    ) {
+        return_places.for_each(|place| self.flood_written(state, place));
+    }
+
+    fn apply_yield_resume_effect(
+        &self,
+        state: &mut State,
+        _resume_block: BasicBlock,
+        resume_place: Place<'tcx>,
+    ) {
+        self.flood_written(state, resume_place);
+    }
+}
+
+impl<'a, 'tcx> ConstAnalysis<'a, 'tcx> {
+    fn new(
+        tcx: TyCtxt<'tcx>,
+        param_env: ParamEnv<'tcx>,
+        body: &'a Body<'tcx>,
+        map: &'a Map<'tcx>,
+    ) -> Self {
+        let ecx = InterpCx::new(tcx, DUMMY_SP, param_env, DummyMachine::new(), ());
+        ConstAnalysis { map, tcx, param_env, local_decls: &body.local_decls, ecx }
+    }
+
+    /// Returns the tracked place written to by a write to `place`, if any. Since the fields of
+    /// the variants of an enum overlap, writing to the fields of one variant also invalidates the
+    /// discriminant and the fields of the other variants.
+    fn find_written(&self, state: &mut State, place: Place<'tcx>) -> Option<PlaceIndex> {
+        let mut index = self.map.locals[place.local]?;
+        for &elem in place.projection {
+            let elem = TrackElem::from_projection(elem)?;
+            if let TrackElem::Variant(variant_index) = elem {
+                if let Some(discr) = self.map.apply(index, TrackElem::Discriminant) {
+                    state.flood(self.map, discr);
+                }
+                self.flood_other_variants(state, index, variant_index);
+            }
+            index = self.map.apply(index, elem)?;
+        }
+        Some(index)
+    }
+
+    fn flood_written(&self, state: &mut State, place: Place<'tcx>) {
+        if let Some(place) = self.find_written(state, place) {
+            state.flood(self.map, place);
+        }
+    }
+
+    fn flood_other_variants(&self, state: &mut State, place: PlaceIndex, keep: VariantIdx) {
+        for &(elem, child) in &self.map.places[place].children {
+            if let TrackElem::Variant(variant_index) = elem {
+                if variant_index != keep {
+                    state.flood(self.map, child);
+                }
+            }
+        }
+    }
+
+    fn handle_assign(&self, state: &mut State, target: Place<'tcx>, rvalue: &Rvalue<'tcx>) {
+        let target_local = target.local;
+        let Some(target) = self.find_written(state, target) else { return };
+        match rvalue {
+            Rvalue::CheckedBinaryOp(op, box (left, right)) => {
+                let (value, overflow) = self.binary_op(state, *op, left, right);
+                state.flood(self.map, target);
+                if let Some(value_target) = self.map.apply(target, TrackElem::Field(Field::new(0)))
+                {
+                    state.assign_value(self.map, value_target, value);
+                }
+                if let Some(overflow_target) =
+                    self.map.apply(target, TrackElem::Field(Field::new(1)))
+                {
+                    state.assign_value(self.map, overflow_target, overflow);
+                }
+            }
+            Rvalue::Aggregate(kind, operands) => {
+                // The operands may be read from the target, so evaluate them before it is
+                // overwritten. Aggregates read from it can't be copied field by field afterwards.
+                let values: Vec<_> = operands
+                    .iter()
+                    .map(|operand| match self.eval_operand(operand) {
+                        ValueOrPlace::Place(place) if self.map.is_scalar(place) => {
+                            ValueOrPlace::Value(state.get(place))
+                        }
+                        ValueOrPlace::Place(_)
+                            if operand.place().map(|place| place.local) == Some(target_local) =>
+                        {
+                            ValueOrPlace::Value(FlatSet::Top)
+                        }
+                        value => value,
+                    })
+                    .collect();
+                state.flood(self.map, target);
+                let fields_place = match **kind {
+                    AggregateKind::Tuple => Some(target),
+                    AggregateKind::Adt(def_id, variant_index, _, _, None)
+                        if self.tcx.adt_def(def_id).is_enum() =>
+                    {
+                        if let Some(discr_target) = self.map.apply(target, TrackElem::Discriminant)
+                        {
+                            let discr =
+                                self.discriminant(self.map.places[target].ty, variant_index);
+                            state.assign_value(self.map, discr_target, discr);
+                        }
+                        self.map.apply(target, TrackElem::Variant(variant_index))
+                    }
+                    AggregateKind::Adt(_, _, _, _, None) => Some(target),
+                    _ => None,
+                };
+                let Some(fields_place) = fields_place else { return };
+                for (i, value) in values.into_iter().enumerate() {
+                    let field = TrackElem::Field(Field::new(i));
+                    if let Some(field_target) = self.map.apply(fields_place, field) {
+                        state.assign(self.map, field_target, value);
+                    }
+                }
+            }
+            _ => {
+                let value = self.eval_rvalue(state, rvalue);
+                state.assign(self.map, target, value);
+            }
+        }
+    }
+
+    fn handle_set_discriminant(&self, state: &mut State, place: Place<'tcx>, variant: VariantIdx) {
+        let Some(place) = self.find_written(state, place) else { return };
+        // The fields of the new variant have just been written, so we keep them.
+        self.flood_other_variants(state, place, variant);
+        if let Some(discr_target) = self.map.apply(place, TrackElem::Discriminant) {
+            let discr = self.discriminant(self.map.places[place].ty, variant);
+            state.assign_value(self.map, discr_target, discr);
+        }
+    }
+
+    fn discriminant(&self, enum_ty: Ty<'tcx>, variant: VariantIdx) -> FlatSet<ScalarInt> {
+        let Some(discr) = enum_ty.discriminant_for_variant(self.tcx, variant) else {
+            return FlatSet::Top;
+        };
+        let Ok(layout) = self.ecx.layout_of(discr.ty) else { return FlatSet::Top };
+        match ScalarInt::try_from_uint(discr.val, layout.size) {
+            Some(discr) => FlatSet::Elem(discr),
+            None => FlatSet::Top,
+        }
+    }
+
+    fn eval_rvalue(&self, state: &State, rvalue: &Rvalue<'tcx>) -> ValueOrPlace {
+        match rvalue {
+            Rvalue::Use(operand) => self.eval_operand(operand),
+            Rvalue::Discriminant(place) => {
+                match self
+                    .map
+                    .find(place.as_ref())
+                    .and_then(|place| self.map.apply(place, TrackElem::Discriminant))
+                {
+                    Some(discr) => ValueOrPlace::Place(discr),
+                    None => ValueOrPlace::Value(FlatSet::Top),
+                }
+            }
+            Rvalue::BinaryOp(op, box (left, right)) => {
+                ValueOrPlace::Value(self.binary_op(state, *op, left, right).0)
+            }
+            Rvalue::UnaryOp(op, operand) => {
+                let value = match self.eval_operand_value(state, operand) {
+                    FlatSet::Elem(value) => self
+                        .immediate(value, operand.ty(self.local_decls, self.tcx))
+                        .and_then(|value| self.ecx.overflowing_unary_op(*op, &value).ok())
+                        .and_then(|(value, _, _)| value.try_to_int().ok())
+                        .map_or(FlatSet::Top, FlatSet::Elem),
+                    value => value,
+                };
+                ValueOrPlace::Value(value)
+            }
+            Rvalue::Cast(CastKind::Misc, operand, cast_ty) => {
+                let value = match self.eval_operand_value(state, operand) {
+                    FlatSet::Elem(value) => self
+                        .int_cast(value, operand.ty(self.local_decls, self.tcx), *cast_ty)
+                        .map_or(FlatSet::Top, FlatSet::Elem),
+                    value => value,
+                };
+                ValueOrPlace::Value(value)
+            }
+            _ => ValueOrPlace::Value(FlatSet::Top),
+        }
+    }
+
+    fn eval_operand(&self, operand: &Operand<'tcx>) -> ValueOrPlace {
+        match operand {
+            Operand::Constant(constant) => ValueOrPlace::Value(self.eval_constant(constant)),
+            Operand::Copy(place) | Operand::Move(place) => match self.map.find(place.as_ref()) {
+                Some(place) => ValueOrPlace::Place(place),
+                None => ValueOrPlace::Value(FlatSet::Top),
+            },
+        }
+    }
+
+    /// Returns the value of a scalar operand.
+    fn eval_operand_value(&self, state: &State, operand: &Operand<'tcx>) -> FlatSet<ScalarInt> {
+        match self.eval_operand(operand) {
+            ValueOrPlace::Value(value) => value,
+            ValueOrPlace::Place(place) if self.map.is_scalar(place) => state.get(place),
+            ValueOrPlace::Place(_) => FlatSet::Top,
+        }
+    }
+
+    fn eval_constant(&self, constant: &Constant<'tcx>) -> FlatSet<ScalarInt> {
+        constant
+            .literal
+            .eval(self.tcx, self.param_env)
+            .try_to_scalar()
+            .and_then(|value| value.try_to_int().ok())
+            .map_or(FlatSet::Top, FlatSet::Elem)
+    }
+
+    /// Returns the value and the overflow flag of `left op right`.
+    fn binary_op(
+        &self,
+        state: &State,
+        op: BinOp,
+        left: &Operand<'tcx>,
+        right: &Operand<'tcx>,
+    ) -> (FlatSet<ScalarInt>, FlatSet<ScalarInt>) {
+        match (self.eval_operand_value(state, left), self.eval_operand_value(state, right)) {
+            (FlatSet::Bottom, _) | (_, FlatSet::Bottom) => (FlatSet::Bottom, FlatSet::Bottom),
+            (FlatSet::Elem(left_value), FlatSet::Elem(right_value)) => {
+                let left = self.immediate(left_value, left.ty(self.local_decls, self.tcx));
+                let right = self.immediate(right_value, right.ty(self.local_decls, self.tcx));
+                let Some((left, right)) = left.zip(right) else {
+                    return (FlatSet::Top, FlatSet::Top);
+                };
+                match self.ecx.overflowing_binary_op(op, &left, &right) {
+                    Ok((value, overflow, _)) => (
+                        value.try_to_int().map_or(FlatSet::Top, FlatSet::Elem),
+                        FlatSet::Elem(ScalarInt::from(overflow)),
+                    ),
+                    Err(_) => (FlatSet::Top, FlatSet::Top),
+                }
+            }
+            _ => (FlatSet::Top, FlatSet::Top),
+        }
+    }
+
+    fn immediate(&self, value: ScalarInt, ty: Ty<'tcx>) -> Option<ImmTy<'tcx>> {
+        Some(ImmTy::from_scalar(Scalar::from(value), self.ecx.layout_of(ty).ok()?))
+    }
+
+    /// Casts an integer, `bool` or `char` to an integer type.
+    fn int_cast(&self, value: ScalarInt, from: Ty<'tcx>, to: Ty<'tcx>) -> Option<ScalarInt> {
+        if !(from.is_integral() || from.is_bool() || from.is_char()) || !to.is_integral() {
+            return None;
+        }
+        let bits = value.assert_bits(value.size());
+        let bits = if from.is_signed() { value.size().sign_extend(bits) } else { bits };
+        let size = self.ecx.layout_of(to).ok()?.size;
+        ScalarInt::try_from_uint(size.truncate(bits), size)
+    }
+}
+
+/// Collects the values found by the analysis, and then replaces the operands and assignments
+/// they make constant.
+struct CollectAndPatch<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    map: &'a Map<'tcx>,
+    /// The known values of the places used as operands at each location, before its effect.
+    before_effect: FxHashMap<(Location, Place<'tcx>), (ScalarInt, Ty<'tcx>)>,
+    /// The known values assigned by the assignments at each location.
+    assignments: FxHashMap<Location, (ScalarInt, Ty<'tcx>)>,
+}
+
+impl<'a, 'tcx> CollectAndPatch<'a, 'tcx> {
+    fn new(tcx: TyCtxt<'tcx>, map: &'a Map<'tcx>) -> Self {
+        Self { tcx, map, before_effect: FxHashMap::default(), assignments: FxHashMap::default() }
+    }
+
+    fn known_value(&self, state: &State, place: PlaceRef<'_>) -> Option<(ScalarInt, Ty<'tcx>)> {
+        let place = self.map.find(place)?;
+        match state.get(place) {
+            FlatSet::Elem(value) if self.map.is_scalar(place) => {
+                Some((value, self.map.places[place].ty))
+            }
+            _ => None,
+        }
+    }
+
+    fn make_operand(&self, (value, ty): (ScalarInt, Ty<'tcx>)) -> Operand<'tcx> {
+        Operand::Constant(Box::new(Constant {
+            span: DUMMY_SP,
+            user_ty: None,
+            literal: ConstantKind::Val(ConstValue::Scalar(Scalar::Int(value)), ty),
+        }))
+    }
+}
+
+impl<'mir, 'a, 'tcx> ResultsVisitor<'mir, 'tcx> for CollectAndPatch<'a, 'tcx> {
+    type FlowState = State;
+
+    fn visit_statement_before_primary_effect(
+        &mut self,
+        state: &State,
+        statement: &'mir Statement<'tcx>,
+        location: Location,
+    ) {
+        if let StatementKind::Assign(box (_, rvalue)) = &statement.kind {
+            OperandCollector { state, visitor: self }.visit_rvalue(rvalue, location);
+        }
+    }
+
+    fn visit_statement_after_primary_effect(
+        &mut self,
+        state: &State,
+        statement: &'mir Statement<'tcx>,
+        location: Location,
+    ) {
+        match statement.kind {
+            // Don't replace assignments that already assign a constant.
+            StatementKind::Assign(box (_, Rvalue::Use(Operand::Constant(_)))) => {}
+            StatementKind::Assign(box (place, _)) => {
+                if let Some(value) = self.known_value(state, place.as_ref()) {
+                    self.assignments.insert(location, value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_terminator_before_primary_effect(
+        &mut self,
+        state: &State,
+        terminator: &'mir Terminator<'tcx>,
+        location: Location,
+    ) {
+        OperandCollector { state, visitor: self }.visit_terminator(terminator, location);
+    }
+}
+
+impl<'a, 'tcx> MutVisitor<'tcx> for CollectAndPatch<'a, 'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_statement(&mut self, statement: &mut Statement<'tcx>, location: Location) {
+        if let Some(&value) = self.assignments.get(&location) {
+            match &mut statement.kind {
+                StatementKind::Assign(box (_, rvalue)) => {
+                    *rvalue = Rvalue::Use(self.make_operand(value));
+                }
+                _ => bug!("found assignment info for non-assign statement"),
+            }
+        } else {
+            self.super_statement(statement, location);
+        }
+    }
+
+    fn visit_operand(&mut self, operand: &mut Operand<'tcx>, location: Location) {
+        if let Operand::Copy(place) | Operand::Move(place) = *operand {
+            if let Some(&value) = self.before_effect.get(&(location, place)) {
+                *operand = self.make_operand(value);
+            }
+        }
+    }
+}
+
+struct OperandCollector<'s, 'v, 'a, 'tcx> {
+    state: &'s State,
+    visitor: &'v mut CollectAndPatch<'a, 'tcx>,
+}
+
+impl<'tcx> Visitor<'tcx> for OperandCollector<'_, '_, '_, 'tcx> {
+    fn visit_operand(&mut self, operand: &Operand<'tcx>, location: Location) {
+        if let Operand::Copy(place) | Operand::Move(place) = *operand {
+            if let Some(value) = self.visitor.known_value(self.state, place.as_ref()) {
+                self.visitor.before_effect.insert((location, place), value);
+            }
+        }
+    }
+}
+
+/// A machine that can't do anything but evaluate operators on immediates.
+struct DummyMachine<'mir, 'tcx> {
+    stack: Vec<Frame<'mir, 'tcx>>,
+}
+
+impl DummyMachine<'_, '_> {
+    fn new() -> Self {
+        DummyMachine { stack: Vec::new() }
+    }
+}
+
+impl<'mir, 'tcx> interpret::Machine<'mir, 'tcx> for DummyMachine<'mir, 'tcx> {
+    compile_time_machine!(<'mir, 'tcx>);
+    const PANIC_ON_ALLOC_FAIL: bool = true;
+
+    type MemoryKind = !;
+
+    type MemoryExtra = ();
+
+    fn find_mir_or_eval_fn(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _instance: ty::Instance<'tcx>,
+        _abi: Abi,
+        _args: &[OpTy<'tcx>],
+        _ret: Option<(&PlaceTy<'tcx>, BasicBlock)>,
+        _unwind: StackPopUnwind,
+    ) -> InterpResult<'tcx, Option<(&'mir Body<'tcx>, ty::Instance<'tcx>)>> {
+        throw_unsup_format!("calling functions isn't supported in DataflowConstProp")
+    }
+
+    fn call_intrinsic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _instance: ty::Instance<'tcx>,
+        _args: &[OpTy<'tcx>],
+        _ret: Option<(&PlaceTy<'tcx>, BasicBlock)>,
+        _unwind: StackPopUnwind,
+    ) -> InterpResult<'tcx> {
+        throw_unsup_format!("calling intrinsics isn't supported in DataflowConstProp")
+    }
+
+    fn assert_panic(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        _msg: &AssertMessage<'tcx>,
+        _unwind: Option<BasicBlock>,
+    ) -> InterpResult<'tcx> {
+        bug!("panics terminators are not evaluated in DataflowConstProp")
+    }
+
+    fn binary_ptr_op(
+        _ecx: &InterpCx<'mir, 'tcx, Self>,
+        _bin_op: BinOp,
+        _left: &ImmTy<'tcx>,
+        _right: &ImmTy<'tcx>,
+    ) -> InterpResult<'tcx, (Scalar, bool, Ty<'tcx>)> {
+        throw_unsup_format!("pointer arithmetic isn't supported in DataflowConstProp")
+    }
+
+    #[inline(always)]
+    fn init_frame_extra(
+        _ecx: &mut InterpCx<'mir, 'tcx, Self>,
+        frame: Frame<'mir, 'tcx>,
+    ) -> InterpResult<'tcx, Frame<'mir, 'tcx>> {
+        Ok(frame)
+    }
+
+    #[inline(always)]
+    fn stack<'a>(
+        ecx: &'a InterpCx<'mir, 'tcx, Self>,
+    ) -> &'a [Frame<'mir, 'tcx, Self::PointerTag, Self::FrameExtra>] {
+        &ecx.machine.stack
+    }
+
+    #[inline(always)]
+    fn stack_mut<'a>(
+        ecx: &'a mut InterpCx<'mir, 'tcx, Self>,
+    ) -> &'a mut Vec<Frame<'mir, 'tcx, Self::PointerTag, Self::FrameExtra>> {
+        &mut ecx.machine.stack
+    }
+}
@@ -0,0 +1,96 @@
+//! This module implements a dead store elimination (DSE) routine.
+//!
+//! This transformation was written specifically for the needs of the MIR produced by derive
+//! expansions and inlining, which tends to contain many temporaries that are written but never
+//! read again. It removes assignments (and `SetDiscriminant` statements) to locals which are not
+//! live directly after the statement, as computed by `MaybeLiveLocals`.
+//!
+//! `MaybeLiveLocals` only considers a borrow to be a use at the point at which the reference is
+//! created, so writes to locals whose address is taken anywhere in the body must be kept: they may
+//! be observed through the reference later on. In the same vein, writes through a dereference are
+//! never removed.
+//!
+//! The pass makes a single pass over the body, so a store whose value is only read by another dead
+//! store is not removed, since that read makes it live.
+
+use crate::MirPass;
+use rustc_index::bit_set::BitSet;
+use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+use rustc_mir_dataflow::impls::MaybeLiveLocals;
+use rustc_mir_dataflow::Analysis;
+
+pub struct DeadStoreElimination;
+
+impl<'tcx> MirPass<'tcx> for DeadStoreElimination {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 2
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let borrowed = borrowed_locals(body);
+        let mut live =
+            MaybeLiveLocals.into_engine(tcx, body).iterate_to_fixpoint().into_results_cursor(body);
+
+        let mut dead_stores = Vec::new();
+        for (block, data) in body.basic_blocks().iter_enumerated() {
+            // Visit the statements in the direction of the analysis to keep seeking cheap.
+            for (statement_index, statement) in data.statements.iter().enumerate().rev() {
+                let place = match &statement.kind {
+                    StatementKind::Assign(box (place, _)) => place,
+                    StatementKind::SetDiscriminant { place, .. } => &**place,
+                    _ => continue,
+                };
+                if place.is_indirect() || borrowed.contains(place.local) {
+                    continue;
+                }
+
+                // For a backward analysis, the state before the primary effect of a statement is
+                // the state right *after* it was executed.
+                let location = Location { block, statement_index };
+                live.seek_before_primary_effect(location);
+                if !live.get().contains(place.local) {
+                    dead_stores.push(location);
+                }
+            }
+        }
+
+        debug!("removing {} dead stores in {:?}", dead_stores.len(), body.source.def_id());
+        let basic_blocks = body.basic_blocks_mut();
+        for Location { block, statement_index } in dead_stores {
+            basic_blocks[block].statements[statement_index].make_nop();
+        }
+    }
+}
+
+/// Returns the set of locals which are borrowed, or whose address is taken, anywhere in `body`.
+fn borrowed_locals(body: &Body<'_>) -> BitSet<Local> {
+    struct BorrowCollector {
+        borrowed: BitSet<Local>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for BorrowCollector {
+        fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+            self.super_place(place, context, location);
+
+            if matches!(
+                context,
+                PlaceContext::NonMutatingUse(
+                    NonMutatingUseContext::SharedBorrow
+                        | NonMutatingUseContext::ShallowBorrow
+                        | NonMutatingUseContext::UniqueBorrow
+                        | NonMutatingUseContext::AddressOf
+                ) | PlaceContext::MutatingUse(
+                    MutatingUseContext::Borrow | MutatingUseContext::AddressOf
+                )
+            ) {
+                self.borrowed.insert(place.local);
+            }
+        }
+    }
+
+    let mut collector = BorrowCollector { borrowed: BitSet::new_empty(body.local_decls.len()) };
+    collector.visit_body(body);
+    collector.borrowed
+}
@@ -0,0 +1,102 @@
+//! This pass splits the places which dereference more than once, or which dereference after
+//! other projections, into a chain of places which each dereference a local directly.
+//!
+//! `_x = (*((*_1).0)).1` becomes
+//!
+//! ```text
+//! _2 = deref_copy ((*_1).0);
+//! _x = (*_2).1;
+//! ```
+//!
+//! After this pass, `Deref` is only ever the first projection of the places in statements and
+//! terminators, so later passes and the backends don't have to handle places like `**x`. The copies
+//! are `Rvalue::CopyForDeref`, since the intermediate pointers can be `&mut` or `Box`es, which may
+//! not be copied with `Operand::Copy`.
+//!
+//! The places of the user variables' debuginfo are left alone: they keep their full projections,
+//! which codegen's debuginfo lowers to a chain of dereferences of the variable's base local, so
+//! debuggers still show the original variables even after the temporaries are dead.
+
+use crate::MirPass;
+use rustc_middle::mir::patch::MirPatch;
+use rustc_middle::mir::visit::{MutVisitor, PlaceContext};
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+
+pub struct Derefer;
+
+impl<'tcx> MirPass<'tcx> for Derefer {
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        deref_finder(tcx, body);
+    }
+}
+
+/// Splits the places of `body` so that they only dereference their base local. This is run as
+/// part of the passes which introduce such places again, like inlining.
+pub fn deref_finder<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+    let patch = MirPatch::new(body);
+    let (basic_blocks, local_decls) = body.basic_blocks_and_local_decls_mut();
+    let mut checker = DerefChecker { tcx, patch, local_decls, statements_len: 0, changed: false };
+    for (block, data) in basic_blocks.iter_enumerated_mut() {
+        checker.statements_len = data.statements.len();
+        checker.visit_basic_block_data(block, data);
+    }
+    let DerefChecker { patch, changed, .. } = checker;
+    if changed {
+        patch.apply(body);
+    }
+}
+
+struct DerefChecker<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    patch: MirPatch<'tcx>,
+    local_decls: &'a LocalDecls<'tcx>,
+    /// The number of statements of the block being visited, to tell its terminator apart.
+    statements_len: usize,
+    changed: bool,
+}
+
+impl<'tcx> MutVisitor<'tcx> for DerefChecker<'_, 'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_place(&mut self, place: &mut Place<'tcx>, _: PlaceContext, location: Location) {
+        if !place.projection.iter().skip(1).any(|elem| elem == ProjectionElem::Deref) {
+            return;
+        }
+
+        self.changed = true;
+        let tcx = self.tcx;
+        let span = self.local_decls[place.local].source_info.span;
+        let in_statement = location.statement_index < self.statements_len;
+        let mut base = place.local;
+        let mut start = 0;
+        let mut temps = Vec::new();
+        for (i, elem) in place.projection.iter().enumerate().skip(1) {
+            if elem != ProjectionElem::Deref {
+                continue;
+            }
+            let ty = Place::ty_from(place.local, &place.projection[..i], self.local_decls, tcx).ty;
+            let projection = tcx.intern_place_elems(&place.projection[start..i]);
+            let pointer = Place { local: base, projection };
+            let temp = self.patch.new_temp(ty, span);
+            if in_statement {
+                self.patch.add_statement(location, StatementKind::StorageLive(temp));
+                temps.push(temp);
+            }
+            self.patch.add_assign(location, Place::from(temp), Rvalue::CopyForDeref(pointer));
+            base = temp;
+            start = i;
+        }
+        let projection = tcx.intern_place_elems(&place.projection[start..]);
+        *place = Place { local: base, projection };
+
+        // The temporaries are only used by this statement. Those of a terminator are left
+        // without storage markers, since there is no room after it for them to die.
+        for temp in temps {
+            let after = location.successor_within_block();
+            self.patch.add_statement(after, StatementKind::StorageDead(temp));
+        }
+    }
+}
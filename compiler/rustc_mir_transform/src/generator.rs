@@ -49,6 +49,7 @@
 //! For generators with state 1 (returned) and state 2 (poisoned) it does nothing.
 //! Otherwise it drops all the values in scope at the last suspension point.
 
+use crate::deref_separator::deref_finder;
 use crate::simplify;
 use crate::util::expand_aggregate;
 use crate::MirPass;
@@ -780,12 +781,26 @@ fn compute_layout<'tcx>(
         storage_liveness,
     } = liveness;
 
-    // Gather live local types and their indices.
+    // Gather live local types, names and their indices.
+    let mut names = FxHashMap::default();
+    for var in &body.var_debug_info {
+        // Skip the bindings introduced by desugarings, like the awaited future of `.await`.
+        if var.source_info.span.desugaring_kind().is_some() {
+            continue;
+        }
+        if let VarDebugInfoContents::Place(place) = var.value {
+            if let Some(local) = place.as_local() {
+                names.entry(local).or_insert(var.name);
+            }
+        }
+    }
     let mut locals = IndexVec::<GeneratorSavedLocal, _>::new();
     let mut tys = IndexVec::<GeneratorSavedLocal, _>::new();
+    let mut field_names = IndexVec::<GeneratorSavedLocal, _>::new();
     for (saved_local, local) in saved_locals.iter_enumerated() {
         locals.push(local);
         tys.push(body.local_decls[local].ty);
+        field_names.push(names.get(&local).copied());
         debug!("generator saved local {:?} => {:?}", saved_local, local);
     }
 
@@ -825,8 +840,13 @@ fn compute_layout<'tcx>(
     debug!("generator variant_fields = {:?}", variant_fields);
     debug!("generator storage_conflicts = {:#?}", storage_conflicts);
 
-    let layout =
-        GeneratorLayout { field_tys: tys, variant_fields, variant_source_info, storage_conflicts };
+    let layout = GeneratorLayout {
+        field_tys: tys,
+        field_names,
+        variant_fields,
+        variant_source_info,
+        storage_conflicts,
+    };
 
     (remap, layout, storage_liveness)
 }
@@ -968,6 +988,10 @@ fn create_generator_drop_shim<'tcx>(
     // unrelated code from the resume part of the function
     simplify::remove_dead_blocks(tcx, &mut body);
 
+    // The generator state is now accessed through a pointer to it, which adds a dereference in
+    // front of the places based on the saved locals.
+    deref_finder(tcx, &mut body);
+
     dump_mir(tcx, None, "generator_drop", &0, &body, |_, _| Ok(()));
 
     body
@@ -992,7 +1016,7 @@ fn insert_panic_block<'tcx>(
         cond: Operand::Constant(Box::new(Constant {
             span: body.span,
             user_ty: None,
-            literal: ty::Const::from_bool(tcx, false).into(),
+            literal: ConstantKind::from_bool(tcx, false),
         })),
         expected: true,
         msg: message,
@@ -1059,6 +1083,7 @@ fn can_unwind<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> bool {
             TerminatorKind::Drop { .. }
             | TerminatorKind::DropAndReplace { .. }
             | TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Assert { .. } => return true,
         }
     }
@@ -1139,6 +1164,9 @@ fn create_generator_resume_function<'tcx>(
     // unrelated code from the drop part of the function
     simplify::remove_dead_blocks(tcx, body);
 
+    // As in the drop shim, the generator state is now behind a pointer.
+    deref_finder(tcx, body);
+
     dump_mir(tcx, None, "generator_resume", &0, body, |_, _| Ok(()));
 }
 
@@ -1489,6 +1517,7 @@ fn visit_terminator(&mut self, terminator: &Terminator<'tcx>, location: Location
             TerminatorKind::InlineAsm { .. } => {}
 
             TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Goto { .. }
             | TerminatorKind::SwitchInt { .. }
             | TerminatorKind::Resume
@@ -0,0 +1,226 @@
+//! Global value numbering.
+//!
+//! This pass finds assignments whose rvalue computes a value that is already held by an earlier
+//! local, and replaces them with a copy of that local. Typical candidates are repeated arithmetic,
+//! `Len` of the same slice (after `LowerSliceLenCalls`), and repeated loads of the same field.
+//!
+//! Only "pure" rvalues are numbered, and only if every local they read is in SSA form, as computed
+//! by the `ssa` module. The value of such a local can't change between its definition and any of
+//! its uses, so two occurrences of the same rvalue over SSA locals evaluate to the same value. SSA
+//! locals are never mutably borrowed, and borrows and raw pointers are the only way to alias a
+//! local, so this is sound without an alias analysis. Reads through a dereference are not
+//! numbered, except for the length of a slice, which is part of the pointer itself.
+//!
+//! An occurrence is only replaced by a local whose definition dominates it. The blocks are visited
+//! in reverse postorder, so the dominating definitions are always seen first. Since the reused
+//! locals now live longer than the MIR builder intended, their storage markers are removed, and
+//! the moves out of them (e.g. into call arguments, which the callee may mutate) are turned into
+//! copies.
+
+use crate::ssa::SsaLocals;
+use crate::MirPass;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_index::bit_set::BitSet;
+use rustc_index::vec::IndexVec;
+use rustc_middle::mir::visit::MutVisitor;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{Ty, TyCtxt};
+
+pub struct GVN;
+
+impl<'tcx> MirPass<'tcx> for GVN {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let def_id = body.source.def_id();
+        let param_env = tcx.param_env_reveal_all_normalized(def_id);
+        let ssa = SsaLocals::new(tcx, param_env, body);
+        let dominators = body.dominators();
+
+        let mut numbering = ValueNumbering {
+            ssa: &ssa,
+            representatives: IndexVec::from_fn_n(|local| local, body.local_decls.len()),
+        };
+        let mut values: FxHashMap<Value<'tcx>, Vec<(Local, Location)>> = FxHashMap::default();
+        let mut replacements = Vec::new();
+        let mut reused = BitSet::new_empty(body.local_decls.len());
+
+        for (block, data) in traversal::reverse_postorder(body) {
+            for (statement_index, statement) in data.statements.iter().enumerate() {
+                let StatementKind::Assign(box (place, rvalue)) = &statement.kind else { continue };
+                let location = Location { block, statement_index };
+                let dest = place.as_local().filter(|&dest| ssa.is_ssa(dest));
+
+                // Copies between locals just make the destination an alias for the source.
+                if let Rvalue::Use(Operand::Copy(source) | Operand::Move(source)) = rvalue {
+                    if let Some(source) = source.as_local() {
+                        if let Some(dest) = dest {
+                            if ssa.is_ssa(source) {
+                                numbering.representatives[dest] = numbering.representatives[source];
+                            }
+                        }
+                        continue;
+                    }
+                }
+
+                let Some(value) = numbering.value_of(rvalue) else { continue };
+                let available = values.get(&value).and_then(|candidates| {
+                    candidates.iter().find(|(_, defined)| defined.dominates(location, &dominators))
+                });
+                match available {
+                    Some(&(source, _)) => {
+                        if !tcx.consider_optimizing(|| {
+                            format!("GVN {:?} = {:?} in {:?}", place, source, def_id)
+                        }) {
+                            continue;
+                        }
+                        debug!("replacing {:?} at {:?} with {:?}", rvalue, location, source);
+                        replacements.push((location, source));
+                        reused.insert(source);
+                        if let Some(dest) = dest {
+                            numbering.representatives[dest] = source;
+                        }
+                    }
+                    None => {
+                        // Only locals of `Copy` type can be reused, since we copy out of them.
+                        if let Some(dest) = dest {
+                            let ty = body.local_decls[dest].ty;
+                            let span = statement.source_info.span;
+                            if ty.is_copy_modulo_regions(tcx.at(span), param_env) {
+                                values.entry(value).or_default().push((dest, location));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if replacements.is_empty() {
+            return;
+        }
+
+        let basic_blocks = body.basic_blocks_mut();
+        for (Location { block, statement_index }, source) in replacements {
+            match &mut basic_blocks[block].statements[statement_index].kind {
+                StatementKind::Assign(box (_, rvalue)) => {
+                    *rvalue = Rvalue::Use(Operand::Copy(source.into()));
+                }
+                _ => bug!("GVN replacement for a statement that is not an assignment"),
+            }
+        }
+        ExtendReused { tcx, reused }.visit_body(body);
+    }
+}
+
+/// An operand of a numbered value.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+enum ValueOperand<'tcx> {
+    Local(Local),
+    Constant(ConstantKind<'tcx>),
+}
+
+/// A pure computation over SSA locals, with every local replaced by its representative.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+enum Value<'tcx> {
+    Load(Place<'tcx>),
+    Len(Place<'tcx>),
+    Discriminant(Place<'tcx>),
+    Cast(CastKind, ValueOperand<'tcx>, Ty<'tcx>),
+    BinaryOp(BinOp, ValueOperand<'tcx>, ValueOperand<'tcx>),
+    CheckedBinaryOp(BinOp, ValueOperand<'tcx>, ValueOperand<'tcx>),
+    UnaryOp(UnOp, ValueOperand<'tcx>),
+    NullaryOp(NullOp, Ty<'tcx>),
+}
+
+struct ValueNumbering<'a> {
+    ssa: &'a SsaLocals,
+    /// For each SSA local, the earliest local known to hold the same value.
+    representatives: IndexVec<Local, Local>,
+}
+
+impl<'a> ValueNumbering<'a> {
+    fn value_of<'tcx>(&self, rvalue: &Rvalue<'tcx>) -> Option<Value<'tcx>> {
+        let value = match *rvalue {
+            Rvalue::Use(Operand::Copy(place) | Operand::Move(place)) => {
+                Value::Load(self.place(place, false)?)
+            }
+            Rvalue::Len(place) => Value::Len(self.place(place, true)?),
+            Rvalue::Discriminant(place) => Value::Discriminant(self.place(place, false)?),
+            Rvalue::Cast(kind, ref operand, ty) => Value::Cast(kind, self.operand(operand)?, ty),
+            Rvalue::BinaryOp(op, box (ref left, ref right)) => {
+                Value::BinaryOp(op, self.operand(left)?, self.operand(right)?)
+            }
+            Rvalue::CheckedBinaryOp(op, box (ref left, ref right)) => {
+                Value::CheckedBinaryOp(op, self.operand(left)?, self.operand(right)?)
+            }
+            Rvalue::UnaryOp(op, ref operand) => Value::UnaryOp(op, self.operand(operand)?),
+            Rvalue::NullaryOp(op, ty) => Value::NullaryOp(op, ty),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    fn operand<'tcx>(&self, operand: &Operand<'tcx>) -> Option<ValueOperand<'tcx>> {
+        match *operand {
+            Operand::Constant(ref constant) => Some(ValueOperand::Constant(constant.literal)),
+            Operand::Copy(place) | Operand::Move(place) => {
+                let local = place.as_local()?;
+                self.ssa.is_ssa(local).then(|| ValueOperand::Local(self.representatives[local]))
+            }
+        }
+    }
+
+    /// Returns the canonical form of a place whose value only depends on its SSA base local. A
+    /// trailing dereference is only allowed if `allow_deref` is set, for `Len`.
+    fn place<'tcx>(&self, place: Place<'tcx>, allow_deref: bool) -> Option<Place<'tcx>> {
+        if !self.ssa.is_ssa(place.local) {
+            return None;
+        }
+        let last = place.projection.len().checked_sub(1);
+        for (i, elem) in place.projection.iter().enumerate() {
+            match elem {
+                ProjectionElem::Field(..)
+                | ProjectionElem::Downcast(..)
+                | ProjectionElem::ConstantIndex { .. }
+                | ProjectionElem::Subslice { .. } => {}
+                ProjectionElem::Deref if allow_deref && Some(i) == last => {}
+                ProjectionElem::Deref | ProjectionElem::Index(_) => return None,
+            }
+        }
+        Some(Place { local: self.representatives[place.local], projection: place.projection })
+    }
+}
+
+/// Makes the reused locals live for the whole body, and their value available to every later use.
+struct ExtendReused<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    reused: BitSet<Local>,
+}
+
+impl<'tcx> MutVisitor<'tcx> for ExtendReused<'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_statement(&mut self, statement: &mut Statement<'tcx>, location: Location) {
+        match statement.kind {
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local)
+                if self.reused.contains(local) =>
+            {
+                statement.make_nop();
+            }
+            _ => self.super_statement(statement, location),
+        }
+    }
+
+    fn visit_operand(&mut self, operand: &mut Operand<'tcx>, location: Location) {
+        if let Operand::Move(place) = *operand {
+            if self.reused.contains(place.local) {
+                *operand = Operand::Copy(place);
+            }
+        }
+        self.super_operand(operand, location);
+    }
+}
@@ -13,18 +13,16 @@
 use rustc_target::spec::abi::Abi;
 
 use super::simplify::{remove_dead_blocks, CfgSimplifier};
+use crate::deref_separator::deref_finder;
 use crate::MirPass;
+use std::fmt;
 use std::iter;
 use std::ops::{Range, RangeFrom};
 
+mod cost;
 crate mod cycle;
 
-const INSTR_COST: usize = 5;
-const CALL_PENALTY: usize = 25;
-const LANDINGPAD_PENALTY: usize = 50;
-const RESUME_PENALTY: usize = 45;
-
-const UNKNOWN_SIZE_COST: usize = 10;
+use cost::{CostChecker, InlineCost};
 
 pub struct Inline;
 
@@ -35,6 +33,35 @@ struct CallSite<'tcx> {
     block: BasicBlock,
     target: Option<BasicBlock>,
     source_info: SourceInfo,
+    /// The number of arguments which are constants.
+    const_args: usize,
+}
+
+/// Why a call site was or wasn't inlined, as explained by `-Zinline-mir-report`.
+#[derive(Copy, Clone, Debug)]
+enum InlineDecision {
+    /// The call site can't or mustn't be inlined, regardless of the callee's cost.
+    Refused(&'static str),
+    /// The callee is `#[inline(always)]`, so its cost isn't compared to the threshold.
+    Always(usize),
+    /// The callee's cost was compared to the threshold.
+    Cost(InlineCost),
+}
+
+impl From<&'static str> for InlineDecision {
+    fn from(reason: &'static str) -> Self {
+        InlineDecision::Refused(reason)
+    }
+}
+
+impl fmt::Display for InlineDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            InlineDecision::Refused(reason) => f.write_str(reason),
+            InlineDecision::Always(cost) => write!(f, "inline(always) hint, cost {}", cost),
+            InlineDecision::Cost(cost) => write!(f, "{}", cost),
+        }
+    }
 }
 
 impl<'tcx> MirPass<'tcx> for Inline {
@@ -53,6 +80,8 @@ fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
             debug!("running simplify cfg on {:?}", body.source);
             CfgSimplifier::new(body).simplify();
             remove_dead_blocks(tcx, body);
+            // The callee's places may now be based on a dereference of the destination.
+            deref_finder(tcx, body);
         }
     }
 }
@@ -118,12 +147,14 @@ fn process_blocks(&mut self, caller_body: &mut Body<'tcx>, blocks: Range<BasicBl
             let _guard = span.enter();
 
             match self.try_inlining(caller_body, &callsite) {
-                Err(reason) => {
-                    debug!("not-inlined {} [{}]", callsite.callee, reason);
+                Err(decision) => {
+                    debug!("not-inlined {} [{}]", callsite.callee, decision);
+                    self.report(&callsite, "not inlined", decision);
                     continue;
                 }
-                Ok(new_blocks) => {
+                Ok((new_blocks, decision)) => {
                     debug!("inlined {}", callsite.callee);
+                    self.report(&callsite, "inlined", decision);
                     self.changed = true;
                     self.history.push(callsite.callee);
                     self.process_blocks(caller_body, new_blocks);
@@ -133,24 +164,35 @@ fn process_blocks(&mut self, caller_body: &mut Body<'tcx>, blocks: Range<BasicBl
         }
     }
 
+    /// Explains an inlining decision with a note at the call site, if `-Zinline-mir-report` is
+    /// enabled.
+    fn report(&self, callsite: &CallSite<'tcx>, outcome: &str, decision: InlineDecision) {
+        if self.tcx.sess.opts.debugging_opts.inline_mir_report {
+            self.tcx.sess.span_note_without_error(
+                callsite.source_info.span,
+                &format!("{} `{}`: {}", outcome, callsite.callee, decision),
+            );
+        }
+    }
+
     /// Attempts to inline a callsite into the caller body. When successful returns basic blocks
-    /// containing the inlined body. Otherwise returns an error describing why inlining didn't take
-    /// place.
+    /// containing the inlined body, and why it was inlined. Otherwise returns why inlining didn't
+    /// take place.
     fn try_inlining(
         &self,
         caller_body: &mut Body<'tcx>,
         callsite: &CallSite<'tcx>,
-    ) -> Result<std::ops::Range<BasicBlock>, &'static str> {
+    ) -> Result<(Range<BasicBlock>, InlineDecision), InlineDecision> {
         let callee_attrs = self.tcx.codegen_fn_attrs(callsite.callee.def_id());
         self.check_codegen_attributes(callsite, callee_attrs)?;
         self.check_mir_is_available(caller_body, &callsite.callee)?;
         let callee_body = self.tcx.instance_mir(callsite.callee.def);
-        self.check_mir_body(callsite, callee_body, callee_attrs)?;
+        let decision = self.check_mir_body(callsite, callee_body, callee_attrs)?;
 
         if !self.tcx.consider_optimizing(|| {
             format!("Inline {:?} into {:?}", callsite.callee, caller_body.source)
         }) {
-            return Err("optimization fuel exhausted");
+            return Err("optimization fuel exhausted".into());
         }
 
         let callee_body = callsite.callee.subst_mir_and_normalize_erasing_regions(
@@ -163,7 +205,7 @@ fn try_inlining(
         self.inline_call(caller_body, &callsite, callee_body);
         let new_blocks = old_blocks..caller_body.basic_blocks().next_index();
 
-        Ok(new_blocks)
+        Ok((new_blocks, decision))
     }
 
     fn check_mir_is_available(
@@ -245,7 +287,7 @@ fn resolve_callsite(
     ) -> Option<CallSite<'tcx>> {
         // Only consider direct calls to functions
         let terminator = bb_data.terminator();
-        if let TerminatorKind::Call { ref func, ref destination, .. } = terminator.kind {
+        if let TerminatorKind::Call { ref func, ref args, ref destination, .. } = terminator.kind {
             let func_ty = func.ty(caller_body, self.tcx);
             if let ty::FnDef(def_id, substs) = *func_ty.kind() {
                 // To resolve an instance its substs have to be fully normalized.
@@ -265,6 +307,7 @@ fn resolve_callsite(
                     block: bb,
                     target: destination.map(|(_, target)| target),
                     source_info: terminator.source_info,
+                    const_args: args.iter().filter(|arg| arg.constant().is_some()).count(),
                 });
             }
         }
@@ -280,7 +323,7 @@ fn check_codegen_attributes(
         callee_attrs: &CodegenFnAttrs,
     ) -> Result<(), &'static str> {
         if let InlineAttr::Never = callee_attrs.inline {
-            return Err("never inline hint");
+            return Err("inline(never) hint");
         }
 
         // Only inline local functions if they would be eligible for cross-crate
@@ -305,7 +348,11 @@ fn check_codegen_attributes(
             return Err("cold");
         }
 
-        if callee_attrs.no_sanitize != self.codegen_fn_attrs.no_sanitize {
+        // Only the sanitizers that are actually enabled matter: a function opting out of a
+        // sanitizer the session doesn't use can be inlined anywhere, and vice versa.
+        let sanitizers = self.tcx.sess.opts.debugging_opts.sanitizer;
+        let mismatch = callee_attrs.no_sanitize ^ self.codegen_fn_attrs.no_sanitize;
+        if !(mismatch & sanitizers).is_empty() {
             return Err("incompatible sanitizer set");
         }
 
@@ -330,157 +377,20 @@ fn check_mir_body(
         callsite: &CallSite<'tcx>,
         callee_body: &Body<'tcx>,
         callee_attrs: &CodegenFnAttrs,
-    ) -> Result<(), &'static str> {
-        let tcx = self.tcx;
-
-        let mut threshold = if callee_attrs.requests_inline() {
-            self.tcx.sess.opts.debugging_opts.inline_mir_hint_threshold.unwrap_or(100)
-        } else {
-            self.tcx.sess.opts.debugging_opts.inline_mir_threshold.unwrap_or(50)
-        };
-
-        // Give a bonus functions with a small number of blocks,
-        // We normally have two or three blocks for even
-        // very small functions.
-        if callee_body.basic_blocks().len() <= 3 {
-            threshold += threshold / 4;
-        }
-        debug!("    final inline threshold = {}", threshold);
-
-        // FIXME: Give a bonus to functions with only a single caller
-        let mut first_block = true;
-        let mut cost = 0;
-
-        // Traverse the MIR manually so we can account for the effects of
-        // inlining on the CFG.
-        let mut work_list = vec![START_BLOCK];
-        let mut visited = BitSet::new_empty(callee_body.basic_blocks().len());
-        while let Some(bb) = work_list.pop() {
-            if !visited.insert(bb.index()) {
-                continue;
-            }
-            let blk = &callee_body.basic_blocks()[bb];
-
-            for stmt in &blk.statements {
-                // Don't count StorageLive/StorageDead in the inlining cost.
-                match stmt.kind {
-                    StatementKind::StorageLive(_)
-                    | StatementKind::StorageDead(_)
-                    | StatementKind::Nop => {}
-                    _ => cost += INSTR_COST,
-                }
-            }
-            let term = blk.terminator();
-            let mut is_drop = false;
-            match term.kind {
-                TerminatorKind::Drop { ref place, target, unwind }
-                | TerminatorKind::DropAndReplace { ref place, target, unwind, .. } => {
-                    is_drop = true;
-                    work_list.push(target);
-                    // If the place doesn't actually need dropping, treat it like
-                    // a regular goto.
-                    let ty = callsite.callee.subst_mir(self.tcx, &place.ty(callee_body, tcx).ty);
-                    if ty.needs_drop(tcx, self.param_env) {
-                        cost += CALL_PENALTY;
-                        if let Some(unwind) = unwind {
-                            cost += LANDINGPAD_PENALTY;
-                            work_list.push(unwind);
-                        }
-                    } else {
-                        cost += INSTR_COST;
-                    }
-                }
-
-                TerminatorKind::Unreachable | TerminatorKind::Call { destination: None, .. }
-                    if first_block =>
-                {
-                    // If the function always diverges, don't inline
-                    // unless the cost is zero
-                    threshold = 0;
-                }
-
-                TerminatorKind::Call { func: Operand::Constant(ref f), cleanup, .. } => {
-                    if let ty::FnDef(def_id, substs) =
-                        *callsite.callee.subst_mir(self.tcx, &f.literal.ty()).kind()
-                    {
-                        let substs = self.tcx.normalize_erasing_regions(self.param_env, substs);
-                        if let Ok(Some(instance)) =
-                            Instance::resolve(self.tcx, self.param_env, def_id, substs)
-                        {
-                            if callsite.callee.def_id() == instance.def_id() {
-                                return Err("self-recursion");
-                            } else if self.history.contains(&instance) {
-                                return Err("already inlined");
-                            }
-                        }
-                        // Don't give intrinsics the extra penalty for calls
-                        let f = tcx.fn_sig(def_id);
-                        if f.abi() == Abi::RustIntrinsic || f.abi() == Abi::PlatformIntrinsic {
-                            cost += INSTR_COST;
-                        } else {
-                            cost += CALL_PENALTY;
-                        }
-                    } else {
-                        cost += CALL_PENALTY;
-                    }
-                    if cleanup.is_some() {
-                        cost += LANDINGPAD_PENALTY;
-                    }
-                }
-                TerminatorKind::Assert { cleanup, .. } => {
-                    cost += CALL_PENALTY;
-
-                    if cleanup.is_some() {
-                        cost += LANDINGPAD_PENALTY;
-                    }
-                }
-                TerminatorKind::Resume => cost += RESUME_PENALTY,
-                TerminatorKind::InlineAsm { cleanup, .. } => {
-                    cost += INSTR_COST;
-
-                    if cleanup.is_some() {
-                        cost += LANDINGPAD_PENALTY;
-                    }
-                }
-                _ => cost += INSTR_COST,
-            }
-
-            if !is_drop {
-                for &succ in term.successors() {
-                    work_list.push(succ);
-                }
-            }
-
-            first_block = false;
-        }
-
-        // Count up the cost of local variables and temps, if we know the size
-        // use that, otherwise we use a moderately-large dummy cost.
-
-        let ptr_size = tcx.data_layout.pointer_size.bytes();
-
-        for v in callee_body.vars_and_temps_iter() {
-            let ty = callsite.callee.subst_mir(self.tcx, &callee_body.local_decls[v].ty);
-            // Cost of the var is the size in machine-words, if we know
-            // it.
-            if let Some(size) = type_size_of(tcx, self.param_env, ty) {
-                cost += ((size + ptr_size - 1) / ptr_size) as usize;
-            } else {
-                cost += UNKNOWN_SIZE_COST;
-            }
-        }
+    ) -> Result<InlineDecision, InlineDecision> {
+        let checker =
+            CostChecker::new(self.tcx, self.param_env, callsite.callee, callee_body, &self.history);
+        let cost = checker.inline_cost(callee_attrs, callsite.const_args)?;
 
         if let InlineAttr::Always = callee_attrs.inline {
-            debug!("INLINING {:?} because inline(always) [cost={}]", callsite, cost);
-            Ok(())
+            debug!("INLINING {:?} because inline(always) [cost={}]", callsite, cost.cost);
+            Ok(InlineDecision::Always(cost.cost))
+        } else if cost.is_within_threshold() {
+            debug!("INLINING {:?} [{}]", callsite, cost);
+            Ok(InlineDecision::Cost(cost))
         } else {
-            if cost <= threshold {
-                debug!("INLINING {:?} [cost={} <= threshold={}]", callsite, cost, threshold);
-                Ok(())
-            } else {
-                debug!("NOT inlining {:?} [cost={} > threshold={}]", callsite, cost, threshold);
-                Err("cost above threshold")
-            }
+            debug!("NOT inlining {:?} [{}]", callsite, cost);
+            Err(InlineDecision::Cost(cost))
         }
     }
 
@@ -755,14 +665,6 @@ fn new_call_temp(
     }
 }
 
-fn type_size_of<'tcx>(
-    tcx: TyCtxt<'tcx>,
-    param_env: ty::ParamEnv<'tcx>,
-    ty: Ty<'tcx>,
-) -> Option<u64> {
-    tcx.layout_of(param_env.and(ty)).ok().map(|layout| layout.size.bytes())
-}
-
 /**
  * Integrator.
  *
@@ -891,6 +793,7 @@ fn visit_terminator(&mut self, terminator: &mut Terminator<'tcx>, loc: Location)
 
         match terminator.kind {
             TerminatorKind::GeneratorDrop | TerminatorKind::Yield { .. } => bug!(),
+            TerminatorKind::TailCall { .. } => bug!("bodies with tail calls are not inlined"),
             TerminatorKind::Goto { ref mut target } => {
                 *target = self.map_block(*target);
             }
@@ -0,0 +1,251 @@
+//! The cost model of the MIR inliner.
+//!
+//! The cost of a callee estimates how much code inlining it adds to the caller. Every statement
+//! and terminator of the callee has a weight, roughly the number of instructions it is lowered to,
+//! and every local costs its size in machine words. Calls, and everything which can unwind, weigh
+//! more since they need a landing pad in the caller as well.
+//!
+//! The cost is compared to a threshold, which is `-Zinline-mir-threshold`, or
+//! `-Zinline-mir-hint-threshold` for callees with an inline hint. The threshold is raised for
+//! callees with very few blocks, whose cost is often close to the cost of the call itself, and for
+//! each constant argument of the call site, since those allow parts of the callee to be simplified
+//! away once it is inlined.
+
+use rustc_index::bit_set::BitSet;
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrs;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, Instance, ParamEnv, Ty, TyCtxt};
+use rustc_target::spec::abi::Abi;
+
+use std::fmt;
+
+const INSTR_COST: usize = 5;
+const CALL_PENALTY: usize = 25;
+const LANDINGPAD_PENALTY: usize = 50;
+const RESUME_PENALTY: usize = 45;
+
+const UNKNOWN_SIZE_COST: usize = 10;
+
+/// The default value of `-Zinline-mir-threshold`.
+const DEFAULT_THRESHOLD: usize = 50;
+/// The default value of `-Zinline-mir-hint-threshold`.
+const DEFAULT_HINT_THRESHOLD: usize = 100;
+/// How much the threshold is raised for each constant argument of the call site.
+const CONST_ARG_BONUS: usize = 10;
+
+/// The estimated cost of inlining a callee at a call site, and the threshold it is compared to.
+#[derive(Copy, Clone, Debug)]
+crate struct InlineCost {
+    crate cost: usize,
+    crate threshold: usize,
+}
+
+impl InlineCost {
+    crate fn is_within_threshold(&self) -> bool {
+        self.cost <= self.threshold
+    }
+}
+
+impl fmt::Display for InlineCost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cmp = if self.is_within_threshold() { "<=" } else { ">" };
+        write!(f, "cost {} {} threshold {}", self.cost, cmp, self.threshold)
+    }
+}
+
+/// Computes the cost of inlining `callee_body` at a call site.
+crate struct CostChecker<'b, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    callee: Instance<'tcx>,
+    callee_body: &'b Body<'tcx>,
+    /// The callees being inlined already, which must not be inlined again.
+    history: &'b [Instance<'tcx>],
+}
+
+impl<'b, 'tcx> CostChecker<'b, 'tcx> {
+    crate fn new(
+        tcx: TyCtxt<'tcx>,
+        param_env: ParamEnv<'tcx>,
+        callee: Instance<'tcx>,
+        callee_body: &'b Body<'tcx>,
+        history: &'b [Instance<'tcx>],
+    ) -> Self {
+        CostChecker { tcx, param_env, callee, callee_body, history }
+    }
+
+    /// Returns the cost of the callee at a call site with `const_args` constant arguments, or an
+    /// error if the callee calls itself or a function that is already being inlined.
+    crate fn inline_cost(
+        &self,
+        callee_attrs: &CodegenFnAttrs,
+        const_args: usize,
+    ) -> Result<InlineCost, &'static str> {
+        let mut threshold = self.threshold(callee_attrs, const_args);
+        let mut cost = 0;
+
+        // Traverse the MIR manually so we can account for the effects of
+        // inlining on the CFG.
+        let mut work_list = vec![START_BLOCK];
+        let mut visited = BitSet::new_empty(self.callee_body.basic_blocks().len());
+        while let Some(bb) = work_list.pop() {
+            if !visited.insert(bb.index()) {
+                continue;
+            }
+            let blk = &self.callee_body.basic_blocks()[bb];
+            cost += blk.statements.iter().map(statement_cost).sum::<usize>();
+
+            let term = blk.terminator();
+            let diverges = matches!(
+                term.kind,
+                TerminatorKind::Unreachable | TerminatorKind::Call { destination: None, .. }
+            );
+            if bb == START_BLOCK && diverges {
+                // If the function always diverges, don't inline
+                // unless the cost is zero
+                threshold = 0;
+            } else {
+                cost += self.terminator_cost(term)?;
+            }
+
+            match term.kind {
+                TerminatorKind::Drop { ref place, target, unwind }
+                | TerminatorKind::DropAndReplace { ref place, target, unwind, .. } => {
+                    // Drops of places which don't need dropping are removed, so don't follow their
+                    // unwind edge.
+                    work_list.push(target);
+                    if let Some(unwind) = unwind {
+                        if self.needs_drop(place) {
+                            work_list.push(unwind);
+                        }
+                    }
+                }
+                _ => work_list.extend(term.successors()),
+            }
+        }
+
+        // Count up the cost of local variables and temps, if we know the size
+        // use that, otherwise we use a moderately-large dummy cost.
+        let ptr_size = self.tcx.data_layout.pointer_size.bytes();
+        for v in self.callee_body.vars_and_temps_iter() {
+            let ty = self.callee.subst_mir(self.tcx, &self.callee_body.local_decls[v].ty);
+            // Cost of the var is the size in machine-words, if we know
+            // it.
+            if let Some(size) = type_size_of(self.tcx, self.param_env, ty) {
+                cost += ((size + ptr_size - 1) / ptr_size) as usize;
+            } else {
+                cost += UNKNOWN_SIZE_COST;
+            }
+        }
+
+        Ok(InlineCost { cost, threshold })
+    }
+
+    fn threshold(&self, callee_attrs: &CodegenFnAttrs, const_args: usize) -> usize {
+        let opts = &self.tcx.sess.opts.debugging_opts;
+        let mut threshold = if callee_attrs.requests_inline() {
+            opts.inline_mir_hint_threshold.unwrap_or(DEFAULT_HINT_THRESHOLD)
+        } else {
+            opts.inline_mir_threshold.unwrap_or(DEFAULT_THRESHOLD)
+        };
+
+        // Give a bonus functions with a small number of blocks,
+        // We normally have two or three blocks for even
+        // very small functions.
+        if self.callee_body.basic_blocks().len() <= 3 {
+            threshold += threshold / 4;
+        }
+
+        // FIXME: Give a bonus to functions with only a single caller
+        threshold += const_args * CONST_ARG_BONUS;
+        debug!("    final inline threshold = {}", threshold);
+        threshold
+    }
+
+    fn terminator_cost(&self, terminator: &Terminator<'tcx>) -> Result<usize, &'static str> {
+        let cost = match terminator.kind {
+            TerminatorKind::Drop { ref place, unwind, .. }
+            | TerminatorKind::DropAndReplace { ref place, unwind, .. } => {
+                // If the place doesn't actually need dropping, treat it like
+                // a regular goto.
+                if self.needs_drop(place) {
+                    CALL_PENALTY + unwind.map_or(0, |_| LANDINGPAD_PENALTY)
+                } else {
+                    INSTR_COST
+                }
+            }
+            TerminatorKind::Call { ref func, cleanup, .. } => {
+                self.call_cost(func)? + cleanup.map_or(0, |_| LANDINGPAD_PENALTY)
+            }
+            TerminatorKind::Assert { cleanup, .. } => {
+                CALL_PENALTY + cleanup.map_or(0, |_| LANDINGPAD_PENALTY)
+            }
+            TerminatorKind::Resume => RESUME_PENALTY,
+            // The tail call would have to become a call followed by a return, which is not what
+            // the callee asked for.
+            TerminatorKind::TailCall { .. } => return Err("tail call"),
+            TerminatorKind::InlineAsm { cleanup, .. } => {
+                INSTR_COST + cleanup.map_or(0, |_| LANDINGPAD_PENALTY)
+            }
+            _ => INSTR_COST,
+        };
+        Ok(cost)
+    }
+
+    fn call_cost(&self, func: &Operand<'tcx>) -> Result<usize, &'static str> {
+        let Operand::Constant(ref f) = *func else {
+            return Ok(CALL_PENALTY);
+        };
+        let func_ty = self.callee.subst_mir(self.tcx, &f.literal.ty());
+        let ty::FnDef(def_id, substs) = *func_ty.kind() else {
+            return Ok(CALL_PENALTY);
+        };
+
+        let substs = self.tcx.normalize_erasing_regions(self.param_env, substs);
+        if let Ok(Some(instance)) = Instance::resolve(self.tcx, self.param_env, def_id, substs) {
+            if self.callee.def_id() == instance.def_id() {
+                return Err("self-recursion");
+            } else if self.history.contains(&instance) {
+                return Err("already inlined");
+            }
+        }
+
+        // Don't give intrinsics the extra penalty for calls
+        let abi = self.tcx.fn_sig(def_id).abi();
+        if abi == Abi::RustIntrinsic || abi == Abi::PlatformIntrinsic {
+            Ok(INSTR_COST)
+        } else {
+            Ok(CALL_PENALTY)
+        }
+    }
+
+    fn needs_drop(&self, place: &Place<'tcx>) -> bool {
+        let ty = self.callee.subst_mir(self.tcx, &place.ty(self.callee_body, self.tcx).ty);
+        ty.needs_drop(self.tcx, self.param_env)
+    }
+}
+
+/// The weight of a statement. Statements which aren't lowered to any code are free.
+fn statement_cost(statement: &Statement<'_>) -> usize {
+    match statement.kind {
+        StatementKind::StorageLive(_)
+        | StatementKind::StorageDead(_)
+        | StatementKind::Retag(..)
+        | StatementKind::AscribeUserType(..)
+        | StatementKind::FakeRead(..)
+        | StatementKind::Nop => 0,
+        // Aggregates are built one field at a time.
+        StatementKind::Assign(box (_, Rvalue::Aggregate(_, ref operands))) => {
+            INSTR_COST * operands.len().max(1)
+        }
+        _ => INSTR_COST,
+    }
+}
+
+fn type_size_of<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ty::ParamEnv<'tcx>,
+    ty: Ty<'tcx>,
+) -> Option<u64> {
+    tcx.layout_of(param_env.and(ty)).ok().map(|layout| layout.size.bytes())
+}
@@ -0,0 +1,191 @@
+//! A jump threading pass for switches on values that are known in a predecessor.
+//!
+//! This pass optimizes the following sequence
+//! ```rust,ignore (example)
+//! bb2: {
+//!     ((_1 as Some).0: u8) = const 5_u8;
+//!     discriminant(_1) = 1;
+//!     goto -> bb3;
+//! }
+//!
+//! bb3: {
+//!     StorageDead(_4);
+//!     _3 = discriminant(_1);
+//!     switchInt(move _3) -> [0: bb4, 1: bb5, otherwise: bb6];
+//! }
+//! ```
+//! into
+//! ```rust,ignore (example)
+//! bb2: {
+//!     ((_1 as Some).0: u8) = const 5_u8;
+//!     discriminant(_1) = 1;
+//!     StorageDead(_4);
+//!     _3 = discriminant(_1);
+//!     goto -> bb5;
+//! }
+//! ```
+//!
+//! The switched-on value may also be a constant assigned in the predecessor, like the booleans
+//! produced by the lowering of `&&` and `||`. The statements of the switching block are copied into
+//! the predecessor, so the switching block is only allowed to contain storage markers and the read
+//! of the discriminant. Match-heavy code and `?` desugaring produce many of these patterns, once
+//! the constructors and `Try` impls have been inlined.
+
+use crate::MirPass;
+use rustc_middle::mir::*;
+use rustc_middle::ty::{ParamEnv, TyCtxt};
+use rustc_target::abi::VariantIdx;
+
+use super::simplify::simplify_cfg;
+
+pub struct JumpThreading;
+
+impl<'tcx> MirPass<'tcx> for JumpThreading {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 3
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        trace!("Running JumpThreading on {:?}", body.source);
+        let param_env = tcx.param_env_reveal_all_normalized(body.source.def_id());
+
+        let mut optimizations = Vec::new();
+        for (bb, data) in body.basic_blocks().iter_enumerated() {
+            if let Some(opt) = find_optimization(tcx, param_env, body, bb, data) {
+                optimizations.push(opt);
+            }
+        }
+        if optimizations.is_empty() {
+            return;
+        }
+
+        let basic_blocks = body.basic_blocks_mut();
+        for opt in optimizations {
+            if !tcx.consider_optimizing(|| format!("JumpThreading {:?}", opt.bb_with_goto)) {
+                break;
+            }
+            debug!("SUCCESS: threading {:?} to {:?}", opt.bb_with_goto, opt.target);
+            let data = &mut basic_blocks[opt.bb_with_goto];
+            data.statements.extend(opt.copied_statements);
+            data.terminator_mut().kind = TerminatorKind::Goto { target: opt.target };
+        }
+
+        // The switching blocks may have become unreachable.
+        simplify_cfg(tcx, body);
+    }
+}
+
+struct OptimizationToApply<'tcx> {
+    bb_with_goto: BasicBlock,
+    /// The statements of the switching block, which are now executed at the end of
+    /// `bb_with_goto` instead.
+    copied_statements: Vec<Statement<'tcx>>,
+    target: BasicBlock,
+}
+
+/// What `bb_with_goto` has to tell us about the switched-on value.
+#[derive(Copy, Clone, Debug)]
+enum Known<'tcx> {
+    /// The constant assigned to this place.
+    Constant(Place<'tcx>),
+    /// The variant this enum place was set to.
+    Variant(Place<'tcx>),
+}
+
+fn find_optimization<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    param_env: ParamEnv<'tcx>,
+    body: &Body<'tcx>,
+    bb: BasicBlock,
+    data: &BasicBlockData<'tcx>,
+) -> Option<OptimizationToApply<'tcx>> {
+    let target_bb = data.terminator().kind.as_goto()?;
+    if target_bb == bb {
+        return None;
+    }
+    let target_data = &body.basic_blocks()[target_bb];
+    let (discr, switch_ty, targets) = target_data.terminator().kind.as_switch()?;
+    let discr = discr.place()?;
+
+    // Find out which value of the predecessor decides the switch.
+    let mut known = Known::Constant(discr);
+    for statement in &target_data.statements {
+        match &statement.kind {
+            StatementKind::StorageLive(_) | StatementKind::StorageDead(_) | StatementKind::Nop => {}
+            StatementKind::Assign(box (place, Rvalue::Discriminant(enum_place)))
+                if *place == discr && enum_place.local != discr.local =>
+            {
+                known = Known::Variant(*enum_place);
+            }
+            _ => return None,
+        }
+    }
+
+    let value = match known {
+        Known::Constant(place) => {
+            let constant = match last_write(&data.statements, place)? {
+                StatementKind::Assign(box (_, Rvalue::Use(Operand::Constant(constant)))) => {
+                    constant
+                }
+                _ => return None,
+            };
+            constant.literal.try_eval_bits(tcx, param_env, switch_ty)?
+        }
+        Known::Variant(place) => {
+            let variant = match last_write(&data.statements, place)? {
+                StatementKind::SetDiscriminant { variant_index, .. } => *variant_index,
+                StatementKind::Assign(box (_, Rvalue::Aggregate(box kind, _))) => {
+                    aggregate_variant(kind)?
+                }
+                _ => return None,
+            };
+            let enum_ty = place.ty(body, tcx).ty;
+            enum_ty.discriminant_for_variant(tcx, variant)?.val
+        }
+    };
+
+    Some(OptimizationToApply {
+        bb_with_goto: bb,
+        copied_statements: target_data.statements.clone(),
+        target: targets.target_for_value(value),
+    })
+}
+
+fn aggregate_variant(kind: &AggregateKind<'_>) -> Option<VariantIdx> {
+    match *kind {
+        AggregateKind::Adt(_, variant_index, _, _, None) => Some(variant_index),
+        _ => None,
+    }
+}
+
+/// Returns the last statement of `statements` that writes all of `place`, provided that no later
+/// statement may write to it or end its storage.
+fn last_write<'a, 'tcx>(
+    statements: &'a [Statement<'tcx>],
+    place: Place<'tcx>,
+) -> Option<&'a StatementKind<'tcx>> {
+    for statement in statements.iter().rev() {
+        match &statement.kind {
+            StatementKind::Assign(box (dest, _)) if *dest == place => return Some(&statement.kind),
+            StatementKind::SetDiscriminant { place: dest, .. } if **dest == place => {
+                return Some(&statement.kind);
+            }
+            // Another write to the local, or to memory that the local may be borrowed from.
+            StatementKind::Assign(box (dest, _))
+            | StatementKind::SetDiscriminant { place: box dest, .. }
+                if dest.local == place.local || dest.is_indirect() =>
+            {
+                return None;
+            }
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local)
+                if *local == place.local =>
+            {
+                return None;
+            }
+            StatementKind::Retag(_, dest) if dest.local == place.local => return None,
+            StatementKind::CopyNonOverlapping(..) => return None,
+            _ => {}
+        }
+    }
+    None
+}
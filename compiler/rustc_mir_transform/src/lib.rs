@@ -36,6 +36,8 @@
 
 use pass_manager::{self as pm, Lint, MirLint, WithMinOptLevel};
 
+pub use pass_manager::check_enable_passes;
+
 mod abort_unwinding_calls;
 mod add_call_guards;
 mod add_moves_for_packed_drops;
@@ -48,17 +50,23 @@
 mod const_debuginfo;
 mod const_goto;
 mod const_prop;
+mod copy_prop;
 mod coverage;
+mod dataflow_const_prop;
+mod dead_store_elimination;
 mod deaggregator;
 mod deduplicate_blocks;
+mod deref_separator;
 mod dest_prop;
 pub mod dump_mir;
 mod early_otherwise_branch;
 mod elaborate_drops;
 mod function_item_references;
 mod generator;
+mod gvn;
 mod inline;
 mod instcombine;
+mod jump_threading;
 mod lower_intrinsics;
 mod lower_slice_len;
 mod marker;
@@ -68,6 +76,7 @@
 mod nrvo;
 // This pass is public to allow external drivers to perform MIR cleanup
 pub mod remove_false_edges;
+mod remove_noop_cleanup_drops;
 mod remove_noop_landing_pads;
 mod remove_storage_markers;
 mod remove_uninit_drops;
@@ -82,6 +91,8 @@
 mod simplify_branches;
 mod simplify_comparison_integral;
 mod simplify_try;
+mod sroa;
+mod ssa;
 mod uninhabited_enum_branching;
 mod unreachable_prop;
 
@@ -191,6 +202,12 @@ fn mir_const_qualif(tcx: TyCtxt<'_>, def: ty::WithOptConstParam<LocalDefId>) ->
         return Default::default();
     }
 
+    // Bodies written with `#[custom_mir]` in the dialect of a later phase skip const checking,
+    // like the other passes of the phases before their own.
+    if body.should_skip() {
+        return Default::default();
+    }
+
     let ccx = check_consts::ConstCx { body, tcx, const_kind, param_env: tcx.param_env(def.did) };
 
     let mut validator = check_consts::check::Checker::new(&ccx);
@@ -384,7 +401,9 @@ fn mir_drops_elaborated_and_const_checked<'tcx>(
     pm::run_passes(tcx, &mut body, &[&remove_false_edges::RemoveFalseEdges]);
 
     // Do a little drop elaboration before const-checking if `const_precise_live_drops` is enabled.
-    if check_consts::post_drop_elaboration::checking_enabled(&ConstCx::new(tcx, &body)) {
+    if !body.should_skip()
+        && check_consts::post_drop_elaboration::checking_enabled(&ConstCx::new(tcx, &body))
+    {
         pm::run_passes(
             tcx,
             &mut body,
@@ -397,7 +416,7 @@ fn mir_drops_elaborated_and_const_checked<'tcx>(
     }
 
     run_post_borrowck_cleanup_passes(tcx, &mut body);
-    assert!(body.phase == MirPhase::DropLowering);
+    assert!(body.phase == MirPhase::Derefered);
     tcx.alloc_steal_mir(body)
 }
 
@@ -429,6 +448,9 @@ fn run_post_borrowck_cleanup_passes<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tc
         // `Deaggregator` is conceptually part of MIR building, some backends rely on it happening
         // and it can help optimizations.
         &deaggregator::Deaggregator,
+        // From here on, places only dereference their base local.
+        &deref_separator::Derefer,
+        &marker::PhaseChange(MirPhase::Derefered),
     ];
 
     pm::run_passes(tcx, body, post_borrowck_cleanup);
@@ -466,6 +488,7 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
         &[
             &remove_storage_markers::RemoveStorageMarkers,
             &remove_zsts::RemoveZsts,
+            &sroa::ScalarReplacementOfAggregates,
             &const_goto::ConstGoto,
             &remove_unneeded_drops::RemoveUnneededDrops,
             &match_branches::MatchBranchSimplification,
@@ -473,12 +496,17 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             &multiple_return_terminators::MultipleReturnTerminators,
             &instcombine::InstCombine,
             &separate_const_switch::SeparateConstSwitch,
+            &jump_threading::JumpThreading,
             //
-            // FIXME(#70073): This pass is responsible for both optimization as well as some lints.
+            // FIXME(#70073): This pass only emits lints, the constants are propagated by
+            // `DataflowConstProp` right after it. It still propagates them when
+            // `DataflowConstProp` is disabled.
             &const_prop::ConstProp,
+            &dataflow_const_prop::DataflowConstProp,
             //
-            // Const-prop runs unconditionally, but doesn't mutate the MIR at mir-opt-level=0.
             &o1(simplify_branches::SimplifyConstCondition::new("after-const-prop")),
+            &copy_prop::CopyProp,
+            &gvn::GVN,
             &early_otherwise_branch::EarlyOtherwiseBranch,
             &simplify_comparison_integral::SimplifyComparisonIntegral,
             &simplify_try::SimplifyArmIdentity,
@@ -489,6 +517,7 @@ fn o1<T>(x: T) -> WithMinOptLevel<T> {
             &o1(simplify::SimplifyCfg::new("final")),
             &nrvo::RenameReturnPlace,
             &const_debuginfo::ConstDebugInfo,
+            &dead_store_elimination::DeadStoreElimination,
             &simplify::SimplifyLocals,
             &multiple_return_terminators::MultipleReturnTerminators,
             &deduplicate_blocks::DeduplicateBlocks,
@@ -1,8 +1,12 @@
 use std::borrow::Cow;
 
+use rustc_ast::MetaItem;
+use rustc_hir::def_id::DefId;
 use rustc_middle::mir::{self, Body, MirPhase};
 use rustc_middle::ty::TyCtxt;
+use rustc_mir_dataflow::has_rustc_mir_with;
 use rustc_session::Session;
+use rustc_span::symbol::sym;
 
 use crate::{validate, MirPass};
 
@@ -82,23 +86,37 @@ pub fn run_passes<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>, passes: &[&dyn
         validate_body(tcx, body, format!("start of phase transition from {:?}", start_phase));
     }
 
+    let overridden_passes = overridden_passes(tcx, body);
+
     for pass in passes {
-        if !pass.is_enabled(&tcx.sess) {
+        let name = pass.name();
+        debug_assert!(
+            is_known_pass(&name) || is_listed(MARKER_NAMES, &name),
+            "MIR pass `{}` is missing from `PASS_NAMES`",
+            name
+        );
+
+        let enabled = is_enabled_with(tcx, &overridden_passes, *pass);
+        if !enabled && pass.phase_change().is_none() {
             continue;
         }
 
-        let name = pass.name();
-        let dump_enabled = pass.is_mir_dump_enabled();
+        // Bodies written with `#[custom_mir]` are already in the dialect of a later phase, so only
+        // keep track of the phase until it is reached. The phase also changes when the pass is
+        // disabled, the later passes rely on it.
+        if enabled && !body.should_skip() {
+            let dump_enabled = pass.is_mir_dump_enabled();
 
-        if dump_enabled {
-            dump_mir(tcx, body, start_phase, &name, cnt, false);
-        }
+            if dump_enabled {
+                dump_mir(tcx, body, start_phase, &name, cnt, false);
+            }
 
-        pass.run_pass(tcx, body);
+            pass.run_pass(tcx, body);
 
-        if dump_enabled {
-            dump_mir(tcx, body, start_phase, &name, cnt, true);
-            cnt += 1;
+            if dump_enabled {
+                dump_mir(tcx, body, start_phase, &name, cnt, true);
+                cnt += 1;
+            }
         }
 
         if let Some(new_phase) = pass.phase_change() {
@@ -110,7 +128,7 @@ pub fn run_passes<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>, passes: &[&dyn
         }
 
         if validate {
-            validate_body(tcx, body, format!("after pass {}", pass.name()));
+            validate_body(tcx, body, format!("after pass {}", name));
         }
     }
 
@@ -119,6 +137,165 @@ pub fn run_passes<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>, passes: &[&dyn
     }
 }
 
+/// Returns whether `pass` runs on `body`, taking the passes forced on or off by
+/// `-Zmir-enable-passes` and `#[rustc_mir(enable_passes)]` into account. This lets a pass adapt to
+/// whether another pass of the same pipeline runs.
+pub fn is_enabled<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>, pass: &dyn MirPass<'tcx>) -> bool {
+    is_enabled_with(tcx, &overridden_passes(tcx, body), pass)
+}
+
+fn is_enabled_with<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    overridden_passes: &[(String, bool)],
+    pass: &dyn MirPass<'tcx>,
+) -> bool {
+    let name = pass.name();
+
+    // The last mention of a pass wins.
+    let overridden = overridden_passes.iter().rev().find(|(s, _)| s == &*name);
+    if let Some(&(_, polarity)) = overridden {
+        trace!(pass = %name, "{} as requested", if polarity { "running" } else { "not running" });
+    }
+    overridden.map_or_else(|| pass.is_enabled(&tcx.sess), |&(_, polarity)| polarity)
+}
+
+/// The names of the MIR passes and lints which can be forced on or off by `-Zmir-enable-passes`.
+/// Passes with a label, like `SimplifyCfg-initial`, are named by the label. The pass manager
+/// asserts that every pass it runs is listed here or in `MARKER_NAMES`.
+const PASS_NAMES: &[&str] = &[
+    "AbortUnwindingCalls",
+    "AddCallGuards",
+    "AddMovesForPackedDrops",
+    "AddRetag",
+    "CheckConstItemMutation",
+    "CheckPackedRef",
+    "CleanupNonCodegenStatements",
+    "ConstDebugInfo",
+    "ConstGoto",
+    "ConstProp",
+    "CopyProp",
+    "DataflowConstProp",
+    "DeadStoreElimination",
+    "Deaggregator",
+    "DeduplicateBlocks",
+    "Derefer",
+    "DestinationPropagation",
+    "EarlyOtherwiseBranch",
+    "ElaborateDrops",
+    "FunctionItemReferences",
+    "GVN",
+    "Inline",
+    "InstCombine",
+    "InstrumentCoverage",
+    "JumpThreading",
+    "LowerIntrinsics",
+    "LowerSliceLenCalls",
+    "MatchBranchSimplification",
+    "MultipleReturnTerminators",
+    "NormalizeArrayLen",
+    "PromoteTemps",
+    "RemoveFalseEdges",
+    "RemoveNoopCleanupDrops",
+    "RemoveNoopLandingPads",
+    "RemoveStorageMarkers",
+    "RemoveUninitDrops",
+    "RemoveUnneededDrops",
+    "RemoveZsts",
+    "RenameReturnPlace",
+    "RevealAll",
+    "SanityCheck",
+    "ScalarReplacementOfAggregates",
+    "SeparateConstSwitch",
+    "SimplifyArmIdentity",
+    "SimplifyBranchSame",
+    "SimplifyCfg",
+    "SimplifyComparisonIntegral",
+    "SimplifyConstCondition",
+    "SimplifyLocals",
+    "StateTransform",
+    "UninhabitedEnumBranching",
+    "UnreachablePropagation",
+];
+
+/// The names of the markers of the pass lists, which don't change the body and so are not forced
+/// on or off.
+const MARKER_NAMES: &[&str] = &["PhaseChange", "PreCodegen"];
+
+fn is_known_pass(name: &str) -> bool {
+    is_listed(PASS_NAMES, name)
+}
+
+fn is_listed(names: &[&str], name: &str) -> bool {
+    names.iter().any(|&known| match name.strip_prefix(known) {
+        Some(label) => label.is_empty() || label.starts_with('-'),
+        None => false,
+    })
+}
+
+/// Returns the `enable_passes` item of the `#[rustc_mir]` attribute of `def_id`, if any.
+fn enable_passes_attr(tcx: TyCtxt<'_>, def_id: DefId) -> Option<MetaItem> {
+    if !tcx.has_attr(def_id, sym::rustc_mir) {
+        return None;
+    }
+    has_rustc_mir_with(tcx.sess, &tcx.get_attrs(def_id), sym::enable_passes)
+}
+
+/// Parses an element of the `#[rustc_mir(enable_passes = "+PassName,-OtherPass")]` attribute into
+/// the name of the pass and whether it is forced on.
+fn parse_enable_pass(pass: &str) -> Option<(&str, bool)> {
+    match pass.strip_prefix('+') {
+        Some(pass_name) => Some((pass_name, true)),
+        None => pass.strip_prefix('-').map(|pass_name| (pass_name, false)),
+    }
+}
+
+/// Reports the unknown passes of `-Zmir-enable-passes`, and the malformed or unknown passes of the
+/// `#[rustc_mir(enable_passes)]` attributes of the crate. This is done once for the whole crate,
+/// the pass manager ignores the malformed passes.
+pub fn check_enable_passes(tcx: TyCtxt<'_>) {
+    for (name, _) in &tcx.sess.opts.debugging_opts.mir_enable_passes {
+        if !is_known_pass(name) {
+            tcx.sess.warn(&format!("MIR pass `{}` is unknown and will be ignored", name));
+        }
+    }
+
+    for def_id in tcx.hir().body_owners() {
+        let Some(item) = enable_passes_attr(tcx, def_id.to_def_id()) else { continue };
+        let Some(value) = item.value_str() else {
+            tcx.sess.span_err(item.span, "`enable_passes` requires an argument");
+            continue;
+        };
+        for pass in value.as_str().split(',') {
+            match parse_enable_pass(pass) {
+                Some((name, _)) if !is_known_pass(name) => {
+                    let msg = format!("MIR pass `{}` is unknown and will be ignored", name);
+                    tcx.sess.span_warn(item.span, &msg);
+                }
+                Some(_) => {}
+                None => {
+                    let msg = format!("`{}` must start with `+` or `-`", pass);
+                    tcx.sess.span_err(item.span, &msg);
+                }
+            }
+        }
+    }
+}
+
+/// Returns the passes forced on or off by `-Zmir-enable-passes`, followed by those of the
+/// `#[rustc_mir(enable_passes = "+PassName,-OtherPass")]` attribute of the body's item, which take
+/// precedence so that tests can control the passes run on each function. Malformed passes are
+/// reported by `check_enable_passes`.
+fn overridden_passes(tcx: TyCtxt<'_>, body: &Body<'_>) -> Vec<(String, bool)> {
+    let mut overridden_passes = tcx.sess.opts.debugging_opts.mir_enable_passes.clone();
+
+    let value = enable_passes_attr(tcx, body.source.def_id()).and_then(|item| item.value_str());
+    if let Some(value) = value {
+        let passes = value.as_str().split(',').filter_map(parse_enable_pass);
+        overridden_passes.extend(passes.map(|(name, polarity)| (name.to_string(), polarity)));
+    }
+    overridden_passes
+}
+
 pub fn validate_body<'tcx>(tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>, when: String) {
     validate::Validator { when, mir_phase: body.phase }.run_pass(tcx, body);
 }
@@ -0,0 +1,56 @@
+//! This pass removes the unwind edges to cleanup blocks which only drop places whose type does not
+//! need dropping.
+//!
+//! MIR building and drop elaboration only emit drops of places which may need dropping, but for
+//! generic types that can only be known exactly once the MIR is monomorphized. This matters for
+//! the drop shims of generators: they are built once for the generic generator and substituted for
+//! each instance, so their cleanup blocks drop all the saved locals of generic type, even though
+//! many of them don't have any drop glue in a given instance. Codegen skips these drops, but it
+//! still emits a landing pad for every call which can unwind into such a cleanup block, although
+//! the landing pad does nothing but resume unwinding.
+//!
+//! The drops are replaced with gotos, after which `RemoveNoopLandingPads` removes the unwind edges
+//! to the cleanup blocks that are left with nothing to do.
+
+use crate::remove_noop_landing_pads::RemoveNoopLandingPads;
+use crate::MirPass;
+use rustc_middle::mir::*;
+use rustc_middle::ty::TyCtxt;
+use rustc_target::spec::PanicStrategy;
+
+pub struct RemoveNoopCleanupDrops;
+
+impl<'tcx> MirPass<'tcx> for RemoveNoopCleanupDrops {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.panic_strategy() != PanicStrategy::Abort
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        trace!("Running RemoveNoopCleanupDrops on {:?}", body.source);
+
+        let did = body.source.def_id();
+        let param_env = tcx.param_env_reveal_all_normalized(did);
+        let mut should_remove_landing_pads = false;
+
+        let (basic_blocks, local_decls) = body.basic_blocks_and_local_decls_mut();
+        for block in basic_blocks.iter_mut().filter(|block| block.is_cleanup) {
+            let terminator = block.terminator_mut();
+            if let TerminatorKind::Drop { place, target, .. } = terminator.kind {
+                let ty = place.ty(local_decls, tcx);
+                if ty.ty.needs_drop(tcx, param_env) {
+                    continue;
+                }
+                if !tcx.consider_optimizing(|| format!("RemoveNoopCleanupDrops {:?} ", did)) {
+                    continue;
+                }
+                debug!("replacing cleanup `drop` of `{:?}` with goto({:?})", ty.ty, target);
+                terminator.kind = TerminatorKind::Goto { target };
+                should_remove_landing_pads = true;
+            }
+        }
+
+        if should_remove_landing_pads {
+            RemoveNoopLandingPads.run_pass(tcx, body);
+        }
+    }
+}
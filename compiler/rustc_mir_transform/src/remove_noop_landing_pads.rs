@@ -72,6 +72,7 @@ fn is_nop_landing_pad(
             | TerminatorKind::Abort
             | TerminatorKind::Unreachable
             | TerminatorKind::Call { .. }
+            | TerminatorKind::TailCall { .. }
             | TerminatorKind::Assert { .. }
             | TerminatorKind::DropAndReplace { .. }
             | TerminatorKind::Drop { .. }
@@ -0,0 +1,299 @@
+//! Scalar replacement of aggregates.
+//!
+//! This pass splits locals of struct or tuple type into one local per field, when the local is only
+//! ever accessed field by field. For example
+//! ```rust,ignore (example)
+//! StorageLive(_1);
+//! (_1.0: u32) = const 1_u32;
+//! (_1.1: bool) = const true;
+//! _2 = (_1.0: u32);
+//! StorageDead(_1);
+//! ```
+//! becomes
+//! ```rust,ignore (example)
+//! StorageLive(_3);
+//! StorageLive(_4);
+//! _3 = const 1_u32;
+//! _4 = const true;
+//! _2 = _3;
+//! StorageDead(_3);
+//! StorageDead(_4);
+//! ```
+//! after which passes that only track whole locals, like constant propagation and dead store
+//! elimination, can handle each field separately. The now unused aggregate local is removed by
+//! `SimplifyLocals`.
+//!
+//! A local is not split if it is an argument or the return place, if it is used as a whole (other
+//! than by storage markers and debuginfo), or if any part of it is borrowed: the borrow could be
+//! used to reach the other fields. Types with a `Drop` impl, unions, boxes and SIMD types are never
+//! split either. Aggregates are only split one level deep, fields of fields are kept together.
+//!
+//! The debuginfo for a split local describes the user variable as a
+//! `VarDebugInfoContents::Composite`, with one fragment per field local, so that the variable can
+//! still be inspected as a whole.
+
+use crate::MirPass;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_index::bit_set::BitSet;
+use rustc_index::vec::IndexVec;
+use rustc_middle::mir::visit::{
+    MutVisitor, MutatingUseContext, NonMutatingUseContext, NonUseContext, PlaceContext, Visitor,
+};
+use rustc_middle::mir::*;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+
+pub struct ScalarReplacementOfAggregates;
+
+impl<'tcx> MirPass<'tcx> for ScalarReplacementOfAggregates {
+    fn is_enabled(&self, sess: &rustc_session::Session) -> bool {
+        sess.mir_opt_level() >= 2
+    }
+
+    fn run_pass(&self, tcx: TyCtxt<'tcx>, body: &mut Body<'tcx>) {
+        let escaping = escaping_locals(tcx, body);
+        let fields = used_fields(body, &escaping);
+        if fields.is_empty() {
+            return;
+        }
+        if !tcx.consider_optimizing(|| format!("ScalarReplacementOfAggregates {:?}", body.source)) {
+            return;
+        }
+
+        let replacements = compute_replacements(body, fields);
+        debug!("splitting {} locals in {:?}", replacements.len(), body.source.def_id());
+
+        let (basic_blocks, local_decls, var_debug_info) =
+            body.basic_blocks_local_decls_mut_and_var_debug_info();
+        let mut replacer = ReplacementVisitor { tcx, replacements: &replacements };
+        for var_debug_info in var_debug_info.iter_mut() {
+            replacer.replace_var_debug_info(local_decls, var_debug_info);
+        }
+        for (block, data) in basic_blocks.iter_enumerated_mut() {
+            replacer.expand_storage_markers(data);
+            replacer.visit_basic_block_data(block, data);
+        }
+    }
+}
+
+/// Returns the locals that can't be split: the arguments and return place, the locals whose type
+/// doesn't have fields we can split along, and the locals which are used as a whole or borrowed.
+fn escaping_locals<'tcx>(tcx: TyCtxt<'tcx>, body: &Body<'tcx>) -> BitSet<Local> {
+    struct EscapeVisitor {
+        escaping: BitSet<Local>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for EscapeVisitor {
+        fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+            let field_access = matches!(place.projection.first(), Some(ProjectionElem::Field(..)));
+            let whole_use_allowed = context.is_storage_marker()
+                || context == PlaceContext::NonUse(NonUseContext::VarDebugInfo);
+            let address_taken = context.is_borrow()
+                || matches!(
+                    context,
+                    PlaceContext::NonMutatingUse(NonMutatingUseContext::AddressOf)
+                        | PlaceContext::MutatingUse(MutatingUseContext::AddressOf)
+                );
+            if address_taken || !(field_access || whole_use_allowed) {
+                self.escaping.insert(place.local);
+            }
+            self.super_place(place, context, location);
+        }
+    }
+
+    let mut escaping = BitSet::new_empty(body.local_decls.len());
+    for (local, decl) in body.local_decls.iter_enumerated() {
+        let splittable = match *decl.ty.kind() {
+            ty::Adt(adt, _) => {
+                adt.is_struct() && !adt.is_box() && !adt.repr.simd() && !adt.has_dtor(tcx)
+            }
+            ty::Tuple(..) => true,
+            _ => false,
+        };
+        let local_kind = body.local_kind(local);
+        if !splittable || matches!(local_kind, LocalKind::Arg | LocalKind::ReturnPointer) {
+            escaping.insert(local);
+        }
+    }
+
+    let mut visitor = EscapeVisitor { escaping };
+    visitor.visit_body(body);
+    visitor.escaping
+}
+
+/// Returns the fields (and their types) which are accessed for each local that will be split.
+fn used_fields<'tcx>(
+    body: &Body<'tcx>,
+    escaping: &BitSet<Local>,
+) -> FxHashMap<Local, Vec<(Field, Ty<'tcx>)>> {
+    struct FieldCollector<'a, 'tcx> {
+        escaping: &'a BitSet<Local>,
+        fields: FxHashMap<Local, Vec<(Field, Ty<'tcx>)>>,
+    }
+
+    impl<'tcx> Visitor<'tcx> for FieldCollector<'_, 'tcx> {
+        fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+            if !self.escaping.contains(place.local) {
+                if let Some(&ProjectionElem::Field(field, ty)) = place.projection.first() {
+                    let fields = self.fields.entry(place.local).or_default();
+                    if !fields.iter().any(|&(f, _)| f == field) {
+                        fields.push((field, ty));
+                    }
+                }
+            }
+            self.super_place(place, context, location);
+        }
+    }
+
+    let mut collector = FieldCollector { escaping, fields: FxHashMap::default() };
+    collector.visit_body(body);
+    collector.fields
+}
+
+/// The locals replacing the used fields of a split local, in field order.
+type FieldLocals<'tcx> = Vec<(Field, Ty<'tcx>, Local)>;
+
+fn compute_replacements<'tcx>(
+    body: &mut Body<'tcx>,
+    fields: FxHashMap<Local, Vec<(Field, Ty<'tcx>)>>,
+) -> FxHashMap<Local, FieldLocals<'tcx>> {
+    // Create the new locals in a deterministic order.
+    let mut fields: Vec<_> = fields.into_iter().collect();
+    fields.sort_by_key(|&(local, _)| local);
+
+    let mut replacements = FxHashMap::default();
+    for (local, mut used) in fields {
+        used.sort_by_key(|&(field, _)| field);
+        let source_info = body.local_decls[local].source_info;
+        let field_locals = used
+            .into_iter()
+            .map(|(field, ty)| {
+                let new_local = body.local_decls.push(LocalDecl::new(ty, source_info.span));
+                body.local_decls[new_local].source_info = source_info;
+                (field, ty, new_local)
+            })
+            .collect();
+        replacements.insert(local, field_locals);
+    }
+    replacements
+}
+
+struct ReplacementVisitor<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    replacements: &'a FxHashMap<Local, FieldLocals<'tcx>>,
+}
+
+impl<'tcx> ReplacementVisitor<'_, 'tcx> {
+    /// Returns the place that `place` is found in after splitting, if its local was split.
+    fn replace_place(&self, place: Place<'tcx>) -> Option<Place<'tcx>> {
+        let field_locals = self.replacements.get(&place.local)?;
+        let Some((&ProjectionElem::Field(field, _), rest)) = place.projection.split_first() else {
+            bug!("split local {:?} used as a whole in {:?}", place.local, place);
+        };
+        let &(_, _, new_local) = field_locals.iter().find(|&&(f, ..)| f == field)?;
+        Some(Place { local: new_local, projection: self.tcx.intern_place_elems(rest) })
+    }
+
+    /// Describes the split locals as composites of the field locals.
+    fn replace_var_debug_info(
+        &self,
+        local_decls: &IndexVec<Local, LocalDecl<'tcx>>,
+        var_debug_info: &mut VarDebugInfo<'tcx>,
+    ) {
+        match &mut var_debug_info.value {
+            VarDebugInfoContents::Const(_) => {}
+            VarDebugInfoContents::Place(place) => {
+                if let Some(field_locals) = self.replacements.get(&place.local) {
+                    if place.projection.is_empty() {
+                        let fragments = self.fragments(&[], field_locals);
+                        let ty = local_decls[place.local].ty;
+                        var_debug_info.value = VarDebugInfoContents::Composite { ty, fragments };
+                    } else if let Some(new_place) = self.replace_place(*place) {
+                        *place = new_place;
+                    }
+                }
+            }
+            VarDebugInfoContents::Composite { ty: _, fragments } => {
+                let old_fragments = std::mem::take(fragments);
+                for fragment in old_fragments {
+                    let contents = fragment.contents;
+                    match self.replacements.get(&contents.local) {
+                        Some(field_locals) if contents.projection.is_empty() => {
+                            fragments.extend(self.fragments(&fragment.projection, field_locals));
+                        }
+                        Some(_) => {
+                            if let Some(contents) = self.replace_place(contents) {
+                                fragments.push(VarDebugInfoFragment { contents, ..fragment });
+                            }
+                        }
+                        None => fragments.push(fragment),
+                    }
+                }
+            }
+        }
+    }
+
+    fn fragments(
+        &self,
+        base: &[PlaceElem<'tcx>],
+        field_locals: &FieldLocals<'tcx>,
+    ) -> Vec<VarDebugInfoFragment<'tcx>> {
+        field_locals
+            .iter()
+            .map(|&(field, ty, new_local)| {
+                let mut projection = base.to_vec();
+                projection.push(ProjectionElem::Field(field, ty));
+                VarDebugInfoFragment { projection, contents: new_local.into() }
+            })
+            .collect()
+    }
+
+    /// Replaces the storage markers of the split locals with markers for each of the field locals.
+    fn expand_storage_markers(&self, data: &mut BasicBlockData<'tcx>) {
+        let needs_expansion = data.statements.iter().any(|statement| match statement.kind {
+            StatementKind::StorageLive(local) | StatementKind::StorageDead(local) => {
+                self.replacements.contains_key(&local)
+            }
+            _ => false,
+        });
+        if !needs_expansion {
+            return;
+        }
+
+        let statements = std::mem::take(&mut data.statements);
+        for statement in statements {
+            let (local, live) = match statement.kind {
+                StatementKind::StorageLive(local) => (local, true),
+                StatementKind::StorageDead(local) => (local, false),
+                _ => {
+                    data.statements.push(statement);
+                    continue;
+                }
+            };
+            let Some(field_locals) = self.replacements.get(&local) else {
+                data.statements.push(statement);
+                continue;
+            };
+            data.statements.extend(field_locals.iter().map(|&(_, _, new_local)| Statement {
+                source_info: statement.source_info,
+                kind: if live {
+                    StatementKind::StorageLive(new_local)
+                } else {
+                    StatementKind::StorageDead(new_local)
+                },
+            }));
+        }
+    }
+}
+
+impl<'tcx> MutVisitor<'tcx> for ReplacementVisitor<'_, 'tcx> {
+    fn tcx(&self) -> TyCtxt<'tcx> {
+        self.tcx
+    }
+
+    fn visit_place(&mut self, place: &mut Place<'tcx>, context: PlaceContext, location: Location) {
+        if let Some(new_place) = self.replace_place(*place) {
+            *place = new_place;
+        }
+        self.super_place(place, context, location);
+    }
+}
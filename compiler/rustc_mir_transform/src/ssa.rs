@@ -0,0 +1,276 @@
+//! Finds the locals in SSA form, and the copies between them.
+//!
+//! A local is in SSA form if it is assigned exactly once, either by an assignment statement or as
+//! the destination of a call (arguments are assigned on entry), if that definition dominates all
+//! its uses, and if it is never mutated otherwise. Its value is then the same at every use. Its
+//! storage markers are ignored: a use is only reachable while the local holds its value.
+//!
+//! Mutable borrows and raw pointers could be used to change the value behind our back, so they
+//! disqualify the local. Shared borrows only allow reading the local, unless its type has interior
+//! mutability, so they are allowed for `Freeze` types. Reads and writes through a dereference of
+//! the local, on the other hand, only read the local itself.
+
+use rustc_data_structures::graph::dominators::Dominators;
+use rustc_index::bit_set::BitSet;
+use rustc_index::vec::IndexVec;
+use rustc_middle::middle::resolve_lifetime::Set1;
+use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::*;
+use rustc_middle::ty::{ParamEnv, TyCtxt};
+use rustc_span::DUMMY_SP;
+
+pub struct SsaLocals {
+    /// The definition of each local, which is `Set1::One` exactly for the SSA locals.
+    assignments: IndexVec<Local, Set1<LocationExtended>>,
+    /// The SSA locals defined by a statement or a call, in reverse postorder of their definitions.
+    assignment_order: Vec<Local>,
+    /// The locals which are borrowed. The SSA ones among them are only borrowed immutably.
+    borrowed_locals: BitSet<Local>,
+    /// For each local, the local it is a copy of, possibly through other copies. Locals which are
+    /// not a copy of another SSA local are their own head.
+    copy_classes: IndexVec<Local, Local>,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum LocationExtended {
+    /// The local is an argument, which is assigned on entry.
+    Arg,
+    /// The local is assigned by the statement or the terminator at this location.
+    Plain(Location),
+}
+
+impl SsaLocals {
+    pub fn new<'tcx>(tcx: TyCtxt<'tcx>, param_env: ParamEnv<'tcx>, body: &Body<'tcx>) -> SsaLocals {
+        let mut assignments = IndexVec::from_elem(Set1::Empty, &body.local_decls);
+        for arg in body.args_iter() {
+            assignments[arg] = Set1::One(LocationExtended::Arg);
+        }
+
+        let mut visitor = SsaVisitor {
+            body,
+            dominators: body.dominators(),
+            assignments,
+            assignment_order: Vec::new(),
+            borrowed_locals: BitSet::new_empty(body.local_decls.len()),
+        };
+        // Visiting in reverse postorder means the definition of an SSA local is always seen before
+        // its uses, which it dominates.
+        for (block, data) in traversal::reverse_postorder(body) {
+            visitor.visit_basic_block_data(block, data);
+        }
+
+        let SsaVisitor { mut assignments, mut assignment_order, borrowed_locals, .. } = visitor;
+        for local in borrowed_locals.iter() {
+            if !body.local_decls[local].ty.is_freeze(tcx.at(DUMMY_SP), param_env) {
+                assignments[local] = Set1::Many;
+            }
+        }
+        assignment_order.retain(|&local| matches!(assignments[local], Set1::One(_)));
+
+        let mut ssa = SsaLocals {
+            assignments,
+            assignment_order,
+            borrowed_locals,
+            copy_classes: IndexVec::from_fn_n(|local| local, body.local_decls.len()),
+        };
+        ssa.compute_copy_classes(body);
+        ssa
+    }
+
+    pub fn is_ssa(&self, local: Local) -> bool {
+        matches!(self.assignments[local], Set1::One(_))
+    }
+
+    /// The locals which are borrowed. Those which are in SSA form are only borrowed immutably, so
+    /// their value doesn't change, but their address is observable.
+    pub fn borrowed_locals(&self) -> &BitSet<Local> {
+        &self.borrowed_locals
+    }
+
+    /// Returns the local each local is a copy of, possibly through other copies, or the local
+    /// itself if it is not a copy. Copies of the return place's class are renamed to the return
+    /// place.
+    pub fn copy_classes(&self) -> &IndexVec<Local, Local> {
+        &self.copy_classes
+    }
+
+    /// Returns an iterator over the SSA locals defined by an assignment statement, and their
+    /// rvalues, in reverse postorder of the definitions.
+    pub fn assignments<'a, 'tcx>(
+        &'a self,
+        body: &'a Body<'tcx>,
+    ) -> impl Iterator<Item = (Local, &'a Rvalue<'tcx>)> + 'a {
+        self.assignment_order.iter().filter_map(|&local| {
+            let Set1::One(LocationExtended::Plain(location)) = self.assignments[local] else {
+                return None;
+            };
+            let block = &body[location.block];
+            match &block.statements.get(location.statement_index)?.kind {
+                StatementKind::Assign(box (place, rvalue)) => {
+                    debug_assert_eq!(place.as_local(), Some(local));
+                    Some((local, rvalue))
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// Restricts `property` to the copy classes in which every local has it.
+    pub fn meet_copy_equivalence(&self, property: &mut BitSet<Local>) {
+        for (local, &head) in self.copy_classes.iter_enumerated() {
+            if !property.contains(local) {
+                property.remove(head);
+            }
+        }
+        for (local, &head) in self.copy_classes.iter_enumerated() {
+            if !property.contains(head) {
+                property.remove(local);
+            }
+        }
+    }
+
+    /// Groups the SSA locals connected by copies `_a = copy _b` or `_a = move _b`. Each copy is
+    /// only seen after the definition of its source, so the head of the source's class is
+    /// already known.
+    fn compute_copy_classes(&mut self, body: &Body<'_>) {
+        let mut copies = IndexVec::from_fn_n(|local| local, body.local_decls.len());
+
+        for (local, rvalue) in self.assignments(body) {
+            let Rvalue::Use(Operand::Copy(place) | Operand::Move(place)) = rvalue else {
+                continue;
+            };
+            let Some(rhs) = place.as_local() else { continue };
+            if !self.is_ssa(rhs) || body.local_decls[local].ty != body.local_decls[rhs].ty {
+                continue;
+            }
+
+            let head = copies[rhs];
+            if local == RETURN_PLACE {
+                // The return place can't be renamed, so rename the class of `rhs` to it instead.
+                // This is only possible if the head has no value before its definition, which
+                // becomes the definition of the return place, and if its address is not observed.
+                if body.local_kind(head) != LocalKind::Temp || self.borrowed_locals.contains(head)
+                {
+                    continue;
+                }
+                for h in copies.iter_mut() {
+                    if *h == head {
+                        *h = RETURN_PLACE;
+                    }
+                }
+            } else {
+                copies[local] = head;
+            }
+        }
+
+        debug!(?copies);
+        self.copy_classes = copies;
+    }
+}
+
+struct SsaVisitor<'a, 'tcx> {
+    body: &'a Body<'tcx>,
+    dominators: Dominators<BasicBlock>,
+    assignments: IndexVec<Local, Set1<LocationExtended>>,
+    assignment_order: Vec<Local>,
+    borrowed_locals: BitSet<Local>,
+}
+
+impl SsaVisitor<'_, '_> {
+    /// Marks `local` as not SSA if its definition does not dominate the use at `location`.
+    fn check_dominates(&mut self, local: Local, location: Location) {
+        let dominates = match self.assignments[local] {
+            Set1::One(LocationExtended::Arg) => true,
+            Set1::One(LocationExtended::Plain(definition)) => self
+                .available_from(definition)
+                .map_or(false, |available| available.dominates(location, &self.dominators)),
+            Set1::Empty | Set1::Many => false,
+        };
+        if !dominates {
+            self.assignments[local] = Set1::Many;
+        }
+    }
+
+    /// Returns the first location at which the value assigned at `definition` is available: the
+    /// next statement for an assignment, and the return block for a call.
+    fn available_from(&self, definition: Location) -> Option<Location> {
+        let data = &self.body[definition.block];
+        if definition.statement_index < data.statements.len() {
+            return Some(definition.successor_within_block());
+        }
+        match data.terminator().kind {
+            TerminatorKind::Call { destination: Some((_, target)), .. } => {
+                Some(target.start_location())
+            }
+            _ => None,
+        }
+    }
+
+    fn record_assignment(&mut self, local: Local, location: Location) {
+        let assignment = &mut self.assignments[local];
+        assignment.insert(LocationExtended::Plain(location));
+        if let Set1::One(_) = assignment {
+            self.assignment_order.push(local);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for SsaVisitor<'_, 'tcx> {
+    fn visit_local(&mut self, &local: &Local, context: PlaceContext, location: Location) {
+        match context {
+            PlaceContext::MutatingUse(MutatingUseContext::Projection)
+            | PlaceContext::NonMutatingUse(NonMutatingUseContext::Projection) => {
+                bug!("projections are handled by `visit_place`")
+            }
+            // Anything can happen through mutable borrows and raw pointers.
+            PlaceContext::MutatingUse(_)
+            | PlaceContext::NonMutatingUse(
+                NonMutatingUseContext::UniqueBorrow | NonMutatingUseContext::AddressOf,
+            ) => {
+                self.assignments[local] = Set1::Many;
+            }
+            // Shared borrows are fine if the type is `Freeze`, which is checked once we know all
+            // the borrowed locals.
+            PlaceContext::NonMutatingUse(
+                NonMutatingUseContext::SharedBorrow | NonMutatingUseContext::ShallowBorrow,
+            ) => {
+                self.borrowed_locals.insert(local);
+                self.check_dominates(local, location);
+            }
+            PlaceContext::NonMutatingUse(_) => self.check_dominates(local, location),
+            PlaceContext::NonUse(_) => {}
+        }
+    }
+
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, location: Location) {
+        if place.projection.first() == Some(&PlaceElem::Deref) {
+            // Writes and borrows through a dereference only read the local itself. Storage
+            // statements and debuginfo are not uses.
+            if context.is_use() {
+                let context = PlaceContext::NonMutatingUse(NonMutatingUseContext::Copy);
+                self.visit_projection(place.as_ref(), context, location);
+                self.check_dominates(place.local, location);
+            }
+            return;
+        }
+
+        if place.projection.is_empty() {
+            let is_definition = match context {
+                PlaceContext::MutatingUse(MutatingUseContext::Call) => true,
+                // `SetDiscriminant` is also visited as a store, but it doesn't define the local.
+                PlaceContext::MutatingUse(MutatingUseContext::Store) => matches!(
+                    self.body[location.block].statements.get(location.statement_index),
+                    Some(Statement { kind: StatementKind::Assign(..), .. })
+                ),
+                _ => false,
+            };
+            if is_definition {
+                self.record_assignment(place.local, location);
+                return;
+            }
+        }
+
+        self.visit_projection(place.as_ref(), context, location);
+        self.visit_local(&place.local, context, location);
+    }
+}
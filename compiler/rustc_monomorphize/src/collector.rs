@@ -180,7 +180,7 @@
 
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_data_structures::sync::{par_iter, MTLock, MTRef, ParallelIterator};
-use rustc_errors::{ErrorReported, FatalError};
+use rustc_errors::{pluralize, ErrorReported, FatalError};
 use rustc_hir as hir;
 use rustc_hir::def_id::{DefId, DefIdMap, LocalDefId, LOCAL_CRATE};
 use rustc_hir::itemlikevisit::ItemLikeVisitor;
@@ -194,14 +194,17 @@
 use rustc_middle::ty::adjustment::{CustomCoerceUnsized, PointerCast};
 use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::subst::{GenericArgKind, InternalSubsts};
-use rustc_middle::ty::{self, GenericParamDefKind, Instance, Ty, TyCtxt, TypeFoldable, VtblEntry};
+use rustc_middle::ty::{
+    self, DefIdTree, GenericParamDefKind, Instance, Ty, TyCtxt, TypeFoldable, VtblEntry,
+};
 use rustc_middle::{middle::codegen_fn_attrs::CodegenFnAttrFlags, mir::visit::TyContext};
 use rustc_session::config::EntryFnType;
-use rustc_session::lint::builtin::LARGE_ASSIGNMENTS;
+use rustc_session::lint::builtin::{LARGE_ASSIGNMENTS, LARGE_FUTURES};
 use rustc_session::Limit;
 use rustc_span::source_map::{dummy_spanned, respan, Span, Spanned, DUMMY_SP};
 use rustc_target::abi::Size;
 use smallvec::SmallVec;
+use std::cmp::Reverse;
 use std::iter;
 use std::ops::Range;
 use std::path::PathBuf;
@@ -603,6 +606,9 @@ fn check_type_length_limit<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) {
     }
 }
 
+/// The number of values held across a suspension point that the `large_futures` lint lists.
+const MAX_HELD_VALUES_LISTED: usize = 3;
+
 struct MirNeighborCollector<'a, 'tcx> {
     tcx: TyCtxt<'tcx>,
     body: &'a mir::Body<'tcx>,
@@ -622,6 +628,101 @@ pub fn monomorphize<T>(&self, value: T) -> T
             value,
         )
     }
+
+    /// Lints the generator whose body is being collected if it is larger than the
+    /// `future_size_limit`, listing the largest values it holds across each suspension point.
+    fn check_generator_size(&self) {
+        let limit = self.tcx.future_size_limit().0;
+        if limit == 0 {
+            return;
+        }
+        // The drop shim of a generator is a copy of its body, layout included.
+        if !matches!(self.instance.def, ty::InstanceDef::Item(_)) {
+            return;
+        }
+        let Some(generator_layout) = self.body.generator_layout() else { return };
+        let tcx = self.tcx;
+        let param_env = ty::ParamEnv::reveal_all();
+        let def_id = self.instance.def_id();
+        let generator_ty = self.monomorphize(tcx.type_of(def_id));
+        let Ok(layout) = tcx.layout_of(param_env.and(generator_ty)) else { return };
+        if layout.size <= Size::from_bytes(limit) {
+            return;
+        }
+        let lint_root = match mir::OUTERMOST_SOURCE_SCOPE.lint_root(&self.body.source_scopes) {
+            Some(lint_root) => lint_root,
+            // The generator is defined in a foreign crate, see `visit_operand`.
+            None => return,
+        };
+
+        let size = layout.size.bytes();
+        let (msg, span, suspension_point) = match tcx.generator_kind(def_id) {
+            Some(hir::GeneratorKind::Async(hir::AsyncGeneratorKind::Fn)) => {
+                let fn_def_id = tcx.parent(def_id).unwrap();
+                let msg = format!(
+                    "the future returned by `{}` is {} bytes",
+                    tcx.def_path_str(fn_def_id),
+                    size
+                );
+                (msg, tcx.def_span(fn_def_id), "`await`")
+            }
+            Some(hir::GeneratorKind::Async(kind)) => {
+                (format!("this {} is {} bytes", kind, size), tcx.def_span(def_id), "`await`")
+            }
+            _ => (format!("this generator is {} bytes", size), tcx.def_span(def_id), "`yield`"),
+        };
+        let span = tcx.sess.source_map().guess_head_span(span);
+
+        tcx.struct_span_lint_hir(LARGE_FUTURES, lint_root, span, |lint| {
+            let mut err = lint.build(&msg);
+            for (variant, fields) in generator_layout.variant_fields.iter_enumerated() {
+                let mut held: Vec<_> = fields
+                    .iter()
+                    .filter_map(|&local| {
+                        let ty = generator_layout.field_tys[local];
+                        let size = tcx.layout_of(param_env.and(self.monomorphize(ty))).ok()?.size;
+                        (size.bytes() > 0).then(|| (size.bytes(), local, ty))
+                    })
+                    .collect();
+                if held.is_empty() {
+                    continue;
+                }
+                held.sort_by_key(|&(size, ..)| Reverse(size));
+
+                let mut values: Vec<_> = held
+                    .iter()
+                    .take(MAX_HELD_VALUES_LISTED)
+                    .map(|&(size, local, ty)| match generator_layout.field_names[local] {
+                        Some(name) => format!("`{}` ({} byte{})", name, size, pluralize!(size)),
+                        None => {
+                            format!("a value of type `{}` ({} byte{})", ty, size, pluralize!(size))
+                        }
+                    })
+                    .collect();
+                let smaller = held.len().saturating_sub(MAX_HELD_VALUES_LISTED);
+                if smaller > 0 {
+                    values.push(format!("{} smaller value{}", smaller, pluralize!(smaller)));
+                }
+                let values = match values.split_last() {
+                    Some((last, rest)) if !rest.is_empty() => {
+                        format!("{} and {}", rest.join(", "), last)
+                    }
+                    _ => values.concat(),
+                };
+                err.span_note(
+                    generator_layout.variant_source_info[variant].span,
+                    &format!(
+                        "{} {} held across this {}",
+                        values,
+                        if held.len() == 1 { "is" } else { "are" },
+                        suspension_point
+                    ),
+                );
+            }
+            err.note(&format!("the `future_size_limit` is {} bytes", limit));
+            err.emit();
+        });
+    }
 }
 
 impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
@@ -710,7 +811,7 @@ fn visit_constant(&mut self, constant: &mir::Constant<'tcx>, location: Location)
         let val = match literal {
             mir::ConstantKind::Val(val, _) => val,
             mir::ConstantKind::Ty(ct) => match ct.val {
-                ty::ConstKind::Value(val) => val,
+                ty::ConstKind::Value(valtree) => self.tcx.valtree_to_const_val((ct.ty, valtree)),
                 ty::ConstKind::Unevaluated(ct) => {
                     let param_env = ty::ParamEnv::reveal_all();
                     match self.tcx.const_eval_resolve(param_env, ct, None) {
@@ -738,7 +839,8 @@ fn visit_const(&mut self, constant: &&'tcx ty::Const<'tcx>, location: Location)
         let param_env = ty::ParamEnv::reveal_all();
 
         match substituted_constant.val {
-            ty::ConstKind::Value(val) => collect_const_value(self.tcx, val, self.output),
+            // Valtrees can't point to functions or statics, so there is nothing to collect.
+            ty::ConstKind::Value(_) => {}
             ty::ConstKind::Unevaluated(unevaluated) => {
                 match self.tcx.const_eval_resolve(param_env, unevaluated, None) {
                     // The `monomorphize` call should have evaluated that constant already.
@@ -768,7 +870,8 @@ fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, location: Loc
 
         let tcx = self.tcx;
         match terminator.kind {
-            mir::TerminatorKind::Call { ref func, .. } => {
+            mir::TerminatorKind::Call { ref func, .. }
+            | mir::TerminatorKind::TailCall { ref func, .. } => {
                 let callee_ty = func.ty(self.body, tcx);
                 let callee_ty = self.monomorphize(callee_ty);
                 visit_fn_use(self.tcx, callee_ty, true, source, &mut self.output);
@@ -1393,7 +1496,9 @@ fn collect_neighbours<'tcx>(
     debug!("collect_neighbours: {:?}", instance.def_id());
     let body = tcx.instance_mir(instance.def);
 
-    MirNeighborCollector { tcx, body: &body, output, instance }.visit_body(&body);
+    let mut collector = MirNeighborCollector { tcx, body: &body, output, instance };
+    collector.check_generator_size();
+    collector.visit_body(&body);
 }
 
 fn collect_const_value<'tcx>(
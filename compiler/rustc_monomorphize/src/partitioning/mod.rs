@@ -217,8 +217,14 @@ pub fn partition<'tcx>(
         // the object file (CGU) containing the dead function stubs is included
         // in the final binary. This will probably require forcing these
         // function symbols to be included via `-u` or `/include` linker args.
+        // CGUs of the same size are ordered by name, so that the same CGU is picked in every
+        // compilation.
         let mut cgus: Vec<_> = post_inlining.codegen_units.iter_mut().collect();
-        cgus.sort_by_key(|cgu| cgu.size_estimate());
+        cgus.sort_by(|a, b| {
+            a.size_estimate()
+                .cmp(&b.size_estimate())
+                .then_with(|| a.name().as_str().cmp(b.name().as_str()))
+        });
 
         let dead_code_cgu = if let Some(cgu) = cgus
             .into_iter()
@@ -1290,6 +1290,8 @@ fn parse_bottom_expr(&mut self) -> PResult<'a, P<Expr>> {
             self.parse_try_block(lo, attrs)
         } else if self.eat_keyword(kw::Return) {
             self.parse_return_expr(attrs)
+        } else if self.eat_keyword(kw::Become) {
+            self.parse_become_expr(attrs)
         } else if self.eat_keyword(kw::Break) {
             self.parse_break_expr(attrs)
         } else if self.eat_keyword(kw::Yield) {
@@ -1523,6 +1525,15 @@ fn parse_return_expr(&mut self, attrs: AttrVec) -> PResult<'a, P<Expr>> {
         self.maybe_recover_from_bad_qpath(expr, true)
     }
 
+    /// Parse `"become" expr`, with `"become"` token already eaten.
+    fn parse_become_expr(&mut self, attrs: AttrVec) -> PResult<'a, P<Expr>> {
+        let lo = self.prev_token.span;
+        let kind = ExprKind::Become(self.parse_expr()?);
+        let span = lo.to(self.prev_token.span);
+        self.sess.gated_spans.gate(sym::explicit_tail_calls, span);
+        Ok(self.mk_expr(span, kind, attrs))
+    }
+
     /// Parse `"break" (('label (:? expr)?) | expr?)` with `"break"` token already eaten.
     /// If the label is followed immediately by a `:` token, the label and `:` are
     /// parsed as part of the expression (i.e. a labeled loop). The language team has
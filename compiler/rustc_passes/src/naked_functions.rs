@@ -211,6 +211,7 @@ fn check_expr(&mut self, expr: &'tcx hir::Expr<'tcx>, span: Span) {
             | ExprKind::Break(..)
             | ExprKind::Continue(..)
             | ExprKind::Ret(..)
+            | ExprKind::Become(..)
             | ExprKind::Struct(..)
             | ExprKind::Repeat(..)
             | ExprKind::Yield(..) => {
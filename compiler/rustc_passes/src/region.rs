@@ -17,7 +17,7 @@
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::TyCtxt;
 use rustc_span::source_map;
-use rustc_span::Span;
+use rustc_span::{DesugaringKind, Span};
 
 use std::mem;
 
@@ -233,7 +233,12 @@ fn resolve_expr<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, expr: &'tcx h
                 terminating(r.hir_id.local_id);
             }
 
-            hir::ExprKind::If(_, ref then, Some(ref otherwise)) => {
+            hir::ExprKind::If(ref cond, ref then, Some(ref otherwise)) => {
+                // The temporaries of a `let...else` initializer are dropped at the end of
+                // the `let` statement, rather than at the end of the enclosing block.
+                if is_let_else(cond) {
+                    terminating(cond.hir_id.local_id);
+                }
                 terminating(then.hir_id.local_id);
                 terminating(otherwise.hir_id.local_id);
             }
@@ -392,6 +397,12 @@ fn resolve_expr<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, expr: &'tcx h
             }
         }
 
+        // The bindings of a `let...else` statement are in scope for the rest of the block, so
+        // its initializer follows the same temporary lifetime rules as a `let` statement's.
+        hir::ExprKind::Let(let_expr) if is_let_else(expr) => {
+            resolve_local(visitor, Some(let_expr.pat), Some(let_expr.init));
+        }
+
         hir::ExprKind::If(ref cond, ref then, Some(ref otherwise)) => {
             let expr_cx = visitor.cx;
             visitor.enter_scope(Scope { id: then.hir_id.local_id, data: ScopeData::IfThen });
@@ -457,6 +468,11 @@ fn resolve_expr<'tcx>(visitor: &mut RegionResolutionVisitor<'tcx>, expr: &'tcx h
     visitor.cx = prev_cx;
 }
 
+/// Returns `true` if `expr` is the condition a `let...else` statement is lowered to.
+fn is_let_else(expr: &hir::Expr<'_>) -> bool {
+    matches!(expr.kind, hir::ExprKind::Let(_)) && expr.span.is_desugaring(DesugaringKind::LetElse)
+}
+
 fn resolve_local<'tcx>(
     visitor: &mut RegionResolutionVisitor<'tcx>,
     pat: Option<&'tcx hir::Pat<'tcx>>,
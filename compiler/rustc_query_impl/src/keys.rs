@@ -285,7 +285,7 @@ fn default_span(&self, _: TyCtxt<'_>) -> Span {
     }
 }
 
-impl<'tcx> Key for mir::interpret::ConstAlloc<'tcx> {
+impl<'tcx> Key for (Ty<'tcx>, ty::ValTree<'tcx>) {
     #[inline(always)]
     fn query_crate_is_local(&self) -> bool {
         true
@@ -6,6 +6,7 @@
 //! Imports are also considered items and placed into modules here, but not resolved yet.
 
 use crate::def_collector::collect_definitions;
+use crate::errors;
 use crate::imports::{Import, ImportKind};
 use crate::macros::{MacroRulesBinding, MacroRulesScope, MacroRulesScopeRef};
 use crate::Namespace::{self, MacroNS, TypeNS, ValueNS};
@@ -19,7 +20,7 @@
 use rustc_ast_lowering::ResolverAstLowering;
 use rustc_attr as attr;
 use rustc_data_structures::sync::Lrc;
-use rustc_errors::{struct_span_err, Applicability};
+use rustc_errors::Applicability;
 use rustc_expand::base::SyntaxExtension;
 use rustc_expand::expand::AstFragment;
 use rustc_hir::def::{self, *};
@@ -535,8 +536,7 @@ fn build_reduced_graph_for_use_tree(
 
                         self.r
                             .session
-                            .struct_span_err(item.span, "`$crate` may not be imported")
-                            .emit();
+                            .emit_err(errors::DollarCrateMayNotBeImported { span: item.span });
                     }
                 }
 
@@ -836,16 +836,10 @@ fn build_reduced_graph_for_extern_crate(
         let expansion = parent_scope.expansion;
 
         let (used, module, binding) = if orig_name.is_none() && ident.name == kw::SelfLower {
-            self.r
-                .session
-                .struct_span_err(item.span, "`extern crate self;` requires renaming")
-                .span_suggestion(
-                    item.span,
-                    "rename the `self` crate to be able to import it",
-                    "extern crate self as name;".into(),
-                    Applicability::HasPlaceholders,
-                )
-                .emit();
+            self.r.session.emit_err(errors::ExternCrateSelfRequiresRenaming {
+                span: item.span,
+                suggestion: (item.span, Applicability::HasPlaceholders),
+            });
             return;
         } else if orig_name == Some(kw::SelfLower) {
             Some(self.r.graph_root)
@@ -1049,13 +1043,9 @@ fn process_macro_use_imports(&mut self, item: &Item, module: Module<'a>) -> bool
         for attr in &item.attrs {
             if attr.has_name(sym::macro_use) {
                 if self.parent_scope.module.parent.is_some() {
-                    struct_span_err!(
-                        self.r.session,
-                        item.span,
-                        E0468,
-                        "an `extern crate` loading macros must be at the crate root"
-                    )
-                    .emit();
+                    self.r.session.emit_err(errors::ExternCrateLoadingMacrosNotAtCrateRoot {
+                        span: item.span,
+                    });
                 }
                 if let ItemKind::ExternCrate(Some(orig_name)) = item.kind {
                     if orig_name == kw::SelfLower {
@@ -1068,8 +1058,7 @@ fn process_macro_use_imports(&mut self, item: &Item, module: Module<'a>) -> bool
                             .emit();
                     }
                 }
-                let ill_formed =
-                    |span| struct_span_err!(self.r.session, span, E0466, "bad macro import").emit();
+                let ill_formed = |span| self.r.session.emit_err(errors::BadMacroImport { span });
                 match attr.meta() {
                     Some(meta) => match meta.kind {
                         MetaItemKind::Word => {
@@ -1142,8 +1131,7 @@ fn process_macro_use_imports(&mut self, item: &Item, module: Module<'a>) -> bool
                         allow_shadowing,
                     );
                 } else {
-                    struct_span_err!(self.r.session, ident.span, E0469, "imported macro not found")
-                        .emit();
+                    self.r.session.emit_err(errors::ImportedMacroNotFound { span: ident.span });
                 }
             }
         }
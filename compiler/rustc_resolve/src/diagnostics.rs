@@ -11,7 +11,7 @@
 use rustc_hir::PrimTy;
 use rustc_middle::bug;
 use rustc_middle::ty::DefIdTree;
-use rustc_session::Session;
+use rustc_session::{Session, SessionDiagnostic};
 use rustc_span::hygiene::MacroKind;
 use rustc_span::lev_distance::find_best_match_for_name;
 use rustc_span::source_map::SourceMap;
@@ -19,6 +19,7 @@
 use rustc_span::{BytePos, MultiSpan, Span};
 use tracing::debug;
 
+use crate::errors;
 use crate::imports::{Import, ImportKind, ImportResolver};
 use crate::path_names_to_string;
 use crate::{AmbiguityError, AmbiguityErrorMisc, AmbiguityKind};
@@ -187,17 +188,8 @@ impl<'a> Resolver<'a> {
                 err
             }
             ResolutionError::NameAlreadyUsedInParameterList(name, first_use_span) => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0403,
-                    "the name `{}` is already used for a generic \
-                     parameter in this item's generic parameters",
-                    name,
-                );
-                err.span_label(span, "already used");
-                err.span_label(first_use_span, format!("first use of `{}`", name));
-                err
+                errors::NameAlreadyUsedInParameterList { span, first_use_span, name }
+                    .into_diagnostic(self.session)
             }
             ResolutionError::MethodNotMemberOfTrait(method, trait_, candidate) => {
                 let mut err = struct_span_err!(
@@ -290,38 +282,16 @@ impl<'a> Resolver<'a> {
                 err
             }
             ResolutionError::VariableBoundWithDifferentMode(variable_name, first_binding_span) => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0409,
-                    "variable `{}` is bound inconsistently across alternatives separated by `|`",
-                    variable_name
-                );
-                err.span_label(span, "bound in different ways");
-                err.span_label(first_binding_span, "first binding");
-                err
+                errors::VariableBoundWithDifferentMode { span, first_binding_span, variable_name }
+                    .into_diagnostic(self.session)
             }
             ResolutionError::IdentifierBoundMoreThanOnceInParameterList(identifier) => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0415,
-                    "identifier `{}` is bound more than once in this parameter list",
-                    identifier
-                );
-                err.span_label(span, "used as parameter more than once");
-                err
+                errors::IdentifierBoundMoreThanOnceInParameterList { span, identifier }
+                    .into_diagnostic(self.session)
             }
             ResolutionError::IdentifierBoundMoreThanOnceInSamePattern(identifier) => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0416,
-                    "identifier `{}` is bound more than once in the same pattern",
-                    identifier
-                );
-                err.span_label(span, "used in a pattern more than once");
-                err
+                errors::IdentifierBoundMoreThanOnceInSamePattern { span, identifier }
+                    .into_diagnostic(self.session)
             }
             ResolutionError::UndeclaredLabel { name, suggestion } => {
                 let mut err = struct_span_err!(
@@ -393,25 +363,11 @@ impl<'a> Resolver<'a> {
                 err
             }
             ResolutionError::SelfImportCanOnlyAppearOnceInTheList => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0430,
-                    "`self` import can only appear once in an import list"
-                );
-                err.span_label(span, "can only appear once in an import list");
-                err
+                errors::SelfImportCanOnlyAppearOnceInTheList { span }.into_diagnostic(self.session)
             }
             ResolutionError::SelfImportOnlyInImportListWithNonEmptyPrefix => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0431,
-                    "`self` import can only appear in an import list with \
-                                                a non-empty prefix"
-                );
-                err.span_label(span, "can only appear in an import list with a non-empty prefix");
-                err
+                errors::SelfImportOnlyInImportListWithNonEmptyPrefix { span }
+                    .into_diagnostic(self.session)
             }
             ResolutionError::FailedToResolve { label, suggestion } => {
                 let mut err =
@@ -429,15 +385,8 @@ impl<'a> Resolver<'a> {
                 err
             }
             ResolutionError::CannotCaptureDynamicEnvironmentInFnItem => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0434,
-                    "{}",
-                    "can't capture dynamic environment in a fn item"
-                );
-                err.help("use the `|| { ... }` closure form instead");
-                err
+                errors::CannotCaptureDynamicEnvironmentInFnItem { span }
+                    .into_diagnostic(self.session)
             }
             ResolutionError::AttemptToUseNonConstantValueInConstant(ident, sugg, current) => {
                 let mut err = struct_span_err!(
@@ -503,31 +452,10 @@ impl<'a> Resolver<'a> {
                 err
             }
             ResolutionError::ForwardDeclaredGenericParam => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0128,
-                    "generic parameters with a default cannot use \
-                                                forward declared identifiers"
-                );
-                err.span_label(
-                    span,
-                    "defaulted generic parameters cannot be forward declared".to_string(),
-                );
-                err
+                errors::ForwardDeclaredGenericParam { span }.into_diagnostic(self.session)
             }
             ResolutionError::ParamInTyOfConstParam(name) => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0770,
-                    "the type of const parameters must not depend on other generic parameters"
-                );
-                err.span_label(
-                    span,
-                    format!("the type must not depend on the parameter `{}`", name),
-                );
-                err
+                errors::ParamInTyOfConstParam { span, name }.into_diagnostic(self.session)
             }
             ResolutionError::ParamInNonTrivialAnonConst { name, is_type } => {
                 let mut err = self.session.struct_span_err(
@@ -554,14 +482,7 @@ impl<'a> Resolver<'a> {
                 err
             }
             ResolutionError::SelfInGenericParamDefault => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0735,
-                    "generic parameters cannot use `Self` in their defaults"
-                );
-                err.span_label(span, "`Self` in generic parameter default".to_string());
-                err
+                errors::SelfInGenericParamDefault { span }.into_diagnostic(self.session)
             }
             ResolutionError::UnreachableLabel { name, definition_span, suggestion } => {
                 let mut err = struct_span_err!(
@@ -626,46 +547,25 @@ impl<'a> Resolver<'a> {
 
     crate fn report_vis_error(&self, vis_resolution_error: VisResolutionError<'_>) {
         match vis_resolution_error {
-            VisResolutionError::Relative2018(span, path) => {
-                let mut err = self.session.struct_span_err(
-                    span,
-                    "relative paths are not supported in visibilities on 2018 edition",
-                );
-                err.span_suggestion(
-                    path.span,
-                    "try",
-                    format!("crate::{}", pprust::path_to_string(&path)),
-                    Applicability::MaybeIncorrect,
-                );
-                err
-            }
-            VisResolutionError::AncestorOnly(span) => struct_span_err!(
-                self.session,
+            VisResolutionError::Relative2018(span, path) => errors::RelativePathInVisibility2018 {
                 span,
-                E0742,
-                "visibilities can only be restricted to ancestor modules"
-            ),
+                path_span: (path.span, Applicability::MaybeIncorrect),
+                path_str: pprust::path_to_string(&path),
+            }
+            .into_diagnostic(self.session),
+            VisResolutionError::AncestorOnly(span) => {
+                errors::VisibilityNotAncestor { span }.into_diagnostic(self.session)
+            }
             VisResolutionError::FailedToResolve(span, label, suggestion) => {
                 self.into_struct_error(span, ResolutionError::FailedToResolve { label, suggestion })
             }
             VisResolutionError::ExpectedFound(span, path_str, res) => {
-                let mut err = struct_span_err!(
-                    self.session,
-                    span,
-                    E0577,
-                    "expected module, found {} `{}`",
-                    res.descr(),
-                    path_str
-                );
-                err.span_label(span, "not a module");
-                err
+                errors::ExpectedModuleFound { span, res_descr: res.descr(), path_str: &path_str }
+                    .into_diagnostic(self.session)
+            }
+            VisResolutionError::Indeterminate(span) => {
+                errors::IndeterminateVisibility { span }.into_diagnostic(self.session)
             }
-            VisResolutionError::Indeterminate(span) => struct_span_err!(
-                self.session,
-                span,
-                E0578,
-                "cannot determine resolution for the visibility"
-            ),
             VisResolutionError::ModuleOnly(span) => {
                 self.session.struct_span_err(span, "visibility must resolve to a module")
             }
@@ -0,0 +1,229 @@
+//! Errors emitted by name resolution.
+use rustc_errors::Applicability;
+use rustc_macros::SessionDiagnostic;
+use rustc_span::{symbol::Ident, Span, Symbol};
+
+#[derive(SessionDiagnostic)]
+#[error = "E0128"]
+#[slug = "resolve-forward-declared-generic-param"]
+pub struct ForwardDeclaredGenericParam {
+    #[message = "generic parameters with a default cannot use forward declared identifiers"]
+    #[label = "defaulted generic parameters cannot be forward declared"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0403"]
+#[slug = "resolve-name-already-used-in-parameter-list"]
+pub struct NameAlreadyUsedInParameterList {
+    #[message = "the name `{name}` is already used for a generic \
+                 parameter in this item's generic parameters"]
+    #[label = "already used"]
+    pub span: Span,
+    #[label = "first use of `{name}`"]
+    pub first_use_span: Span,
+    pub name: Symbol,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0409"]
+#[slug = "resolve-variable-bound-with-different-mode"]
+pub struct VariableBoundWithDifferentMode {
+    #[message = "variable `{variable_name}` is bound inconsistently across alternatives \
+                 separated by `|`"]
+    #[label = "bound in different ways"]
+    pub span: Span,
+    #[label = "first binding"]
+    pub first_binding_span: Span,
+    pub variable_name: Symbol,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0415"]
+#[slug = "resolve-identifier-bound-more-than-once-in-parameter-list"]
+pub struct IdentifierBoundMoreThanOnceInParameterList {
+    #[message = "identifier `{identifier}` is bound more than once in this parameter list"]
+    #[label = "used as parameter more than once"]
+    pub span: Span,
+    pub identifier: Symbol,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0416"]
+#[slug = "resolve-identifier-bound-more-than-once-in-same-pattern"]
+pub struct IdentifierBoundMoreThanOnceInSamePattern {
+    #[message = "identifier `{identifier}` is bound more than once in the same pattern"]
+    #[label = "used in a pattern more than once"]
+    pub span: Span,
+    pub identifier: Symbol,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0430"]
+#[slug = "resolve-self-import-can-only-appear-once-in-the-list"]
+pub struct SelfImportCanOnlyAppearOnceInTheList {
+    #[message = "`self` import can only appear once in an import list"]
+    #[label = "can only appear once in an import list"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0431"]
+#[slug = "resolve-self-import-only-in-import-list-with-non-empty-prefix"]
+pub struct SelfImportOnlyInImportListWithNonEmptyPrefix {
+    #[message = "`self` import can only appear in an import list with a non-empty prefix"]
+    #[label = "can only appear in an import list with a non-empty prefix"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0577"]
+#[slug = "resolve-expected-module-found"]
+pub struct ExpectedModuleFound<'a> {
+    #[message = "expected module, found {res_descr} `{path_str}`"]
+    #[label = "not a module"]
+    pub span: Span,
+    pub res_descr: &'static str,
+    pub path_str: &'a str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0578"]
+#[slug = "resolve-indeterminate-visibility"]
+pub struct IndeterminateVisibility {
+    #[message = "cannot determine resolution for the visibility"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0735"]
+#[slug = "resolve-self-in-generic-param-default"]
+pub struct SelfInGenericParamDefault {
+    #[message = "generic parameters cannot use `Self` in their defaults"]
+    #[label = "`Self` in generic parameter default"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0742"]
+#[slug = "resolve-visibility-not-ancestor"]
+pub struct VisibilityNotAncestor {
+    #[message = "visibilities can only be restricted to ancestor modules"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0770"]
+#[slug = "resolve-param-in-ty-of-const-param"]
+pub struct ParamInTyOfConstParam {
+    #[message = "the type of const parameters must not depend on other generic parameters"]
+    #[label = "the type must not depend on the parameter `{name}`"]
+    pub span: Span,
+    pub name: Symbol,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0434"]
+#[slug = "resolve-cannot-capture-dynamic-environment-in-fn-item"]
+#[help = "use the `|| { ... }` closure form instead"]
+pub struct CannotCaptureDynamicEnvironmentInFnItem {
+    #[message = "can't capture dynamic environment in a fn item"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0789"]
+#[slug = "resolve-relative-path-in-visibility-2018"]
+pub struct RelativePathInVisibility2018 {
+    #[message = "relative paths are not supported in visibilities on 2018 edition"]
+    pub span: Span,
+    #[suggestion(message = "try", code = "crate::{path_str}")]
+    pub path_span: (Span, Applicability),
+    pub path_str: String,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0466"]
+#[slug = "resolve-bad-macro-import"]
+pub struct BadMacroImport {
+    #[message = "bad macro import"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0468"]
+#[slug = "resolve-extern-crate-loading-macros-not-at-crate-root"]
+pub struct ExternCrateLoadingMacrosNotAtCrateRoot {
+    #[message = "an `extern crate` loading macros must be at the crate root"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0469"]
+#[slug = "resolve-imported-macro-not-found"]
+pub struct ImportedMacroNotFound {
+    #[message = "imported macro not found"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0263"]
+#[slug = "resolve-lifetime-declared-twice"]
+pub struct LifetimeDeclaredTwice {
+    #[message = "lifetime name `{name}` declared twice in the same scope"]
+    #[label = "declared twice"]
+    pub span: Span,
+    #[label = "previous declaration here"]
+    pub previous_span: Span,
+    pub name: Ident,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0657"]
+#[slug = "resolve-impl-trait-captures-lifetime-not-at-fn-or-impl"]
+pub struct ImplTraitCapturesLifetimeNotAtFnOrImpl {
+    #[message = "`impl Trait` can only capture lifetimes bound at the fn or impl level"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0687"]
+#[slug = "resolve-in-band-lifetime-in-fn-syntax"]
+pub struct InBandLifetimeInFnSyntax {
+    #[message = "lifetimes used in `fn` or `Fn` syntax must be explicitly declared using `<...>` \
+                 binders"]
+    #[label = "in-band lifetime definition"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0688"]
+#[slug = "resolve-mixed-in-band-and-explicit-lifetimes"]
+pub struct MixedInBandAndExplicitLifetimes {
+    #[message = "cannot mix in-band and explicit lifetime definitions"]
+    #[label = "in-band lifetime definition here"]
+    pub span: Span,
+    #[label = "explicit lifetime definition here"]
+    pub explicit_span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0791"]
+#[slug = "resolve-extern-crate-self-requires-renaming"]
+pub struct ExternCrateSelfRequiresRenaming {
+    #[message = "`extern crate self;` requires renaming"]
+    pub span: Span,
+    #[suggestion(
+        message = "rename the `self` crate to be able to import it",
+        code = "extern crate self as name;"
+    )]
+    pub suggestion: (Span, Applicability),
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0792"]
+#[slug = "resolve-dollar-crate-may-not-be-imported"]
+pub struct DollarCrateMayNotBeImported {
+    #[message = "`$crate` may not be imported"]
+    pub span: Span,
+}
@@ -6,6 +6,7 @@
 //! used between functions, and they operate in a purely top-down
 //! way. Therefore, we break lifetime name resolution into a separate pass.
 
+use crate::errors;
 use crate::late::diagnostics::{ForLifetimeSpanType, MissingLifetimeSpot};
 use rustc_ast::walk_list;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
@@ -1021,14 +1022,11 @@ fn visit_ty(&mut self, ty: &'tcx hir::Ty<'tcx>) {
                     let parent_id = self.tcx.hir().get_parent_node(hir_id);
                     if !parent_id.is_owner() {
                         if !self.trait_definition_only {
-                            struct_span_err!(
-                                self.tcx.sess,
-                                lifetime.span,
-                                E0657,
-                                "`impl Trait` can only capture lifetimes \
-                                    bound at the fn or impl level"
-                            )
-                            .emit();
+                            self.tcx.sess.emit_err(
+                                errors::ImplTraitCapturesLifetimeNotAtFnOrImpl {
+                                    span: lifetime.span,
+                                },
+                            );
                         }
                         self.uninsert_lifetime_on_error(lifetime, def.unwrap());
                     }
@@ -1550,15 +1548,10 @@ fn check_mixed_explicit_and_in_band_defs(tcx: TyCtxt<'_>, params: &[hir::Generic
     let in_band = lifetime_params.iter().find(|(kind, _)| *kind == LifetimeParamKind::InBand);
 
     if let (Some((_, explicit_span)), Some((_, in_band_span))) = (explicit, in_band) {
-        struct_span_err!(
-            tcx.sess,
-            *in_band_span,
-            E0688,
-            "cannot mix in-band and explicit lifetime definitions"
-        )
-        .span_label(*in_band_span, "in-band lifetime definition here")
-        .span_label(*explicit_span, "explicit lifetime definition here")
-        .emit();
+        tcx.sess.emit_err(errors::MixedInBandAndExplicitLifetimes {
+            span: *in_band_span,
+            explicit_span: *explicit_span,
+        });
     }
 }
 
@@ -2351,15 +2344,9 @@ fn resolve_lifetime_ref(&mut self, lifetime_ref: &'tcx hir::Lifetime) {
                 match def {
                     Region::EarlyBound(_, _, LifetimeDefOrigin::InBand)
                     | Region::LateBound(_, _, _, LifetimeDefOrigin::InBand) => {
-                        struct_span_err!(
-                            self.tcx.sess,
-                            lifetime_ref.span,
-                            E0687,
-                            "lifetimes used in `fn` or `Fn` syntax must be \
-                             explicitly declared using `<...>` binders"
-                        )
-                        .span_label(lifetime_ref.span, "in-band lifetime definition")
-                        .emit();
+                        self.tcx
+                            .sess
+                            .emit_err(errors::InBandLifetimeInFnSyntax { span: lifetime_ref.span });
                     }
 
                     Region::Static
@@ -3210,16 +3197,11 @@ fn check_lifetime_params(
             // It is a hard error to shadow a lifetime within the same scope.
             for (lifetime_j, lifetime_j_name) in lifetimes.iter().skip(i + 1) {
                 if lifetime_i_name == lifetime_j_name {
-                    struct_span_err!(
-                        self.tcx.sess,
-                        lifetime_j.span,
-                        E0263,
-                        "lifetime name `{}` declared twice in the same scope",
-                        lifetime_j.name.ident()
-                    )
-                    .span_label(lifetime_j.span, "declared twice")
-                    .span_label(lifetime_i.span, "previous declaration here")
-                    .emit();
+                    self.tcx.sess.emit_err(errors::LifetimeDeclaredTwice {
+                        span: lifetime_j.span,
+                        previous_span: lifetime_i.span,
+                        name: lifetime_j.name.ident(),
+                    });
                 }
             }
 
@@ -88,6 +88,7 @@
 mod check_unused;
 mod def_collector;
 mod diagnostics;
+mod errors;
 mod imports;
 mod late;
 mod macros;
@@ -11,6 +11,8 @@
 use rustc_data_structures::fx::FxHashSet;
 use rustc_data_structures::impl_stable_hash_via_hash;
 
+use rustc_ast::{IntTy, UintTy};
+
 use rustc_target::abi::{Align, TargetDataLayout};
 use rustc_target::spec::{LinkerFlavor, SplitDebuginfo, Target, TargetTriple, TargetWarnings};
 
@@ -112,6 +114,15 @@ pub enum LtoCli {
     Unspecified,
 }
 
+/// The different settings that the `-Z dump-mir-format` flag can have.
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum MirDumpFormat {
+    /// `-Z dump-mir-format=text`, the default: `.mir` files in the textual MIR syntax.
+    Text,
+    /// `-Z dump-mir-format=json`: `.json` files, see the unstable book for the schema.
+    Json,
+}
+
 /// The different settings that the `-Z dump_mir_spanview` flag can have. `Statement` generates a
 /// document highlighting each span of every statement (including terminators). `Terminator` and
 /// `Block` highlight a single span per `BasicBlock`: the span of the block's `Terminator`, or a
@@ -158,6 +169,25 @@ pub enum InstrumentCoverage {
     Off,
 }
 
+/// Settings for `-Z instrument-xray` flag.
+#[derive(Clone, Copy, PartialEq, Hash, Debug, Default)]
+pub struct InstrumentXRay {
+    /// `-Z instrument-xray=always`, force instrumentation
+    pub always: bool,
+    /// `-Z instrument-xray=never`, disable instrumentation
+    pub never: bool,
+    /// `-Z instrument-xray=ignore-loops`, ignore presence of loops,
+    /// instrument functions based only on instruction count
+    pub ignore_loops: bool,
+    /// `-Z instrument-xray=instruction-threshold=N`, explicitly set instruction threshold
+    /// for instrumentation, or `None` to use compiler's default
+    pub instruction_threshold: Option<usize>,
+    /// `-Z instrument-xray=skip-entry`, do not instrument function prologue
+    pub skip_entry: bool,
+    /// `-Z instrument-xray=skip-exit`, do not instrument function epilogue
+    pub skip_exit: bool,
+}
+
 #[derive(Clone, PartialEq, Hash, Debug)]
 pub enum LinkerPluginLto {
     LinkerPlugin(PathBuf),
@@ -224,6 +254,35 @@ pub enum SymbolManglingVersion {
 
 impl_stable_hash_via_hash!(SymbolManglingVersion);
 
+/// What unconstrained diverging type variables (e.g. the type of `panic!()`)
+/// fall back to. See `calculate_diverging_fallback` in `rustc_typeck`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NeverTypeFallback {
+    /// Always fall back to `()`. This is the behavior on stable.
+    Unit,
+    /// Fall back to `!`, except for variables which flow into live code,
+    /// which fall back to `()` instead. This is the behavior of
+    /// `#![feature(never_type_fallback)]`.
+    Niko,
+    /// Always fall back to `!`.
+    Never,
+}
+
+/// What unconstrained integer inference variables (e.g. the type of `1`)
+/// fall back to. See `-Z int-fallback`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntFallback {
+    Signed(IntTy),
+    Unsigned(UintTy),
+}
+
+/// The format `-Z dump-borrowck-facts` writes its output in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BorrowckFactsFormat {
+    /// One JSON document per MIR body.
+    Json,
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Hash)]
 pub enum DebugInfo {
     None,
@@ -357,6 +416,11 @@ pub enum ErrorOutputType {
         /// human output.
         json_rendered: HumanReadableErrorType,
     },
+    /// A SARIF log, for tools such as code scanning services.
+    Sarif,
+    /// The identical diagnostics grouped like `--error-format=short` does, as one JSON object per
+    /// line, followed by a summary of the number of errors and warnings of each code or lint.
+    ShortJson,
 }
 
 impl Default for ErrorOutputType {
@@ -767,6 +831,7 @@ fn default() -> Options {
             borrowck_mode: BorrowckMode::Migrate,
             cg: Default::default(),
             error_format: ErrorOutputType::default(),
+            diagnostic_width: None,
             externs: Externs(BTreeMap::new()),
             extern_dep_specs: ExternDepSpecs(BTreeMap::new()),
             crate_name: None,
@@ -870,6 +935,31 @@ pub fn is_archive(&self) -> bool {
     }
 }
 
+/// The value of `-Csplit-debuginfo`: a mode for all crate types, and the modes of the crate types
+/// which were given their own, as in `-Csplit-debuginfo=packed,rlib=unpacked`.
+#[derive(Clone, Debug, Default, Hash, PartialEq)]
+pub struct SplitDebuginfoConfig {
+    /// The mode of the crate types which were not given their own.
+    pub default: Option<SplitDebuginfo>,
+    pub per_crate_type: Vec<(CrateType, SplitDebuginfo)>,
+}
+
+impl SplitDebuginfoConfig {
+    pub fn is_empty(&self) -> bool {
+        self.default.is_none() && self.per_crate_type.is_empty()
+    }
+
+    /// Returns the mode requested for `crate_type`, if any.
+    pub fn for_crate_type(&self, crate_type: CrateType) -> Option<SplitDebuginfo> {
+        self.per_crate_type
+            .iter()
+            .rev()
+            .find(|&&(ty, _)| ty == crate_type)
+            .map(|&(_, mode)| mode)
+            .or(self.default)
+    }
+}
+
 #[derive(Clone, Hash, Debug, PartialEq, Eq)]
 pub enum Passes {
     Some(Vec<String>),
@@ -1256,6 +1346,12 @@ pub fn rustc_optgroups() -> Vec<RustcOptGroup> {
             "human|json|short",
         ),
         opt::multi_s("", "json", "Configure the JSON output of the compiler", "CONFIG"),
+        opt::opt(
+            "",
+            "diagnostic-width",
+            "Inform rustc of the width of the output so that diagnostics fit in it",
+            "WIDTH",
+        ),
         opt::opt_s(
             "",
             "color",
@@ -1400,7 +1496,9 @@ pub fn parse_error_format(
             }
             Some("json") => ErrorOutputType::Json { pretty: false, json_rendered },
             Some("pretty-json") => ErrorOutputType::Json { pretty: true, json_rendered },
+            Some("sarif") => ErrorOutputType::Sarif,
             Some("short") => ErrorOutputType::HumanReadable(HumanReadableErrorType::Short(color)),
+            Some("short-json") => ErrorOutputType::ShortJson,
 
             Some(arg) => early_error(
                 ErrorOutputType::HumanReadable(HumanReadableErrorType::Default(color)),
@@ -1485,6 +1583,18 @@ fn check_debug_option_stability(
                 "`--error-format=human-annotate-rs` is unstable",
             );
         }
+        if let ErrorOutputType::Sarif = error_format {
+            early_error(
+                ErrorOutputType::Json { pretty: false, json_rendered },
+                "`--error-format=sarif` is unstable",
+            );
+        }
+        if let ErrorOutputType::ShortJson = error_format {
+            early_error(
+                ErrorOutputType::Json { pretty: false, json_rendered },
+                "`--error-format=short-json` is unstable",
+            );
+        }
     }
 }
 
@@ -2109,6 +2219,17 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
 
     check_debug_option_stability(&debugging_opts, error_format, json_rendered);
 
+    let diagnostic_width = match matches.opt_str("diagnostic-width") {
+        Some(width) => Some(width.parse().unwrap_or_else(|_| {
+            early_error(
+                error_format,
+                &format!("`--diagnostic-width` expects a number of columns, found `{}`", width),
+            )
+        })),
+        // `-Z terminal-width` is the unstable flag that `--diagnostic-width` replaces.
+        None => debugging_opts.terminal_width,
+    };
+
     if !debugging_opts.unstable_options && json_unused_externs {
         early_error(
             error_format,
@@ -2312,13 +2433,21 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
 
     if !debugging_opts.unstable_options
         && !target_triple.triple().contains("apple")
-        && cg.split_debuginfo.is_some()
+        && !cg.split_debuginfo.is_empty()
     {
         {
             early_error(error_format, "`-Csplit-debuginfo` is unstable on this platform");
         }
     }
 
+    if !debugging_opts.unstable_options && !cg.split_debuginfo.per_crate_type.is_empty() {
+        early_error(
+            error_format,
+            "the `-Z unstable-options` flag must also be passed to \
+             enable the modes per crate type of `-C split-debuginfo`",
+        );
+    }
+
     // Try to find a directory containing the Rust `src`, for more details see
     // the doc comment on the `real_rust_source_base_dir` field.
     let tmp_buf;
@@ -2378,6 +2507,7 @@ pub fn build_session_options(matches: &getopts::Matches) -> Options {
         borrowck_mode,
         cg,
         error_format,
+        diagnostic_width,
         externs,
         unstable_features: UnstableFeatures::from_environment(crate_name.as_deref()),
         extern_dep_specs,
@@ -2635,12 +2765,14 @@ pub fn needs_analysis(&self) -> bool {
     use super::LdImpl;
     use super::{
         BranchProtection, CFGuard, CrateType, DebugInfo, ErrorOutputType, InstrumentCoverage,
-        LinkerPluginLto, LocationDetail, LtoCli, OptLevel, OutputType, OutputTypes, Passes,
-        SourceFileHashAlgorithm, SwitchWithOptPath, SymbolManglingVersion, TrimmedDefPaths,
+        InstrumentXRay, IntFallback, LinkerPluginLto, LocationDetail, LtoCli, NeverTypeFallback,
+        OptLevel, OutputType, OutputTypes, Passes, SourceFileHashAlgorithm, SplitDebuginfoConfig,
+        SwitchWithOptPath, SymbolManglingVersion, TrimmedDefPaths,
     };
     use crate::lint;
     use crate::options::WasiExecModel;
     use crate::utils::{NativeLib, NativeLibKind};
+    use rustc_ast::FloatTy;
     use rustc_feature::UnstableFeatures;
     use rustc_span::edition::Edition;
     use rustc_span::RealFileName;
@@ -2704,6 +2836,7 @@ fn hash(
         CodeModel,
         TlsModel,
         InstrumentCoverage,
+        InstrumentXRay,
         CrateType,
         MergeFunctions,
         PanicStrategy,
@@ -2721,6 +2854,7 @@ fn hash(
         Edition,
         LinkerPluginLto,
         SplitDebuginfo,
+        SplitDebuginfoConfig,
         StackProtector,
         SwitchWithOptPath,
         SymbolManglingVersion,
@@ -2731,6 +2865,9 @@ fn hash(
         RealFileName,
         LocationDetail,
         BranchProtection,
+        NeverTypeFallback,
+        IntFallback,
+        FloatTy,
     );
 
     impl<T1, T2> DepTrackingHash for (T1, T2)
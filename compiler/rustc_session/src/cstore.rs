@@ -86,6 +86,24 @@ pub struct DllImport {
     pub calling_convention: DllCallingConvention,
     /// Span of import's "extern" declaration; used for diagnostics.
     pub span: Span,
+    /// Whether this is a function, rather than a static.
+    pub is_fn: bool,
+    /// How the name of the import maps to the name the library exports it under, as given by
+    /// `#[link(import_name_type = "...")]`. Only used on i686.
+    pub import_name_type: Option<PeImportNameType>,
+}
+
+/// How the name an i686 import is exported under is derived from its name.
+#[derive(Copy, Clone, PartialEq, Debug, Encodable, Decodable, HashStable_Generic)]
+pub enum PeImportNameType {
+    /// The name is fully decorated, e.g. `_foo@4` for a stdcall function. This is what the
+    /// target's toolchain would use by default.
+    Decorated,
+    /// The name is decorated, but without its `_` or `@` prefix, e.g. `foo@4`.
+    NoPrefix,
+    /// The name isn't decorated at all, e.g. `foo`, which is how most system libraries export
+    /// their functions.
+    Undecorated,
 }
 
 /// Calling convention for a function defined in an external library.
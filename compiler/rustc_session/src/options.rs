@@ -4,6 +4,7 @@
 use crate::lint;
 use crate::search_paths::SearchPath;
 use crate::utils::NativeLib;
+use rustc_ast::{FloatTy, IntTy, UintTy};
 use rustc_target::spec::{CodeModel, LinkerFlavor, MergeFunctions, PanicStrategy, SanitizerSet};
 use rustc_target::spec::{
     RelocModel, RelroLevel, SplitDebuginfo, StackProtector, TargetTriple, TlsModel,
@@ -169,6 +170,9 @@ pub struct Options {
 
         test: bool [TRACKED],
         error_format: ErrorOutputType [UNTRACKED],
+        /// The width that diagnostics are rendered to, if it was given with `--diagnostic-width`
+        /// (or `-Z terminal-width`), instead of the width of the terminal.
+        diagnostic_width: Option<usize> [UNTRACKED],
 
         /// If `Some`, enable incremental compilation, using the given
         /// directory to store intermediate results.
@@ -372,6 +376,8 @@ mod desc {
     pub const parse_string_push: &str = parse_string;
     pub const parse_opt_pathbuf: &str = "a path";
     pub const parse_list: &str = "a space-separated list of strings";
+    pub const parse_list_with_polarity: &str =
+        "a comma-separated list of strings, with elements beginning with + or -";
     pub const parse_opt_comma_list: &str = "a comma-separated list of strings";
     pub const parse_number: &str = "a number";
     pub const parse_opt_number: &str = parse_number;
@@ -380,16 +386,18 @@ mod desc {
     pub const parse_panic_strategy: &str = "either `unwind` or `abort`";
     pub const parse_opt_panic_strategy: &str = parse_panic_strategy;
     pub const parse_relro_level: &str = "one of: `full`, `partial`, or `off`";
-    pub const parse_sanitizers: &str = "comma separated list of sanitizers: `address`, `cfi`, `hwaddress`, `leak`, `memory` or `thread`";
+    pub const parse_sanitizers: &str = "comma separated list of sanitizers: `address`, `cfi`, `hwaddress`, `kcfi`, `leak`, `memory` or `thread`";
     pub const parse_sanitizer_memory_track_origins: &str = "0, 1, or 2";
     pub const parse_cfguard: &str =
         "either a boolean (`yes`, `no`, `on`, `off`, etc), `checks`, or `nochecks`";
     pub const parse_strip: &str = "either `none`, `debuginfo`, or `symbols`";
     pub const parse_linker_flavor: &str = ::rustc_target::spec::LinkerFlavor::one_of();
     pub const parse_optimization_fuel: &str = "crate=integer";
+    pub const parse_mir_dump_format: &str = "either `text` (default) or `json`";
     pub const parse_mir_spanview: &str = "`statement` (default), `terminator`, or `block`";
     pub const parse_instrument_coverage: &str =
         "`all` (default), `except-unused-generics`, `except-unused-functions`, or `off`";
+    pub const parse_instrument_xray: &str = "either a boolean (`yes`, `no`, `on`, `off`, etc), or a comma separated list of settings: `always` or `never` (mutually exclusive), `ignore-loops`, `instruction-threshold=N`, `skip-entry`, `skip-exit`";
     pub const parse_unpretty: &str = "`string` or `string=string`";
     pub const parse_treat_err_as_bug: &str = "either no value or a number bigger than 0";
     pub const parse_lto: &str =
@@ -402,6 +410,12 @@ mod desc {
         "an optional path to the profiling data output directory";
     pub const parse_merge_functions: &str = "one of: `disabled`, `trampolines`, or `aliases`";
     pub const parse_symbol_mangling_version: &str = "either `legacy` or `v0` (RFC 2603)";
+    pub const parse_never_type_fallback: &str = "one of: `unit`, `niko`, or `never`";
+    pub const parse_borrowck_facts_format: &str = "`json`";
+    pub const parse_int_fallback: &str =
+        "one of: `i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `u8`, `u16`, `u32`, `u64`, `u128`, \
+        or `usize`";
+    pub const parse_float_fallback: &str = "either `f32` or `f64`";
     pub const parse_src_file_hash: &str = "either `md5` or `sha1`";
     pub const parse_relocation_model: &str =
         "one of supported relocation models (`rustc --print relocation-models`)";
@@ -409,15 +423,15 @@ mod desc {
     pub const parse_tls_model: &str = "one of supported TLS models (`rustc --print tls-models`)";
     pub const parse_target_feature: &str = parse_string;
     pub const parse_wasi_exec_model: &str = "either `command` or `reactor`";
-    pub const parse_split_debuginfo: &str =
-        "one of supported split-debuginfo modes (`off`, `packed`, or `unpacked`)";
+    pub const parse_split_debuginfo: &str = "a comma-separated list of supported split-debuginfo \
+        modes (`off`, `packed`, or `unpacked`), each optionally preceded by a crate type and `=`";
     pub const parse_split_dwarf_kind: &str =
         "one of supported split dwarf modes (`split` or `single`)";
     pub const parse_gcc_ld: &str = "one of: no value, `lld`";
     pub const parse_stack_protector: &str =
         "one of (`none` (default), `basic`, `strong`, or `all`)";
     pub const parse_branch_protection: &str =
-        "a `,` separated combination of `bti`, `b-key`, `pac-ret`, or `leaf`";
+        "a `,` or `+` separated combination of `bti`, `b-key`, `pac-ret`, or `leaf`";
 }
 
 mod parse {
@@ -520,6 +534,29 @@ mod parse {
         }
     }
 
+    crate fn parse_list_with_polarity(slot: &mut Vec<(String, bool)>, v: Option<&str>) -> bool {
+        match v {
+            Some(s) => {
+                for s in s.split(',') {
+                    let (pass_name, enabled) = if let Some(pass_name) = s.strip_prefix('+') {
+                        (pass_name, true)
+                    } else if let Some(pass_name) = s.strip_prefix('-') {
+                        (pass_name, false)
+                    } else {
+                        return false;
+                    };
+                    let is_pass_name_char = |c: char| c.is_ascii_alphanumeric() || "-_".contains(c);
+                    if pass_name.is_empty() || !pass_name.chars().all(is_pass_name_char) {
+                        return false;
+                    }
+                    slot.push((pass_name.to_string(), enabled));
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
     crate fn parse_location_detail(ld: &mut LocationDetail, v: Option<&str>) -> bool {
         if let Some(v) = v {
             ld.line = false;
@@ -640,6 +677,7 @@ mod parse {
                 *slot |= match s {
                     "address" => SanitizerSet::ADDRESS,
                     "cfi" => SanitizerSet::CFI,
+                    "kcfi" => SanitizerSet::KCFI,
                     "leak" => SanitizerSet::LEAK,
                     "memory" => SanitizerSet::MEMORY,
                     "thread" => SanitizerSet::THREAD,
@@ -737,6 +775,15 @@ mod parse {
         }
     }
 
+    crate fn parse_mir_dump_format(slot: &mut MirDumpFormat, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("text") => MirDumpFormat::Text,
+            Some("json") => MirDumpFormat::Json,
+            _ => return false,
+        };
+        true
+    }
+
     crate fn parse_mir_spanview(slot: &mut Option<MirSpanview>, v: Option<&str>) -> bool {
         if v.is_some() {
             let mut bool_arg = None;
@@ -797,6 +844,66 @@ mod parse {
         true
     }
 
+    crate fn parse_instrument_xray(slot: &mut Option<InstrumentXRay>, v: Option<&str>) -> bool {
+        if v.is_some() {
+            let mut bool_arg = None;
+            if parse_opt_bool(&mut bool_arg, v) {
+                *slot = if bool_arg.unwrap() { Some(InstrumentXRay::default()) } else { None };
+                return true;
+            }
+        }
+
+        let options = slot.get_or_insert_with(InstrumentXRay::default);
+        let mut seen_always = false;
+        let mut seen_never = false;
+        let mut seen_ignore_loops = false;
+        let mut seen_instruction_threshold = false;
+        let mut seen_skip_entry = false;
+        let mut seen_skip_exit = false;
+        for option in v.into_iter().flat_map(|v| v.split(',')) {
+            match option {
+                "always" if !seen_always && !seen_never => {
+                    options.always = true;
+                    options.never = false;
+                    seen_always = true;
+                }
+                "never" if !seen_never && !seen_always => {
+                    options.never = true;
+                    options.always = false;
+                    seen_never = true;
+                }
+                "ignore-loops" if !seen_ignore_loops => {
+                    options.ignore_loops = true;
+                    seen_ignore_loops = true;
+                }
+                option
+                    if option.starts_with("instruction-threshold")
+                        && !seen_instruction_threshold =>
+                {
+                    let n = match option.split_once('=') {
+                        Some(("instruction-threshold", n)) => n,
+                        _ => return false,
+                    };
+                    match n.parse() {
+                        Ok(n) => options.instruction_threshold = Some(n),
+                        Err(_) => return false,
+                    }
+                    seen_instruction_threshold = true;
+                }
+                "skip-entry" if !seen_skip_entry => {
+                    options.skip_entry = true;
+                    seen_skip_entry = true;
+                }
+                "skip-exit" if !seen_skip_exit => {
+                    options.skip_exit = true;
+                    seen_skip_exit = true;
+                }
+                _ => return false,
+            }
+        }
+        true
+    }
+
     crate fn parse_treat_err_as_bug(slot: &mut Option<NonZeroUsize>, v: Option<&str>) -> bool {
         match v {
             Some(s) => {
@@ -901,6 +1008,58 @@ mod parse {
         true
     }
 
+    crate fn parse_never_type_fallback(
+        slot: &mut Option<NeverTypeFallback>,
+        v: Option<&str>,
+    ) -> bool {
+        *slot = match v {
+            Some("unit") => Some(NeverTypeFallback::Unit),
+            Some("niko") => Some(NeverTypeFallback::Niko),
+            Some("never") => Some(NeverTypeFallback::Never),
+            _ => return false,
+        };
+        true
+    }
+
+    crate fn parse_borrowck_facts_format(
+        slot: &mut Option<BorrowckFactsFormat>,
+        v: Option<&str>,
+    ) -> bool {
+        *slot = match v {
+            Some("json") => Some(BorrowckFactsFormat::Json),
+            _ => return false,
+        };
+        true
+    }
+
+    crate fn parse_int_fallback(slot: &mut Option<IntFallback>, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("i8") => Some(IntFallback::Signed(IntTy::I8)),
+            Some("i16") => Some(IntFallback::Signed(IntTy::I16)),
+            Some("i32") => Some(IntFallback::Signed(IntTy::I32)),
+            Some("i64") => Some(IntFallback::Signed(IntTy::I64)),
+            Some("i128") => Some(IntFallback::Signed(IntTy::I128)),
+            Some("isize") => Some(IntFallback::Signed(IntTy::Isize)),
+            Some("u8") => Some(IntFallback::Unsigned(UintTy::U8)),
+            Some("u16") => Some(IntFallback::Unsigned(UintTy::U16)),
+            Some("u32") => Some(IntFallback::Unsigned(UintTy::U32)),
+            Some("u64") => Some(IntFallback::Unsigned(UintTy::U64)),
+            Some("u128") => Some(IntFallback::Unsigned(UintTy::U128)),
+            Some("usize") => Some(IntFallback::Unsigned(UintTy::Usize)),
+            _ => return false,
+        };
+        true
+    }
+
+    crate fn parse_float_fallback(slot: &mut Option<FloatTy>, v: Option<&str>) -> bool {
+        *slot = match v {
+            Some("f32") => Some(FloatTy::F32),
+            Some("f64") => Some(FloatTy::F64),
+            _ => return false,
+        };
+        true
+    }
+
     crate fn parse_src_file_hash(
         slot: &mut Option<SourceFileHashAlgorithm>,
         v: Option<&str>,
@@ -934,10 +1093,29 @@ mod parse {
         true
     }
 
-    crate fn parse_split_debuginfo(slot: &mut Option<SplitDebuginfo>, v: Option<&str>) -> bool {
-        match v.and_then(|s| SplitDebuginfo::from_str(s).ok()) {
-            Some(e) => *slot = Some(e),
-            _ => return false,
+    crate fn parse_split_debuginfo(slot: &mut SplitDebuginfoConfig, v: Option<&str>) -> bool {
+        let v = match v {
+            Some(v) => v,
+            None => return false,
+        };
+        for part in v.split(',') {
+            let (crate_type, mode) = match part.split_once('=') {
+                Some((crate_type, mode)) => {
+                    match parse_crate_types_from_list(vec![crate_type.to_string()]) {
+                        Ok(crate_types) => (Some(crate_types[0]), mode),
+                        Err(_) => return false,
+                    }
+                }
+                None => (None, part),
+            };
+            let mode = match SplitDebuginfo::from_str(mode) {
+                Ok(mode) => mode,
+                Err(_) => return false,
+            };
+            match crate_type {
+                Some(crate_type) => slot.per_crate_type.push((crate_type, mode)),
+                None => slot.default = Some(mode),
+            }
         }
         true
     }
@@ -970,7 +1148,7 @@ mod parse {
     crate fn parse_branch_protection(slot: &mut BranchProtection, v: Option<&str>) -> bool {
         match v {
             Some(s) => {
-                for opt in s.split(',') {
+                for opt in s.split(|c| c == ',' || c == '+') {
                     match opt {
                         "bti" => slot.bti = true,
                         "pac-ret" if slot.pac_ret.is_none() => {
@@ -1096,8 +1274,9 @@ mod parse {
         "save all temporary output files during compilation (default: no)"),
     soft_float: bool = (false, parse_bool, [TRACKED],
         "use soft float ABI (*eabihf targets only) (default: no)"),
-    split_debuginfo: Option<SplitDebuginfo> = (None, parse_split_debuginfo, [TRACKED],
-        "how to handle split-debuginfo, a platform-specific option"),
+    split_debuginfo: SplitDebuginfoConfig = (SplitDebuginfoConfig::default(),
+        parse_split_debuginfo, [TRACKED],
+        "how to handle split-debuginfo, a platform-specific option, for all or some crate types"),
     strip: Strip = (Strip::None, parse_strip, [UNTRACKED],
         "tell the linker which information to strip (`none` (default), `debuginfo` or `symbols`)"),
     symbol_mangling_version: Option<SymbolManglingVersion> = (None,
@@ -1152,8 +1331,17 @@ mod parse {
         "enable the experimental Chalk-based trait solving engine"),
     codegen_backend: Option<String> = (None, parse_opt_string, [TRACKED],
         "the backend to use"),
+    coinductive_traits: bool = (false, parse_bool, [TRACKED],
+        "treat all trait goals as coinductive, so that cycles between them hold \
+        instead of overflowing (unsound; for experimentation only) (default: no)"),
     combine_cgu: bool = (false, parse_bool, [TRACKED],
         "combine CGUs into a single one"),
+    const_eval_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "the number of terminators const evaluation may execute before it errors; overrides \
+        `#![const_eval_limit]`, and `0` disables the limit (default: 1000000)"),
+    const_eval_memory_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "the number of bytes of stack and heap memory const evaluation may have allocated at once \
+        before it errors (default: no limit)"),
     crate_attr: Vec<String> = (Vec::new(), parse_string_push, [TRACKED],
         "inject the given attribute in the crate"),
     debug_info_for_profiling: bool = (false, parse_bool, [TRACKED],
@@ -1177,6 +1365,9 @@ mod parse {
         "enables drop tracking in generators (default: no)"),
     dual_proc_macros: bool = (false, parse_bool, [TRACKED],
         "load proc macros for both target and host, but only link to the target (default: no)"),
+    dump_borrowck_facts: Option<BorrowckFactsFormat> = (None, parse_borrowck_facts_format, [UNTRACKED],
+        "dump the region constraint graph, borrow set and outlives facts of each MIR body \
+        into `-Z nll-facts-dir` (format: `json`)"),
     dump_dep_graph: bool = (false, parse_bool, [UNTRACKED],
         "dump the dependency graph to $RUST_DEP_GRAPH (default: /tmp/dep_graph.gv) \
         (default: no)"),
@@ -1194,6 +1385,9 @@ mod parse {
         "the directory the MIR is dumped into (default: `mir_dump`)"),
     dump_mir_exclude_pass_number: bool = (false, parse_bool, [UNTRACKED],
         "exclude the pass number when dumping MIR (used in tests) (default: no)"),
+    dump_mir_format: MirDumpFormat = (MirDumpFormat::Text, parse_mir_dump_format, [UNTRACKED],
+        "the format of the dumped MIR: `text` for `.mir` files, or `json` for `.json` files \
+        (default: text)"),
     dump_mir_graphviz: bool = (false, parse_bool, [UNTRACKED],
         "in addition to `.mir` files, create graphviz `.dot` files (and with \
         `-Z instrument-coverage`, also create a `.dot` file for the MIR-derived \
@@ -1204,11 +1398,20 @@ mod parse {
         computed `block` spans (one span encompassing a block's terminator and \
         all statements). If `-Z instrument-coverage` is also enabled, create \
         an additional `.html` file showing the computed coverage spans."),
+    dump_thinlto_summary: bool = (false, parse_bool, [UNTRACKED],
+        "dump the combined ThinLTO summary of the crate, with the size and calls of each \
+        function and the functions imported into each module, to `<crate>.thinlto-summary.txt` \
+        (default: no)"),
     emit_stack_sizes: bool = (false, parse_bool, [UNTRACKED],
         "emit a section containing stack size metadata (default: no)"),
+    explain_region_errors: bool = (false, parse_bool, [TRACKED],
+        "explain each \"lifetime may not live long enough\" error with the chain of outlives \
+        constraints that led to it (default: no)"),
     fewer_names: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "reduce memory use by retaining fewer names within compilation artifacts (LLVM-IR) \
         (default: no)"),
+    float_fallback: Option<FloatTy> = (None, parse_float_fallback, [TRACKED],
+        "the type that unconstrained floating-point literals fall back to (default: `f64`)"),
     force_unstable_if_unmarked: bool = (false, parse_bool, [TRACKED],
         "force all crates to be `rustc_private` unstable (default: no)"),
     fuel: Option<(String, u64)> = (None, parse_optimization_fuel, [TRACKED],
@@ -1217,12 +1420,18 @@ mod parse {
         "whether each function should go in its own section"),
     future_incompat_test: bool = (false, parse_bool, [UNTRACKED],
         "forces all lints to be future incompatible, used for internal testing (default: no)"),
+    future_size_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
+        "the size of generators and futures at which the `large_futures` lint starts to be \
+        emitted"),
     gcc_ld: Option<LdImpl> = (None, parse_gcc_ld, [TRACKED], "implementation of ld used by cc"),
     graphviz_dark_mode: bool = (false, parse_bool, [UNTRACKED],
         "use dark-themed colors in graphviz output (default: no)"),
     graphviz_font: String = ("Courier, monospace".to_string(), parse_string, [UNTRACKED],
         "use the given `fontname` in graphviz output; can be overridden by setting \
         environment variable `RUSTC_GRAPHVIZ_FONT` (default: `Courier, monospace`)"),
+    group_diagnostics: bool = (false, parse_bool, [UNTRACKED],
+        "with `--error-format=short`, report identical errors and warnings once, with all their \
+        locations, followed by a summary of their number (default: no)"),
     hir_stats: bool = (false, parse_bool, [UNTRACKED],
         "print some statistics about AST and HIR (default: no)"),
     human_readable_cgu_names: bool = (false, parse_bool, [TRACKED],
@@ -1244,6 +1453,8 @@ mod parse {
         "a default MIR inlining threshold (default: 50)"),
     inline_mir_hint_threshold: Option<usize> = (None, parse_opt_number, [TRACKED],
         "inlining threshold for functions with inline hint (default: 100)"),
+    inline_mir_report: bool = (false, parse_bool, [TRACKED],
+        "emit a note explaining the MIR inlining decision at each call site (default: no)"),
     inline_in_all_cgus: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "control whether `#[inline]` functions are in all CGUs"),
     input_stats: bool = (false, parse_bool, [UNTRACKED],
@@ -1258,6 +1469,18 @@ mod parse {
         `=off` (default)"),
     instrument_mcount: bool = (false, parse_bool, [TRACKED],
         "insert function instrument code for mcount-based tracing (default: no)"),
+    instrument_xray: Option<InstrumentXRay> = (None, parse_instrument_xray, [TRACKED],
+        "insert function instrument code for XRay-based tracing (default: no)
+         Optional extra settings:
+         `=always`
+         `=never`
+         `=ignore-loops`
+         `=instruction-threshold=N`
+         `=skip-entry`
+         `=skip-exit`
+         Multiple options can be combined with commas."),
+    int_fallback: Option<IntFallback> = (None, parse_int_fallback, [TRACKED],
+        "the type that unconstrained integer literals fall back to (default: `i32`)"),
     keep_hygiene_data: bool = (false, parse_bool, [UNTRACKED],
         "keep hygiene data after analysis (default: no)"),
     link_native_libraries: bool = (true, parse_bool, [UNTRACKED],
@@ -1271,10 +1494,17 @@ mod parse {
     location_detail: LocationDetail = (LocationDetail::all(), parse_location_detail, [TRACKED],
         "comma seperated list of location details to be tracked when using caller_location \
         valid options are `file`, `line`, and `column` (default: all)"),
+    long_type_length: Option<usize> = (None, parse_opt_number, [UNTRACKED],
+        "print the types longer than this many characters in a shortened form in diagnostics, \
+        and write them in full to a `long-type-<hash>.txt` file in the output directory \
+        (default: no limit)"),
     ls: bool = (false, parse_bool, [UNTRACKED],
         "list the symbols defined by a library crate (default: no)"),
     macro_backtrace: bool = (false, parse_bool, [UNTRACKED],
         "show macro backtraces (default: no)"),
+    max_const_backtrace_frames: usize = (32, parse_number, [UNTRACKED],
+        "the maximum number of frames shown in the backtraces of const-eval errors; the \
+        innermost and the outermost ones are kept (default: 32, 0 means no limit)"),
     merge_functions: Option<MergeFunctions> = (None, parse_merge_functions, [TRACKED],
         "control the operation of the MergeFunctions LLVM pass, taking \
         the same values as the target option of the same name"),
@@ -1283,12 +1513,21 @@ mod parse {
     mir_emit_retag: bool = (false, parse_bool, [TRACKED],
         "emit Retagging MIR statements, interpreted e.g., by miri; implies -Zmir-opt-level=0 \
         (default: no)"),
+    mir_enable_passes: Vec<(String, bool)> = (Vec::new(), parse_list_with_polarity, [TRACKED],
+        "use like `-Zmir-enable-passes=+DestinationPropagation,-InstCombine`. Forces the specified \
+        passes to be enabled or disabled, overriding `-Zmir-opt-level` and all other checks. Passes \
+        that are not specified are enabled or disabled by other flags as usual. Later elements win \
+        over earlier ones"),
     mir_opt_level: Option<usize> = (None, parse_opt_number, [TRACKED],
         "MIR optimization level (0-4; default: 1 in non optimized builds and 2 in optimized builds)"),
     move_size_limit: Option<usize> = (None, parse_opt_number, [TRACKED],
         "the size at which the `large_assignments` lint starts to be emitted"),
     mutable_noalias: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "emit noalias metadata for mutable references (default: yes)"),
+    never_type_fallback: Option<NeverTypeFallback> = (None, parse_never_type_fallback, [TRACKED],
+        "override what unconstrained diverging type variables fall back to \
+        (`unit`, `niko`, or `never`; default: `niko` with `#![feature(never_type_fallback)]`, \
+        `unit` otherwise)"),
     new_llvm_pass_manager: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "use new LLVM pass manager (default: no)"),
     nll_facts: bool = (false, parse_bool, [UNTRACKED],
@@ -1315,6 +1554,9 @@ mod parse {
         "prevent automatic injection of the profiler_builtins crate"),
     normalize_docs: bool = (false, parse_bool, [TRACKED],
         "normalize associated items in rustdoc when generating documentation"),
+    obligation_chain: Option<usize> = (None, parse_opt_number, [UNTRACKED],
+        "note the requirements that led to an unsatisfied trait bound as an indented chain, at \
+        most this many levels deep (default: one note per requirement)"),
     osx_rpath_install_name: bool = (false, parse_bool, [TRACKED],
         "pass `-install_name @rpath/...` to the macOS linker (default: no)"),
     panic_abort_tests: bool = (false, parse_bool, [TRACKED],
@@ -1444,6 +1686,9 @@ mod parse {
         "select processor to schedule for (`rustc --print target-cpus` for details)"),
     thinlto: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "enable ThinLTO when possible"),
+    thinlto_import_instr_limit: Option<u32> = (None, parse_opt_number, [TRACKED],
+        "only import functions with at most this many instructions into other modules during \
+        ThinLTO (default: LLVM's `-import-instr-limit`, which is 100)"),
     thir_unsafeck: bool = (false, parse_bool, [TRACKED],
         "use the THIR unsafety checker (default: no)"),
     /// We default to 1 here since we want to behave like
@@ -1462,6 +1707,15 @@ mod parse {
         "choose the TLS model to use (`rustc --print tls-models` for details)"),
     trace_macros: bool = (false, parse_bool, [UNTRACKED],
         "for every macro invocation, print its name and arguments (default: no)"),
+    track_unification_origins: bool = (false, parse_bool, [TRACKED],
+        "record where and why type inference variables are unified, and explain \
+        this history in type annotation errors (default: no)"),
+    translate_additional_ftl: Option<PathBuf> = (None, parse_opt_pathbuf, [UNTRACKED],
+        "additional Fluent resource to translate diagnostics with, which takes precedence over \
+        the translations of the sysroot"),
+    translate_lang: Option<String> = (None, parse_opt_string, [UNTRACKED],
+        "language to translate diagnostics into, with the Fluent resources of the sysroot \
+        (default: no translation)"),
     trap_unreachable: Option<bool> = (None, parse_opt_bool, [TRACKED],
         "generate trap instructions for unreachable intrinsics (default: use target setting, usually yes)"),
     treat_err_as_bug: Option<NonZeroUsize> = (None, parse_treat_err_as_bug, [TRACKED],
@@ -1494,6 +1748,12 @@ mod parse {
         "validate MIR after each transformation"),
     verbose: bool = (false, parse_bool, [UNTRACKED],
         "in general, enable more debug printouts (default: no)"),
+    verbose_type_diffs: bool = (false, parse_bool, [UNTRACKED],
+        "print the expected and found types of type mismatches in full, instead of eliding the \
+        parts they have in common (default: no)"),
+    verify_determinism: bool = (false, parse_bool, [UNTRACKED],
+        "compile the crate a second time and check that both compilations produce identical \
+        outputs (default: no)"),
     verify_llvm_ir: bool = (false, parse_bool, [TRACKED],
         "verify LLVM IR (default: no)"),
     wasi_exec_model: Option<WasiExecModel> = (None, parse_wasi_exec_model, [TRACKED],
@@ -1,7 +1,7 @@
 use crate::cgu_reuse_tracker::CguReuseTracker;
 use crate::code_stats::CodeStats;
 pub use crate::code_stats::{DataTypeKind, FieldInfo, SizeKind, VariantInfo};
-use crate::config::{self, CrateType, OutputType, SwitchWithOptPath};
+use crate::config::{self, BranchProtection, CrateType, OutputType, SwitchWithOptPath};
 use crate::parse::ParseSess;
 use crate::search_paths::{PathKind, SearchPath};
 use crate::{filesearch, lint};
@@ -19,7 +19,9 @@
 use rustc_errors::emitter::{Emitter, EmitterWriter, HumanReadableErrorType};
 use rustc_errors::json::JsonEmitter;
 use rustc_errors::registry::Registry;
-use rustc_errors::{DiagnosticBuilder, DiagnosticId, ErrorReported};
+use rustc_errors::sarif::SarifEmitter;
+use rustc_errors::short_json::ShortJsonEmitter;
+use rustc_errors::{DiagnosticBuilder, DiagnosticId, ErrorReported, FluentBundle};
 use rustc_macros::HashStable_Generic;
 pub use rustc_span::def_id::StableCrateId;
 use rustc_span::edition::Edition;
@@ -116,10 +118,15 @@ pub struct Limits {
     /// The size at which the `large_assignments` lint starts
     /// being emitted.
     pub move_size_limit: Limit,
+    /// The size of generators and futures at which the `large_futures` lint starts being
+    /// emitted.
+    pub future_size_limit: Limit,
     /// The maximum length of types during monomorphization.
     pub type_length_limit: Limit,
     /// The maximum blocks a const expression can evaluate.
     pub const_eval_limit: Limit,
+    /// The maximum number of bytes a const expression can have allocated at once.
+    pub const_eval_memory_limit: Limit,
 }
 
 /// Represents the data associated with a compilation
@@ -196,6 +203,10 @@ pub struct Session {
 
     /// Set of enabled features for the current target.
     pub target_features: FxHashSet<Symbol>,
+
+    /// The translations of diagnostics selected with `-Z translate-lang` and
+    /// `-Z translate-additional-ftl`, if any.
+    translations: Option<FluentBundle>,
 }
 
 pub struct PerfStats {
@@ -399,6 +410,16 @@ pub fn err(&self, msg: &str) {
     pub fn emit_err<'a>(&'a self, err: impl SessionDiagnostic<'a>) {
         err.into_diagnostic(self).emit()
     }
+    /// Returns the translation of the message `slug` of a `SessionDiagnostic`, or of its
+    /// attribute `attr`, if diagnostics are translated and the message has a translation.
+    pub fn translate(
+        &self,
+        slug: &str,
+        attr: Option<&str>,
+        args: &[(&str, String)],
+    ) -> Option<String> {
+        self.translations.as_ref()?.format(slug, attr, args)
+    }
     #[inline]
     pub fn err_count(&self) -> usize {
         self.diagnostic().err_count()
@@ -670,6 +691,9 @@ pub fn is_nightly_build(&self) -> bool {
     pub fn is_sanitizer_cfi_enabled(&self) -> bool {
         self.opts.debugging_opts.sanitizer.contains(SanitizerSet::CFI)
     }
+    pub fn is_sanitizer_kcfi_enabled(&self) -> bool {
+        self.opts.debugging_opts.sanitizer.contains(SanitizerSet::KCFI)
+    }
     pub fn overflow_checks(&self) -> bool {
         self.opts.cg.overflow_checks.unwrap_or(self.opts.debug_assertions)
     }
@@ -719,8 +743,44 @@ pub fn is_wasi_reactor(&self) -> bool {
             )
     }
 
+    /// Returns the split debuginfo mode of the objects of this crate. They are shared by all its
+    /// crate types, so they are split if any of them asks for it.
     pub fn split_debuginfo(&self) -> SplitDebuginfo {
-        self.opts.cg.split_debuginfo.unwrap_or(self.target.split_debuginfo)
+        let requested: Vec<_> = match self.crate_types.get() {
+            Some(crate_types) => crate_types
+                .iter()
+                .map(|&crate_type| self.requested_split_debuginfo(Some(crate_type)))
+                .collect(),
+            None => vec![self.requested_split_debuginfo(None)],
+        };
+        if requested.contains(&SplitDebuginfo::Unpacked) {
+            SplitDebuginfo::Unpacked
+        } else if requested.contains(&SplitDebuginfo::Packed) {
+            SplitDebuginfo::Packed
+        } else {
+            SplitDebuginfo::Off
+        }
+    }
+
+    /// Returns the split debuginfo mode used when linking `crate_type`.
+    pub fn split_debuginfo_for(&self, crate_type: CrateType) -> SplitDebuginfo {
+        match self.requested_split_debuginfo(Some(crate_type)) {
+            // The objects were split for the other crate types, so the debuginfo of this one is
+            // left where they put it.
+            SplitDebuginfo::Off if self.split_debuginfo() != SplitDebuginfo::Off => {
+                SplitDebuginfo::Unpacked
+            }
+            mode => mode,
+        }
+    }
+
+    fn requested_split_debuginfo(&self, crate_type: Option<CrateType>) -> SplitDebuginfo {
+        let config = &self.opts.cg.split_debuginfo;
+        let requested = match crate_type {
+            Some(crate_type) => config.for_crate_type(crate_type),
+            None => config.default,
+        };
+        requested.unwrap_or(self.target.split_debuginfo)
     }
 
     pub fn stack_protector(&self) -> StackProtector {
@@ -1107,7 +1167,7 @@ fn default_emitter(
                         Some(source_map),
                         short,
                         sopts.debugging_opts.teach,
-                        sopts.debugging_opts.terminal_width,
+                        sopts.diagnostic_width,
                         macro_backtrace,
                     ),
                     Some(dst) => EmitterWriter::new(
@@ -1120,7 +1180,12 @@ fn default_emitter(
                         macro_backtrace,
                     ),
                 };
-                Box::new(emitter.ui_testing(sopts.debugging_opts.ui_testing))
+                let group_identical = short && sopts.debugging_opts.group_diagnostics;
+                Box::new(
+                    emitter
+                        .ui_testing(sopts.debugging_opts.ui_testing)
+                        .group_identical(group_identical),
+                )
             }
         }
         (config::ErrorOutputType::Json { pretty, json_rendered }, None) => Box::new(
@@ -1129,7 +1194,7 @@ fn default_emitter(
                 source_map,
                 pretty,
                 json_rendered,
-                sopts.debugging_opts.terminal_width,
+                sopts.diagnostic_width,
                 macro_backtrace,
             )
             .ui_testing(sopts.debugging_opts.ui_testing),
@@ -1141,11 +1206,25 @@ fn default_emitter(
                 source_map,
                 pretty,
                 json_rendered,
-                sopts.debugging_opts.terminal_width,
+                sopts.diagnostic_width,
                 macro_backtrace,
             )
             .ui_testing(sopts.debugging_opts.ui_testing),
         ),
+        (config::ErrorOutputType::Sarif, None) => Box::new(
+            SarifEmitter::stderr(Some(registry), source_map)
+                .ui_testing(sopts.debugging_opts.ui_testing),
+        ),
+        (config::ErrorOutputType::Sarif, Some(dst)) => Box::new(
+            SarifEmitter::new(dst, Some(registry), source_map)
+                .ui_testing(sopts.debugging_opts.ui_testing),
+        ),
+        (config::ErrorOutputType::ShortJson, None) => {
+            Box::new(ShortJsonEmitter::stderr(source_map))
+        }
+        (config::ErrorOutputType::ShortJson, Some(dst)) => {
+            Box::new(ShortJsonEmitter::new(dst, source_map))
+        }
     }
 }
 
@@ -1279,6 +1358,38 @@ pub fn build_session(
         _ => CtfeBacktrace::Disabled,
     });
 
+    let translations = if sopts.debugging_opts.translate_lang.is_some()
+        || sopts.debugging_opts.translate_additional_ftl.is_some()
+    {
+        let locale = sopts.debugging_opts.translate_lang.as_deref().unwrap_or("en-US");
+        let additional_ftl = sopts.debugging_opts.translate_additional_ftl.as_deref();
+        match FluentBundle::load(&sysroot, locale, additional_ftl) {
+            Ok(Some(bundle)) => Some(bundle),
+            Ok(None) => {
+                early_warn(
+                    sopts.error_format,
+                    &format!(
+                        "the sysroot has no translations of diagnostics into `{}`, \
+                        so they are emitted in English",
+                        locale
+                    ),
+                );
+                None
+            }
+            Err(errors) => {
+                for error in &errors {
+                    early_error_no_abort(sopts.error_format, &error.to_string());
+                }
+                early_error(
+                    sopts.error_format,
+                    &format!("failed to load the translations of diagnostics into `{}`", locale),
+                )
+            }
+        }
+    } else {
+        None
+    };
+
     let asm_arch =
         if target_cfg.allow_asm { InlineAsmArch::from_str(&target_cfg.arch).ok() } else { None };
 
@@ -1313,6 +1424,7 @@ pub fn build_session(
         miri_unleashed_features: Lock::new(Default::default()),
         asm_arch,
         target_features: FxHashSet::default(),
+        translations,
     };
 
     validate_commandline_args_with_session_available(&sess);
@@ -1389,8 +1501,11 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
         sess.err(&format!("`-Zsanitizer={}` is incompatible with `-Zsanitizer={}`", first, second));
     }
 
-    // Cannot enable crt-static with sanitizers on Linux
-    if sess.crt_static(None) && !sess.opts.debugging_opts.sanitizer.is_empty() {
+    // Cannot enable crt-static with sanitizers on Linux. KCFI doesn't have a runtime library, so
+    // it's fine with any libc.
+    if sess.crt_static(None)
+        && !(sess.opts.debugging_opts.sanitizer - SanitizerSet::KCFI).is_empty()
+    {
         sess.err(
             "sanitizer is incompatible with statically linked libc, \
                                 disable it using `-C target-feature=-crt-static`",
@@ -1415,6 +1530,35 @@ fn validate_commandline_args_with_session_available(sess: &Session) {
             ))
         }
     }
+
+    if sess.opts.debugging_opts.branch_protection != BranchProtection::default() {
+        if sess.target.arch != "aarch64" {
+            sess.warn(&format!(
+                "`-Z branch-protection` is not supported for target {} and will be ignored",
+                sess.opts.target_triple
+            ))
+        } else if sess.opts.debugging_opts.branch_protection.bti {
+            // The sanitizer runtimes that are linked into the binary aren't built with BTI, so the
+            // linker won't mark the binary as BTI-enforced.
+            let with_runtime = sess.opts.debugging_opts.sanitizer
+                & (SanitizerSet::ADDRESS
+                    | SanitizerSet::LEAK
+                    | SanitizerSet::MEMORY
+                    | SanitizerSet::THREAD
+                    | SanitizerSet::HWADDRESS);
+            if !with_runtime.is_empty() {
+                sess.warn(&format!(
+                    "`-Z branch-protection=bti` is not supported with `-Z sanitizer={}`, whose \
+                    runtime is not built with branch target identification",
+                    with_runtime
+                ))
+            }
+        }
+    }
+
+    if sess.opts.debugging_opts.instrument_xray.is_some() && !sess.target.options.supports_xray {
+        sess.err("XRay instrumentation is not supported for this target");
+    }
 }
 
 /// Holds data on the current incremental compilation session, if there is one.
@@ -1444,6 +1588,8 @@ pub fn early_error_no_abort(output: config::ErrorOutputType, msg: &str) {
         config::ErrorOutputType::Json { pretty, json_rendered } => {
             Box::new(JsonEmitter::basic(pretty, json_rendered, None, false))
         }
+        config::ErrorOutputType::Sarif => Box::new(SarifEmitter::basic()),
+        config::ErrorOutputType::ShortJson => Box::new(ShortJsonEmitter::basic()),
     };
     let handler = rustc_errors::Handler::with_emitter(true, None, emitter);
     handler.struct_fatal(msg).emit();
@@ -1463,6 +1609,8 @@ pub fn early_warn(output: config::ErrorOutputType, msg: &str) {
         config::ErrorOutputType::Json { pretty, json_rendered } => {
             Box::new(JsonEmitter::basic(pretty, json_rendered, None, false))
         }
+        config::ErrorOutputType::Sarif => Box::new(SarifEmitter::basic()),
+        config::ErrorOutputType::ShortJson => Box::new(ShortJsonEmitter::basic()),
     };
     let handler = rustc_errors::Handler::with_emitter(true, None, emitter);
     handler.struct_warn(msg).emit();
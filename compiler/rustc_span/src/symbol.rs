@@ -208,6 +208,7 @@
         LinkedList,
         LintPass,
         Mutex,
+        MutexGuard,
         N,
         None,
         Ok,
@@ -245,6 +246,8 @@
         Right,
         RustcDecodable,
         RustcEncodable,
+        RwLockReadGuard,
+        RwLockWriteGuard,
         Send,
         SeqCst,
         Some,
@@ -323,6 +326,7 @@
         append_const_msg,
         arbitrary_enum_discriminant,
         arbitrary_self_types,
+        arch,
         args,
         arith_offset,
         arm,
@@ -472,6 +476,7 @@
         const_fn,
         const_fn_floating_point_arithmetic,
         const_fn_fn_ptr_basics,
+        const_fn_ptr_calls,
         const_fn_trait_bound,
         const_fn_transmute,
         const_fn_union,
@@ -487,6 +492,7 @@
         const_indexing,
         const_let,
         const_loop,
+        const_make_global,
         const_mut_refs,
         const_panic,
         const_panic_fmt,
@@ -535,6 +541,7 @@
         custom_attribute,
         custom_derive,
         custom_inner_attributes,
+        custom_mir,
         custom_test_frameworks,
         d,
         dbg_macro,
@@ -567,6 +574,7 @@
         derive_default_enum,
         destructuring_assignment,
         diagnostic,
+        dialect,
         direct,
         discriminant_kind,
         discriminant_type,
@@ -612,6 +620,7 @@
         emit_struct,
         emit_struct_field,
         enable,
+        enable_passes,
         enclosing_scope,
         encode,
         end,
@@ -635,6 +644,7 @@
         expf32,
         expf64,
         explicit_generic_args_with_impl_trait,
+        explicit_tail_calls,
         export_name,
         expr,
         extended_key_value_attributes,
@@ -709,6 +719,7 @@
         fsub_fast,
         fundamental,
         future,
+        future_size_limit,
         future_trait,
         ge,
         gen_future,
@@ -753,6 +764,7 @@
         impl_lint_pass,
         impl_macros,
         impl_trait_in_bindings,
+        import_name_type,
         import_shadowing,
         imported_main,
         in_band_lifetimes,
@@ -782,6 +794,14 @@
         intra_doc_pointers,
         intrinsics,
         irrefutable_let_patterns,
+        is_aarch64_feature_detected,
+        is_arm_feature_detected,
+        is_mips64_feature_detected,
+        is_mips_feature_detected,
+        is_powerpc64_feature_detected,
+        is_powerpc_feature_detected,
+        is_riscv_feature_detected,
+        is_x86_feature_detected,
         isa_attribute,
         isize,
         issue,
@@ -791,6 +811,7 @@
         item_like_imports,
         iter,
         iter_repeat,
+        kcfi,
         keyword,
         kind,
         kreg,
@@ -887,6 +908,17 @@
         minnumf32,
         minnumf64,
         mips_target_feature,
+        mir_call,
+        mir_checked,
+        mir_discriminant,
+        mir_drop,
+        mir_goto,
+        mir_move,
+        mir_retag,
+        mir_return,
+        mir_storage_dead,
+        mir_storage_live,
+        mir_unreachable,
         misc,
         mmx_reg,
         modifiers,
@@ -901,6 +933,7 @@
         mul,
         mul_assign,
         mul_with_overflow,
+        multiversion,
         must_not_suspend,
         must_use,
         mut_ptr,
@@ -1012,6 +1045,7 @@
         path,
         pattern_parentheses,
         phantom_data,
+        phase,
         pin,
         pinned,
         platform_intrinsics,
@@ -1262,6 +1296,8 @@
         simd_insert,
         simd_le,
         simd_lt,
+        simd_masked_load,
+        simd_masked_store,
         simd_mul,
         simd_ne,
         simd_neg,
@@ -1290,6 +1326,7 @@
         simd_shr,
         simd_shuffle,
         simd_sub,
+        simd_swizzle_dyn,
         simd_trunc,
         simd_xor,
         since,
@@ -1336,6 +1373,7 @@
         store,
         str,
         str_alloc,
+        strict_provenance,
         stringify,
         stringify_macro,
         struct_field_attributes,
@@ -1410,6 +1448,7 @@
         ty,
         type_alias_enum_variants,
         type_alias_impl_trait,
+        type_ascribe,
         type_ascription,
         type_changing_struct_update,
         type_id,
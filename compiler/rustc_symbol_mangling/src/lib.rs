@@ -109,6 +109,7 @@
 use tracing::debug;
 
 mod legacy;
+mod typeid;
 mod v0;
 
 pub mod test;
@@ -152,7 +153,19 @@ fn symbol_name_provider<'tcx>(tcx: TyCtxt<'tcx>, instance: Instance<'tcx>) -> ty
 
 /// This function computes the typeid for the given function ABI.
 pub fn typeid_for_fnabi<'tcx>(tcx: TyCtxt<'tcx>, fn_abi: &FnAbi<'tcx, Ty<'tcx>>) -> String {
-    v0::mangle_typeid_for_fnabi(tcx, fn_abi)
+    typeid::typeid_for_fnabi(tcx, fn_abi, false)
+}
+
+/// This function computes the typeid used for calls through a vtable. It is the same as the
+/// one computed by `typeid_for_fnabi`, but with the receiver encoded as an untyped pointer, so
+/// that methods of all implementations of a trait can be called through the same check.
+pub fn typeid_for_vtable_fnabi<'tcx>(tcx: TyCtxt<'tcx>, fn_abi: &FnAbi<'tcx, Ty<'tcx>>) -> String {
+    typeid::typeid_for_fnabi(tcx, fn_abi, true)
+}
+
+/// This function computes the type id used by KCFI for the given function ABI.
+pub fn kcfi_typeid_for_fnabi<'tcx>(tcx: TyCtxt<'tcx>, fn_abi: &FnAbi<'tcx, Ty<'tcx>>) -> u32 {
+    typeid::kcfi_typeid_for_fnabi(tcx, fn_abi)
 }
 
 /// Computes the symbol name for the given instance. This function will call
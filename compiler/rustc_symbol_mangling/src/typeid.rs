@@ -0,0 +1,295 @@
+//! Type identifiers for LLVM Control Flow Integrity.
+//!
+//! LLVM uses type metadata to allow IR modules to aggregate pointers by their types.[1] This type
+//! metadata is used by LLVM Control Flow Integrity to test whether a given pointer is associated
+//! with a type identifier (i.e., test type membership).
+//!
+//! Clang uses the Itanium C++ ABI's[2] typeinfo structure names[3] as type metadata identifiers
+//! for function pointers. The typeinfo name encoding is a two-character code (i.e., "TS")
+//! prefixed to the type encoding for the function.
+//!
+//! For cross-language LLVM CFI support, we use the same encoding, with types that have a C
+//! equivalent encoded as that C type: integers are encoded as the C integer type of the same
+//! size on the target (e.g., `i32` as `int` and `u64` as `unsigned long` on LP64 targets),
+//! pointers and references as C pointers, and `#[repr(C)]` types by their name. This makes the
+//! type identifier of an `extern "C" fn(*const u8, usize) -> i32` the one Clang uses for
+//! `int (*)(const unsigned char *, size_t)`. All other types are encoded as Itanium vendor
+//! extended types,[4] named by their v0 mangling, so they can't collide with types from other
+//! languages and still tell apart distinct Rust types.
+//!
+//! [1] https://llvm.org/docs/TypeMetadata.html
+//! [2] https://itanium-cxx-abi.github.io/cxx-abi/abi.html
+//! [3] https://itanium-cxx-abi.github.io/cxx-abi/abi.html#mangling-special-vtables
+//! [4] https://itanium-cxx-abi.github.io/cxx-abi/abi.html#mangling-type
+
+use rustc_data_structures::base_n;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir as hir;
+use rustc_middle::ty::{self, FloatTy, Ty, TyCtxt};
+use rustc_target::abi::call::FnAbi;
+
+/// A part of a type identifier that, once encoded, is referred to by an Itanium substitution
+/// (`S_`, `S0_`, `S1_`, ...) everywhere it appears again.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Component<'tcx> {
+    /// A type that isn't a builtin type.
+    Ty(Ty<'tcx>),
+    /// A `const`-qualified type, i.e., the pointee of a `*const T` or `&T`.
+    Const(Ty<'tcx>),
+    /// The function type a function pointer points to.
+    FnSig(Ty<'tcx>),
+}
+
+struct TypeIdEncoder<'tcx> {
+    tcx: TyCtxt<'tcx>,
+    out: String,
+    /// The components that were encoded so far, with their substitution index.
+    substitutions: FxHashMap<Component<'tcx>, usize>,
+}
+
+/// Computes the type identifier of the function ABI `fn_abi`. If `erase_receiver` is set, its
+/// first argument is encoded as a `void *`, as it is for virtual calls, where the type of the
+/// receiver is unknown.
+pub(super) fn typeid_for_fnabi<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    fn_abi: &FnAbi<'tcx, Ty<'tcx>>,
+    erase_receiver: bool,
+) -> String {
+    let mut encoder =
+        TypeIdEncoder { tcx, out: String::from("_ZTS"), substitutions: FxHashMap::default() };
+    // The variadic arguments of a call aren't part of the type of the callee.
+    let args = if fn_abi.c_variadic { &fn_abi.args[..fn_abi.fixed_count] } else { &fn_abi.args };
+    let mut inputs: Vec<_> = args.iter().map(|arg| arg.layout.ty).collect();
+    if erase_receiver {
+        if let Some(receiver) = inputs.first_mut() {
+            *receiver = tcx.mk_mut_ptr(tcx.mk_unit());
+        }
+    }
+    encoder.encode_fn_sig(&inputs, fn_abi.ret.layout.ty, fn_abi.c_variadic);
+    encoder.out
+}
+
+/// Computes the KCFI type id of the function ABI `fn_abi`: the low 32 bits of the xxHash64 of
+/// its type identifier, which is how Clang computes the type ids of C functions.
+pub(super) fn kcfi_typeid_for_fnabi<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    fn_abi: &FnAbi<'tcx, Ty<'tcx>>,
+) -> u32 {
+    xxhash64(typeid_for_fnabi(tcx, fn_abi, false).as_bytes()) as u32
+}
+
+/// The 64-bit xxHash of `bytes` with a seed of 0, see
+/// <https://github.com/Cyan4973/xxHash/blob/dev/doc/xxhash_spec.md>.
+fn xxhash64(bytes: &[u8]) -> u64 {
+    const P1: u64 = 0x9E3779B185EBCA87;
+    const P2: u64 = 0xC2B2AE3D27D4EB4F;
+    const P3: u64 = 0x165667B19E3779F9;
+    const P4: u64 = 0x85EBCA77C2B2AE63;
+    const P5: u64 = 0x27D4EB2F165667C5;
+
+    fn round(acc: u64, input: u64) -> u64 {
+        acc.wrapping_add(input.wrapping_mul(P2)).rotate_left(31).wrapping_mul(P1)
+    }
+    fn merge_round(acc: u64, val: u64) -> u64 {
+        (acc ^ round(0, val)).wrapping_mul(P1).wrapping_add(P4)
+    }
+    fn read_u64(bytes: &[u8]) -> u64 {
+        u64::from_le_bytes(bytes[..8].try_into().unwrap())
+    }
+
+    let mut rest = bytes;
+    let mut hash = if bytes.len() >= 32 {
+        let mut acc = [P1.wrapping_add(P2), P2, 0, 0u64.wrapping_sub(P1)];
+        while rest.len() >= 32 {
+            for (i, acc) in acc.iter_mut().enumerate() {
+                *acc = round(*acc, read_u64(&rest[i * 8..]));
+            }
+            rest = &rest[32..];
+        }
+        let mut hash = acc[0]
+            .rotate_left(1)
+            .wrapping_add(acc[1].rotate_left(7))
+            .wrapping_add(acc[2].rotate_left(12))
+            .wrapping_add(acc[3].rotate_left(18));
+        for acc in acc {
+            hash = merge_round(hash, acc);
+        }
+        hash
+    } else {
+        P5
+    };
+    hash = hash.wrapping_add(bytes.len() as u64);
+    while rest.len() >= 8 {
+        hash ^= round(0, read_u64(rest));
+        hash = hash.rotate_left(27).wrapping_mul(P1).wrapping_add(P4);
+        rest = &rest[8..];
+    }
+    if rest.len() >= 4 {
+        let word = u32::from_le_bytes(rest[..4].try_into().unwrap());
+        hash ^= u64::from(word).wrapping_mul(P1);
+        hash = hash.rotate_left(23).wrapping_mul(P2).wrapping_add(P3);
+        rest = &rest[4..];
+    }
+    for &byte in rest {
+        hash ^= u64::from(byte).wrapping_mul(P5);
+        hash = hash.rotate_left(11).wrapping_mul(P1);
+    }
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(P2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(P3);
+    hash ^= hash >> 32;
+    hash
+}
+
+impl<'tcx> TypeIdEncoder<'tcx> {
+    fn push(&mut self, s: &str) {
+        self.out.push_str(s);
+    }
+
+    /// Emits the substitution for `component` if it was encoded before, and returns whether it
+    /// was.
+    fn try_substitute(&mut self, component: Component<'tcx>) -> bool {
+        match self.substitutions.get(&component).copied() {
+            Some(0) => self.push("S_"),
+            Some(i) => {
+                let seq_id = base_n::encode((i - 1) as u128, base_n::CASE_INSENSITIVE);
+                self.push(&format!("S{}_", seq_id.to_ascii_uppercase()));
+            }
+            None => return false,
+        }
+        true
+    }
+
+    fn add_substitution(&mut self, component: Component<'tcx>) {
+        let i = self.substitutions.len();
+        self.substitutions.insert(component, i);
+    }
+
+    fn encode_fn_sig(&mut self, inputs: &[Ty<'tcx>], output: Ty<'tcx>, c_variadic: bool) {
+        self.push("F");
+        self.encode_ty(output);
+        if inputs.is_empty() && !c_variadic {
+            self.push("v");
+        }
+        for &input in inputs {
+            self.encode_ty(input);
+        }
+        if c_variadic {
+            self.push("z");
+        }
+        self.push("E");
+    }
+
+    /// Encodes an identifier as an Itanium `<source-name>`.
+    fn encode_source_name(&mut self, name: &str) {
+        self.push(&format!("{}{}", name.len(), name));
+    }
+
+    /// Returns the Itanium encoding of the C integer type with `bits` bits on the target.
+    fn c_integer(&self, bits: u64, signed: bool) -> &'static str {
+        let target = &self.tcx.sess.target;
+        let int_bits = if target.c_int_width == "16" { 16 } else { 32 };
+        let long_bits = if target.pointer_width == 64 && !target.is_like_windows { 64 } else { 32 };
+        let (signed_name, unsigned_name) = match bits {
+            // `signed char` and `unsigned char`
+            8 => ("a", "h"),
+            // `int` and `unsigned int`
+            _ if bits == int_bits => ("i", "j"),
+            // `short` and `unsigned short`
+            16 => ("s", "t"),
+            // `long` and `unsigned long`
+            _ if bits == long_bits => ("l", "m"),
+            // `long long` and `unsigned long long`
+            64 => ("x", "y"),
+            // `__int128` and `unsigned __int128`
+            128 => ("n", "o"),
+            _ => bug!("no C integer type with {} bits", bits),
+        };
+        if signed { signed_name } else { unsigned_name }
+    }
+
+    fn encode_ty(&mut self, ty: Ty<'tcx>) {
+        let pointer_width = u64::from(self.tcx.sess.target.pointer_width);
+        // Builtin types are never substituted.
+        match *ty.kind() {
+            ty::Bool => return self.push("b"),
+            ty::Int(ity) => {
+                let bits = ity.bit_width().unwrap_or(pointer_width);
+                return self.push(self.c_integer(bits, true));
+            }
+            ty::Uint(uty) => {
+                let bits = uty.bit_width().unwrap_or(pointer_width);
+                return self.push(self.c_integer(bits, false));
+            }
+            ty::Float(FloatTy::F32) => return self.push("f"),
+            ty::Float(FloatTy::F64) => return self.push("d"),
+            ty::Tuple(tys) if tys.is_empty() => return self.push("v"),
+            _ => {}
+        }
+
+        if self.try_substitute(Component::Ty(ty)) {
+            return;
+        }
+
+        match *ty.kind() {
+            ty::Ref(_, pointee, mutbl) | ty::RawPtr(ty::TypeAndMut { ty: pointee, mutbl }) => {
+                self.push("P");
+                self.encode_pointee(pointee, mutbl);
+            }
+
+            ty::Array(elem, len) => {
+                let len = len.eval_usize(self.tcx, ty::ParamEnv::reveal_all());
+                self.push(&format!("A{}_", len));
+                self.encode_ty(elem);
+            }
+
+            ty::FnPtr(sig) => {
+                self.push("P");
+                if !self.try_substitute(Component::FnSig(ty)) {
+                    let sig = self.tcx.erase_late_bound_regions(sig);
+                    self.encode_fn_sig(sig.inputs(), sig.output(), sig.c_variadic);
+                    self.add_substitution(Component::FnSig(ty));
+                }
+            }
+
+            // Types that can be shared with C are encoded by their name, like Clang does.
+            ty::Adt(def, substs) if def.repr.c() => {
+                self.encode_source_name(self.tcx.item_name(def.did).as_str());
+                let mut types = substs.types().peekable();
+                if types.peek().is_some() {
+                    self.push("I");
+                    for ty in types {
+                        self.encode_ty(ty);
+                    }
+                    self.push("E");
+                }
+            }
+            ty::Foreign(def_id) => {
+                self.encode_source_name(self.tcx.item_name(def_id).as_str());
+            }
+
+            // Everything else only exists in Rust, and is encoded as a vendor extended type.
+            _ => {
+                let name = super::v0::mangle_type(self.tcx, ty);
+                self.push("u");
+                self.encode_source_name(&name);
+            }
+        }
+
+        self.add_substitution(Component::Ty(ty));
+    }
+
+    fn encode_pointee(&mut self, pointee: Ty<'tcx>, mutbl: hir::Mutability) {
+        match mutbl {
+            hir::Mutability::Mut => self.encode_ty(pointee),
+            hir::Mutability::Not => {
+                if !self.try_substitute(Component::Const(pointee)) {
+                    self.push("K");
+                    self.encode_ty(pointee);
+                    self.add_substitution(Component::Const(pointee));
+                }
+            }
+        }
+    }
+}
@@ -821,7 +821,17 @@ fn evaluate_nested_obligations(
                                 unevaluated,
                                 Some(obligation.cause.span),
                             ) {
-                                Ok(val) => Ok(ty::Const::from_value(select.tcx(), val, c.ty)),
+                                Ok(Some(valtree)) => {
+                                    Ok(ty::Const::from_value(select.tcx(), valtree, c.ty))
+                                }
+                                Ok(None) => {
+                                    let tcx = select.tcx();
+                                    span_bug!(
+                                        tcx.def_span(unevaluated.def.did),
+                                        "unable to construct a valtree for {:?}",
+                                        unevaluated
+                                    )
+                                }
                                 Err(err) => Err(err),
                             }
                         } else {
@@ -8,12 +8,13 @@
 //! In this case we try to build an abstract representation of this constant using
 //! `thir_abstract_const` which can then be checked for structural equality with other
 //! generic constants mentioned in the `caller_bounds` of the current environment.
+use rustc_data_structures::fx::FxHasher;
 use rustc_errors::ErrorReported;
 use rustc_hir::def::DefKind;
 use rustc_index::vec::IndexVec;
 use rustc_infer::infer::InferCtxt;
 use rustc_middle::mir;
-use rustc_middle::mir::interpret::ErrorHandled;
+use rustc_middle::mir::interpret::{ConstValue, ErrorHandled, Scalar};
 use rustc_middle::thir;
 use rustc_middle::thir::abstract_const::{self, Node, NodeId, NotConstEvaluatable};
 use rustc_middle::ty::subst::{Subst, SubstsRef};
@@ -23,7 +24,9 @@
 use rustc_span::Span;
 
 use std::cmp;
+use std::hash::{Hash, Hasher};
 use std::iter;
+use std::mem;
 use std::ops::ControlFlow;
 
 /// Check if a given constant can be evaluated.
@@ -296,6 +299,10 @@ fn visit_pat(&mut self, pat: &thir::Pat<'tcx>) {
             fn visit_const(&mut self, ct: &'tcx ty::Const<'tcx>) {
                 self.is_poly |= ct.has_param_types_or_consts();
             }
+
+            fn visit_constant(&mut self, ct: mir::ConstantKind<'tcx>) {
+                self.is_poly |= ct.has_param_types_or_consts();
+            }
         }
 
         let mut is_poly_vis = IsThirPolymorphic { is_poly: false, thir: body };
@@ -356,7 +363,22 @@ fn recurse_build(&mut self, node: thir::ExprId) -> Result<NodeId, ErrorReported>
 
             // subtle: associated consts are literals this arm handles
             // `<T as Trait>::ASSOC` as well as `12`
-            &ExprKind::Literal { literal, .. } => self.nodes.push(Node::Leaf(literal)),
+            &ExprKind::Literal { ref literal, .. } => {
+                let ct = match **literal {
+                    mir::ConstantKind::Ty(ct) => ct,
+                    // Literals and function items are built as MIR constants, turn them back
+                    // into type level constants.
+                    mir::ConstantKind::Val(val, ty) => {
+                        let valtree = match (val, ty.kind()) {
+                            (_, ty::FnDef(..)) => ty::ValTree::zst(),
+                            (ConstValue::Scalar(Scalar::Int(int)), _) => ty::ValTree::Leaf(int),
+                            _ => self.error(node.span, "unsupported constant in generic constants")?,
+                        };
+                        ty::Const::from_value(self.tcx, valtree, ty)
+                    }
+                };
+                self.nodes.push(Node::Leaf(ct))
+            }
 
             ExprKind::Call { fun, args, .. } => {
                 let fun = self.recurse_build(*fun)?;
@@ -472,10 +494,12 @@ fn recurse_build(&mut self, node: thir::ExprId) -> Result<NodeId, ErrorReported>
             ExprKind::Assign { .. } | ExprKind::AssignOp { .. } => {
                 self.error(node.span, "assignment is not supported in generic constants")?
             }
-            ExprKind::Closure { .. } | ExprKind::Return { .. } => self.error(
-                node.span,
-                "closures and function keywords are not supported in generic constants",
-            )?,
+            ExprKind::Closure { .. } | ExprKind::Return { .. } | ExprKind::Become { .. } => {
+                self.error(
+                    node.span,
+                    "closures and function keywords are not supported in generic constants",
+                )?
+            }
             // let expressions imply control flow
             ExprKind::Match { .. } | ExprKind::If { .. } | ExprKind::Let { .. } =>
                 self.error(node.span, "control flow is not supported in generic constants")?,
@@ -576,6 +600,77 @@ fn recurse<'tcx, R>(
     recurse(tcx, ct, &mut f)
 }
 
+/// Returns the operands of a binary operation. The operands of commutative
+/// operations are put in a canonical order, so that e.g. `N + 1` and `1 + N`
+/// unify without having to try both orders.
+fn binop_operands<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    ct: AbstractConst<'tcx>,
+    op: mir::BinOp,
+    l: NodeId,
+    r: NodeId,
+) -> (NodeId, NodeId) {
+    if op.is_commutative()
+        && commutative_hash(tcx, ct.subtree(r)) < commutative_hash(tcx, ct.subtree(l))
+    {
+        (r, l)
+    } else {
+        (l, r)
+    }
+}
+
+/// Hashes the structure of an abstract constant, in a way that does not depend
+/// on the order of the operands of commutative operations. Leaves are expanded
+/// the same way as in `try_unify`.
+fn commutative_hash<'tcx>(tcx: TyCtxt<'tcx>, mut ct: AbstractConst<'tcx>) -> u64 {
+    while let Node::Leaf(leaf) = ct.root(tcx) {
+        match AbstractConst::from_const(tcx, leaf) {
+            Ok(Some(inner)) => ct = inner,
+            Ok(None) | Err(_) => break,
+        }
+    }
+
+    let mut hasher = FxHasher::default();
+    match ct.root(tcx) {
+        Node::Leaf(leaf) => {
+            0u8.hash(&mut hasher);
+            leaf.hash(&mut hasher);
+        }
+        Node::Binop(op, l, r) => {
+            1u8.hash(&mut hasher);
+            op.hash(&mut hasher);
+            let l = commutative_hash(tcx, ct.subtree(l));
+            let r = commutative_hash(tcx, ct.subtree(r));
+            if op.is_commutative() {
+                cmp::min(l, r).hash(&mut hasher);
+                cmp::max(l, r).hash(&mut hasher);
+            } else {
+                l.hash(&mut hasher);
+                r.hash(&mut hasher);
+            }
+        }
+        Node::UnaryOp(op, v) => {
+            2u8.hash(&mut hasher);
+            op.hash(&mut hasher);
+            commutative_hash(tcx, ct.subtree(v)).hash(&mut hasher);
+        }
+        Node::FunctionCall(func, args) => {
+            3u8.hash(&mut hasher);
+            commutative_hash(tcx, ct.subtree(func)).hash(&mut hasher);
+            for &arg in args {
+                commutative_hash(tcx, ct.subtree(arg)).hash(&mut hasher);
+            }
+        }
+        Node::Cast(kind, operand, ty) => {
+            4u8.hash(&mut hasher);
+            mem::discriminant(&kind).hash(&mut hasher);
+            ty.hash(&mut hasher);
+            commutative_hash(tcx, ct.subtree(operand)).hash(&mut hasher);
+        }
+    }
+    hasher.finish()
+}
+
 /// Tries to unify two abstract constants using structural equality.
 pub(super) fn try_unify<'tcx>(
     tcx: TyCtxt<'tcx>,
@@ -635,6 +730,8 @@ pub(super) fn try_unify<'tcx>(
             }
         }
         (Node::Binop(a_op, al, ar), Node::Binop(b_op, bl, br)) if a_op == b_op => {
+            let (al, ar) = binop_operands(tcx, a, a_op, al, ar);
+            let (bl, br) = binop_operands(tcx, b, b_op, bl, br);
             try_unify(tcx, a.subtree(al), b.subtree(bl))
                 && try_unify(tcx, a.subtree(ar), b.subtree(br))
         }
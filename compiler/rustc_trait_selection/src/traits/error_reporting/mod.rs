@@ -1,3 +1,4 @@
+mod obligation_chain;
 pub mod on_unimplemented;
 pub mod suggestions;
 
@@ -36,7 +37,7 @@
 use crate::traits::query::normalize::AtExt as _;
 use crate::traits::specialize::to_pretty_impl_header;
 use on_unimplemented::InferCtxtExt as _;
-use suggestions::InferCtxtExt as _;
+use suggestions::{predicate_constraint, InferCtxtExt as _};
 
 pub use rustc_infer::traits::error_reporting::*;
 
@@ -169,9 +170,18 @@ struct ErrorDescriptor<'tcx> {
         }
 
         for (error, suppressed) in iter::zip(errors, is_suppressed) {
-            if !suppressed {
-                self.report_fulfillment_error(error, body_id, fallback_has_occurred);
+            if suppressed {
+                continue;
             }
+            if self.is_derived_from_reported_error(error) {
+                // Reporting the error would bury the one it is derived from. Taint the inference
+                // context to also suppress the errors about what couldn't be inferred because of
+                // it.
+                debug!("skipping {:?} (derived from a reported error)", error);
+                self.set_tainted_by_errors();
+                continue;
+            }
+            self.report_fulfillment_error(error, body_id, fallback_has_occurred);
         }
     }
 
@@ -190,6 +200,7 @@ fn report_overflow_error<T>(
         T: fmt::Display + TypeFoldable<'tcx>,
     {
         let predicate = self.resolve_vars_if_possible(obligation.predicate.clone());
+        let (predicate, predicate_file) = self.tcx.short_string(predicate);
         let mut err = struct_span_err!(
             self.tcx.sess,
             obligation.cause.span,
@@ -197,6 +208,7 @@ fn report_overflow_error<T>(
             "overflow evaluating the requirement `{}`",
             predicate
         );
+        err.note_long_type(predicate_file.as_deref());
 
         if suggest_increasing_limit {
             self.suggest_new_overflow_limit(&mut err);
@@ -338,6 +350,7 @@ fn report_selection_error(
                             (message, note, append_const_msg)
                         };
 
+                        let mut predicate_file = None;
                         let mut err = struct_span_err!(
                             self.tcx.sess,
                             span,
@@ -360,11 +373,21 @@ fn report_selection_error(
                                         (true, None) => None,
                                     }
                                 })
-                                .unwrap_or_else(|| format!(
-                                    "the trait bound `{}` is not satisfied{}",
-                                    trait_predicate, post_message,
-                                ))
+                                .unwrap_or_else(|| {
+                                    let (predicate, file) = self.tcx.short_string(trait_predicate);
+                                    predicate_file = file;
+                                    format!(
+                                        "the trait bound `{}` is not satisfied{}",
+                                        predicate, post_message,
+                                    )
+                                })
                         );
+                        let (self_ty, self_ty_file) =
+                            self.tcx.short_string(trait_ref.skip_binder().self_ty());
+                        err.note_long_type(predicate_file.as_deref());
+                        if self_ty_file != predicate_file {
+                            err.note_long_type(self_ty_file.as_deref());
+                        }
 
                         if is_try_conversion {
                             let none_error = self
@@ -412,7 +435,7 @@ fn report_selection_error(
                                 "{}the trait `{}` is not implemented for `{}`",
                                 pre_message,
                                 trait_predicate.print_modifiers_and_trait_path(),
-                                trait_ref.skip_binder().self_ty(),
+                                self_ty,
                             )
                         };
 
@@ -889,12 +912,32 @@ fn report_selection_error(
                             self.tcx.sess.struct_span_err(span, "unconstrained generic constant");
                         let const_span = self.tcx.def_span(uv.def.did);
                         match self.tcx.sess.source_map().span_to_snippet(const_span) {
-                            Ok(snippet) => err.help(&format!(
-                                "try adding a `where` bound using this expression: `where [(); {}]:`",
-                                snippet
-                            )),
-                            _ => err.help("consider adding a `where` bound using this expression"),
-                        };
+                            Ok(snippet) => {
+                                err.help(&format!(
+                                    "try adding a `where` bound using this expression: `where [(); {}]:`",
+                                    snippet
+                                ));
+                                // Also let tools insert the bound. The expression is written in
+                                // terms of the generics of its definition, which may not be the
+                                // generics of the item we're suggesting to add the bound to.
+                                let owner = obligation.cause.body_id.owner;
+                                if let Some(generics) = self.tcx.hir().get_generics(owner) {
+                                    let (span, bound) = predicate_constraint(
+                                        generics,
+                                        format!("[(); {}]:", snippet),
+                                    );
+                                    err.tool_only_span_suggestion(
+                                        span,
+                                        "add a `where` bound using this expression",
+                                        bound,
+                                        Applicability::MaybeIncorrect,
+                                    );
+                                }
+                            }
+                            _ => {
+                                err.help("consider adding a `where` bound using this expression");
+                            }
+                        }
                         err
                     }
                     _ => {
@@ -1134,6 +1177,10 @@ trait InferCtxtPrivExt<'hir, 'tcx> {
     // `error` occurring implies that `cond` occurs.
     fn error_implies(&self, cond: ty::Predicate<'tcx>, error: ty::Predicate<'tcx>) -> bool;
 
+    /// Returns whether `error` only occurs because of a type, or const, that an error was already
+    /// reported for, like the type of a path that failed to resolve.
+    fn is_derived_from_reported_error(&self, error: &FulfillmentError<'tcx>) -> bool;
+
     fn report_fulfillment_error(
         &self,
         error: &FulfillmentError<'tcx>,
@@ -1279,6 +1326,27 @@ fn error_implies(&self, cond: ty::Predicate<'tcx>, error: ty::Predicate<'tcx>) -
         false
     }
 
+    fn is_derived_from_reported_error(&self, error: &FulfillmentError<'tcx>) -> bool {
+        if !self.tcx.sess.has_errors() && !self.is_tainted_by_errors() {
+            return false;
+        }
+        let code_references_error = match error.code {
+            FulfillmentErrorCode::CodeSubtypeError(expected_found, _) => {
+                self.resolve_vars_if_possible(expected_found).references_error()
+            }
+            FulfillmentErrorCode::CodeConstEquateError(expected_found, _) => {
+                self.resolve_vars_if_possible(expected_found).references_error()
+            }
+            FulfillmentErrorCode::CodeSelectionError(_)
+            | FulfillmentErrorCode::CodeProjectionError(_)
+            | FulfillmentErrorCode::CodeAmbiguity => false,
+        };
+        // Only the obligation's own predicate is checked: a root obligation that references an
+        // error may still be derived into an obligation that fails on its own.
+        code_references_error
+            || self.resolve_vars_if_possible(error.obligation.predicate).references_error()
+    }
+
     #[instrument(skip(self), level = "debug")]
     fn report_fulfillment_error(
         &self,
@@ -0,0 +1,73 @@
+//! Rendering of the requirements that led to an unsatisfied obligation as an indented chain, for
+//! `-Z obligation-chain`.
+
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::pluralize;
+
+/// The requirements of an obligation, each one nested below the one that introduced it.
+pub struct ObligationChain {
+    max_depth: usize,
+    requirements: Vec<String>,
+    seen: FxHashSet<String>,
+    /// The number of requirements that were already in the chain, which happens for recursive
+    /// types.
+    redundant: usize,
+    /// The number of requirements that are deeper than `max_depth`.
+    truncated: usize,
+}
+
+impl ObligationChain {
+    pub fn new(max_depth: usize) -> Self {
+        ObligationChain {
+            max_depth: max_depth.max(1),
+            requirements: vec![],
+            seen: FxHashSet::default(),
+            redundant: 0,
+            truncated: 0,
+        }
+    }
+
+    /// Adds `requirement` one level below the last requirement that was added.
+    pub fn push(&mut self, requirement: String) {
+        if self.seen.contains(&requirement) {
+            self.redundant += 1;
+        } else if self.requirements.len() == self.max_depth {
+            self.truncated += 1;
+        } else {
+            self.seen.insert(requirement.clone());
+            self.requirements.push(requirement);
+        }
+    }
+
+    /// Renders the chain with one requirement per line, or returns `None` if there is no
+    /// requirement to show.
+    pub fn render(&self) -> Option<String> {
+        if self.requirements.is_empty() {
+            return None;
+        }
+        let mut lines: Vec<_> = self
+            .requirements
+            .iter()
+            .enumerate()
+            .map(|(depth, requirement)| format!("{}{}", "  ".repeat(depth), requirement))
+            .collect();
+        let indent = "  ".repeat(self.requirements.len());
+        if self.truncated > 0 {
+            lines.push(format!(
+                "{}... and {} more requirement{}",
+                indent,
+                self.truncated,
+                pluralize!(self.truncated),
+            ));
+        }
+        if self.redundant > 0 {
+            lines.push(format!(
+                "{}({} redundant requirement{} hidden)",
+                indent,
+                self.redundant,
+                pluralize!(self.redundant),
+            ));
+        }
+        Some(lines.join("\n"))
+    }
+}
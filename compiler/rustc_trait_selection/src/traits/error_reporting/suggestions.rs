@@ -1,3 +1,4 @@
+use super::obligation_chain::ObligationChain;
 use super::{
     EvaluationResult, Obligation, ObligationCause, ObligationCauseCode, PredicateObligation,
     SelectionContext,
@@ -172,6 +173,19 @@ fn note_obligation_cause_code<T>(
     ) where
         T: fmt::Display;
 
+    /// Notes the chain of derived obligations that starts at `cause_code` in a single indented
+    /// note, then the cause that introduced the first of them. Used with `-Z obligation-chain`
+    /// instead of one note per derived obligation.
+    fn note_obligation_chain(
+        &self,
+        err: &mut DiagnosticBuilder<'_>,
+        param_env: ty::ParamEnv<'tcx>,
+        cause_code: &ObligationCauseCode<'tcx>,
+        max_depth: usize,
+        obligated_types: &mut Vec<&ty::TyS<'tcx>>,
+        seen_requirements: &mut FxHashSet<DefId>,
+    );
+
     fn suggest_new_overflow_limit(&self, err: &mut DiagnosticBuilder<'_>);
 
     /// Suggest to await before try: future? => future.await?
@@ -184,7 +198,7 @@ fn suggest_await_before_try(
     );
 }
 
-fn predicate_constraint(generics: &hir::Generics<'_>, pred: String) -> (Span, String) {
+pub(super) fn predicate_constraint(generics: &hir::Generics<'_>, pred: String) -> (Span, String) {
     (
         generics.where_clause.tail_span_for_suggestion(),
         format!(
@@ -1715,6 +1729,32 @@ fn note_obligation_cause_for_async_await(
             format!("does not implement `{}`", trait_pred.print_modifiers_and_trait_path())
         };
 
+        // The type held across the yield can contain the one that doesn't implement the trait,
+        // which is then the one the original obligation is about.
+        //     has type `Bar` which is not `Send`, because `Foo` is not `Send`
+        let contained_explanation = match obligation.predicate.kind().skip_binder() {
+            ty::PredicateKind::Trait(inner_pred) => {
+                let inner_ty = self.resolve_vars_if_possible(inner_pred.self_ty());
+                let outer_ty = self.resolve_vars_if_possible(target_ty);
+                if self.tcx.erase_regions(inner_ty) != self.tcx.erase_regions(outer_ty)
+                    && !inner_ty.has_escaping_bound_vars()
+                {
+                    let inner_explanation = match self.tcx.get_diagnostic_name(inner_pred.def_id())
+                    {
+                        Some(name @ (sym::Send | sym::Sync)) => format!("is not `{}`", name),
+                        _ => format!(
+                            "does not implement `{}`",
+                            inner_pred.print_modifiers_and_trait_path()
+                        ),
+                    };
+                    format!(", because `{}` {}", inner_ty, inner_explanation)
+                } else {
+                    String::new()
+                }
+            }
+            _ => String::new(),
+        };
+
         let mut explain_yield = |interior_span: Span,
                                  yield_span: Span,
                                  scope_span: Option<Span>| {
@@ -1746,7 +1786,10 @@ fn note_obligation_cause_for_async_await(
                 );
                 span.push_span_label(
                     interior_span,
-                    format!("has type `{}` which {}", target_ty, trait_explanation),
+                    format!(
+                        "has type `{}` which {}{}",
+                        target_ty, trait_explanation, contained_explanation
+                    ),
                 );
                 // If available, use the scope span to annotate the drop location.
                 let mut scope_note = None;
@@ -1912,6 +1955,21 @@ fn note_obligation_cause_code<T>(
         T: fmt::Display,
     {
         let tcx = self.tcx;
+        if let Some(max_depth) = tcx.sess.opts.debugging_opts.obligation_chain {
+            if let ObligationCauseCode::BuiltinDerivedObligation(_)
+            | ObligationCauseCode::ImplDerivedObligation(_)
+            | ObligationCauseCode::DerivedObligation(_) = cause_code
+            {
+                return self.note_obligation_chain(
+                    err,
+                    param_env,
+                    cause_code,
+                    max_depth,
+                    obligated_types,
+                    seen_requirements,
+                );
+            }
+        }
         match *cause_code {
             ObligationCauseCode::ExprAssignable
             | ObligationCauseCode::MatchExpressionArm { .. }
@@ -2417,6 +2475,78 @@ fn note_obligation_cause_code<T>(
         }
     }
 
+    fn note_obligation_chain(
+        &self,
+        err: &mut DiagnosticBuilder<'_>,
+        param_env: ty::ParamEnv<'tcx>,
+        cause_code: &ObligationCauseCode<'tcx>,
+        max_depth: usize,
+        obligated_types: &mut Vec<&ty::TyS<'tcx>>,
+        seen_requirements: &mut FxHashSet<DefId>,
+    ) {
+        let mut chain = ObligationChain::new(max_depth);
+        let mut code = cause_code;
+        let mut parent_predicate = None;
+        loop {
+            let data = match code {
+                ObligationCauseCode::BuiltinDerivedObligation(data) => {
+                    let parent_trait_ref = self.resolve_vars_if_possible(data.parent_trait_pred);
+                    if parent_trait_ref.references_error() {
+                        err.cancel();
+                        return;
+                    }
+                    // Like in `note_obligation_cause_code`, don't show the tuple of the captures
+                    // of a generator or closure.
+                    let ty = parent_trait_ref.skip_binder().self_ty();
+                    let is_upvar_tys_tuple = matches!(ty.kind(), ty::Tuple(..))
+                        && match &*data.parent_code {
+                            ObligationCauseCode::BuiltinDerivedObligation(data) => {
+                                let parent_trait_ref =
+                                    self.resolve_vars_if_possible(data.parent_trait_pred);
+                                let ty = parent_trait_ref.skip_binder().self_ty();
+                                matches!(ty.kind(), ty::Generator(..) | ty::Closure(..))
+                            }
+                            _ => false,
+                        };
+                    if !is_upvar_tys_tuple {
+                        chain.push(format!("required because it appears within the type `{}`", ty));
+                    }
+                    data
+                }
+                ObligationCauseCode::ImplDerivedObligation(data) => {
+                    let mut parent_trait_pred =
+                        self.resolve_vars_if_possible(data.parent_trait_pred);
+                    parent_trait_pred.remap_constness_diag(param_env);
+                    chain.push(format!(
+                        "required because of the requirements on the impl of `{}` for `{}`",
+                        parent_trait_pred.print_modifiers_and_trait_path(),
+                        parent_trait_pred.skip_binder().self_ty()
+                    ));
+                    data
+                }
+                ObligationCauseCode::DerivedObligation(data) => data,
+                _ => break,
+            };
+            let parent_trait_pred = self.resolve_vars_if_possible(data.parent_trait_pred);
+            parent_predicate = Some(parent_trait_pred.to_predicate(self.tcx));
+            code = &*data.parent_code;
+        }
+
+        if let Some(chain) = chain.render() {
+            err.note(&chain);
+        }
+        if let Some(parent_predicate) = parent_predicate {
+            self.note_obligation_cause_code(
+                err,
+                &parent_predicate,
+                param_env,
+                code,
+                obligated_types,
+                seen_requirements,
+            );
+        }
+    }
+
     fn suggest_new_overflow_limit(&self, err: &mut DiagnosticBuilder<'_>) {
         let suggested_limit = match self.tcx.recursion_limit() {
             Limit(0) => Limit(2),
@@ -582,7 +582,17 @@ fn progress_changed_obligations(
                                 unevaluated,
                                 Some(obligation.cause.span),
                             ) {
-                                Ok(val) => Ok(Const::from_value(self.selcx.tcx(), val, c.ty)),
+                                Ok(Some(valtree)) => {
+                                    Ok(Const::from_value(self.selcx.tcx(), valtree, c.ty))
+                                }
+                                Ok(None) => {
+                                    let tcx = self.selcx.tcx();
+                                    span_bug!(
+                                        tcx.def_span(unevaluated.def.did),
+                                        "unable to construct a valtree for {:?}",
+                                        unevaluated
+                                    )
+                                }
                                 Err(ErrorHandled::TooGeneric) => {
                                     stalled_on.extend(
                                         unevaluated
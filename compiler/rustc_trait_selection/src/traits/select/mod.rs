@@ -654,13 +654,21 @@ fn evaluate_predicate_recursively<'o>(
 
                     let evaluate = |c: &'tcx ty::Const<'tcx>| {
                         if let ty::ConstKind::Unevaluated(unevaluated) = c.val {
-                            self.infcx
-                                .const_eval_resolve(
-                                    obligation.param_env,
-                                    unevaluated,
-                                    Some(obligation.cause.span),
-                                )
-                                .map(|val| ty::Const::from_value(self.tcx(), val, c.ty))
+                            match self.infcx.const_eval_resolve(
+                                obligation.param_env,
+                                unevaluated,
+                                Some(obligation.cause.span),
+                            ) {
+                                Ok(Some(valtree)) => {
+                                    Ok(ty::Const::from_value(self.tcx(), valtree, c.ty))
+                                }
+                                Ok(None) => span_bug!(
+                                    self.tcx().def_span(unevaluated.def.did),
+                                    "unable to construct a valtree for {:?}",
+                                    unevaluated
+                                ),
+                                Err(err) => Err(err),
+                            }
                         } else {
                             Ok(c)
                         }
@@ -980,7 +988,8 @@ fn evaluate_stack<'o>(
         }
     }
 
-    /// For defaulted traits, we use a co-inductive strategy to solve, so
+    /// For defaulted traits (and, with `-Z coinductive-traits`, for all
+    /// traits), we use a co-inductive strategy to solve, so
     /// that recursion is ok. This routine returns `true` if the top of the
     /// stack (`cycle[0]`):
     ///
@@ -997,7 +1006,14 @@ pub fn coinductive_match<I>(&mut self, mut cycle: I) -> bool
 
     fn coinductive_predicate(&self, predicate: ty::Predicate<'tcx>) -> bool {
         let result = match predicate.kind().skip_binder() {
-            ty::PredicateKind::Trait(ref data) => self.tcx().trait_is_auto(data.def_id()),
+            ty::PredicateKind::Trait(ref data) => {
+                // With `-Z coinductive-traits`, all trait goals are coinductive.
+                // This lets cycles such as `A: Trait<B>` requiring `B: Trait<A>`
+                // hold, but is unsound in general: e.g. `impl<T: Foo> Foo for T`
+                // would then prove `T: Foo` for every `T`.
+                self.tcx().trait_is_auto(data.def_id())
+                    || self.tcx().sess.opts.debugging_opts.coinductive_traits
+            }
             _ => false,
         };
         debug!(?predicate, ?result, "coinductive_predicate");
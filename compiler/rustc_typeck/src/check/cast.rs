@@ -32,6 +32,7 @@
 
 use crate::hir::def_id::DefId;
 use crate::type_error_struct;
+use rustc_ast::util::parser::PREC_POSTFIX;
 use rustc_errors::{struct_span_err, Applicability, DiagnosticBuilder, ErrorReported};
 use rustc_hir as hir;
 use rustc_hir::lang_items::LangItem;
@@ -769,11 +770,21 @@ pub fn do_check(&self, fcx: &FnCtxt<'a, 'tcx>) -> Result<CastKind, CastError> {
 
             // ptr -> *
             (Ptr(m_e), Ptr(m_c)) => self.check_ptr_ptr_cast(fcx, m_e, m_c), // ptr-ptr-cast
-            (Ptr(m_expr), Int(_)) => self.check_ptr_addr_cast(fcx, m_expr), // ptr-addr-cast
+            (Ptr(m_expr), Int(_)) => {
+                // ptr-addr-cast
+                let kind = self.check_ptr_addr_cast(fcx, m_expr)?;
+                self.lossy_provenance_ptr2int_lint(fcx);
+                Ok(kind)
+            }
             (FnPtr, Int(_)) => Ok(CastKind::FnPtrAddrCast),
 
             // * -> ptr
-            (Int(_), Ptr(mt)) => self.check_addr_ptr_cast(fcx, mt), // addr-ptr-cast
+            (Int(_), Ptr(mt)) => {
+                // addr-ptr-cast
+                let kind = self.check_addr_ptr_cast(fcx, mt)?;
+                self.fuzzy_provenance_int2ptr_lint(fcx);
+                Ok(kind)
+            }
             (FnPtr, Ptr(mt)) => self.check_fptr_ptr_cast(fcx, mt),
 
             // prim -> prim
@@ -913,6 +924,82 @@ fn try_coercion_cast(&self, fcx: &FnCtxt<'a, 'tcx>) -> Result<(), ty::error::Typ
         }
     }
 
+    /// The source of the cast expression, as the receiver of a method call.
+    fn receiver_snippet(&self, fcx: &FnCtxt<'a, 'tcx>) -> Option<String> {
+        let snippet = fcx.tcx.sess.source_map().span_to_snippet(self.expr.span).ok()?;
+        if self.expr.precedence().order() < PREC_POSTFIX {
+            Some(format!("({})", snippet))
+        } else {
+            Some(snippet)
+        }
+    }
+
+    fn lossy_provenance_ptr2int_lint(&self, fcx: &FnCtxt<'a, 'tcx>) {
+        fcx.tcx.struct_span_lint_hir(
+            lint::builtin::LOSSY_PROVENANCE_CASTS,
+            self.expr.hir_id,
+            self.span,
+            |err| {
+                let mut err = err.build(&format!(
+                    "under strict provenance it is considered bad style to cast pointer `{}` to \
+                     integer `{}`",
+                    self.expr_ty, self.cast_ty
+                ));
+                let msg = "use `.addr()` to obtain the address of a pointer";
+                if let Some(receiver) = self.receiver_snippet(fcx) {
+                    let int_cast = match self.cast_ty.kind() {
+                        ty::Uint(ty::UintTy::Usize) => String::new(),
+                        _ => format!(" as {}", self.cast_ty),
+                    };
+                    err.span_suggestion(
+                        self.span,
+                        msg,
+                        format!("{}.addr(){}", receiver, int_cast),
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    err.help(msg);
+                }
+                err.help(
+                    "if you can't comply with strict provenance and need to expose the provenance \
+                     of the pointer, you can use `.expose_addr()` instead",
+                );
+                err.emit();
+            },
+        );
+    }
+
+    fn fuzzy_provenance_int2ptr_lint(&self, fcx: &FnCtxt<'a, 'tcx>) {
+        fcx.tcx.struct_span_lint_hir(
+            lint::builtin::FUZZY_PROVENANCE_CASTS,
+            self.expr.hir_id,
+            self.span,
+            |err| {
+                let mut err = err.build(&format!(
+                    "strict provenance disallows casting integer `{}` to pointer `{}`",
+                    self.expr_ty, self.cast_ty
+                ));
+                let msg = "use `.with_addr()` to adjust a valid pointer in the same allocation to \
+                           this address";
+                if let Ok(snippet) = fcx.tcx.sess.source_map().span_to_snippet(self.expr.span) {
+                    err.span_suggestion(
+                        self.span,
+                        msg,
+                        format!("(...).with_addr({})", snippet),
+                        Applicability::HasPlaceholders,
+                    );
+                } else {
+                    err.help(msg);
+                }
+                err.help(
+                    "if you can't comply with strict provenance and don't have a pointer with the \
+                     correct provenance, you can use `std::ptr::from_exposed_addr()` instead",
+                );
+                err.emit();
+            },
+        );
+    }
+
     fn cenum_impl_drop_lint(&self, fcx: &FnCtxt<'a, 'tcx>) {
         if let ty::Adt(d, _) = self.expr_ty.kind() {
             if d.has_dtor(fcx.tcx) {
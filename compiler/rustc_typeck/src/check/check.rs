@@ -2,6 +2,7 @@
 use super::compare_method::check_type_bounds;
 use super::compare_method::{compare_const_impl, compare_impl_method, compare_ty_impl};
 use super::*;
+use crate::errors::{ConflictingPackedAndAlignHints, SimdVectorEmpty, SimdVectorNotHomogeneous};
 
 use rustc_attr as attr;
 use rustc_errors::{Applicability, ErrorReported};
@@ -1029,14 +1030,12 @@ pub fn check_simd(tcx: TyCtxt<'_>, sp: Span, def_id: LocalDefId) {
         if def.is_struct() {
             let fields = &def.non_enum_variant().fields;
             if fields.is_empty() {
-                struct_span_err!(tcx.sess, sp, E0075, "SIMD vector cannot be empty").emit();
+                tcx.sess.emit_err(SimdVectorEmpty { span: sp });
                 return;
             }
             let e = fields[0].ty(tcx, substs);
             if !fields.iter().all(|f| f.ty(tcx, substs) == e) {
-                struct_span_err!(tcx.sess, sp, E0076, "SIMD vector should be homogeneous")
-                    .span_label(sp, "SIMD elements must have the same type")
-                    .emit();
+                tcx.sess.emit_err(SimdVectorNotHomogeneous { span: sp });
                 return;
             }
 
@@ -1047,7 +1046,7 @@ pub fn check_simd(tcx: TyCtxt<'_>, sp: Span, def_id: LocalDefId) {
             };
             if let Some(len) = len {
                 if len == 0 {
-                    struct_span_err!(tcx.sess, sp, E0075, "SIMD vector cannot be empty").emit();
+                    tcx.sess.emit_err(SimdVectorEmpty { span: sp });
                     return;
                 } else if len > MAX_SIMD_LANES {
                     struct_span_err!(
@@ -1113,13 +1112,7 @@ pub(super) fn check_packed(tcx: TyCtxt<'_>, sp: Span, def: &ty::AdtDef) {
             }
         }
         if repr.align.is_some() {
-            struct_span_err!(
-                tcx.sess,
-                sp,
-                E0587,
-                "type has conflicting packed and align representation hints"
-            )
-            .emit();
+            tcx.sess.emit_err(ConflictingPackedAndAlignHints { span: sp });
         } else {
             if let Some(def_spans) = check_packed_inner(tcx, def.did, &mut vec![]) {
                 let mut err = struct_span_err!(
@@ -1151,9 +1151,7 @@ fn try_find_coercion_lub<E>(
                     expr,
                 );
 
-                return self
-                    .commit_if_ok(|_| self.at(cause, self.param_env).lub(prev_ty, new_ty))
-                    .map(|ok| self.register_infer_ok_obligations(ok));
+                return self.lub_with_coercions(cause, exprs, prev_ty, new, new_ty);
             }
         }
 
@@ -1163,8 +1161,7 @@ fn try_find_coercion_lub<E>(
                 if let Some(e) = first_error {
                     Err(e)
                 } else {
-                    self.commit_if_ok(|_| self.at(cause, self.param_env).lub(prev_ty, new_ty))
-                        .map(|ok| self.register_infer_ok_obligations(ok))
+                    self.lub_with_coercions(cause, exprs, prev_ty, new, new_ty)
                 }
             }
             Ok(ok) => {
@@ -1181,6 +1178,67 @@ fn try_find_coercion_lub<E>(
             }
         }
     }
+
+    /// Computes the LUB of `prev_ty` and `new_ty`.
+    ///
+    /// The LUB computed by `super_lattice_tys` is not always a supertype of
+    /// both sides: the LUB of `fn()` and `unsafe fn()` is `unsafe fn()`, and
+    /// with `#![feature(trait_upcasting)]` the LUB of two trait objects may
+    /// be the trait object of a supertrait. In those cases we additionally
+    /// apply a coercion to the expressions whose type differs from the LUB,
+    /// on top of any adjustments they already have.
+    fn lub_with_coercions<E>(
+        &self,
+        cause: &ObligationCause<'tcx>,
+        exprs: &[E],
+        prev_ty: Ty<'tcx>,
+        new: &hir::Expr<'_>,
+        new_ty: Ty<'tcx>,
+    ) -> RelateResult<'tcx, Ty<'tcx>>
+    where
+        E: AsCoercionSite,
+    {
+        self.commit_if_ok(|_| {
+            let target = self
+                .at(cause, self.param_env)
+                .lub(prev_ty, new_ty)
+                .map(|ok| self.register_infer_ok_obligations(ok))?;
+            // Returns the adjustments needed to coerce `source` to the LUB, if any.
+            let coerce_to_target = |source: Ty<'tcx>| -> RelateResult<'tcx, Vec<Adjustment<'tcx>>> {
+                if self.can_eq(self.param_env, source, target).is_ok() {
+                    return Ok(vec![]);
+                }
+                let coerce = Coerce::new(self, cause.clone(), AllowTwoPhase::No);
+                let (adjustments, _) =
+                    self.register_infer_ok_obligations(coerce.coerce(source, target)?);
+                debug!("lub_with_coercions: {:?} -> {:?}: {:?}", source, target, adjustments);
+                Ok(adjustments)
+            };
+            let append_adjustments = |expr: &hir::Expr<'_>, adjustments: &[Adjustment<'tcx>]| {
+                if adjustments.is_empty() {
+                    return;
+                }
+                let mut typeck_results = self.typeck_results.borrow_mut();
+                let mut adjustments_table = typeck_results.adjustments_mut();
+                let existing = adjustments_table.entry(expr.hir_id).or_default();
+                // Expressions of type `!` are already coerced to whatever type we need.
+                if let &[Adjustment { kind: Adjust::NeverToAny, .. }] = &existing[..] {
+                    return;
+                }
+                existing.extend(adjustments.iter().cloned());
+            };
+
+            // Only touch the adjustments table once both sides are known to
+            // coerce, so that nothing is left behind if the second one fails.
+            let prev_adjustments = coerce_to_target(prev_ty)?;
+            let new_adjustments = coerce_to_target(new_ty)?;
+            for expr in exprs {
+                append_adjustments(expr.as_coercion_site(), &prev_adjustments);
+            }
+            append_adjustments(new, &new_adjustments);
+            Ok(target)
+        })
+    }
 }
 
 /// CoerceMany encapsulates the pattern you should use when you have
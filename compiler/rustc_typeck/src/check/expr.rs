@@ -35,11 +35,12 @@
 use rustc_infer::infer;
 use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
 use rustc_infer::infer::InferOk;
+use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrFlags;
 use rustc_middle::middle::stability;
 use rustc_middle::ty::adjustment::{Adjust, Adjustment, AllowTwoPhase};
 use rustc_middle::ty::error::ExpectedFound;
 use rustc_middle::ty::error::TypeError::{FieldMisMatch, Sorts};
-use rustc_middle::ty::subst::SubstsRef;
+use rustc_middle::ty::subst::{Subst, SubstsRef};
 use rustc_middle::ty::{self, AdtKind, Ty, TypeFoldable};
 use rustc_session::parse::feature_err;
 use rustc_span::edition::LATEST_STABLE_EDITION;
@@ -295,6 +296,7 @@ fn check_expr_kind(
                 }
             }
             ExprKind::Ret(ref expr_opt) => self.check_expr_return(expr_opt.as_deref(), expr),
+            ExprKind::Become(call) => self.check_expr_become(call, expr),
             ExprKind::Let(let_expr) => self.check_expr_let(let_expr),
             ExprKind::Loop(body, _, source, _) => {
                 self.check_expr_loop(body, source, expected, expr)
@@ -826,6 +828,173 @@ pub(super) fn check_return_expr(
         );
     }
 
+    /// Checks `become call`. Unlike `return`, the type of the call must be exactly the return
+    /// type of the function, as there is no way to perform a coercion after a tail call.
+    fn check_expr_become(
+        &self,
+        call: &'tcx hir::Expr<'tcx>,
+        expr: &'tcx hir::Expr<'tcx>,
+    ) -> Ty<'tcx> {
+        let Some(ret_coercion) = self.ret_coercion.as_ref() else {
+            self.tcx.sess.span_err(expr.span, "`become` outside of function body");
+            self.check_expr(call);
+            return self.tcx.types.never;
+        };
+
+        let ret_ty = ret_coercion.borrow().expected_ty();
+        let call_ty = self.check_expr_with_hint(call, ret_ty);
+        self.demand_eqtype(call.span, ret_ty, call_ty);
+        self.check_tail_call(call, expr);
+
+        self.tcx.types.never
+    }
+
+    /// Checks that the operand of `become` is a call which can be performed as a tail call, that
+    /// is, a call to a function with the same signature and calling convention as the caller.
+    fn check_tail_call(&self, call: &'tcx hir::Expr<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+        let tcx = self.tcx;
+        let caller_id = tcx.hir().enclosing_body_owner(expr.hir_id);
+        let caller_def_id = tcx.hir().local_def_id(caller_id).to_def_id();
+        if tcx.is_closure(caller_def_id) {
+            tcx.sess.span_err(expr.span, "`become` is not allowed in closures");
+            return;
+        }
+
+        // The callee, if it is known to be a function item or a method.
+        let mut callee_def = None;
+        let callee_sig = match call.kind {
+            ExprKind::Call(callee, _) => {
+                let callee_ty = self.typeck_results.borrow().expr_ty_adjusted_opt(callee);
+                let callee_ty = callee_ty.map(|ty| self.resolve_vars_with_obligations(ty));
+                let is_overloaded =
+                    self.typeck_results.borrow().type_dependent_def_id(call.hir_id).is_some();
+                match callee_ty {
+                    Some(ty) if ty.references_error() => return,
+                    Some(ty) if !is_overloaded && ty.is_fn() => {
+                        if let ty::FnDef(def_id, substs) = *ty.kind() {
+                            callee_def = Some((def_id, substs));
+                        }
+                        ty.fn_sig(tcx)
+                    }
+                    _ => {
+                        let mut err = tcx.sess.struct_span_err(
+                            callee.span,
+                            "tail calls can only be performed on functions and function pointers",
+                        );
+                        if let Some(ty) = callee_ty {
+                            err.note(&format!("`{}` is not a function or a function pointer", ty));
+                        }
+                        err.emit();
+                        return;
+                    }
+                }
+            }
+            ExprKind::MethodCall(..) => {
+                let typeck_results = self.typeck_results.borrow();
+                let Some(def_id) = typeck_results.type_dependent_def_id(call.hir_id) else {
+                    // An error was already reported when checking the method call.
+                    return;
+                };
+                let substs = typeck_results.node_substs(call.hir_id);
+                callee_def = Some((def_id, substs));
+                tcx.fn_sig(def_id).subst(tcx, substs)
+            }
+            _ => {
+                tcx.sess
+                    .struct_span_err(call.span, "`become` requires a function call")
+                    .span_label(call.span, "not a function call")
+                    .emit();
+                return;
+            }
+        };
+
+        let caller_sig = tcx.fn_sig(caller_def_id);
+        let caller_sig = tcx.liberate_late_bound_regions(caller_def_id, caller_sig);
+        let caller_sig = self.normalize_associated_types_in(expr.span, caller_sig);
+        let callee_sig =
+            self.replace_bound_vars_with_fresh_vars(call.span, infer::FnCall, callee_sig).0;
+        let callee_sig = self.normalize_associated_types_in(call.span, callee_sig);
+        let callee_sig = self.resolve_vars_if_possible(callee_sig);
+
+        if caller_sig.abi != callee_sig.abi {
+            tcx.sess
+                .struct_span_err(expr.span, "mismatched function ABIs")
+                .note("`become` requires caller and callee to have the same ABI")
+                .note(&format!(
+                    "caller ABI is {}, while callee ABI is {}",
+                    caller_sig.abi, callee_sig.abi
+                ))
+                .emit();
+        }
+
+        if caller_sig.c_variadic || callee_sig.c_variadic {
+            let descr = if caller_sig.c_variadic { "caller" } else { "callee" };
+            tcx.sess.span_err(
+                expr.span,
+                &format!("tail calls are not allowed when the {} is C-variadic", descr),
+            );
+        }
+
+        // The implicit location argument makes the signatures of the caller and callee differ.
+        // Trait methods which only turn out to be `#[track_caller]` after monomorphization are
+        // called normally by codegen.
+        let callee_needs_location = callee_def.map_or(false, |(def_id, substs)| {
+            let substs = self.resolve_vars_if_possible(substs);
+            let instance = if substs.needs_infer() {
+                None
+            } else {
+                ty::Instance::resolve(tcx, self.param_env, def_id, substs).ok().flatten()
+            };
+            let instance = instance.unwrap_or_else(|| ty::Instance::new(def_id, substs));
+            instance.def.requires_caller_location(tcx)
+        });
+        let caller_needs_location =
+            tcx.codegen_fn_attrs(caller_def_id).flags.contains(CodegenFnAttrFlags::TRACK_CALLER);
+        if caller_needs_location || callee_needs_location {
+            tcx.sess.span_err(
+                expr.span,
+                "tail calls to or from `#[track_caller]` functions are not supported",
+            );
+        }
+
+        if caller_sig.inputs().len() != callee_sig.inputs().len() {
+            tcx.sess
+                .struct_span_err(expr.span, "mismatched signatures")
+                .note("`become` requires caller and callee to have matching signatures")
+                .note(&format!("caller signature: `{}`", caller_sig))
+                .note(&format!("callee signature: `{}`", callee_sig))
+                .emit();
+            return;
+        }
+
+        // Lifetimes are irrelevant to the calling convention, and they are only checked by
+        // borrowck, which makes sure that no local is borrowed by the arguments.
+        for (&caller_input, &callee_input) in
+            std::iter::zip(caller_sig.inputs(), callee_sig.inputs())
+        {
+            if let Some(mut err) = self.demand_eqtype_diag(call.span, caller_input, callee_input) {
+                err.note("`become` requires caller and callee to have matching signatures");
+                err.emit();
+                return;
+            }
+        }
+
+        // The callee takes over the memory of the caller's arguments which are passed
+        // indirectly, and an unsized argument may not fit in it.
+        for &caller_input in caller_sig.inputs() {
+            if !caller_input.is_sized(tcx.at(expr.span), self.param_env) {
+                tcx.sess.span_err(
+                    expr.span,
+                    &format!(
+                        "tail calls with unsized arguments like `{}` are not supported",
+                        caller_input
+                    ),
+                );
+                return;
+            }
+        }
+    }
+
     pub(crate) fn check_lhs_assignable(
         &self,
         lhs: &'tcx hir::Expr<'tcx>,
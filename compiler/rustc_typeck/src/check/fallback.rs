@@ -3,9 +3,11 @@
     fx::FxHashMap,
     graph::WithSuccessors,
     graph::{iterate::DepthFirstSearch, vec_graph::VecGraph},
-    stable_set::FxHashSet,
 };
-use rustc_middle::ty::{self, Ty};
+use rustc_middle::ty::{self, Ty, TypeFoldable};
+use rustc_session::config::{IntFallback, NeverTypeFallback};
+use rustc_session::lint::builtin::{NEVER_TYPE_FALLBACK_CHANGE, NUMERIC_FALLBACK};
+use rustc_span::Span;
 
 impl<'tcx> FnCtxt<'_, 'tcx> {
     /// Performs type inference fallback, returning true if any fallback
@@ -77,7 +79,8 @@ pub(super) fn type_inference_fallback(&self) -> bool {
     //
     // - Non-numerics may get replaced with `()` or `!`, depending on
     //   how they were categorized by `calculate_diverging_fallback`
-    //   (and the setting of `#![feature(never_type_fallback)]`).
+    //   (and the setting of `#![feature(never_type_fallback)]` and
+    //   `-Znever-type-fallback`).
     //
     // Fallback becomes very dubious if we have encountered
     // type-checking errors.  In that case, fallback to Error.
@@ -108,8 +111,8 @@ fn fallback_if_possible(
         // same is true for float variables.)
         let fallback = match ty.kind() {
             _ if self.is_tainted_by_errors() => self.tcx.ty_error(),
-            ty::Infer(ty::IntVar(_)) => self.tcx.types.i32,
-            ty::Infer(ty::FloatVar(_)) => self.tcx.types.f64,
+            ty::Infer(ty::IntVar(_)) => self.int_fallback_ty(),
+            ty::Infer(ty::FloatVar(_)) => self.float_fallback_ty(),
             _ => match diverging_fallback.get(&ty) {
                 Some(&fallback_ty) => fallback_ty,
                 None => return false,
@@ -123,9 +126,46 @@ fn fallback_if_possible(
             .map(|origin| origin.span)
             .unwrap_or(rustc_span::DUMMY_SP);
         self.demand_eqtype(span, ty, fallback);
+
+        if let ty::Infer(ty::IntVar(_) | ty::FloatVar(_)) = ty.kind() {
+            if let Some(&lit_span) = self.numeric_literal_vars.borrow().get(&ty) {
+                if !fallback.references_error() {
+                    self.lint_numeric_fallback(lit_span, fallback);
+                }
+            }
+        }
         true
     }
 
+    /// The type that unconstrained integer variables fall back to: `i32`,
+    /// unless overridden with `-Z int-fallback`.
+    fn int_fallback_ty(&self) -> Ty<'tcx> {
+        match self.tcx.sess.opts.debugging_opts.int_fallback {
+            None => self.tcx.types.i32,
+            Some(IntFallback::Signed(t)) => self.tcx.mk_mach_int(ty::int_ty(t)),
+            Some(IntFallback::Unsigned(t)) => self.tcx.mk_mach_uint(ty::uint_ty(t)),
+        }
+    }
+
+    /// The type that unconstrained float variables fall back to: `f64`,
+    /// unless overridden with `-Z float-fallback`.
+    fn float_fallback_ty(&self) -> Ty<'tcx> {
+        match self.tcx.sess.opts.debugging_opts.float_fallback {
+            None => self.tcx.types.f64,
+            Some(t) => self.tcx.mk_mach_float(ty::float_ty(t)),
+        }
+    }
+
+    fn lint_numeric_fallback(&self, span: Span, fallback: Ty<'tcx>) {
+        self.tcx.struct_span_lint_hir(NUMERIC_FALLBACK, self.body_id, span, |lint| {
+            let mut err =
+                lint.build(&format!("the type of this literal falls back to `{}`", fallback));
+            err.note("the type of this literal is not constrained by the surrounding code");
+            err.help(&format!("specify the type explicitly, e.g. with a `{}` suffix", fallback));
+            err.emit();
+        });
+    }
+
     /// The "diverging fallback" system is rather complicated. This is
     /// a result of our need to balance 'do the right thing' with
     /// backwards compatibility.
@@ -192,12 +232,24 @@ fn fallback_if_possible(
     ///   reachable from there. Call that set `D`.
     /// * Walk over all unsolved, non-diverging variables, and find
     ///   any variable that has an edge into `D`.
+    ///
+    /// Which fallback is actually applied depends on the configured
+    /// `NeverTypeFallback`: the algorithm above is always run, but with
+    /// `NeverTypeFallback::Unit` (the default without
+    /// `#![feature(never_type_fallback)]`) every variable falls back to
+    /// `()`, and with `NeverTypeFallback::Never` every variable falls back
+    /// to `!`. Whenever a variable falls back to `()` but the algorithm
+    /// would have picked `!`, we emit the `never_type_fallback_change`
+    /// lint so that users can migrate ahead of the fallback changing.
     fn calculate_diverging_fallback(
         &self,
         unsolved_variables: &[Ty<'tcx>],
     ) -> FxHashMap<Ty<'tcx>, Ty<'tcx>> {
         debug!("calculate_diverging_fallback({:?})", unsolved_variables);
 
+        let behavior = self.diverging_fallback_behavior();
+        debug!("calculate_diverging_fallback: behavior={:?}", behavior);
+
         let relationships = self.fulfillment_cx.borrow_mut().relationships().clone();
 
         // Construct a coercion graph where an edge `A -> B` indicates
@@ -214,13 +266,12 @@ fn calculate_diverging_fallback(
         //
         // These variables are the ones that are targets for fallback to
         // either `!` or `()`.
-        let diverging_roots: FxHashSet<ty::TyVid> = self
+        let diverging_roots: FxHashMap<ty::TyVid, Span> = self
             .diverging_type_vars
             .borrow()
             .iter()
-            .map(|&ty| self.infcx.shallow_resolve(ty))
-            .filter_map(|ty| ty.ty_vid())
-            .map(|vid| self.infcx.root_var(vid))
+            .filter_map(|(&ty, &span)| Some((self.infcx.shallow_resolve(ty).ty_vid()?, span)))
+            .map(|(vid, span)| (self.infcx.root_var(vid), span))
             .collect();
         debug!(
             "calculate_diverging_fallback: diverging_type_vars={:?}",
@@ -241,9 +292,9 @@ fn calculate_diverging_fallback(
                 "calculate_diverging_fallback: unsolved_vid={:?} root_vid={:?} diverges={:?}",
                 unsolved_vid,
                 root_vid,
-                diverging_roots.contains(&root_vid),
+                diverging_roots.contains_key(&root_vid),
             );
-            if diverging_roots.contains(&root_vid) {
+            if diverging_roots.contains_key(&root_vid) {
                 diverging_vids.push(unsolved_vid);
                 roots_reachable_from_diverging.push_start_node(root_vid);
 
@@ -308,7 +359,7 @@ fn calculate_diverging_fallback(
                 }
             }
 
-            if relationship.self_in_trait && relationship.output {
+            let fallback_to_unit = if relationship.self_in_trait && relationship.output {
                 // This case falls back to () to ensure that the code pattern in
                 // src/test/ui/never_type/fallback-closure-ret.rs continues to
                 // compile when never_type_fallback is enabled.
@@ -334,19 +385,62 @@ fn calculate_diverging_fallback(
                 // set, see the relationship finding module in
                 // compiler/rustc_trait_selection/src/traits/relationships.rs.
                 debug!("fallback to () - found trait and projection: {:?}", diverging_vid);
-                diverging_fallback.insert(diverging_ty, self.tcx.types.unit);
+                true
             } else if can_reach_non_diverging {
                 debug!("fallback to () - reached non-diverging: {:?}", diverging_vid);
-                diverging_fallback.insert(diverging_ty, self.tcx.types.unit);
+                true
             } else {
                 debug!("fallback to ! - all diverging: {:?}", diverging_vid);
-                diverging_fallback.insert(diverging_ty, self.tcx.mk_diverging_default());
+                false
+            };
+
+            let fallback_ty = match behavior {
+                NeverTypeFallback::Unit => self.tcx.types.unit,
+                NeverTypeFallback::Niko if fallback_to_unit => self.tcx.types.unit,
+                NeverTypeFallback::Niko | NeverTypeFallback::Never => self.tcx.types.never,
+            };
+
+            if !fallback_to_unit && fallback_ty.is_unit() {
+                self.lint_never_type_fallback_change(diverging_roots[&root_vid]);
             }
+
+            diverging_fallback.insert(diverging_ty, fallback_ty);
         }
 
         diverging_fallback
     }
 
+    /// Returns the configured fallback for diverging type variables: either the
+    /// one requested with `-Znever-type-fallback`, or the one implied by whether
+    /// `#![feature(never_type_fallback)]` is enabled.
+    fn diverging_fallback_behavior(&self) -> NeverTypeFallback {
+        if let Some(behavior) = self.tcx.sess.opts.debugging_opts.never_type_fallback {
+            return behavior;
+        }
+
+        if self.tcx.features().never_type_fallback {
+            NeverTypeFallback::Niko
+        } else {
+            NeverTypeFallback::Unit
+        }
+    }
+
+    /// Emits the `never_type_fallback_change` lint for the diverging expression at
+    /// `span`, whose type falls back to `()` but would fall back to `!` under never
+    /// type fallback.
+    fn lint_never_type_fallback_change(&self, span: Span) {
+        self.tcx.struct_span_lint_hir(NEVER_TYPE_FALLBACK_CHANGE, self.body_id, span, |lint| {
+            let mut err =
+                lint.build("the type of this expression will fall back to `!` instead of `()`");
+            err.note(
+                "this expression diverges, and its type is otherwise unconstrained, so it \
+                 currently falls back to `()`",
+            );
+            err.help("specify the type explicitly if this code relies on it being `()`");
+            err.emit();
+        });
+    }
+
     /// Returns a graph whose nodes are (unresolved) inference variables and where
     /// an edge `?A -> ?B` indicates that the variable `?A` is coerced to `?B`.
     fn create_coercion_graph(&self) -> VecGraph<ty::TyVid> {
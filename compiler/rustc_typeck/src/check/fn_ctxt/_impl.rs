@@ -274,7 +274,7 @@ pub fn apply_adjustments(&self, expr: &hir::Expr<'_>, adj: Vec<Adjustment<'tcx>>
         for a in &adj {
             if let Adjust::NeverToAny = a.kind {
                 if a.target.is_ty_var() {
-                    self.diverging_type_vars.borrow_mut().insert(a.target);
+                    self.diverging_type_vars.borrow_mut().entry(a.target).or_insert(expr.span);
                     debug!("apply_adjustments: adding `{:?}` as diverging type var", a.target);
                 }
             }
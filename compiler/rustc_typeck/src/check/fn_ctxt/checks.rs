@@ -544,7 +544,11 @@ pub(in super::super) fn check_lit(
                     ty::FnDef(..) | ty::FnPtr(_) => Some(tcx.types.usize),
                     _ => None,
                 });
-                opt_ty.unwrap_or_else(|| self.next_int_var())
+                opt_ty.unwrap_or_else(|| {
+                    let ty = self.next_int_var();
+                    self.numeric_literal_vars.borrow_mut().insert(ty, lit.span);
+                    ty
+                })
             }
             ast::LitKind::Float(_, ast::LitFloatType::Suffixed(t)) => {
                 tcx.mk_mach_float(ty::float_ty(t))
@@ -554,7 +558,11 @@ pub(in super::super) fn check_lit(
                     ty::Float(_) => Some(ty),
                     _ => None,
                 });
-                opt_ty.unwrap_or_else(|| self.next_float_var())
+                opt_ty.unwrap_or_else(|| {
+                    let ty = self.next_float_var();
+                    self.numeric_literal_vars.borrow_mut().insert(ty, lit.span);
+                    ty
+                })
             }
             ast::LitKind::Bool(_) => tcx.types.bool,
             ast::LitKind::Err(_) => tcx.ty_error(),
@@ -188,6 +188,7 @@ fn reinit_expr(&mut self, expr: &hir::Expr<'_>) {
             | ExprKind::Break(..)
             | ExprKind::Continue(..)
             | ExprKind::Ret(..)
+            | ExprKind::Become(..)
             | ExprKind::InlineAsm(..)
             | ExprKind::Struct(..)
             | ExprKind::Repeat(..)
@@ -374,6 +375,7 @@ fn visit_expr(&mut self, expr: &'tcx Expr<'tcx>) {
             | ExprKind::Path(..)
             | ExprKind::Repeat(..)
             | ExprKind::Ret(..)
+            | ExprKind::Become(..)
             | ExprKind::Struct(..)
             | ExprKind::Tup(..)
             | ExprKind::Type(..)
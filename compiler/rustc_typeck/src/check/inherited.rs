@@ -1,7 +1,7 @@
 use super::callee::DeferredCallResolution;
 use super::MaybeInProgressTables;
 
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::FxHashMap;
 use rustc_hir as hir;
 use rustc_hir::def_id::{DefIdMap, LocalDefId};
 use rustc_hir::HirIdMap;
@@ -56,9 +56,15 @@ pub struct Inherited<'a, 'tcx> {
     pub(super) body_id: Option<hir::BodyId>,
 
     /// Whenever we introduce an adjustment from `!` into a type variable,
-    /// we record that type variable here. This is later used to inform
-    /// fallback. See the `fallback` module for details.
-    pub(super) diverging_type_vars: RefCell<FxHashSet<Ty<'tcx>>>,
+    /// we record that type variable here, along with the span of the
+    /// diverging expression. This is later used to inform fallback.
+    /// See the `fallback` module for details.
+    pub(super) diverging_type_vars: RefCell<FxHashMap<Ty<'tcx>, Span>>,
+
+    /// Integer and float inference variables introduced for unsuffixed
+    /// literals, along with the span of the literal. Used to report where
+    /// numeric fallback is applied.
+    pub(super) numeric_literal_vars: RefCell<FxHashMap<Ty<'tcx>, Span>>,
 }
 
 impl<'a, 'tcx> Deref for Inherited<'a, 'tcx> {
@@ -122,6 +128,7 @@ pub(super) fn new(infcx: InferCtxt<'a, 'tcx>, def_id: LocalDefId) -> Self {
             deferred_cast_checks: RefCell::new(Vec::new()),
             deferred_generator_interiors: RefCell::new(Vec::new()),
             diverging_type_vars: RefCell::new(Default::default()),
+            numeric_literal_vars: RefCell::new(Default::default()),
             body_id,
         }
     }
@@ -302,6 +302,9 @@ pub fn check_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>) {
                 vec![tcx.mk_mut_ptr(tcx.types.u8), tcx.types.usize, tcx.types.usize],
                 tcx.mk_unit(),
             ),
+            sym::const_make_global => {
+                (0, vec![tcx.mk_mut_ptr(tcx.types.u8)], tcx.mk_imm_ptr(tcx.types.u8))
+            }
 
             sym::ptr_offset_from => {
                 (1, vec![tcx.mk_imm_ptr(param(0)), tcx.mk_imm_ptr(param(0))], tcx.types.isize)
@@ -456,6 +459,9 @@ pub fn check_platform_intrinsic_type(tcx: TyCtxt<'_>, it: &hir::ForeignItem<'_>)
         sym::simd_fma => (1, vec![param(0), param(0), param(0)], param(0)),
         sym::simd_gather => (3, vec![param(0), param(1), param(2)], param(0)),
         sym::simd_scatter => (3, vec![param(0), param(1), param(2)], tcx.mk_unit()),
+        sym::simd_masked_load => (3, vec![param(0), param(1), param(2)], param(2)),
+        sym::simd_masked_store => (3, vec![param(0), param(1), param(2)], tcx.mk_unit()),
+        sym::simd_swizzle_dyn => (2, vec![param(0), param(1)], param(0)),
         sym::simd_insert => (2, vec![param(0), tcx.types.u32, param(1)], param(0)),
         sym::simd_extract => (2, vec![param(0), tcx.types.u32], param(1)),
         sym::simd_cast | sym::simd_as => (2, vec![param(0)], param(1)),
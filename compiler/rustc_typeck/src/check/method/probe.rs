@@ -1090,7 +1090,20 @@ fn pick(mut self) -> PickResult<'tcx> {
         if let Some((kind, def_id)) = private_candidate {
             return Err(MethodError::PrivateMatch(kind, def_id, out_of_scope_traits));
         }
-        let lev_candidate = self.probe_for_lev_candidate()?;
+        let mut out_of_scope_traits = out_of_scope_traits;
+        let mut lev_candidate = self.probe_for_lev_candidate(false)?;
+        if lev_candidate.is_none() && out_of_scope_traits.is_empty() && !self.is_suggestion.0 {
+            // Look for a similarly named method in all the traits of the loaded crates, so that
+            // the trait to import can be suggested along with the name.
+            lev_candidate = self.probe_for_lev_candidate(true)?;
+            if let Some(ty::AssocItem { container: ty::TraitContainer(trait_def_id), .. }) =
+                lev_candidate
+            {
+                if !self.is_trait_in_scope(trait_def_id) {
+                    out_of_scope_traits.push(trait_def_id);
+                }
+            }
+        }
 
         Err(MethodError::NoMatch(NoMatchData::new(
             static_candidates,
@@ -1713,7 +1726,14 @@ fn collapse_candidates_to_trait_pick(
     /// Similarly to `probe_for_return_type`, this method attempts to find the best matching
     /// candidate method where the method name may have been misspelt. Similarly to other
     /// Levenshtein based suggestions, we provide at most one such suggestion.
-    fn probe_for_lev_candidate(&mut self) -> Result<Option<ty::AssocItem>, MethodError<'tcx>> {
+    ///
+    /// If `all_traits` is set, the methods of all the traits that can be imported are considered
+    /// instead of the inherent candidates. Only the traits of the crates that are loaded are known:
+    /// a crate passed with `--extern` that the current crate doesn't refer to isn't searched.
+    fn probe_for_lev_candidate(
+        &mut self,
+        all_traits: bool,
+    ) -> Result<Option<ty::AssocItem>, MethodError<'tcx>> {
         debug!("probing for method names similar to {:?}", self.method_name);
 
         let steps = self.steps.clone();
@@ -1729,20 +1749,39 @@ fn probe_for_lev_candidate(&mut self) -> Result<Option<ty::AssocItem>, MethodErr
                 IsSuggestion(true),
                 self.scope_expr_id,
             );
+            let assemble = |pcx: &mut ProbeContext<'a, 'tcx>| {
+                if all_traits {
+                    pcx.assemble_extension_candidates_for_all_traits();
+                } else {
+                    pcx.assemble_inherent_candidates();
+                }
+            };
             pcx.allow_similar_names = true;
-            pcx.assemble_inherent_candidates();
+            assemble(&mut pcx);
 
             let method_names = pcx.candidate_method_names();
             pcx.allow_similar_names = false;
-            let applicable_close_candidates: Vec<ty::AssocItem> = method_names
+            let mut applicable_close_candidates: Vec<ty::AssocItem> = method_names
                 .iter()
                 .filter_map(|&method_name| {
                     pcx.reset();
                     pcx.method_name = Some(method_name);
-                    pcx.assemble_inherent_candidates();
+                    assemble(&mut pcx);
                     pcx.pick_core().and_then(|pick| pick.ok()).map(|pick| pick.item)
                 })
                 .collect();
+            if all_traits {
+                // Only suggest the traits that can be imported, and whose methods can be called
+                // without an unstable feature.
+                applicable_close_candidates.retain(|item| {
+                    let trait_def_id = item.container.id();
+                    self.is_trait_nameable(trait_def_id)
+                        && !matches!(
+                            self.tcx.eval_stability(trait_def_id, None, self.span, None),
+                            stability::EvalResult::Deny { .. }
+                        )
+                });
+            }
 
             if applicable_close_candidates.is_empty() {
                 Ok(None)
@@ -1762,6 +1801,12 @@ fn probe_for_lev_candidate(&mut self) -> Result<Option<ty::AssocItem>, MethodErr
 
     ///////////////////////////////////////////////////////////////////////////
     // MISCELLANY
+    fn is_trait_in_scope(&self, trait_def_id: DefId) -> bool {
+        self.tcx
+            .in_scope_traits(self.scope_expr_id)
+            .map_or(false, |traits| traits.iter().any(|trait_| trait_.def_id == trait_def_id))
+    }
+
     fn has_applicable_self(&self, item: &ty::AssocItem) -> bool {
         // "Fast track" -- check for usage of sugar when in method call
         // mode.
@@ -83,6 +83,18 @@ pub fn report_method_error(
         if rcvr_ty.references_error() {
             return None;
         }
+        // The method exists, but its bounds only fail to hold because of a type that an error was
+        // already reported for.
+        if let MethodError::NoMatch(NoMatchData { ref unsatisfied_predicates, .. }) = error {
+            if !unsatisfied_predicates.is_empty()
+                && unsatisfied_predicates.iter().all(|(predicate, ..)| {
+                    self.resolve_vars_if_possible(*predicate).references_error()
+                })
+            {
+                self.set_tainted_by_errors();
+                return None;
+            }
+        }
 
         let report_candidates = |span: Span,
                                  err: &mut DiagnosticBuilder<'_>,
@@ -245,7 +257,7 @@ pub fn report_method_error(
                 let tcx = self.tcx;
 
                 let actual = self.resolve_vars_if_possible(rcvr_ty);
-                let ty_str = self.ty_to_string(actual);
+                let (ty_str, ty_file) = tcx.short_string(self.ty_to_string(actual));
                 let is_method = mode == Mode::MethodCall;
                 let item_kind = if is_method {
                     "method"
@@ -418,6 +430,7 @@ pub fn report_method_error(
                             actual.prefix_string(self.tcx),
                             ty_str_reported,
                         );
+                        err.note_long_type(ty_file.as_deref());
                         if let Mode::MethodCall = mode {
                             if let SelfSource::MethodCall(call) = source {
                                 self.suggest_await_before_method(
@@ -1394,6 +1407,29 @@ fn suggest_use_candidates(
         }
     }
 
+    /// Whether the trait `trait_def_id` can be named, and thus imported, from the current body.
+    /// The private traits of other crates can't be, even if their methods are visible.
+    crate fn is_trait_nameable(&self, trait_def_id: DefId) -> bool {
+        match trait_def_id.as_local() {
+            Some(_) => {
+                let module = self.tcx.parent_module(self.body_id).to_def_id();
+                self.tcx.visibility(trait_def_id).is_accessible_from(module, self.tcx)
+            }
+            None => self.tcx.visible_parent_map(()).contains_key(&trait_def_id),
+        }
+    }
+
+    /// Whether the trait `trait_def_id` is sealed, i.e. has a supertrait that can't be named,
+    /// which means that it can only be implemented in the crate defining it.
+    fn is_trait_sealed(&self, trait_def_id: DefId) -> bool {
+        self.tcx.super_predicates_of(trait_def_id).predicates.iter().any(|(pred, _)| {
+            match pred.kind().skip_binder() {
+                ty::PredicateKind::Trait(pred) => !self.is_trait_nameable(pred.def_id()),
+                _ => false,
+            }
+        })
+    }
+
     fn suggest_valid_traits(
         &self,
         err: &mut DiagnosticBuilder<'_>,
@@ -1569,7 +1605,10 @@ fn suggest_traits_to_import(
                         }
                         _ => false,
                     }
-                }) && (type_is_local || info.def_id.is_local())
+                }) && (info.def_id.is_local()
+                    || type_is_local
+                        && self.is_trait_nameable(info.def_id)
+                        && !self.is_trait_sealed(info.def_id))
                     && self
                         .associated_value(info.def_id, item_name)
                         .filter(|item| {
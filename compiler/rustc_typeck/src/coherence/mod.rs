@@ -5,6 +5,7 @@
 // done by the orphan and overlap modules. Then we build up various
 // mappings. That mapping code resides here.
 
+use crate::errors::{ExplicitImplOfBuiltinTrait, ExplicitImplOfUnsize, MarkerTraitImplWithItems};
 use rustc_errors::struct_span_err;
 use rustc_hir::def_id::{DefId, LocalDefId};
 use rustc_middle::ty::query::Providers;
@@ -51,53 +52,25 @@ fn enforce_trait_manually_implementable(
     // Disallow *all* explicit impls of `Pointee`, `DiscriminantKind`, `Sized` and `Unsize` for now.
     if did == li.pointee_trait() {
         let span = impl_header_span(tcx, impl_def_id);
-        struct_span_err!(
-            tcx.sess,
-            span,
-            E0322,
-            "explicit impls for the `Pointee` trait are not permitted"
-        )
-        .span_label(span, "impl of 'Pointee' not allowed")
-        .emit();
+        tcx.sess.emit_err(ExplicitImplOfBuiltinTrait { span, trait_name: "Pointee" });
         return;
     }
 
     if did == li.discriminant_kind_trait() {
         let span = impl_header_span(tcx, impl_def_id);
-        struct_span_err!(
-            tcx.sess,
-            span,
-            E0322,
-            "explicit impls for the `DiscriminantKind` trait are not permitted"
-        )
-        .span_label(span, "impl of 'DiscriminantKind' not allowed")
-        .emit();
+        tcx.sess.emit_err(ExplicitImplOfBuiltinTrait { span, trait_name: "DiscriminantKind" });
         return;
     }
 
     if did == li.sized_trait() {
         let span = impl_header_span(tcx, impl_def_id);
-        struct_span_err!(
-            tcx.sess,
-            span,
-            E0322,
-            "explicit impls for the `Sized` trait are not permitted"
-        )
-        .span_label(span, "impl of 'Sized' not allowed")
-        .emit();
+        tcx.sess.emit_err(ExplicitImplOfBuiltinTrait { span, trait_name: "Sized" });
         return;
     }
 
     if did == li.unsize_trait() {
         let span = impl_header_span(tcx, impl_def_id);
-        struct_span_err!(
-            tcx.sess,
-            span,
-            E0328,
-            "explicit impls for the `Unsize` trait are not permitted"
-        )
-        .span_label(span, "impl of `Unsize` not allowed")
-        .emit();
+        tcx.sess.emit_err(ExplicitImplOfUnsize { span });
         return;
     }
 
@@ -139,7 +112,7 @@ fn enforce_empty_impls_for_marker_traits(
     }
 
     let span = impl_header_span(tcx, impl_def_id);
-    struct_span_err!(tcx.sess, span, E0715, "impls for marker traits cannot contain items").emit();
+    tcx.sess.emit_err(MarkerTraitImplWithItems { span });
 }
 
 pub fn provide(providers: &mut Providers) {
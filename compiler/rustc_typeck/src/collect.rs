@@ -2801,50 +2801,26 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, id: DefId) -> CodegenFnAttrs {
                 codegen_fn_attrs.flags |= CodegenFnAttrFlags::FFI_RETURNS_TWICE;
             } else {
                 // `#[ffi_returns_twice]` is only allowed `extern fn`s.
-                struct_span_err!(
-                    tcx.sess,
-                    attr.span,
-                    E0724,
-                    "`#[ffi_returns_twice]` may only be used on foreign functions"
-                )
-                .emit();
+                tcx.sess.emit_err(errors::FfiReturnsTwiceOnNonForeignFn { span: attr.span });
             }
         } else if attr.has_name(sym::ffi_pure) {
             if tcx.is_foreign_item(id) {
                 if attrs.iter().any(|a| a.has_name(sym::ffi_const)) {
                     // `#[ffi_const]` functions cannot be `#[ffi_pure]`
-                    struct_span_err!(
-                        tcx.sess,
-                        attr.span,
-                        E0757,
-                        "`#[ffi_const]` function cannot be `#[ffi_pure]`"
-                    )
-                    .emit();
+                    tcx.sess.emit_err(errors::FfiConstAndFfiPure { span: attr.span });
                 } else {
                     codegen_fn_attrs.flags |= CodegenFnAttrFlags::FFI_PURE;
                 }
             } else {
                 // `#[ffi_pure]` is only allowed on foreign functions
-                struct_span_err!(
-                    tcx.sess,
-                    attr.span,
-                    E0755,
-                    "`#[ffi_pure]` may only be used on foreign functions"
-                )
-                .emit();
+                tcx.sess.emit_err(errors::FfiPureOnNonForeignFn { span: attr.span });
             }
         } else if attr.has_name(sym::ffi_const) {
             if tcx.is_foreign_item(id) {
                 codegen_fn_attrs.flags |= CodegenFnAttrFlags::FFI_CONST;
             } else {
                 // `#[ffi_const]` is only allowed on foreign functions
-                struct_span_err!(
-                    tcx.sess,
-                    attr.span,
-                    E0756,
-                    "`#[ffi_const]` may only be used on foreign functions"
-                )
-                .emit();
+                tcx.sess.emit_err(errors::FfiConstOnNonForeignFn { span: attr.span });
             }
         } else if attr.has_name(sym::rustc_allocator_nounwind) {
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::NEVER_UNWIND;
@@ -2895,25 +2871,17 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, id: DefId) -> CodegenFnAttrs {
             }
         } else if attr.has_name(sym::cmse_nonsecure_entry) {
             if !matches!(tcx.fn_sig(id).abi(), abi::Abi::C { .. }) {
-                struct_span_err!(
-                    tcx.sess,
-                    attr.span,
-                    E0776,
-                    "`#[cmse_nonsecure_entry]` requires C ABI"
-                )
-                .emit();
+                tcx.sess.emit_err(errors::CmseNonsecureEntryRequiresCAbi { span: attr.span });
             }
             if !tcx.sess.target.llvm_target.contains("thumbv8m") {
-                struct_span_err!(tcx.sess, attr.span, E0775, "`#[cmse_nonsecure_entry]` is only valid for targets with the TrustZone-M extension")
-                    .emit();
+                tcx.sess.emit_err(errors::CmseNonsecureEntryRequiresTrustzoneM { span: attr.span });
             }
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::CMSE_NONSECURE_ENTRY;
         } else if attr.has_name(sym::thread_local) {
             codegen_fn_attrs.flags |= CodegenFnAttrFlags::THREAD_LOCAL;
         } else if attr.has_name(sym::track_caller) {
             if !tcx.is_closure(id) && tcx.fn_sig(id).abi() != abi::Abi::Rust {
-                struct_span_err!(tcx.sess, attr.span, E0737, "`#[track_caller]` requires Rust ABI")
-                    .emit();
+                tcx.sess.emit_err(errors::TrackCallerRequiresRustAbi { span: attr.span });
             }
             if tcx.is_closure(id) && !tcx.features().closure_track_caller {
                 feature_err(
@@ -3018,11 +2986,10 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, id: DefId) -> CodegenFnAttrs {
                         codegen_fn_attrs.no_sanitize |= SanitizerSet::THREAD;
                     } else if item.has_name(sym::hwaddress) {
                         codegen_fn_attrs.no_sanitize |= SanitizerSet::HWADDRESS;
+                    } else if item.has_name(sym::kcfi) {
+                        codegen_fn_attrs.no_sanitize |= SanitizerSet::KCFI;
                     } else {
-                        tcx.sess
-                            .struct_span_err(item.span(), "invalid argument for `no_sanitize`")
-                            .note("expected one of: `address`, `hwaddress`, `memory` or `thread`")
-                            .emit();
+                        tcx.sess.emit_err(errors::InvalidNoSanitizeArgument { span: item.span() });
                     }
                 }
             }
@@ -3035,13 +3002,9 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, id: DefId) -> CodegenFnAttrs {
                         match segments.as_slice() {
                             [sym::arm, sym::a32] | [sym::arm, sym::t32] => {
                                 if !tcx.sess.target.has_thumb_interworking {
-                                    struct_span_err!(
-                                        tcx.sess.diagnostic(),
-                                        attr.span,
-                                        E0779,
-                                        "target does not support `#[instruction_set]`"
-                                    )
-                                    .emit();
+                                    tcx.sess.emit_err(errors::InstructionSetUnsupportedOnTarget {
+                                        span: attr.span,
+                                    });
                                     None
                                 } else if segments[1] == sym::a32 {
                                     Some(InstructionSetAttr::ArmA32)
@@ -3052,46 +3015,24 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, id: DefId) -> CodegenFnAttrs {
                                 }
                             }
                             _ => {
-                                struct_span_err!(
-                                    tcx.sess.diagnostic(),
-                                    attr.span,
-                                    E0779,
-                                    "invalid instruction set specified",
-                                )
-                                .emit();
+                                tcx.sess
+                                    .emit_err(errors::InvalidInstructionSet { span: attr.span });
                                 None
                             }
                         }
                     }
                     [] => {
-                        struct_span_err!(
-                            tcx.sess.diagnostic(),
-                            attr.span,
-                            E0778,
-                            "`#[instruction_set]` requires an argument"
-                        )
-                        .emit();
+                        tcx.sess
+                            .emit_err(errors::InstructionSetRequiresArgument { span: attr.span });
                         None
                     }
                     _ => {
-                        struct_span_err!(
-                            tcx.sess.diagnostic(),
-                            attr.span,
-                            E0779,
-                            "cannot specify more than one instruction set"
-                        )
-                        .emit();
+                        tcx.sess.emit_err(errors::MultipleInstructionSets { span: attr.span });
                         None
                     }
                 },
                 _ => {
-                    struct_span_err!(
-                        tcx.sess.diagnostic(),
-                        attr.span,
-                        E0778,
-                        "must specify an instruction set"
-                    )
-                    .emit();
+                    tcx.sess.emit_err(errors::InstructionSetMissing { span: attr.span });
                     None
                 }
             };
@@ -3137,26 +3078,14 @@ fn codegen_fn_attrs(tcx: TyCtxt<'_>, id: DefId) -> CodegenFnAttrs {
             Some(MetaItemKind::List(ref items)) => {
                 inline_span = Some(attr.span);
                 if items.len() != 1 {
-                    struct_span_err!(
-                        tcx.sess.diagnostic(),
-                        attr.span,
-                        E0534,
-                        "expected one argument"
-                    )
-                    .emit();
+                    tcx.sess.emit_err(errors::InlineExpectedOneArgument { span: attr.span });
                     InlineAttr::None
                 } else if list_contains_name(&items, sym::always) {
                     InlineAttr::Always
                 } else if list_contains_name(&items, sym::never) {
                     InlineAttr::Never
                 } else {
-                    struct_span_err!(
-                        tcx.sess.diagnostic(),
-                        items[0].span(),
-                        E0535,
-                        "invalid argument"
-                    )
-                    .emit();
+                    tcx.sess.emit_err(errors::InlineInvalidArgument { span: items[0].span() });
 
                     InlineAttr::None
                 }
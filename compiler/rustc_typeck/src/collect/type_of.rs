@@ -1,4 +1,4 @@
-use rustc_errors::{Applicability, ErrorReported, StashKey};
+use rustc_errors::{pluralize, Applicability, ErrorReported, StashKey};
 use rustc_hir as hir;
 use rustc_hir::def::CtorOf;
 use rustc_hir::def::{DefKind, Res};
@@ -7,11 +7,11 @@
 use rustc_hir::intravisit::Visitor;
 use rustc_hir::{HirId, Node};
 use rustc_middle::hir::nested_filter;
-use rustc_middle::ty::subst::InternalSubsts;
+use rustc_middle::ty::subst::{GenericArgKind, InternalSubsts};
 use rustc_middle::ty::util::IntTypeExt;
 use rustc_middle::ty::{self, DefIdTree, Ty, TyCtxt, TypeFoldable, TypeFolder};
 use rustc_span::symbol::Ident;
-use rustc_span::{Span, DUMMY_SP};
+use rustc_span::{MultiSpan, Span, DUMMY_SP};
 
 use super::ItemCtxt;
 use super::{bad_placeholder, is_suggestable_infer_ty};
@@ -387,7 +387,7 @@ pub(super) fn type_of(tcx: TyCtxt<'_>, def_id: DefId) -> Ty<'_> {
                         .mir_borrowck(owner)
                         .concrete_opaque_types
                         .get_value_matching(|(key, _)| key.def_id == def_id.to_def_id())
-                        .copied()
+                        .map(|concrete| concrete.ty)
                         .unwrap_or_else(|| {
                             let table = tcx.typeck(owner);
                             if let Some(ErrorReported) = table.tainted_by_errors {
@@ -580,12 +580,20 @@ struct ConstraintLocator<'tcx> {
         /// def_id of the opaque type whose defining uses are being checked
         def_id: DefId,
 
-        /// as we walk the defining uses, we are checking that all of them
-        /// define the same hidden type. This variable is set to `Some`
-        /// with the first type that we find, and then later types are
-        /// checked against it (we also carry the span of that first
-        /// type).
-        found: Option<(Span, Ty<'tcx>)>,
+        /// The hidden types of all the defining uses that we walk, in the
+        /// order in which they are found. They are only checked against
+        /// each other once the walk is done, so that a mismatch can be
+        /// reported with every candidate hidden type.
+        uses: Vec<ty::OpaqueHiddenType<'tcx>>,
+
+        /// Set if one of the items that we walk has errors, as we can't
+        /// know the hidden type it would have defined.
+        tainted_by_errors: bool,
+
+        /// The spans of the functions that mention the opaque type in
+        /// their signature without constraining it, which are pointed at
+        /// if there is no defining use.
+        non_defining_fns: Vec<Span>,
     }
 
     impl ConstraintLocator<'_> {
@@ -609,16 +617,18 @@ fn check(&mut self, def_id: LocalDefId) {
             // ```
             let tables = self.tcx.typeck(def_id);
             if let Some(_) = tables.tainted_by_errors {
-                self.found = Some((DUMMY_SP, self.tcx.ty_error()));
+                self.tainted_by_errors = true;
                 return;
             }
             if tables.concrete_opaque_types.get(&self.def_id).is_none() {
                 debug!("no constraints in typeck results");
+                self.check_non_defining_fn(def_id);
                 return;
             }
             // Use borrowck to get the type with unerased regions.
             let concrete_opaque_types = &self.tcx.mir_borrowck(def_id).concrete_opaque_types;
             debug!(?concrete_opaque_types);
+            let prev_uses = self.uses.len();
             for (opaque_type_key, concrete_type) in concrete_opaque_types {
                 if opaque_type_key.def_id != self.def_id {
                     // Ignore constraints for other opaque types.
@@ -626,28 +636,31 @@ fn check(&mut self, def_id: LocalDefId) {
                 }
 
                 debug!(?concrete_type, ?opaque_type_key.substs, "found constraint");
+                self.uses.push(*concrete_type);
+            }
+            if self.uses.len() == prev_uses {
+                self.check_non_defining_fn(def_id);
+            }
+        }
 
-                // FIXME(oli-obk): trace the actual span from inference to improve errors.
-                let span = self.tcx.def_span(def_id);
-
-                if let Some((prev_span, prev_ty)) = self.found {
-                    if *concrete_type != prev_ty && !(*concrete_type, prev_ty).references_error() {
-                        debug!(?span);
-                        // Found different concrete types for the opaque type.
-                        let mut err = self.tcx.sess.struct_span_err(
-                            span,
-                            "concrete type differs from previous defining opaque type use",
-                        );
-                        err.span_label(
-                            span,
-                            format!("expected `{}`, got `{}`", prev_ty, concrete_type),
-                        );
-                        err.span_note(prev_span, "previous use here");
-                        err.emit();
-                    }
-                } else {
-                    self.found = Some((span, concrete_type));
-                }
+        /// Records `def_id` if it is a function whose signature mentions
+        /// the opaque type, as such a function could have defined it.
+        fn check_non_defining_fn(&mut self, def_id: LocalDefId) {
+            if !matches!(self.tcx.def_kind(def_id), DefKind::Fn | DefKind::AssocFn) {
+                return;
+            }
+            let sig = self.tcx.fn_sig(def_id);
+            let mentions_opaque = sig.skip_binder().inputs_and_output.iter().any(|ty| {
+                ty.walk().any(|arg| match arg.unpack() {
+                    GenericArgKind::Type(ty) => match *ty.kind() {
+                        ty::Opaque(opaque_def_id, _) => opaque_def_id == self.def_id,
+                        _ => false,
+                    },
+                    _ => false,
+                })
+            });
+            if mentions_opaque {
+                self.non_defining_fns.push(self.tcx.def_span(def_id));
             }
         }
     }
@@ -690,7 +703,13 @@ fn visit_trait_item(&mut self, it: &'tcx TraitItem<'tcx>) {
 
     let hir_id = tcx.hir().local_def_id_to_hir_id(def_id);
     let scope = tcx.hir().get_defining_scope(hir_id);
-    let mut locator = ConstraintLocator { def_id: def_id.to_def_id(), tcx, found: None };
+    let mut locator = ConstraintLocator {
+        def_id: def_id.to_def_id(),
+        tcx,
+        uses: Vec::new(),
+        tainted_by_errors: false,
+        non_defining_fns: Vec::new(),
+    };
 
     debug!(?scope);
 
@@ -720,19 +739,99 @@ fn visit_trait_item(&mut self, it: &'tcx TraitItem<'tcx>) {
         }
     }
 
-    match locator.found {
-        Some((_, ty)) => ty,
-        None => {
-            let span = tcx.def_span(def_id);
-            let name = tcx.item_name(tcx.parent(def_id.to_def_id()).unwrap());
-            let label = format!(
-                "`{}` must be used in combination with a concrete type within the same module",
-                name
+    let uses: Vec<_> =
+        locator.uses.into_iter().filter(|hidden| !hidden.ty.references_error()).collect();
+    if uses.is_empty() {
+        if locator.tainted_by_errors {
+            return tcx.ty_error();
+        }
+        let span = tcx.def_span(def_id);
+        let name = tcx.item_name(tcx.parent(def_id.to_def_id()).unwrap());
+        let label = format!(
+            "`{}` must be used in combination with a concrete type within the same module",
+            name
+        );
+        let mut err = tcx.sess.struct_span_err(span, "unconstrained opaque type");
+        err.note(&label);
+        for span in locator.non_defining_fns {
+            err.span_note(
+                span,
+                &format!(
+                    "this function mentions `{}` in its signature, but doesn't define its \
+                     hidden type",
+                    name
+                ),
             );
-            tcx.sess.struct_span_err(span, "unconstrained opaque type").note(&label).emit();
-            tcx.ty_error()
         }
+        err.emit();
+        return tcx.ty_error();
+    }
+
+    let hidden_ty = check_defining_uses_agree(tcx, def_id, &uses);
+    if locator.tainted_by_errors { tcx.ty_error() } else { hidden_ty }
+}
+
+/// Checks that all the defining uses of the opaque type `def_id` imply the same hidden type,
+/// reporting the ones that don't, and returns the hidden type that they are expected to imply.
+fn check_defining_uses_agree<'tcx>(
+    tcx: TyCtxt<'tcx>,
+    def_id: LocalDefId,
+    uses: &[ty::OpaqueHiddenType<'tcx>],
+) -> Ty<'tcx> {
+    // The uses are expected to imply the hidden type that most of them imply, and with a tie,
+    // the one of the earliest use, so that of two uses the later one is reported.
+    let mut candidates: Vec<(Ty<'tcx>, usize)> = Vec::new();
+    for hidden in uses {
+        match candidates.iter_mut().find(|(ty, _)| *ty == hidden.ty) {
+            Some((_, count)) => *count += 1,
+            None => candidates.push((hidden.ty, 1)),
+        }
+    }
+    let (expected, expected_count) = candidates
+        .iter()
+        .copied()
+        .reduce(|best, candidate| if candidate.1 > best.1 { candidate } else { best })
+        .unwrap();
+    if candidates.len() == 1 {
+        return expected;
+    }
+
+    let (conflicting, agreeing): (Vec<_>, Vec<_>) =
+        uses.iter().partition(|hidden| hidden.ty != expected);
+    let mut spans = MultiSpan::from_spans(conflicting.iter().map(|hidden| hidden.span).collect());
+    for hidden in &conflicting {
+        spans.push_span_label(hidden.span, format!("expected `{}`, got `{}`", expected, hidden.ty));
+    }
+    let mut err = tcx
+        .sess
+        .struct_span_err(spans, "concrete type differs from previous defining opaque type use");
+    for hidden in &agreeing {
+        err.span_note(
+            hidden.span,
+            &format!("this defining use implies the hidden type `{}`", expected),
+        );
+    }
+
+    let name = tcx.item_name(tcx.parent(def_id.to_def_id()).unwrap());
+    let highlighted = format!("highlighted use{}", pluralize!(conflicting.len()));
+    let is_tie = candidates.iter().any(|&(ty, count)| ty != expected && count == expected_count);
+    if is_tie {
+        err.help(&format!(
+            "either change the {} to imply `{}`, or the other use{} of `{}` to agree with {}",
+            highlighted,
+            expected,
+            pluralize!(agreeing.len()),
+            name,
+            if conflicting.len() == 1 { "it" } else { "them" },
+        ));
+    } else {
+        err.help(&format!(
+            "change the {} to imply `{}`, like the other defining uses of `{}`",
+            highlighted, expected, name,
+        ));
     }
+    err.emit();
+    expected
 }
 
 fn infer_placeholder_type<'a>(
@@ -4,6 +4,7 @@
 
 #[derive(SessionDiagnostic)]
 #[error = "E0062"]
+#[slug = "typeck-field-multiply-specified-in-initializer"]
 pub struct FieldMultiplySpecifiedInInitializer {
     #[message = "field `{ident}` specified more than once"]
     #[label = "used more than once"]
@@ -15,6 +16,7 @@ pub struct FieldMultiplySpecifiedInInitializer {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0092"]
+#[slug = "typeck-unrecognized-atomic-operation"]
 pub struct UnrecognizedAtomicOperation<'a> {
     #[message = "unrecognized atomic operation function: `{op}`"]
     #[label = "unrecognized atomic operation"]
@@ -24,6 +26,7 @@ pub struct UnrecognizedAtomicOperation<'a> {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0094"]
+#[slug = "typeck-wrong-number-of-generic-arguments-to-intrinsic"]
 pub struct WrongNumberOfGenericArgumentsToIntrinsic<'a> {
     #[message = "intrinsic has wrong number of {descr} \
                          parameters: found {found}, expected {expected}"]
@@ -37,6 +40,7 @@ pub struct WrongNumberOfGenericArgumentsToIntrinsic<'a> {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0093"]
+#[slug = "typeck-unrecognized-intrinsic-function"]
 pub struct UnrecognizedIntrinsicFunction {
     #[message = "unrecognized intrinsic function: `{name}`"]
     #[label = "unrecognized intrinsic"]
@@ -46,6 +50,7 @@ pub struct UnrecognizedIntrinsicFunction {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0195"]
+#[slug = "typeck-lifetimes-or-bounds-mismatch-on-trait"]
 pub struct LifetimesOrBoundsMismatchOnTrait {
     #[message = "lifetime parameters or bounds on {item_kind} `{ident}` do not match the trait declaration"]
     #[label = "lifetimes do not match {item_kind} in trait"]
@@ -58,6 +63,7 @@ pub struct LifetimesOrBoundsMismatchOnTrait {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0120"]
+#[slug = "typeck-drop-impl-on-wrong-item"]
 pub struct DropImplOnWrongItem {
     #[message = "the `Drop` trait may only be implemented for structs, enums, and unions"]
     #[label = "must be a struct, enum, or union"]
@@ -66,6 +72,7 @@ pub struct DropImplOnWrongItem {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0124"]
+#[slug = "typeck-field-already-declared"]
 pub struct FieldAlreadyDeclared {
     pub field_name: Ident,
     #[message = "field `{field_name}` is already declared"]
@@ -75,8 +82,18 @@ pub struct FieldAlreadyDeclared {
     pub prev_span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[error = "E0132"]
+#[slug = "typeck-start-function-with-type-parameters"]
+pub struct StartFunctionWithTypeParameters {
+    #[message = "start function is not allowed to have type parameters"]
+    #[label = "start function cannot have type parameters"]
+    pub span: Span,
+}
+
 #[derive(SessionDiagnostic)]
 #[error = "E0184"]
+#[slug = "typeck-copy-impl-on-type-with-dtor"]
 pub struct CopyImplOnTypeWithDtor {
     #[message = "the trait `Copy` may not be implemented for this type; the \
                               type has a destructor"]
@@ -84,8 +101,28 @@ pub struct CopyImplOnTypeWithDtor {
     pub span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[error = "E0322"]
+#[slug = "typeck-explicit-impl-of-builtin-trait"]
+pub struct ExplicitImplOfBuiltinTrait {
+    #[message = "explicit impls for the `{trait_name}` trait are not permitted"]
+    #[label = "impl of '{trait_name}' not allowed"]
+    pub span: Span,
+    pub trait_name: &'static str,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0328"]
+#[slug = "typeck-explicit-impl-of-unsize"]
+pub struct ExplicitImplOfUnsize {
+    #[message = "explicit impls for the `Unsize` trait are not permitted"]
+    #[label = "impl of `Unsize` not allowed"]
+    pub span: Span,
+}
+
 #[derive(SessionDiagnostic)]
 #[error = "E0203"]
+#[slug = "typeck-multiple-relaxed-default-bounds"]
 pub struct MultipleRelaxedDefaultBounds {
     #[message = "type parameter has more than one relaxed default bound, only one is supported"]
     pub span: Span,
@@ -93,6 +130,7 @@ pub struct MultipleRelaxedDefaultBounds {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0206"]
+#[slug = "typeck-copy-impl-on-non-adt"]
 pub struct CopyImplOnNonAdt {
     #[message = "the trait `Copy` may not be implemented for this type"]
     #[label = "type is not a structure or enumeration"]
@@ -101,6 +139,7 @@ pub struct CopyImplOnNonAdt {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0224"]
+#[slug = "typeck-trait-object-declared-with-no-traits"]
 pub struct TraitObjectDeclaredWithNoTraits {
     #[message = "at least one trait is required for an object type"]
     pub span: Span,
@@ -108,6 +147,7 @@ pub struct TraitObjectDeclaredWithNoTraits {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0227"]
+#[slug = "typeck-ambiguous-lifetime-bound"]
 pub struct AmbiguousLifetimeBound {
     #[message = "ambiguous lifetime bound, explicit lifetime bound required"]
     pub span: Span,
@@ -115,6 +155,7 @@ pub struct AmbiguousLifetimeBound {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0229"]
+#[slug = "typeck-assoc-type-binding-not-allowed"]
 pub struct AssocTypeBindingNotAllowed {
     #[message = "associated type bindings are not allowed here"]
     #[label = "associated type not allowed here"]
@@ -123,6 +164,7 @@ pub struct AssocTypeBindingNotAllowed {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0436"]
+#[slug = "typeck-functional-record-update-on-non-struct"]
 pub struct FunctionalRecordUpdateOnNonStruct {
     #[message = "functional record update syntax requires a struct"]
     pub span: Span,
@@ -130,6 +172,7 @@ pub struct FunctionalRecordUpdateOnNonStruct {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0516"]
+#[slug = "typeck-typeof-reserved-keyword-used"]
 pub struct TypeofReservedKeywordUsed {
     #[message = "`typeof` is a reserved keyword but unimplemented"]
     #[label = "reserved keyword"]
@@ -138,6 +181,7 @@ pub struct TypeofReservedKeywordUsed {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0572"]
+#[slug = "typeck-return-stmt-outside-of-fn-body"]
 pub struct ReturnStmtOutsideOfFnBody {
     #[message = "return statement outside of function body"]
     pub span: Span,
@@ -149,6 +193,7 @@ pub struct ReturnStmtOutsideOfFnBody {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0627"]
+#[slug = "typeck-yield-expr-outside-of-generator"]
 pub struct YieldExprOutsideOfGenerator {
     #[message = "yield expression outside of generator literal"]
     pub span: Span,
@@ -156,21 +201,41 @@ pub struct YieldExprOutsideOfGenerator {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0639"]
+#[slug = "typeck-struct-expr-non-exhaustive"]
 pub struct StructExprNonExhaustive {
     #[message = "cannot create non-exhaustive {what} using struct expression"]
     pub span: Span,
     pub what: &'static str,
 }
 
+#[derive(SessionDiagnostic)]
+#[error = "E0647"]
+#[slug = "typeck-start-function-with-where-clause"]
+pub struct StartFunctionWithWhereClause {
+    #[message = "start function is not allowed to have a `where` clause"]
+    #[label = "start function cannot have a `where` clause"]
+    pub span: Span,
+}
+
 #[derive(SessionDiagnostic)]
 #[error = "E0699"]
+#[slug = "typeck-method-call-on-unknown-type"]
 pub struct MethodCallOnUnknownType {
     #[message = "the type of this value must be known to call a method on a raw pointer on it"]
     pub span: Span,
 }
 
+#[derive(SessionDiagnostic)]
+#[error = "E0715"]
+#[slug = "typeck-marker-trait-impl-with-items"]
+pub struct MarkerTraitImplWithItems {
+    #[message = "impls for marker traits cannot contain items"]
+    pub span: Span,
+}
+
 #[derive(SessionDiagnostic)]
 #[error = "E0719"]
+#[slug = "typeck-value-of-associated-struct-already-specified"]
 pub struct ValueOfAssociatedStructAlreadySpecified {
     #[message = "the value of the associated type `{item_name}` (from trait `{def_path}`) is already specified"]
     #[label = "re-bound here"]
@@ -183,8 +248,195 @@ pub struct ValueOfAssociatedStructAlreadySpecified {
 
 #[derive(SessionDiagnostic)]
 #[error = "E0745"]
+#[slug = "typeck-address-of-temporary-taken"]
 pub struct AddressOfTemporaryTaken {
     #[message = "cannot take address of a temporary"]
     #[label = "temporary value"]
     pub span: Span,
 }
+
+#[derive(SessionDiagnostic)]
+#[error = "E0752"]
+#[slug = "typeck-start-function-is-async"]
+pub struct StartFunctionIsAsync {
+    #[message = "`start` is not allowed to be `async`"]
+    #[label = "`start` is not allowed to be `async`"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0785"]
+#[slug = "typeck-inherent-impl-on-dyn-auto-trait"]
+#[note = "define and implement a new trait or type instead"]
+pub struct InherentImplOnDynAutoTrait {
+    #[message = "cannot define inherent `impl` for a dyn auto trait"]
+    #[label = "impl requires at least one non-auto trait"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0788"]
+#[slug = "typeck-track-caller-on-main"]
+pub struct TrackCallerOnMain {
+    #[message = "`main` function is not allowed to be `#[track_caller]`"]
+    pub span: Span,
+    #[label = "`main` function is not allowed to be `#[track_caller]`"]
+    pub main_span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0788"]
+#[slug = "typeck-track-caller-on-start"]
+pub struct TrackCallerOnStart {
+    #[message = "`start` is not allowed to be `#[track_caller]`"]
+    pub span: Span,
+    #[label = "`start` is not allowed to be `#[track_caller]`"]
+    pub start_span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0724"]
+#[slug = "typeck-ffi-returns-twice-on-non-foreign-fn"]
+pub struct FfiReturnsTwiceOnNonForeignFn {
+    #[message = "`#[ffi_returns_twice]` may only be used on foreign functions"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0757"]
+#[slug = "typeck-ffi-const-and-ffi-pure"]
+pub struct FfiConstAndFfiPure {
+    #[message = "`#[ffi_const]` function cannot be `#[ffi_pure]`"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0755"]
+#[slug = "typeck-ffi-pure-on-non-foreign-fn"]
+pub struct FfiPureOnNonForeignFn {
+    #[message = "`#[ffi_pure]` may only be used on foreign functions"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0756"]
+#[slug = "typeck-ffi-const-on-non-foreign-fn"]
+pub struct FfiConstOnNonForeignFn {
+    #[message = "`#[ffi_const]` may only be used on foreign functions"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0776"]
+#[slug = "typeck-cmse-nonsecure-entry-requires-c-abi"]
+pub struct CmseNonsecureEntryRequiresCAbi {
+    #[message = "`#[cmse_nonsecure_entry]` requires C ABI"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0775"]
+#[slug = "typeck-cmse-nonsecure-entry-requires-trustzone-m"]
+pub struct CmseNonsecureEntryRequiresTrustzoneM {
+    #[message = "`#[cmse_nonsecure_entry]` is only valid for targets with the TrustZone-M \
+                 extension"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0737"]
+#[slug = "typeck-track-caller-requires-rust-abi"]
+pub struct TrackCallerRequiresRustAbi {
+    #[message = "`#[track_caller]` requires Rust ABI"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0779"]
+#[slug = "typeck-instruction-set-unsupported-on-target"]
+pub struct InstructionSetUnsupportedOnTarget {
+    #[message = "target does not support `#[instruction_set]`"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0779"]
+#[slug = "typeck-invalid-instruction-set"]
+pub struct InvalidInstructionSet {
+    #[message = "invalid instruction set specified"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0779"]
+#[slug = "typeck-multiple-instruction-sets"]
+pub struct MultipleInstructionSets {
+    #[message = "cannot specify more than one instruction set"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0778"]
+#[slug = "typeck-instruction-set-requires-argument"]
+pub struct InstructionSetRequiresArgument {
+    #[message = "`#[instruction_set]` requires an argument"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0778"]
+#[slug = "typeck-instruction-set-missing"]
+pub struct InstructionSetMissing {
+    #[message = "must specify an instruction set"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0534"]
+#[slug = "typeck-inline-expected-one-argument"]
+pub struct InlineExpectedOneArgument {
+    #[message = "expected one argument"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0535"]
+#[slug = "typeck-inline-invalid-argument"]
+pub struct InlineInvalidArgument {
+    #[message = "invalid argument"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0075"]
+#[slug = "typeck-simd-vector-empty"]
+pub struct SimdVectorEmpty {
+    #[message = "SIMD vector cannot be empty"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0076"]
+#[slug = "typeck-simd-vector-not-homogeneous"]
+pub struct SimdVectorNotHomogeneous {
+    #[message = "SIMD vector should be homogeneous"]
+    #[label = "SIMD elements must have the same type"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0587"]
+#[slug = "typeck-conflicting-packed-and-align-hints"]
+pub struct ConflictingPackedAndAlignHints {
+    #[message = "type has conflicting packed and align representation hints"]
+    pub span: Span,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0793"]
+#[slug = "typeck-invalid-no-sanitize-argument"]
+#[note = "expected one of: `address`, `hwaddress`, `memory` or `thread`"]
+pub struct InvalidNoSanitizeArgument {
+    #[message = "invalid argument for `no_sanitize`"]
+    pub span: Span,
+}
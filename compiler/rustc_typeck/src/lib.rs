@@ -95,6 +95,10 @@
 mod structured_errors;
 mod variance;
 
+use crate::errors::{
+    StartFunctionIsAsync, StartFunctionWithTypeParameters, StartFunctionWithWhereClause,
+    TrackCallerOnMain, TrackCallerOnStart,
+};
 use rustc_errors::{struct_span_err, ErrorReported};
 use rustc_hir as hir;
 use rustc_hir::def_id::DefId;
@@ -296,13 +300,7 @@ fn main_fn_return_type_span(tcx: TyCtxt<'_>, def_id: DefId) -> Option<Span> {
 
     for attr in tcx.get_attrs(main_def_id) {
         if attr.has_name(sym::track_caller) {
-            tcx.sess
-                .struct_span_err(
-                    attr.span,
-                    "`main` function is not allowed to be `#[track_caller]`",
-                )
-                .span_label(main_span, "`main` function is not allowed to be `#[track_caller]`")
-                .emit();
+            tcx.sess.emit_err(TrackCallerOnMain { span: attr.span, main_span });
             error = true;
         }
     }
@@ -393,53 +391,23 @@ fn check_start_fn_ty(tcx: TyCtxt<'_>, start_def_id: DefId) {
                 if let hir::ItemKind::Fn(ref sig, ref generics, _) = it.kind {
                     let mut error = false;
                     if !generics.params.is_empty() {
-                        struct_span_err!(
-                            tcx.sess,
-                            generics.span,
-                            E0132,
-                            "start function is not allowed to have type parameters"
-                        )
-                        .span_label(generics.span, "start function cannot have type parameters")
-                        .emit();
+                        tcx.sess.emit_err(StartFunctionWithTypeParameters { span: generics.span });
                         error = true;
                     }
                     if let Some(sp) = generics.where_clause.span() {
-                        struct_span_err!(
-                            tcx.sess,
-                            sp,
-                            E0647,
-                            "start function is not allowed to have a `where` clause"
-                        )
-                        .span_label(sp, "start function cannot have a `where` clause")
-                        .emit();
+                        tcx.sess.emit_err(StartFunctionWithWhereClause { span: sp });
                         error = true;
                     }
                     if let hir::IsAsync::Async = sig.header.asyncness {
                         let span = tcx.sess.source_map().guess_head_span(it.span);
-                        struct_span_err!(
-                            tcx.sess,
-                            span,
-                            E0752,
-                            "`start` is not allowed to be `async`"
-                        )
-                        .span_label(span, "`start` is not allowed to be `async`")
-                        .emit();
+                        tcx.sess.emit_err(StartFunctionIsAsync { span });
                         error = true;
                     }
 
                     let attrs = tcx.hir().attrs(start_id);
                     for attr in attrs {
                         if attr.has_name(sym::track_caller) {
-                            tcx.sess
-                                .struct_span_err(
-                                    attr.span,
-                                    "`start` is not allowed to be `#[track_caller]`",
-                                )
-                                .span_label(
-                                    start_span,
-                                    "`start` is not allowed to be `#[track_caller]`",
-                                )
-                                .emit();
+                            tcx.sess.emit_err(TrackCallerOnStart { span: attr.span, start_span });
                             error = true;
                         }
                     }
@@ -57,6 +57,8 @@
 use crate::marker::DiscriminantKind;
 use crate::mem;
 
+pub mod mir;
+
 // These imports are used for simplifying intra-doc links
 #[allow(unused_imports)]
 #[cfg(all(target_has_atomic = "8", target_has_atomic = "32", target_has_atomic = "ptr"))]
@@ -1939,6 +1941,22 @@ pub unsafe fn drop_in_place<T: ?Sized>(to_drop: *mut T) {
     #[cfg(not(bootstrap))]
     pub fn const_deallocate(ptr: *mut u8, size: usize, align: usize);
 
+    /// Makes a memory allocated by `intrinsics::const_allocate` at compile time immutable, so that
+    /// it can be part of the final value of the constant, and returns the pointer to it.
+    /// At runtime, just returns `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// - The `ptr` must point to the beginning of a memory allocated by
+    ///   `intrinsics::const_allocate` in the same constant, which was neither deallocated nor
+    ///   already made global.
+    ///    - At compile time, a compile error occurs if this constraint is violated.
+    ///    - At runtime, it is not checked.
+    /// - The memory must not be written to or deallocated afterwards.
+    #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
+    #[cfg(not(bootstrap))]
+    pub fn const_make_global(ptr: *mut u8) -> *const u8;
+
     /// Determines whether the raw bytes of the two values are equal.
     ///
     /// This is particularly handy for arrays, since it allows things like just
@@ -0,0 +1,174 @@
+//! Rustc internal tooling for hand-writing MIR.
+//!
+//! If for some reasons you are not writing rustc tests and have found yourself considering using
+//! this feature, turn back. This is *exceptionally* unstable. There is no attempt at all to make
+//! anything work besides those things which the rustc test suite happened to need. If you make a
+//! typo you'll probably ICE. Really, this is not the solution to your problems. Consider instead
+//! supporting the [stable MIR project group](https://github.com/rust-lang/project-stable-mir).
+//!
+//! The documentation for this module describes how to use this feature. If you are interested in
+//! hacking on the implementation, most of that documentation lives at
+//! `rustc_mir_build/src/build/custom/mod.rs`.
+//!
+//! Typical usage will look like this:
+//!
+//! ```rust
+//! #![feature(core_intrinsics, custom_mir)]
+//!
+//! extern crate core;
+//! use core::intrinsics::mir::*;
+//!
+//! #[custom_mir(dialect = "built")]
+//! pub fn simple(x: i32) -> i32 {
+//!     mir!(
+//!         let temp1: i32;
+//!         let temp2: _;
+//!
+//!         {
+//!             temp1 = x;
+//!             Goto(exit)
+//!         }
+//!
+//!         exit = {
+//!             temp2 = Move(temp1);
+//!             RET = temp2;
+//!             Return()
+//!         }
+//!     )
+//! }
+//! ```
+//!
+//! Hopefully most of this is fairly self-explanatory. Expanding on some notable details:
+//!
+//!  - The `custom_mir` attribute tells the compiler to treat the function as being custom MIR. This
+//!    attribute only works on functions - there is no way to insert custom MIR into the middle of
+//!    another function.
+//!  - The `dialect` and `phase` parameters indicate which version of MIR you are inserting here.
+//!    This will normally be the phase that corresponds to the thing you are trying to test. The
+//!    phase can be omitted for dialects that have just one.
+//!  - You should define your function signature like you normally would. Externally, this function
+//!    can be called like any other function.
+//!  - Type inference works - you don't have to spell out the type of all of your locals.
+//!
+//! For now, all statements and terminators are parsed from nested invocations of the special
+//! functions provided in this module. We additionally want to (but do not yet) support more
+//! "normal" Rust syntax in places where it makes sense. Also, most kinds of instructions are not
+//! supported yet.
+//!
+//! # Dialects and phases
+//!
+//! - `#[custom_mir(dialect = "built")]` is MIR as it comes out of MIR building. All passes run on
+//!   it, including the borrow checker.
+//! - `#[custom_mir(dialect = "analysis")]` is MIR after the promotion of constants, as seen by the
+//!   borrow checker. The MIR building passes and promotion are skipped, but the borrow checker
+//!   still runs.
+//! - `#[custom_mir(dialect = "runtime")]` is MIR after drop elaboration, in which drops are
+//!   explicit and aggregates other than arrays are not allowed. Neither the borrow checker nor any
+//!   pass before drop elaboration runs on it. With `phase = "optimized"`, none of the optimizations
+//!   run either, and the MIR is used for codegen as it was written.
+//!
+//! # Syntax
+//!
+//! The `mir!` macro takes the declarations of the locals, the entry block, and the other basic
+//! blocks, in that order:
+//!
+//!  - Locals are declared with `let name: Type;`, where the type may be omitted. The arguments of
+//!    the function and the return place `RET` are always in scope.
+//!  - The entry block is written as a `{ ... }` block, and all other blocks as `name = { ... }`.
+//!  - The body of a block is a sequence of statements, followed by a terminator.
+//!
+//! The supported statements are:
+//!
+//!  - Assignments `place = rvalue;`, where the rvalue is a `&`, `&mut`, `&raw const` or `&raw mut`
+//!    borrow of a place, a binary or unary operation, an `as` cast, a tuple or array expression, a
+//!    [`Checked`] binary operation, a [`Discriminant`], or an operand.
+//!  - [`Retag`], [`StorageLive`] and [`StorageDead`].
+//!
+//! The supported terminators are [`Return`], [`Goto`], [`Unreachable`], [`Drop`], [`Call`], and
+//! `match` expressions with integer patterns, which must end with a `_` arm and are lowered to
+//! `SwitchInt`.
+//!
+//! Operands are constants, which includes function items, copies of places, or moves of places
+//! written with [`Move`]. Places are locals, optionally followed by field accesses, dereferences
+//! and indexing by a local.
+
+#![unstable(
+    feature = "custom_mir",
+    reason = "MIR is an implementation detail and extremely unstable",
+    issue = "none"
+)]
+#![allow(unused_variables, non_snake_case, missing_debug_implementations)]
+
+/// Type representing basic blocks.
+///
+/// All terminators will have this type as a return type. It helps achieve some type safety.
+pub struct BasicBlock;
+
+macro_rules! define {
+    ($name:literal, $($sig:tt)*) => {
+        #[rustc_diagnostic_item = $name]
+        pub $($sig)* { panic!() }
+    }
+}
+
+define!("mir_return", fn Return() -> BasicBlock);
+define!("mir_goto", fn Goto(destination: BasicBlock) -> BasicBlock);
+define!("mir_unreachable", fn Unreachable() -> BasicBlock);
+define!("mir_drop", fn Drop<T>(place: T, goto: BasicBlock) -> BasicBlock);
+define!("mir_call", fn Call<T>(place: T, goto: BasicBlock, call: T) -> BasicBlock);
+define!("mir_retag", fn Retag<T>(place: T));
+define!("mir_storage_live", fn StorageLive<T>(local: T));
+define!("mir_storage_dead", fn StorageDead<T>(local: T));
+define!("mir_move", fn Move<T>(place: T) -> T);
+define!("mir_checked", fn Checked<T>(binop: T) -> (T, bool));
+define!(
+    "mir_discriminant",
+    fn Discriminant<T>(place: T) -> <T as crate::marker::DiscriminantKind>::Discriminant
+);
+
+/// Convenience macro for generating custom MIR.
+///
+/// See the module documentation for syntax details. This macro is not magic - it only transforms
+/// your MIR into something that is easier to parse in the compiler.
+#[rustc_macro_transparency = "transparent"]
+pub macro mir {
+    (
+        $(let $local_decl:ident $(: $local_decl_ty:ty)? ;)*
+
+        {
+            $($entry:tt)*
+        }
+
+        $(
+            $block_name:ident = {
+                $($block:tt)*
+            }
+        )*
+    ) => {{
+        // First, we declare all basic blocks.
+        $(
+            let $block_name: ::core::intrinsics::mir::BasicBlock;
+        )*
+
+        {
+            // Now all locals
+            #[allow(non_snake_case)]
+            let RET;
+            $(
+                let $local_decl $(: $local_decl_ty)? ;
+            )*
+
+            {
+                // Finally, the contents of the basic blocks
+                $($entry)*;
+                $(
+                    $block_name = {
+                        $($block)*
+                    };
+                )*
+
+                RET
+            }
+        }
+    }}
+}
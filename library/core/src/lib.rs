@@ -157,7 +157,6 @@
 #![feature(auto_traits)]
 #![cfg_attr(bootstrap, feature(cfg_target_has_atomic))]
 #![cfg_attr(not(bootstrap), feature(cfg_target_has_atomic_equal_alignment))]
-#![feature(const_fn_floating_point_arithmetic)]
 #![feature(const_fn_fn_ptr_basics)]
 #![feature(const_fn_trait_bound)]
 #![feature(const_impl_trait)]
@@ -1519,6 +1519,20 @@ macro_rules! trace_macros {
         /* compiler built-in */
     }
 
+    /// Ascribes the type `$ty` to the expression `$expr`.
+    ///
+    /// This is the same as the unstable `$expr: $ty` syntax: `$expr` is
+    /// type-checked with `$ty` as its expected type, rather than cast to it.
+    #[unstable(
+        feature = "type_ascription",
+        issue = "23416",
+        reason = "placeholder syntax for type ascription"
+    )]
+    #[rustc_builtin_macro]
+    pub macro type_ascribe($expr:expr, $ty:ty) {
+        /* compiler built-in */
+    }
+
     /// Unstable implementation detail of the `rustc` compiler, do not use.
     #[rustc_builtin_macro]
     #[stable(feature = "rust1", since = "1.0.0")]
@@ -982,6 +982,14 @@ mod prim_tuple {}
 ///   it is considered infectious as almost all calculations where one of the
 ///   operands is NaN will also result in NaN.
 ///
+/// The sign and payload bits of a NaN produced by an arithmetic operation are not specified, and
+/// can differ between targets. Operations evaluated at compile time, for example in the
+/// initializer of a `const` or in a `const fn` called from one, give the same result on every
+/// target: when their result is NaN, it is always [`f32::NAN`], the quiet NaN with a positive sign
+/// and no payload. Code that depends on the bit pattern of NaN results can therefore see
+/// different values at compile time and at runtime. Negation and [`f32::from_bits`] are not
+/// arithmetic, and keep the bit patterns of NaNs exactly.
+///
 /// For more information on floating point numbers, see [Wikipedia][wikipedia].
 ///
 /// *[See also the `std::f32::consts` module](crate::f32::consts).*
@@ -63,6 +63,25 @@
 //! separate allocated object), heap allocations (each allocation created by the global allocator is
 //! a separate allocated object), and `static` variables.
 //!
+//! ## Strict provenance
+//!
+//! A pointer is more than an address: it also has a *provenance*, which determines the allocated
+//! object that it may access. Casting a pointer to an integer with `as` *exposes* its provenance,
+//! and casting an integer to a pointer with `as` guesses which exposed provenance it should get,
+//! which makes it hard to reason about which accesses are allowed.
+//!
+//! The Strict Provenance experiment replaces these casts with operations that say what happens
+//! to the provenance:
+//!
+//! * [`addr`](pointer::addr) gets the address of a pointer and discards its provenance,
+//! * [`with_addr`](pointer::with_addr) and [`map_addr`](pointer::map_addr) create a pointer with
+//!   a new address and the provenance of an existing pointer,
+//! * [`expose_addr`](pointer::expose_addr) and [`from_exposed_addr`] are the `as` casts, for the
+//!   code that can't keep the pointers it converts to integers.
+//!
+//! The `fuzzy_provenance_casts` and `lossy_provenance_casts` lints point out the `as` casts
+//! between pointers and integers.
+//!
 //! [aliasing]: ../../nomicon/aliasing.html
 //! [book]: ../../book/ch19-01-unsafe-rust.html#dereferencing-a-raw-pointer
 //! [ub]: ../../reference/behavior-considered-undefined.html
@@ -233,6 +252,42 @@ pub const fn null_mut<T>() -> *mut T {
     0 as *mut T
 }
 
+/// Converts an address back to a pointer, picking up a previously exposed provenance.
+///
+/// This is equivalent to `addr as *const T`. The provenance of the returned pointer is that of
+/// a pointer whose address was exposed with [`expose_addr`](pointer::expose_addr), if there is
+/// one that makes the accesses through the returned pointer valid.
+///
+/// This API is part of the Strict Provenance experiment, see the
+/// [module documentation][crate::ptr#strict-provenance] for details.
+#[must_use]
+#[inline]
+#[unstable(feature = "strict_provenance", issue = "95228")]
+pub fn from_exposed_addr<T>(addr: usize) -> *const T
+where
+    T: Sized,
+{
+    addr as *const T
+}
+
+/// Converts an address back to a mutable pointer, picking up a previously exposed provenance.
+///
+/// This is equivalent to `addr as *mut T`. The provenance of the returned pointer is that of
+/// a pointer whose address was exposed with [`expose_addr`](pointer::expose_addr), if there is
+/// one that makes the accesses through the returned pointer valid.
+///
+/// This API is part of the Strict Provenance experiment, see the
+/// [module documentation][crate::ptr#strict-provenance] for details.
+#[must_use]
+#[inline]
+#[unstable(feature = "strict_provenance", issue = "95228")]
+pub fn from_exposed_addr_mut<T>(addr: usize) -> *mut T
+where
+    T: Sized,
+{
+    addr as *mut T
+}
+
 /// Forms a raw slice from a pointer and a length.
 ///
 /// The `len` argument is the number of **elements**, not the number of bytes.
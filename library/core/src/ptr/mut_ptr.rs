@@ -110,6 +110,91 @@ pub fn from_bits(bits: usize) -> Self
         bits as Self
     }
 
+    /// Gets the address of a pointer, without exposing its provenance.
+    ///
+    /// This is equivalent to `self as usize`, except that the provenance of the pointer is
+    /// discarded instead of being exposed: the address can't be cast back to a pointer that is
+    /// valid to access memory with. Use [`with_addr`](#method.with_addr-1) or
+    /// [`map_addr`](#method.map_addr-1) on a pointer to the same allocated object instead.
+    ///
+    /// This API is part of the Strict Provenance experiment, see the
+    /// [module documentation][crate::ptr#strict-provenance] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(strict_provenance)]
+    /// let mut array = [13, 42];
+    /// let p0: *mut i32 = &mut array[0];
+    /// let p1 = p0.with_addr(p0.addr() + 4);
+    /// unsafe { *p1 = 7 };
+    /// assert_eq!(array, [13, 7]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn addr(self) -> usize
+    where
+        T: Sized,
+    {
+        self as usize
+    }
+
+    /// Gets the address of a pointer, and exposes its provenance so that
+    /// [`from_exposed_addr_mut`](crate::ptr::from_exposed_addr_mut) can recover it.
+    ///
+    /// This is equivalent to `self as usize`, and is meant for code that can't keep the pointers
+    /// it converts to integers, like code that passes addresses through an FFI boundary.
+    ///
+    /// This API is part of the Strict Provenance experiment, see the
+    /// [module documentation][crate::ptr#strict-provenance] for details.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn expose_addr(self) -> usize
+    where
+        T: Sized,
+    {
+        self as usize
+    }
+
+    /// Creates a pointer with the given address and the provenance of `self`.
+    ///
+    /// This replaces an `addr as *mut _` cast: it is equivalent to offsetting
+    /// `self` to `addr` with [`wrapping_offset`](#method.wrapping_offset-1), and has the same
+    /// capabilities and restrictions.
+    ///
+    /// This API is part of the Strict Provenance experiment, see the
+    /// [module documentation][crate::ptr#strict-provenance] for details.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn with_addr(self, addr: usize) -> Self
+    where
+        T: Sized,
+    {
+        let offset = (addr as isize).wrapping_sub(self.addr() as isize);
+        self.cast::<u8>().wrapping_offset(offset).cast::<T>()
+    }
+
+    /// Creates a pointer by mapping the address of `self` to a new one, with the provenance of
+    /// `self`.
+    ///
+    /// This is a convenience for [`with_addr`](#method.with_addr-1), for operations like tagging
+    /// the low bits of an aligned pointer.
+    ///
+    /// This API is part of the Strict Provenance experiment, see the
+    /// [module documentation][crate::ptr#strict-provenance] for details.
+    #[must_use]
+    #[inline]
+    #[unstable(feature = "strict_provenance", issue = "95228")]
+    pub fn map_addr(self, f: impl FnOnce(usize) -> usize) -> Self
+    where
+        T: Sized,
+    {
+        self.with_addr(f(self.addr()))
+    }
+
     /// Decompose a (possibly wide) pointer into its address and metadata components.
     ///
     /// The pointer can be later reconstructed with [`from_raw_parts_mut`].
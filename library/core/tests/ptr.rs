@@ -306,6 +306,26 @@ pub fn test_variadic_fnptr() {
     assert_eq!(p.hash(&mut s), q.hash(&mut s));
 }
 
+#[test]
+fn strict_provenance_addr() {
+    let mut xs = [1u64, 2, 3];
+    let p0 = xs.as_mut_ptr();
+    let p2 = p0.with_addr(p0.addr() + 2 * core::mem::size_of::<u64>());
+    assert_eq!(p2, p0.wrapping_add(2));
+    unsafe { *p2 = 4 };
+    assert_eq!(xs, [1, 2, 4]);
+
+    // Tag the low bit of an aligned pointer, and untag it again.
+    let p: *const u64 = &xs[1];
+    let tagged = p.map_addr(|addr| addr | 1);
+    assert_eq!(tagged.addr() & 1, 1);
+    let untagged = tagged.map_addr(|addr| addr & !1);
+    assert_eq!(unsafe { *untagged }, 2);
+
+    let exposed = p.expose_addr();
+    assert_eq!(from_exposed_addr::<u64>(exposed), p);
+}
+
 #[test]
 fn write_unaligned_drop() {
     thread_local! {
@@ -67,6 +67,16 @@
     pub(crate) fn simd_gather<T, U, V>(val: T, ptr: U, mask: V) -> T;
     pub(crate) fn simd_scatter<T, U, V>(val: T, ptr: U, mask: V);
 
+    // llvm.masked.load and llvm.masked.store, with `ptr` pointing to the first element
+    #[allow(unused)]
+    pub(crate) fn simd_masked_load<M, P, T>(mask: M, ptr: P, val: T) -> T;
+    #[allow(unused)]
+    pub(crate) fn simd_masked_store<M, P, T>(mask: M, ptr: P, val: T);
+
+    // lanes of `val` selected by the lanes of `idx`, or zero for the indices that are out of range
+    #[allow(unused)]
+    pub(crate) fn simd_swizzle_dyn<T, U>(val: T, idx: U) -> T;
+
     // {s,u}add.sat
     pub(crate) fn simd_saturating_add<T>(x: T, y: T) -> T;
 
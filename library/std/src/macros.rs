@@ -312,6 +312,23 @@ macro_rules! dbg {
     };
 }
 
+/// Compiles a function once for each of the given sets of target features, and calls the first
+/// version whose features the CPU supports, or the baseline version if there is none.
+///
+/// The features are detected at runtime with the `std::arch` macros, such as
+/// `is_x86_feature_detected`, so the target architecture must have one of them.
+#[unstable(
+    feature = "multiversion",
+    issue = "none",
+    reason = "`multiversion` is a recently implemented feature"
+)]
+#[allow_internal_unsafe]
+#[allow_internal_unstable(stdsimd)]
+#[rustc_builtin_macro]
+pub macro multiversion($item:item) {
+    /* compiler built-in */
+}
+
 #[cfg(test)]
 macro_rules! assert_approx_eq {
     ($a:expr, $b:expr) => {{
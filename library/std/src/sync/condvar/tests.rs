@@ -207,5 +207,5 @@ fn two_mutexes() {
     drop(g);
 
     let m = Mutex::new(());
-    let _ = c.wait(m.lock().unwrap()).unwrap();
+    let _g = c.wait(m.lock().unwrap()).unwrap();
 }
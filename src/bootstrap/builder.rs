@@ -595,6 +595,7 @@ macro_rules! describe {
                 dist::RustDemangler,
                 dist::Clippy,
                 dist::Miri,
+                dist::CodegenBackend,
                 dist::LlvmTools,
                 dist::RustDev,
                 dist::Extended,
@@ -616,6 +617,7 @@ macro_rules! describe {
                 install::RustDemangler,
                 install::Clippy,
                 install::Miri,
+                install::RustcCodegenCranelift,
                 install::Analysis,
                 install::Src,
                 install::Rustc
@@ -15,6 +15,7 @@
 use crate::Compiler;
 
 use crate::builder::{Builder, RunConfig, ShouldRun, Step};
+use crate::cache::INTERNER;
 use crate::config::{Config, TargetSelection};
 
 #[cfg(target_os = "illumos")]
@@ -194,6 +195,18 @@ fn run($sel, $builder: &Builder<'_>) {
             );
         }
     };
+    RustcCodegenCranelift, "rustc-codegen-cranelift", _config.rust_codegen_backends.contains(&INTERNER.intern_str("cranelift")), only_hosts: true, {
+        if let Some(tarball) = builder.ensure(dist::CodegenBackend {
+            compiler: builder.compiler(builder.top_stage, self.target),
+            backend: INTERNER.intern_str("cranelift"),
+        }) {
+            install_sh(builder, "rustc-codegen-cranelift", self.compiler.stage, Some(self.target), &tarball);
+        } else {
+            builder.info(
+                &format!("skipping Install rustc-codegen-cranelift stage{} ({})", self.compiler.stage, self.target),
+            );
+        }
+    };
     Rustfmt, "rustfmt", Self::should_build(_config), only_hosts: true, {
         if let Some(tarball) = builder.ensure(dist::Rustfmt {
             compiler: self.compiler,
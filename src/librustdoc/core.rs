@@ -2,6 +2,8 @@
 use rustc_data_structures::sync::{self, Lrc};
 use rustc_errors::emitter::{Emitter, EmitterWriter};
 use rustc_errors::json::JsonEmitter;
+use rustc_errors::sarif::SarifEmitter;
+use rustc_errors::short_json::ShortJsonEmitter;
 use rustc_feature::UnstableFeatures;
 use rustc_hir::def::Res;
 use rustc_hir::def_id::{DefId, DefIdMap, LocalDefId};
@@ -136,8 +138,9 @@ impl<'tcx> DocContext<'tcx> {
 
 /// Creates a new diagnostic `Handler` that can be used to emit warnings and errors.
 ///
-/// If the given `error_format` is `ErrorOutputType::Json` and no `SourceMap` is given, a new one
-/// will be created for the handler.
+/// If the given `error_format` is `ErrorOutputType::Json`, `ErrorOutputType::Sarif` or
+/// `ErrorOutputType::ShortJson` and no `SourceMap` is given, a new one will be created for the
+/// handler.
 crate fn new_handler(
     error_format: ErrorOutputType,
     source_map: Option<Lrc<source_map::SourceMap>>,
@@ -155,7 +158,8 @@ impl<'tcx> DocContext<'tcx> {
                     debugging_opts.terminal_width,
                     false,
                 )
-                .ui_testing(debugging_opts.ui_testing),
+                .ui_testing(debugging_opts.ui_testing)
+                .group_identical(short && debugging_opts.group_diagnostics),
             )
         }
         ErrorOutputType::Json { pretty, json_rendered } => {
@@ -174,6 +178,18 @@ impl<'tcx> DocContext<'tcx> {
                 .ui_testing(debugging_opts.ui_testing),
             )
         }
+        ErrorOutputType::Sarif => {
+            let source_map = source_map.unwrap_or_else(|| {
+                Lrc::new(source_map::SourceMap::new(source_map::FilePathMapping::empty()))
+            });
+            Box::new(SarifEmitter::stderr(None, source_map).ui_testing(debugging_opts.ui_testing))
+        }
+        ErrorOutputType::ShortJson => {
+            let source_map = source_map.unwrap_or_else(|| {
+                Lrc::new(source_map::SourceMap::new(source_map::FilePathMapping::empty()))
+            });
+            Box::new(ShortJsonEmitter::stderr(source_map))
+        }
     };
 
     rustc_errors::Handler::with_emitter_and_flags(
@@ -239,6 +255,7 @@ impl<'tcx> DocContext<'tcx> {
         target_triple: target,
         unstable_features: UnstableFeatures::from_environment(crate_name.as_deref()),
         actually_rustdoc: true,
+        diagnostic_width: debugging_opts.terminal_width,
         debugging_opts,
         error_format,
         edition,
@@ -1,12 +1,14 @@
-// Test that the correct module flags are emitted with different branch protection flags.
+// Test that the correct module flags and function attributes are emitted with different branch
+// protection flags.
 
-// revisions: bti pac-ret leaf b-key
+// revisions: bti pac-ret leaf b-key plus
 // min-llvm-version: 12.0.0
 // needs-llvm-components: aarch64
 // [bti] compile-flags: -Z branch-protection=bti
 // [pac-ret] compile-flags: -Z branch-protection=pac-ret
 // [leaf] compile-flags: -Z branch-protection=pac-ret,leaf
 // [b-key] compile-flags: -Z branch-protection=pac-ret,b-key
+// [plus] compile-flags: -Z branch-protection=bti+pac-ret+leaf+b-key
 // compile-flags: --target aarch64-unknown-linux-gnu
 
 #![crate_type = "lib"]
@@ -20,6 +22,12 @@ trait Sized { }
 pub fn test() {
 }
 
+// bti: attributes #0 = {{.*}}"branch-target-enforcement"="true"
+// pac-ret: attributes #0 = {{.*}}"sign-return-address"="non-leaf" "sign-return-address-key"="a_key"
+// leaf: attributes #0 = {{.*}}"sign-return-address"="all" "sign-return-address-key"="a_key"
+// b-key: attributes #0 = {{.*}}"sign-return-address"="non-leaf" "sign-return-address-key"="b_key"
+// plus: attributes #0 = {{.*}}"branch-target-enforcement"="true"{{.*}}"sign-return-address"="all" "sign-return-address-key"="b_key"
+
 // bti: !"branch-target-enforcement", i32 1
 // bti: !"sign-return-address", i32 0
 // bti: !"sign-return-address-all", i32 0
@@ -39,3 +47,8 @@ pub fn test() {
 // b-key: !"sign-return-address", i32 1
 // b-key: !"sign-return-address-all", i32 0
 // b-key: !"sign-return-address-with-bkey", i32 1
+
+// plus: !"branch-target-enforcement", i32 1
+// plus: !"sign-return-address", i32 1
+// plus: !"sign-return-address-all", i32 1
+// plus: !"sign-return-address-with-bkey", i32 1
@@ -0,0 +1,42 @@
+// Checks that `become` is lowered to a `musttail` call followed by a return of its result.
+// compile-flags: -C no-prepopulate-passes
+// ignore-wasm32
+
+#![crate_type = "lib"]
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+// CHECK-LABEL: @direct
+#[no_mangle]
+pub fn direct(x: u32) -> u32 {
+    // CHECK: [[RET:%.*]] = musttail call i32 @callee(i32 %{{.*}})
+    // CHECK-NEXT: ret i32 [[RET]]
+    become callee(x)
+}
+
+// CHECK-LABEL: @indirect
+#[no_mangle]
+pub fn indirect(x: u32) -> u32 {
+    let f: fn(u32) -> u32 = callee;
+    // CHECK: [[RET:%.*]] = musttail call i32 %{{.*}}(i32 %{{.*}})
+    // CHECK-NEXT: ret i32 [[RET]]
+    become f(x)
+}
+
+// CHECK-LABEL: @unit
+#[no_mangle]
+pub fn unit(x: u32) {
+    // CHECK: musttail call void @sink(i32 %{{.*}})
+    // CHECK-NEXT: ret void
+    become sink(x)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn callee(x: u32) -> u32 {
+    x + 1
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn sink(_: u32) {}
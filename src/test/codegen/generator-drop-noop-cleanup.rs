@@ -0,0 +1,25 @@
+// Check that once the drop shim of a generic generator is monomorphized, the drops of saved locals
+// which can unwind don't get a landing pad if the cleanup only drops values without drop glue.
+
+// compile-flags: -C panic=unwind -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+#![feature(generators, generator_trait)]
+
+use std::ops::Generator;
+
+fn generator<T>(x: T, s: String) -> impl Generator<Yield = (), Return = ()> {
+    move || {
+        let _x = x;
+        let _s = s;
+        yield;
+    }
+}
+
+// CHECK-LABEL: ; core::ptr::drop_in_place<generator_drop_noop_cleanup::generator{{.*}}>
+// CHECK-NOT: landingpad
+// CHECK: {{^[}]$}}
+pub fn drop_suspended(s: String) {
+    let mut generator = generator(0u32, s);
+    let _ = std::pin::Pin::new(&mut generator).resume(());
+}
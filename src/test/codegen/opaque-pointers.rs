@@ -0,0 +1,51 @@
+// Check that the IR we emit is valid when LLVM uses opaque pointers, which means that we must
+// never look at the pointee type of a pointer.
+//
+// min-llvm-version: 14.0
+// compile-flags: -C no-prepopulate-passes -C llvm-args=-opaque-pointers
+
+#![crate_type = "lib"]
+
+pub struct Pair {
+    a: u32,
+    b: u32,
+}
+
+pub static COUNTER: u32 = 0;
+
+// CHECK-LABEL: @load
+#[no_mangle]
+pub fn load(x: &u32) -> u32 {
+    // CHECK: load i32, ptr %x
+    *x
+}
+
+// CHECK-LABEL: @store
+#[no_mangle]
+pub fn store(x: &mut Pair) {
+    // CHECK: getelementptr inbounds %Pair, ptr %x
+    // CHECK: store i32 1, ptr
+    x.b = 1;
+}
+
+// CHECK-LABEL: @index
+#[no_mangle]
+pub fn index(x: &[u16; 4], i: usize) -> u16 {
+    // CHECK: getelementptr inbounds [4 x i16], ptr %x
+    // CHECK: load i16, ptr
+    x[i]
+}
+
+// CHECK-LABEL: @call_indirect
+#[no_mangle]
+pub fn call_indirect(f: fn(u32) -> u32) -> u32 {
+    // CHECK: call i32 %f(i32 7)
+    f(7)
+}
+
+// CHECK-LABEL: @read_static
+#[no_mangle]
+pub fn read_static() -> u32 {
+    // CHECK: load i32, ptr @{{.*}}COUNTER
+    COUNTER
+}
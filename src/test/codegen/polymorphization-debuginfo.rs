@@ -0,0 +1,21 @@
+// Check that a function with an unused generic parameter is only instantiated once when
+// polymorphized, under a symbol whose v0 mangling shows the parameter as a placeholder, and that
+// its debuginfo names the parameter instead of the types of any one of its instantiations.
+//
+// compile-flags: -Zpolymorphize=on -Cdebuginfo=2 -Copt-level=0 -Csymbol-mangling-version=v0
+
+#![crate_type = "lib"]
+
+// CHECK: define {{.*}}6unusedpE
+// CHECK-NOT: define {{.*}}6unused
+// CHECK-DAG: !DISubprogram(name: "unused<T>"
+// CHECK-DAG: !DITemplateTypeParameter(name: "T", type: ![[T:[0-9]+]])
+// CHECK-DAG: ![[T]] = !DIBasicType(name: "T"
+#[inline(never)]
+fn unused<T>() -> u32 {
+    42
+}
+
+pub fn caller() -> u32 {
+    unused::<u8>() + unused::<u16>()
+}
@@ -0,0 +1,26 @@
+// Verifies that no_sanitize only disables the sanitizers it names, and that
+// opting out of a sanitizer which isn't enabled has no effect on inlining.
+//
+// needs-sanitizer-thread
+// compile-flags: -Zsanitizer=thread -C opt-level=3 -Z mir-opt-level=4
+
+#![crate_type="lib"]
+#![feature(no_sanitize)]
+
+// CHECK-LABEL: define void @test
+// CHECK-NOT:     call
+// CHECK:       }
+#[no_mangle]
+pub fn test(n: &mut u32) {
+    no_asan_inline(n);
+}
+
+// CHECK: define void @no_asan_inline{{.*}}#[[ATTRS:[0-9]+]]
+#[no_sanitize(address)]
+#[inline]
+#[no_mangle]
+pub fn no_asan_inline(n: &mut u32) {
+    *n = 42;
+}
+
+// CHECK: attributes #[[ATTRS]] = {{.*}}sanitize_thread
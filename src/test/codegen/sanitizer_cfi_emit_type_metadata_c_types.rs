@@ -0,0 +1,35 @@
+// Verifies that type metadata identifiers for functions using types that have a C equivalent
+// are the ones Clang uses for the C function types.
+//
+// needs-sanitizer-cfi
+// only-x86_64
+// only-linux
+// compile-flags: -Clto -Cno-prepopulate-passes -Zsanitizer=cfi
+
+#![crate_type="lib"]
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn foo(f: extern "C" fn(*const u8, usize) -> i32, p: *const u8, n: usize) -> i32 {
+    // CHECK-LABEL: define{{.*}}foo
+    // CHECK:       call i1 @llvm.type.test(i8* %{{[0-9]+}}, metadata !"_ZTSFiPKhmE")
+    f(p, n)
+}
+
+pub fn bar(f: extern "C" fn(&Point, &mut Point, i64, u16), a: &Point, b: &mut Point) {
+    // CHECK-LABEL: define{{.*}}bar
+    // CHECK:       call i1 @llvm.type.test(i8* %{{[0-9]+}}, metadata !"_ZTSFvPK5PointPS_ltE")
+    f(a, b, 1, 2)
+}
+
+pub fn baz(f: unsafe extern "C" fn(i8, u64, f32, f64, bool), g: fn(char, &str)) {
+    // CHECK-LABEL: define{{.*}}baz
+    // CHECK:       call i1 @llvm.type.test(i8* %{{[0-9]+}}, metadata !"_ZTSFvamfdbE")
+    // CHECK:       call i1 @llvm.type.test(i8* %{{[0-9]+}}, metadata !"_ZTSFvu1cPKu1eE")
+    unsafe { f(1, 2, 3.0, 4.0, true) };
+    g('a', "b")
+}
@@ -0,0 +1,43 @@
+// Verifies that virtual calls test for a type identifier with the receiver erased, and that
+// methods which can be called through a vtable are associated with it, but not the others.
+//
+// needs-sanitizer-cfi
+// only-x86_64
+// only-linux
+// compile-flags: -Clto -Cno-prepopulate-passes -Zsanitizer=cfi
+
+#![crate_type="lib"]
+
+pub trait Trait {
+    fn f(&self, x: i32) -> i32;
+}
+
+pub struct S;
+
+impl Trait for S {
+    fn f(&self, x: i32) -> i32 {
+        x
+    }
+}
+
+impl S {
+    #[no_mangle]
+    pub fn inherent(&self, x: i32) -> i32 {
+        // CHECK-LABEL: define{{.*}}@inherent({{[^!]*}}!type !{{[0-9]+}} {
+        x
+    }
+}
+
+pub fn make() -> &'static dyn Trait {
+    &S
+}
+
+#[no_mangle]
+pub fn call(t: &dyn Trait) -> i32 {
+    // CHECK-LABEL: define{{.*}}@call
+    // CHECK:       call i1 @llvm.type.test(i8* %{{[0-9]+}}, metadata !"_ZTSFiPviE")
+    t.f(1)
+}
+
+// Only `<S as Trait>::f` can be called through the vtable.
+// CHECK: !{{[0-9]+}} = !{i64 0, !"_ZTSFiPviE"}
@@ -0,0 +1,28 @@
+// Verifies that KCFI operand bundles are emitted for indirect calls, and KCFI type ids for
+// functions, without requiring LTO.
+//
+// needs-sanitizer-kcfi
+// min-llvm-version: 16
+// compile-flags: -Cno-prepopulate-passes -Zsanitizer=kcfi
+
+#![crate_type="lib"]
+#![feature(no_sanitize)]
+
+pub fn foo(f: fn(i32) -> i32, arg: i32) -> i32 {
+    // CHECK-LABEL: define{{.*}}foo{{.*}}!kcfi_type ![[TYPE:[0-9]+]]
+    // CHECK:       call i32 %f(i32 %arg){{.*}}[ "kcfi"(i32 329620) ]
+    f(arg)
+}
+
+#[no_sanitize(kcfi)]
+pub fn unchecked(f: fn(i32) -> i32, arg: i32) -> i32 {
+    // CHECK-LABEL: define{{.*}}unchecked
+    // CHECK:       call i32 %f(i32 %arg)
+    // CHECK-NOT:   "kcfi"
+    // CHECK:       ret i32
+    f(arg)
+}
+
+// CHECK-DAG: !{{[0-9]+}} = !{i32 4, !"kcfi", i32 1}
+// The type id of `fn(fn(i32) -> i32, i32) -> i32`.
+// CHECK-DAG: ![[TYPE]] = !{i32 1631892647}
@@ -0,0 +1,32 @@
+//
+
+// compile-flags: -C no-prepopulate-passes
+
+#![crate_type = "lib"]
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Vec2<T>(pub T, pub T);
+
+extern "platform-intrinsic" {
+    fn simd_swizzle_dyn<T, U>(values: T, indices: U) -> T;
+}
+
+// CHECK-LABEL: @swizzle_dyn_f32x2
+#[no_mangle]
+pub unsafe fn swizzle_dyn_f32x2(values: Vec2<f32>, indices: Vec2<u8>) -> Vec2<f32> {
+    // CHECK: [[I0:%.*]] = extractelement <2 x i8> [[INDICES:%.*]], i32 0
+    // CHECK: [[R0:%.*]] = icmp ult i8 [[I0]], 2
+    // CHECK: [[E0:%.*]] = extractelement <2 x float> [[VALUES:%.*]], i8 [[I0]]
+    // CHECK: [[S0:%.*]] = select i1 [[R0]], float [[E0]], float 0.000000e+00
+    // CHECK: [[V0:%.*]] = insertelement <2 x float> undef, float [[S0]], i32 0
+    // CHECK: [[I1:%.*]] = extractelement <2 x i8> [[INDICES]], i32 1
+    // CHECK: [[R1:%.*]] = icmp ult i8 [[I1]], 2
+    // CHECK: [[E1:%.*]] = extractelement <2 x float> [[VALUES]], i8 [[I1]]
+    // CHECK: [[S1:%.*]] = select i1 [[R1]], float [[E1]], float 0.000000e+00
+    // CHECK: insertelement <2 x float> [[V0]], float [[S1]], i32 1
+    simd_swizzle_dyn(values, indices)
+}
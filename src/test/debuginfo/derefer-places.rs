@@ -0,0 +1,73 @@
+// min-lldb-version: 310
+
+// Checks that variables stay inspectable once the `Derefer` split the places which dereference
+// more than once, both for a variable read through a double reference and for the captures of a
+// closure, whose debuginfo places still dereference the closure environment.
+
+// compile-flags:-g
+
+// === GDB TESTS ===================================================================================
+
+// gdb-command:run
+
+// gdb-command:print b
+// gdb-check:$1 = 2
+// gdb-command:print **rr
+// gdbg-check:$2 = {a = 1, b = 2}
+// gdbr-check:$2 = derefer_places::Pair {a: 1, b: 2}
+// gdb-command:continue
+
+// gdb-command:print *captured
+// gdbg-check:$3 = {a = 1, b = 2}
+// gdbr-check:$3 = derefer_places::Pair {a: 1, b: 2}
+// gdb-command:print sum
+// gdb-check:$4 = 3
+// gdb-command:continue
+
+// === LLDB TESTS ==================================================================================
+
+// lldb-command:run
+
+// lldb-command:print b
+// lldbg-check:[...]$0 = 2
+// lldbr-check:(u32) b = 2
+// lldb-command:print **rr
+// lldbg-check:[...]$1 = { a = 1 b = 2 }
+// lldbr-check:(derefer_places::Pair) **rr = { a = 1 b = 2 }
+// lldb-command:continue
+
+// lldb-command:print *captured
+// lldbg-check:[...]$2 = { a = 1 b = 2 }
+// lldbr-check:(derefer_places::Pair) *captured = { a = 1 b = 2 }
+// lldb-command:print sum
+// lldbg-check:[...]$3 = 3
+// lldbr-check:(u32) sum = 3
+// lldb-command:continue
+
+#![allow(unused_variables)]
+#![feature(omit_gdb_pretty_printer_section)]
+#![omit_gdb_pretty_printer_section]
+
+struct Pair {
+    a: u32,
+    b: u32,
+}
+
+fn main() {
+    let pair = Pair { a: 1, b: 2 };
+    let r = &pair;
+    let rr = &r;
+    let b = rr.b;
+
+    zzz(); // #break
+
+    let captured = &pair;
+    let closure = || {
+        let sum = captured.a + captured.b;
+
+        zzz(); // #break
+    };
+    closure();
+}
+
+fn zzz() {()}
@@ -0,0 +1,59 @@
+// min-lldb-version: 310
+
+// Checks that the variables split up by `ScalarReplacementOfAggregates` can still be inspected
+// from their fragments.
+
+// compile-flags:-g -Zmir-opt-level=2
+
+// === GDB TESTS ===================================================================================
+
+// gdb-command:run
+
+// gdb-command:print point
+// gdbg-check:$1 = {x = 7, y = 8}
+// gdbr-check:$1 = sroa_fragments::Point {x: 7, y: 8}
+// gdb-command:print pair
+// gdbg-check:$2 = {__0 = 7, __1 = true}
+// gdbr-check:$2 = (7, true)
+// gdb-command:continue
+
+// === LLDB TESTS ==================================================================================
+
+// lldb-command:run
+
+// lldb-command:print point
+// lldbg-check:[...]$0 = { x = 7 y = 8 }
+// lldbr-check:(sroa_fragments::Point) point = { x = 7 y = 8 }
+// lldb-command:print pair
+// lldbg-check:[...]$1 = { 0 = 7 1 = true }
+// lldbr-check:((u32, bool)) pair = { 0 = 7 1 = true }
+// lldb-command:continue
+
+#![allow(unused_variables)]
+#![feature(omit_gdb_pretty_printer_section)]
+#![omit_gdb_pretty_printer_section]
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+fn main() {
+    let n = seven();
+    let point = Point { x: n, y: n + 1 };
+    let pair = (point.x, point.y > 7);
+
+    zzz(); // #break
+
+    use_pair(pair.0, pair.1);
+}
+
+#[inline(never)]
+fn seven() -> u32 {
+    7
+}
+
+#[inline(never)]
+fn use_pair(_: u32, _: bool) {}
+
+fn zzz() {()}
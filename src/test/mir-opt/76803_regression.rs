@@ -1,4 +1,5 @@
 // compile-flags: -Z mir-opt-level=1
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // EMIT_MIR 76803_regression.encode.SimplifyBranchSame.diff
 
 #[derive(Debug, Eq, PartialEq)]
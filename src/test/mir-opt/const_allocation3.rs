@@ -1,3 +1,4 @@
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // ignore-endian-big
 // EMIT_MIR_FOR_EACH_BIT_WIDTH
 // EMIT_MIR const_allocation3.main.ConstProp.after.mir
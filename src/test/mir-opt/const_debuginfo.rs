@@ -1,4 +1,5 @@
 // compile-flags: -C overflow-checks=no -Zunsound-mir-opts
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 struct Point {
     x: u32,
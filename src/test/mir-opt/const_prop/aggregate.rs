@@ -1,4 +1,4 @@
-// compile-flags: -O
+// compile-flags: -O -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // EMIT_MIR aggregate.main.ConstProp.diff
 fn main() {
@@ -1,4 +1,4 @@
-// compile-flags: -O
+// compile-flags: -O -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // ignore-emscripten compiled with panic=abort by default
 // ignore-wasm32
 // ignore-wasm64
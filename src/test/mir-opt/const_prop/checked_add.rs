@@ -1,4 +1,5 @@
 // compile-flags: -C overflow-checks=on
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // EMIT_MIR checked_add.main.ConstProp.diff
 fn main() {
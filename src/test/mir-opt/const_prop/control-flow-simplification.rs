@@ -1,4 +1,5 @@
 // compile-flags: -Zmir-opt-level=1
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 trait NeedsDrop:Sized{
     const NEEDS:bool=std::mem::needs_drop::<Self>();
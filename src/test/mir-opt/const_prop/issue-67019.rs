@@ -1,4 +1,5 @@
 // compile-flags: -Z mir-opt-level=3
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // This used to ICE in const-prop
 
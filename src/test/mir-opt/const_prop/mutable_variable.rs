@@ -1,4 +1,4 @@
-// compile-flags: -O
+// compile-flags: -O -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // EMIT_MIR mutable_variable.main.ConstProp.diff
 fn main() {
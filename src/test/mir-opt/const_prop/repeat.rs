@@ -1,4 +1,4 @@
-// compile-flags: -O
+// compile-flags: -O -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // EMIT_MIR_FOR_EACH_BIT_WIDTH
 // EMIT_MIR repeat.main.ConstProp.diff
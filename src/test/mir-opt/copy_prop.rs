@@ -0,0 +1,12 @@
+// Check that chains of moves between SSA locals are unified with their source.
+
+// EMIT_MIR copy_prop.move_chain.CopyProp.diff
+fn move_chain(x: String) -> String {
+    let y = x;
+    let z = y;
+    z
+}
+
+fn main() {
+    move_chain(String::new());
+}
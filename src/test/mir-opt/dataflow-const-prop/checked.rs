@@ -0,0 +1,8 @@
+// compile-flags: -Coverflow-checks=on
+
+// EMIT_MIR checked.main.DataflowConstProp.diff
+fn main() {
+    let a = 1;
+    let b = 2;
+    let c = a + b;
+}
@@ -0,0 +1,11 @@
+// Check that a value which is the same on both sides of a branch is still known after it.
+
+// EMIT_MIR join.join.DataflowConstProp.diff
+fn join(b: bool) -> i32 {
+    let x = if b { 1 + 2 } else { 3 };
+    x + 1
+}
+
+fn main() {
+    join(true);
+}
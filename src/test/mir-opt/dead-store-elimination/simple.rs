@@ -0,0 +1,32 @@
+// Check that stores to locals which are never read again are removed, unless the local is borrowed.
+
+#[inline(never)]
+fn opaque(_: u8) {}
+
+#[inline(never)]
+fn opaque_ref(_: &u8) {}
+
+// EMIT_MIR simple.unused_local.DeadStoreElimination.diff
+fn unused_local(x: u8) -> u8 {
+    let y = x;
+    x
+}
+
+// EMIT_MIR simple.overwritten_argument.DeadStoreElimination.diff
+fn overwritten_argument(mut x: u8) {
+    opaque(x);
+    x = 5;
+}
+
+// EMIT_MIR simple.borrowed.DeadStoreElimination.diff
+fn borrowed(mut x: u8) {
+    // `x` may be read through the reference, so the store is kept.
+    opaque_ref(&x);
+    x = 5;
+}
+
+fn main() {
+    unused_local(0);
+    overwritten_argument(0);
+    borrowed(0);
+}
@@ -0,0 +1,10 @@
+// Check that places dereferencing more than once are split into `deref_copy` temporaries.
+
+// EMIT_MIR derefer_test.nested.Derefer.diff
+fn nested(x: &&i32) -> i32 {
+    **x
+}
+
+fn main() {
+    nested(&&5);
+}
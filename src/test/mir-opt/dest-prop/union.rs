@@ -1,5 +1,5 @@
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 //! Tests that projections through unions cancel `DestinationPropagation`.
-// compile-flags: -Zunsound-mir-opts
 fn val() -> u32 {
     1
 }
@@ -1,4 +1,5 @@
 // compile-flags: -Z mir-opt-level=4 -Zunsound-mir-opts
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // must not optimize as it does not follow the pattern of
 // left and right hand side being the same variant
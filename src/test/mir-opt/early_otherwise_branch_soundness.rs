@@ -1,4 +1,5 @@
 // compile-flags: -Z mir-opt-level=4 -Zunsound-mir-opts
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // Tests various cases that the `early_otherwise_branch` opt should *not* optimize
 
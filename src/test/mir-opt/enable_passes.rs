@@ -0,0 +1,13 @@
+// Check that `-Zmir-enable-passes` runs the passes that `-Zmir-opt-level` would not.
+// compile-flags: -Zmir-opt-level=0 -Zmir-enable-passes=+CopyProp
+
+// EMIT_MIR enable_passes.move_chain.CopyProp.diff
+fn move_chain(x: String) -> String {
+    let y = x;
+    let z = y;
+    z
+}
+
+fn main() {
+    move_chain(String::new());
+}
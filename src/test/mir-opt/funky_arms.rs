@@ -1,4 +1,5 @@
 // compile-flags: --crate-type lib -Cdebug-assertions=no
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 #![feature(flt2dec)]
 
@@ -0,0 +1,22 @@
+// Check that repeated pure computations are replaced with a copy of the first result.
+// compile-flags: -C overflow-checks=no
+
+#[inline(never)]
+fn opaque(_: u64) {}
+
+// EMIT_MIR gvn.repeated_arithmetic.GVN.diff
+fn repeated_arithmetic(x: u64, y: u64) {
+    opaque(x + y);
+    opaque(x + y);
+}
+
+// EMIT_MIR gvn.repeated_field.GVN.diff
+fn repeated_field(t: (u64, u64)) {
+    opaque(t.0);
+    opaque(t.0);
+}
+
+fn main() {
+    repeated_arithmetic(1, 2);
+    repeated_field((1, 2));
+}
@@ -1,3 +1,4 @@
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // ignore-wasm32-bare compiled with panic=abort by default
 
 // EMIT_MIR cycle.f.Inline.diff
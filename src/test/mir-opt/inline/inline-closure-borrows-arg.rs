@@ -1,4 +1,5 @@
 // compile-flags: -Z span_free_formats -Zunsound-mir-opts
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // Tests that MIR inliner can handle closure arguments,
 // even when (#45894)
@@ -1,6 +1,9 @@
 // Checks that only functions with compatible attributes are inlined.
 //
 // only-x86_64
+// needs-sanitizer-address
+// compile-flags: -Zsanitizer=address
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 #![crate_type = "lib"]
 #![feature(no_sanitize)]
@@ -1,3 +1,4 @@
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // Tests inlining of diverging calls.
 //
 // ignore-wasm32-bare compiled with panic=abort by default
@@ -1,6 +1,7 @@
 // Checks that only functions with the compatible instruction_set attributes are inlined.
 //
 // compile-flags: --target thumbv4t-none-eabi
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // needs-llvm-components: arm
 
 #![crate_type = "lib"]
@@ -2,6 +2,7 @@
 // inline-mir-threshold and inline-hint-threshold options.
 //
 // compile-flags: -Zinline-mir-threshold=90
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // compile-flags: -Zinline-mir-hint-threshold=50
 
 // EMIT_MIR inline_options.main.Inline.after.mir
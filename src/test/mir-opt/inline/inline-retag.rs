@@ -1,4 +1,5 @@
 // compile-flags: -Z span_free_formats -Z mir-emit-retag
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // Tests that MIR inliner fixes up `Retag`'s `fn_entry` flag
 
@@ -1,3 +1,4 @@
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 #![feature(specialization)]
 
 // EMIT_MIR inline_specialization.main.Inline.diff
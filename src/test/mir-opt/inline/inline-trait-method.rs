@@ -1,4 +1,5 @@
 // compile-flags: -Z span_free_formats
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 fn main() {
     println!("{}", test(&()));
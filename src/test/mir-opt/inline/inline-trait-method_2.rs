@@ -1,4 +1,5 @@
 // compile-flags: -Z span_free_formats -Z mir-opt-level=4
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // EMIT_MIR inline_trait_method_2.test2.Inline.after.mir
 fn test2(x: &dyn X) -> bool {
@@ -1,4 +1,5 @@
 // compile-flags: -Z mir-opt-level=3 -Z inline-mir
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // ignore-wasm32-bare compiled with panic=abort by default
 #![crate_type = "lib"]
 
@@ -8,6 +8,7 @@
 
 // EMIT_MIR issue_59352.num_to_digit.PreCodegen.after.mir
 // compile-flags: -Z mir-opt-level=3 -Z span_free_formats
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 pub fn num_to_digit(num: char) -> u32 {
     // CHECK-NOT: panic
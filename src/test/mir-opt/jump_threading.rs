@@ -0,0 +1,18 @@
+// compile-flags: -Zmir-opt-level=1
+// Check that switching on the variant of an enum that was just set jumps to the matching arm.
+
+// EMIT_MIR jump_threading.rebuilt.JumpThreading.diff
+fn rebuilt(x: Result<u8, ()>) -> u8 {
+    let y = match x {
+        Ok(v) => Some(v),
+        Err(()) => None,
+    };
+    match y {
+        Some(v) => v,
+        None => 0,
+    }
+}
+
+fn main() {
+    rebuilt(Ok(1));
+}
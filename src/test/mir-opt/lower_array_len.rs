@@ -1,4 +1,5 @@
 // compile-flags: -Z mir-opt-level=4
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 // EMIT_MIR lower_array_len.array_bound.NormalizeArrayLen.diff
 // EMIT_MIR lower_array_len.array_bound.SimplifyLocals.diff
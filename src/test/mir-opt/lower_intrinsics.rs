@@ -1,4 +1,4 @@
-// compile-flags: -Cpanic=abort
+// compile-flags: -Cpanic=abort -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 #![feature(core_intrinsics)]
 #![crate_type = "lib"]
 
@@ -4,6 +4,7 @@
 
 // Force generation of optimized mir for functions that do not reach codegen.
 // compile-flags: --emit mir,link
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 #![feature(never_type)]
 #![warn(const_err)]
@@ -1,3 +1,4 @@
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 #![feature(control_flow_enum)]
 #![feature(try_trait_v2)]
 
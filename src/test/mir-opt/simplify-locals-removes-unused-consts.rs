@@ -1,4 +1,5 @@
 // compile-flags: -C overflow-checks=no
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 fn use_zst(_: ((), ())) {}
 
@@ -1,4 +1,5 @@
 // compile-flags: -Zunsound-mir-opts
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 fn map(x: Option<Box<()>>) -> Option<Box<()>> {
     match x {
@@ -1,4 +1,5 @@
 // compile-flags: -C overflow-checks=off
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 
 #![feature(box_syntax)]
 #![feature(thread_local)]
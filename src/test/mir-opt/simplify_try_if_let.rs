@@ -1,4 +1,5 @@
 // compile-flags: -Zmir-opt-level=1 -Zunsound-mir-opts
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // ignore-test
 // FIXME: the pass is unsound and causes ICEs in the MIR validator
 
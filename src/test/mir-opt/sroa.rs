@@ -0,0 +1,17 @@
+// Check that a struct local that is only accessed field by field is split into one local per
+// field, and that its debuginfo describes the fields.
+
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+// EMIT_MIR sroa.fields.ScalarReplacementOfAggregates.diff
+fn fields(n: u32) -> u32 {
+    let p = Point { x: n, y: 2 };
+    p.x + p.y
+}
+
+fn main() {
+    fields(1);
+}
@@ -1,3 +1,4 @@
+// compile-flags: -Zmir-enable-passes=-JumpThreading,-DataflowConstProp,-CopyProp,-GVN
 // Test that we correctly generate StorageDead statements for while loop
 // conditions on all branches
 
@@ -0,0 +1,4 @@
+pub fn borrow(x: &(u8, u8)) -> &u8 {
+    let y = &x.0;
+    y
+}
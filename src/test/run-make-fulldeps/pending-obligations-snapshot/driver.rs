@@ -0,0 +1,74 @@
+#![feature(rustc_private)]
+
+//! This program implements a rustc driver that registers obligations on a
+//! fresh type variable and checks what `pending_obligations_snapshot` reports
+//! for them, both before and after the variable is resolved.
+
+extern crate rustc_driver;
+extern crate rustc_infer;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_span;
+extern crate rustc_trait_selection;
+
+use rustc_driver::Compilation;
+use rustc_infer::infer::type_variable::{TypeVariableOrigin, TypeVariableOriginKind};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_infer::traits::{ObligationCause, TraitEngine};
+use rustc_interface::interface::Compiler;
+use rustc_interface::Queries;
+use rustc_middle::ty;
+use rustc_span::DUMMY_SP;
+use rustc_trait_selection::traits::FulfillmentContext;
+
+fn main() {
+    let exit_code = rustc_driver::catch_with_exit_code(move || {
+        let rustc_args: Vec<_> = std::env::args().collect();
+        rustc_driver::RunCompiler::new(&rustc_args, &mut CompilerCalls).run()
+    });
+    std::process::exit(exit_code);
+}
+
+struct CompilerCalls;
+
+impl rustc_driver::Callbacks for CompilerCalls {
+    fn after_analysis<'tcx>(
+        &mut self,
+        compiler: &Compiler,
+        queries: &'tcx Queries<'tcx>,
+    ) -> Compilation {
+        compiler.session().abort_if_errors();
+        queries.global_ctxt().unwrap().peek_mut().enter(|tcx| {
+            tcx.infer_ctxt().enter(|infcx| {
+                let param_env = ty::ParamEnv::empty();
+                let cause = ObligationCause::dummy();
+                let var = infcx.next_ty_var(TypeVariableOrigin {
+                    kind: TypeVariableOriginKind::MiscVariable,
+                    span: DUMMY_SP,
+                });
+
+                let mut fulfill_cx = FulfillmentContext::new();
+                let clone_trait = tcx.lang_items().clone_trait().unwrap();
+                fulfill_cx.register_bound(&infcx, param_env, var, clone_trait, cause.clone());
+                infcx.register_region_obligation_with_cause(
+                    var,
+                    tcx.lifetimes.re_static,
+                    &cause,
+                );
+
+                // The variable is still unresolved here.
+                let snapshot = infcx.pending_obligations_snapshot(&fulfill_cx);
+                assert_eq!(snapshot.obligations.len(), 1);
+                assert_eq!(snapshot.region_obligations.len(), 1);
+                assert_eq!(snapshot.region_obligations[0].region, "'static");
+
+                infcx.at(&cause, param_env).eq(var, tcx.types.u8).unwrap();
+                let snapshot = infcx.pending_obligations_snapshot(&fulfill_cx);
+                assert_eq!(snapshot.region_obligations[0].ty, "u8");
+                assert!(snapshot.obligations[0].predicate.contains("u8"));
+            });
+        });
+
+        Compilation::Stop
+    }
+}
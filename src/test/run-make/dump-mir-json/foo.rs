@@ -0,0 +1,3 @@
+pub fn add_one(x: u32) -> u32 {
+    if x == u32::MAX { 0 } else { x + 1 }
+}
@@ -0,0 +1,13 @@
+pub mod a {
+    #[no_mangle]
+    pub fn small(x: u32) -> u32 {
+        x.wrapping_mul(3)
+    }
+}
+
+pub mod b {
+    #[no_mangle]
+    pub fn caller(x: u32) -> u32 {
+        crate::a::small(x) + 1
+    }
+}
@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+pub struct Counter {
+    counts: HashMap<String, u32>,
+}
+
+impl Counter {
+    pub fn new() -> Counter {
+        Counter { counts: HashMap::new() }
+    }
+
+    pub fn add(&mut self, word: &str) {
+        *self.counts.entry(word.to_string()).or_insert(0) += 1;
+    }
+
+    pub fn get(&self, word: &str) -> u32 {
+        self.counts.get(word).copied().unwrap_or(0)
+    }
+}
+
+pub fn generic<T: std::fmt::Debug>(value: T) -> String {
+    format!("{:?}", value)
+}
+
+pub fn instances() -> Vec<String> {
+    vec![generic(1u8), generic("two"), generic(3.0f64), generic((4, 'f'))]
+}
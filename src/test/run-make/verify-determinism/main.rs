@@ -0,0 +1,21 @@
+mod a {
+    pub fn f(x: u32) -> u32 {
+        x.wrapping_mul(3)
+    }
+}
+
+mod b {
+    pub fn g(x: u32) -> u32 {
+        super::a::f(x) + 1
+    }
+}
+
+mod c {
+    pub fn h<T: Into<u64>>(x: T) -> u64 {
+        x.into() * 2
+    }
+}
+
+fn main() {
+    println!("{} {} {}", b::g(1), c::h(2u8), c::h(3u32));
+}
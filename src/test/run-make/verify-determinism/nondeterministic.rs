@@ -0,0 +1,3 @@
+extern crate process_id;
+
+pub const PROCESS_ID: u32 = process_id::process_id!();
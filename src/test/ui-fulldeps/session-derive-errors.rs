@@ -264,3 +264,35 @@ struct ErrorWithLifetime<'a> {
     span: Span,
     name: &'a str,
 }
+
+#[derive(SessionDiagnostic)]
+#[error = "E0123"]
+#[note = "`{name}` was written here"]
+#[help = "remove `{name}`"]
+struct NoteAndHelpOk {
+    #[message = "`{name}` is not allowed here"]
+    span: Span,
+    name: Ident,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0123"]
+#[slug = "test-slug-ok"]
+#[note = "`{name}` was written here"]
+struct SlugOk {
+    #[message = "`{name}` is not allowed here"]
+    #[label = "not allowed"]
+    span: Span,
+    #[suggestion(message = "remove `{name}`", code = "")]
+    removal_span: Span,
+    name: Ident,
+}
+
+#[derive(SessionDiagnostic)]
+#[error = "E0123"]
+#[slug = "test-slug-specified-twice"]
+#[slug = "test-slug-specified-again"] //~ ERROR `slug` specified multiple times
+struct SlugSpecifiedTwice {
+    #[message = "Hello, world!"]
+    span: Span,
+}
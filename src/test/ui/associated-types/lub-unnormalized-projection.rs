@@ -0,0 +1,31 @@
+// check-pass
+// Checks that coercing match arms to a common type works when one of the
+// arm types is an associated type projection.
+
+trait Trait {
+    type Assoc;
+
+    fn make(&self) -> Self::Assoc;
+}
+
+impl Trait for u8 {
+    type Assoc = &'static str;
+
+    fn make(&self) -> &'static str {
+        "u8"
+    }
+}
+
+fn pick<T: Trait<Assoc = &'static str>>(t: T, n: usize) -> &'static str {
+    match n {
+        0 => t.make(),
+        1 => "one",
+        _ => <T as Trait>::make(&t),
+    }
+}
+
+fn main() {
+    let s = if true { 0u8.make() } else { "other" };
+    assert_eq!(s, "u8");
+    assert_eq!(pick(0u8, 1), "one");
+}
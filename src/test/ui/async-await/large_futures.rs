@@ -0,0 +1,29 @@
+#![deny(large_futures)]
+#![feature(large_assignments)]
+#![cfg_attr(attribute, future_size_limit = "1000")]
+// build-fail
+// revisions: attribute option
+// [option]compile-flags: -Zfuture-size-limit=1000
+
+// edition:2018
+
+use std::future::Future;
+
+async fn tick() {}
+
+async fn process() { //~ ERROR the future returned by `process` is 4098 bytes
+    let buf = [0u8; 4096];
+    tick().await;
+    drop(buf);
+}
+
+fn main() {
+    let _: Box<dyn Future<Output = ()>> = Box::new(process());
+    let block = async { //~ ERROR this `async` block is 3002 bytes
+        let a = [0u8; 2000];
+        let b = [0u8; 1000];
+        tick().await;
+        drop((a, b));
+    };
+    let _: Box<dyn Future<Output = ()>> = Box::new(block);
+}
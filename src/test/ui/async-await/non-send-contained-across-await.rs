@@ -0,0 +1,21 @@
+// Check that when the value held across an await contains the type that isn't `Send`, the error
+// names both types.
+
+// edition:2018
+
+use std::rc::Rc;
+
+struct Wrapper(Rc<u8>);
+
+async fn ready() {}
+
+async fn hold_wrapper() {
+    let _wrapper = Wrapper(Rc::new(0));
+    ready().await;
+}
+
+fn assert_send(_: impl Send) {}
+
+fn main() {
+    assert_send(hold_wrapper()); //~ ERROR future cannot be sent between threads safely
+}
@@ -0,0 +1,20 @@
+// Check that moves that can't be undone by appending `.clone()` at the move site don't get the
+// suggestion tested in `suggest-clone-on-move.rs`.
+
+struct Pair {
+    a: String,
+    b: String,
+}
+
+fn move_into_binding(t: (String, String)) {
+    let (a, _) = t;
+    drop(a);
+    drop(t); //~ ERROR use of partially moved value: `t`
+}
+
+fn move_out_of_update_base(p: Pair) {
+    let _q = Pair { a: String::new(), ..p };
+    drop(p); //~ ERROR use of partially moved value: `p`
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+// run-rustfix
+// Check that moves of `Clone` values get a machine-applicable `.clone()` suggestion.
+#![allow(dead_code)]
+
+struct Wrapper {
+    data: Vec<u8>,
+}
+
+fn take(_: String) {}
+
+fn use_after_move() {
+    let s = String::new();
+    take(s);
+    take(s); //~ ERROR use of moved value: `s`
+}
+
+fn move_out_of_deref(r: &String) -> String {
+    *r //~ ERROR cannot move out of `*r` which is behind a shared reference
+}
+
+fn move_out_of_field(w: &Wrapper) -> Vec<u8> {
+    w.data //~ ERROR cannot move out of `w.data` which is behind a shared reference
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+// Check that the LUB of function pointer types is only computed through an
+// unsafety coercion when one side can be coerced to the other. Here the
+// operands would have to be coerced in opposite directions, so there is none.
+
+fn safe() {}
+
+unsafe fn not_safe() {}
+
+fn takes_safe(_: fn()) {}
+
+fn takes_unsafe(_: unsafe fn()) {}
+
+fn tuple(c: bool) {
+    let safe: fn() = safe;
+    let not_safe: unsafe fn() = not_safe;
+    let _ = if c { (safe, not_safe) } else { (not_safe, safe) };
+    //~^ ERROR `if` and `else` have incompatible types
+}
+
+fn argument(c: bool) {
+    // The argument types are in a contravariant position, so this needs their
+    // GLB, which can't be an unsafety coercion.
+    let _ = if c { takes_safe as fn(fn()) } else { takes_unsafe as fn(unsafe fn()) };
+    //~^ ERROR `if` and `else` have incompatible types
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+// run-pass
+// Check that the LUB of `fn()` and `unsafe fn()` is `unsafe fn()`, even when
+// the expressions of type `fn()` have already been coerced.
+
+fn foo() -> u8 {
+    1
+}
+
+unsafe fn bar() -> u8 {
+    2
+}
+
+fn pick(n: u8) -> unsafe fn() -> u8 {
+    let f: fn() -> u8 = foo;
+    let g: unsafe fn() -> u8 = bar;
+    // The second arm is reified to `fn() -> u8`, so by the time we see the
+    // third one we have to compute the LUB of `fn() -> u8` and
+    // `unsafe fn() -> u8`.
+    let h = match n {
+        0 => f,
+        1 => foo,
+        _ => g,
+    };
+    h
+}
+
+fn main() {
+    unsafe {
+        assert_eq!(pick(0)(), 1);
+        assert_eq!(pick(1)(), 1);
+        assert_eq!(pick(2)(), 2);
+    }
+}
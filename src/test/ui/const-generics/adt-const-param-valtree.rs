@@ -0,0 +1,58 @@
+// Check that values of user-defined types, and references to them, can be used as const
+// arguments, and that equal values give the same type no matter how they were computed.
+// run-pass
+
+#![feature(adt_const_params)]
+#![allow(incomplete_features)]
+
+#[derive(PartialEq, Eq, Debug)]
+struct Point {
+    x: u32,
+    name: &'static str,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+enum Shape {
+    Dot(Point),
+    Line(Point, Point),
+    Empty,
+}
+
+struct Wrap<const S: Shape>;
+
+impl<const S: Shape> Wrap<S> {
+    fn get(&self) -> Shape {
+        S
+    }
+}
+
+struct Refs<const R: &'static [&'static Point]>;
+
+impl<const R: &'static [&'static Point]> Refs<R> {
+    fn len(&self) -> usize {
+        R.len()
+    }
+}
+
+const ORIGIN: Point = Point { x: 0, name: "origin" };
+
+const fn dot(x: u32) -> Shape {
+    Shape::Dot(Point { x, name: "origin" })
+}
+
+// Both arguments need to have the same type, which only holds if their consts are equal.
+fn same<T>(_: T, _: T) {}
+
+fn main() {
+    same(Wrap::<{ Shape::Dot(ORIGIN) }>, Wrap::<{ dot(0) }>);
+    same(Wrap::<{ Shape::Empty }>, Wrap::<{ Shape::Empty }>);
+    same(Refs::<{ &[&ORIGIN] }>, Refs::<{ &[&Point { x: 0, name: "origin" }] }>);
+
+    assert_eq!(Wrap::<{ dot(3) }>.get(), Shape::Dot(Point { x: 3, name: "origin" }));
+    assert_eq!(
+        Wrap::<{ Shape::Line(ORIGIN, Point { x: 1, name: "end" }) }>.get(),
+        Shape::Line(ORIGIN, Point { x: 1, name: "end" }),
+    );
+    assert_eq!(Wrap::<{ Shape::Empty }>.get(), Shape::Empty);
+    assert_eq!(Refs::<{ &[&ORIGIN, &ORIGIN] }>.len(), 2);
+}
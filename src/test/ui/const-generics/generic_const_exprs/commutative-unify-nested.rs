@@ -0,0 +1,23 @@
+// check-pass
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+// Check that the operands of nested commutative operations are reordered at
+// every level when unifying abstract consts.
+
+fn callee<const N: usize>()
+where
+    [(); 1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + (1 + N)))))))))))]:,
+{
+}
+
+fn caller<const N: usize>()
+where
+    [(); (((((((((((N + 1) + 1) + 1) + 1) + 1) + 1) + 1) + 1) + 1) + 1) + 1) + 1]:,
+{
+    callee::<N>();
+}
+
+fn main() {
+    caller::<3>();
+}
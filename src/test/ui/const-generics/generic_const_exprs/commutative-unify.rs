@@ -0,0 +1,31 @@
+// check-pass
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features)]
+
+// Check that abstract consts unify up to swapping the operands of
+// commutative operations.
+
+fn callee<const M: usize>()
+where
+    [u8; 1 + M]: Sized,
+{
+}
+
+fn caller<const N: usize>()
+where
+    [u8; N + 1]: Sized,
+{
+    callee::<N>();
+}
+
+fn double<const N: usize>() -> [u8; N * 2]
+where
+    [u8; N * 2]: Sized,
+{
+    [0; 2 * N]
+}
+
+fn main() {
+    caller::<3>();
+    assert_eq!(double::<3>().len(), 6);
+}
@@ -0,0 +1,16 @@
+// run-rustfix
+#![feature(generic_const_exprs)]
+#![allow(incomplete_features, dead_code)]
+
+fn callee<const N: usize>()
+where
+    [(); N + 1]:,
+{
+}
+
+fn caller<const N: usize>() {
+    callee::<N>();
+    //~^ ERROR unconstrained generic constant
+}
+
+fn main() {}
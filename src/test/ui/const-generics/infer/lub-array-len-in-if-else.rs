@@ -0,0 +1,16 @@
+// check-pass
+// Checks that the LUB of two array types with a const inference variable
+// as the length resolves the variable instead of emitting a type mismatch.
+
+fn zeroes<const N: usize>() -> [u8; N] {
+    [0; N]
+}
+
+fn main() {
+    let a = if true { zeroes() } else { [1u8; 3] };
+    let b = match a.len() {
+        0 => [2u8; 4],
+        _ => zeroes(),
+    };
+    let _: ([u8; 3], [u8; 4]) = (a, b);
+}
@@ -0,0 +1,13 @@
+// Check that `-Zmax-const-backtrace-frames` only keeps the innermost and outermost frames.
+
+// compile-flags: -Zmax-const-backtrace-frames=4
+
+const fn a() -> usize {
+    b() //~ ERROR evaluation of constant value failed [E0080]
+}
+const fn b() -> usize {
+    a()
+}
+const ARR: [i32; a()] = [5; 6];
+
+fn main() {}
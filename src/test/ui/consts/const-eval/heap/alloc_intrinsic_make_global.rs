@@ -0,0 +1,44 @@
+// run-pass
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+#![feature(const_ptr_offset)]
+use std::intrinsics;
+
+// A collection which keeps its elements behind a raw pointer, like `Vec`.
+struct Buffer {
+    ptr: *const u32,
+    len: usize,
+}
+
+impl Buffer {
+    const fn from_multiples_of_ten(len: usize) -> Buffer {
+        unsafe {
+            let ptr = intrinsics::const_allocate(len * 4, 4) as *mut u32;
+            let mut i = 0;
+            while i < len {
+                *ptr.add(i) = i as u32 * 10;
+                i += 1;
+            }
+            let ptr = intrinsics::const_make_global(ptr as *mut u8) as *const u32;
+            Buffer { ptr, len }
+        }
+    }
+
+    fn as_slice(&self) -> &[u32] {
+        unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+const BUFFER: Buffer = Buffer::from_multiples_of_ten(3);
+
+const REFERENCE: &u32 = unsafe {
+    let ptr = intrinsics::const_allocate(4, 4) as *mut u32;
+    *ptr = 42;
+    &*(intrinsics::const_make_global(ptr as *mut u8) as *const u32)
+};
+
+fn main() {
+    assert_eq!(BUFFER.as_slice(), [0, 10, 20]);
+    assert_eq!(*REFERENCE, 42);
+}
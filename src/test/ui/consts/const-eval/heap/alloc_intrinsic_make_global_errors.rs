@@ -0,0 +1,28 @@
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+use std::intrinsics;
+
+const WRITE_AFTER: &u8 = unsafe {
+    let ptr = intrinsics::const_allocate(1, 1);
+    let global = intrinsics::const_make_global(ptr);
+    *ptr = 1;
+    //~^ ERROR evaluation of constant value failed
+    &*global
+};
+
+const TWICE: &u8 = unsafe {
+    let ptr = intrinsics::const_allocate(1, 1);
+    *ptr = 1;
+    intrinsics::const_make_global(ptr);
+    &*intrinsics::const_make_global(ptr)
+    //~^ ERROR evaluation of constant value failed
+};
+
+const NOT_HEAP: &u8 = unsafe {
+    let mut x = 1u8;
+    &*intrinsics::const_make_global(&mut x)
+    //~^ ERROR evaluation of constant value failed
+};
+
+fn main() {}
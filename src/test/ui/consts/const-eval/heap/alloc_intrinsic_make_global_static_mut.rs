@@ -0,0 +1,13 @@
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+use std::intrinsics;
+
+static mut COUNTER: &mut u32 = unsafe {
+    let ptr = intrinsics::const_allocate(4, 4) as *mut u32;
+    *ptr = 0;
+    &mut *(intrinsics::const_make_global(ptr as *mut u8) as *mut u32)
+};
+//~^^^^^ ERROR encountered mutable reference or `UnsafeCell` to memory made immutable
+
+fn main() {}
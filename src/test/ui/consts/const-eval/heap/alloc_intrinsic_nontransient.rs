@@ -10,7 +10,7 @@ const fn foo() -> &'static i32 {
     let t = unsafe {
         let i = intrinsics::const_allocate(4, 4) as * mut i32;
         *i = 20;
-        i
+        intrinsics::const_make_global(i as *mut u8) as *const i32
     };
     unsafe { &*t }
 }
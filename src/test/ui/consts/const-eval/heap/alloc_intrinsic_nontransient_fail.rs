@@ -4,7 +4,7 @@
 use std::intrinsics;
 
 const FOO: *const i32 = foo();
-//~^ ERROR untyped pointers are not allowed in constant
+//~^ ERROR encountered `const_allocate` pointer in final value that was not made global
 
 const fn foo() -> &'static i32 {
     let t = unsafe {
@@ -0,0 +1,25 @@
+// Heap allocations behind references in the final value must have been made global as well.
+
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+use std::intrinsics;
+
+const fn allocate(value: i32) -> &'static i32 {
+    unsafe {
+        let ptr = intrinsics::const_allocate(4, 4) as *mut i32;
+        *ptr = value;
+        &*ptr
+    }
+}
+
+const REF: &i32 = allocate(20);
+//~^ ERROR encountered `const_allocate` pointer in final value that was not made global
+
+const NESTED: &&i32 = &allocate(20);
+//~^ ERROR encountered `const_allocate` pointer in final value that was not made global
+
+static STATIC: &i32 = allocate(20);
+//~^ ERROR encountered `const_allocate` pointer in final value that was not made global
+
+fn main() {}
@@ -0,0 +1,23 @@
+// Check that uninitialized bytes behind references in the final value are rejected, and that the
+// error points at the first uninitialized element.
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+#![feature(const_mut_refs)]
+#![feature(const_ptr_offset)]
+
+use std::intrinsics;
+
+struct Buf<'a> {
+    len: usize,
+    data: &'a [u8; 4],
+}
+
+const PARTIAL: Buf<'static> = unsafe {
+    let ptr = intrinsics::const_allocate(4, 1);
+    *ptr = 1;
+    *ptr.add(1) = 2;
+    Buf { len: 4, data: &*(intrinsics::const_make_global(ptr) as *const [u8; 4]) }
+};
+//~^^^^^^ ERROR encountered uninitialized bytes behind a reference in final constant
+
+fn main() {}
@@ -0,0 +1,19 @@
+// Check that pointers to deallocated heap memory in the final value are rejected, and that the
+// error points at where in the value they are.
+#![feature(core_intrinsics)]
+#![feature(const_heap)]
+
+use std::intrinsics;
+
+struct Buf {
+    len: usize,
+    ptr: *const u8,
+}
+
+const FREED: (u8, Buf) = unsafe { //~ ERROR encountered dangling pointer in final constant
+    let ptr = intrinsics::const_allocate(4, 4);
+    intrinsics::const_deallocate(ptr, 4, 4);
+    (0, Buf { len: 4, ptr })
+};
+
+fn main() {}
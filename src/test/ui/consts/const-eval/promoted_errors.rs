@@ -7,7 +7,7 @@
 // ignore-pass (test emits codegen-time warnings and verifies that they are not errors)
 
 //! This test ensures that when we promote code that fails to evaluate, the build still succeeds.
-
+#![allow(const_fn_call_promotion)]
 #![warn(const_err, arithmetic_overflow, unconditional_panic)]
 
 // The only way to have promoteds that fail is in `const fn` called from `const`/`static`.
@@ -5,7 +5,6 @@
 //~^ ERROR function pointer
 //~| ERROR function pointer cast
 const unsafe extern "C" fn use_float() { 1.0 + 1.0; }
-//~^ ERROR floating point arithmetic
 const extern "C" fn ptr_cast(val: *const u8) { val as usize; }
 //~^ ERROR pointers cannot be cast to integers
 
@@ -0,0 +1,17 @@
+// Check that calling a function pointer to a non-const function in a const context is an error.
+
+#![feature(const_fn_ptr_calls)]
+#![feature(const_fn_fn_ptr_basics)]
+
+const fn apply(f: fn(u32) -> u32, x: u32) -> u32 {
+    f(x)
+    //~^ ERROR evaluation of constant value failed
+}
+
+fn triple(x: u32) -> u32 {
+    x * 3
+}
+
+const NINE: u32 = apply(triple, 3);
+
+fn main() {}
@@ -0,0 +1,31 @@
+// Check that functions can be called through pointers in const contexts.
+
+// run-pass
+#![feature(const_fn_ptr_calls)]
+#![feature(const_fn_fn_ptr_basics)]
+
+const fn apply(f: fn(u32) -> u32, x: u32) -> u32 {
+    f(x)
+}
+
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn increment(x: u32) -> u32 {
+    x + 1
+}
+
+const DOUBLE: fn(u32) -> u32 = double;
+const FOUR: u32 = DOUBLE(2);
+const SIX: u32 = apply(double, 3);
+const TABLE: [fn(u32) -> u32; 2] = [double, DOUBLE];
+const TEN: u32 = TABLE[1](5);
+
+fn main() {
+    assert_eq!(FOUR, 4);
+    assert_eq!(SIX, 6);
+    assert_eq!(TEN, 10);
+    // At runtime, the pointee doesn't need to be a `const fn`.
+    assert_eq!(apply(increment, 3), 4);
+}
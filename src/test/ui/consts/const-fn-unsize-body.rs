@@ -0,0 +1,59 @@
+// Check that const fns can unsize to slices and trait objects in their bodies, e.g. to build
+// values with `&'static dyn Trait` fields, and that the vtables built at compile time work at
+// runtime.
+
+// run-pass
+
+trait Greet {
+    fn greet(&self) -> String;
+}
+
+struct English;
+
+impl Greet for English {
+    fn greet(&self) -> String {
+        "hello".to_string()
+    }
+}
+
+struct Count(usize);
+
+impl Greet for Count {
+    fn greet(&self) -> String {
+        format!("{} greetings", self.0)
+    }
+}
+
+struct Registry {
+    default: &'static dyn Greet,
+    sizes: &'static [u8],
+}
+
+impl Registry {
+    const fn new(
+        english: &'static English,
+        count: &'static Count,
+        sizes: &'static [u8; 3],
+        first: bool,
+    ) -> Registry {
+        let greeters: [&'static dyn Greet; 2] = [english, count];
+        Registry { default: greeters[if first { 0 } else { 1 }], sizes }
+    }
+
+    const fn empty(english: &'static English) -> Registry {
+        Registry { default: english, sizes: &[] }
+    }
+}
+
+const ENGLISH: Registry = Registry::new(&English, &Count(3), &[1, 2, 3], true);
+const COUNT: Registry = Registry::new(&English, &Count(3), &[4, 5, 6], false);
+const EMPTY: Registry = Registry::empty(&English);
+
+fn main() {
+    assert_eq!(ENGLISH.default.greet(), "hello");
+    assert_eq!(ENGLISH.sizes, &[1, 2, 3]);
+    assert_eq!(COUNT.default.greet(), "3 greetings");
+    assert_eq!(COUNT.sizes, &[4, 5, 6]);
+    assert_eq!(EMPTY.default.greet(), "hello");
+    assert!(EMPTY.sizes.is_empty());
+}
@@ -0,0 +1,54 @@
+// Check that builder-style APIs, which mutate a value through `&mut` in several `const fn`s, can
+// be used to compute constants.
+
+// run-pass
+#![feature(const_mut_refs)]
+
+struct ArrayBuilder {
+    buf: [u8; 4],
+    len: usize,
+}
+
+impl ArrayBuilder {
+    const fn new() -> Self {
+        ArrayBuilder { buf: [0; 4], len: 0 }
+    }
+
+    const fn push(&mut self, value: u8) -> &mut Self {
+        self.buf[self.len] = value;
+        self.len += 1;
+        self
+    }
+
+    const fn last_mut(&mut self) -> &mut u8 {
+        &mut self.buf[self.len - 1]
+    }
+}
+
+const fn double_in_place(x: &mut u8) {
+    *x *= 2;
+}
+
+const fn build() -> ArrayBuilder {
+    let mut builder = ArrayBuilder::new();
+    builder.push(1).push(2);
+    let last = builder.last_mut();
+    double_in_place(last);
+    builder.push(3);
+    builder
+}
+
+const BUILT: ArrayBuilder = build();
+
+const LEN: usize = {
+    let mut builder = ArrayBuilder::new();
+    let reference = &mut builder;
+    reference.push(7);
+    builder.len
+};
+
+fn main() {
+    assert_eq!(BUILT.buf, [1, 4, 3, 0]);
+    assert_eq!(BUILT.len, 3);
+    assert_eq!(LEN, 1);
+}
@@ -1,20 +1,53 @@
-// gate-test-const_fn_floating_point_arithmetic
-
-// revisions: stock gated
-
-#![feature(rustc_attrs)]
-#![cfg_attr(gated, feature(const_fn_floating_point_arithmetic))]
+// Floating point arithmetic is allowed in `const fn`, and gives the same results as at runtime.
+// NaN results always have the bit pattern of the preferred NaN at compile time.
+// run-pass
 
 const fn add(f: f32) -> f32 { f + 2.0 }
-//[stock]~^ floating point arithmetic
 const fn sub(f: f32) -> f32 { 2.0 - f }
-//[stock]~^ floating point arithmetic
 const fn mul(f: f32, g: f32) -> f32 { f * g }
-//[stock]~^ floating point arithmetic
 const fn div(f: f32, g: f32) -> f32 { f / g }
-//[stock]~^ floating point arithmetic
+const fn rem(f: f64, g: f64) -> f64 { f % g }
 const fn neg(f: f32) -> f32 { -f }
-//[stock]~^ floating point arithmetic
+const fn to_f64(f: f32) -> f64 { f as f64 }
+
+// A lookup table computed from float math.
+const fn squares() -> [f64; 4] {
+    let mut table = [0.0; 4];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = (i as f64 + 0.5) * (i as f64 + 0.5);
+        i += 1;
+    }
+    table
+}
+
+const ADD: f32 = add(1.5);
+const SUB: f32 = sub(0.5);
+const MUL: f32 = mul(1.5, -4.0);
+const DIV: f32 = div(1.0, 5.0);
+const REM: f64 = rem(7.5, 2.0);
+const NEG: f32 = neg(0.0);
+const TO_F64: f64 = to_f64(0.1);
+const SQUARES: [f64; 4] = squares();
+
+const NAN_F32: f32 = div(0.0, 0.0);
+const NEG_NAN_F32: f32 = neg(div(0.0, 0.0));
+const NAN_F64: f64 = rem(1.0, 0.0);
+const NAN_TO_F64: f64 = to_f64(neg(div(0.0, 0.0)) * 1.0);
+
+fn main() {
+    assert_eq!(ADD, 3.5);
+    assert_eq!(SUB, 1.5);
+    assert_eq!(MUL, -6.0);
+    assert_eq!(DIV, 0.2);
+    assert_eq!(REM, 1.5);
+    assert_eq!(NEG.to_bits(), (-0.0f32).to_bits());
+    assert_eq!(TO_F64, 0.1f32 as f64);
+    assert_eq!(SQUARES, [0.25, 2.25, 6.25, 12.25]);
 
-#[rustc_error]
-fn main() {} //[gated]~ fatal error triggered by #[rustc_error]
+    assert_eq!(NAN_F32.to_bits(), 0x7fc0_0000);
+    // Negation only flips the sign bit.
+    assert_eq!(NEG_NAN_F32.to_bits(), 0xffc0_0000);
+    assert_eq!(NAN_F64.to_bits(), 0x7ff8_0000_0000_0000);
+    assert_eq!(NAN_TO_F64.to_bits(), 0x7ff8_0000_0000_0000);
+}
@@ -0,0 +1,12 @@
+// Check that `-Zconst-eval-limit` sets the step limit, and that the error points at the functions
+// that took the most steps.
+
+// compile-flags: -Zconst-eval-limit=500
+
+const fn spin() -> usize {
+    loop {} //~ ERROR evaluation of constant value failed [E0080]
+}
+
+const ARR: [i32; spin()] = [];
+
+fn main() {}
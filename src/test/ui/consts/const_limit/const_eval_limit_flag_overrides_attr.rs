@@ -0,0 +1,14 @@
+// Check that `-Zconst-eval-limit` takes precedence over `#![const_eval_limit]`.
+
+// compile-flags: -Zconst-eval-limit=500
+
+#![feature(const_eval_limit)]
+#![const_eval_limit = "1000000"]
+
+const fn spin() -> usize {
+    loop {} //~ ERROR evaluation of constant value failed [E0080]
+}
+
+const ARR: [i32; spin()] = [];
+
+fn main() {}
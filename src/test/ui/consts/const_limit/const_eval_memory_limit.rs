@@ -0,0 +1,26 @@
+// Check that `-Zconst-eval-memory-limit` limits the memory a constant may have allocated at once.
+
+// compile-flags: -Zconst-eval-memory-limit=1024
+
+const fn sum() -> usize {
+    let table = [1u8; 2048]; //~ ERROR evaluation of constant value failed [E0080]
+    table.len()
+}
+
+const ARR: [i32; sum()] = [];
+
+// Memory that has been deallocated again doesn't count towards the limit.
+const fn small() -> u8 {
+    let mut i = 0;
+    let mut total = 0;
+    while i < 4 {
+        let table = [1u8; 512];
+        total += table[0];
+        i += 1;
+    }
+    total
+}
+
+const OK: [i32; small() as usize] = [0; 4];
+
+fn main() {}
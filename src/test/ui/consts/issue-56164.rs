@@ -5,7 +5,7 @@ const fn foo() { (||{})() }
 
 const fn bad(input: fn()) {
     input()
-    //~^ ERROR function pointer
+    //~^ ERROR function pointer calls
 }
 
 fn main() {
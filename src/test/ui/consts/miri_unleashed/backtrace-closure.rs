@@ -0,0 +1,23 @@
+// compile-flags: -Zunleash-the-miri-inside-of-you
+
+// Check that the frames of closures in the backtraces of const-eval errors show the generic args of
+// the function the closure is defined in.
+
+fn get<T: Copy>(slice: &[T], index: usize) -> T {
+    slice[index]
+    //~^ ERROR could not evaluate static initializer
+    //~| NOTE index out of bounds: the length is 2 but the index is 3
+    //~| NOTE inside `get::<u8>`
+}
+
+fn call_with<T: Copy>(slice: &[T]) -> T {
+    let f = |index| get(slice, index);
+    //~^ NOTE inside closure in `call_with::<u8>`
+    f(3)
+    //~^ NOTE inside `call_with::<u8>`
+}
+
+static X: u8 = call_with::<u8>(&[1, 2]);
+//~^ NOTE inside `X`
+
+fn main() {}
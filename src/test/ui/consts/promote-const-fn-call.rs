@@ -0,0 +1,55 @@
+// Calls to `const fn` without `#[rustc_promotable]` are only promoted in `const` and `static`
+// initializers, for the borrows which need this to be `'static`, and those are linted against.
+// check-pass
+
+const fn make() -> (i32, i32) {
+    (1, 2)
+}
+
+const fn assert_static<T>(_: &'static T) {}
+
+const BLOCK: i32 = {
+    let r: &'static (i32, i32) = &make(); //~ WARN relies on the promotion of a `const fn` call
+    //~^ WARN this was previously accepted by the compiler
+    r.0
+};
+
+static ARG: () = {
+    assert_static(&make().1); //~ WARN relies on the promotion of a `const fn` call
+    //~^ WARN this was previously accepted by the compiler
+};
+
+const NAMED: (i32, i32) = make();
+const VIA_NAMED: i32 = {
+    let r: &'static (i32, i32) = &NAMED;
+    r.1
+};
+
+// Borrows in the final value live as long as the constant, so they do not need promotion.
+const FINAL: &(i32, i32) = &make();
+static FINAL_STATIC: &[(i32, i32)] = &[make(), make()];
+
+// Neither do the borrows that are only read through, even after being copied or reborrowed.
+const READ: i32 = {
+    let r = &make();
+    r.0
+};
+static READ_REBORROW: i32 = {
+    let r = &make();
+    let s = r;
+    let second = &s.1;
+    *second
+};
+const READ_ARG: i32 = {
+    let r = &make();
+    first(r.0)
+};
+
+const fn first(x: i32) -> i32 {
+    x
+}
+
+fn main() {
+    assert_eq!(BLOCK + VIA_NAMED, FINAL.0 + FINAL_STATIC[1].1);
+    assert_eq!(READ + READ_REBORROW, READ_ARG + 2);
+}
@@ -4,6 +4,7 @@
 //[opt_with_overflow_checks]compile-flags: -C overflow-checks=on -O
 
 // build-pass
+#![allow(const_fn_call_promotion)]
 #[allow(arithmetic_overflow)]
 
 const fn assert_static<T>(_: &'static T) {}
@@ -0,0 +1,74 @@
+// Check the restrictions on the calls which can be performed as tail calls.
+
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+fn id(x: u32) -> u32 {
+    x
+}
+
+fn add(x: u32, y: u32) -> u32 {
+    x + y
+}
+
+extern "C" fn c_id(x: u32) -> u32 {
+    x
+}
+
+fn from_u64(x: u64) -> u32 {
+    x as u32
+}
+
+#[track_caller]
+fn tracked(x: u32) -> u32 {
+    x
+}
+
+fn not_a_call() -> u32 {
+    become 1
+    //~^ ERROR `become` requires a function call
+}
+
+fn mismatched_signature(x: u32) -> u32 {
+    become add(x, x)
+    //~^ ERROR mismatched signatures
+}
+
+fn mismatched_abi(x: u32) -> u32 {
+    become c_id(x)
+    //~^ ERROR mismatched function ABIs
+}
+
+fn in_closure(x: u32) -> u32 {
+    let f = |y: u32| -> u32 {
+        become id(y)
+        //~^ ERROR `become` is not allowed in closures
+    };
+    f(x)
+}
+
+fn mismatched_input(x: u32) -> u32 {
+    become from_u64(x as u64)
+    //~^ ERROR mismatched types
+}
+
+fn to_track_caller(x: u32) -> u32 {
+    become tracked(x)
+    //~^ ERROR tail calls to or from `#[track_caller]` functions are not supported
+}
+
+#[track_caller]
+fn from_track_caller(x: u32) -> u32 {
+    become id(x)
+    //~^ ERROR tail calls to or from `#[track_caller]` functions are not supported
+}
+
+fn main() {
+    not_a_call();
+    mismatched_signature(1);
+    mismatched_abi(1);
+    in_closure(1);
+    mismatched_input(1);
+    to_track_caller(1);
+    from_track_caller(1);
+}
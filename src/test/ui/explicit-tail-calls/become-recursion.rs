@@ -0,0 +1,56 @@
+// Check that recursion through `become` doesn't grow the stack, which would overflow it with this
+// many calls.
+
+// run-pass
+// compile-flags: -Copt-level=0
+// ignore-wasm32 musttail is not supported
+// ignore-emscripten
+
+#![allow(incomplete_features)]
+#![feature(explicit_tail_calls)]
+
+fn count(n: u64, acc: u64) -> u64 {
+    if n == 0 {
+        return acc;
+    }
+    become count(n - 1, acc + 1)
+}
+
+fn is_even(n: u64) -> bool {
+    if n == 0 {
+        return true;
+    }
+    become is_odd(n - 1)
+}
+
+fn is_odd(n: u64) -> bool {
+    if n == 0 {
+        return false;
+    }
+    become is_even(n - 1)
+}
+
+// Large enough to be passed and returned indirectly.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Big([u64; 8]);
+
+fn swap(n: u64, a: Big, b: Big) -> (Big, Big) {
+    if n == 0 {
+        return (a, b);
+    }
+    become swap(n - 1, b, a)
+}
+
+fn indirect(n: u64, acc: u64) -> u64 {
+    let f: fn(u64, u64) -> u64 = count;
+    become f(n, acc)
+}
+
+fn main() {
+    assert_eq!(count(10_000_000, 0), 10_000_000);
+    assert!(is_even(10_000_000));
+    assert!(!is_odd(10_000_000));
+    assert_eq!(indirect(1000, 0), 1000);
+    let (a, b) = (Big([1; 8]), Big([2; 8]));
+    assert_eq!(swap(1_000_001, a, b), (b, a));
+}
@@ -0,0 +1,10 @@
+const fn double(x: u32) -> u32 {
+    x * 2
+}
+
+const DOUBLE: fn(u32) -> u32 = double;
+
+const FOUR: u32 = DOUBLE(2);
+//~^ ERROR function pointer calls are not allowed in constants
+
+fn main() {}
@@ -0,0 +1,12 @@
+fn f() -> u32 {
+    become g()
+    //~^ ERROR `become` expression is experimental [E0658]
+}
+
+fn g() -> u32 {
+    0
+}
+
+fn main() {
+    f();
+}
@@ -1,5 +1,6 @@
-// check that `move_size_limit is feature-gated
+// check that `move_size_limit` and `future_size_limit` are feature-gated
 
 #![move_size_limit = "42"] //~ ERROR the `#[move_size_limit]` attribute is an experimental feature
+#![future_size_limit = "42"] //~ ERROR the `#[future_size_limit]` attribute is an experimental
 
 fn main() {}
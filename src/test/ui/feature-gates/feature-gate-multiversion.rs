@@ -0,0 +1,4 @@
+#[multiversion(enable = "avx2")] //~ ERROR use of unstable library feature 'multiversion'
+fn foo() {}
+
+fn main() {}
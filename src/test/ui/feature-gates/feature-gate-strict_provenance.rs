@@ -0,0 +1,8 @@
+#![deny(fuzzy_provenance_casts)]
+//~^ ERROR the `fuzzy_provenance_casts` lint is unstable
+//~| ERROR the `fuzzy_provenance_casts` lint is unstable
+#![deny(lossy_provenance_casts)]
+//~^ ERROR the `lossy_provenance_casts` lint is unstable
+//~| ERROR the `lossy_provenance_casts` lint is unstable
+
+fn main() {}
@@ -0,0 +1,22 @@
+// Check that the suggestion to capture a lifetime through a `Captures` helper trait doesn't
+// define the trait again when one is already in scope.
+
+trait Captures<'a> {}
+impl<'a, T: ?Sized> Captures<'a> for T {}
+
+trait Swap: Sized {
+    fn swap(self, other: Self);
+}
+
+impl<T> Swap for &mut T {
+    fn swap(self, other: Self) {
+        std::mem::swap(self, other);
+    }
+}
+
+fn hide_ref<'a, 'b, T: 'static>(x: &'a mut &'b T) -> impl Swap + 'a {
+    x
+    //~^ ERROR hidden type
+}
+
+fn main() {}
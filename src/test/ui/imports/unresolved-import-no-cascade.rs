@@ -0,0 +1,27 @@
+// Check that the errors that only occur because of the type of a path that failed to resolve
+// aren't reported, as they would bury the error about the path.
+
+mod bar {}
+
+use bar::Foo; //~ ERROR unresolved import `bar::Foo`
+
+struct Wrapper<T>(T);
+
+impl<T: Clone> Wrapper<T> {
+    fn get(&self) -> T {
+        self.0.clone()
+    }
+}
+
+fn needs_send<T: Send>(_: T) {}
+
+fn needs_eq<T: PartialEq>(_: &T, _: &T) {}
+
+fn main() {
+    let foo = Foo::new();
+    let wrapper = Wrapper(vec![foo]);
+    let _: usize = wrapper.get().len();
+    needs_send(&wrapper);
+    needs_eq(&wrapper.0, &Vec::new());
+    let _ = wrapper.0[0].field + 1;
+}
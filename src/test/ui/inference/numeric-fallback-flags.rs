@@ -0,0 +1,18 @@
+// run-pass
+// compile-flags: -Zint-fallback=u64 -Zfloat-fallback=f32
+
+fn size_of_val<T>(_: T) -> usize {
+    std::mem::size_of::<T>()
+}
+
+fn main() {
+    assert_eq!(size_of_val(1), 8);
+    assert_eq!(size_of_val(1.0), 4);
+
+    let x = 1 << 40;
+    assert_eq!(x, 1099511627776u64);
+
+    // Explicitly typed literals are unaffected.
+    assert_eq!(size_of_val(1i32), 4);
+    assert_eq!(size_of_val(1.0f64), 8);
+}
@@ -0,0 +1,11 @@
+// compile-flags: -Zint-fallback=u64
+#![deny(numeric_fallback)]
+
+fn main() {
+    let _ = 1; //~ ERROR the type of this literal falls back to `u64`
+    let _ = 1.5; //~ ERROR the type of this literal falls back to `f64`
+
+    // Literals whose type is constrained are not reported.
+    let _: u8 = 1;
+    let _ = 1i32 + 2;
+}
@@ -0,0 +1,35 @@
+// compile-flags: -Ztrack-unification-origins
+// Check that coercions, method calls and the common type of `if` branches are
+// explained in the "type annotations needed" error.
+
+struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    fn get(self) -> T {
+        self.0
+    }
+}
+
+fn wrap<T>() -> Wrapper<T> {
+    loop {}
+}
+
+fn coercion() {
+    let x = Default::default();
+    let y = x;
+    y.0;
+    //~^ ERROR type annotations needed
+}
+
+fn method_call() {
+    wrap().get().0;
+    //~^ ERROR type annotations needed
+}
+
+fn lattice(c: bool) {
+    let x = if c { None } else { None };
+    x.unwrap().0;
+    //~^ ERROR type annotations needed
+}
+
+fn main() {}
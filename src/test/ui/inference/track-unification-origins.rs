@@ -0,0 +1,10 @@
+// compile-flags: -Ztrack-unification-origins
+// Check that the recorded unification history is explained in the
+// "type annotations needed" error.
+
+fn main() {
+    let x = None;
+    let y = x;
+    y.unwrap().method_that_could_exist_on_some_type();
+    //~^ ERROR type annotations needed
+}
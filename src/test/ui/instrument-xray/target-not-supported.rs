@@ -0,0 +1,8 @@
+// Verifies that `-Z instrument-xray` cannot be used with unsupported targets.
+//
+// needs-llvm-components: x86
+// compile-flags: -Z instrument-xray --target x86_64-pc-windows-msvc
+
+#![feature(no_core)]
+#![no_core]
+#![no_main]
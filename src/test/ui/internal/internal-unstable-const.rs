@@ -2,12 +2,14 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 #![feature(staged_api)]
-#![feature(const_fn_floating_point_arithmetic)]
+#![feature(const_refs_to_cell)]
 
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_stable(feature = "rust1", since = "1.0.0")]
-pub const fn foo() -> f32 {
-    1.0 + 1.0 //~ ERROR const-stable function cannot use `#[feature(const_fn_floating_point_arithmetic)]`
+pub const fn foo() -> bool {
+    let cell = std::cell::Cell::new(true);
+    let _r = &cell; //~ ERROR const-stable function cannot use `#[feature(const_refs_to_cell)]`
+    true
 }
 
 fn main() {}
@@ -0,0 +1,8 @@
+// check-pass
+// revisions: address thread
+// compile-flags: -Z branch-protection=bti,pac-ret --target aarch64-unknown-linux-gnu --crate-type=rlib
+// [address] compile-flags: -Z sanitizer=address
+// [thread] compile-flags: -Z sanitizer=thread
+// needs-llvm-components: aarch64
+#![feature(no_core)]
+#![no_core]
@@ -0,0 +1,5 @@
+// check-pass
+// compile-flags: -Z branch-protection=bti --target x86_64-unknown-linux-gnu --crate-type=rlib
+// needs-llvm-components: x86
+#![feature(no_core)]
+#![no_core]
@@ -0,0 +1 @@
+// compile-flags: -Zmir-enable-passes=InstCombine
@@ -0,0 +1,48 @@
+// run-pass
+#![feature(let_else)]
+
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+struct Droppy<'a> {
+    name: &'static str,
+    log: &'a RefCell<Vec<&'static str>>,
+}
+
+impl Droppy<'_> {
+    fn get(&self) -> Option<u8> {
+        Some(1)
+    }
+}
+
+impl Drop for Droppy<'_> {
+    fn drop(&mut self) {
+        self.log.borrow_mut().push(self.name);
+    }
+}
+
+fn main() {
+    // Temporaries in the initializer are dropped at the end of the statement...
+    let log = RefCell::new(Vec::new());
+    {
+        let Some(x) = Droppy { name: "temporary", log: &log }.get() else { panic!() };
+        log.borrow_mut().push("rest");
+        assert_eq!(x, 1);
+    }
+    assert_eq!(*log.borrow(), ["temporary", "rest"]);
+
+    // ...unless the bindings borrow from them.
+    let log = RefCell::new(Vec::new());
+    {
+        let Some(ref x) = Some(Droppy { name: "extended", log: &log }) else { panic!() };
+        log.borrow_mut().push("rest");
+        assert_eq!(x.name, "extended");
+    }
+    assert_eq!(*log.borrow(), ["rest", "extended"]);
+
+    // A guard taken in the initializer is released before the rest of the block.
+    let m = Mutex::new(Some(1));
+    let Some(x) = *m.lock().unwrap() else { panic!() };
+    assert!(m.try_lock().is_ok());
+    assert_eq!(x, 1);
+}
@@ -0,0 +1,13 @@
+// run-rustfix
+
+#![allow(dead_code)]
+
+struct Counter;
+
+impl Counter {
+    fn pick(&self, other: &u32) -> &u32 {
+        other //~ ERROR lifetime mismatch
+    }
+}
+
+fn main() {}
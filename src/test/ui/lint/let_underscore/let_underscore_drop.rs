@@ -0,0 +1,24 @@
+// check-pass
+#![warn(let_underscore_drop)]
+
+struct NontrivialDrop;
+
+impl Drop for NontrivialDrop {
+    fn drop(&mut self) {
+        println!("Dropping!");
+    }
+}
+
+fn main() {
+    let _ = NontrivialDrop; //~WARNING non-binding let on a type that implements `Drop`
+
+    // Binding a place doesn't move out of it, so nothing is dropped.
+    let value = NontrivialDrop;
+    let _ = value;
+
+    // Values without a significant destructor are fine.
+    let _ = Vec::<u8>::new();
+
+    // The type annotation is kept, so only the binding can be suggested.
+    let _: NontrivialDrop = NontrivialDrop; //~WARNING non-binding let on a type that implements
+}
@@ -0,0 +1,14 @@
+use std::sync::{Arc, Mutex, RwLock};
+
+fn main() {
+    let data = Arc::new(Mutex::new(0));
+    let _ = data.lock().unwrap(); //~ERROR non-binding let on a synchronization lock
+    let _ = data.lock(); //~ERROR non-binding let on a synchronization lock
+
+    let rw = RwLock::new(());
+    let _ = rw.read().unwrap(); //~ERROR non-binding let on a synchronization lock
+    let _ = rw.write().unwrap(); //~ERROR non-binding let on a synchronization lock
+
+    // The guard lives until the end of the scope.
+    let _guard = data.lock().unwrap();
+}
@@ -0,0 +1,14 @@
+#![feature(strict_provenance)]
+#![deny(lossy_provenance_casts)]
+
+fn main() {
+    let x: u8 = 37;
+    let _addr: usize = &x as *const u8 as usize;
+    //~^ ERROR cast pointer `*const u8` to integer `usize`
+
+    let _addr_32bit = &x as *const u8 as u32;
+    //~^ ERROR cast pointer `*const u8` to integer `u32`
+
+    // Casts of function pointers aren't linted, there is no `addr` method on them.
+    let _fn_addr = main as fn() as usize;
+}
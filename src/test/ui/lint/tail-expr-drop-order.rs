@@ -0,0 +1,28 @@
+#![deny(tail_expr_drop_order)]
+
+use std::sync::Mutex;
+
+struct Logger;
+
+impl Drop for Logger {
+    fn drop(&mut self) {}
+}
+
+fn tail(m: &Mutex<Vec<u8>>) -> usize {
+    let _logger = Logger;
+    m.lock().unwrap().len()
+    //~^ ERROR temporary with significant `Drop` in tail expression
+}
+
+fn tail_bound(m: &Mutex<Vec<u8>>) -> usize {
+    let _logger = Logger;
+    let guard = m.lock().unwrap();
+    guard.len()
+}
+
+fn tail_no_locals(m: &Mutex<Vec<u8>>) -> usize {
+    let _ = Logger;
+    m.lock().unwrap().len()
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Test that the LUB of a higher-ranked function type and a less general one is
+// the less general type, rather than requiring the two types to be equal. This
+// commonly comes up when coercing function items in match arms.
+//
+// check-pass
+
+fn generic(_: &u8) {}
+
+fn not_generic(_: &'static u8) {}
+
+fn main() {
+    let f = match 22 {
+        0 => generic,
+        _ => not_generic,
+    };
+    f(&0);
+
+    let g = if true { not_generic } else { generic };
+    let _: fn(&'static u8) = g;
+}
@@ -1,20 +1,20 @@
 // Test taking the LUB of two function types that are not equatable but where one is more
 // general than the other. Test the case where the more general type (`x`) is the first
 // match arm specifically.
+//
+// check-pass
 
 fn foo(x: for<'a, 'b> fn(&'a u8, &'b u8) -> &'a u8, y: for<'a> fn(&'a u8, &'a u8) -> &'a u8) {
-    // The two types above are not equivalent. With the older LUB/GLB
-    // algorithm, this may have worked (I don't remember), but now it
-    // doesn't because we require equality.
+    // The two types above are not equivalent. We used to require equality
+    // here, but as `x` is a subtype of `y`, the LUB is just the type of `y`.
     let z = match 22 {
         0 => x,
-        _ => y, //~ ERROR `match` arms have incompatible types
+        _ => y,
     };
 }
 
 fn foo_cast(x: for<'a, 'b> fn(&'a u8, &'b u8) -> &'a u8, y: for<'a> fn(&'a u8, &'a u8) -> &'a u8) {
-    // But we can *upcast* explicitly the type of `x` and figure
-    // things out:
+    // We can also *upcast* explicitly the type of `x`:
     let z = match 22 {
         0 => x as for<'a> fn(&'a u8, &'a u8) -> &'a u8,
         _ => y,
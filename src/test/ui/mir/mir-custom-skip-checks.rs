@@ -0,0 +1,47 @@
+// Check that the unsafety check and const checking, which run in the phases before the dialect of
+// these `#[custom_mir]` bodies, are skipped: the bodies don't pass them as written. The MIR
+// validator only checks the bodies from the phase of their dialect on.
+
+// run-pass
+// compile-flags: -Zvalidate-mir
+
+#![feature(custom_mir, core_intrinsics)]
+
+extern crate core;
+use core::intrinsics::mir::*;
+
+// Dereferencing a raw pointer outside of an `unsafe` block.
+#[custom_mir(dialect = "analysis", phase = "initial")]
+fn read(p: *const i32) -> i32 {
+    mir!({
+        RET = *p;
+        Return()
+    })
+}
+
+// Calling a non-`const` function from a `const fn`.
+#[custom_mir(dialect = "runtime", phase = "initial")]
+const fn call_double(x: i32) -> i32 {
+    mir!(
+        let temp: i32;
+
+        {
+            Call(temp, ret, double(x))
+        }
+
+        ret = {
+            RET = temp;
+            Return()
+        }
+    )
+}
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+fn main() {
+    let x = 3;
+    assert_eq!(read(&x), 3);
+    assert_eq!(call_double(4), 8);
+}
@@ -0,0 +1,82 @@
+// Check that functions written in custom MIR, in each of the dialects, behave as written.
+
+// run-pass
+
+#![feature(custom_mir, core_intrinsics)]
+
+extern crate core;
+use core::intrinsics::mir::*;
+
+#[custom_mir(dialect = "built")]
+fn deref(x: &i32) -> i32 {
+    mir!({
+        RET = *x;
+        Return()
+    })
+}
+
+#[custom_mir(dialect = "built")]
+fn checked(x: u8) -> (u8, bool) {
+    mir!({
+        RET = Checked(x + 1);
+        Return()
+    })
+}
+
+#[custom_mir(dialect = "analysis", phase = "initial")]
+fn call(x: i32) -> i32 {
+    mir!(
+        let temp: i32;
+
+        {
+            Call(temp, ret, double(x))
+        }
+
+        ret = {
+            RET = temp + 1;
+            Return()
+        }
+    )
+}
+
+fn double(x: i32) -> i32 {
+    x * 2
+}
+
+#[custom_mir(dialect = "runtime", phase = "optimized")]
+fn switch(x: u32) -> u32 {
+    mir!(
+        {
+            match x {
+                1 => one,
+                2 => two,
+                _ => other,
+            }
+        }
+
+        one = {
+            RET = 10;
+            Return()
+        }
+
+        two = {
+            RET = 20;
+            Return()
+        }
+
+        other = {
+            RET = x;
+            Return()
+        }
+    )
+}
+
+fn main() {
+    assert_eq!(deref(&3), 3);
+    assert_eq!(checked(1), (2, false));
+    assert_eq!(checked(255), (0, true));
+    assert_eq!(call(4), 9);
+    assert_eq!(switch(1), 10);
+    assert_eq!(switch(2), 20);
+    assert_eq!(switch(7), 7);
+}
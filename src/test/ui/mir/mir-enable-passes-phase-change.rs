@@ -0,0 +1,13 @@
+// Check that the MIR phase still changes when a pass changing it is disabled, the passes after it
+// rely on the phase.
+
+// build-pass
+// compile-flags: -Zmir-enable-passes=-StateTransform -Zvalidate-mir
+
+fn add(x: u32, y: u32) -> u32 {
+    x + y
+}
+
+fn main() {
+    let _ = add(1, 2);
+}
@@ -0,0 +1,17 @@
+// Check that the unknown passes of `-Zmir-enable-passes` and of the `#[rustc_mir(enable_passes)]`
+// attribute are reported once, even though the passes of several phases run on each body.
+
+// compile-flags: -Zmir-enable-passes=+NotAPass,+SimplifyCfg-initial
+
+#![feature(rustc_attrs)]
+
+#[rustc_mir(enable_passes = "-AlsoNotAPass,+CopyProp,Inline")]
+//~^ WARN MIR pass `AlsoNotAPass` is unknown and will be ignored
+//~| ERROR `Inline` must start with `+` or `-`
+fn f(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    f(1);
+}
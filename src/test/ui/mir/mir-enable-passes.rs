@@ -0,0 +1,25 @@
+// Check that the passes forced on by `-Zmir-enable-passes` run at `-Zmir-opt-level=0`, and that the
+// `#[rustc_mir(enable_passes)]` attribute of a function takes precedence over the flag.
+
+// run-pass
+// compile-flags: -Zmir-opt-level=0 -Zmir-enable-passes=+CopyProp,+GVN -Zvalidate-mir
+
+#![feature(rustc_attrs)]
+
+fn forced(x: String) -> usize {
+    let y = x;
+    let z = y;
+    z.len() + z.len()
+}
+
+#[rustc_mir(enable_passes = "-CopyProp,-GVN,+DestinationPropagation")]
+fn overridden(x: String) -> usize {
+    let y = x;
+    let z = y;
+    z.len() + z.len()
+}
+
+fn main() {
+    assert_eq!(forced(String::from("abc")), 6);
+    assert_eq!(overridden(String::from("abcd")), 8);
+}
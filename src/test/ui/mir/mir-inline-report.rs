@@ -0,0 +1,31 @@
+// Check that `-Zinline-mir-report` explains the inlining decision at each call site, and that the
+// `#[inline(never)]` and `#[inline(always)]` hints override the cost model.
+
+// build-pass
+// compile-flags: -Zinline-mir -Zinline-mir-report
+
+#[inline]
+fn small(x: u32) -> u32 {
+    x
+}
+
+#[inline(never)]
+fn never(x: u32) -> u32 {
+    x
+}
+
+#[inline(always)]
+fn always(x: u32) -> u32 {
+    x
+}
+
+fn not_exported(x: u32) -> u32 {
+    x
+}
+
+fn main() {
+    let x = small(1);
+    let y = never(x);
+    let z = always(y);
+    not_exported(z);
+}
@@ -0,0 +1,73 @@
+// Check that the discriminant checks of the MIR validator accept the enums whose layouts use
+// niches, explicit discriminants or leave out uninhabited variants, at all optimization levels.
+
+// run-pass
+// revisions: opt0 opt3
+//[opt0] compile-flags: -Zvalidate-mir -Zmir-opt-level=0
+//[opt3] compile-flags: -Zvalidate-mir -Zmir-opt-level=3
+
+#![feature(never_type)]
+
+#[derive(Clone, Copy)]
+enum Niche {
+    A(bool),
+    B,
+    C,
+}
+
+#[derive(Clone, Copy)]
+#[repr(i8)]
+enum Explicit {
+    Neg = -1,
+    Zero = 0,
+    Big = 100,
+}
+
+enum Uninhabited {
+    A(u32),
+    #[allow(dead_code)]
+    B(!, u32),
+    C,
+}
+
+fn niche(x: Niche) -> u32 {
+    match x {
+        Niche::A(true) => 1,
+        Niche::A(false) => 2,
+        Niche::B => 3,
+        Niche::C => 4,
+    }
+}
+
+fn explicit(x: Explicit) -> i32 {
+    match x {
+        Explicit::Neg => 1,
+        Explicit::Zero => 2,
+        Explicit::Big => 3,
+    }
+}
+
+fn uninhabited(x: u32) -> Uninhabited {
+    if x == 0 { Uninhabited::C } else { Uninhabited::A(x) }
+}
+
+fn unwrap_uninhabited(x: Uninhabited) -> u32 {
+    match x {
+        Uninhabited::A(x) => x,
+        Uninhabited::B(never, _) => never,
+        Uninhabited::C => 0,
+    }
+}
+
+fn main() {
+    assert_eq!(niche(Niche::A(true)), 1);
+    assert_eq!(niche(Niche::A(false)), 2);
+    assert_eq!(niche(Niche::B), 3);
+    assert_eq!(niche(Niche::C), 4);
+    assert_eq!(explicit(Explicit::Neg), 1);
+    assert_eq!(explicit(Explicit::Zero), 2);
+    assert_eq!(explicit(Explicit::Big), 3);
+    assert_eq!(unwrap_uninhabited(uninhabited(0)), 0);
+    assert_eq!(unwrap_uninhabited(uninhabited(7)), 7);
+    assert_eq!(Some(Niche::B).map(niche), Some(3));
+}
@@ -0,0 +1,22 @@
+// Check that the generic arguments that nested types have in common at the start or at the end
+// are collapsed in the notes of type mismatches, and that `-Z verbose-type-diffs` shows them.
+
+// revisions: elided full
+//[full] compile-flags: -Z verbose-type-diffs
+
+struct Wide<A, B, C, D>(A, B, C, D);
+struct Wrap<T>(T);
+
+fn suffix(x: Wrap<Wide<u8, u16, u32, u64>>) -> Wrap<Wide<u8, u16, u32, i64>> {
+    x //~ ERROR mismatched types
+}
+
+fn prefix(x: Wrap<Wide<u8, u16, u32, u64>>) -> Wrap<Wide<u8, i16, u32, u64>> {
+    x //~ ERROR mismatched types
+}
+
+fn outermost(x: Wide<u8, u16, u32, u64>) -> Wide<u8, u16, u32, i64> {
+    x //~ ERROR mismatched types
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#![deny(never_type_fallback_change)]
+#![allow(unreachable_code)]
+
+fn foo<T>(_: T) {}
+
+fn main() {
+    foo(return);
+    //~^ ERROR the type of this expression will fall back to `!` instead of `()`
+}
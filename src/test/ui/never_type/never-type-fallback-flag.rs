@@ -0,0 +1,18 @@
+// check-pass
+// compile-flags: -Znever-type-fallback=never
+
+// Checks that `-Znever-type-fallback=never` makes diverging type variables
+// fall back to `!` even without `#![feature(never_type_fallback)]`.
+
+#![feature(never_type)]
+#![allow(unreachable_code)]
+
+trait OnlyNever {}
+
+impl OnlyNever for ! {}
+
+fn foo<T: OnlyNever>(_: T) {}
+
+fn main() {
+    foo(return);
+}
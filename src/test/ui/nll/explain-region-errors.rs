@@ -0,0 +1,12 @@
+// Test that `-Zexplain-region-errors` lists the constraints that led to a region error.
+
+// compile-flags: -Zexplain-region-errors
+// normalize-stderr-test "'_#\d+r" -> "'_#Nr"
+
+#![feature(nll)]
+
+fn foo<'a, 'b>(x: &'a usize) -> &'b usize {
+    x //~ERROR lifetime may not live long enough
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+// run-pass
+// compile-flags:-Zpolymorphize=on -Csymbol-mangling-version=v0
+
+// Check that a generic parameter of a closure's or generator's parent isn't mistaken for the
+// tupled upvars of the closure or generator when it is substituted with the same type as them.
+
+#![feature(generators, generator_trait)]
+
+use std::ops::{Generator, GeneratorState};
+use std::pin::Pin;
+
+fn unused<T>() -> u32 {
+    let x = 1u32;
+    let add = move |y: u32| x + y;
+    add(2)
+}
+
+fn unused_in_generator<T>() -> u32 {
+    let x = 1u32;
+    let mut gen = move || {
+        yield x;
+        x + 2
+    };
+    match Pin::new(&mut gen).resume(()) {
+        GeneratorState::Yielded(1) => {}
+        _ => panic!(),
+    }
+    match Pin::new(&mut gen).resume(()) {
+        GeneratorState::Complete(returned) => returned,
+        _ => panic!(),
+    }
+}
+
+fn main() {
+    assert_eq!(unused::<(u32,)>(), 3);
+    assert_eq!(unused::<u8>(), 3);
+    assert_eq!(unused_in_generator::<(u32,)>(), 3);
+    assert_eq!(unused_in_generator::<u8>(), 3);
+}
@@ -0,0 +1,18 @@
+// only-x86
+// only-windows
+#![allow(incomplete_features)]
+#![feature(raw_dylib)]
+
+#[link(name = "foo", kind = "raw-dylib", import_name_type = "unknown")]
+//~^ ERROR unknown import name type `unknown`, expected one of: decorated, noprefix, undecorated
+extern "C" {}
+
+#[link(name = "foo", kind = "raw-dylib", import_name_type = 6)]
+//~^ ERROR must be of the form `#[link(import_name_type = "...")]`
+extern "C" {}
+
+#[link(name = "foo", kind = "raw-dylib", import_name_type = "decorated", import_name_type = "noprefix")]
+//~^ ERROR multiple `import_name_type` arguments in a single `#[link]` attribute
+extern "C" {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// revisions: x64 aarch64
+//
+// [x64] needs-llvm-components: x86
+// [x64] compile-flags: --target=x86_64-pc-windows-msvc --crate-type=rlib
+// [aarch64] needs-llvm-components: aarch64
+// [aarch64] compile-flags: --target=aarch64-pc-windows-msvc --crate-type=rlib
+#![no_core]
+#![allow(incomplete_features)]
+#![feature(no_core, raw_dylib)]
+
+#[link(name = "foo", kind = "raw-dylib", import_name_type = "decorated")]
+//~^ ERROR import name type is only supported on x86
+extern "C" {}
@@ -0,0 +1,11 @@
+// KCFI doesn't have a runtime library, so unlike other sanitizers it can be used with a statically
+// linked libc.
+//
+// check-pass
+// min-llvm-version: 16
+// compile-flags: -Z sanitizer=kcfi -C target-feature=+crt-static --target x86_64-unknown-linux-gnu
+// needs-llvm-components: x86
+
+#![feature(no_core)]
+#![no_core]
+#![no_main]
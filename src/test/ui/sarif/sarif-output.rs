@@ -0,0 +1,13 @@
+// compile-flags: --error-format=sarif -Z unstable-options
+// Check the SARIF log of an error with a label on its primary span and a related location.
+
+struct Foo {
+    x: i32,
+}
+
+fn main() {
+    let _ = Foo {
+        x: 0,
+        x: 1,
+    };
+}
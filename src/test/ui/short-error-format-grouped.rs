@@ -0,0 +1,16 @@
+// compile-flags: --error-format=short -Z group-diagnostics
+// Check that identical diagnostics are reported once with their number of occurrences and all
+// their locations, followed by a summary of the number of errors and warnings of each code and
+// lint.
+
+fn foo(_: u32) {}
+
+fn main() {
+    let _ = (1);
+    foo("Bonjour".to_owned());
+    foo("Hello".to_owned());
+    let x = 0u32;
+    x.salut();
+    foo("Hallo".to_owned());
+    let _ = (2);
+}
@@ -0,0 +1,11 @@
+// compile-flags: --error-format=short-json -Z unstable-options
+// Check that identical diagnostics are written once, with the locations of all of them.
+
+fn foo(_: u32) {}
+
+fn main() {
+    foo("Bonjour".to_owned());
+    let x = 0u32;
+    x.salut();
+    foo("Hello".to_owned());
+}
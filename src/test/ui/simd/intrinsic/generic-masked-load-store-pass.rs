@@ -0,0 +1,38 @@
+// run-pass
+// ignore-emscripten
+
+// Test that the simd_masked_{load,store} intrinsics only access the memory of enabled lanes.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct x4<T>(pub T, pub T, pub T, pub T);
+
+extern "platform-intrinsic" {
+    fn simd_masked_load<M, P, T>(mask: M, pointer: P, values: T) -> T;
+    fn simd_masked_store<M, P, T>(mask: M, pointer: P, values: T) -> ();
+}
+
+fn main() {
+    let mut x = [0_u16, 1, 2, 3, 4, 5];
+
+    let default = x4(10_u16, 11, 12, 13);
+    let mask = x4(-1_i8, 0, -1, 0);
+
+    unsafe {
+        // The last enabled lane is `x[4]`, so the load doesn't read past the end of `x`.
+        let loaded = simd_masked_load(mask, x.as_ptr().add(2), default);
+        assert_eq!(loaded, x4(2, 11, 4, 13));
+
+        simd_masked_store(mask, x.as_mut_ptr().add(2), default);
+        assert_eq!(x, [0, 1, 10, 3, 12, 5]);
+
+        // Nothing is accessed when all lanes are disabled, so the pointer may be dangling.
+        let none = x4(0_i8, 0, 0, 0);
+        let dangling = std::ptr::NonNull::<u16>::dangling().as_ptr();
+        assert_eq!(simd_masked_load(none, dangling as *const u16, default), default);
+        simd_masked_store(none, dangling, default);
+    }
+}
@@ -0,0 +1,51 @@
+// build-fail
+
+// Test that the simd_masked_{load,store} intrinsics produce ok-ish error
+// messages when misused.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct f32x2(pub f32, pub f32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct f32x4(pub f32, pub f32, pub f32, pub f32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct u32x4(pub u32, pub u32, pub u32, pub u32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct i32x4(pub i32, pub i32, pub i32, pub i32);
+
+extern "platform-intrinsic" {
+    fn simd_masked_load<M, P, T>(mask: M, pointer: P, values: T) -> T;
+    fn simd_masked_store<M, P, T>(mask: M, pointer: P, values: T);
+}
+
+fn main() {
+    let m = i32x4(0, 0, 0, 0);
+    let z = f32x4(0.0, 0.0, 0.0, 0.0);
+    let mut a = [0_f32; 4];
+
+    unsafe {
+        simd_masked_load(m, a.as_ptr(), z);
+        simd_masked_store(m, a.as_mut_ptr(), z);
+
+        simd_masked_load(m, a.as_ptr(), f32x2(0.0, 0.0));
+        //~^ ERROR expected third argument with length 4 (same as input type `i32x4`), found `f32x2` with length 2
+
+        simd_masked_load(u32x4(0, 0, 0, 0), a.as_ptr(), z);
+        //~^ ERROR expected element type `u32` of first argument `u32x4` to be a signed integer type
+
+        simd_masked_load(m, &0_u32 as *const u32, z);
+        //~^ ERROR expected second argument `*const u32` to be a pointer to the element type `f32` of the third argument `f32x4`, found `*const u32` != `*_ f32`
+
+        simd_masked_store(m, a.as_ptr(), z);
+        //~^ ERROR expected second argument `*const f32` to be a pointer to the element type `f32` of the third argument `f32x4`, found `*const f32` != `*mut f32`
+    }
+}
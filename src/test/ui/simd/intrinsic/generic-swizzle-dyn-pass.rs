@@ -0,0 +1,28 @@
+// run-pass
+// ignore-emscripten
+
+// Test that the simd_swizzle_dyn intrinsic selects the lanes of its indices, and zero for the
+// indices that are out of range.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct x4<T>(pub T, pub T, pub T, pub T);
+
+extern "platform-intrinsic" {
+    fn simd_swizzle_dyn<T, U>(values: T, indices: U) -> T;
+}
+
+fn main() {
+    let values = x4(10_u16, 11, 12, 13);
+    let floats = x4(1.0_f32, 2.0, 3.0, 4.0);
+
+    unsafe {
+        assert_eq!(simd_swizzle_dyn(values, x4(3_u8, 2, 1, 0)), x4(13, 12, 11, 10));
+        assert_eq!(simd_swizzle_dyn(values, x4(0_u32, 0, 2, 2)), x4(10, 10, 12, 12));
+        assert_eq!(simd_swizzle_dyn(values, x4(1_u8, 4, 255, 3)), x4(11, 0, 0, 13));
+        assert_eq!(simd_swizzle_dyn(floats, x4(2_u64, u64::MAX, 0, 1)), x4(3.0, 0.0, 1.0, 2.0));
+    }
+}
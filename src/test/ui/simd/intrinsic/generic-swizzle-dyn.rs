@@ -0,0 +1,44 @@
+// build-fail
+
+// Test that the simd_swizzle_dyn intrinsic produces ok-ish error
+// messages when misused.
+
+#![feature(repr_simd, platform_intrinsics)]
+#![allow(non_camel_case_types)]
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct f32x4(pub f32, pub f32, pub f32, pub f32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct u32x2(pub u32, pub u32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct u32x4(pub u32, pub u32, pub u32, pub u32);
+
+#[repr(simd)]
+#[derive(Copy, Clone)]
+pub struct i32x4(pub i32, pub i32, pub i32, pub i32);
+
+extern "platform-intrinsic" {
+    fn simd_swizzle_dyn<T, U>(values: T, indices: U) -> T;
+}
+
+fn main() {
+    let v = f32x4(0.0, 1.0, 2.0, 3.0);
+
+    unsafe {
+        simd_swizzle_dyn(v, u32x4(3, 2, 1, 0));
+
+        simd_swizzle_dyn(v, u32x2(1, 0));
+        //~^ ERROR expected second argument with length 4 (same as input type `f32x4`), found `u32x2` with length 2
+
+        simd_swizzle_dyn(v, i32x4(3, 2, 1, 0));
+        //~^ ERROR expected element type `i32` of second argument `i32x4` to be an unsigned integer type
+
+        simd_swizzle_dyn(0_u32, 0_u32);
+        //~^ ERROR expected SIMD input type, found non-SIMD `u32`
+    }
+}
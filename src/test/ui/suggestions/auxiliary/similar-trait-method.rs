@@ -0,0 +1,17 @@
+pub trait Greet {
+    fn greet_loudly(&self);
+}
+
+impl Greet for u32 {
+    fn greet_loudly(&self) {}
+}
+
+mod private {
+    pub trait Whisper {
+        fn greet_quietly(&self);
+    }
+
+    impl Whisper for u32 {
+        fn greet_quietly(&self) {}
+    }
+}
@@ -0,0 +1,11 @@
+#![feature(staged_api)]
+#![stable(feature = "stable_test_feature", since = "1.0.0")]
+
+#[unstable(feature = "unstable_test_feature", issue = "none")]
+pub trait Shout {
+    fn shout_loudly(&self);
+}
+
+impl Shout for u32 {
+    fn shout_loudly(&self) {}
+}
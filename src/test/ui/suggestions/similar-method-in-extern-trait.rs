@@ -0,0 +1,19 @@
+// Check that a method with a similar name is suggested when it's provided by a trait of another
+// crate that isn't in scope, along with a `use` for it, unless the trait can't be named or is
+// unstable.
+// aux-build:similar-trait-method.rs
+// aux-build:similar-unstable-trait-method.rs
+
+extern crate similar_trait_method;
+extern crate similar_unstable_trait_method;
+
+fn main() {
+    1u32.greet_loud();
+    //~^ ERROR no method named `greet_loud` found for type `u32` in the current scope
+    //~| HELP there is an associated function with a similar name
+    //~| HELP the following trait is implemented but not in scope
+    1u32.greet_quiet();
+    //~^ ERROR no method named `greet_quiet` found for type `u32` in the current scope
+    1u32.shout_loud();
+    //~^ ERROR no method named `shout_loud` found for type `u32` in the current scope
+}
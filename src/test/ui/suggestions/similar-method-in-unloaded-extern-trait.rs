@@ -0,0 +1,10 @@
+// Check the limitation of the suggestion of similarly named trait methods: only the crates that
+// are loaded are searched, so nothing is suggested for the traits of a crate that is passed with
+// `--extern` but that the current crate doesn't refer to.
+// edition:2018
+// aux-crate:similar_trait_method=similar-trait-method.rs
+
+fn main() {
+    1u32.greet_loud();
+    //~^ ERROR no method named `greet_loud` found for type `u32` in the current scope
+}
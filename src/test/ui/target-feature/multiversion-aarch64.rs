@@ -0,0 +1,14 @@
+// run-pass
+// only-aarch64
+// Check that `#[multiversion]` detects the target features of other architectures than x86.
+
+#![feature(multiversion, aarch64_target_feature)]
+
+#[multiversion(enable = "sve", enable = "neon,aes")]
+fn sum(xs: &[u32]) -> u32 {
+    xs.iter().sum()
+}
+
+fn main() {
+    assert_eq!(sum(&[1, 2, 3]), 6);
+}
@@ -0,0 +1,23 @@
+// edition:2018
+
+#![feature(multiversion)]
+
+#[multiversion(enable = "avx2")]
+struct S; //~ ERROR the `#[multiversion]` attribute can only be applied to free functions
+
+#[multiversion(avx2)] //~ ERROR expected `enable = "feature,..."`
+fn a() {}
+
+#[multiversion()] //~ ERROR `#[multiversion]` needs at least one `enable` list
+fn b() {}
+
+#[multiversion(enable = "avx2")]
+const fn c() {} //~ ERROR `#[multiversion]` can't be applied to `const` functions
+
+#[multiversion(enable = "avx2")]
+async fn d() {} //~ ERROR `#[multiversion]` can't be applied to `async` functions
+
+#[multiversion(enable = "avx2")]
+fn e() -> impl Copy {} //~ ERROR `impl Trait` can't be used in the signature of a `#[multiversion]`
+
+fn main() {}
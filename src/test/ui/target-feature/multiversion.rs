@@ -0,0 +1,58 @@
+// run-pass
+// only-x86_64
+// Check that `#[multiversion]` functions behave like their body, whichever version the CPU picks.
+
+#![feature(multiversion)]
+
+#[multiversion(enable = "avx2,fma", enable = "sse4.2")]
+fn sum(xs: &[f32]) -> f32 {
+    xs.iter().sum()
+}
+
+#[multiversion(enable = "avx2")]
+fn largest<T: PartialOrd + Copy, const N: usize>(xs: [T; N]) -> T {
+    let mut largest = xs[0];
+    for x in xs {
+        if x > largest {
+            largest = x;
+        }
+    }
+    largest
+}
+
+#[multiversion(enable = "sse2")]
+fn swap((a, b): (u8, u8), mut count: u32) -> (u8, u8, u32) {
+    count += 1;
+    (b, a, count)
+}
+
+#[multiversion(enable = "popcnt")]
+unsafe fn read(ptr: *const u32) -> u32 {
+    *ptr
+}
+
+// The items of the body are only defined once.
+#[multiversion(enable = "avx2", enable = "sse4.1")]
+fn with_items(x: u32) -> u32 {
+    #[no_mangle]
+    extern "C" fn multiversion_with_items_helper(x: u32) -> u32 {
+        x + 1
+    }
+    static OFFSET: u32 = 2;
+    multiversion_with_items_helper(x) + OFFSET
+}
+
+fn main() {
+    #[multiversion(enable = "avx")]
+    fn double(x: u64) -> u64 {
+        x * 2
+    }
+
+    assert_eq!(sum(&[1.0, 2.0, 3.5]), 6.5);
+    assert_eq!(largest([3, 9, 2]), 9);
+    assert_eq!(largest(['a', 'z']), 'z');
+    assert_eq!(swap((1, 2), 0), (2, 1, 1));
+    assert_eq!(unsafe { read(&7) }, 7);
+    assert_eq!(double(21), 42);
+    assert_eq!(with_items(1), 4);
+}
@@ -0,0 +1,13 @@
+// compile-flags: -Z unstable-options --diagnostic-width=60
+
+// This test checks that `--diagnostic-width` wraps the labels of diagnostics to fit in the given
+// width.
+
+fn touch<A>(_a: &A) {}
+
+fn main() {
+    let x = "hi".to_string();
+    let _y = x;
+    touch(&x);
+    //~^ ERROR borrow of moved value: `x`
+}
@@ -0,0 +1,10 @@
+// check-pass
+// compile-flags: -Z unstable-options --diagnostic-width=52
+
+// This test checks that `--diagnostic-width` wraps the notes of diagnostics to fit in the given
+// width.
+
+fn main() {
+    Err::<(), ()>(());
+    //~^ WARN unused `Result` that must be used
+}
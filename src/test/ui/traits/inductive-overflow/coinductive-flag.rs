@@ -0,0 +1,19 @@
+// check-pass
+// compile-flags: -Zcoinductive-traits
+// Check that with `-Zcoinductive-traits`, mutually recursive where-clauses
+// hold instead of overflowing.
+
+trait Trait<T> {}
+
+struct A;
+struct B;
+
+impl Trait<B> for A where B: Trait<A> {}
+impl Trait<A> for B where A: Trait<B> {}
+
+fn require<X: Trait<Y>, Y>() {}
+
+fn main() {
+    require::<A, B>();
+    require::<B, A>();
+}
@@ -0,0 +1,16 @@
+// Check that `-Z obligation-chain` notes the requirements that led to an unsatisfied bound as a
+// chain, cut after the given depth.
+// compile-flags: -Z obligation-chain=2
+
+use std::rc::Rc;
+
+struct Inner(Rc<u8>);
+struct Middle(Inner);
+struct Outer(Middle);
+
+fn is_send<T: Send>() {}
+
+fn main() {
+    is_send::<Outer>();
+    //~^ ERROR `Rc<u8>` cannot be sent between threads safely
+}
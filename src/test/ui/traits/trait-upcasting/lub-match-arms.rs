@@ -0,0 +1,49 @@
+// run-pass
+// Check that the LUB of a subtrait object and a supertrait object (e.g. of
+// `match` arms) is the supertrait object, with the subtrait arms upcast.
+
+#![feature(trait_upcasting)]
+#![allow(incomplete_features)]
+
+trait Super {
+    fn name(&self) -> &'static str;
+}
+
+trait Sub: Super {}
+
+struct A;
+struct B;
+
+impl Super for A {
+    fn name(&self) -> &'static str {
+        "A"
+    }
+}
+impl Sub for A {}
+
+impl Super for B {
+    fn name(&self) -> &'static str {
+        "B"
+    }
+}
+
+fn pick<'a>(n: u8, sub: &'a dyn Sub, sup: &'a dyn Super) -> &'a dyn Super {
+    // The second arm is coerced to `&dyn Sub`, so by the time we see the
+    // third one, the first two arms can no longer simply be coerced to its
+    // type and we have to compute the LUB instead.
+    let x = match n {
+        0 => sub,
+        1 => &A,
+        _ => sup,
+    };
+    x
+}
+
+fn main() {
+    assert_eq!(pick(0, &A, &B).name(), "A");
+    assert_eq!(pick(1, &A, &B).name(), "A");
+    assert_eq!(pick(2, &A, &B).name(), "B");
+
+    let boxed = if true { Box::new(A) as Box<dyn Sub> } else { Box::new(B) as Box<dyn Super> };
+    assert_eq!(boxed.name(), "A");
+}
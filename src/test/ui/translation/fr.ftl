@@ -0,0 +1,4 @@
+# Translations used by `translate-additional-ftl.rs`. The `.prev-span` label of the diagnostic is
+# left untranslated on purpose, so that it is emitted in English.
+typeck-field-multiply-specified-in-initializer = champ `{ $ident }` spécifié plus d'une fois
+    .span = utilisé plus d'une fois
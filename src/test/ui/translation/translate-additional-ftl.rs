@@ -0,0 +1,16 @@
+// Check that diagnostics are translated with the Fluent resource given to
+// `-Z translate-additional-ftl`, and that their untranslated messages are emitted in English.
+
+// compile-flags: -Z translate-lang=fr -Z translate-additional-ftl={{src-base}}/translation/fr.ftl
+
+struct Foo {
+    x: i32,
+}
+
+fn main() {
+    let _ = Foo {
+        x: 0,
+        x: 1,
+        //~^ ERROR champ `x` spécifié plus d'une fois
+    };
+}
@@ -0,0 +1,16 @@
+// Check that diagnostics are emitted in English, with a warning, when the sysroot has no
+// translations into the language given to `-Z translate-lang`.
+
+// compile-flags: -Z translate-lang=zz
+
+struct Foo {
+    x: i32,
+}
+
+fn main() {
+    let _ = Foo {
+        x: 0,
+        x: 1,
+        //~^ ERROR field `x` specified more than once
+    };
+}
@@ -10,6 +10,5 @@ fn foo() -> Foo {
 }
 
 fn bar() -> Foo {
-    //~^ ERROR concrete type differs from previous
-    42i32
+    42i32 //~ ERROR concrete type differs from previous
 }
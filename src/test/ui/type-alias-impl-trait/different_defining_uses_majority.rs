@@ -0,0 +1,18 @@
+#![feature(type_alias_impl_trait)]
+
+fn main() {}
+
+// three definitions, of which only one has a different type
+type Foo = impl std::fmt::Debug;
+
+fn foo() -> Foo {
+    ""
+}
+
+fn bar() -> Foo {
+    42i32 //~ ERROR concrete type differs from previous
+}
+
+fn baz() -> Foo {
+    "baz"
+}
@@ -13,6 +13,5 @@ fn two<T: Debug, U>(t: T, _: U) -> Two<T, U> {
 }
 
 fn three<T, U: Debug>(_: T, u: U) -> Two<T, U> {
-    //~^ ERROR concrete type differs from previous defining opaque type use
-    u
+    u //~ ERROR concrete type differs from previous defining opaque type use
 }
@@ -7,7 +7,6 @@
 fn main() {
     type Opaque = impl Debug;
     fn _unused() -> Opaque { String::new() }
-    let null = || -> Opaque { 0 };
-    //~^ ERROR: concrete type differs from previous defining opaque type use
+    let null = || -> Opaque { 0 }; //~ ERROR concrete type differs from previous
     println!("{:?}", null());
 }
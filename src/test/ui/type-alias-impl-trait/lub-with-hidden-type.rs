@@ -0,0 +1,20 @@
+// check-pass
+// Checks that computing the LUB of an opaque type and another type inside
+// the opaque type's defining scope uses the other type as the hidden type.
+
+#![feature(type_alias_impl_trait)]
+
+type Tait = impl Copy;
+
+fn define(b: bool) -> Tait {
+    let x = if b { define(false) } else { 0u32 };
+    let y = match b {
+        true => 1u32,
+        false => x,
+    };
+    y
+}
+
+fn main() {
+    let _: Tait = define(true);
+}
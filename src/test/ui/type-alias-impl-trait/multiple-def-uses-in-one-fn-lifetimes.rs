@@ -3,7 +3,7 @@
 type Foo<'a, 'b> = impl std::fmt::Debug;
 
 fn foo<'x, 'y>(i: &'x i32, j: &'y i32) -> (Foo<'x, 'y>, Foo<'y, 'x>) {
-    (i, i) //~^ ERROR concrete type differs from previous
+    (i, i) //~ ERROR concrete type differs from previous
 }
 
 fn main() {}
@@ -7,8 +7,7 @@
 type X<A: ToString + Clone, B: ToString + Clone> = impl ToString;
 
 fn f<A: ToString + Clone, B: ToString + Clone>(a: A, b: B) -> (X<A, B>, X<B, A>) {
-    //~^ ERROR concrete type differs from previous defining opaque type
-    (a.clone(), a)
+    (a.clone(), a) //~ ERROR concrete type differs from previous defining opaque type
 }
 
 fn main() {
@@ -22,8 +22,7 @@ impl Bar for u32 {
 }
 
 fn four<T: Debug, U: Bar>(t: T) -> Two<T, U> {
-    //~^ ERROR concrete type differs from previous
-    (t, <U as Bar>::FOO)
+    (t, <U as Bar>::FOO) //~ ERROR concrete type differs from previous
 }
 
 fn is_sync<T: Sync>() {}
@@ -0,0 +1,15 @@
+// run-pass
+// Check that `type_ascribe!` guides inference like `expr: Ty` does.
+
+#![feature(type_ascription)]
+
+fn main() {
+    let x = type_ascribe!(Default::default(), u8);
+    assert_eq!(x, 0u8);
+
+    let v = type_ascribe!(vec![1, 2, 3].into_iter().collect(), Vec<i64>);
+    assert_eq!(v.len(), 3);
+
+    let s = type_ascribe!("a".into(), String,);
+    assert_eq!(s, "a");
+}
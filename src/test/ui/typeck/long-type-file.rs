@@ -0,0 +1,16 @@
+// Check that the types longer than `-Z long-type-length` are shortened in diagnostics, and
+// written in full to a file.
+
+// compile-flags: -Z long-type-length=32
+// normalize-stderr-test: "long-type-[0-9a-f]{16}\.txt" -> "long-type-HASH.txt"
+
+trait Marker {}
+
+fn needs_marker<T: Marker>(_: T) {}
+
+fn main() {
+    let nested: Vec<Option<Option<Option<Option<u8>>>>> = Vec::new();
+    nested.frobnicate(); //~ ERROR no method named `frobnicate` found
+    needs_marker(nested); //~ ERROR the trait bound
+    let _: Vec<Option<Option<Option<Option<u16>>>>> = nested; //~ ERROR mismatched types
+}
@@ -0,0 +1,8 @@
+// Check that the expected and found types of a type mismatch note, and their labels, are colored,
+// and that the parts of the types that differ are emphasized.
+
+// compile-flags: --error-format=human --color=always
+
+fn main() {
+    let _: Vec<u32> = Vec::<i32>::new();
+}
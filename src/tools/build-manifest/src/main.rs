@@ -182,7 +182,8 @@
 
 static MINGW: &[&str] = &["i686-pc-windows-gnu", "x86_64-pc-windows-gnu"];
 
-static NIGHTLY_ONLY_COMPONENTS: &[&str] = &["miri-preview", "rust-analyzer-preview"];
+static NIGHTLY_ONLY_COMPONENTS: &[&str] =
+    &["miri-preview", "rust-analyzer-preview", "rustc-codegen-cranelift-preview"];
 
 macro_rules! t {
     ($e:expr) => {
@@ -322,6 +323,7 @@ macro_rules! package {
         package!("clippy-preview", HOSTS);
         package!("miri-preview", HOSTS);
         package!("rustfmt-preview", HOSTS);
+        package!("rustc-codegen-cranelift-preview", HOSTS);
         package!("rust-analysis", TARGETS);
         package!("llvm-tools-preview", TARGETS);
     }
@@ -378,6 +380,7 @@ fn add_profiles_to(&mut self, manifest: &mut Manifest) {
                 "llvm-tools-preview",
                 "rust-analysis",
                 "miri-preview",
+                "rustc-codegen-cranelift-preview",
             ],
         );
 
@@ -398,6 +401,7 @@ fn add_renames_to(&self, manifest: &mut Manifest) {
         rename("rustfmt", "rustfmt-preview");
         rename("clippy", "clippy-preview");
         rename("miri", "miri-preview");
+        rename("rustc-codegen-cranelift", "rustc-codegen-cranelift-preview");
     }
 
     fn rust_package(&mut self, manifest: &Manifest) -> Package {
@@ -451,6 +455,7 @@ fn target_host_combination(&mut self, host: &str, manifest: &Manifest) -> Option
             host_component("rls-preview"),
             host_component("rust-analyzer-preview"),
             host_component("rustfmt-preview"),
+            host_component("rustc-codegen-cranelift-preview"),
             host_component("llvm-tools-preview"),
             host_component("rust-analysis"),
         ]);
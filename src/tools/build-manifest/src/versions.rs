@@ -20,6 +20,7 @@ pub(crate) enum PkgType {
     Rustfmt,
     LlvmTools,
     Miri,
+    RustcCodegenCranelift,
     Other(String),
 }
 
@@ -36,6 +37,9 @@ pub(crate) fn from_component(component: &str) -> Self {
             "rustfmt" | "rustfmt-preview" => PkgType::Rustfmt,
             "llvm-tools" | "llvm-tools-preview" => PkgType::LlvmTools,
             "miri" | "miri-preview" => PkgType::Miri,
+            "rustc-codegen-cranelift" | "rustc-codegen-cranelift-preview" => {
+                PkgType::RustcCodegenCranelift
+            }
             other => PkgType::Other(other.into()),
         }
     }
@@ -53,6 +57,7 @@ fn tarball_component_name(&self) -> &str {
             PkgType::Rustfmt => "rustfmt",
             PkgType::LlvmTools => "llvm-tools",
             PkgType::Miri => "miri",
+            PkgType::RustcCodegenCranelift => "rustc-codegen-cranelift",
             PkgType::Other(component) => component,
         }
     }
@@ -72,6 +77,7 @@ fn should_use_rust_version(&self) -> bool {
             PkgType::Rust => true,
             PkgType::RustSrc => true,
             PkgType::Rustc => true,
+            PkgType::RustcCodegenCranelift => true,
             PkgType::Other(_) => true,
         }
     }
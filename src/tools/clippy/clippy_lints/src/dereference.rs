@@ -507,6 +507,7 @@ fn is_linted_explicit_deref_position(parent: Option<Node<'_>>, child_id: HirId,
         | ExprKind::Break(..)
         | ExprKind::Continue(..)
         | ExprKind::Ret(..)
+        | ExprKind::Become(..)
         | ExprKind::InlineAsm(..)
         | ExprKind::Struct(..)
         | ExprKind::Repeat(..)
@@ -164,6 +164,7 @@ fn never_loop_expr(expr: &Expr<'_>, main_loop_id: HirId) -> NeverLoopResult {
         ExprKind::Break(_, e) | ExprKind::Ret(e) => e.as_ref().map_or(NeverLoopResult::AlwaysBreak, |e| {
             combine_seq(never_loop_expr(e, main_loop_id), NeverLoopResult::AlwaysBreak)
         }),
+        ExprKind::Become(e) => combine_seq(never_loop_expr(e, main_loop_id), NeverLoopResult::AlwaysBreak),
         ExprKind::InlineAsm(asm) => asm
             .operands
             .iter()
@@ -273,6 +273,10 @@ fn print_expr(cx: &LateContext<'_>, expr: &hir::Expr<'_>, indent: usize) {
                 print_expr(cx, e, indent + 1);
             }
         },
+        hir::ExprKind::Become(e) => {
+            println!("{}Become", ind);
+            print_expr(cx, e, indent + 1);
+        },
         hir::ExprKind::InlineAsm(asm) => {
             println!("{}InlineAsm", ind);
             println!("{}template: {}", ind, InlineAsmTemplatePiece::to_string(asm.template));
@@ -174,6 +174,7 @@ fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
                 ExprKind::Break(..)
                 | ExprKind::Continue(_)
                 | ExprKind::Ret(_)
+                | ExprKind::Become(_)
                 | ExprKind::InlineAsm(_)
                 | ExprKind::Yield(..)
                 | ExprKind::Err => {
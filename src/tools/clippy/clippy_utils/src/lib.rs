@@ -798,6 +798,7 @@ pub fn can_move_expr_to_closure_no_visit<'tcx>(
         ExprKind::Break(..)
         | ExprKind::Continue(_)
         | ExprKind::Ret(_)
+        | ExprKind::Become(_)
         | ExprKind::Yield(..)
         | ExprKind::InlineAsm(_) => false,
         // Accessing a field of a local value can only be done if the type isn't
@@ -144,7 +144,11 @@ fn check_rvalue<'tcx>(
     match rvalue {
         Rvalue::ThreadLocalRef(_) => Err((span, "cannot access thread local storage in const fn".into())),
         Rvalue::Repeat(operand, _) | Rvalue::Use(operand) => check_operand(tcx, operand, span, body),
-        Rvalue::Len(place) | Rvalue::Discriminant(place) | Rvalue::Ref(_, _, place) | Rvalue::AddressOf(_, place) => {
+        Rvalue::Len(place)
+        | Rvalue::Discriminant(place)
+        | Rvalue::CopyForDeref(place)
+        | Rvalue::Ref(_, _, place)
+        | Rvalue::AddressOf(_, place) => {
             check_place(tcx, *place, span, body)
         },
         Rvalue::Cast(CastKind::Misc, operand, cast_ty) => {
@@ -316,6 +320,8 @@ fn check_terminator<'a, 'tcx>(
             Err((span, "const fn generators are unstable".into()))
         },
 
+        TerminatorKind::TailCall { .. } => Err((span, "tail calls are not allowed in const fn".into())),
+
         TerminatorKind::Call {
             func,
             args,
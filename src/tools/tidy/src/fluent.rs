@@ -0,0 +1,150 @@
+//! Tidy check to ensure that the Fluent resources translate diagnostics that exist.
+//!
+//! The messages of the `.ftl` resources are named by the `#[slug = "..."]` of a diagnostic that
+//! derives `SessionDiagnostic`, and their attributes by `note`, `help` or the field that a label
+//! or a suggestion is on. A message can only refer to the fields that the English message it
+//! translates refers to, as those are the only ones given to the translation. A translation that
+//! doesn't match its diagnostic would silently never be used, or keep a variable untranslated.
+
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+lazy_static::lazy_static! {
+    static ref SLUG: Regex = Regex::new(r#"(?m)^#\[slug = "([^"]+)"\]"#).unwrap();
+    static ref ATTR: Regex =
+        Regex::new(r#"(?s)(#\[|\(|, )([a-z_]+) = "((?:[^"\\]|\\.)*)""#).unwrap();
+    static ref FIELD: Regex = Regex::new(r"(?m)^\s*pub ([a-z_][a-z0-9_]*):").unwrap();
+    static ref FORMAT_ARG: Regex = Regex::new(r"\{([a-z_][a-z0-9_]*)\}").unwrap();
+    static ref FTL_MESSAGE: Regex = Regex::new(r"^([a-zA-Z][a-zA-Z0-9_-]*)\s*=").unwrap();
+    static ref FTL_ATTRIBUTE: Regex =
+        Regex::new(r"^\s+\.([a-zA-Z][a-zA-Z0-9_-]*)\s*=").unwrap();
+    static ref FTL_VARIABLE: Regex =
+        Regex::new(r"\{\s*\$([a-zA-Z][a-zA-Z0-9_-]*)\s*\}").unwrap();
+}
+
+/// The messages of a diagnostic that can be translated: `None` for the primary message, and the
+/// name of the Fluent attribute otherwise, each with the fields that its English message refers
+/// to.
+type Messages = BTreeMap<Option<String>, BTreeSet<String>>;
+
+pub fn check(src_path: &Path, compiler_path: &Path, bad: &mut bool) {
+    let mut diagnostics = BTreeMap::new();
+    super::walk(
+        compiler_path,
+        &mut |path| super::filter_dirs(path) || path.extension().map_or(true, |ext| ext != "rs"),
+        &mut |_, contents| {
+            for (slug, messages) in slugged_diagnostics(contents) {
+                diagnostics.insert(slug, messages);
+            }
+        },
+    );
+
+    super::walk_many(
+        &[src_path, compiler_path],
+        &mut |path| super::filter_dirs(path) || path.extension().map_or(true, |ext| ext != "ftl"),
+        &mut |entry, contents| {
+            let file = entry.path().display();
+            // The message whose attributes and continuation lines follow, and the attribute of it
+            // whose pattern the next continuation lines are part of.
+            let mut current: Option<(&Messages, String, Option<String>)> = None;
+            for (i, line) in contents.lines().enumerate() {
+                let line_no = i + 1;
+                if line.trim_start().starts_with('#') {
+                    continue;
+                }
+                if let Some(caps) = FTL_MESSAGE.captures(line) {
+                    let slug = &caps[1];
+                    current = match diagnostics.get(slug) {
+                        Some(messages) => Some((messages, slug.to_string(), None)),
+                        None => {
+                            tidy_error!(
+                                bad,
+                                "{}:{}: `{}` isn't the slug of a diagnostic",
+                                file,
+                                line_no,
+                                slug
+                            );
+                            None
+                        }
+                    };
+                } else if let Some(caps) = FTL_ATTRIBUTE.captures(line) {
+                    if let Some((messages, slug, attr)) = &mut current {
+                        let name = caps[1].to_string();
+                        if !messages.contains_key(&Some(name.clone())) {
+                            tidy_error!(
+                                bad,
+                                "{}:{}: the diagnostic `{}` has no message translated by `.{}`",
+                                file,
+                                line_no,
+                                slug,
+                                name
+                            );
+                        }
+                        *attr = Some(name);
+                    }
+                } else if !line.starts_with(char::is_whitespace) {
+                    current = None;
+                }
+
+                let (slug, fields) = match &current {
+                    Some((messages, slug, attr)) => match messages.get(attr) {
+                        Some(fields) => (slug, fields),
+                        None => continue,
+                    },
+                    None => continue,
+                };
+                for caps in FTL_VARIABLE.captures_iter(line) {
+                    let variable = &caps[1];
+                    if !fields.contains(variable) {
+                        tidy_error!(
+                            bad,
+                            "{}:{}: `{}` isn't a field of `{}` that its English message refers to",
+                            file,
+                            line_no,
+                            variable,
+                            slug
+                        );
+                    }
+                }
+            }
+        },
+    );
+}
+
+/// Returns the slug and the messages of each diagnostic with a slug in `contents`.
+fn slugged_diagnostics(contents: &str) -> Vec<(String, Messages)> {
+    let mut diagnostics = Vec::new();
+    for caps in SLUG.captures_iter(contents) {
+        let start = caps.get(0).unwrap().end();
+        // The attributes and the fields of the diagnostic, up to the end of its struct.
+        let body = &contents[start..];
+        let body = &body[..body.find("\n}").unwrap_or(body.len())];
+        let fields: BTreeSet<String> =
+            FIELD.captures_iter(body).map(|caps| caps[1].to_string()).collect();
+
+        let mut messages = Messages::new();
+        for attr in ATTR.captures_iter(body) {
+            let pos = attr.get(0).unwrap().start();
+            let key = match (&attr[2], &attr[1] == "#[") {
+                // The primary message, on the struct or on a field.
+                ("message", true) => None,
+                ("note" | "help", true) => Some(attr[2].to_string()),
+                // Labels and the messages of suggestions are translated by the attribute named
+                // after the field they are on, which is the next one declared.
+                ("label", true) | ("message", false) => match FIELD.captures(&body[pos..]) {
+                    Some(field) => Some(field[1].replace('_', "-")),
+                    None => continue,
+                },
+                _ => continue,
+            };
+            let referenced = FORMAT_ARG
+                .captures_iter(&attr[3])
+                .map(|caps| caps[1].to_string())
+                .filter(|field| fields.contains(field));
+            messages.entry(key).or_default().extend(referenced);
+        }
+        diagnostics.push((caps[1].to_string(), messages));
+    }
+    diagnostics
+}
@@ -45,11 +45,13 @@ macro_rules! tidy_error {
 pub mod errors;
 pub mod extdeps;
 pub mod features;
+pub mod fluent;
 pub mod pal;
 pub mod primitive_docs;
 pub mod style;
 pub mod target_specific_tests;
 pub mod ui_tests;
+pub mod uncoded_errors;
 pub mod unit_tests;
 pub mod unstable_book;
 
@@ -68,6 +68,8 @@ macro_rules! check {
         // Checks that only make sense for the compiler.
         check!(errors, &compiler_path);
         check!(error_codes_check, &[&src_path, &compiler_path]);
+        check!(uncoded_errors, &compiler_path);
+        check!(fluent, &src_path, &compiler_path);
 
         // Checks that only make sense for the std libs.
         check!(pal, &library_path);
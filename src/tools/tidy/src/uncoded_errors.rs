@@ -0,0 +1,37 @@
+//! Tidy check to ensure that new errors are given an error code.
+//!
+//! Errors created with `struct_span_err!` always have a code, but the `struct_span_err` methods
+//! create errors without one. Existing uses of those methods are being given codes over time, so
+//! this only checks that their number doesn't grow. Unit tests (`tests.rs` files) create errors
+//! to exercise the emitters and aren't counted.
+
+use std::path::Path;
+
+// FIXME: This limit should be reduced as errors are given codes.
+const UNCODED_ERRORS_LIMIT: usize = 510;
+
+pub fn check(path: &Path, bad: &mut bool) {
+    let mut count = 0;
+    super::walk(
+        path,
+        &mut |path| {
+            super::filter_dirs(path)
+                || path.extension().map_or(false, |ext| ext != "rs")
+                || path.file_name().map_or(false, |name| name == "tests.rs")
+        },
+        &mut |_, contents| {
+            count += contents.matches(".struct_span_err(").count();
+        },
+    );
+
+    if count > UNCODED_ERRORS_LIMIT {
+        tidy_error!(
+            bad,
+            "found {} calls to `struct_span_err` methods, which create errors without \
+            an error code (limit: {}); use `struct_span_err!` or `#[derive(SessionDiagnostic)]` \
+            with a new error code instead",
+            count,
+            UNCODED_ERRORS_LIMIT,
+        );
+    }
+}